@@ -43,7 +43,8 @@ use mz_ore::collections::CollectionExt;
 use mz_ore::metrics::MetricsRegistry;
 use mz_ore::now::{to_datetime, EpochMillis, NowFn};
 use mz_pgrepr::oid::FIRST_USER_OID;
-use mz_repr::{explain_new::ExprHumanizer, Diff, GlobalId, RelationDesc, ScalarType};
+use mz_repr::adt::datetime::Timezone;
+use mz_repr::{explain_new::ExprHumanizer, Diff, GlobalId, RelationDesc, Row, ScalarType};
 use mz_secrets::InMemorySecretsController;
 use mz_sql::ast::display::AstDisplay;
 use mz_sql::ast::Expr;
@@ -54,9 +55,9 @@ use mz_sql::catalog::{
     SessionCatalog, TypeReference,
 };
 use mz_sql::names::{
-    Aug, DatabaseId, FullObjectName, ObjectQualifiers, PartialObjectName, QualifiedObjectName,
-    QualifiedSchemaName, RawDatabaseSpecifier, ResolvedDatabaseSpecifier, RoleId, SchemaId,
-    SchemaSpecifier,
+    Aug, DatabaseId, FullObjectName, ObjectId, ObjectQualifiers, PartialObjectName,
+    QualifiedObjectName, QualifiedSchemaName, RawDatabaseSpecifier, ResolvedDatabaseSpecifier,
+    RoleId, SchemaId, SchemaSpecifier,
 };
 use mz_sql::plan::{
     AlterOptionParameter, CreateConnectionPlan, CreateIndexPlan, CreateMaterializedViewPlan,
@@ -64,8 +65,11 @@ use mz_sql::plan::{
     CreateViewPlan, Params, Plan, PlanContext, StatementDesc,
     StorageHostConfig as PlanStorageHostConfig,
 };
+use mz_sql::rbac::{self, AclMode, Privileges, PUBLIC_ROLE_NAME};
 use mz_sql::{plan, DEFAULT_SCHEMA};
-use mz_sql_parser::ast::{CreateSinkOption, CreateSourceOption, Statement, WithOptionValue};
+use mz_sql_parser::ast::{
+    CreateSinkOption, CreateSourceOption, ObjectType as SqlObjectType, Statement, WithOptionValue,
+};
 use mz_ssh_util::keys::SshKeyPairSet;
 use mz_stash::{Append, Memory, Postgres, PostgresFactory};
 use mz_storage_client::types::hosts::{StorageHostConfig, StorageHostResourceAllocation};
@@ -183,6 +187,9 @@ pub struct CatalogState {
     compute_instances_by_id: HashMap<ComputeInstanceId, ComputeInstance>,
     compute_instances_by_name: HashMap<String, ComputeInstanceId>,
     roles: HashMap<String, Role>,
+    /// The owner and access control list of each object. Builtin objects have
+    /// no entry; see [`CatalogState::get_privileges`].
+    privileges: BTreeMap<ObjectId, Privileges>,
     config: mz_sql::catalog::CatalogConfig,
     oid_counter: u32,
     cluster_replica_sizes: ClusterReplicaSizeMap,
@@ -193,6 +200,17 @@ pub struct CatalogState {
     system_configuration: SystemVars,
     egress_ips: Vec<Ipv4Addr>,
     aws_principal_context: Option<AwsPrincipalContext>,
+    /// The statistics most recently collected by `ANALYZE` for each relation.
+    relation_statistics: BTreeMap<GlobalId, RelationStatistics>,
+}
+
+/// Statistics about the contents of a relation, as collected by `ANALYZE`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelationStatistics {
+    /// The number of rows in the relation.
+    pub row_count: u64,
+    /// The number of distinct values in each column of the relation.
+    pub distinct_counts: Vec<u64>,
 }
 
 impl CatalogState {
@@ -500,6 +518,7 @@ impl CatalogState {
             search_path: Vec::new(),
             user: SYSTEM_USER.clone(),
             prepared_statements: None,
+            timezone: Timezone::UTC,
         };
         let stmt = mz_sql::parse::parse(&create_sql)?.into_element();
         let (stmt, depends_on) = mz_sql::names::resolve(&session_catalog, stmt)?;
@@ -979,6 +998,98 @@ impl CatalogState {
             || schema == MZ_INTERNAL_SCHEMA
     }
 
+    /// Returns the ID of the `mz_system` role, which owns all builtin objects.
+    pub fn system_role_id(&self) -> RoleId {
+        self.roles[&SYSTEM_USER.name].id
+    }
+
+    /// Returns the name of the role identified by `id`.
+    pub fn get_role_name(&self, id: &RoleId) -> &str {
+        match id {
+            RoleId::Public => PUBLIC_ROLE_NAME,
+            id => self
+                .roles
+                .values()
+                .find(|role| role.id == *id)
+                .map(|role| role.name.as_str())
+                .expect("role must exist"),
+        }
+    }
+
+    /// Returns the role as which `session` executes, which owns any objects
+    /// that `session` creates. Operations without a session execute as the
+    /// `mz_system` role.
+    pub fn session_role_id(&self, session: Option<&Session>) -> RoleId {
+        session
+            .and_then(|session| self.roles.get(&session.user().name))
+            .map(|role| role.id)
+            .unwrap_or_else(|| self.system_role_id())
+    }
+
+    /// Reports whether `session` executes as a superuser, which bypasses all
+    /// privilege checks.
+    pub fn is_superuser(&self, session: &Session) -> bool {
+        self.roles
+            .get(&session.user().name)
+            .map(|role| role.superuser)
+            .unwrap_or(false)
+    }
+
+    /// Returns the type of the object identified by `id`.
+    pub fn get_object_type(&self, id: &ObjectId) -> SqlObjectType {
+        match id {
+            ObjectId::Cluster(_) => SqlObjectType::Cluster,
+            ObjectId::Database(_) => SqlObjectType::Database,
+            ObjectId::Schema(_) => SqlObjectType::Schema,
+            ObjectId::Item(id) => rbac::item_object_type(self.get_entry(id).item().typ()),
+        }
+    }
+
+    /// Returns the name of the object identified by `id`, as it should appear
+    /// in error messages.
+    pub fn get_object_name(&self, id: &ObjectId, conn_id: ConnectionId) -> String {
+        match id {
+            ObjectId::Cluster(id) => self.compute_instances_by_id[id].name.clone(),
+            ObjectId::Database(id) => self.get_database(id).name.clone(),
+            ObjectId::Schema((database_spec, schema_spec)) => self
+                .get_schema(database_spec, schema_spec, conn_id)
+                .name
+                .schema
+                .clone(),
+            ObjectId::Item(id) => self
+                .resolve_full_name(self.get_entry(id).name(), Some(conn_id))
+                .to_string(),
+        }
+    }
+
+    /// Returns the owner and access control list of the object identified by
+    /// `id`.
+    ///
+    /// Builtin objects are not persisted, so their privileges are synthesized:
+    /// they are owned by `mz_system`, and `PUBLIC` may read and use them.
+    pub fn get_privileges(&self, id: &ObjectId) -> Cow<Privileges> {
+        if let Some(privileges) = self.privileges.get(id) {
+            return Cow::Borrowed(privileges);
+        }
+        let object_type = self.get_object_type(id);
+        let mut privileges = Privileges::new(self.system_role_id(), object_type);
+        let is_builtin = match id {
+            ObjectId::Cluster(id) => id.is_system(),
+            ObjectId::Database(_) => false,
+            ObjectId::Schema((database_spec, _)) => {
+                *database_spec == ResolvedDatabaseSpecifier::Ambient
+            }
+            ObjectId::Item(id) => id.is_system(),
+        };
+        if is_builtin {
+            privileges.grant(
+                RoleId::Public,
+                AclMode::all_for(object_type) & (AclMode::SELECT | AclMode::USAGE),
+            );
+        }
+        Cow::Owned(privileges)
+    }
+
     /// Optimized lookup for a builtin table
     ///
     /// Panics if the builtin table doesn't exist in the catalog
@@ -1283,6 +1394,162 @@ impl CatalogState {
         tx.insert_storage_usage_event(details);
         Ok(())
     }
+
+    pub fn for_sessionless_user(&self, user: User) -> ConnCatalog {
+        ConnCatalog {
+            state: Cow::Borrowed(self),
+            conn_id: SYSTEM_CONN_ID,
+            compute_instance: "default".into(),
+            database: self
+                .resolve_database(DEFAULT_DATABASE_NAME)
+                .ok()
+                .map(|db| db.id()),
+            search_path: Vec::new(),
+            user,
+            prepared_statements: None,
+            timezone: Timezone::UTC,
+        }
+    }
+
+    // Leaving the system's search path empty allows us to catch issues
+    // where catalog object names have not been normalized correctly.
+    pub fn for_system_session(&self) -> ConnCatalog {
+        self.for_sessionless_user(SYSTEM_USER.clone())
+    }
+
+    /// Parses the given SQL string into a `CatalogItem`.
+    ///
+    /// If `conn_id` is specified, the item is a temporary item owned by that
+    /// connection, and names are resolved on its behalf.
+    fn parse_item(
+        &self,
+        create_sql: String,
+        pcx: Option<&PlanContext>,
+        conn_id: Option<ConnectionId>,
+    ) -> Result<CatalogItem, anyhow::Error> {
+        let mut session_catalog = self.for_system_session();
+        if let Some(conn_id) = conn_id {
+            session_catalog.conn_id = conn_id;
+        }
+        let stmt = mz_sql::parse::parse(&create_sql)?.into_element();
+        let (stmt, depends_on) = mz_sql::names::resolve(&session_catalog, stmt)?;
+        let depends_on = depends_on.into_iter().collect();
+        let plan = mz_sql::plan::plan(pcx, &session_catalog, stmt, &Params::empty())?;
+        Ok(match plan {
+            Plan::CreateTable(CreateTablePlan { table, .. }) => CatalogItem::Table(Table {
+                create_sql: table.create_sql,
+                desc: table.desc,
+                defaults: table.defaults,
+                missing_values: Some(table.missing_values),
+                conn_id,
+                depends_on,
+                custom_logical_compaction_window: None,
+                is_retained_metrics_relation: false,
+            }),
+            Plan::CreateSource(CreateSourcePlan {
+                source,
+                timeline,
+                host_config,
+                ..
+            }) => {
+                let allow_undefined_size = true;
+                CatalogItem::Source(Source {
+                    create_sql: source.create_sql,
+                    data_source: match source.data_source {
+                        plan::DataSourceDesc::Ingestion(ingestion) => {
+                            DataSourceDesc::Ingestion(Ingestion {
+                                desc: ingestion.desc,
+                                source_imports: ingestion.source_imports,
+                                subsource_exports: ingestion.subsource_exports,
+                                host_config: self.resolve_storage_host_config(
+                                    host_config,
+                                    allow_undefined_size,
+                                )?,
+                            })
+                        }
+                        plan::DataSourceDesc::Source => DataSourceDesc::Source,
+                        plan::DataSourceDesc::Webhook(webhook) => DataSourceDesc::Webhook(webhook),
+                    },
+                    desc: source.desc,
+                    timeline,
+                    depends_on,
+                    custom_logical_compaction_window: None,
+                    is_retained_metrics_relation: false,
+                })
+            }
+            Plan::CreateView(CreateViewPlan { view, .. }) => {
+                let optimizer = Optimizer::logical_optimizer();
+                let optimized_expr = optimizer.optimize(view.expr)?;
+                let desc = RelationDesc::new(optimized_expr.typ(), view.column_names);
+                CatalogItem::View(View {
+                    create_sql: view.create_sql,
+                    optimized_expr,
+                    desc,
+                    conn_id,
+                    depends_on,
+                })
+            }
+            Plan::CreateMaterializedView(CreateMaterializedViewPlan {
+                materialized_view, ..
+            }) => {
+                let optimizer = Optimizer::logical_optimizer();
+                let optimized_expr = optimizer.optimize(materialized_view.expr)?;
+                let desc = RelationDesc::new(optimized_expr.typ(), materialized_view.column_names);
+                CatalogItem::MaterializedView(MaterializedView {
+                    create_sql: materialized_view.create_sql,
+                    optimized_expr,
+                    desc,
+                    depends_on,
+                    compute_instance: materialized_view.compute_instance,
+                })
+            }
+            Plan::CreateIndex(CreateIndexPlan { index, .. }) => CatalogItem::Index(Index {
+                create_sql: index.create_sql,
+                on: index.on,
+                keys: index.keys,
+                conn_id,
+                depends_on,
+                compute_instance: index.compute_instance,
+            }),
+            Plan::CreateSink(CreateSinkPlan {
+                sink,
+                with_snapshot,
+                host_config,
+                ..
+            }) => {
+                let allow_undefined_size = true;
+                CatalogItem::Sink(Sink {
+                    create_sql: sink.create_sql,
+                    from: sink.from,
+                    connection: StorageSinkConnectionState::Pending(sink.connection_builder),
+                    envelope: sink.envelope,
+                    with_snapshot,
+                    depends_on,
+                    host_config: self
+                        .resolve_storage_host_config(host_config, allow_undefined_size)?,
+                })
+            }
+            Plan::CreateType(CreateTypePlan { typ, .. }) => CatalogItem::Type(Type {
+                create_sql: typ.create_sql,
+                details: CatalogTypeDetails {
+                    array_id: None,
+                    typ: typ.inner,
+                },
+                depends_on,
+            }),
+            Plan::CreateSecret(CreateSecretPlan { secret, .. }) => CatalogItem::Secret(Secret {
+                create_sql: secret.create_sql,
+            }),
+            Plan::CreateConnection(CreateConnectionPlan { connection, .. }) => {
+                CatalogItem::Connection(Connection {
+                    create_sql: connection.create_sql,
+                    connection: connection.connection,
+                    depends_on,
+                })
+            }
+            _ => bail!("catalog entry generated inappropriate plan"),
+        })
+    }
 }
 
 #[derive(Debug)]
@@ -1294,6 +1561,7 @@ pub struct ConnCatalog<'a> {
     search_path: Vec<(ResolvedDatabaseSpecifier, SchemaSpecifier)>,
     user: User,
     prepared_statements: Option<Cow<'a, HashMap<String, PreparedStatement>>>,
+    timezone: Timezone,
 }
 
 impl ConnCatalog<'_> {
@@ -1314,6 +1582,7 @@ impl ConnCatalog<'_> {
             search_path: self.search_path,
             user: self.user,
             prepared_statements: self.prepared_statements.map(|s| Cow::Owned(s.into_owned())),
+            timezone: self.timezone,
         }
     }
 
@@ -1376,6 +1645,8 @@ pub struct Role {
     pub id: RoleId,
     #[serde(skip)]
     pub oid: u32,
+    /// Whether the role bypasses all privilege checks.
+    pub superuser: bool,
 }
 
 impl Role {
@@ -1452,6 +1723,10 @@ pub struct Table {
     pub desc: RelationDesc,
     #[serde(skip)]
     pub defaults: Vec<Expr<Aug>>,
+    /// The values of the table's columns in rows that were written before the
+    /// columns were added to the table, or `None` if the table's columns
+    /// cannot change.
+    pub missing_values: Option<Row>,
     pub conn_id: Option<ConnectionId>,
     pub depends_on: Vec<GlobalId>,
     pub custom_logical_compaction_window: Option<Duration>,
@@ -1476,6 +1751,8 @@ pub enum DataSourceDesc {
     Source,
     /// Receives introspection data from an internal system
     Introspection(IntrospectionType),
+    /// Receives data appended through `environmentd`'s HTTP webhook endpoint
+    Webhook(plan::Webhook),
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -1495,14 +1772,16 @@ impl Source {
     pub fn size(&self) -> Option<&str> {
         match &self.data_source {
             DataSourceDesc::Ingestion(Ingestion { host_config, .. }) => host_config.size(),
-            DataSourceDesc::Introspection(_) | DataSourceDesc::Source => None,
+            DataSourceDesc::Introspection(_)
+            | DataSourceDesc::Source
+            | DataSourceDesc::Webhook(_) => None,
         }
     }
 
     /// Returns whether this source ingests data from an external source.
     pub fn is_external(&self) -> bool {
         match self.data_source {
-            DataSourceDesc::Ingestion(_) => true,
+            DataSourceDesc::Ingestion(_) | DataSourceDesc::Webhook(_) => true,
             DataSourceDesc::Source | DataSourceDesc::Introspection(_) => false,
         }
     }
@@ -1513,6 +1792,7 @@ impl Source {
             DataSourceDesc::Ingestion(ingestion) => ingestion.desc.name(),
             DataSourceDesc::Source => "subsource",
             DataSourceDesc::Introspection(_) => "source",
+            DataSourceDesc::Webhook(_) => "webhook",
         }
     }
 
@@ -1549,6 +1829,8 @@ impl Source {
                     Some("materialize")
                 }
             },
+            // Webhook sources only ever append, which is the "NONE"-envelope.
+            DataSourceDesc::Webhook(_) => Some("none"),
             DataSourceDesc::Source => None,
             DataSourceDesc::Introspection(_) => None,
         }
@@ -1558,7 +1840,9 @@ impl Source {
     pub fn connection_id(&self) -> Option<GlobalId> {
         match &self.data_source {
             DataSourceDesc::Ingestion(ingestion) => ingestion.desc.connection.connection_id(),
-            DataSourceDesc::Source | DataSourceDesc::Introspection(_) => None,
+            DataSourceDesc::Source
+            | DataSourceDesc::Introspection(_)
+            | DataSourceDesc::Webhook(_) => None,
         }
     }
 }
@@ -1739,7 +2023,9 @@ impl CatalogItem {
         match &self {
             CatalogItem::Source(source) => match &source.data_source {
                 DataSourceDesc::Ingestion(ingestion) => Ok(Some(&ingestion.desc)),
-                DataSourceDesc::Source | DataSourceDesc::Introspection(_) => Ok(None),
+                DataSourceDesc::Source
+                | DataSourceDesc::Introspection(_)
+                | DataSourceDesc::Webhook(_) => Ok(None),
             },
             _ => Err(SqlCatalogError::UnexpectedType {
                 name: entry.name().item.to_string(),
@@ -2002,6 +2288,17 @@ impl CatalogEntry {
         matches!(self.item(), CatalogItem::Source(_))
     }
 
+    /// Reports whether this catalog entry is a webhook source.
+    pub fn is_webhook_source(&self) -> bool {
+        matches!(
+            self.item(),
+            CatalogItem::Source(Source {
+                data_source: DataSourceDesc::Webhook(_),
+                ..
+            })
+        )
+    }
+
     /// Reports whether this catalog entry is a sink.
     pub fn is_sink(&self) -> bool {
         matches!(self.item(), CatalogItem::Sink(_))
@@ -2204,6 +2501,7 @@ impl<S: Append> Catalog<S> {
                 compute_instances_by_id: HashMap::new(),
                 compute_instances_by_name: HashMap::new(),
                 roles: HashMap::new(),
+                privileges: BTreeMap::new(),
                 config: mz_sql::catalog::CatalogConfig {
                     start_time: to_datetime((config.now)()),
                     start_instant: Instant::now(),
@@ -2225,6 +2523,7 @@ impl<S: Append> Catalog<S> {
                 system_configuration: SystemVars::default(),
                 egress_ips: config.egress_ips,
                 aws_principal_context: config.aws_principal_context,
+                relation_statistics: BTreeMap::new(),
             },
             transient_revision: 0,
             storage: Arc::new(Mutex::new(config.storage)),
@@ -2290,7 +2589,7 @@ impl<S: Append> Catalog<S> {
         }
 
         let roles = catalog.storage().await.load_roles().await?;
-        for (id, name) in roles {
+        for (id, name, superuser) in roles {
             let oid = catalog.allocate_oid()?;
             catalog.state.roles.insert(
                 name.clone(),
@@ -2298,10 +2597,14 @@ impl<S: Append> Catalog<S> {
                     name: name.clone(),
                     id,
                     oid,
+                    superuser,
                 },
             );
         }
 
+        let privileges = catalog.storage().await.load_privileges().await?;
+        catalog.state.privileges.extend(privileges);
+
         catalog
             .load_system_configuration(
                 config.bootstrap_system_parameters,
@@ -2374,6 +2677,7 @@ impl<S: Append> Catalog<S> {
                             create_sql: CREATE_SQL_TODO.to_string(),
                             desc: table.desc.clone(),
                             defaults: vec![Expr::null(); table.desc.arity()],
+                            missing_values: None,
                             conn_id: None,
                             depends_on: vec![],
                             custom_logical_compaction_window: table
@@ -2610,6 +2914,28 @@ impl<S: Append> Catalog<S> {
             .apply_persisted_builtin_migration(&mut builtin_migration_metadata)
             .await?;
 
+        // Statistics of relations that no longer exist, like builtin relations
+        // whose IDs were changed by a migration, are forgotten.
+        let (relation_statistics, stale_relation_statistics): (Vec<_>, Vec<_>) = catalog
+            .storage()
+            .await
+            .load_relation_statistics()
+            .await?
+            .into_iter()
+            .partition(|(id, _)| catalog.state.try_get_entry(id).is_some());
+        if !stale_relation_statistics.is_empty() {
+            let mut storage = catalog.storage().await;
+            let mut tx = storage.transaction().await?;
+            for (id, _) in stale_relation_statistics {
+                tx.remove_relation_statistics(id)?;
+            }
+            tx.commit().await?;
+        }
+        catalog
+            .state
+            .relation_statistics
+            .extend(relation_statistics);
+
         // Load public keys for SSH connections from the secrets store to the catalog
         for (id, entry) in catalog.state.entry_by_id.iter_mut() {
             if let CatalogItem::Connection(ref mut connection) = entry.item {
@@ -2652,6 +2978,9 @@ impl<S: Append> Catalog<S> {
         for (_name, role) in &catalog.state.roles {
             builtin_table_updates.push(catalog.state.pack_role_update(role, 1));
         }
+        for id in catalog.state.relation_statistics.keys() {
+            builtin_table_updates.extend(catalog.state.pack_relation_statistics_update(*id, 1));
+        }
         for (name, id) in &catalog.state.compute_instances_by_name {
             builtin_table_updates.push(catalog.state.pack_compute_instance_update(name, 1));
             let instance = &catalog.state.compute_instances_by_id[id];
@@ -2915,6 +3244,9 @@ impl<S: Append> Catalog<S> {
                 key_reference: name_to_id_map[key_reference],
                 value_reference: name_to_id_map[value_reference],
             },
+            CatalogType::Range { element_reference } => CatalogType::Range {
+                element_reference: name_to_id_map[element_reference],
+            },
             CatalogType::Record { fields } => CatalogType::Record {
                 fields: fields
                     .into_iter()
@@ -3321,28 +3653,16 @@ impl<S: Append> Catalog<S> {
             search_path,
             user: session.user().clone(),
             prepared_statements: Some(Cow::Borrowed(session.prepared_statements())),
+            timezone: *session.vars().timezone(),
         }
     }
 
     pub fn for_sessionless_user(&self, user: User) -> ConnCatalog {
-        ConnCatalog {
-            state: Cow::Borrowed(&self.state),
-            conn_id: SYSTEM_CONN_ID,
-            compute_instance: "default".into(),
-            database: self
-                .resolve_database(DEFAULT_DATABASE_NAME)
-                .ok()
-                .map(|db| db.id()),
-            search_path: Vec::new(),
-            user,
-            prepared_statements: None,
-        }
+        self.state.for_sessionless_user(user)
     }
 
-    // Leaving the system's search path empty allows us to catch issues
-    // where catalog object names have not been normalized correctly.
     pub fn for_system_session(&self) -> ConnCatalog {
-        self.for_sessionless_user(SYSTEM_USER.clone())
+        self.state.for_system_session()
     }
 
     async fn storage<'a>(&'a self) -> MutexGuard<'a, storage::Connection<S>> {
@@ -3917,23 +4237,27 @@ impl<S: Append> Catalog<S> {
                 id: DatabaseId,
                 oid: u32,
                 name: String,
+                privileges: Privileges,
             },
             CreateSchema {
                 id: SchemaId,
                 oid: u32,
                 database_id: DatabaseId,
                 schema_name: String,
+                privileges: Privileges,
             },
             CreateRole {
                 id: RoleId,
                 oid: u32,
                 name: String,
+                superuser: bool,
             },
             CreateComputeInstance {
                 id: ComputeInstanceId,
                 name: String,
                 // These are the legacy, active logs of this compute instance
                 arranged_introspection_sources: Vec<(&'static BuiltinLog, GlobalId)>,
+                privileges: Privileges,
             },
             CreateComputeReplica {
                 id: ReplicaId,
@@ -3946,6 +4270,7 @@ impl<S: Append> Catalog<S> {
                 oid: u32,
                 name: QualifiedObjectName,
                 item: CatalogItem,
+                privileges: Privileges,
             },
             DropDatabase {
                 id: DatabaseId,
@@ -3971,9 +4296,17 @@ impl<S: Append> Catalog<S> {
                 to_name: QualifiedObjectName,
                 to_item: CatalogItem,
             },
+            UpdatePrivileges {
+                id: ObjectId,
+                privileges: Privileges,
+            },
             UpdateComputeReplicaStatus {
                 event: ComputeInstanceEvent,
             },
+            UpdateRelationStatistics {
+                id: GlobalId,
+                statistics: RelationStatistics,
+            },
             UpdateSystemConfiguration {
                 name: String,
                 value: String,
@@ -4003,6 +4336,15 @@ impl<S: Append> Catalog<S> {
             }
         }
 
+        /// Reports whether `id` identifies a temporary item, whose privileges
+        /// are not persisted.
+        fn is_temporary_object(state: &CatalogState, id: &ObjectId) -> bool {
+            match id {
+                ObjectId::Item(id) => state.get_entry(id).item().is_temporary(),
+                _ => false,
+            }
+        }
+
         for op in ops {
             match op {
                 Op::AlterSink { id, size, remote } => {
@@ -4151,7 +4493,9 @@ impl<S: Append> Catalog<S> {
                         DataSourceDesc::Ingestion(ingestion) => {
                             alter_host_config(&ingestion.host_config, size, remote)?
                         }
-                        DataSourceDesc::Introspection(_) | DataSourceDesc::Source => None,
+                        DataSourceDesc::Introspection(_)
+                        | DataSourceDesc::Source
+                        | DataSourceDesc::Webhook(_) => None,
                     };
 
                     if let Some(config) = new_config {
@@ -4178,7 +4522,9 @@ impl<S: Append> Catalog<S> {
                         // unsafe mode.
                         let allow_undefined_size = state.config().unsafe_mode
                             || match old_source.data_source {
-                                DataSourceDesc::Introspection(_) | DataSourceDesc::Source => true,
+                                DataSourceDesc::Introspection(_)
+                                | DataSourceDesc::Source
+                                | DataSourceDesc::Webhook(_) => true,
                                 DataSourceDesc::Ingestion(_) => false,
                             };
 
@@ -4239,6 +4585,131 @@ impl<S: Append> Catalog<S> {
                         )?;
                     }
                 }
+                Op::AlterTableAddColumn { id, create_sql } => {
+                    let entry = state.get_entry(&id);
+                    let name = entry.name().clone();
+                    let conn_id = entry.conn_id();
+
+                    if entry.id().is_system() {
+                        let schema_name = state
+                            .resolve_full_name(&name, session.map(|session| session.conn_id()))
+                            .schema;
+                        return Err(AdapterError::Catalog(Error::new(
+                            ErrorKind::ReadOnlySystemSchema(schema_name),
+                        )));
+                    }
+
+                    let old_table = match entry.item() {
+                        CatalogItem::Table(table) => table.clone(),
+                        other => {
+                            coord_bail!("ALTER TABLE entry was not a table: {}", other.typ())
+                        }
+                    };
+
+                    // Indexes and sinks are bound to the full relation of the table, so they
+                    // cannot keep their original projection across the change. Views and
+                    // materialized views are re-planned below instead.
+                    for dependent_id in entry.used_by() {
+                        let dependent = state.get_entry(dependent_id);
+                        if !matches!(
+                            dependent.item(),
+                            CatalogItem::Index(_) | CatalogItem::Sink(_)
+                        ) {
+                            continue;
+                        }
+                        return Err(AdapterError::Catalog(Error::new(
+                            ErrorKind::AlterTableDependency {
+                                table: state.resolve_full_name(&name, conn_id).to_string(),
+                                dependent_type: dependent.item().typ().to_string(),
+                                dependent: state
+                                    .resolve_full_name(dependent.name(), dependent.conn_id())
+                                    .to_string(),
+                            },
+                        )));
+                    }
+                    let used_by = entry.used_by().to_vec();
+
+                    let table =
+                        match state.parse_item(create_sql, Some(&PlanContext::zero()), conn_id)? {
+                            CatalogItem::Table(table) => CatalogItem::Table(Table {
+                                custom_logical_compaction_window: old_table
+                                    .custom_logical_compaction_window,
+                                is_retained_metrics_relation: old_table
+                                    .is_retained_metrics_relation,
+                                ..table
+                            }),
+                            other => {
+                                coord_bail!(
+                                    "ALTER TABLE produced a non-table item: {}",
+                                    other.typ()
+                                )
+                            }
+                        };
+
+                    if !table.is_temporary() {
+                        tx.update_item(id, &name.item, &Self::serialize_item(&table))?;
+                    }
+
+                    state.add_to_audit_log(
+                        session,
+                        tx,
+                        builtin_table_updates,
+                        audit_events,
+                        EventType::Alter,
+                        ObjectType::Table,
+                        EventDetails::IdFullNameV1(mz_audit_log::IdFullNameV1 {
+                            id: id.to_string(),
+                            name: Self::full_name_detail(&state.resolve_full_name(&name, conn_id)),
+                        }),
+                    )?;
+
+                    // NB: this will be re-incremented by the action below.
+                    builtin_table_updates.extend(state.pack_item_update(id, -1));
+                    catalog_action(
+                        state,
+                        builtin_table_updates,
+                        Action::UpdateItem {
+                            id,
+                            to_name: name.clone(),
+                            to_item: table,
+                        },
+                    )?;
+
+                    // Dependent views are re-planned against the new table. Their stored
+                    // `create_sql` is unchanged, but they must continue to produce the same
+                    // relation, which rules out e.g. `SELECT *`.
+                    for dependent_id in used_by {
+                        let dependent = state.get_entry(&dependent_id);
+                        let dependent_name = dependent.name().clone();
+                        let dependent_conn_id = dependent.conn_id();
+                        let full_name = state.resolve_full_name(&dependent_name, dependent_conn_id);
+                        let old_desc = dependent.desc(&full_name)?.into_owned();
+                        let to_item = state.parse_item(
+                            dependent.create_sql().to_string(),
+                            Some(&PlanContext::zero()),
+                            dependent_conn_id,
+                        )?;
+                        if *to_item.desc(&full_name)? != old_desc {
+                            return Err(AdapterError::Catalog(Error::new(
+                                ErrorKind::AlterTableDependency {
+                                    table: state.resolve_full_name(&name, conn_id).to_string(),
+                                    dependent_type: dependent.item().typ().to_string(),
+                                    dependent: full_name.to_string(),
+                                },
+                            )));
+                        }
+                        builtin_table_updates.extend(state.pack_item_update(dependent_id, -1));
+                        catalog_action(
+                            state,
+                            builtin_table_updates,
+                            Action::UpdateItem {
+                                id: dependent_id,
+                                to_name: dependent_name,
+                                to_item,
+                            },
+                        )?;
+                    }
+                }
                 Op::CreateDatabase {
                     name,
                     oid,
@@ -4246,6 +4717,20 @@ impl<S: Append> Catalog<S> {
                 } => {
                     let database_id = tx.insert_database(&name)?;
                     let schema_id = tx.insert_schema(database_id, DEFAULT_SCHEMA)?;
+                    let owner_id = state.session_role_id(session);
+                    let database_privileges = Privileges::new(owner_id, SqlObjectType::Database);
+                    tx.set_privileges(ObjectId::Database(database_id), &database_privileges)?;
+                    // As in PostgreSQL, everyone may use the default schema of
+                    // a new database.
+                    let mut schema_privileges = Privileges::new(owner_id, SqlObjectType::Schema);
+                    schema_privileges.grant(RoleId::Public, AclMode::USAGE);
+                    tx.set_privileges(
+                        ObjectId::Schema((
+                            ResolvedDatabaseSpecifier::Id(database_id),
+                            SchemaSpecifier::Id(schema_id),
+                        )),
+                        &schema_privileges,
+                    )?;
                     state.add_to_audit_log(
                         session,
                         tx,
@@ -4265,6 +4750,7 @@ impl<S: Append> Catalog<S> {
                             id: database_id,
                             oid,
                             name: name.clone(),
+                            privileges: database_privileges,
                         },
                     )?;
                     state.add_to_audit_log(
@@ -4288,6 +4774,7 @@ impl<S: Append> Catalog<S> {
                             oid: public_schema_oid,
                             database_id,
                             schema_name: DEFAULT_SCHEMA.to_string(),
+                            privileges: schema_privileges,
                         },
                     )?;
                 }
@@ -4310,6 +4797,15 @@ impl<S: Append> Catalog<S> {
                         }
                     };
                     let schema_id = tx.insert_schema(database_id, &schema_name)?;
+                    let privileges =
+                        Privileges::new(state.session_role_id(session), SqlObjectType::Schema);
+                    tx.set_privileges(
+                        ObjectId::Schema((
+                            ResolvedDatabaseSpecifier::Id(database_id),
+                            SchemaSpecifier::Id(schema_id),
+                        )),
+                        &privileges,
+                    )?;
                     state.add_to_audit_log(
                         session,
                         tx,
@@ -4331,16 +4827,21 @@ impl<S: Append> Catalog<S> {
                             oid,
                             database_id,
                             schema_name,
+                            privileges,
                         },
                     )?;
                 }
-                Op::CreateRole { name, oid } => {
+                Op::CreateRole {
+                    name,
+                    oid,
+                    superuser,
+                } => {
                     if is_reserved_name(&name) {
                         return Err(AdapterError::Catalog(Error::new(
                             ErrorKind::ReservedRoleName(name),
                         )));
                     }
-                    let role_id = tx.insert_user_role(&name)?;
+                    let role_id = tx.insert_user_role(&name, superuser)?;
                     state.add_to_audit_log(
                         session,
                         tx,
@@ -4360,6 +4861,7 @@ impl<S: Append> Catalog<S> {
                             id: role_id,
                             oid,
                             name,
+                            superuser,
                         },
                     )?;
                 }
@@ -4374,6 +4876,9 @@ impl<S: Append> Catalog<S> {
                     }
                     let id =
                         tx.insert_user_compute_instance(&name, &arranged_introspection_sources)?;
+                    let privileges =
+                        Privileges::new(state.session_role_id(session), SqlObjectType::Cluster);
+                    tx.set_privileges(ObjectId::Cluster(id), &privileges)?;
                     state.add_to_audit_log(
                         session,
                         tx,
@@ -4393,6 +4898,7 @@ impl<S: Append> Catalog<S> {
                             id,
                             name,
                             arranged_introspection_sources,
+                            privileges,
                         },
                     )?;
                 }
@@ -4457,6 +4963,14 @@ impl<S: Append> Catalog<S> {
                 } => {
                     state.ensure_no_unstable_uses(&item)?;
 
+                    let object_type = rbac::item_object_type(item.typ());
+                    let mut privileges =
+                        Privileges::new(state.session_role_id(session), object_type);
+                    // As in PostgreSQL, everyone may use a new type.
+                    if object_type == SqlObjectType::Type {
+                        privileges.grant(RoleId::Public, AclMode::USAGE);
+                    }
+
                     if let Some(id @ ComputeInstanceId::System(_)) = item.compute_instance_id() {
                         let compute_instance_name = state.compute_instances_by_id[&id].name.clone();
                         return Err(AdapterError::Catalog(Error::new(
@@ -4506,6 +5020,7 @@ impl<S: Append> Catalog<S> {
                         let schema_id = name.qualifiers.schema_spec.clone().into();
                         let serialized_item = Self::serialize_item(&item);
                         tx.insert_item(id, schema_id, &name.item, serialized_item)?;
+                        tx.set_privileges(ObjectId::Item(id), &privileges)?;
                     }
 
                     if Self::should_audit_log_item(&item) {
@@ -4552,12 +5067,14 @@ impl<S: Append> Catalog<S> {
                             oid,
                             name,
                             item,
+                            privileges,
                         },
                     )?;
                 }
                 Op::DropDatabase { id } => {
                     let database = &state.database_by_id[&id];
                     tx.remove_database(&id)?;
+                    tx.remove_privileges(ObjectId::Database(id))?;
                     builtin_table_updates.push(state.pack_database_update(database, -1));
                     state.add_to_audit_log(
                         session,
@@ -4579,6 +5096,10 @@ impl<S: Append> Catalog<S> {
                 } => {
                     let schema = &state.database_by_id[&database_id].schemas_by_id[&schema_id];
                     tx.remove_schema(&database_id, &schema_id)?;
+                    tx.remove_privileges(ObjectId::Schema((
+                        ResolvedDatabaseSpecifier::Id(database_id),
+                        SchemaSpecifier::Id(schema_id),
+                    )))?;
                     builtin_table_updates.push(state.pack_schema_update(
                         &ResolvedDatabaseSpecifier::Id(database_id.clone()),
                         &schema_id,
@@ -4612,8 +5133,17 @@ impl<S: Append> Catalog<S> {
                             ErrorKind::ReservedRoleName(name),
                         )));
                     }
-                    tx.remove_role(&name)?;
                     let role = &state.roles[&name];
+                    if state
+                        .privileges
+                        .values()
+                        .any(|privileges| privileges.references(role.id))
+                    {
+                        return Err(AdapterError::Catalog(Error::new(ErrorKind::RoleInUse(
+                            name,
+                        ))));
+                    }
+                    tx.remove_role(&name)?;
                     builtin_table_updates.push(state.pack_role_update(role, -1));
                     state.add_to_audit_log(
                         session,
@@ -4637,6 +5167,7 @@ impl<S: Append> Catalog<S> {
                     }
                     let (instance_id, introspection_source_index_ids) =
                         tx.remove_compute_instance(&name)?;
+                    tx.remove_privileges(ObjectId::Cluster(instance_id))?;
                     builtin_table_updates.push(state.pack_compute_instance_update(&name, -1));
                     for id in &introspection_source_index_ids {
                         builtin_table_updates.extend(state.pack_item_update(*id, -1));
@@ -4726,8 +5257,11 @@ impl<S: Append> Catalog<S> {
                     let entry = state.get_entry(&id);
                     if !entry.item().is_temporary() {
                         tx.remove_item(id)?;
+                        tx.remove_privileges(ObjectId::Item(id))?;
+                        tx.remove_relation_statistics(id)?;
                     }
                     builtin_table_updates.extend(state.pack_item_update(id, -1));
+                    builtin_table_updates.extend(state.pack_relation_statistics_update(id, -1));
                     if Self::should_audit_log_item(&entry.item) {
                         state.add_to_audit_log(
                             session,
@@ -4876,6 +5410,16 @@ impl<S: Append> Catalog<S> {
                         Action::UpdateComputeReplicaStatus { event },
                     )?;
                 }
+                Op::UpdateRelationStatistics { id, statistics } => {
+                    if !state.get_entry(&id).item().is_temporary() {
+                        tx.set_relation_statistics(id, &statistics)?;
+                    }
+                    catalog_action(
+                        state,
+                        builtin_table_updates,
+                        Action::UpdateRelationStatistics { id, statistics },
+                    )?;
+                }
                 Op::UpdateItem { id, name, to_item } => {
                     let ser = Self::serialize_item(&to_item);
                     tx.update_item(id, &name.item, &ser)?;
@@ -4890,6 +5434,43 @@ impl<S: Append> Catalog<S> {
                         },
                     )?;
                 }
+                Op::UpdateOwner { id, new_owner } => {
+                    let mut privileges = state.get_privileges(&id).into_owned();
+                    privileges.set_owner(new_owner);
+                    if !is_temporary_object(state, &id) {
+                        tx.set_privileges(id, &privileges)?;
+                    }
+                    builtin_table_updates.extend(state.pack_object_update(&id, -1));
+                    catalog_action(
+                        state,
+                        builtin_table_updates,
+                        Action::UpdatePrivileges { id, privileges },
+                    )?;
+                }
+                Op::UpdatePrivilege {
+                    target_id,
+                    grantee,
+                    acl_mode,
+                    variant,
+                } => {
+                    let mut privileges = state.get_privileges(&target_id).into_owned();
+                    match variant {
+                        UpdatePrivilegeVariant::Grant => privileges.grant(grantee, acl_mode),
+                        UpdatePrivilegeVariant::Revoke => privileges.revoke(grantee, acl_mode),
+                    }
+                    if !is_temporary_object(state, &target_id) {
+                        tx.set_privileges(target_id, &privileges)?;
+                    }
+                    builtin_table_updates.extend(state.pack_object_update(&target_id, -1));
+                    catalog_action(
+                        state,
+                        builtin_table_updates,
+                        Action::UpdatePrivileges {
+                            id: target_id,
+                            privileges,
+                        },
+                    )?;
+                }
                 Op::UpdateStorageUsage {
                     shard_id,
                     size_bytes,
@@ -4969,8 +5550,14 @@ impl<S: Append> Catalog<S> {
             action: Action,
         ) -> Result<(), AdapterError> {
             match action {
-                Action::CreateDatabase { id, oid, name } => {
+                Action::CreateDatabase {
+                    id,
+                    oid,
+                    name,
+                    privileges,
+                } => {
                     info!("create database {}", name);
+                    state.privileges.insert(ObjectId::Database(id), privileges);
                     state.database_by_id.insert(
                         id.clone(),
                         Database {
@@ -4991,12 +5578,20 @@ impl<S: Append> Catalog<S> {
                     oid,
                     database_id,
                     schema_name,
+                    privileges,
                 } => {
                     info!(
                         "create schema {}.{}",
                         state.get_database(&database_id).name,
                         schema_name
                     );
+                    state.privileges.insert(
+                        ObjectId::Schema((
+                            ResolvedDatabaseSpecifier::Id(database_id),
+                            SchemaSpecifier::Id(id),
+                        )),
+                        privileges,
+                    );
                     let db = state.database_by_id.get_mut(&database_id).unwrap();
                     db.schemas_by_id.insert(
                         id.clone(),
@@ -5019,7 +5614,12 @@ impl<S: Append> Catalog<S> {
                     ));
                 }
 
-                Action::CreateRole { id, oid, name } => {
+                Action::CreateRole {
+                    id,
+                    oid,
+                    name,
+                    superuser,
+                } => {
                     info!("create role {}", name);
                     state.roles.insert(
                         name.clone(),
@@ -5027,6 +5627,7 @@ impl<S: Append> Catalog<S> {
                             name: name.clone(),
                             id,
                             oid,
+                            superuser,
                         },
                     );
                     let role = &state.roles[&name];
@@ -5037,8 +5638,10 @@ impl<S: Append> Catalog<S> {
                     id,
                     name,
                     arranged_introspection_sources,
+                    privileges,
                 } => {
                     info!("create cluster {}", name);
+                    state.privileges.insert(ObjectId::Cluster(id), privileges);
                     let arranged_introspection_source_ids: Vec<GlobalId> =
                         arranged_introspection_sources
                             .iter()
@@ -5084,7 +5687,9 @@ impl<S: Append> Catalog<S> {
                     oid,
                     name,
                     item,
+                    privileges,
                 } => {
+                    state.privileges.insert(ObjectId::Item(id), privileges);
                     state.insert_item(id, oid, name, item);
                     builtin_table_updates.extend(state.pack_item_update(id, 1));
                 }
@@ -5093,6 +5698,7 @@ impl<S: Append> Catalog<S> {
                     let db = state.database_by_id.get(&id).unwrap();
                     state.database_by_name.remove(db.name());
                     state.database_by_id.remove(&id);
+                    state.privileges.remove(&ObjectId::Database(id));
                 }
 
                 Action::DropSchema {
@@ -5103,6 +5709,10 @@ impl<S: Append> Catalog<S> {
                     let schema = db.schemas_by_id.get(&schema_id).unwrap();
                     db.schemas_by_name.remove(&schema.name.schema);
                     db.schemas_by_id.remove(&schema_id);
+                    state.privileges.remove(&ObjectId::Schema((
+                        ResolvedDatabaseSpecifier::Id(database_id),
+                        SchemaSpecifier::Id(schema_id),
+                    )));
                 }
 
                 Action::DropRole { name } => {
@@ -5133,6 +5743,7 @@ impl<S: Append> Catalog<S> {
                         instance.exports.is_empty() && instance.replicas_by_id.is_empty(),
                         "not all items dropped before compute instance"
                     );
+                    state.privileges.remove(&ObjectId::Cluster(id));
                 }
 
                 Action::DropComputeReplica { name, compute_id } => {
@@ -5153,6 +5764,8 @@ impl<S: Append> Catalog<S> {
 
                 Action::DropItem(id) => {
                     state.drop_item(id);
+                    state.privileges.remove(&ObjectId::Item(id));
+                    state.relation_statistics.remove(&id);
                 }
 
                 Action::UpdateItem {
@@ -5183,6 +5796,12 @@ impl<S: Append> Catalog<S> {
                     builtin_table_updates.extend(state.pack_item_update(id, 1));
                 }
 
+                Action::UpdatePrivileges { id, privileges } => {
+                    info!("update privileges of {} {}", state.get_object_type(&id), id);
+                    state.privileges.insert(id, privileges);
+                    builtin_table_updates.extend(state.pack_object_update(&id, 1));
+                }
+
                 Action::UpdateComputeReplicaStatus { event } => {
                     builtin_table_updates.push(state.pack_compute_replica_status_update(
                         event.instance_id,
@@ -5206,6 +5825,11 @@ impl<S: Append> Catalog<S> {
                         1,
                     ));
                 }
+                Action::UpdateRelationStatistics { id, statistics } => {
+                    builtin_table_updates.extend(state.pack_relation_statistics_update(id, -1));
+                    state.relation_statistics.insert(id, statistics);
+                    builtin_table_updates.extend(state.pack_relation_statistics_update(id, 1));
+                }
                 Action::UpdateSystemConfiguration { name, value } => {
                     state.insert_system_configuration(&name, &value)?;
                 }
@@ -5298,119 +5922,7 @@ impl<S: Append> Catalog<S> {
         create_sql: String,
         pcx: Option<&PlanContext>,
     ) -> Result<CatalogItem, anyhow::Error> {
-        let session_catalog = self.for_system_session();
-        let stmt = mz_sql::parse::parse(&create_sql)?.into_element();
-        let (stmt, depends_on) = mz_sql::names::resolve(&session_catalog, stmt)?;
-        let depends_on = depends_on.into_iter().collect();
-        let plan = mz_sql::plan::plan(pcx, &session_catalog, stmt, &Params::empty())?;
-        Ok(match plan {
-            Plan::CreateTable(CreateTablePlan { table, .. }) => CatalogItem::Table(Table {
-                create_sql: table.create_sql,
-                desc: table.desc,
-                defaults: table.defaults,
-                conn_id: None,
-                depends_on,
-                custom_logical_compaction_window: None,
-                is_retained_metrics_relation: false,
-            }),
-            Plan::CreateSource(CreateSourcePlan {
-                source,
-                timeline,
-                host_config,
-                ..
-            }) => {
-                let allow_undefined_size = true;
-                CatalogItem::Source(Source {
-                    create_sql: source.create_sql,
-                    data_source: match source.ingestion {
-                        Some(ingestion) => DataSourceDesc::Ingestion(Ingestion {
-                            desc: ingestion.desc,
-                            source_imports: ingestion.source_imports,
-                            subsource_exports: ingestion.subsource_exports,
-                            host_config: self
-                                .resolve_storage_host_config(host_config, allow_undefined_size)?,
-                        }),
-                        None => DataSourceDesc::Source,
-                    },
-                    desc: source.desc,
-                    timeline,
-                    depends_on,
-                    custom_logical_compaction_window: None,
-                    is_retained_metrics_relation: false,
-                })
-            }
-            Plan::CreateView(CreateViewPlan { view, .. }) => {
-                let optimizer = Optimizer::logical_optimizer();
-                let optimized_expr = optimizer.optimize(view.expr)?;
-                let desc = RelationDesc::new(optimized_expr.typ(), view.column_names);
-                CatalogItem::View(View {
-                    create_sql: view.create_sql,
-                    optimized_expr,
-                    desc,
-                    conn_id: None,
-                    depends_on,
-                })
-            }
-            Plan::CreateMaterializedView(CreateMaterializedViewPlan {
-                materialized_view, ..
-            }) => {
-                let optimizer = Optimizer::logical_optimizer();
-                let optimized_expr = optimizer.optimize(materialized_view.expr)?;
-                let desc = RelationDesc::new(optimized_expr.typ(), materialized_view.column_names);
-                CatalogItem::MaterializedView(MaterializedView {
-                    create_sql: materialized_view.create_sql,
-                    optimized_expr,
-                    desc,
-                    depends_on,
-                    compute_instance: materialized_view.compute_instance,
-                })
-            }
-            Plan::CreateIndex(CreateIndexPlan { index, .. }) => CatalogItem::Index(Index {
-                create_sql: index.create_sql,
-                on: index.on,
-                keys: index.keys,
-                conn_id: None,
-                depends_on,
-                compute_instance: index.compute_instance,
-            }),
-            Plan::CreateSink(CreateSinkPlan {
-                sink,
-                with_snapshot,
-                host_config,
-                ..
-            }) => {
-                let allow_undefined_size = true;
-                CatalogItem::Sink(Sink {
-                    create_sql: sink.create_sql,
-                    from: sink.from,
-                    connection: StorageSinkConnectionState::Pending(sink.connection_builder),
-                    envelope: sink.envelope,
-                    with_snapshot,
-                    depends_on,
-                    host_config: self
-                        .resolve_storage_host_config(host_config, allow_undefined_size)?,
-                })
-            }
-            Plan::CreateType(CreateTypePlan { typ, .. }) => CatalogItem::Type(Type {
-                create_sql: typ.create_sql,
-                details: CatalogTypeDetails {
-                    array_id: None,
-                    typ: typ.inner,
-                },
-                depends_on,
-            }),
-            Plan::CreateSecret(CreateSecretPlan { secret, .. }) => CatalogItem::Secret(Secret {
-                create_sql: secret.create_sql,
-            }),
-            Plan::CreateConnection(CreateConnectionPlan { connection, .. }) => {
-                CatalogItem::Connection(Connection {
-                    create_sql: connection.create_sql,
-                    connection: connection.connection,
-                    depends_on,
-                })
-            }
-            _ => bail!("catalog entry generated inappropriate plan"),
-        })
+        self.state.parse_item(create_sql, pcx, None)
     }
 
     pub fn uses_tables(&self, id: GlobalId) -> bool {
@@ -5634,6 +6146,10 @@ pub enum Op {
         size: AlterOptionParameter,
         remote: AlterOptionParameter,
     },
+    AlterTableAddColumn {
+        id: GlobalId,
+        create_sql: String,
+    },
     CreateDatabase {
         name: String,
         oid: u32,
@@ -5647,6 +6163,7 @@ pub enum Op {
     CreateRole {
         name: String,
         oid: u32,
+        superuser: bool,
     },
     CreateComputeInstance {
         name: String,
@@ -5693,11 +6210,26 @@ pub enum Op {
     UpdateComputeReplicaStatus {
         event: ComputeInstanceEvent,
     },
+    /// Replaces the statistics collected about the identified relation.
+    UpdateRelationStatistics {
+        id: GlobalId,
+        statistics: RelationStatistics,
+    },
     UpdateItem {
         id: GlobalId,
         name: QualifiedObjectName,
         to_item: CatalogItem,
     },
+    UpdateOwner {
+        id: ObjectId,
+        new_owner: RoleId,
+    },
+    UpdatePrivilege {
+        target_id: ObjectId,
+        grantee: RoleId,
+        acl_mode: AclMode,
+        variant: UpdatePrivilegeVariant,
+    },
     UpdateStorageUsage {
         shard_id: Option<String>,
         size_bytes: u64,
@@ -5718,6 +6250,12 @@ pub enum Op {
     },
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdatePrivilegeVariant {
+    Grant,
+    Revoke,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 pub enum SerializedCatalogItem {
     V1 { create_sql: String },
@@ -5954,6 +6492,21 @@ impl ExprHumanizer for ConnCatalog<'_> {
     }
 }
 
+impl mz_transform::StatisticsOracle for CatalogState {
+    fn cardinality_estimate(&self, id: GlobalId) -> Option<usize> {
+        self.relation_statistics
+            .get(&id)
+            .map(|statistics| usize::cast_from(statistics.row_count))
+    }
+
+    fn distinct_estimate(&self, id: GlobalId, column: usize) -> Option<usize> {
+        self.relation_statistics
+            .get(&id)
+            .and_then(|statistics| statistics.distinct_counts.get(column))
+            .map(|distinct_count| usize::cast_from(*distinct_count))
+    }
+}
+
 impl SessionCatalog for ConnCatalog<'_> {
     fn active_user(&self) -> &str {
         &self.user.name
@@ -6104,6 +6657,10 @@ impl SessionCatalog for ConnCatalog<'_> {
         self.conn_id == SYSTEM_CONN_ID || self.state.system_config().window_functions()
     }
 
+    fn timezone(&self) -> Timezone {
+        self.timezone
+    }
+
     fn now(&self) -> EpochMillis {
         (self.state.config().now)()
     }
@@ -6252,13 +6809,19 @@ impl mz_sql::catalog::CatalogItem for CatalogEntry {
         self.used_by()
     }
 
+    fn is_webhook_source(&self) -> bool {
+        self.is_webhook_source()
+    }
+
     fn subsources(&self) -> Vec<GlobalId> {
         match &self.item {
             CatalogItem::Source(source) => match &source.data_source {
                 DataSourceDesc::Ingestion(ingestion) => {
                     ingestion.subsource_exports.keys().copied().collect()
                 }
-                DataSourceDesc::Source | DataSourceDesc::Introspection(_) => vec![],
+                DataSourceDesc::Source
+                | DataSourceDesc::Introspection(_)
+                | DataSourceDesc::Webhook(_) => vec![],
             },
             CatalogItem::Table(_)
             | CatalogItem::Log(_)
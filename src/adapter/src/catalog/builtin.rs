@@ -1015,6 +1015,160 @@ pub const TYPE_MZ_TIMESTAMP_ARRAY: BuiltinType<NameReference> = BuiltinType {
     },
 };
 
+pub const TYPE_ANYRANGE: BuiltinType<NameReference> = BuiltinType {
+    name: "anyrange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_ANYRANGE_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Pseudo,
+        array_id: None,
+    },
+};
+
+pub const TYPE_INT4RANGE: BuiltinType<NameReference> = BuiltinType {
+    name: "int4range",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_INT4RANGE_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Range {
+            element_reference: TYPE_INT4.name,
+        },
+        array_id: None,
+    },
+};
+
+pub const TYPE_INT4RANGE_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_int4range",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_INT4RANGE_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_INT4RANGE.name,
+        },
+        array_id: None,
+    },
+};
+
+pub const TYPE_INT8RANGE: BuiltinType<NameReference> = BuiltinType {
+    name: "int8range",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_INT8RANGE_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Range {
+            element_reference: TYPE_INT8.name,
+        },
+        array_id: None,
+    },
+};
+
+pub const TYPE_INT8RANGE_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_int8range",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_INT8RANGE_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_INT8RANGE.name,
+        },
+        array_id: None,
+    },
+};
+
+pub const TYPE_DATERANGE: BuiltinType<NameReference> = BuiltinType {
+    name: "daterange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_DATERANGE_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Range {
+            element_reference: TYPE_DATE.name,
+        },
+        array_id: None,
+    },
+};
+
+pub const TYPE_DATERANGE_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_daterange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_DATERANGE_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_DATERANGE.name,
+        },
+        array_id: None,
+    },
+};
+
+pub const TYPE_NUMRANGE: BuiltinType<NameReference> = BuiltinType {
+    name: "numrange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_NUMRANGE_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Range {
+            element_reference: TYPE_NUMERIC.name,
+        },
+        array_id: None,
+    },
+};
+
+pub const TYPE_NUMRANGE_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_numrange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_NUMRANGE_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_NUMRANGE.name,
+        },
+        array_id: None,
+    },
+};
+
+pub const TYPE_TSRANGE: BuiltinType<NameReference> = BuiltinType {
+    name: "tsrange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_TSRANGE_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Range {
+            element_reference: TYPE_TIMESTAMP.name,
+        },
+        array_id: None,
+    },
+};
+
+pub const TYPE_TSRANGE_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_tsrange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_TSRANGE_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_TSRANGE.name,
+        },
+        array_id: None,
+    },
+};
+
+pub const TYPE_TSTZRANGE: BuiltinType<NameReference> = BuiltinType {
+    name: "tstzrange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_TSTZRANGE_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Range {
+            element_reference: TYPE_TIMESTAMPTZ.name,
+        },
+        array_id: None,
+    },
+};
+
+pub const TYPE_TSTZRANGE_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_tstzrange",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_TSTZRANGE_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_TSTZRANGE.name,
+        },
+        array_id: None,
+    },
+};
+
 pub const MZ_DATAFLOW_OPERATORS: BuiltinLog = BuiltinLog {
     name: "mz_dataflow_operators",
     schema: MZ_INTERNAL_SCHEMA,
@@ -1105,6 +1259,12 @@ pub const MZ_RAW_PEEK_DURATIONS: BuiltinLog = BuiltinLog {
     variant: LogVariant::Compute(ComputeLog::PeekDuration),
 };
 
+pub const MZ_LIR_MAPPING: BuiltinLog = BuiltinLog {
+    name: "mz_lir_mapping",
+    schema: MZ_INTERNAL_SCHEMA,
+    variant: LogVariant::Compute(ComputeLog::LirMapping),
+};
+
 pub const MZ_MESSAGE_COUNTS_RECEIVED_INTERNAL: BuiltinLog = BuiltinLog {
     name: "mz_message_counts_received_internal",
     schema: MZ_INTERNAL_SCHEMA,
@@ -1179,7 +1339,12 @@ pub static MZ_DATABASES: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
     desc: RelationDesc::empty()
         .with_column("id", ScalarType::UInt64.nullable(false))
         .with_column("oid", ScalarType::Oid.nullable(false))
-        .with_column("name", ScalarType::String.nullable(false)),
+        .with_column("name", ScalarType::String.nullable(false))
+        .with_column("owner_id", ScalarType::String.nullable(false))
+        .with_column(
+            "privileges",
+            ScalarType::Array(Box::new(ScalarType::String)).nullable(false),
+        ),
     is_retained_metrics_relation: false,
 });
 pub static MZ_SCHEMAS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
@@ -1189,7 +1354,12 @@ pub static MZ_SCHEMAS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
         .with_column("id", ScalarType::UInt64.nullable(false))
         .with_column("oid", ScalarType::Oid.nullable(false))
         .with_column("database_id", ScalarType::UInt64.nullable(true))
-        .with_column("name", ScalarType::String.nullable(false)),
+        .with_column("name", ScalarType::String.nullable(false))
+        .with_column("owner_id", ScalarType::String.nullable(false))
+        .with_column(
+            "privileges",
+            ScalarType::Array(Box::new(ScalarType::String)).nullable(false),
+        ),
     is_retained_metrics_relation: false,
 });
 pub static MZ_COLUMNS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
@@ -1234,7 +1404,12 @@ pub static MZ_TABLES: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
         .with_column("id", ScalarType::String.nullable(false))
         .with_column("oid", ScalarType::Oid.nullable(false))
         .with_column("schema_id", ScalarType::UInt64.nullable(false))
-        .with_column("name", ScalarType::String.nullable(false)),
+        .with_column("name", ScalarType::String.nullable(false))
+        .with_column("owner_id", ScalarType::String.nullable(false))
+        .with_column(
+            "privileges",
+            ScalarType::Array(Box::new(ScalarType::String)).nullable(false),
+        ),
     is_retained_metrics_relation: false,
 });
 pub static MZ_CONNECTIONS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
@@ -1245,7 +1420,12 @@ pub static MZ_CONNECTIONS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
         .with_column("oid", ScalarType::Oid.nullable(false))
         .with_column("schema_id", ScalarType::UInt64.nullable(false))
         .with_column("name", ScalarType::String.nullable(false))
-        .with_column("type", ScalarType::String.nullable(false)),
+        .with_column("type", ScalarType::String.nullable(false))
+        .with_column("owner_id", ScalarType::String.nullable(false))
+        .with_column(
+            "privileges",
+            ScalarType::Array(Box::new(ScalarType::String)).nullable(false),
+        ),
     is_retained_metrics_relation: false,
 });
 pub static MZ_SSH_TUNNEL_CONNECTIONS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
@@ -1268,7 +1448,12 @@ pub static MZ_SOURCES: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
         .with_column("type", ScalarType::String.nullable(false))
         .with_column("connection_id", ScalarType::String.nullable(true))
         .with_column("size", ScalarType::String.nullable(true))
-        .with_column("envelope_type", ScalarType::String.nullable(true)),
+        .with_column("envelope_type", ScalarType::String.nullable(true))
+        .with_column("owner_id", ScalarType::String.nullable(false))
+        .with_column(
+            "privileges",
+            ScalarType::Array(Box::new(ScalarType::String)).nullable(false),
+        ),
     is_retained_metrics_relation: true,
 });
 pub static MZ_SINKS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
@@ -1292,7 +1477,12 @@ pub static MZ_VIEWS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
         .with_column("oid", ScalarType::Oid.nullable(false))
         .with_column("schema_id", ScalarType::UInt64.nullable(false))
         .with_column("name", ScalarType::String.nullable(false))
-        .with_column("definition", ScalarType::String.nullable(false)),
+        .with_column("definition", ScalarType::String.nullable(false))
+        .with_column("owner_id", ScalarType::String.nullable(false))
+        .with_column(
+            "privileges",
+            ScalarType::Array(Box::new(ScalarType::String)).nullable(false),
+        ),
     is_retained_metrics_relation: false,
 });
 pub static MZ_MATERIALIZED_VIEWS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
@@ -1304,7 +1494,12 @@ pub static MZ_MATERIALIZED_VIEWS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable
         .with_column("schema_id", ScalarType::UInt64.nullable(false))
         .with_column("name", ScalarType::String.nullable(false))
         .with_column("cluster_id", ScalarType::String.nullable(false))
-        .with_column("definition", ScalarType::String.nullable(false)),
+        .with_column("definition", ScalarType::String.nullable(false))
+        .with_column("owner_id", ScalarType::String.nullable(false))
+        .with_column(
+            "privileges",
+            ScalarType::Array(Box::new(ScalarType::String)).nullable(false),
+        ),
     is_retained_metrics_relation: false,
 });
 pub static MZ_TYPES: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
@@ -1315,7 +1510,12 @@ pub static MZ_TYPES: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
         .with_column("oid", ScalarType::Oid.nullable(false))
         .with_column("schema_id", ScalarType::UInt64.nullable(false))
         .with_column("name", ScalarType::String.nullable(false))
-        .with_column("category", ScalarType::String.nullable(false)),
+        .with_column("category", ScalarType::String.nullable(false))
+        .with_column("owner_id", ScalarType::String.nullable(false))
+        .with_column(
+            "privileges",
+            ScalarType::Array(Box::new(ScalarType::String)).nullable(false),
+        ),
     is_retained_metrics_relation: false,
 });
 pub static MZ_ARRAY_TYPES: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
@@ -1355,7 +1555,8 @@ pub static MZ_ROLES: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
     desc: RelationDesc::empty()
         .with_column("id", ScalarType::String.nullable(false))
         .with_column("oid", ScalarType::Oid.nullable(false))
-        .with_column("name", ScalarType::String.nullable(false)),
+        .with_column("name", ScalarType::String.nullable(false))
+        .with_column("superuser", ScalarType::Bool.nullable(false)),
     is_retained_metrics_relation: false,
 });
 pub static MZ_PSEUDO_TYPES: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
@@ -1389,7 +1590,12 @@ pub static MZ_CLUSTERS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
     schema: MZ_CATALOG_SCHEMA,
     desc: RelationDesc::empty()
         .with_column("id", ScalarType::String.nullable(false))
-        .with_column("name", ScalarType::String.nullable(false)),
+        .with_column("name", ScalarType::String.nullable(false))
+        .with_column("owner_id", ScalarType::String.nullable(false))
+        .with_column(
+            "privileges",
+            ScalarType::Array(Box::new(ScalarType::String)).nullable(false),
+        ),
     is_retained_metrics_relation: false,
 });
 pub static MZ_SECRETS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
@@ -1398,7 +1604,12 @@ pub static MZ_SECRETS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
     desc: RelationDesc::empty()
         .with_column("id", ScalarType::String.nullable(false))
         .with_column("schema_id", ScalarType::UInt64.nullable(false))
-        .with_column("name", ScalarType::String.nullable(false)),
+        .with_column("name", ScalarType::String.nullable(false))
+        .with_column("owner_id", ScalarType::String.nullable(false))
+        .with_column(
+            "privileges",
+            ScalarType::Array(Box::new(ScalarType::String)).nullable(false),
+        ),
     is_retained_metrics_relation: false,
 });
 pub static MZ_CLUSTER_REPLICAS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
@@ -1641,6 +1852,25 @@ pub static MZ_STORAGE_USAGE_BY_SHARD: Lazy<BuiltinTable> = Lazy::new(|| BuiltinT
     is_retained_metrics_relation: false,
 });
 
+pub static MZ_RELATION_STATISTICS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
+    name: "mz_relation_statistics",
+    schema: MZ_INTERNAL_SCHEMA,
+    desc: RelationDesc::empty()
+        .with_column("id", ScalarType::String.nullable(false))
+        .with_column("row_count", ScalarType::UInt64.nullable(false)),
+    is_retained_metrics_relation: false,
+});
+
+pub static MZ_COLUMN_STATISTICS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
+    name: "mz_column_statistics",
+    schema: MZ_INTERNAL_SCHEMA,
+    desc: RelationDesc::empty()
+        .with_column("id", ScalarType::String.nullable(false))
+        .with_column("position", ScalarType::UInt64.nullable(false))
+        .with_column("distinct_count", ScalarType::UInt64.nullable(false)),
+    is_retained_metrics_relation: false,
+});
+
 pub static MZ_EGRESS_IPS: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
     name: "mz_egress_ips",
     schema: MZ_CATALOG_SCHEMA,
@@ -1725,11 +1955,11 @@ GROUP BY object_id, collection_timestamp",
 pub const MZ_RELATIONS: BuiltinView = BuiltinView {
     name: "mz_relations",
     schema: MZ_CATALOG_SCHEMA,
-    sql: "CREATE VIEW mz_catalog.mz_relations (id, oid, schema_id, name, type) AS
-      SELECT id, oid, schema_id, name, 'table' FROM mz_catalog.mz_tables
-UNION ALL SELECT id, oid, schema_id, name, 'source' FROM mz_catalog.mz_sources
-UNION ALL SELECT id, oid, schema_id, name, 'view' FROM mz_catalog.mz_views
-UNION ALL SELECT id, oid, schema_id, name, 'materialized-view' FROM mz_catalog.mz_materialized_views",
+    sql: "CREATE VIEW mz_catalog.mz_relations (id, oid, schema_id, name, type, owner_id, privileges) AS
+      SELECT id, oid, schema_id, name, 'table', owner_id, privileges FROM mz_catalog.mz_tables
+UNION ALL SELECT id, oid, schema_id, name, 'source', owner_id, privileges FROM mz_catalog.mz_sources
+UNION ALL SELECT id, oid, schema_id, name, 'view', owner_id, privileges FROM mz_catalog.mz_views
+UNION ALL SELECT id, oid, schema_id, name, 'materialized-view', owner_id, privileges FROM mz_catalog.mz_materialized_views",
 };
 
 pub const MZ_OBJECTS: BuiltinView = BuiltinView {
@@ -1876,10 +2106,11 @@ pub const PG_NAMESPACE: BuiltinView = BuiltinView {
     sql: "CREATE VIEW pg_catalog.pg_namespace AS SELECT
 s.oid AS oid,
 s.name AS nspname,
-NULL::pg_catalog.oid AS nspowner,
-NULL::pg_catalog.text[] AS nspacl
+role_owner.oid AS nspowner,
+s.privileges AS nspacl
 FROM mz_catalog.mz_schemas s
 LEFT JOIN mz_catalog.mz_databases d ON d.id = s.database_id
+JOIN mz_catalog.mz_roles role_owner ON role_owner.id = s.owner_id
 WHERE s.database_id IS NULL OR d.name = pg_catalog.current_database()",
 };
 
@@ -1892,7 +2123,7 @@ pub const PG_CLASS: BuiltinView = BuiltinView {
     mz_schemas.oid AS relnamespace,
     -- MZ doesn't support typed tables so reloftype is filled with 0
     0::pg_catalog.oid AS reloftype,
-    NULL::pg_catalog.oid AS relowner,
+    role_owner.oid AS relowner,
     0::pg_catalog.oid AS relam,
     -- MZ doesn't have tablespaces so reltablespace is filled in with 0 implying the default tablespace
     0::pg_catalog.oid AS reltablespace,
@@ -1926,16 +2157,21 @@ pub const PG_CLASS: BuiltinView = BuiltinView {
     -- PG removed relhasoids in v12 so it's filled with false
     false AS relhasoids,
     -- MZ doesn't support options for relations
-    NULL::pg_catalog.text[] as reloptions
+    NULL::pg_catalog.text[] as reloptions,
+    class_objects.privileges AS relacl
 FROM (
     -- pg_class catalogs relations and indexes
-    SELECT id, oid, schema_id, name, type FROM mz_catalog.mz_relations
+    SELECT id, oid, schema_id, name, type, owner_id, privileges FROM mz_catalog.mz_relations
     UNION ALL
-        SELECT mz_indexes.id, mz_indexes.oid, mz_relations.schema_id, mz_indexes.name, 'index' AS type
+        -- Indexes are owned by the owner of the relation they index and have
+        -- no privileges of their own
+        SELECT mz_indexes.id, mz_indexes.oid, mz_relations.schema_id, mz_indexes.name, 'index' AS type,
+            mz_relations.owner_id, NULL::pg_catalog.text[] AS privileges
         FROM mz_catalog.mz_indexes
         JOIN mz_catalog.mz_relations ON mz_indexes.on_id = mz_relations.id
 ) AS class_objects
 JOIN mz_catalog.mz_schemas ON mz_schemas.id = class_objects.schema_id
+JOIN mz_catalog.mz_roles role_owner ON role_owner.id = class_objects.owner_id
 LEFT JOIN mz_catalog.mz_databases d ON d.id = mz_schemas.database_id
 WHERE mz_schemas.database_id IS NULL OR d.name = pg_catalog.current_database()",
 };
@@ -1946,12 +2182,13 @@ pub const PG_DATABASE: BuiltinView = BuiltinView {
     sql: "CREATE VIEW pg_catalog.pg_database AS SELECT
     oid,
     name as datname,
-    NULL::pg_catalog.oid AS datdba,
+    role_owner.oid AS datdba,
     6 as encoding,
     'C' as datcollate,
     'C' as datctype,
-    NULL::pg_catalog.text[] as datacl
-FROM mz_catalog.mz_databases d",
+    privileges as datacl
+FROM mz_catalog.mz_databases d
+JOIN mz_catalog.mz_roles role_owner ON role_owner.id = d.owner_id",
 };
 
 pub const PG_INDEX: BuiltinView = BuiltinView {
@@ -2009,8 +2246,13 @@ pub const PG_TYPE: BuiltinView = BuiltinView {
     mz_schemas.oid AS typnamespace,
     NULL::pg_catalog.int2 AS typlen,
     -- 'a' is used internally to denote an array type, but in postgres they show up
-    -- as 'b'.
-    (CASE mztype WHEN 'a' THEN 'b' ELSE mztype END)::pg_catalog.char AS typtype,
+    -- as 'b'. Range types are stored as base types, but in postgres they show up
+    -- as 'r'.
+    (CASE
+        WHEN mztype = 'a' THEN 'b'
+        WHEN category = 'range' THEN 'r'
+        ELSE mztype
+    END)::pg_catalog.char AS typtype,
     (CASE category
         WHEN 'array' THEN 'A'
         WHEN 'bit-string' THEN 'V'
@@ -2023,6 +2265,7 @@ pub const PG_TYPE: BuiltinView = BuiltinView {
         WHEN 'network-address' THEN 'I'
         WHEN 'numeric' THEN 'N'
         WHEN 'pseudo' THEN 'P'
+        WHEN 'range' THEN 'R'
         WHEN 'string' THEN 'S'
         WHEN 'timespan' THEN 'T'
         WHEN 'user-defined' THEN 'U'
@@ -2574,16 +2817,13 @@ pub const PG_AUTHID: BuiltinView = BuiltinView {
 AS SELECT
     r.oid AS oid,
     r.name AS rolname,
-    CASE
-        WHEN r.name = 'mz_system' THEN true
-        ELSE false
-    END AS rolsuper,
+    r.superuser AS rolsuper,
     -- MZ doesn't have role inheritence
     false AS rolinherit,
-    -- All roles can create other roles
-    true AS rolcreaterole,
-    -- All roles can create other dbs
-    true AS rolcreatedb,
+    -- Only superusers can create other roles
+    r.superuser AS rolcreaterole,
+    -- Only superusers can create other dbs
+    r.superuser AS rolcreatedb,
     -- All roles can login
     true AS rolcanlogin,
     -- MZ doesn't support replication in the same way Postgres does
@@ -2890,6 +3130,19 @@ pub static BUILTINS_STATIC: Lazy<Vec<Builtin<NameReference>>> = Lazy::new(|| {
         Builtin::Type(&TYPE_UINT8_ARRAY),
         Builtin::Type(&TYPE_MZ_TIMESTAMP),
         Builtin::Type(&TYPE_MZ_TIMESTAMP_ARRAY),
+        Builtin::Type(&TYPE_ANYRANGE),
+        Builtin::Type(&TYPE_INT4RANGE),
+        Builtin::Type(&TYPE_INT4RANGE_ARRAY),
+        Builtin::Type(&TYPE_INT8RANGE),
+        Builtin::Type(&TYPE_INT8RANGE_ARRAY),
+        Builtin::Type(&TYPE_DATERANGE),
+        Builtin::Type(&TYPE_DATERANGE_ARRAY),
+        Builtin::Type(&TYPE_NUMRANGE),
+        Builtin::Type(&TYPE_NUMRANGE_ARRAY),
+        Builtin::Type(&TYPE_TSRANGE),
+        Builtin::Type(&TYPE_TSRANGE_ARRAY),
+        Builtin::Type(&TYPE_TSTZRANGE),
+        Builtin::Type(&TYPE_TSTZRANGE_ARRAY),
     ];
     for (schema, funcs) in &[
         (PG_CATALOG_SCHEMA, &*mz_sql::func::PG_CATALOG_BUILTINS),
@@ -2928,6 +3181,7 @@ pub static BUILTINS_STATIC: Lazy<Vec<Builtin<NameReference>>> = Lazy::new(|| {
         Builtin::Log(&MZ_WORKER_COMPUTE_FRONTIERS),
         Builtin::Log(&MZ_WORKER_COMPUTE_IMPORT_FRONTIERS),
         Builtin::Log(&MZ_RAW_WORKER_COMPUTE_DELAYS),
+        Builtin::Log(&MZ_LIR_MAPPING),
         Builtin::Table(&MZ_VIEW_KEYS),
         Builtin::Table(&MZ_VIEW_FOREIGN_KEYS),
         Builtin::Table(&MZ_KAFKA_SINKS),
@@ -2964,6 +3218,8 @@ pub static BUILTINS_STATIC: Lazy<Vec<Builtin<NameReference>>> = Lazy::new(|| {
         Builtin::Table(&MZ_STORAGE_USAGE_BY_SHARD),
         Builtin::Table(&MZ_EGRESS_IPS),
         Builtin::Table(&MZ_AWS_PRIVATELINK_CONNECTIONS),
+        Builtin::Table(&MZ_RELATION_STATISTICS),
+        Builtin::Table(&MZ_COLUMN_STATISTICS),
         Builtin::View(&MZ_RELATIONS),
         Builtin::View(&MZ_OBJECTS),
         Builtin::View(&MZ_ARRANGEMENT_SHARING),
@@ -3279,6 +3535,13 @@ mod tests {
                                 ty.name
                             )
                         }
+                        CatalogType::Range { .. } => {
+                            assert_eq!(
+                                pg_ty.ty, "r",
+                                "type {} is not a range type as expected",
+                                ty.name
+                            )
+                        }
                         _ => {
                             assert_eq!(
                                 pg_ty.ty, "b",
@@ -3449,7 +3712,8 @@ mod tests {
                     | ScalarType::RegProc
                     | ScalarType::RegType
                     | ScalarType::RegClass
-                    | ScalarType::Int2Vector => {}
+                    | ScalarType::Int2Vector
+                    | ScalarType::Range { .. } => {}
                 }
             }
         }
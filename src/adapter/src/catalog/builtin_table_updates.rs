@@ -26,7 +26,7 @@ use mz_repr::adt::jsonb::Jsonb;
 use mz_repr::{Datum, Diff, GlobalId, Row};
 use mz_sql::ast::{CreateIndexStatement, Statement};
 use mz_sql::catalog::{CatalogDatabase, CatalogType, TypeCategory};
-use mz_sql::names::{ResolvedDatabaseSpecifier, SchemaId, SchemaSpecifier};
+use mz_sql::names::{ObjectId, ResolvedDatabaseSpecifier, SchemaId, SchemaSpecifier};
 use mz_sql_parser::ast::display::AstDisplay;
 use mz_storage_client::types::connections::KafkaConnection;
 use mz_storage_client::types::hosts::{StorageHostConfig, StorageHostResourceAllocation};
@@ -47,7 +47,8 @@ use crate::catalog::{
 };
 
 use super::builtin::{
-    MZ_AWS_PRIVATELINK_CONNECTIONS, MZ_CLUSTER_REPLICA_SIZES, MZ_STORAGE_HOST_SIZES,
+    MZ_AWS_PRIVATELINK_CONNECTIONS, MZ_CLUSTER_REPLICA_SIZES, MZ_COLUMN_STATISTICS,
+    MZ_RELATION_STATISTICS, MZ_STORAGE_HOST_SIZES,
 };
 use super::{AwsPrincipalContext, DataSourceDesc, Ingestion};
 
@@ -63,18 +64,66 @@ pub struct BuiltinTableUpdate {
 }
 
 impl CatalogState {
+    /// Packs the `owner_id` and `privileges` columns that describe the owner
+    /// and access control list of the object identified by `id`.
+    fn pack_privileges(&self, id: &ObjectId) -> Row {
+        let privileges = self.get_privileges(id);
+        let acl: Vec<_> = privileges
+            .acl
+            .iter()
+            .map(|item| item.to_aclitem_string(|role_id| self.get_role_name(role_id)))
+            .collect();
+        let mut row = Row::default();
+        let mut packer = row.packer();
+        packer.push(Datum::String(&privileges.owner_id.to_string()));
+        packer
+            .push_array(
+                &[ArrayDimension {
+                    lower_bound: 1,
+                    length: acl.len(),
+                }],
+                acl.iter().map(|item| Datum::String(item)),
+            )
+            .unwrap();
+        row
+    }
+
+    /// Packs the updates to the catalog table that describes the object
+    /// identified by `id`.
+    pub(super) fn pack_object_update(&self, id: &ObjectId, diff: Diff) -> Vec<BuiltinTableUpdate> {
+        match id {
+            ObjectId::Cluster(id) => {
+                let name = &self.compute_instances_by_id[id].name;
+                vec![self.pack_compute_instance_update(name, diff)]
+            }
+            ObjectId::Database(id) => {
+                vec![self.pack_database_update(&self.database_by_id[id], diff)]
+            }
+            ObjectId::Schema((database_spec, SchemaSpecifier::Id(schema_id))) => {
+                vec![self.pack_schema_update(database_spec, schema_id, diff)]
+            }
+            ObjectId::Schema((_, SchemaSpecifier::Temporary)) => vec![],
+            ObjectId::Item(id) => self.pack_item_update(*id, diff),
+        }
+    }
+
     pub(super) fn pack_database_update(
         &self,
         database: &Database,
         diff: Diff,
     ) -> BuiltinTableUpdate {
+        let privileges = self.pack_privileges(&ObjectId::Database(database.id));
         BuiltinTableUpdate {
             id: self.resolve_builtin_table(&MZ_DATABASES),
-            row: Row::pack_slice(&[
-                Datum::UInt64(database.id.0),
-                Datum::UInt32(database.oid),
-                Datum::String(database.name()),
-            ]),
+            row: Row::pack(
+                [
+                    Datum::UInt64(database.id.0),
+                    Datum::UInt32(database.oid),
+                    Datum::String(database.name()),
+                ]
+                .into_iter()
+                .chain(privileges.iter()),
+            ),
             diff,
         }
     }
@@ -92,14 +141,22 @@ impl CatalogState {
                 &self.database_by_id[id].schemas_by_id[schema_id],
             ),
         };
+        let privileges = self.pack_privileges(&ObjectId::Schema((
+            *database_spec,
+            SchemaSpecifier::Id(*schema_id),
+        )));
         BuiltinTableUpdate {
             id: self.resolve_builtin_table(&MZ_SCHEMAS),
-            row: Row::pack_slice(&[
-                Datum::UInt64(schema_id.0),
-                Datum::UInt32(schema.oid),
-                Datum::from(database_id),
-                Datum::String(&schema.name.schema),
-            ]),
+            row: Row::pack(
+                [
+                    Datum::UInt64(schema_id.0),
+                    Datum::UInt32(schema.oid),
+                    Datum::from(database_id),
+                    Datum::String(&schema.name.schema),
+                ]
+                .into_iter()
+                .chain(privileges.iter()),
+            ),
             diff,
         }
     }
@@ -111,6 +168,7 @@ impl CatalogState {
                 Datum::String(&role.id.to_string()),
                 Datum::UInt32(role.oid),
                 Datum::String(&role.name),
+                Datum::from(role.superuser),
             ]),
             diff,
         }
@@ -122,9 +180,14 @@ impl CatalogState {
         diff: Diff,
     ) -> BuiltinTableUpdate {
         let id = self.compute_instances_by_name[name];
+        let privileges = self.pack_privileges(&ObjectId::Cluster(id));
         BuiltinTableUpdate {
             id: self.resolve_builtin_table(&MZ_CLUSTERS),
-            row: Row::pack_slice(&[Datum::String(&id.to_string()), Datum::String(name)]),
+            row: Row::pack(
+                [Datum::String(&id.to_string()), Datum::String(name)]
+                    .into_iter()
+                    .chain(privileges.iter()),
+            ),
             diff,
         }
     }
@@ -200,12 +263,24 @@ impl CatalogState {
             )
             .id;
         let name = &entry.name().item;
+        let privileges = self.pack_privileges(&ObjectId::Item(id));
         let mut updates = match entry.item() {
-            CatalogItem::Log(_) => {
-                self.pack_source_update(id, oid, schema_id, name, "log", None, None, None, diff)
-            }
+            CatalogItem::Log(_) => self.pack_source_update(
+                id,
+                oid,
+                schema_id,
+                name,
+                "log",
+                None,
+                None,
+                None,
+                &privileges,
+                diff,
+            ),
             CatalogItem::Index(index) => self.pack_index_update(id, oid, name, index, diff),
-            CatalogItem::Table(_) => self.pack_table_update(id, oid, schema_id, name, diff),
+            CatalogItem::Table(_) => {
+                self.pack_table_update(id, oid, schema_id, name, &privileges, diff)
+            }
             CatalogItem::Source(source) => {
                 let source_type = source.source_type();
                 let connection_id = source.connection_id();
@@ -226,19 +301,32 @@ impl CatalogState {
                         _ => None,
                     },
                     envelope,
+                    &privileges,
                     diff,
                 )
             }
-            CatalogItem::View(view) => self.pack_view_update(id, oid, schema_id, name, view, diff),
-            CatalogItem::MaterializedView(mview) => {
-                self.pack_materialized_view_update(id, oid, schema_id, name, mview, diff)
+            CatalogItem::View(view) => {
+                self.pack_view_update(id, oid, schema_id, name, view, &privileges, diff)
             }
+            CatalogItem::MaterializedView(mview) => self.pack_materialized_view_update(
+                id,
+                oid,
+                schema_id,
+                name,
+                mview,
+                &privileges,
+                diff,
+            ),
             CatalogItem::Sink(sink) => self.pack_sink_update(id, oid, schema_id, name, sink, diff),
-            CatalogItem::Type(ty) => self.pack_type_update(id, oid, schema_id, name, ty, diff),
+            CatalogItem::Type(ty) => {
+                self.pack_type_update(id, oid, schema_id, name, ty, &privileges, diff)
+            }
             CatalogItem::Func(func) => self.pack_func_update(id, schema_id, name, func, diff),
-            CatalogItem::Secret(_) => self.pack_secret_update(id, schema_id, name, diff),
+            CatalogItem::Secret(_) => {
+                self.pack_secret_update(id, schema_id, name, &privileges, diff)
+            }
             CatalogItem::Connection(connection) => {
-                self.pack_connection_update(id, oid, schema_id, name, connection, diff)
+                self.pack_connection_update(id, oid, schema_id, name, connection, &privileges, diff)
             }
         };
 
@@ -279,16 +367,21 @@ impl CatalogState {
         oid: u32,
         schema_id: &SchemaSpecifier,
         name: &str,
+        privileges: &Row,
         diff: Diff,
     ) -> Vec<BuiltinTableUpdate> {
         vec![BuiltinTableUpdate {
             id: self.resolve_builtin_table(&MZ_TABLES),
-            row: Row::pack_slice(&[
-                Datum::String(&id.to_string()),
-                Datum::UInt32(oid),
-                Datum::UInt64(schema_id.into()),
-                Datum::String(name),
-            ]),
+            row: Row::pack(
+                [
+                    Datum::String(&id.to_string()),
+                    Datum::UInt32(oid),
+                    Datum::UInt64(schema_id.into()),
+                    Datum::String(name),
+                ]
+                .into_iter()
+                .chain(privileges.iter()),
+            ),
             diff,
         }]
     }
@@ -303,20 +396,25 @@ impl CatalogState {
         connection_id: Option<GlobalId>,
         size: Option<&str>,
         envelope: Option<&str>,
+        privileges: &Row,
         diff: Diff,
     ) -> Vec<BuiltinTableUpdate> {
         vec![BuiltinTableUpdate {
             id: self.resolve_builtin_table(&MZ_SOURCES),
-            row: Row::pack_slice(&[
-                Datum::String(&id.to_string()),
-                Datum::UInt32(oid),
-                Datum::UInt64(schema_id.into()),
-                Datum::String(name),
-                Datum::String(source_desc_name),
-                Datum::from(connection_id.map(|id| id.to_string()).as_deref()),
-                Datum::from(size),
-                Datum::from(envelope),
-            ]),
+            row: Row::pack(
+                [
+                    Datum::String(&id.to_string()),
+                    Datum::UInt32(oid),
+                    Datum::UInt64(schema_id.into()),
+                    Datum::String(name),
+                    Datum::String(source_desc_name),
+                    Datum::from(connection_id.map(|id| id.to_string()).as_deref()),
+                    Datum::from(size),
+                    Datum::from(envelope),
+                ]
+                .into_iter()
+                .chain(privileges.iter()),
+            ),
             diff,
         }]
     }
@@ -328,30 +426,38 @@ impl CatalogState {
         schema_id: &SchemaSpecifier,
         name: &str,
         connection: &Connection,
+        privileges: &Row,
         diff: Diff,
     ) -> Vec<BuiltinTableUpdate> {
         let mut updates = vec![BuiltinTableUpdate {
             id: self.resolve_builtin_table(&MZ_CONNECTIONS),
-            row: Row::pack_slice(&[
-                Datum::String(&id.to_string()),
-                Datum::UInt32(oid),
-                Datum::UInt64(schema_id.into()),
-                Datum::String(name),
-                Datum::String(match connection.connection {
-                    mz_storage_client::types::connections::Connection::Kafka { .. } => "kafka",
-                    mz_storage_client::types::connections::Connection::Csr { .. } => {
-                        "confluent-schema-registry"
-                    }
-                    mz_storage_client::types::connections::Connection::Postgres { .. } => {
-                        "postgres"
-                    }
-                    mz_storage_client::types::connections::Connection::Aws(..) => "aws",
-                    mz_storage_client::types::connections::Connection::AwsPrivatelink(..) => {
-                        "aws-privatelink"
-                    }
-                    mz_storage_client::types::connections::Connection::Ssh { .. } => "ssh-tunnel",
-                }),
-            ]),
+            row: Row::pack(
+                [
+                    Datum::String(&id.to_string()),
+                    Datum::UInt32(oid),
+                    Datum::UInt64(schema_id.into()),
+                    Datum::String(name),
+                    Datum::String(match connection.connection {
+                        mz_storage_client::types::connections::Connection::Kafka { .. } => "kafka",
+                        mz_storage_client::types::connections::Connection::Csr { .. } => {
+                            "confluent-schema-registry"
+                        }
+                        mz_storage_client::types::connections::Connection::Postgres { .. } => {
+                            "postgres"
+                        }
+                        mz_storage_client::types::connections::Connection::MySql { .. } => "mysql",
+                        mz_storage_client::types::connections::Connection::Aws(..) => "aws",
+                        mz_storage_client::types::connections::Connection::AwsPrivatelink(..) => {
+                            "aws-privatelink"
+                        }
+                        mz_storage_client::types::connections::Connection::Ssh { .. } => {
+                            "ssh-tunnel"
+                        }
+                    }),
+                ]
+                .into_iter()
+                .chain(privileges.iter()),
+            ),
             diff,
         }];
         match connection.connection {
@@ -371,6 +477,7 @@ impl CatalogState {
             }
             mz_storage_client::types::connections::Connection::Csr(_)
             | mz_storage_client::types::connections::Connection::Postgres(_)
+            | mz_storage_client::types::connections::Connection::MySql(_)
             | mz_storage_client::types::connections::Connection::Aws(_)
             | mz_storage_client::types::connections::Connection::AwsPrivatelink(_) => {
                 if let Some(aws_principal_context) = self.aws_principal_context.as_ref() {
@@ -460,6 +567,7 @@ impl CatalogState {
         schema_id: &SchemaSpecifier,
         name: &str,
         view: &View,
+        privileges: &Row,
         diff: Diff,
     ) -> Vec<BuiltinTableUpdate> {
         let create_sql = mz_sql::parse::parse(&view.create_sql)
@@ -477,13 +585,17 @@ impl CatalogState {
 
         vec![BuiltinTableUpdate {
             id: self.resolve_builtin_table(&MZ_VIEWS),
-            row: Row::pack_slice(&[
-                Datum::String(&id.to_string()),
-                Datum::UInt32(oid),
-                Datum::UInt64(schema_id.into()),
-                Datum::String(name),
-                Datum::String(&query_string),
-            ]),
+            row: Row::pack(
+                [
+                    Datum::String(&id.to_string()),
+                    Datum::UInt32(oid),
+                    Datum::UInt64(schema_id.into()),
+                    Datum::String(name),
+                    Datum::String(&query_string),
+                ]
+                .into_iter()
+                .chain(privileges.iter()),
+            ),
             diff,
         }]
     }
@@ -495,6 +607,7 @@ impl CatalogState {
         schema_id: &SchemaSpecifier,
         name: &str,
         mview: &MaterializedView,
+        privileges: &Row,
         diff: Diff,
     ) -> Vec<BuiltinTableUpdate> {
         let create_sql = mz_sql::parse::parse(&mview.create_sql)
@@ -512,14 +625,18 @@ impl CatalogState {
 
         vec![BuiltinTableUpdate {
             id: self.resolve_builtin_table(&MZ_MATERIALIZED_VIEWS),
-            row: Row::pack_slice(&[
-                Datum::String(&id.to_string()),
-                Datum::UInt32(oid),
-                Datum::UInt64(schema_id.into()),
-                Datum::String(name),
-                Datum::String(&mview.compute_instance.to_string()),
-                Datum::String(&query_string),
-            ]),
+            row: Row::pack(
+                [
+                    Datum::String(&id.to_string()),
+                    Datum::UInt32(oid),
+                    Datum::UInt64(schema_id.into()),
+                    Datum::String(name),
+                    Datum::String(&mview.compute_instance.to_string()),
+                    Datum::String(&query_string),
+                ]
+                .into_iter()
+                .chain(privileges.iter()),
+            ),
             diff,
         }]
     }
@@ -643,17 +760,22 @@ impl CatalogState {
         schema_id: &SchemaSpecifier,
         name: &str,
         typ: &Type,
+        privileges: &Row,
         diff: Diff,
     ) -> Vec<BuiltinTableUpdate> {
         let generic_update = BuiltinTableUpdate {
             id: self.resolve_builtin_table(&MZ_TYPES),
-            row: Row::pack_slice(&[
-                Datum::String(&id.to_string()),
-                Datum::UInt32(oid),
-                Datum::UInt64(schema_id.into()),
-                Datum::String(name),
-                Datum::String(&TypeCategory::from_catalog_type(&typ.details.typ).to_string()),
-            ]),
+            row: Row::pack(
+                [
+                    Datum::String(&id.to_string()),
+                    Datum::UInt32(oid),
+                    Datum::UInt64(schema_id.into()),
+                    Datum::String(name),
+                    Datum::String(&TypeCategory::from_catalog_type(&typ.details.typ).to_string()),
+                ]
+                .into_iter()
+                .chain(privileges.iter()),
+            ),
             diff,
         };
 
@@ -756,15 +878,20 @@ impl CatalogState {
         id: GlobalId,
         schema_id: &SchemaSpecifier,
         name: &str,
+        privileges: &Row,
         diff: Diff,
     ) -> Vec<BuiltinTableUpdate> {
         vec![BuiltinTableUpdate {
             id: self.resolve_builtin_table(&MZ_SECRETS),
-            row: Row::pack_slice(&[
-                Datum::String(&id.to_string()),
-                Datum::UInt64(schema_id.into()),
-                Datum::String(name),
-            ]),
+            row: Row::pack(
+                [
+                    Datum::String(&id.to_string()),
+                    Datum::UInt64(schema_id.into()),
+                    Datum::String(name),
+                ]
+                .into_iter()
+                .chain(privileges.iter()),
+            ),
             diff,
         }]
     }
@@ -955,6 +1082,36 @@ impl CatalogState {
         updates
     }
 
+    pub(super) fn pack_relation_statistics_update(
+        &self,
+        id: GlobalId,
+        diff: Diff,
+    ) -> Vec<BuiltinTableUpdate> {
+        let statistics = match self.relation_statistics.get(&id) {
+            Some(statistics) => statistics,
+            None => return vec![],
+        };
+        let id_str = id.to_string();
+        let mut updates = vec![BuiltinTableUpdate {
+            id: self.resolve_builtin_table(&MZ_RELATION_STATISTICS),
+            row: Row::pack_slice(&[Datum::String(&id_str), Datum::UInt64(statistics.row_count)]),
+            diff,
+        }];
+        let column_statistics_id = self.resolve_builtin_table(&MZ_COLUMN_STATISTICS);
+        for (i, distinct_count) in statistics.distinct_counts.iter().enumerate() {
+            updates.push(BuiltinTableUpdate {
+                id: column_statistics_id,
+                row: Row::pack_slice(&[
+                    Datum::String(&id_str),
+                    Datum::UInt64(u64::cast_from(i + 1)),
+                    Datum::UInt64(*distinct_count),
+                ]),
+                diff,
+            });
+        }
+        updates
+    }
+
     pub fn pack_replica_write_frontiers_updates(
         &self,
         replica_id: ReplicaId,
@@ -58,6 +58,8 @@ pub enum ErrorKind {
     ReadOnlyItem(String),
     #[error("cannot drop non-empty schema '{0}'")]
     SchemaNotEmpty(String),
+    #[error("role {} cannot be dropped because some objects depend on it", .0.quoted())]
+    RoleInUse(String),
     #[error("non-temporary items cannot depend on temporary item '{0}'")]
     InvalidTemporaryDependency(String),
     #[error("cannot create temporary item in non-temporary schema")]
@@ -68,6 +70,14 @@ pub enum ErrorKind {
     AmbiguousRename(#[from] AmbiguousRename),
     #[error("cannot rename type: {0}")]
     TypeRename(String),
+    #[error(
+        "cannot add column to table {table} because {dependent_type} {dependent} depends on it"
+    )]
+    AlterTableDependency {
+        table: String,
+        dependent_type: String,
+        dependent: String,
+    },
     #[error("cannot migrate from catalog version {last_seen_version} to version {this_version} (earlier versions might still work): {cause}")]
     FailedMigration {
         last_seen_version: String,
@@ -105,6 +115,13 @@ impl Error {
             ErrorKind::ReservedClusterName(_) => {
                 Some("The prefixes \"mz_\" and \"pg_\" are reserved for system clusters.".into())
             }
+            ErrorKind::RoleInUse(_) => {
+                Some("The role owns objects or has been granted privileges on objects.".into())
+            }
+            ErrorKind::AlterTableDependency { dependent_type, .. } => Some(format!(
+                "The {dependent_type} would not reflect the new column. Drop the {dependent_type} \
+                 and recreate it after altering the table."
+            )),
             _ => None,
         }
     }
@@ -23,21 +23,25 @@ use mz_ore::cast::CastFrom;
 use mz_ore::collections::CollectionExt;
 use mz_ore::now::EpochMillis;
 use mz_repr::GlobalId;
+use mz_sql::ast::Statement;
 use mz_sql::catalog::{CatalogError as SqlCatalogError, CatalogItemType};
 use mz_sql::names::{
-    DatabaseId, ObjectQualifiers, QualifiedObjectName, ResolvedDatabaseSpecifier, RoleId, SchemaId,
-    SchemaSpecifier,
+    DatabaseId, ObjectId, ObjectQualifiers, QualifiedObjectName, ResolvedDatabaseSpecifier, RoleId,
+    SchemaId, SchemaSpecifier,
 };
+use mz_sql::rbac::{AclMode, MzAclItem, Privileges};
+use mz_sql::DEFAULT_SCHEMA;
+use mz_sql_parser::ast::ObjectType as SqlObjectType;
 use mz_stash::{Append, AppendBatch, Stash, StashError, TableTransaction, TypedCollection};
 use mz_storage_client::types::sources::Timeline;
 
 use crate::catalog;
 use crate::catalog::builtin::{
     BuiltinLog, BUILTIN_COMPUTE_INSTANCES, BUILTIN_COMPUTE_REPLICAS, BUILTIN_PREFIXES,
-    BUILTIN_ROLES,
+    BUILTIN_ROLES, MZ_SYSTEM_ROLE,
 };
 use crate::catalog::error::{Error, ErrorKind};
-use crate::catalog::{is_reserved_name, SystemObjectMapping};
+use crate::catalog::{is_reserved_name, RelationStatistics, SystemObjectMapping};
 use crate::catalog::{SerializedComputeReplicaConfig, DEFAULT_CLUSTER_REPLICA_NAME};
 
 use super::{
@@ -257,6 +261,7 @@ async fn migrate<S: Append>(
                 },
                 RoleValue {
                     name: "materialize".into(),
+                    superuser: true,
                 },
             )?;
             let id = txn.get_and_increment_id(AUDIT_LOG_ID_ALLOC_KEY.to_string())?;
@@ -387,6 +392,7 @@ async fn migrate<S: Append>(
     add_new_builtin_roles_migration(&mut txn)?;
     add_new_builtin_compute_instances_migration(&mut txn)?;
     add_new_builtin_compute_replicas_migration(&mut txn, bootstrap_args)?;
+    add_missing_privileges_migration(&mut txn)?;
     txn.commit().await?;
     Ok(())
 }
@@ -413,6 +419,96 @@ fn add_new_builtin_roles_migration<S: Append>(
     Ok(())
 }
 
+/// Assigns an owner and access control list to every database, schema, user
+/// cluster and item that does not yet have one, which is the case for objects
+/// created before ownership was tracked. Such objects are owned by the
+/// `mz_system` role. `PUBLIC` is granted `USAGE` on the `public` schemas, the
+/// default cluster and types, as it is for newly created objects.
+fn add_missing_privileges_migration<S: Append>(
+    txn: &mut Transaction<'_, S>,
+) -> Result<(), catalog::error::Error> {
+    let owner_id = txn
+        .roles
+        .items()
+        .into_iter()
+        .find(|(_, value)| value.name == MZ_SYSTEM_ROLE.name)
+        .map(|(key, _)| key.id)
+        .expect("mz_system role must exist");
+    let existing: HashSet<_> = txn
+        .privileges
+        .items()
+        .into_keys()
+        .map(|key| key.object_id)
+        .collect();
+
+    let mut missing = Vec::new();
+    for (key, _) in txn.databases.items() {
+        let object_id = ObjectId::Database(DatabaseId::new(key.id));
+        if !existing.contains(&object_id) {
+            missing.push((
+                object_id,
+                Privileges::new(owner_id, SqlObjectType::Database),
+            ));
+        }
+    }
+    for (key, value) in txn.schemas.items() {
+        let database_id = match value.database_id {
+            Some(database_id) => DatabaseId::new(database_id),
+            None => continue,
+        };
+        let object_id = ObjectId::Schema((
+            ResolvedDatabaseSpecifier::Id(database_id),
+            SchemaSpecifier::Id(SchemaId::new(key.id)),
+        ));
+        if !existing.contains(&object_id) {
+            let mut privileges = Privileges::new(owner_id, SqlObjectType::Schema);
+            if value.name == DEFAULT_SCHEMA {
+                privileges.grant(RoleId::Public, AclMode::USAGE);
+            }
+            missing.push((object_id, privileges));
+        }
+    }
+    for key in txn.compute_instances.items().into_keys() {
+        let object_id = ObjectId::Cluster(key.id);
+        if key.id.is_user() && !existing.contains(&object_id) {
+            let mut privileges = Privileges::new(owner_id, SqlObjectType::Cluster);
+            if key.id == DEFAULT_USER_COMPUTE_INSTANCE_ID {
+                privileges.grant(RoleId::Public, AclMode::USAGE);
+            }
+            missing.push((object_id, privileges));
+        }
+    }
+    for (key, value) in txn.items.items() {
+        let object_id = ObjectId::Item(key.gid);
+        if existing.contains(&object_id) {
+            continue;
+        }
+        let SerializedCatalogItem::V1 { create_sql } = &value.definition;
+        let object_type = match mz_sql::parse::parse(create_sql).map(|stmts| stmts.into_element()) {
+            Ok(Statement::CreateTable(_)) => SqlObjectType::Table,
+            Ok(Statement::CreateSource(_) | Statement::CreateSubsource(_)) => SqlObjectType::Source,
+            Ok(Statement::CreateView(_)) => SqlObjectType::View,
+            Ok(Statement::CreateMaterializedView(_)) => SqlObjectType::MaterializedView,
+            Ok(Statement::CreateSink(_)) => SqlObjectType::Sink,
+            Ok(Statement::CreateIndex(_)) => SqlObjectType::Index,
+            Ok(Statement::CreateType(_)) => SqlObjectType::Type,
+            Ok(Statement::CreateSecret(_)) => SqlObjectType::Secret,
+            Ok(Statement::CreateConnection(_)) => SqlObjectType::Connection,
+            _ => SqlObjectType::Object,
+        };
+        let mut privileges = Privileges::new(owner_id, object_type);
+        if object_type == SqlObjectType::Type {
+            privileges.grant(RoleId::Public, AclMode::USAGE);
+        }
+        missing.push((object_id, privileges));
+    }
+
+    for (object_id, privileges) in missing {
+        txn.set_privileges(object_id, &privileges)?;
+    }
+    Ok(())
+}
+
 fn add_new_builtin_compute_instances_migration<S: Append>(
     txn: &mut Transaction<'_, S>,
 ) -> Result<(), catalog::error::Error> {
@@ -633,12 +729,48 @@ impl<S: Append> Connection<S> {
             .collect())
     }
 
-    pub async fn load_roles(&mut self) -> Result<Vec<(RoleId, String)>, Error> {
+    pub async fn load_roles(&mut self) -> Result<Vec<(RoleId, String, bool)>, Error> {
         Ok(COLLECTION_ROLE
             .peek_one(&mut self.stash)
             .await?
             .into_iter()
-            .map(|(k, v)| (k.id, v.name))
+            .map(|(k, v)| (k.id, v.name, v.superuser))
+            .collect())
+    }
+
+    pub async fn load_privileges(&mut self) -> Result<Vec<(ObjectId, Privileges)>, Error> {
+        Ok(COLLECTION_PRIVILEGES
+            .peek_one(&mut self.stash)
+            .await?
+            .into_iter()
+            .map(|(k, v)| {
+                (
+                    k.object_id,
+                    Privileges {
+                        owner_id: v.owner_id,
+                        acl: v.acl,
+                    },
+                )
+            })
+            .collect())
+    }
+
+    pub async fn load_relation_statistics(
+        &mut self,
+    ) -> Result<Vec<(GlobalId, RelationStatistics)>, Error> {
+        Ok(COLLECTION_RELATION_STATISTICS
+            .peek_one(&mut self.stash)
+            .await?
+            .into_iter()
+            .map(|(k, v)| {
+                (
+                    k.id,
+                    RelationStatistics {
+                        row_count: v.row_count,
+                        distinct_counts: v.distinct_counts,
+                    },
+                )
+            })
             .collect())
     }
 
@@ -953,6 +1085,7 @@ pub async fn transaction<'a, S: Append>(stash: &'a mut S) -> Result<Transaction<
     let databases = COLLECTION_DATABASE.peek_one(stash).await?;
     let schemas = COLLECTION_SCHEMA.peek_one(stash).await?;
     let roles = COLLECTION_ROLE.peek_one(stash).await?;
+    let privileges = COLLECTION_PRIVILEGES.peek_one(stash).await?;
     let items = COLLECTION_ITEM.peek_one(stash).await?;
     let compute_instances = COLLECTION_COMPUTE_INSTANCES.peek_one(stash).await?;
     let compute_replicas = COLLECTION_COMPUTE_REPLICAS.peek_one(stash).await?;
@@ -965,6 +1098,7 @@ pub async fn transaction<'a, S: Append>(stash: &'a mut S) -> Result<Transaction<
     let timestamps = COLLECTION_TIMESTAMP.peek_one(stash).await?;
     let system_gid_mapping = COLLECTION_SYSTEM_GID_MAPPING.peek_one(stash).await?;
     let system_configurations = COLLECTION_SYSTEM_CONFIGURATION.peek_one(stash).await?;
+    let relation_statistics = COLLECTION_RELATION_STATISTICS.peek_one(stash).await?;
 
     Ok(Transaction {
         stash,
@@ -974,6 +1108,7 @@ pub async fn transaction<'a, S: Append>(stash: &'a mut S) -> Result<Transaction<
         }),
         items: TableTransaction::new(items, |a, b| a.schema_id == b.schema_id && a.name == b.name),
         roles: TableTransaction::new(roles, |a, b| a.name == b.name),
+        privileges: TableTransaction::new(privileges, |_a, _b| false),
         compute_instances: TableTransaction::new(compute_instances, |a, b| a.name == b.name),
         compute_replicas: TableTransaction::new(compute_replicas, |a, b| {
             a.compute_instance_id == b.compute_instance_id && a.name == b.name
@@ -985,6 +1120,7 @@ pub async fn transaction<'a, S: Append>(stash: &'a mut S) -> Result<Transaction<
         timestamps: TableTransaction::new(timestamps, |_a, _b| false),
         system_gid_mapping: TableTransaction::new(system_gid_mapping, |_a, _b| false),
         system_configurations: TableTransaction::new(system_configurations, |_a, _b| false),
+        relation_statistics: TableTransaction::new(relation_statistics, |_a, _b| false),
         audit_log_updates: Vec::new(),
         storage_usage_updates: Vec::new(),
     })
@@ -996,6 +1132,7 @@ pub struct Transaction<'a, S> {
     schemas: TableTransaction<SchemaKey, SchemaValue>,
     items: TableTransaction<ItemKey, ItemValue>,
     roles: TableTransaction<RoleKey, RoleValue>,
+    privileges: TableTransaction<PrivilegeKey, PrivilegeValue>,
     compute_instances: TableTransaction<ComputeInstanceKey, ComputeInstanceValue>,
     compute_replicas: TableTransaction<ComputeReplicaKey, ComputeReplicaValue>,
     introspection_sources:
@@ -1006,6 +1143,7 @@ pub struct Transaction<'a, S> {
     timestamps: TableTransaction<TimestampKey, TimestampValue>,
     system_gid_mapping: TableTransaction<GidMappingKey, GidMappingValue>,
     system_configurations: TableTransaction<ServerConfigurationKey, ServerConfigurationValue>,
+    relation_statistics: TableTransaction<RelationStatisticsKey, RelationStatisticsValue>,
     // Don't make this a table transaction so that it's not read into the stash
     // memory cache.
     audit_log_updates: Vec<(AuditLogKey, (), i64)>,
@@ -1098,17 +1236,18 @@ impl<'a, S: Append> Transaction<'a, S> {
         }
     }
 
-    pub fn insert_user_role(&mut self, role_name: &str) -> Result<RoleId, Error> {
-        self.insert_role(role_name, USER_ROLE_ID_ALLOC_KEY, RoleId::User)
+    pub fn insert_user_role(&mut self, role_name: &str, superuser: bool) -> Result<RoleId, Error> {
+        self.insert_role(role_name, superuser, USER_ROLE_ID_ALLOC_KEY, RoleId::User)
     }
 
     fn insert_system_role(&mut self, role_name: &str) -> Result<RoleId, Error> {
-        self.insert_role(role_name, SYSTEM_ROLE_ID_ALLOC_KEY, RoleId::System)
+        self.insert_role(role_name, true, SYSTEM_ROLE_ID_ALLOC_KEY, RoleId::System)
     }
 
     fn insert_role<F>(
         &mut self,
         role_name: &str,
+        superuser: bool,
         id_alloc_key: &str,
         role_id_variant: F,
     ) -> Result<RoleId, Error>
@@ -1121,6 +1260,7 @@ impl<'a, S: Append> Transaction<'a, S> {
             RoleKey { id },
             RoleValue {
                 name: role_name.to_string(),
+                superuser,
             },
         ) {
             Ok(_) => Ok(id),
@@ -1130,6 +1270,49 @@ impl<'a, S: Append> Transaction<'a, S> {
         }
     }
 
+    /// Sets the owner and access control list of object `id`, replacing any
+    /// existing ones.
+    pub fn set_privileges(&mut self, id: ObjectId, privileges: &Privileges) -> Result<(), Error> {
+        self.privileges.set(
+            PrivilegeKey { object_id: id },
+            Some(PrivilegeValue {
+                owner_id: privileges.owner_id,
+                acl: privileges.acl.clone(),
+            }),
+        )?;
+        Ok(())
+    }
+
+    /// Removes the owner and access control list of object `id`, if any.
+    pub fn remove_privileges(&mut self, id: ObjectId) -> Result<(), Error> {
+        self.privileges.set(PrivilegeKey { object_id: id }, None)?;
+        Ok(())
+    }
+
+    /// Sets the statistics collected about relation `id`, replacing any
+    /// existing ones.
+    pub fn set_relation_statistics(
+        &mut self,
+        id: GlobalId,
+        statistics: &RelationStatistics,
+    ) -> Result<(), Error> {
+        self.relation_statistics.set(
+            RelationStatisticsKey { id },
+            Some(RelationStatisticsValue {
+                row_count: statistics.row_count,
+                distinct_counts: statistics.distinct_counts.clone(),
+            }),
+        )?;
+        Ok(())
+    }
+
+    /// Removes the statistics collected about relation `id`, if any.
+    pub fn remove_relation_statistics(&mut self, id: GlobalId) -> Result<(), Error> {
+        self.relation_statistics
+            .set(RelationStatisticsKey { id }, None)?;
+        Ok(())
+    }
+
     /// Panics if any introspection source id is not a system id
     pub fn insert_user_compute_instance(
         &mut self,
@@ -1614,6 +1797,13 @@ impl<'a, S: Append> Transaction<'a, S> {
             self.roles.pending(),
         )
         .await?;
+        add_batch(
+            self.stash,
+            &mut batches,
+            &COLLECTION_PRIVILEGES,
+            self.privileges.pending(),
+        )
+        .await?;
         add_batch(
             self.stash,
             &mut batches,
@@ -1677,6 +1867,13 @@ impl<'a, S: Append> Transaction<'a, S> {
             self.system_configurations.pending(),
         )
         .await?;
+        add_batch(
+            self.stash,
+            &mut batches,
+            &COLLECTION_RELATION_STATISTICS,
+            self.relation_statistics.pending(),
+        )
+        .await?;
         add_batch(
             self.stash,
             &mut batches,
@@ -1755,6 +1952,7 @@ pub async fn initialize_stash<S: Append>(stash: &mut S) -> Result<(), Error> {
     add_batch(stash, &mut batches, &COLLECTION_SCHEMA).await?;
     add_batch(stash, &mut batches, &COLLECTION_ITEM).await?;
     add_batch(stash, &mut batches, &COLLECTION_ROLE).await?;
+    add_batch(stash, &mut batches, &COLLECTION_PRIVILEGES).await?;
     add_batch(stash, &mut batches, &COLLECTION_TIMESTAMP).await?;
     add_batch(stash, &mut batches, &COLLECTION_SYSTEM_CONFIGURATION).await?;
     add_batch(stash, &mut batches, &COLLECTION_AUDIT_LOG).await?;
@@ -1869,6 +2067,39 @@ pub struct RoleKey {
 #[derive(Clone, Deserialize, Serialize, PartialOrd, PartialEq, Eq, Ord)]
 pub struct RoleValue {
     name: String,
+    // Roles created before superuser status was tracked were all superusers.
+    #[serde(default = "default_superuser", skip_serializing_if = "is_superuser")]
+    superuser: bool,
+}
+
+fn default_superuser() -> bool {
+    true
+}
+
+fn is_superuser(superuser: &bool) -> bool {
+    *superuser
+}
+
+#[derive(Clone, Deserialize, Serialize, PartialOrd, PartialEq, Eq, Ord, Hash)]
+pub struct PrivilegeKey {
+    object_id: ObjectId,
+}
+
+#[derive(Clone, Deserialize, Serialize, PartialOrd, PartialEq, Eq, Ord)]
+pub struct PrivilegeValue {
+    owner_id: RoleId,
+    acl: Vec<MzAclItem>,
+}
+
+#[derive(Clone, Deserialize, Serialize, PartialOrd, PartialEq, Eq, Ord, Hash)]
+pub struct RelationStatisticsKey {
+    id: GlobalId,
+}
+
+#[derive(Clone, Deserialize, Serialize, PartialOrd, PartialEq, Eq, Ord)]
+pub struct RelationStatisticsValue {
+    row_count: u64,
+    distinct_counts: Vec<u64>,
 }
 
 #[derive(Clone, Deserialize, Serialize, PartialOrd, PartialEq, Eq, Ord, Hash)]
@@ -1927,12 +2158,18 @@ pub static COLLECTION_SCHEMA: TypedCollection<SchemaKey, SchemaValue> =
     TypedCollection::new("schema");
 pub static COLLECTION_ITEM: TypedCollection<ItemKey, ItemValue> = TypedCollection::new("item");
 pub static COLLECTION_ROLE: TypedCollection<RoleKey, RoleValue> = TypedCollection::new("role");
+pub static COLLECTION_PRIVILEGES: TypedCollection<PrivilegeKey, PrivilegeValue> =
+    TypedCollection::new("privileges");
 pub static COLLECTION_TIMESTAMP: TypedCollection<TimestampKey, TimestampValue> =
     TypedCollection::new("timestamp");
 pub static COLLECTION_SYSTEM_CONFIGURATION: TypedCollection<
     ServerConfigurationKey,
     ServerConfigurationValue,
 > = TypedCollection::new("system_configuration");
+pub static COLLECTION_RELATION_STATISTICS: TypedCollection<
+    RelationStatisticsKey,
+    RelationStatisticsValue,
+> = TypedCollection::new("relation_statistics");
 pub static COLLECTION_AUDIT_LOG: TypedCollection<AuditLogKey, ()> =
     TypedCollection::new("audit_log");
 pub static COLLECTION_STORAGE_USAGE: TypedCollection<StorageUsageKey, ()> =
@@ -1950,8 +2187,10 @@ pub static ALL_COLLECTIONS: &[&str] = &[
     COLLECTION_SCHEMA.name(),
     COLLECTION_ITEM.name(),
     COLLECTION_ROLE.name(),
+    COLLECTION_PRIVILEGES.name(),
     COLLECTION_TIMESTAMP.name(),
     COLLECTION_SYSTEM_CONFIGURATION.name(),
+    COLLECTION_RELATION_STATISTICS.name(),
     COLLECTION_AUDIT_LOG.name(),
     COLLECTION_STORAGE_USAGE.name(),
 ];
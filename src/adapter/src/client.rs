@@ -26,7 +26,9 @@ use mz_repr::{GlobalId, Row, ScalarType};
 use mz_sql::ast::{Raw, Statement};
 
 use crate::catalog::INTROSPECTION_USER;
-use crate::command::{Canceled, Command, ExecuteResponse, Response, StartupResponse};
+use crate::command::{
+    Canceled, Command, ExecuteResponse, GetWebhookResponse, Response, StartupResponse,
+};
 use crate::error::AdapterError;
 use crate::metrics::Metrics;
 use crate::session::{EndTransactionAction, PreparedStatement, Session, TransactionId};
@@ -400,6 +402,41 @@ impl SessionClient {
         .await
     }
 
+    /// Looks up the webhook source with the given name.
+    pub async fn get_webhook(
+        &mut self,
+        database: String,
+        schema: String,
+        name: String,
+    ) -> Result<GetWebhookResponse, AdapterError> {
+        self.send(|tx, session| Command::GetWebhook {
+            database,
+            schema,
+            name,
+            session,
+            tx,
+        })
+        .await
+    }
+
+    /// Appends a set of rows to the given webhook source.
+    ///
+    /// Like [`SessionClient::insert_rows`], the rows are only written once the
+    /// current transaction commits.
+    pub async fn append_webhook(
+        &mut self,
+        id: GlobalId,
+        rows: Vec<Row>,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        self.send(|tx, session| Command::AppendWebhook {
+            id,
+            rows,
+            session,
+            tx,
+        })
+        .await
+    }
+
     /// Terminates the client session.
     pub async fn terminate(&mut self) {
         let res = self
@@ -450,6 +487,8 @@ impl SessionClient {
                     | Command::CancelRequest { .. }
                     | Command::DumpCatalog { .. }
                     | Command::CopyRows { .. }
+                    | Command::GetWebhook { .. }
+                    | Command::AppendWebhook { .. }
                     | Command::Terminate { .. } => {}
                 };
                 cmd
@@ -25,8 +25,9 @@ use tokio::sync::watch;
 use mz_ore::str::StrExt;
 use mz_pgcopy::CopyFormatParams;
 use mz_repr::{GlobalId, Row, ScalarType};
+use mz_secrets::SecretsReader;
 use mz_sql::ast::{FetchDirection, ObjectType, Raw, Statement};
-use mz_sql::plan::ExecuteTimeout;
+use mz_sql::plan::{ExecuteTimeout, Webhook};
 
 use crate::client::ConnectionId;
 use crate::coord::peek::PeekResponseUnary;
@@ -102,6 +103,21 @@ pub enum Command {
         tx: oneshot::Sender<Response<ExecuteResponse>>,
     },
 
+    GetWebhook {
+        database: String,
+        schema: String,
+        name: String,
+        session: Session,
+        tx: oneshot::Sender<Response<GetWebhookResponse>>,
+    },
+
+    AppendWebhook {
+        id: GlobalId,
+        rows: Vec<Row>,
+        session: Session,
+        tx: oneshot::Sender<Response<ExecuteResponse>>,
+    },
+
     Terminate {
         session: Session,
         tx: Option<oneshot::Sender<Response<()>>>,
@@ -134,6 +150,19 @@ impl Transmittable for StartupResponse {
     }
 }
 
+/// The response to [`SessionClient::get_webhook`](crate::SessionClient::get_webhook).
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct GetWebhookResponse {
+    /// The ID of the webhook source.
+    pub id: GlobalId,
+    /// How requests to the webhook source are validated and decoded.
+    pub webhook: Webhook,
+    /// A reader for the secret named in `webhook`'s validation, if any.
+    #[derivative(Debug = "ignore")]
+    pub secrets_reader: Arc<dyn SecretsReader>,
+}
+
 /// Messages in a [`StartupResponse`].
 #[derive(Debug)]
 pub enum StartupMessage {
@@ -181,6 +210,8 @@ pub enum ExecuteResponse {
     AlteredIndexLogicalCompaction,
     /// The system configuration was altered.
     AlteredSystemConfiguration,
+    /// Statistics about the requested relation were collected.
+    Analyzed,
     /// The query was canceled.
     Canceled,
     /// The requested cursor was closed.
@@ -275,12 +306,16 @@ pub enum ExecuteResponse {
         /// How long to wait for results to arrive.
         timeout: ExecuteTimeout,
     },
+    /// The requested privilege was granted.
+    GrantedPrivilege,
     /// The specified number of rows were inserted into the requested table.
     Inserted(usize),
     /// The specified prepared statement was created.
     Prepare,
     /// A user-requested warning was raised.
     Raised,
+    /// The requested privilege was revoked.
+    RevokedPrivilege,
     /// Rows will be delivered via the specified future.
     SendingRows {
         #[derivative(Debug = "ignore")]
@@ -314,6 +349,7 @@ impl ExecuteResponse {
             AlteredObject(o) => Some(format!("ALTER {}", o)),
             AlteredIndexLogicalCompaction => Some("ALTER INDEX".into()),
             AlteredSystemConfiguration => Some("ALTER SYSTEM".into()),
+            Analyzed => Some("ANALYZE".into()),
             Canceled => None,
             ClosedCursor => Some("CLOSE CURSOR".into()),
             CopyTo { .. } => None,
@@ -355,6 +391,7 @@ impl ExecuteResponse {
             DroppedSecret => Some("DROP SECRET".into()),
             EmptyQuery => None,
             Fetch { .. } => None,
+            GrantedPrivilege => Some("GRANT".into()),
             Inserted(n) => {
                 // "On successful completion, an INSERT command returns a
                 // command tag of the form `INSERT <oid> <count>`."
@@ -367,6 +404,7 @@ impl ExecuteResponse {
             }
             Prepare => Some("PREPARE".into()),
             Raised => Some("RAISE".into()),
+            RevokedPrivilege => Some("REVOKE".into()),
             SendingRows { .. } => None,
             SetVariable { reset: true, .. } => Some("RESET".into()),
             SetVariable { reset: false, .. } => Some("SET".into()),
@@ -386,15 +424,15 @@ impl ExecuteResponse {
 
         match plan {
             AbortTransaction => vec![TransactionRolledBack],
-            AlterItemRename | AlterNoop | AlterSecret | AlterSink | AlterSource | RotateKeys => {
-                vec![AlteredObject]
-            }
+            AlterItemRename | AlterNoop | AlterOwner | AlterSecret | AlterSink | AlterSource
+            | AlterTableAddColumn | RotateKeys => vec![AlteredObject],
             AlterIndexSetOptions | AlterIndexResetOptions => {
                 vec![AlteredObject, AlteredIndexLogicalCompaction]
             }
             AlterSystemSet | AlterSystemReset | AlterSystemResetAll => {
                 vec![AlteredSystemConfiguration]
             }
+            Analyze => vec![Analyzed],
             Close => vec![ClosedCursor],
             PlanKind::CopyFrom => vec![ExecuteResponseKind::CopyFrom],
             CommitTransaction => vec![TransactionCommitted, TransactionRolledBack],
@@ -438,9 +476,11 @@ impl ExecuteResponse {
             }
             Execute | ReadThenWrite | SendDiffs => vec![Deleted, Inserted, SendingRows, Updated],
             PlanKind::Fetch => vec![ExecuteResponseKind::Fetch],
+            GrantPrivileges => vec![GrantedPrivilege],
             Insert => vec![Inserted, SendingRows],
             PlanKind::Prepare => vec![ExecuteResponseKind::Prepare],
             PlanKind::Raise => vec![ExecuteResponseKind::Raised],
+            RevokePrivileges => vec![RevokedPrivilege],
             PlanKind::SetVariable | ResetVariable => vec![ExecuteResponseKind::SetVariable],
             PlanKind::Subscribe => vec![Subscribing, CopyTo],
             StartTransaction => vec![StartedTransaction],
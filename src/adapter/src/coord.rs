@@ -101,7 +101,9 @@ use mz_persist_client::usage::StorageUsageClient;
 use mz_persist_client::ShardId;
 use mz_repr::{Datum, Diff, GlobalId, Row, Timestamp};
 use mz_secrets::SecretsController;
-use mz_sql::ast::{CreateSourceStatement, CreateSubsourceStatement, Raw, Statement};
+use mz_sql::ast::{
+    CreateSinkStatement, CreateSourceStatement, CreateSubsourceStatement, Raw, Statement,
+};
 use mz_sql::catalog::EnvironmentId;
 use mz_sql::names::Aug;
 use mz_sql::plan::{MutationKind, Params};
@@ -117,7 +119,7 @@ use mz_transform::Optimizer;
 use crate::catalog::builtin::{BUILTINS, MZ_VIEW_FOREIGN_KEYS, MZ_VIEW_KEYS};
 use crate::catalog::{
     self, storage, AwsPrincipalContext, BuiltinMigrationMetadata, BuiltinTableUpdate, Catalog,
-    CatalogItem, ClusterReplicaSizeMap, DataSourceDesc, StorageHostSizeMap,
+    CatalogItem, ClusterReplicaSizeMap, DataSourceDesc, RelationStatistics, StorageHostSizeMap,
     StorageSinkConnectionState,
 };
 use crate::client::{Client, ConnectionId, Handle};
@@ -135,7 +137,9 @@ use crate::error::AdapterError;
 use crate::metrics::Metrics;
 use crate::session::{EndTransactionAction, Session};
 use crate::subscribe::PendingSubscribe;
-use crate::util::{ClientTransmitter, CompletedClientTransmitter, ComputeSinkId};
+use crate::util::{
+    compute_config, storage_config, ClientTransmitter, CompletedClientTransmitter, ComputeSinkId,
+};
 use crate::AdapterNotice;
 
 pub(crate) mod id_bundle;
@@ -178,8 +182,10 @@ pub enum Message<T = mz_repr::Timestamp> {
     Command(Command),
     ControllerReady,
     CreateSourceStatementReady(CreateSourceStatementReady),
+    CreateSinkStatementReady(CreateSinkStatementReady),
     SinkConnectionReady(SinkConnectionReady),
     SendDiffs(SendDiffs),
+    AnalyzeComplete(AnalyzeComplete),
     WriteLockGrant(tokio::sync::OwnedMutexGuard<()>),
     /// Initiates a group commit.
     GroupCommitInitiate,
@@ -215,6 +221,16 @@ pub struct SendDiffs {
     pub returning: Vec<(Row, NonZeroUsize)>,
 }
 
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct AnalyzeComplete {
+    session: Session,
+    #[derivative(Debug = "ignore")]
+    tx: ClientTransmitter<ExecuteResponse>,
+    pub id: GlobalId,
+    pub statistics: Result<RelationStatistics, AdapterError>,
+}
+
 #[derive(Derivative)]
 #[derivative(Debug)]
 pub struct CreateSourceStatementReady {
@@ -234,6 +250,19 @@ pub struct CreateSourceStatementReady {
     pub otel_ctx: OpenTelemetryContext,
 }
 
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct CreateSinkStatementReady {
+    pub session: Session,
+    #[derivative(Debug = "ignore")]
+    pub tx: ClientTransmitter<ExecuteResponse>,
+    pub result: Result<CreateSinkStatement<Aug>, AdapterError>,
+    pub params: Params,
+    pub depends_on: Vec<GlobalId>,
+    pub original_stmt: Statement<Raw>,
+    pub otel_ctx: OpenTelemetryContext,
+}
+
 #[derive(Derivative)]
 #[derivative(Debug)]
 pub struct SinkConnectionReady {
@@ -497,13 +526,19 @@ impl<S: Append + 'static> Coordinator<S> {
         let mut policies_to_set: BTreeMap<Timestamp, CollectionIdBundle> = Default::default();
         policies_to_set.insert(DEFAULT_LOGICAL_COMPACTION_WINDOW_TS, Default::default());
 
+        self.controller
+            .storage
+            .update_configuration(storage_config(self.catalog.system_config()))
+            .await;
+
         info!("coordinator init: creating compute replicas");
+        self.controller
+            .compute
+            .update_configuration(compute_config(self.catalog.system_config()));
         for instance in self.catalog.compute_instances() {
-            self.controller.compute.create_instance(
-                instance.id,
-                instance.log_indexes.clone(),
-                self.catalog.system_config().max_result_size(),
-            )?;
+            self.controller
+                .compute
+                .create_instance(instance.id, instance.log_indexes.clone())?;
             for (replica_id, replica) in instance.replicas_by_id.clone() {
                 let introspection_collections = replica
                     .config
@@ -641,7 +676,9 @@ impl<S: Append + 'static> Coordinator<S> {
                                 source_status_collection_id,
                             )
                         }
-                        DataSourceDesc::Source => (DataSource::Other, None),
+                        DataSourceDesc::Source | DataSourceDesc::Webhook(_) => {
+                            (DataSource::Other, None)
+                        }
                         DataSourceDesc::Introspection(introspection) => {
                             (DataSource::Introspection(*introspection), None)
                         }
@@ -656,6 +693,7 @@ impl<S: Append + 'static> Coordinator<S> {
                                 data_source,
                                 since: None,
                                 status_collection_id,
+                                missing_values: None,
                             },
                         )])
                         .await
@@ -667,7 +705,10 @@ impl<S: Append + 'static> Coordinator<S> {
                         .insert(entry.id());
                 }
                 CatalogItem::Table(table) => {
-                    let collection_desc = table.desc.clone().into();
+                    let collection_desc = CollectionDescription {
+                        missing_values: table.missing_values.clone(),
+                        ..table.desc.clone().into()
+                    };
                     self.controller
                         .storage
                         .create_collections(vec![(entry.id(), collection_desc)])
@@ -903,7 +944,8 @@ impl<S: Append + 'static> Coordinator<S> {
         builtin_table_updates.extend(self.catalog.state().pack_all_replica_size_updates());
         builtin_table_updates.extend(self.catalog.state().pack_all_storage_host_size_updates());
 
-        // Advance all tables to the current timestamp
+        // Advance all tables (and webhook sources, which are written like
+        // tables) to the current timestamp
         info!("coordinator init: advancing all tables to current timestamp");
         let WriteTimestamp {
             timestamp: _,
@@ -911,7 +953,7 @@ impl<S: Append + 'static> Coordinator<S> {
         } = self.get_local_write_ts().await;
         let appends = entries
             .iter()
-            .filter(|entry| entry.is_table())
+            .filter(|entry| entry.is_table() || entry.is_webhook_source())
             .map(|entry| (entry.id(), Vec::new(), advance_to))
             .collect();
         self.controller
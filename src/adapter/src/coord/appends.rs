@@ -279,8 +279,13 @@ impl<S: Append + 'static> Coordinator<S> {
         for (_, updates) in &mut appends {
             differential_dataflow::consolidation::consolidate(updates);
         }
-        // Add table advancements for all tables.
-        for table in self.catalog.entries().filter(|entry| entry.is_table()) {
+        // Add table advancements for all tables. Webhook sources are written
+        // the same way as tables, so they are advanced alongside them.
+        for table in self
+            .catalog
+            .entries()
+            .filter(|entry| entry.is_table() || entry.is_webhook_source())
+        {
             appends.entry(table.id()).or_default();
         }
         let appends = appends
@@ -22,16 +22,22 @@ use mz_ore::task;
 use mz_repr::ScalarType;
 use mz_sql::ast::{InsertSource, Query, Raw, SetExpr, Statement};
 use mz_sql::catalog::SessionCatalog as _;
-use mz_sql::plan::{CreateRolePlan, Params};
+use mz_sql::names::PartialObjectName;
+use mz_sql::plan::Params;
 use mz_stash::Append;
 
+use crate::catalog::{CatalogItem, DataSourceDesc, Source};
 use crate::client::ConnectionId;
 use crate::command::{
-    Canceled, Command, ExecuteResponse, Response, StartupMessage, StartupResponse,
+    Canceled, Command, ExecuteResponse, GetWebhookResponse, Response, StartupMessage,
+    StartupResponse,
 };
 use crate::coord::appends::{Deferred, PendingWriteTxn};
 use crate::coord::peek::PendingPeek;
-use crate::coord::{ConnMeta, Coordinator, CreateSourceStatementReady, Message, PendingTxn};
+use crate::coord::{
+    ConnMeta, Coordinator, CreateSinkStatementReady, CreateSourceStatementReady, Message,
+    PendingTxn,
+};
 use crate::error::AdapterError;
 use crate::metrics;
 use crate::session::{PreparedStatement, Session, TransactionStatus};
@@ -114,6 +120,27 @@ impl<S: Append + 'static> Coordinator<S> {
                 let _ = tx.send(Response { result, session });
             }
 
+            Command::GetWebhook {
+                database,
+                schema,
+                name,
+                session,
+                tx,
+            } => {
+                let result = self.handle_get_webhook(&session, database, schema, name);
+                let _ = tx.send(Response { result, session });
+            }
+
+            Command::AppendWebhook {
+                id,
+                rows,
+                mut session,
+                tx,
+            } => {
+                let result = self.sequence_append_webhook(&mut session, id, rows);
+                let _ = tx.send(Response { result, session });
+            }
+
             Command::Terminate { mut session, tx } => {
                 self.handle_terminate(&mut session).await;
                 if let Some(tx) = tx {
@@ -157,6 +184,50 @@ impl<S: Append + 'static> Coordinator<S> {
         }
     }
 
+    /// Looks up the webhook source with the given name, returning what the
+    /// caller needs to validate and decode requests to it.
+    fn handle_get_webhook(
+        &self,
+        session: &Session,
+        database: String,
+        schema: String,
+        name: String,
+    ) -> Result<GetWebhookResponse, AdapterError> {
+        let partial_name = PartialObjectName {
+            database: Some(database.clone()),
+            schema: Some(schema.clone()),
+            item: name.clone(),
+        };
+        let id = self
+            .catalog
+            .for_session(session)
+            .resolve_item(&partial_name)
+            .map(|item| item.id())
+            .ok();
+        let entry = id.and_then(|id| self.catalog.try_get_entry(&id));
+        match entry.map(|entry| (entry.id(), entry.item())) {
+            Some((
+                id,
+                CatalogItem::Source(Source {
+                    data_source: DataSourceDesc::Webhook(webhook),
+                    ..
+                }),
+            )) => {
+                self.check_webhook_privileges(session, id)?;
+                Ok(GetWebhookResponse {
+                    id,
+                    webhook: webhook.clone(),
+                    secrets_reader: self.secrets_controller.reader(),
+                })
+            }
+            _ => Err(AdapterError::UnknownWebhook {
+                database,
+                schema,
+                name,
+            }),
+        }
+    }
+
     async fn handle_startup(
         &mut self,
         session: Session,
@@ -185,10 +256,9 @@ impl<S: Append + 'static> Coordinator<S> {
                 });
                 return;
             }
-            let plan = CreateRolePlan {
-                name: session.user().name.to_string(),
-            };
-            if let Err(err) = self.sequence_create_role(&session, plan).await {
+            // Roles created on first login are not superusers; superuser
+            // status must be granted explicitly.
+            if let Err(err) = self.sequence_create_login_role(&session).await {
                 let _ = tx.send(Response {
                     result: Err(err),
                     session,
@@ -332,6 +402,7 @@ impl<S: Append + 'static> Coordinator<S> {
                     | Statement::Discard(_)
                     | Statement::Execute(_)
                     | Statement::Explain(_)
+                    | Statement::ExplainAnalyze(_)
                     | Statement::Fetch(_)
                     | Statement::Prepare(_)
                     | Statement::Rollback(_)
@@ -361,11 +432,14 @@ impl<S: Append + 'static> Coordinator<S> {
 
                     // Statements below must by run singly (in Started).
                     Statement::AlterConnection(_)
+                    | Statement::Analyze(_)
                     | Statement::AlterIndex(_)
                     | Statement::AlterSecret(_)
                     | Statement::AlterSink(_)
                     | Statement::AlterSource(_)
+                    | Statement::AlterTableAddColumn(_)
                     | Statement::AlterObjectRename(_)
+                    | Statement::AlterOwner(_)
                     | Statement::AlterSystemSet(_)
                     | Statement::AlterSystemReset(_)
                     | Statement::AlterSystemResetAll(_)
@@ -391,7 +465,9 @@ impl<S: Append + 'static> Coordinator<S> {
                     | Statement::DropRoles(_)
                     | Statement::DropClusters(_)
                     | Statement::DropClusterReplicas(_)
+                    | Statement::GrantPrivileges(_)
                     | Statement::Insert(_)
+                    | Statement::RevokePrivileges(_)
                     | Statement::Update(_) => {
                         return tx.send(
                             Err(AdapterError::OperationProhibitsTransaction(
@@ -415,6 +491,7 @@ impl<S: Append + 'static> Coordinator<S> {
         // purification.  This should be done back on the main thread.
         // We do the validation:
         //   - In the handler for `Message::CreateSourceStatementReady`, before we handle the purified statement.
+        //   - In the handler for `Message::CreateSinkStatementReady`, before we handle the purified statement.
         // If we add special handling for more types of `Statement`s, we'll need to ensure similar verification
         // occurs.
         match stmt {
@@ -450,6 +527,38 @@ impl<S: Append + 'static> Coordinator<S> {
                 });
             }
 
+            // `CREATE SINK` statements may need to fetch schemas from a
+            // schema registry, and so are likewise purified off the main
+            // coordinator thread of control.
+            Statement::CreateSink(stmt) => {
+                let internal_cmd_tx = self.internal_cmd_tx.clone();
+                let conn_id = session.conn_id();
+                let purify_fut = mz_sql::pure::purify_create_sink(
+                    Box::new(catalog.into_owned()),
+                    stmt,
+                    self.connection_context.clone(),
+                );
+                let otel_ctx = OpenTelemetryContext::obtain();
+                task::spawn(|| format!("purify:{conn_id}"), async move {
+                    let result = purify_fut.await.map_err(|e| e.into());
+                    // It is not an error for purification to complete after `internal_cmd_rx` is dropped.
+                    let result = internal_cmd_tx.send(Message::CreateSinkStatementReady(
+                        CreateSinkStatementReady {
+                            session,
+                            tx,
+                            result,
+                            params,
+                            depends_on,
+                            original_stmt,
+                            otel_ctx,
+                        },
+                    ));
+                    if let Err(e) = result {
+                        tracing::warn!("internal_cmd_rx dropped before we could send: {:?}", e);
+                    }
+                });
+            }
+
             // `CREATE SUBSOURCE` statements are disallowed for users and are only generated
             // automatically as part of purification
             Statement::CreateSubsource(_) => tx.send(
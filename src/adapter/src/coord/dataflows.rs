@@ -324,7 +324,7 @@ impl<'a> DataflowBuilder<'a, mz_repr::Timestamp> {
         dataflow.export_index(id, index_description, on_type);
 
         // Optimize the dataflow across views, and any other ways that appeal.
-        mz_transform::optimize_dataflow(&mut dataflow, &self.index_oracle())?;
+        mz_transform::optimize_dataflow(&mut dataflow, &self.index_oracle(), self.catalog)?;
 
         Ok(dataflow)
     }
@@ -361,7 +361,7 @@ impl<'a> DataflowBuilder<'a, mz_repr::Timestamp> {
         dataflow.export_sink(id, sink_description);
 
         // Optimize the dataflow across views, and any other ways that appeal.
-        mz_transform::optimize_dataflow(dataflow, &self.index_oracle())?;
+        mz_transform::optimize_dataflow(dataflow, &self.index_oracle(), self.catalog)?;
 
         Ok(())
     }
@@ -417,6 +417,8 @@ impl<'a> DataflowBuilder<'a, mz_repr::Timestamp> {
         // we can retrieve monotonicity information from the parent source.
         match &source.data_source {
             DataSourceDesc::Ingestion(ingestion) => ingestion.desc.monotonic(),
+            // Webhook sources only ever append.
+            DataSourceDesc::Webhook(_) => true,
             DataSourceDesc::Introspection(_) | DataSourceDesc::Source => false,
         }
     }
@@ -692,14 +692,18 @@ impl<S: Append + 'static> Coordinator<S> {
                 }
                 Op::AlterSink { .. }
                 | Op::AlterSource { .. }
+                | Op::AlterTableAddColumn { .. }
                 | Op::DropTimeline(_)
                 | Op::RenameItem { .. }
                 | Op::UpdateComputeReplicaStatus { .. }
+                | Op::UpdateRelationStatistics { .. }
                 | Op::UpdateStorageUsage { .. }
                 | Op::UpdateSystemConfiguration { .. }
                 | Op::ResetSystemConfiguration { .. }
                 | Op::ResetAllSystemConfiguration { .. }
                 | Op::UpdateItem { .. }
+                | Op::UpdateOwner { .. }
+                | Op::UpdatePrivilege { .. }
                 | Op::UpdateRotatedKeys { .. } => {}
             }
         }
@@ -22,18 +22,19 @@ use mz_ore::now::EpochMillis;
 use mz_ore::task;
 use mz_persist_client::ShardId;
 use mz_sql::ast::Statement;
+use mz_sql::catalog::CatalogError;
 use mz_sql::plan::{Plan, SendDiffsPlan};
 use mz_stash::Append;
 
 use crate::command::{Command, ExecuteResponse};
 use crate::coord::appends::{BuiltinTableUpdateSource, Deferred};
 use crate::util::ResultExt;
-use crate::{catalog, AdapterNotice};
+use crate::{catalog, AdapterError, AdapterNotice};
 
 use crate::coord::timestamp_selection::TimestampContext;
 use crate::coord::{
-    Coordinator, CreateSourceStatementReady, Message, PendingReadTxn, SendDiffs,
-    SinkConnectionReady,
+    AnalyzeComplete, Coordinator, CreateSinkStatementReady, CreateSourceStatementReady, Message,
+    PendingReadTxn, SendDiffs, SinkConnectionReady,
 };
 
 impl<S: Append + 'static> Coordinator<S> {
@@ -48,11 +49,15 @@ impl<S: Append + 'static> Coordinator<S> {
             Message::CreateSourceStatementReady(ready) => {
                 self.message_create_source_statement_ready(ready).await
             }
+            Message::CreateSinkStatementReady(ready) => {
+                self.message_create_sink_statement_ready(ready).await
+            }
             Message::SinkConnectionReady(ready) => self.message_sink_connection_ready(ready).await,
             Message::WriteLockGrant(write_lock_guard) => {
                 self.message_write_lock_grant(write_lock_guard).await;
             }
             Message::SendDiffs(diffs) => self.message_send_diffs(diffs),
+            Message::AnalyzeComplete(complete) => self.message_analyze_complete(complete).await,
             Message::GroupCommitInitiate => {
                 self.try_group_commit().await;
             }
@@ -378,6 +383,43 @@ impl<S: Append + 'static> Coordinator<S> {
         tx.send(result, session);
     }
 
+    #[tracing::instrument(level = "debug", skip(self, tx, session))]
+    async fn message_create_sink_statement_ready(
+        &mut self,
+        CreateSinkStatementReady {
+            mut session,
+            tx,
+            result,
+            params,
+            depends_on,
+            original_stmt,
+            otel_ctx,
+        }: CreateSinkStatementReady,
+    ) {
+        otel_ctx.attach_as_parent();
+
+        // As with sources, ensure that all dependencies still exist after
+        // purification, and repurify the original statement if not.
+        if !depends_on
+            .iter()
+            .all(|id| self.catalog.try_get_entry(id).is_some())
+        {
+            self.handle_execute_inner(original_stmt, params, session, tx)
+                .await;
+            return;
+        }
+
+        let stmt = match result {
+            Ok(stmt) => stmt,
+            Err(e) => return tx.send(Err(e), session),
+        };
+
+        match self.plan_statement(&mut session, Statement::CreateSink(stmt), &params) {
+            Ok(plan) => self.sequence_plan(tx, session, plan, depends_on).await,
+            Err(e) => tx.send(Err(e), session),
+        }
+    }
+
     #[tracing::instrument(level = "debug", skip(self, session_and_tx))]
     async fn message_sink_connection_ready(
         &mut self,
@@ -506,6 +548,35 @@ impl<S: Append + 'static> Coordinator<S> {
         }
     }
 
+    #[tracing::instrument(level = "debug", skip_all, fields(id))]
+    async fn message_analyze_complete(
+        &mut self,
+        AnalyzeComplete {
+            session,
+            tx,
+            id,
+            statistics,
+        }: AnalyzeComplete,
+    ) {
+        event!(Level::TRACE, statistics = format!("{:?}", statistics));
+        let result = match statistics {
+            // The relation may have been dropped while its statistics were
+            // being collected.
+            Ok(_) if self.catalog.try_get_entry(&id).is_none() => Err(AdapterError::SqlCatalog(
+                CatalogError::UnknownItem(id.to_string()),
+            )),
+            Ok(statistics) => self
+                .catalog_transact(
+                    Some(&session),
+                    vec![catalog::Op::UpdateRelationStatistics { id, statistics }],
+                )
+                .await
+                .map(|()| ExecuteResponse::Analyzed),
+            Err(e) => Err(e),
+        };
+        tx.send(result, session);
+    }
+
     #[tracing::instrument(level = "debug", skip_all)]
     async fn message_compute_instance_status(&mut self, event: ComputeInstanceEvent) {
         event!(Level::TRACE, event = format!("{:?}", event));
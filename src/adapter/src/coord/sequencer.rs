@@ -16,6 +16,7 @@ use std::time::Duration;
 use anyhow::anyhow;
 use maplit::btreeset;
 use timely::progress::{Antichain, Timestamp as TimelyTimestamp};
+use timely::PartialOrder;
 use tokio::sync::{mpsc, OwnedMutexGuard};
 use tracing::{event, warn, Level};
 
@@ -25,7 +26,8 @@ use mz_compute_client::controller::{
 };
 use mz_compute_client::types::dataflows::{BuildDesc, DataflowDesc, IndexDesc};
 use mz_compute_client::types::sinks::{
-    ComputeSinkConnection, ComputeSinkDesc, SinkAsOf, SubscribeSinkConnection,
+    ComputeSinkConnection, ComputeSinkDesc, SinkAsOf, SubscribeEnvelope, SubscribeEnvelopeKind,
+    SubscribeSinkConnection,
 };
 use mz_expr::{
     permutation_for_arrangement, CollectionPlan, MirRelationExpr, MirScalarExpr,
@@ -39,19 +41,25 @@ use mz_sql::catalog::{
     CatalogComputeInstance, CatalogError, CatalogItem as SqlCatalogItem, CatalogItemType,
     CatalogTypeDetails,
 };
-use mz_sql::names::QualifiedObjectName;
+use mz_sql::names::{
+    ObjectId, QualifiedObjectName, ResolvedDatabaseSpecifier, RoleId, SchemaSpecifier,
+};
 use mz_sql::plan::{
-    AlterIndexResetOptionsPlan, AlterIndexSetOptionsPlan, AlterItemRenamePlan, AlterSecretPlan,
-    AlterSinkPlan, AlterSourcePlan, AlterSystemResetAllPlan, AlterSystemResetPlan,
-    AlterSystemSetPlan, CreateComputeInstancePlan, CreateComputeReplicaPlan, CreateConnectionPlan,
-    CreateDatabasePlan, CreateIndexPlan, CreateMaterializedViewPlan, CreateRolePlan,
-    CreateSchemaPlan, CreateSecretPlan, CreateSinkPlan, CreateSourcePlan, CreateTablePlan,
-    CreateTypePlan, CreateViewPlan, DropComputeInstancesPlan, DropComputeReplicasPlan,
-    DropDatabasePlan, DropItemsPlan, DropRolesPlan, DropSchemaPlan, ExecutePlan, ExplainPlan,
-    FetchPlan, IndexOption, InsertPlan, MaterializedView, MutationKind, OptimizerConfig, PeekPlan,
-    Plan, PlanKind, QueryWhen, RaisePlan, ReadThenWritePlan, ResetVariablePlan, RotateKeysPlan,
-    SendDiffsPlan, SetVariablePlan, ShowVariablePlan, SubscribeFrom, SubscribePlan, View,
+    AlterIndexResetOptionsPlan, AlterIndexSetOptionsPlan, AlterItemRenamePlan, AlterOwnerPlan,
+    AlterSecretPlan, AlterSinkPlan, AlterSourcePlan, AlterSystemResetAllPlan, AlterSystemResetPlan,
+    AlterSystemSetPlan, AlterTableAddColumnPlan, AnalyzePlan, CopyFromPlan,
+    CreateComputeInstancePlan, CreateComputeReplicaPlan, CreateConnectionPlan, CreateDatabasePlan,
+    CreateIndexPlan, CreateMaterializedViewPlan, CreateRolePlan, CreateSchemaPlan,
+    CreateSecretPlan, CreateSinkPlan, CreateSourcePlan, CreateTablePlan, CreateTypePlan,
+    CreateViewPlan, DropComputeInstancesPlan, DropComputeReplicasPlan, DropDatabasePlan,
+    DropItemsPlan, DropRolesPlan, DropSchemaPlan, ExecutePlan, ExplainPlan, FetchPlan,
+    GrantPrivilegesPlan, IndexOption, InsertPlan, MaterializedView, MutationKind, OptimizerConfig,
+    PeekPlan, Plan, PlanKind, QueryWhen, RaisePlan, ReadThenWritePlan, ResetVariablePlan,
+    RevokePrivilegesPlan, RotateKeysPlan, SendDiffsPlan, SetVariablePlan, ShowVariablePlan,
+    SubscribeFrom, SubscribeOutput, SubscribePlan, UpdatePrivilege, View,
 };
+use mz_sql::rbac::AclMode;
+use mz_sql::DEFAULT_SCHEMA;
 use mz_ssh_util::keys::SshKeyPairSet;
 use mz_stash::Append;
 use mz_storage_client::controller::{CollectionDescription, DataSource, ReadPolicy, StorageError};
@@ -64,7 +72,8 @@ use crate::catalog::builtin::{
 };
 use crate::catalog::{
     self, Catalog, CatalogItem, ComputeInstance, Connection, DataSourceDesc, Ingestion,
-    SerializedComputeReplicaLocation, StorageSinkConnectionState, SYSTEM_USER,
+    SerializedComputeReplicaLocation, StorageSinkConnectionState, UpdatePrivilegeVariant,
+    SYSTEM_USER,
 };
 use crate::command::{Command, ExecuteResponse};
 use crate::coord::appends::{BuiltinTableUpdateSource, Deferred, DeferredPlan, PendingWriteTxn};
@@ -72,22 +81,22 @@ use crate::coord::dataflows::{prep_relation_expr, prep_scalar_expr, ExprPrepStyl
 use crate::coord::timeline::TimelineContext;
 use crate::coord::timestamp_selection::TimestampContext;
 use crate::coord::{
-    peek, Coordinator, Message, PendingReadTxn, PendingTxn, SendDiffs, SinkConnectionReady,
-    DEFAULT_LOGICAL_COMPACTION_WINDOW_TS,
+    peek, AnalyzeComplete, Coordinator, Message, PendingReadTxn, PendingTxn, SendDiffs,
+    SinkConnectionReady, DEFAULT_LOGICAL_COMPACTION_WINDOW_TS,
 };
 use crate::error::AdapterError;
 use crate::explain_new::optimizer_trace::OptimizerTrace;
 use crate::metrics;
 use crate::notice::AdapterNotice;
-use crate::session::vars::{
-    IsolationLevel, CLUSTER_VAR_NAME, DATABASE_VAR_NAME, REAL_TIME_RECENCY_VAR_NAME,
-};
+use crate::session::vars::{CLUSTER_VAR_NAME, DATABASE_VAR_NAME, REAL_TIME_RECENCY_VAR_NAME};
 use crate::session::{
     EndTransactionAction, PreparedStatement, Session, TransactionOps, TransactionStatus, Var,
-    WriteOp,
+    WriteOp, DEFAULT_DATABASE_NAME,
 };
 use crate::subscribe::PendingSubscribe;
-use crate::util::{send_immediate_rows, ClientTransmitter, ComputeSinkId};
+use crate::util::{
+    compute_config, send_immediate_rows, storage_config, ClientTransmitter, ComputeSinkId,
+};
 use crate::{guard_write_critical_section, session, PeekResponseUnary};
 
 use super::timestamp_selection::{TimestampExplanation, TimestampSource};
@@ -115,6 +124,10 @@ impl<S: Append + 'static> Coordinator<S> {
             }
         }
 
+        if let Err(e) = self.check_privileges(&session, &plan, &depends_on) {
+            return tx.send(Err(e), session);
+        }
+
         match plan {
             Plan::CreateSource(plan) => {
                 let source_id = match self.catalog.allocate_user_id().await {
@@ -310,6 +323,9 @@ impl<S: Append + 'static> Coordinator<S> {
             Plan::ReadThenWrite(plan) => {
                 self.sequence_read_then_write(tx, session, plan).await;
             }
+            Plan::Analyze(plan) => {
+                self.sequence_analyze(tx, session, plan).await;
+            }
             Plan::AlterNoop(plan) => {
                 tx.send(
                     Ok(ExecuteResponse::AlteredObject(plan.object_type)),
@@ -337,6 +353,12 @@ impl<S: Append + 'static> Coordinator<S> {
             Plan::AlterSource(plan) => {
                 tx.send(self.sequence_alter_source(&session, plan).await, session);
             }
+            Plan::AlterTableAddColumn(plan) => {
+                tx.send(
+                    self.sequence_alter_table_add_column(&session, plan).await,
+                    session,
+                );
+            }
             Plan::AlterSystemSet(plan) => {
                 tx.send(
                     self.sequence_alter_system_set(&session, plan).await,
@@ -461,6 +483,21 @@ impl<S: Append + 'static> Coordinator<S> {
             Plan::RotateKeys(RotateKeysPlan { id }) => {
                 tx.send(self.sequence_rotate_keys(&session, id).await, session);
             }
+            Plan::AlterOwner(plan) => {
+                tx.send(self.sequence_alter_owner(&session, plan).await, session);
+            }
+            Plan::GrantPrivileges(plan) => {
+                tx.send(
+                    self.sequence_grant_privileges(&session, plan).await,
+                    session,
+                );
+            }
+            Plan::RevokePrivileges(plan) => {
+                tx.send(
+                    self.sequence_revoke_privileges(&session, plan).await,
+                    session,
+                );
+            }
         }
     }
 
@@ -487,8 +524,8 @@ impl<S: Append + 'static> Coordinator<S> {
             let source_oid = self.catalog.allocate_oid()?;
             let source = catalog::Source {
                 create_sql: plan.source.create_sql,
-                data_source: match plan.source.ingestion {
-                    Some(ingestion) => {
+                data_source: match plan.source.data_source {
+                    mz_sql::plan::DataSourceDesc::Ingestion(ingestion) => {
                         let host_config = self.catalog.resolve_storage_host_config(
                             plan.host_config,
                             // Undefined sizes permitted in unsafe mode
@@ -501,13 +538,20 @@ impl<S: Append + 'static> Coordinator<S> {
                             host_config,
                         })
                     }
-                    None => {
+                    mz_sql::plan::DataSourceDesc::Source => {
                         assert!(
                             matches!(plan.host_config, mz_sql::plan::StorageHostConfig::Undefined),
                             "subsources must not have a host config defined"
                         );
                         DataSourceDesc::Source
                     }
+                    mz_sql::plan::DataSourceDesc::Webhook(webhook) => {
+                        assert!(
+                            matches!(plan.host_config, mz_sql::plan::StorageHostConfig::Undefined),
+                            "webhook sources must not have a host config defined"
+                        );
+                        DataSourceDesc::Webhook(webhook)
+                    }
                 },
                 desc: plan.source.desc,
                 timeline: plan.timeline,
@@ -531,6 +575,7 @@ impl<S: Append + 'static> Coordinator<S> {
                             &crate::catalog::builtin::MZ_SOURCE_STATUS_HISTORY,
                         ));
 
+                    let is_webhook = matches!(source.data_source, DataSourceDesc::Webhook(_));
                     let (data_source, status_collection_id) = match source.data_source {
                         DataSourceDesc::Ingestion(ingestion) => {
                             let mut source_imports = BTreeMap::new();
@@ -564,12 +609,19 @@ impl<S: Append + 'static> Coordinator<S> {
                                 source_status_collection_id,
                             )
                         }
-                        DataSourceDesc::Source => (DataSource::Other, None),
+                        DataSourceDesc::Source | DataSourceDesc::Webhook(_) => {
+                            (DataSource::Other, None)
+                        }
                         DataSourceDesc::Introspection(_) => {
                             unreachable!("cannot create sources with introspection data sources")
                         }
                     };
 
+                    // Like tables, webhook sources are written by the
+                    // coordinator, so their initial validity is determined the
+                    // same way.
+                    let webhook_since_ts = is_webhook.then(|| self.peek_local_write_ts());
+
                     self.controller
                         .storage
                         .create_collections(vec![(
@@ -579,16 +631,39 @@ impl<S: Append + 'static> Coordinator<S> {
                                 data_source,
                                 since: None,
                                 status_collection_id,
+                                missing_values: None,
                             },
                         )])
                         .await
                         .unwrap();
 
+                    if let Some(since_ts) = webhook_since_ts {
+                        let policy = ReadPolicy::ValidFrom(Antichain::from_elem(since_ts));
+                        self.controller
+                            .storage
+                            .set_read_policy(vec![(source_id, policy)]);
+                    }
+
                     self.initialize_storage_read_policies(
                         vec![source_id],
                         Some(DEFAULT_LOGICAL_COMPACTION_WINDOW_TS),
                     )
                     .await;
+
+                    if let Some(since_ts) = webhook_since_ts {
+                        // Advance the new source to a timestamp higher than the
+                        // current read timestamp so that it is immediately
+                        // readable.
+                        let upper = since_ts.step_forward();
+                        let appends = vec![(source_id, Vec::new(), upper)];
+                        self.controller
+                            .storage
+                            .append(appends)
+                            .expect("invalid webhook source upper initialization")
+                            .await
+                            .expect("One-shot dropped while waiting synchronously")
+                            .unwrap();
+                    }
                 }
                 Ok(ExecuteResponse::CreatedSource)
             }
@@ -752,12 +827,66 @@ impl<S: Append + 'static> Coordinator<S> {
         let op = catalog::Op::CreateRole {
             name: plan.name,
             oid,
+            superuser: plan.superuser,
         };
         self.catalog_transact(Some(session), vec![op])
             .await
             .map(|_| ExecuteResponse::CreatedRole)
     }
 
+    /// Creates the role of a user that logs in for the first time.
+    ///
+    /// The role is not a superuser, but it is granted the privileges to create
+    /// objects in the default database, schema, and cluster, so that a newly
+    /// provisioned user can get started without a superuser's help.
+    pub(crate) async fn sequence_create_login_role(
+        &mut self,
+        session: &Session,
+    ) -> Result<(), AdapterError> {
+        let plan = CreateRolePlan {
+            name: session.user().name.to_string(),
+            superuser: false,
+        };
+        self.sequence_create_role(session, plan).await?;
+
+        let mut grants = Vec::new();
+        if let Ok(database) = self.catalog.resolve_database(DEFAULT_DATABASE_NAME) {
+            let database_spec = ResolvedDatabaseSpecifier::Id(database.id);
+            grants.push((
+                ObjectId::Database(database.id),
+                AclMode::all_for(ObjectType::Database),
+            ));
+            if let Ok(schema) = self.catalog.resolve_schema_in_database(
+                &database_spec,
+                DEFAULT_SCHEMA,
+                session.conn_id(),
+            ) {
+                grants.push((
+                    ObjectId::Schema((database_spec, schema.id)),
+                    AclMode::all_for(ObjectType::Schema),
+                ));
+            }
+        }
+        if let Ok(cluster) = self.catalog.resolve_compute_instance("default") {
+            grants.push((
+                ObjectId::Cluster(cluster.id),
+                AclMode::all_for(ObjectType::Cluster),
+            ));
+        }
+
+        let grantee = self.catalog.state().session_role_id(Some(session));
+        let ops = grants
+            .into_iter()
+            .map(|(target_id, acl_mode)| catalog::Op::UpdatePrivilege {
+                target_id,
+                grantee,
+                acl_mode,
+                variant: UpdatePrivilegeVariant::Grant,
+            })
+            .collect();
+        self.catalog_transact(Some(session), ops).await
+    }
+
     // Utility function used by both `sequence_create_compute_instance`
     // and `sequence_create_compute_replica`. Chooses the availability zone
     // for a replica arbitrarily based on some state (currently: the number of replicas
@@ -915,11 +1044,9 @@ impl<S: Append + 'static> Coordinator<S> {
             .into_iter()
             .map(|(log, id)| (log.variant.clone(), id))
             .collect();
-        self.controller.compute.create_instance(
-            instance_id,
-            arranged_logs,
-            self.catalog.system_config().max_result_size(),
-        )?;
+        self.controller
+            .compute
+            .create_instance(instance_id, arranged_logs)?;
         for (replica_id, replica) in instance.replicas_by_id.clone() {
             self.controller
                 .active_compute()
@@ -1117,6 +1244,7 @@ impl<S: Append + 'static> Coordinator<S> {
             create_sql: table.create_sql,
             desc: table.desc,
             defaults: table.defaults,
+            missing_values: Some(table.missing_values),
             conn_id,
             depends_on,
             custom_logical_compaction_window: None,
@@ -1134,7 +1262,10 @@ impl<S: Append + 'static> Coordinator<S> {
                 // Determine the initial validity for the table.
                 let since_ts = self.peek_local_write_ts();
 
-                let collection_desc = table.desc.clone().into();
+                let collection_desc = CollectionDescription {
+                    missing_values: table.missing_values.clone(),
+                    ..table.desc.clone().into()
+                };
                 self.controller
                     .storage
                     .create_collections(vec![(table_id, collection_desc)])
@@ -1552,6 +1683,7 @@ impl<S: Append + 'static> Coordinator<S> {
                             data_source: DataSource::Other,
                             since: Some(as_of),
                             status_collection_id: None,
+                            missing_values: None,
                         },
                     )])
                     .await
@@ -1907,7 +2039,11 @@ impl<S: Append + 'static> Coordinator<S> {
             ObjectType::Type => ExecuteResponse::DroppedType,
             ObjectType::Secret => ExecuteResponse::DroppedSecret,
             ObjectType::Connection => ExecuteResponse::DroppedConnection,
-            ObjectType::Role | ObjectType::Cluster | ObjectType::ClusterReplica => {
+            ObjectType::Role
+            | ObjectType::Cluster
+            | ObjectType::ClusterReplica
+            | ObjectType::Database
+            | ObjectType::Schema => {
                 unreachable!("handled through their respective sequence_drop functions")
             }
             ObjectType::Object => unreachable!("generic OBJECT cannot be dropped"),
@@ -2053,8 +2189,7 @@ impl<S: Append + 'static> Coordinator<S> {
                 return;
             }
             Ok((Some(TransactionOps::Peeks(timestamp_context)), _))
-                if session.vars().transaction_isolation()
-                    == &IsolationLevel::StrictSerializable =>
+                if session.vars().transaction_isolation().is_linearizable() =>
             {
                 self.strict_serializable_reads_tx
                     .send(PendingReadTxn::Read {
@@ -2395,7 +2530,7 @@ impl<S: Append + 'static> Coordinator<S> {
         );
 
         // Optimize the dataflow across views, and any other ways that appeal.
-        mz_transform::optimize_dataflow(&mut dataflow, &builder.index_oracle())?;
+        mz_transform::optimize_dataflow(&mut dataflow, &builder.index_oracle(), builder.catalog)?;
 
         // At this point, `dataflow_plan` contains our best optimized dataflow.
         // We will check the plan to see if there is a fast path to escape full dataflow construction.
@@ -2432,7 +2567,7 @@ impl<S: Append + 'static> Coordinator<S> {
         // of the main coord thread.
         Ok(
             if session.vars().real_time_recency()
-                && session.vars().transaction_isolation() == &IsolationLevel::StrictSerializable
+                && session.vars().transaction_isolation().is_linearizable()
                 && !session.contains_read_timestamp()
             {
                 // We should have prevented anyone from turning on rtr outside of unsafe
@@ -2458,6 +2593,8 @@ impl<S: Append + 'static> Coordinator<S> {
             when,
             copy_to,
             emit_progress,
+            output,
+            up_to,
         } = plan;
 
         let compute_instance = self.catalog.active_compute_instance(session)?;
@@ -2471,7 +2608,12 @@ impl<S: Append + 'static> Coordinator<S> {
             session.add_transaction_ops(TransactionOps::Subscribe)?;
         }
 
-        let make_sink_desc = |coord: &mut Coordinator<S>, from, from_desc, uses| {
+        let up_to = match up_to {
+            Some(up_to) => Antichain::from_elem(self.evaluate_when(up_to, session, "UP TO")?),
+            None => Antichain::new(),
+        };
+
+        let make_sink_desc = |coord: &mut Coordinator<S>, from, from_desc: RelationDesc, uses| {
             // Determine the frontier of updates to subscribe *from*.
             // Updates greater or equal to this frontier will be produced.
             let id_bundle = coord
@@ -2490,11 +2632,42 @@ impl<S: Append + 'static> Coordinator<S> {
                 )?
                 .timestamp_context
                 .antichain();
+            if !PartialOrder::less_equal(&frontier, &up_to) {
+                coord_bail!(
+                    "UP TO ({:?}) must be greater than or equal to AS OF ({:?})",
+                    up_to.elements(),
+                    frontier.elements()
+                );
+            }
+
+            let envelope = match &output {
+                SubscribeOutput::Diffs => None,
+                SubscribeOutput::EnvelopeUpsert { key_indices } => {
+                    Some((SubscribeEnvelopeKind::Upsert, key_indices))
+                }
+                SubscribeOutput::EnvelopeDebezium { key_indices } => {
+                    Some((SubscribeEnvelopeKind::Debezium, key_indices))
+                }
+            }
+            .map(|(kind, key_indices)| SubscribeEnvelope {
+                kind,
+                key_indices: key_indices.clone(),
+                // If the key contains any of the keys of the collection, the
+                // sink doesn't need to check it.
+                key_is_unique: from_desc
+                    .typ()
+                    .keys
+                    .iter()
+                    .any(|key| key.iter().all(|col| key_indices.contains(col))),
+            });
 
             Ok::<_, AdapterError>(ComputeSinkDesc {
                 from,
                 from_desc,
-                connection: ComputeSinkConnection::Subscribe(SubscribeSinkConnection::default()),
+                connection: ComputeSinkConnection::Subscribe(SubscribeSinkConnection {
+                    envelope,
+                    up_to: up_to.clone(),
+                }),
                 as_of: SinkAsOf {
                     frontier,
                     strict: !with_snapshot,
@@ -2502,7 +2675,7 @@ impl<S: Append + 'static> Coordinator<S> {
             })
         };
 
-        let dataflow = match from {
+        let mut dataflow = match from {
             SubscribeFrom::Id(from_id) => {
                 check_no_invalid_log_reads(
                     &self.catalog,
@@ -2543,6 +2716,9 @@ impl<S: Append + 'static> Coordinator<S> {
                 dataflow
             }
         };
+        // Nothing beyond `up_to` is ever emitted, so there's no need to compute
+        // it.
+        dataflow.until = up_to;
 
         let (sink_id, sink_desc) = dataflow.sink_exports.iter().next().unwrap();
         self.active_conns
@@ -2554,6 +2730,15 @@ impl<S: Append + 'static> Coordinator<S> {
                 global_id: *sink_id,
             });
         let arity = sink_desc.from_desc.arity();
+        // The number of columns after the timestamp and progress columns.
+        let arity = match &output {
+            // The diff or the state, followed by the columns.
+            SubscribeOutput::Diffs | SubscribeOutput::EnvelopeUpsert { .. } => 1 + arity,
+            // The state, the key, and the old and new values.
+            SubscribeOutput::EnvelopeDebezium { key_indices } => {
+                1 + key_indices.len() + 2 * (arity - key_indices.len())
+            }
+        };
         let (tx, rx) = mpsc::unbounded_channel();
         let session_type = metrics::session_type_label_value(session);
         self.metrics
@@ -2566,6 +2751,7 @@ impl<S: Append + 'static> Coordinator<S> {
                 session_type,
                 channel: tx,
                 emit_progress,
+                emit_diffs: output == SubscribeOutput::Diffs,
                 arity,
             },
         );
@@ -2658,6 +2844,7 @@ impl<S: Append + 'static> Coordinator<S> {
                 mz_transform::optimize_dataflow(
                     &mut dataflow,
                     &self.index_oracle(compute_instance),
+                    self.catalog.state(),
                 )?;
 
                 let used_indexes = dataflow
@@ -3087,6 +3274,60 @@ impl<S: Append + 'static> Coordinator<S> {
         self.sequence_insert_constant(session, id, values.into_inner())
     }
 
+    /// Verifies that the role as which `session` executes may append to the
+    /// webhook source `id`, which requires owning it or holding INSERT on it.
+    pub(crate) fn check_webhook_privileges(
+        &self,
+        session: &Session,
+        id: GlobalId,
+    ) -> Result<(), AdapterError> {
+        let plan = Plan::SendDiffs(SendDiffsPlan {
+            id,
+            updates: Vec::new(),
+            kind: MutationKind::Insert,
+            returning: Vec::new(),
+        });
+        self.check_privileges(session, &plan, &Vec::new())
+    }
+
+    pub(crate) fn sequence_append_webhook(
+        &mut self,
+        session: &mut Session,
+        id: GlobalId,
+        rows: Vec<Row>,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        // The source may have been dropped since it was looked up, so we need
+        // to re-verify that it exists.
+        let desc = match self.catalog.try_get_entry(&id) {
+            Some(source) if source.is_webhook_source() => source.desc(
+                &self
+                    .catalog
+                    .resolve_full_name(source.name(), Some(session.conn_id())),
+            )?,
+            _ => {
+                return Err(AdapterError::SqlCatalog(CatalogError::UnknownItem(
+                    id.to_string(),
+                )))
+            }
+        };
+
+        // Privileges may have been revoked since the source was looked up.
+        self.check_webhook_privileges(session, id)?;
+
+        for row in &rows {
+            for (i, datum) in row.iter().enumerate() {
+                desc.constraints_met(i, &datum)?;
+            }
+        }
+        let diffs_plan = SendDiffsPlan {
+            id,
+            updates: rows.into_iter().map(|row| (row, 1)).collect(),
+            kind: MutationKind::Insert,
+            returning: Vec::new(),
+        };
+        self.sequence_send_diffs(session, diffs_plan)
+    }
+
     // ReadThenWrite is a plan whose writes depend on the results of a
     // read. This works by doing a Peek then queuing a SendDiffs. No writes
     // or read-then-writes can occur between the Peek and SendDiff otherwise a
@@ -3372,6 +3613,91 @@ impl<S: Append + 'static> Coordinator<S> {
         });
     }
 
+    /// Collects statistics about a relation by peeking the aggregation in
+    /// `plan`, and records them in the catalog once the peek completes.
+    async fn sequence_analyze(
+        &mut self,
+        tx: ClientTransmitter<ExecuteResponse>,
+        mut session: Session,
+        plan: AnalyzePlan,
+    ) {
+        let AnalyzePlan {
+            id,
+            selection,
+            finishing,
+            arity,
+        } = plan;
+
+        let peek_response = match self
+            .sequence_peek(
+                &mut session,
+                PeekPlan {
+                    source: selection,
+                    when: QueryWhen::Immediately,
+                    finishing,
+                    copy_to: None,
+                },
+            )
+            .await
+        {
+            Ok(resp) => resp,
+            Err(e) => {
+                tx.send(Err(e), session);
+                return;
+            }
+        };
+
+        let timeout_dur = *session.vars().statement_timeout();
+
+        let internal_cmd_tx = self.internal_cmd_tx.clone();
+        task::spawn(|| format!("sequence_analyze:{id}"), async move {
+            let statistics = match peek_response {
+                ExecuteResponse::SendingRows {
+                    future: batch,
+                    span: _,
+                } => match tokio::time::timeout(timeout_dur, batch).await {
+                    Ok(PeekResponseUnary::Rows(rows)) => {
+                        let (row_count, distinct_counts) =
+                            mz_sql::plan::analyze_estimates(arity, &rows);
+                        Ok(catalog::RelationStatistics {
+                            row_count,
+                            distinct_counts,
+                        })
+                    }
+                    Ok(PeekResponseUnary::Canceled) => {
+                        Err(AdapterError::Unstructured(anyhow!("execution canceled")))
+                    }
+                    Ok(PeekResponseUnary::Error(e)) => Err(AdapterError::Unstructured(anyhow!(e))),
+                    Err(_) => {
+                        // We timed out, so remove the pending peek. This is
+                        // best-effort and doesn't guarantee we won't
+                        // receive a response.
+                        // It is not an error for this timeout to occur after `internal_cmd_rx` has been dropped.
+                        let result = internal_cmd_tx.send(Message::RemovePendingPeeks {
+                            conn_id: session.conn_id(),
+                        });
+                        if let Err(e) = result {
+                            warn!("internal_cmd_rx dropped before we could send: {:?}", e);
+                        }
+                        Err(AdapterError::StatementTimeout)
+                    }
+                },
+                _ => Err(AdapterError::Unstructured(anyhow!("expected SendingRows"))),
+            };
+
+            // It is not an error for these results to be ready after `internal_cmd_rx` has been dropped.
+            let result = internal_cmd_tx.send(Message::AnalyzeComplete(AnalyzeComplete {
+                session,
+                tx,
+                id,
+                statistics,
+            }));
+            if let Err(e) = result {
+                warn!("internal_cmd_rx dropped before we could send: {:?}", e);
+            }
+        });
+    }
+
     async fn sequence_alter_item_rename(
         &mut self,
         session: &Session,
@@ -3388,6 +3714,84 @@ impl<S: Append + 'static> Coordinator<S> {
         }
     }
 
+    async fn sequence_alter_owner(
+        &mut self,
+        session: &Session,
+        plan: AlterOwnerPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let op = catalog::Op::UpdateOwner {
+            id: plan.id,
+            new_owner: plan.new_owner,
+        };
+        match self.catalog_transact(Some(session), vec![op]).await {
+            Ok(()) => Ok(ExecuteResponse::AlteredObject(plan.object_type)),
+            Err(err) => Err(err),
+        }
+    }
+
+    async fn sequence_grant_privileges(
+        &mut self,
+        session: &Session,
+        GrantPrivilegesPlan {
+            update_privileges,
+            grantees,
+        }: GrantPrivilegesPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        self.sequence_update_privileges(
+            session,
+            update_privileges,
+            grantees,
+            UpdatePrivilegeVariant::Grant,
+        )
+        .await
+    }
+
+    async fn sequence_revoke_privileges(
+        &mut self,
+        session: &Session,
+        RevokePrivilegesPlan {
+            update_privileges,
+            revokees,
+        }: RevokePrivilegesPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        self.sequence_update_privileges(
+            session,
+            update_privileges,
+            revokees,
+            UpdatePrivilegeVariant::Revoke,
+        )
+        .await
+    }
+
+    async fn sequence_update_privileges(
+        &mut self,
+        session: &Session,
+        update_privileges: Vec<UpdatePrivilege>,
+        grantees: Vec<RoleId>,
+        variant: UpdatePrivilegeVariant,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let mut ops = Vec::with_capacity(update_privileges.len() * grantees.len());
+        for UpdatePrivilege {
+            acl_mode,
+            target_id,
+        } in update_privileges
+        {
+            for grantee in &grantees {
+                ops.push(catalog::Op::UpdatePrivilege {
+                    target_id,
+                    grantee: *grantee,
+                    acl_mode,
+                    variant,
+                });
+            }
+        }
+        self.catalog_transact(Some(session), ops).await?;
+        Ok(match variant {
+            UpdatePrivilegeVariant::Grant => ExecuteResponse::GrantedPrivilege,
+            UpdatePrivilegeVariant::Revoke => ExecuteResponse::RevokedPrivilege,
+        })
+    }
+
     fn sequence_alter_index_set_options(
         &mut self,
         plan: AlterIndexSetOptionsPlan,
@@ -3515,6 +3919,26 @@ impl<S: Append + 'static> Coordinator<S> {
         Ok(ExecuteResponse::AlteredObject(ObjectType::Source))
     }
 
+    async fn sequence_alter_table_add_column(
+        &mut self,
+        session: &Session,
+        AlterTableAddColumnPlan { id, table, .. }: AlterTableAddColumnPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let op = catalog::Op::AlterTableAddColumn {
+            id,
+            create_sql: table.create_sql,
+        };
+        self.catalog_transact(Some(session), vec![op]).await?;
+
+        // The table's existing data is not rewritten; readers created from now
+        // on fill in the new column from `missing_values`.
+        self.controller
+            .storage
+            .alter_table_desc(id, table.desc, table.missing_values)?;
+
+        Ok(ExecuteResponse::AlteredObject(ObjectType::Table))
+    }
+
     fn extract_secret(
         &mut self,
         session: &Session,
@@ -3572,7 +3996,8 @@ impl<S: Append + 'static> Coordinator<S> {
     ) -> Result<ExecuteResponse, AdapterError> {
         self.is_user_allowed_to_alter_system(session)?;
         use mz_sql::ast::{SetVariableValue, Value};
-        let update_max_result_size = name == session::vars::MAX_RESULT_SIZE.name();
+        let update_compute_config = session::vars::is_compute_config_var(&name);
+        let update_storage_config = session::vars::is_storage_config_var(&name);
         let update_metrics_retention = name == session::vars::METRICS_RETENTION.name();
         let op = match value {
             SetVariableValue::Default => catalog::Op::ResetSystemConfiguration { name },
@@ -3589,8 +4014,11 @@ impl<S: Append + 'static> Coordinator<S> {
             },
         };
         self.catalog_transact(Some(session), vec![op]).await?;
-        if update_max_result_size {
-            self.update_max_result_size();
+        if update_compute_config {
+            self.update_compute_config();
+        }
+        if update_storage_config {
+            self.update_storage_config().await;
         }
         if update_metrics_retention {
             self.update_metrics_retention();
@@ -3604,12 +4032,16 @@ impl<S: Append + 'static> Coordinator<S> {
         AlterSystemResetPlan { name }: AlterSystemResetPlan,
     ) -> Result<ExecuteResponse, AdapterError> {
         self.is_user_allowed_to_alter_system(session)?;
-        let update_max_result_size = name == session::vars::MAX_RESULT_SIZE.name();
+        let update_compute_config = session::vars::is_compute_config_var(&name);
+        let update_storage_config = session::vars::is_storage_config_var(&name);
         let update_metrics_retention = name == session::vars::METRICS_RETENTION.name();
         let op = catalog::Op::ResetSystemConfiguration { name };
         self.catalog_transact(Some(session), vec![op]).await?;
-        if update_max_result_size {
-            self.update_max_result_size();
+        if update_compute_config {
+            self.update_compute_config();
+        }
+        if update_storage_config {
+            self.update_storage_config().await;
         }
         if update_metrics_retention {
             self.update_metrics_retention();
@@ -3625,7 +4057,8 @@ impl<S: Append + 'static> Coordinator<S> {
         self.is_user_allowed_to_alter_system(session)?;
         let op = catalog::Op::ResetAllSystemConfiguration {};
         self.catalog_transact(Some(session), vec![op]).await?;
-        self.update_max_result_size();
+        self.update_compute_config();
+        self.update_storage_config().await;
         self.update_metrics_retention();
         Ok(ExecuteResponse::AlteredSystemConfiguration)
     }
@@ -3641,16 +4074,17 @@ impl<S: Append + 'static> Coordinator<S> {
         }
     }
 
-    fn update_max_result_size(&mut self) {
-        let mut compute = self.controller.active_compute();
-        for compute_instance in self.catalog.compute_instances() {
-            compute
-                .update_max_result_size(
-                    compute_instance.id,
-                    self.catalog.system_config().max_result_size(),
-                )
-                .unwrap();
-        }
+    fn update_compute_config(&mut self) {
+        let config_params = compute_config(self.catalog.system_config());
+        self.controller.compute.update_configuration(config_params);
+    }
+
+    async fn update_storage_config(&mut self) {
+        let config_params = storage_config(self.catalog.system_config());
+        self.controller
+            .storage
+            .update_configuration(config_params)
+            .await;
     }
 
     fn update_metrics_retention(&mut self) {
@@ -3696,6 +4130,274 @@ impl<S: Append + 'static> Coordinator<S> {
         Ok(GlobalId::Transient(id))
     }
 
+    /// Verifies that the role as which `session` executes holds the privileges
+    /// that `plan` requires. Superusers bypass all privilege checks.
+    fn check_privileges(
+        &self,
+        session: &Session,
+        plan: &Plan,
+        depends_on: &Vec<GlobalId>,
+    ) -> Result<(), AdapterError> {
+        let state = self.catalog.state();
+        if state.is_superuser(session) {
+            return Ok(());
+        }
+        let role_id = state.session_role_id(Some(session));
+        let conn_id = session.conn_id();
+
+        let describe = |id: &ObjectId| {
+            format!(
+                "{} {}",
+                state.get_object_type(id).to_string().to_lowercase(),
+                state.get_object_name(id, conn_id)
+            )
+        };
+        let require_superuser = |action: &str| -> Result<(), AdapterError> {
+            Err(AdapterError::Unauthorized(format!(
+                "must be superuser to {action}"
+            )))
+        };
+        let require_owner = |id: ObjectId| {
+            if state.get_privileges(&id).owner_id == role_id {
+                Ok(())
+            } else {
+                Err(AdapterError::Unauthorized(format!(
+                    "must be owner of {}",
+                    describe(&id)
+                )))
+            }
+        };
+        let require_privilege = |id: ObjectId, acl_mode: AclMode| {
+            if state
+                .get_privileges(&id)
+                .effective(role_id)
+                .contains(acl_mode)
+            {
+                Ok(())
+            } else {
+                Err(AdapterError::Unauthorized(format!(
+                    "permission denied for {}",
+                    describe(&id)
+                )))
+            }
+        };
+        // Temporary schemas are private to the session that created them, so
+        // they require no privileges.
+        let require_schema_privilege =
+            |name: &QualifiedObjectName, acl_mode: AclMode| match name.qualifiers.schema_spec {
+                SchemaSpecifier::Temporary => Ok(()),
+                schema_spec => require_privilege(
+                    ObjectId::Schema((name.qualifiers.database_spec, schema_spec)),
+                    acl_mode,
+                ),
+            };
+
+        // The relation that `plan` writes to, which requires a write privilege
+        // rather than SELECT.
+        let mut write_target = None;
+        let mut reads_dependencies = false;
+        match plan {
+            Plan::CreateRole(_) => require_superuser("create roles")?,
+            Plan::DropRoles(_) => require_superuser("drop roles")?,
+            Plan::CreateDatabase(_) => require_superuser("create databases")?,
+            Plan::CreateComputeInstance(_) => require_superuser("create clusters")?,
+            Plan::AlterSystemSet(_) | Plan::AlterSystemReset(_) | Plan::AlterSystemResetAll(_) => {
+                require_superuser("alter system parameters")?
+            }
+            Plan::CreateSchema(CreateSchemaPlan { database_spec, .. }) => {
+                if let ResolvedDatabaseSpecifier::Id(id) = database_spec {
+                    require_privilege(ObjectId::Database(*id), AclMode::CREATE)?;
+                }
+            }
+            Plan::CreateComputeReplica(CreateComputeReplicaPlan { of_cluster, .. }) => {
+                let cluster = self.catalog.resolve_compute_instance(of_cluster)?;
+                require_owner(ObjectId::Cluster(cluster.id))?;
+            }
+            Plan::CreateConnection(CreateConnectionPlan { name, .. })
+            | Plan::CreateSecret(CreateSecretPlan { name, .. })
+            | Plan::CreateSource(CreateSourcePlan { name, .. })
+            | Plan::CreateSink(CreateSinkPlan { name, .. })
+            | Plan::CreateTable(CreateTablePlan { name, .. })
+            | Plan::CreateType(CreateTypePlan { name, .. }) => {
+                require_schema_privilege(name, AclMode::CREATE)?;
+                reads_dependencies = true;
+            }
+            Plan::CreateView(CreateViewPlan { name, replace, .. }) => {
+                require_schema_privilege(name, AclMode::CREATE)?;
+                if let Some(id) = replace {
+                    require_owner(ObjectId::Item(*id))?;
+                }
+                reads_dependencies = true;
+            }
+            Plan::CreateMaterializedView(CreateMaterializedViewPlan {
+                name,
+                materialized_view,
+                replace,
+                ..
+            }) => {
+                require_schema_privilege(name, AclMode::CREATE)?;
+                require_privilege(
+                    ObjectId::Cluster(materialized_view.compute_instance),
+                    AclMode::CREATE,
+                )?;
+                if let Some(id) = replace {
+                    require_owner(ObjectId::Item(*id))?;
+                }
+                reads_dependencies = true;
+            }
+            Plan::CreateIndex(CreateIndexPlan { index, .. }) => {
+                require_owner(ObjectId::Item(index.on))?;
+                require_privilege(ObjectId::Cluster(index.compute_instance), AclMode::CREATE)?;
+            }
+            Plan::DropDatabase(DropDatabasePlan { id }) => {
+                if let Some(id) = id {
+                    require_owner(ObjectId::Database(*id))?;
+                }
+            }
+            Plan::DropSchema(DropSchemaPlan { id }) => {
+                if let Some((database_id, schema_id)) = id {
+                    require_owner(ObjectId::Schema((
+                        ResolvedDatabaseSpecifier::Id(*database_id),
+                        SchemaSpecifier::Id(*schema_id),
+                    )))?;
+                }
+            }
+            Plan::DropComputeInstances(DropComputeInstancesPlan { names }) => {
+                for name in names {
+                    let cluster = self.catalog.resolve_compute_instance(name)?;
+                    require_owner(ObjectId::Cluster(cluster.id))?;
+                }
+            }
+            Plan::DropComputeReplicas(DropComputeReplicasPlan { names }) => {
+                for (cluster_name, _) in names {
+                    let cluster = self.catalog.resolve_compute_instance(cluster_name)?;
+                    require_owner(ObjectId::Cluster(cluster.id))?;
+                }
+            }
+            Plan::DropItems(DropItemsPlan { items, .. }) => {
+                for id in items {
+                    require_owner(ObjectId::Item(*id))?;
+                }
+            }
+            Plan::AlterIndexSetOptions(AlterIndexSetOptionsPlan { id, .. })
+            | Plan::AlterIndexResetOptions(AlterIndexResetOptionsPlan { id, .. })
+            | Plan::AlterSink(AlterSinkPlan { id, .. })
+            | Plan::AlterSource(AlterSourcePlan { id, .. })
+            | Plan::AlterTableAddColumn(AlterTableAddColumnPlan { id, .. })
+            | Plan::AlterItemRename(AlterItemRenamePlan { id, .. })
+            | Plan::AlterSecret(AlterSecretPlan { id, .. })
+            | Plan::RotateKeys(RotateKeysPlan { id }) => require_owner(ObjectId::Item(*id))?,
+            Plan::AlterOwner(AlterOwnerPlan { id, new_owner, .. }) => {
+                require_owner(*id)?;
+                // Without role membership, only a superuser can give an object
+                // away to another role.
+                if *new_owner != role_id {
+                    return Err(AdapterError::Unauthorized(format!(
+                        "must be member of role {}",
+                        state.get_role_name(new_owner)
+                    )));
+                }
+            }
+            Plan::GrantPrivileges(GrantPrivilegesPlan {
+                update_privileges, ..
+            })
+            | Plan::RevokePrivileges(RevokePrivilegesPlan {
+                update_privileges, ..
+            }) => {
+                for UpdatePrivilege { target_id, .. } in update_privileges {
+                    require_owner(*target_id)?;
+                }
+            }
+            Plan::Peek(_) | Plan::Subscribe(_) => {
+                if let Ok(cluster) = self.catalog.active_compute_instance(session) {
+                    require_privilege(ObjectId::Cluster(cluster.id), AclMode::USAGE)?;
+                }
+                reads_dependencies = true;
+            }
+            Plan::Explain(_) => reads_dependencies = true,
+            Plan::Analyze(AnalyzePlan { id, .. }) => {
+                require_owner(ObjectId::Item(*id))?;
+                if let Ok(cluster) = self.catalog.active_compute_instance(session) {
+                    require_privilege(ObjectId::Cluster(cluster.id), AclMode::USAGE)?;
+                }
+                reads_dependencies = true;
+            }
+            Plan::Insert(InsertPlan { id, .. }) | Plan::CopyFrom(CopyFromPlan { id, .. }) => {
+                require_privilege(ObjectId::Item(*id), AclMode::INSERT)?;
+                write_target = Some(*id);
+                reads_dependencies = true;
+            }
+            Plan::SendDiffs(SendDiffsPlan { id, kind, .. }) => {
+                // Diffs that are sent without having been planned from SQL, like
+                // webhook appends, may also be written by the relation's owner.
+                let acl_mode = match kind {
+                    MutationKind::Insert => AclMode::INSERT,
+                    MutationKind::Update => AclMode::UPDATE,
+                    MutationKind::Delete => AclMode::DELETE,
+                };
+                if require_owner(ObjectId::Item(*id)).is_err() {
+                    require_privilege(ObjectId::Item(*id), acl_mode)?;
+                }
+            }
+            Plan::ReadThenWrite(ReadThenWritePlan { id, kind, .. }) => {
+                let acl_mode = match kind {
+                    MutationKind::Insert => AclMode::INSERT,
+                    MutationKind::Update => AclMode::UPDATE,
+                    MutationKind::Delete => AclMode::DELETE,
+                };
+                require_privilege(ObjectId::Item(*id), acl_mode)?;
+                write_target = Some(*id);
+                reads_dependencies = true;
+            }
+            Plan::DiscardTemp
+            | Plan::DiscardAll
+            | Plan::EmptyQuery
+            | Plan::ShowAllVariables
+            | Plan::ShowVariable(_)
+            | Plan::SetVariable(_)
+            | Plan::ResetVariable(_)
+            | Plan::StartTransaction(_)
+            | Plan::CommitTransaction
+            | Plan::AbortTransaction
+            | Plan::SendRows(_)
+            | Plan::AlterNoop(_)
+            | Plan::Declare(_)
+            | Plan::Fetch(_)
+            | Plan::Close(_)
+            | Plan::Prepare(_)
+            | Plan::Execute(_)
+            | Plan::Deallocate(_)
+            | Plan::Raise(_) => {}
+        }
+
+        if reads_dependencies {
+            for id in depends_on {
+                let entry = state.get_entry(id);
+                if !id.is_system() {
+                    require_schema_privilege(entry.name(), AclMode::USAGE)?;
+                }
+                if write_target == Some(*id) {
+                    continue;
+                }
+                let acl_mode = match entry.item().typ() {
+                    CatalogItemType::Table
+                    | CatalogItemType::Source
+                    | CatalogItemType::View
+                    | CatalogItemType::MaterializedView => AclMode::SELECT,
+                    CatalogItemType::Type
+                    | CatalogItemType::Secret
+                    | CatalogItemType::Connection => AclMode::USAGE,
+                    CatalogItemType::Sink | CatalogItemType::Index | CatalogItemType::Func => {
+                        AclMode::empty()
+                    }
+                };
+                require_privilege(ObjectId::Item(*id), acl_mode)?;
+            }
+        }
+
+        Ok(())
+    }
+
     /// TODO(jkosh44) This function will verify the privileges for the mz_introspection user.
     ///  All of the privileges are hard coded into this function. In the future if we ever add
     ///  a more robust privileges framework, then this function should be replaced with that
@@ -3760,14 +4462,19 @@ impl<S: Append + 'static> Coordinator<S> {
             | Plan::AlterIndexResetOptions(_)
             | Plan::AlterSink(_)
             | Plan::AlterSource(_)
+            | Plan::AlterTableAddColumn(_)
             | Plan::AlterItemRename(_)
             | Plan::AlterSecret(_)
             | Plan::AlterSystemSet(_)
             | Plan::AlterSystemReset(_)
             | Plan::AlterSystemResetAll(_)
             | Plan::ReadThenWrite(_)
+            | Plan::Analyze(_)
             | Plan::Raise(_)
-            | Plan::RotateKeys(_) => {
+            | Plan::RotateKeys(_)
+            | Plan::AlterOwner(_)
+            | Plan::GrantPrivileges(_)
+            | Plan::RevokePrivileges(_) => {
                 return Err(AdapterError::Unauthorized(
                     "user 'mz_introspection' is unauthorized to perform this action".into(),
                 ))
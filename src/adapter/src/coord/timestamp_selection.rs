@@ -29,7 +29,7 @@ use crate::coord::dataflows::{prep_scalar_expr, ExprPrepStyle};
 use crate::coord::id_bundle::CollectionIdBundle;
 use crate::coord::timeline::TimelineContext;
 use crate::coord::Coordinator;
-use crate::session::{vars, Session};
+use crate::session::Session;
 use crate::AdapterError;
 
 /// The timeline and timestamp context of a read.
@@ -135,12 +135,22 @@ impl<S: Append + 'static> Coordinator<S> {
 
         let since = self.least_valid_read(id_bundle);
 
-        // Initialize candidate to the minimum correct time.
+        // Initialize candidate to the minimum correct time. Each of the rules
+        // below may only advance the candidate, and we remember the last rule
+        // that advanced it (or would have, in case of a tie) so that `EXPLAIN
+        // TIMESTAMP` can report it.
         let mut candidate = Timestamp::minimum();
+        let mut rule = TimestampSelectionRule::Minimum;
+        let mut advance_to = |ts: Timestamp, by: TimestampSelectionRule| {
+            if candidate <= ts {
+                candidate = ts;
+                rule = by;
+            }
+        };
 
         if let Some(timestamp) = when.advance_to_timestamp() {
-            let ts = self.evaluate_when(timestamp, session)?;
-            candidate.join_assign(&ts);
+            let ts = self.evaluate_when(timestamp, session, "AS OF")?;
+            advance_to(ts, TimestampSelectionRule::AsOf);
         }
 
         let isolation_level = session.vars().transaction_isolation();
@@ -156,47 +166,58 @@ impl<S: Append + 'static> Coordinator<S> {
         let mut oracle_read_ts = None;
 
         if when.advance_to_since() {
-            candidate.advance_by(since.borrow());
+            // `since` is the join of the read frontiers of all inputs, so in
+            // practice it contains at most one element.
+            for ts in since.iter() {
+                advance_to(*ts, TimestampSelectionRule::Since);
+            }
         }
 
         // In order to use a timestamp oracle, we must be in the context of some timeline. In that
         // context we would use the timestamp oracle in the following scenarios:
-        // - The isolation level is Strict Serializable and the `when` allows us to use the
-        //   the timestamp oracle (ex: queries with no AS OF).
+        // - The isolation level is linearizable (i.e. Strict Serializable) and the `when` allows
+        //   us to use the the timestamp oracle (ex: queries with no AS OF).
         // - The `when` requires us to use the timestamp oracle (ex: read-then-write queries).
         if let Some(timeline) = &timeline {
             if when.must_advance_to_timeline_ts()
-                || (when.can_advance_to_timeline_ts()
-                    && isolation_level == &vars::IsolationLevel::StrictSerializable)
+                || (when.can_advance_to_timeline_ts() && isolation_level.is_linearizable())
             {
                 let timestamp_oracle = self.get_timestamp_oracle(timeline);
-                oracle_read_ts = Some(timestamp_oracle.read_ts());
-                candidate.join_assign(&oracle_read_ts.unwrap());
+                let read_ts = timestamp_oracle.read_ts();
+                oracle_read_ts = Some(read_ts);
+                advance_to(read_ts, TimestampSelectionRule::Oracle);
             }
         }
 
-        // We advance to the upper in the following scenarios:
-        // - The isolation level is Serializable and the `when` allows us to advance to upper (ex:
-        //   queries with no AS OF). We avoid using the upper in Strict Serializable to prevent
-        //   reading source data that is being written to in the future.
+        // We advance to the upper, i.e. the freshest timestamp that is readable across all
+        // inputs, in the following scenarios:
+        // - The isolation level is not linearizable (i.e. Serializable or weaker) and the `when`
+        //   allows us to advance to upper (ex: queries with no AS OF). We avoid using the upper
+        //   in Strict Serializable to prevent reading source data that is being written to in
+        //   the future.
         // - The isolation level is Strict Serializable but there is no timelines and the `when`
         //   allows us to advance to upper.
         // - The `when` requires us to advance to the upper (ex: read-then-write queries).
         if when.must_advance_to_upper()
             || (when.can_advance_to_upper()
-                && (isolation_level == &vars::IsolationLevel::Serializable || timeline.is_none()))
+                && (!isolation_level.is_linearizable() || timeline.is_none()))
         {
-            candidate.join_assign(&largest_not_in_advance_of_upper);
+            advance_to(
+                largest_not_in_advance_of_upper,
+                TimestampSelectionRule::Upper,
+            );
         }
 
         if let Some(real_time_recency_ts) = real_time_recency_ts {
             assert!(
-                session.vars().real_time_recency()
-                    && isolation_level == &vars::IsolationLevel::StrictSerializable,
+                session.vars().real_time_recency() && isolation_level.is_linearizable(),
                 "real time recency timestamp should only be supplied when real time recency \
                     is enabled and the isolation level is strict serializable"
             );
-            candidate.join_assign(&real_time_recency_ts);
+            advance_to(
+                real_time_recency_ts,
+                TimestampSelectionRule::RealTimeRecency,
+            );
         }
 
         // If the timestamp is greater or equal to some element in `since` we are
@@ -231,6 +252,7 @@ impl<S: Append + 'static> Coordinator<S> {
             upper,
             largest_not_in_advance_of_upper,
             oracle_read_ts,
+            rule,
         };
         self.metrics
             .determine_timestamp
@@ -325,16 +347,19 @@ impl<S: Append + 'static> Coordinator<S> {
         }
     }
 
-    fn evaluate_when(
+    /// Evaluates `timestamp`, the expression of the given clause, e.g.
+    /// `AS OF`, to a timestamp.
+    pub(crate) fn evaluate_when(
         &self,
         mut timestamp: MirScalarExpr,
         session: &Session,
+        clause: &str,
     ) -> Result<mz_repr::Timestamp, AdapterError> {
         let temp_storage = RowArena::new();
         prep_scalar_expr(self.catalog.state(), &mut timestamp, ExprPrepStyle::AsOf)?;
         let evaled = timestamp.eval(&[], &temp_storage)?;
         if evaled.is_null() {
-            coord_bail!("can't use {} as a mz_timestamp for AS OF", evaled);
+            coord_bail!("can't use {} as a mz_timestamp for {}", evaled, clause);
         }
         let ty = timestamp.typ(&[]);
         Ok(match ty.scalar_type {
@@ -352,8 +377,9 @@ impl<S: Append + 'static> Coordinator<S> {
             ScalarType::TimestampTz => evaled.unwrap_timestamptz().timestamp_millis().try_into()?,
             ScalarType::Timestamp => evaled.unwrap_timestamp().timestamp_millis().try_into()?,
             _ => coord_bail!(
-                "can't use {} as a mz_timestamp for AS OF",
-                self.catalog.for_session(session).humanize_column_type(&ty)
+                "can't use {} as a mz_timestamp for {}",
+                self.catalog.for_session(session).humanize_column_type(&ty),
+                clause
             ),
         })
     }
@@ -425,6 +451,46 @@ pub struct TimestampDetermination<T> {
     pub largest_not_in_advance_of_upper: T,
     /// The value of the timeline's oracle timestamp, if used.
     pub oracle_read_ts: Option<T>,
+    /// The rule that chose the timestamp.
+    pub rule: TimestampSelectionRule,
+}
+
+/// The rules that `determine_timestamp` uses to choose a query timestamp.
+///
+/// Each rule provides a lower bound for the timestamp, and the chosen
+/// timestamp is the largest of them. A rule is said to have chosen the
+/// timestamp if it provided that largest bound. Ties are attributed to the
+/// rule that was applied last, in the order of the variants below.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TimestampSelectionRule {
+    /// No rule applied, so the minimum timestamp was chosen.
+    Minimum,
+    /// The timestamp requested by an `AS OF` clause.
+    AsOf,
+    /// The read frontier of the inputs, as nothing newer was required.
+    Since,
+    /// The read timestamp of the timeline's timestamp oracle, which
+    /// linearizes the query with respect to previous writes.
+    Oracle,
+    /// The largest timestamp not in advance of the upper of the inputs, i.e.
+    /// the freshest timestamp that is readable across all inputs.
+    Upper,
+    /// The timestamp at which all data from the upstream sources of the inputs
+    /// that existed when the query began is guaranteed to be visible.
+    RealTimeRecency,
+}
+
+impl fmt::Display for TimestampSelectionRule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Minimum => "minimum",
+            Self::AsOf => "as of",
+            Self::Since => "since",
+            Self::Oracle => "oracle read timestamp",
+            Self::Upper => "largest not in advance of upper",
+            Self::RealTimeRecency => "real time recency",
+        })
+    }
 }
 
 impl<T: TimestampManipulation> TimestampDetermination<T> {
@@ -508,6 +574,11 @@ impl<T: fmt::Display + fmt::Debug + DisplayableInTimeline + TimestampManipulatio
                 .timestamp_or_default()
                 .display(timeline)
         )?;
+        writeln!(
+            f,
+            "      query timestamp chosen by: {}",
+            self.determination.rule
+        )?;
         if let Some(oracle_read_ts) = &self.determination.oracle_read_ts {
             writeln!(
                 f,
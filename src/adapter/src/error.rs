@@ -162,6 +162,12 @@ pub enum AdapterError {
         cluster_name: String,
         replica_name: String,
     },
+    /// The named webhook source does not exist.
+    UnknownWebhook {
+        database: String,
+        schema: String,
+        name: String,
+    },
     /// A generic error occurred.
     //
     // TODO(benesch): convert all those errors to structured errors.
@@ -466,6 +472,14 @@ impl fmt::Display for AdapterError {
                 f,
                 "cluster replica '{cluster_name}.{replica_name}' does not exist"
             ),
+            AdapterError::UnknownWebhook {
+                database,
+                schema,
+                name,
+            } => write!(
+                f,
+                "webhook source '{database}.{schema}.{name}' does not exist"
+            ),
             AdapterError::UnstableDependency { object_type, .. } => {
                 write!(f, "cannot create {object_type} with unstable dependencies")
             }
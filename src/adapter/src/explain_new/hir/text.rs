@@ -121,10 +121,21 @@ impl<'a> Displayable<'a, HirRelationExpr> {
                     Ok(())
                 })?;
             }
-            LetRec { bindings, body } => {
+            LetRec {
+                bindings,
+                max_iters,
+                body,
+            } => {
                 writeln!(f, "{}Return", ctx.indent)?;
                 ctx.indented(|ctx| Displayable::from(body.as_ref()).fmt_text(f, ctx))?;
-                writeln!(f, "{}Where Recursive", ctx.indent)?;
+                match max_iters {
+                    Some(max_iters) => writeln!(
+                        f,
+                        "{}Where Recursive [recursion_limit={}]",
+                        ctx.indent, max_iters
+                    )?,
+                    None => writeln!(f, "{}Where Recursive", ctx.indent)?,
+                }
                 ctx.indented(|ctx| {
                     for (_name, id, value, _type) in bindings.iter().rev() {
                         // TODO: print the name and not the id
@@ -356,6 +367,12 @@ impl<'a> DisplayText for Displayable<'a, HirScalarExpr> {
                         Displayable::from(scalar.expr.as_ref()).fmt_text(f, ctx)?;
                         write!(f, ")")?
                     }
+                    WindowExprType::Aggregate(aggregate) => {
+                        write!(f, "{}(", aggregate.clone().into_expr())?;
+                        Displayable::from(aggregate.aggregate_expr.expr.as_ref())
+                            .fmt_text(f, ctx)?;
+                        write!(f, ")")?
+                    }
                 }
                 write!(f, " over (")?;
                 for (i, e) in expr.partition.iter().enumerate() {
@@ -130,6 +130,30 @@ impl<'a> DisplayText<PlanRenderingContext<'_, Plan>> for Displayable<'a, Plan> {
                     Ok(())
                 })?;
             }
+            LetRec {
+                ids,
+                values,
+                max_iters,
+                body,
+            } => {
+                writeln!(f, "{}Return", ctx.indent)?;
+                ctx.indented(|ctx| Displayable::from(body.as_ref()).fmt_text(f, ctx))?;
+                match max_iters {
+                    Some(max_iters) => writeln!(
+                        f,
+                        "{}Where Recursive [recursion_limit={}]",
+                        ctx.indent, max_iters
+                    )?,
+                    None => writeln!(f, "{}Where Recursive", ctx.indent)?,
+                }
+                ctx.indented(|ctx| {
+                    for (id, value) in ids.iter().zip(values.iter()).rev() {
+                        writeln!(f, "{}cte {} =", ctx.indent, *id)?;
+                        ctx.indented(|ctx| Displayable::from(value).fmt_text(f, ctx))?;
+                    }
+                    Ok(())
+                })?;
+            }
             Mfp {
                 input,
                 mfp,
@@ -291,6 +315,53 @@ impl<'a> DisplayText<PlanRenderingContext<'_, Plan>> for Displayable<'a, Plan> {
                 writeln!(f)?;
                 ctx.indented(|ctx| Displayable::from(input.as_ref()).fmt_text(f, ctx))?;
             }
+            Window {
+                input,
+                key_val_plan,
+                window_plan,
+                mfp,
+                input_key,
+            } => {
+                use mz_compute_client::plan::window::WindowPlan;
+                match window_plan {
+                    WindowPlan::RowNumber(_) => write!(f, "{}Window::RowNumber", ctx.indent)?,
+                    WindowPlan::LagLead(plan) => {
+                        write!(f, "{}Window::LagLead {:?}", ctx.indent, plan.lag_lead)?
+                    }
+                    WindowPlan::PrefixSum(plan) => {
+                        write!(f, "{}Window::PrefixSum {}", ctx.indent, plan.aggregate)?
+                    }
+                }
+                if window_plan.order_by().len() > 0 {
+                    let order_by = separated(", ", window_plan.order_by());
+                    write!(f, " order_by=[{}]", order_by)?;
+                }
+                writeln!(f)?;
+                ctx.indented(|ctx| {
+                    Displayable::from(mfp).fmt_text(f, ctx)?;
+                    if key_val_plan.val_plan.deref().is_identity() {
+                        writeln!(f, "{}val_plan=id", ctx.indent)?;
+                    } else {
+                        writeln!(f, "{}val_plan", ctx.indent)?;
+                        ctx.indented(|ctx| {
+                            Displayable::from(key_val_plan.val_plan.deref()).fmt_text(f, ctx)
+                        })?;
+                    }
+                    if key_val_plan.key_plan.deref().is_identity() {
+                        writeln!(f, "{}key_plan=id", ctx.indent)?;
+                    } else {
+                        writeln!(f, "{}key_plan", ctx.indent)?;
+                        ctx.indented(|ctx| {
+                            Displayable::from(key_val_plan.key_plan.deref()).fmt_text(f, ctx)
+                        })?;
+                    }
+                    if let Some(key) = input_key {
+                        let key = separated_text(", ", key.iter().map(Displayable::from));
+                        writeln!(f, "{}input_key={}", ctx.indent, key)?;
+                    }
+                    Displayable::from(input.as_ref()).fmt_text(f, ctx)
+                })?;
+            }
             Negate { input } => {
                 writeln!(f, "{}Negate", ctx.indent)?;
                 ctx.indented(|ctx| Displayable::from(input.as_ref()).fmt_text(f, ctx))?;
@@ -303,6 +303,11 @@ fn id_gen(expr: &MirRelationExpr) -> Result<impl Iterator<Item = LocalId>, Recur
     expr.visit_post(&mut |expr| {
         match expr {
             MirRelationExpr::Let { id, .. } => max_id = std::cmp::max(max_id, id.into()),
+            MirRelationExpr::LetRec { ids, .. } => {
+                for id in ids {
+                    max_id = std::cmp::max(max_id, id.into());
+                }
+            }
             _ => (),
         };
     })?;
@@ -323,6 +328,14 @@ fn enforce_let_id_sequence(expr: &mut MirRelationExpr) -> Result<(), RecursionLi
                 id_map.insert(id_old, LocalId::new(id_new));
                 std::mem::swap(id, &mut LocalId::new(id_new));
             }
+            MirRelationExpr::LetRec { ids, .. } => {
+                for id in ids.iter_mut() {
+                    let id_old = id.clone();
+                    let id_new = u64::cast_from(id_map.len());
+                    id_map.insert(id_old, LocalId::new(id_new));
+                    std::mem::swap(id, &mut LocalId::new(id_new));
+                }
+            }
             MirRelationExpr::Get {
                 id: Id::Local(id_old),
                 ..
@@ -441,9 +454,8 @@ fn normalize_lets_at_root(expr: &mut MirRelationExpr) {
                 None
             }
         }
-        MirRelationExpr::LetRec { .. } => {
-            unimplemented!("unclear how to normalize LetRec");
-        }
+        // Bindings are not lifted out of (or through) recursive bindings.
+        LetRec { .. } => None,
         Project { input, outputs } => {
             let is_modified = at_inner_most_let_body(input, |body| {
                 *body = Project {
@@ -125,8 +125,47 @@ impl<'a> Displayable<'a, MirRelationExpr> {
                     })?;
                 }
             }
-            MirRelationExpr::LetRec { .. } => {
-                unimplemented!("unclear how to format");
+            LetRec {
+                ids,
+                values,
+                max_iters,
+                body,
+            } => {
+                let bindings = ids.iter().zip(values.iter()).collect::<Vec<_>>();
+                let head = body.as_ref();
+
+                let with = match max_iters {
+                    Some(max_iters) => {
+                        format!("With Mutually Recursive [recursion_limit={}]", max_iters)
+                    }
+                    None => "With Mutually Recursive".to_string(),
+                };
+
+                if ctx.config.linear_chains {
+                    writeln!(f, "{}{}", ctx.indent, with)?;
+                    ctx.indented(|ctx| {
+                        for (id, value) in bindings.iter() {
+                            writeln!(f, "{}cte {} =", ctx.indent, *id)?;
+                            ctx.indented(|ctx| Displayable::from(*value).fmt_text(f, ctx))?;
+                        }
+                        Ok(())
+                    })?;
+                    write!(f, "{}Return", ctx.indent)?;
+                    self.fmt_attributes(f, ctx)?;
+                    ctx.indented(|ctx| Displayable::from(head).fmt_text(f, ctx))?;
+                } else {
+                    write!(f, "{}Return", ctx.indent)?;
+                    self.fmt_attributes(f, ctx)?;
+                    ctx.indented(|ctx| Displayable::from(head).fmt_text(f, ctx))?;
+                    writeln!(f, "{}{}", ctx.indent, with)?;
+                    ctx.indented(|ctx| {
+                        for (id, value) in bindings.iter().rev() {
+                            writeln!(f, "{}cte {} =", ctx.indent, *id)?;
+                            ctx.indented(|ctx| Displayable::from(*value).fmt_text(f, ctx))?;
+                        }
+                        Ok(())
+                    })?;
+                }
             }
             Get { id, .. } => {
                 match id {
@@ -49,7 +49,8 @@ pub mod telemetry;
 
 pub use crate::client::{Client, ConnClient, Handle, SessionClient};
 pub use crate::command::{
-    Canceled, ExecuteResponse, ExecuteResponseKind, RowsFuture, StartupMessage, StartupResponse,
+    Canceled, ExecuteResponse, ExecuteResponseKind, GetWebhookResponse, RowsFuture, StartupMessage,
+    StartupResponse,
 };
 pub use crate::coord::peek::PeekResponseUnary;
 pub use crate::coord::timestamp_selection::{TimestampContext, TimestampExplanation};
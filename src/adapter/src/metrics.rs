@@ -99,10 +99,12 @@ where
         StatementKind::AlterSecret => "alter_secret",
         StatementKind::AlterSink => "alter_sink",
         StatementKind::AlterSource => "alter_source",
+        StatementKind::AlterTableAddColumn => "alter_table_add_column",
         StatementKind::AlterSystemSet => "alter_system_set",
         StatementKind::AlterSystemReset => "alter_system_reset",
         StatementKind::AlterSystemResetAll => "alter_system_reset_all",
         StatementKind::AlterConnection => "alter_connection",
+        StatementKind::AlterOwner => "alter_owner",
         StatementKind::Discard => "discard",
         StatementKind::DropDatabase => "drop_database",
         StatementKind::DropSchema => "drop_schema",
@@ -119,6 +121,7 @@ where
         StatementKind::Rollback => "rollback",
         StatementKind::Subscribe => "subscribe",
         StatementKind::Explain => "explain",
+        StatementKind::ExplainAnalyze => "explain_analyze",
         StatementKind::Declare => "declare",
         StatementKind::Fetch => "fetch",
         StatementKind::Close => "close",
@@ -126,5 +129,8 @@ where
         StatementKind::Execute => "execute",
         StatementKind::Deallocate => "deallocate",
         StatementKind::Raise => "raise",
+        StatementKind::GrantPrivileges => "grant_privileges",
+        StatementKind::RevokePrivileges => "revoke_privileges",
+        StatementKind::Analyze => "analyze",
     }
 }
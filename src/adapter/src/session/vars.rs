@@ -17,6 +17,7 @@ use serde::Serialize;
 use uncased::UncasedStr;
 
 use mz_ore::cast;
+use mz_repr::adt::datetime::Timezone;
 use mz_sql::ast::{Ident, SetVariableValue, Value as AstValue};
 use mz_sql::DEFAULT_SCHEMA;
 use mz_sql_parser::ast::TransactionIsolationLevel;
@@ -194,10 +195,10 @@ const STANDARD_CONFORMING_STRINGS: ServerVar<bool> = ServerVar {
     internal: false,
 };
 
-const TIMEZONE: ServerVar<TimeZone> = ServerVar {
+const TIMEZONE: ServerVar<Timezone> = ServerVar {
     // TimeZone has nonstandard capitalization for historical reasons.
     name: UncasedStr::new("TimeZone"),
-    value: &TimeZone::UTC,
+    value: &Timezone::UTC,
     description: "Sets the time zone for displaying and interpreting time stamps (PostgreSQL).",
     internal: false,
 };
@@ -305,6 +306,16 @@ pub const MAX_RESULT_SIZE: ServerVar<u32> = ServerVar {
     internal: false,
 };
 
+/// The number of updates a persist source in a compute dataflow emits before
+/// yielding to other operators.
+pub const COMPUTE_PERSIST_SOURCE_YIELD_THRESHOLD: ServerVar<u32> = ServerVar {
+    name: UncasedStr::new("compute_persist_source_yield_threshold"),
+    value: &1_000_000,
+    description: "The number of updates a persist source in a compute dataflow emits before \
+                  yielding to other operators (Materialize).",
+    internal: true,
+};
+
 /// The logical compaction window for builtin tables and sources that have the
 /// `retained_metrics_relation` flag set.
 ///
@@ -318,6 +329,33 @@ pub const METRICS_RETENTION: ServerVar<Duration> = ServerVar {
     internal: true,
 };
 
+/// Controls [`mz_persist_client::cfg::DynamicConfig::stats_collection_enabled`].
+pub const PERSIST_STATS_COLLECTION_ENABLED: ServerVar<bool> = ServerVar {
+    name: UncasedStr::new("persist_stats_collection_enabled"),
+    value: &false,
+    description: "Whether to calculate and record statistics about the data stored in persist \
+                  to be used at read time, see persist_stats_filter_enabled (Materialize).",
+    internal: true,
+};
+
+/// Controls [`mz_persist_client::cfg::DynamicConfig::stats_filter_enabled`].
+pub const PERSIST_STATS_FILTER_ENABLED: ServerVar<bool> = ServerVar {
+    name: UncasedStr::new("persist_stats_filter_enabled"),
+    value: &false,
+    description: "Whether to use recorded statistics about the data stored in persist \
+                  to filter at read time, see persist_stats_collection_enabled (Materialize).",
+    internal: true,
+};
+
+/// Controls [`mz_persist_client::cfg::DynamicConfig::structured_parts_enabled`].
+pub const PERSIST_STRUCTURED_PARTS_ENABLED: ServerVar<bool> = ServerVar {
+    name: UncasedStr::new("persist_structured_parts_enabled"),
+    value: &false,
+    description: "Whether to write the data stored in persist split into one column per \
+                  column of the relation (Materialize).",
+    internal: true,
+};
+
 static DEFAULT_ALLOWED_CLUSTER_REPLICA_SIZES: Lazy<Vec<String>> = Lazy::new(Vec::new);
 static ALLOWED_CLUSTER_REPLICA_SIZES: Lazy<ServerVar<Vec<String>>> = Lazy::new(|| ServerVar {
     name: UncasedStr::new("allowed_cluster_replica_sizes"),
@@ -409,7 +447,7 @@ pub struct SessionVars {
     standard_conforming_strings: ServerVar<bool>,
     statement_timeout: SessionVar<Duration>,
     idle_in_transaction_session_timeout: SessionVar<Duration>,
-    timezone: SessionVar<TimeZone>,
+    timezone: SessionVar<Timezone>,
     transaction_isolation: SessionVar<IsolationLevel>,
     real_time_recency: SessionVar<bool>,
     emit_timestamp_notice: SessionVar<bool>,
@@ -680,7 +718,7 @@ impl SessionVars {
         } else if name == IDLE_IN_TRANSACTION_SESSION_TIMEOUT.name {
             self.idle_in_transaction_session_timeout.set(value, local)
         } else if name == TIMEZONE.name {
-            if let Ok(_) = TimeZone::parse(value) {
+            if let Ok(_) = Timezone::parse(value) {
                 self.timezone.set(value, local)
             } else {
                 Err(AdapterError::ConstrainedParameter {
@@ -908,7 +946,7 @@ impl SessionVars {
     }
 
     /// Returns the value of the `timezone` configuration parameter.
-    pub fn timezone(&self) -> &TimeZone {
+    pub fn timezone(&self) -> &Timezone {
         self.timezone.value()
     }
 
@@ -947,10 +985,14 @@ pub struct SystemVars {
     max_secrets: SystemVar<u32>,
     max_roles: SystemVar<u32>,
     max_result_size: SystemVar<u32>,
+    compute_persist_source_yield_threshold: SystemVar<u32>,
     allowed_cluster_replica_sizes: SystemVar<Vec<String>>, // TODO: BTreeSet<String> will be better
     window_functions: SystemVar<bool>,
     config_has_synced_once: SystemVar<bool>,
     metrics_retention: SystemVar<Duration>,
+    persist_stats_collection_enabled: SystemVar<bool>,
+    persist_stats_filter_enabled: SystemVar<bool>,
+    persist_structured_parts_enabled: SystemVar<bool>,
 }
 
 impl Default for SystemVars {
@@ -969,10 +1011,16 @@ impl Default for SystemVars {
             max_secrets: SystemVar::new(&MAX_SECRETS),
             max_roles: SystemVar::new(&MAX_ROLES),
             max_result_size: SystemVar::new(&MAX_RESULT_SIZE),
+            compute_persist_source_yield_threshold: SystemVar::new(
+                &COMPUTE_PERSIST_SOURCE_YIELD_THRESHOLD,
+            ),
             allowed_cluster_replica_sizes: SystemVar::new(&ALLOWED_CLUSTER_REPLICA_SIZES),
             window_functions: SystemVar::new(&WINDOW_FUNCTIONS),
             config_has_synced_once: SystemVar::new(&CONFIG_HAS_SYNCED_ONCE),
             metrics_retention: SystemVar::new(&METRICS_RETENTION),
+            persist_stats_collection_enabled: SystemVar::new(&PERSIST_STATS_COLLECTION_ENABLED),
+            persist_stats_filter_enabled: SystemVar::new(&PERSIST_STATS_FILTER_ENABLED),
+            persist_structured_parts_enabled: SystemVar::new(&PERSIST_STRUCTURED_PARTS_ENABLED),
         }
     }
 }
@@ -981,7 +1029,7 @@ impl SystemVars {
     /// Returns an iterator over the configuration parameters and their current
     /// values on disk.
     pub fn iter(&self) -> impl Iterator<Item = &dyn Var> {
-        let vars: [&dyn Var; 17] = [
+        let vars: [&dyn Var; 21] = [
             &self.max_aws_privatelink_connections,
             &self.max_tables,
             &self.max_sources,
@@ -995,10 +1043,14 @@ impl SystemVars {
             &self.max_secrets,
             &self.max_roles,
             &self.max_result_size,
+            &self.compute_persist_source_yield_threshold,
             &self.allowed_cluster_replica_sizes,
             &self.window_functions,
             &self.config_has_synced_once,
             &self.metrics_retention,
+            &self.persist_stats_collection_enabled,
+            &self.persist_stats_filter_enabled,
+            &self.persist_structured_parts_enabled,
         ];
         vars.into_iter()
     }
@@ -1052,6 +1104,8 @@ impl SystemVars {
             Ok(&self.max_roles)
         } else if name == MAX_RESULT_SIZE.name {
             Ok(&self.max_result_size)
+        } else if name == COMPUTE_PERSIST_SOURCE_YIELD_THRESHOLD.name {
+            Ok(&self.compute_persist_source_yield_threshold)
         } else if name == ALLOWED_CLUSTER_REPLICA_SIZES.name {
             Ok(&self.allowed_cluster_replica_sizes)
         } else if name == WINDOW_FUNCTIONS.name {
@@ -1060,6 +1114,12 @@ impl SystemVars {
             Ok(&self.config_has_synced_once)
         } else if name == METRICS_RETENTION.name {
             Ok(&self.metrics_retention)
+        } else if name == PERSIST_STATS_COLLECTION_ENABLED.name {
+            Ok(&self.persist_stats_collection_enabled)
+        } else if name == PERSIST_STATS_FILTER_ENABLED.name {
+            Ok(&self.persist_stats_filter_enabled)
+        } else if name == PERSIST_STRUCTURED_PARTS_ENABLED.name {
+            Ok(&self.persist_structured_parts_enabled)
         } else {
             Err(AdapterError::UnknownParameter(name.into()))
         }
@@ -1101,6 +1161,9 @@ impl SystemVars {
             self.max_roles.is_default(value)
         } else if name == MAX_RESULT_SIZE.name {
             self.max_result_size.is_default(value)
+        } else if name == COMPUTE_PERSIST_SOURCE_YIELD_THRESHOLD.name {
+            self.compute_persist_source_yield_threshold
+                .is_default(value)
         } else if name == ALLOWED_CLUSTER_REPLICA_SIZES.name {
             self.allowed_cluster_replica_sizes.is_default(value)
         } else if name == WINDOW_FUNCTIONS.name {
@@ -1109,6 +1172,12 @@ impl SystemVars {
             self.config_has_synced_once.is_default(value)
         } else if name == METRICS_RETENTION.name {
             self.metrics_retention.is_default(value)
+        } else if name == PERSIST_STATS_COLLECTION_ENABLED.name {
+            self.persist_stats_collection_enabled.is_default(value)
+        } else if name == PERSIST_STATS_FILTER_ENABLED.name {
+            self.persist_stats_filter_enabled.is_default(value)
+        } else if name == PERSIST_STRUCTURED_PARTS_ENABLED.name {
+            self.persist_structured_parts_enabled.is_default(value)
         } else {
             Err(AdapterError::UnknownParameter(name.into()))
         }
@@ -1159,6 +1228,8 @@ impl SystemVars {
             self.max_roles.set(value)
         } else if name == MAX_RESULT_SIZE.name {
             self.max_result_size.set(value)
+        } else if name == COMPUTE_PERSIST_SOURCE_YIELD_THRESHOLD.name {
+            self.compute_persist_source_yield_threshold.set(value)
         } else if name == ALLOWED_CLUSTER_REPLICA_SIZES.name {
             self.allowed_cluster_replica_sizes.set(value)
         } else if name == WINDOW_FUNCTIONS.name {
@@ -1167,6 +1238,12 @@ impl SystemVars {
             self.config_has_synced_once.set(value)
         } else if name == METRICS_RETENTION.name {
             self.metrics_retention.set(value)
+        } else if name == PERSIST_STATS_COLLECTION_ENABLED.name {
+            self.persist_stats_collection_enabled.set(value)
+        } else if name == PERSIST_STATS_FILTER_ENABLED.name {
+            self.persist_stats_filter_enabled.set(value)
+        } else if name == PERSIST_STRUCTURED_PARTS_ENABLED.name {
+            self.persist_structured_parts_enabled.set(value)
         } else {
             Err(AdapterError::UnknownParameter(name.into()))
         }
@@ -1212,6 +1289,8 @@ impl SystemVars {
             Ok(self.max_roles.reset())
         } else if name == MAX_RESULT_SIZE.name {
             Ok(self.max_result_size.reset())
+        } else if name == COMPUTE_PERSIST_SOURCE_YIELD_THRESHOLD.name {
+            Ok(self.compute_persist_source_yield_threshold.reset())
         } else if name == ALLOWED_CLUSTER_REPLICA_SIZES.name {
             Ok(self.allowed_cluster_replica_sizes.reset())
         } else if name == WINDOW_FUNCTIONS.name {
@@ -1220,6 +1299,12 @@ impl SystemVars {
             Ok(self.config_has_synced_once.reset())
         } else if name == METRICS_RETENTION.name {
             Ok(self.metrics_retention.reset())
+        } else if name == PERSIST_STATS_COLLECTION_ENABLED.name {
+            Ok(self.persist_stats_collection_enabled.reset())
+        } else if name == PERSIST_STATS_FILTER_ENABLED.name {
+            Ok(self.persist_stats_filter_enabled.reset())
+        } else if name == PERSIST_STRUCTURED_PARTS_ENABLED.name {
+            Ok(self.persist_structured_parts_enabled.reset())
         } else {
             Err(AdapterError::UnknownParameter(name.into()))
         }
@@ -1290,6 +1375,11 @@ impl SystemVars {
         *self.max_result_size.value()
     }
 
+    /// Returns the value of the `compute_persist_source_yield_threshold` configuration parameter.
+    pub fn compute_persist_source_yield_threshold(&self) -> u32 {
+        *self.compute_persist_source_yield_threshold.value()
+    }
+
     /// Returns the value of the `allowed_cluster_replica_sizes` configuration parameter.
     pub fn allowed_cluster_replica_sizes(&self) -> &Vec<String> {
         self.allowed_cluster_replica_sizes.value()
@@ -1308,6 +1398,40 @@ impl SystemVars {
     pub fn metrics_retention(&self) -> Duration {
         *self.metrics_retention.value()
     }
+
+    /// Returns the `persist_stats_collection_enabled` configuration parameter.
+    pub fn persist_stats_collection_enabled(&self) -> bool {
+        *self.persist_stats_collection_enabled.value()
+    }
+
+    /// Returns the `persist_stats_filter_enabled` configuration parameter.
+    pub fn persist_stats_filter_enabled(&self) -> bool {
+        *self.persist_stats_filter_enabled.value()
+    }
+
+    /// Returns the `persist_structured_parts_enabled` configuration parameter.
+    pub fn persist_structured_parts_enabled(&self) -> bool {
+        *self.persist_structured_parts_enabled.value()
+    }
+}
+
+/// Returns whether the named variable is a compute configuration parameter.
+pub fn is_compute_config_var(name: &str) -> bool {
+    name == MAX_RESULT_SIZE.name
+        || name == COMPUTE_PERSIST_SOURCE_YIELD_THRESHOLD.name
+        || is_persist_config_var(name)
+}
+
+/// Returns whether the named variable is a storage configuration parameter.
+pub fn is_storage_config_var(name: &str) -> bool {
+    is_persist_config_var(name)
+}
+
+/// Returns whether the named variable is a persist configuration parameter.
+fn is_persist_config_var(name: &str) -> bool {
+    name == PERSIST_STATS_COLLECTION_ENABLED.name
+        || name == PERSIST_STATS_FILTER_ENABLED.name
+        || name == PERSIST_STRUCTURED_PARTS_ENABLED.name
 }
 
 /// A `Var` represents a configuration parameter of an arbitrary type.
@@ -1944,45 +2068,15 @@ impl Value for ClientSeverity {
     }
 }
 
-/// List of valid time zones.
-///
-/// Names are following the tz database, but only time zones equivalent
-/// to UTC±00:00 are supported.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub enum TimeZone {
-    /// UTC
-    UTC,
-    /// Fixed offset from UTC, currently only "+00:00" is supported.
-    /// A string representation is kept here for compatibility with Postgres.
-    FixedOffset(&'static str),
-}
-
-impl TimeZone {
-    fn as_str(&self) -> &'static str {
-        match self {
-            TimeZone::UTC => "UTC",
-            TimeZone::FixedOffset(s) => s,
-        }
-    }
-}
-
-impl Value for TimeZone {
+impl Value for Timezone {
     const TYPE_NAME: &'static str = "string";
 
-    fn parse(s: &str) -> Result<Self::Owned, ()> {
-        let s = UncasedStr::new(s);
-
-        if s == TimeZone::UTC.as_str() {
-            Ok(TimeZone::UTC)
-        } else if s == "+00:00" {
-            Ok(TimeZone::FixedOffset("+00:00"))
-        } else {
-            Err(())
-        }
+    fn parse(s: &str) -> Result<Self, ()> {
+        s.parse().map_err(|_| ())
     }
 
     fn format(&self) -> String {
-        self.as_str().into()
+        self.to_string()
     }
 }
 
@@ -2007,6 +2101,24 @@ impl IsolationLevel {
         }
     }
 
+    /// Reports whether reads at this isolation level must be linearizable,
+    /// i.e. must reflect every write that completed before they started.
+    ///
+    /// Only Strict Serializable reads are linearized against the timestamp
+    /// oracle. We have no weaker implementations for the levels below
+    /// Serializable, so they all get Serializable's guarantees: reads see a
+    /// consistent snapshot at the freshest timestamp that is readable across
+    /// all of their inputs, without waiting on the timestamp oracle.
+    pub fn is_linearizable(&self) -> bool {
+        match self {
+            Self::ReadUncommitted
+            | Self::ReadCommitted
+            | Self::RepeatableRead
+            | Self::Serializable => false,
+            Self::StrictSerializable => true,
+        }
+    }
+
     fn valid_values() -> Vec<&'static str> {
         vec![
             Self::ReadUncommitted.as_str(),
@@ -2024,13 +2136,16 @@ impl Value for IsolationLevel {
     fn parse(s: &str) -> Result<Self::Owned, ()> {
         let s = UncasedStr::new(s);
 
-        // We don't have any optimizations for levels below Serializable,
-        // so we upgrade them all to Serializable.
-        if s == Self::ReadUncommitted.as_str()
-            || s == Self::ReadCommitted.as_str()
-            || s == Self::RepeatableRead.as_str()
-            || s == Self::Serializable.as_str()
-        {
+        // Like PostgreSQL, we remember the level that was requested, even
+        // though levels below Serializable get Serializable's guarantees. See
+        // `IsolationLevel::is_linearizable`.
+        if s == Self::ReadUncommitted.as_str() {
+            Ok(Self::ReadUncommitted)
+        } else if s == Self::ReadCommitted.as_str() {
+            Ok(Self::ReadCommitted)
+        } else if s == Self::RepeatableRead.as_str() {
+            Ok(Self::RepeatableRead)
+        } else if s == Self::Serializable.as_str() {
             Ok(Self::Serializable)
         } else if s == Self::StrictSerializable.as_str() {
             Ok(Self::StrictSerializable)
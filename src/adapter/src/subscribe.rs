@@ -27,7 +27,11 @@ pub struct PendingSubscribe {
     pub channel: mpsc::UnboundedSender<PeekResponseUnary>,
     /// Whether progress information should be emitted
     pub emit_progress: bool,
-    /// Number of columns in the output
+    /// Whether each update should be preceded by its diff. Updates that are
+    /// presented in an envelope by the sink carry no diff.
+    pub emit_diffs: bool,
+    /// Number of columns in the output, after the timestamp and progress
+    /// columns
     pub arity: usize,
 }
 
@@ -65,7 +69,9 @@ impl PendingSubscribe {
                                     packer.push(Datum::False);
                                 }
 
-                                packer.push(Datum::Int64(diff));
+                                if self.emit_diffs {
+                                    packer.push(Datum::Int64(diff));
+                                }
 
                                 packer.extend_by_row(&row);
 
@@ -98,7 +104,7 @@ impl PendingSubscribe {
                     packer.push(Datum::from(numeric::Numeric::from(upper[0])));
                     packer.push(Datum::True);
                     // Fill in the diff column and all table columns with NULL.
-                    for _ in 0..(self.arity + 1) {
+                    for _ in 0..self.arity {
                         packer.push(Datum::Null);
                     }
 
@@ -12,9 +12,11 @@ use std::fmt::Debug;
 use tokio::sync::mpsc::UnboundedSender;
 use tokio::sync::oneshot;
 
+use mz_compute_client::command::ComputeParameters;
 use mz_compute_client::controller::ComputeInstanceId;
 use mz_ore::halt;
 use mz_ore::soft_assert;
+use mz_persist_client::cfg::PersistParameters;
 use mz_repr::{GlobalId, RelationDesc, Row, ScalarType};
 use mz_sql::names::FullObjectName;
 use mz_sql::plan::StatementDesc;
@@ -23,11 +25,13 @@ use mz_sql_parser::ast::{
     CreateIndexStatement, FetchStatement, Ident, Raw, RawClusterName, RawObjectName, Statement,
 };
 use mz_stash::{Append, StashError};
+use mz_storage_client::client::StorageParameters;
 
 use crate::catalog::Catalog;
 use crate::command::{Command, Response};
 use crate::coord::Message;
 use crate::error::AdapterError;
+use crate::session::vars::SystemVars;
 use crate::session::{EndTransactionAction, Session};
 use crate::{ExecuteResponse, PeekResponseUnary};
 
@@ -285,3 +289,28 @@ impl ShouldHalt for StashError {
         self.is_fence()
     }
 }
+
+/// Returns the compute configuration parameters derived from the given system configuration.
+pub(crate) fn compute_config(config: &SystemVars) -> ComputeParameters {
+    ComputeParameters {
+        max_result_size: Some(config.max_result_size()),
+        persist_source_yield_threshold: Some(config.compute_persist_source_yield_threshold()),
+        persist: persist_config(config),
+    }
+}
+
+/// Returns the storage configuration parameters derived from the given system configuration.
+pub(crate) fn storage_config(config: &SystemVars) -> StorageParameters {
+    StorageParameters {
+        persist: persist_config(config),
+    }
+}
+
+/// Returns the persist configuration parameters derived from the given system configuration.
+fn persist_config(config: &SystemVars) -> PersistParameters {
+    PersistParameters {
+        stats_collection_enabled: Some(config.persist_stats_collection_enabled()),
+        stats_filter_enabled: Some(config.persist_stats_filter_enabled()),
+        structured_parts_enabled: Some(config.persist_structured_parts_enabled()),
+    }
+}
@@ -25,6 +25,7 @@ fn main() {
         .extern_path(".mz_expr.relation", "::mz_expr")
         .extern_path(".mz_expr.scalar", "::mz_expr")
         .extern_path(".mz_kafka_util.addr", "::mz_kafka_util")
+        .extern_path(".mz_persist_client.cfg", "::mz_persist_client::cfg")
         .extern_path(".mz_proto", "::mz_proto")
         .extern_path(".mz_postgres_util.desc", "::mz_postgres_util::desc")
         .extern_path(".mz_repr.adt.regex", "::mz_repr::adt::regex")
@@ -44,6 +45,7 @@ fn main() {
                 "compute-client/src/plan/reduce.proto",
                 "compute-client/src/plan/threshold.proto",
                 "compute-client/src/plan/top_k.proto",
+                "compute-client/src/plan/window.proto",
                 "compute-client/src/response.proto",
                 "compute-client/src/service.proto",
                 "compute-client/src/types/dataflows.proto",
@@ -22,6 +22,7 @@ use uuid::Uuid;
 
 use mz_expr::RowSetFinishing;
 use mz_ore::tracing::OpenTelemetryContext;
+use mz_persist_client::cfg::PersistParameters;
 use mz_proto::{any_uuid, IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
 use mz_repr::{GlobalId, Row};
 use mz_storage_client::client::ProtoAllowCompaction;
@@ -45,7 +46,7 @@ include!(concat!(env!("OUT_DIR"), "/mz_compute_client.command.rs"));
 /// After a timely runtime has been built with CreateTimely, a sequence of commands that have to be
 /// handled in the timely runtime can be sent: First a CreateInstance must be sent which activates
 /// logging sources. After this, any combination of CreateDataflows, AllowCompaction, Peek,
-/// UpdateMaxResultSize and CancelPeeks can be sent.
+/// UpdateConfiguration and CancelPeeks can be sent.
 ///
 /// Within this sequence, exactly one InitializationComplete has to be sent. Commands sent before
 /// InitializationComplete are buffered and are compacted. For example a Peek followed by a
@@ -97,7 +98,11 @@ pub enum ComputeCommand<T = mz_repr::Timestamp> {
         /// The identifiers of the peek requests to cancel.
         uuids: BTreeSet<Uuid>,
     },
-    UpdateMaxResultSize(u32),
+
+    /// Update compute instance configuration.
+    ///
+    /// Parameters that are not set in the command retain their current values.
+    UpdateConfiguration(ComputeParameters),
 }
 
 impl RustType<ProtoComputeCommand> for ComputeCommand<mz_repr::Timestamp> {
@@ -122,8 +127,8 @@ impl RustType<ProtoComputeCommand> for ComputeCommand<mz_repr::Timestamp> {
                 ComputeCommand::CancelPeeks { uuids } => CancelPeeks(ProtoCancelPeeks {
                     uuids: uuids.into_proto(),
                 }),
-                ComputeCommand::UpdateMaxResultSize(max_result_size) => {
-                    UpdateMaxResultSize(max_result_size.into_proto())
+                ComputeCommand::UpdateConfiguration(params) => {
+                    UpdateConfiguration(params.into_proto())
                 }
                 ComputeCommand::CreateTimely {
                     comm_config,
@@ -155,8 +160,8 @@ impl RustType<ProtoComputeCommand> for ComputeCommand<mz_repr::Timestamp> {
             Some(CancelPeeks(ProtoCancelPeeks { uuids })) => Ok(ComputeCommand::CancelPeeks {
                 uuids: uuids.into_rust()?,
             }),
-            Some(UpdateMaxResultSize(ProtoUpdateMaxResultSize { max_result_size })) => {
-                Ok(ComputeCommand::UpdateMaxResultSize(max_result_size))
+            Some(UpdateConfiguration(params)) => {
+                Ok(ComputeCommand::UpdateConfiguration(params.into_rust()?))
             }
             Some(CreateTimely(ProtoCreateTimely { comm_config, epoch })) => {
                 let comm_config = comm_config.ok_or_else(|| {
@@ -206,7 +211,8 @@ impl Arbitrary for ComputeCommand<mz_repr::Timestamp> {
                 ComputeCommand::CancelPeeks {
                     uuids: BTreeSet::from_iter(uuids.into_iter()),
                 }
-            })
+            }),
+            any::<ComputeParameters>().prop_map(ComputeCommand::UpdateConfiguration),
         ]
         .boxed()
     }
@@ -300,8 +306,44 @@ impl Ord for ComputeStartupEpoch {
 pub struct InstanceConfig {
     /// Configuration of logging sources.
     pub logging: LoggingConfig,
-    /// Max size in bytes of any result.
-    pub max_result_size: u32,
+}
+
+/// Compute instance configuration parameters.
+///
+/// Parameters can be set (`Some`) or unset (`None`). Unset parameters should be interpreted to
+/// mean "use the previous value".
+#[derive(Arbitrary, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ComputeParameters {
+    /// The maximum allowed size in bytes for results of peeks and subscribes.
+    pub max_result_size: Option<u32>,
+    /// The number of updates a persist source emits before yielding.
+    pub persist_source_yield_threshold: Option<u32>,
+    /// Persist client configuration.
+    pub persist: PersistParameters,
+}
+
+impl ComputeParameters {
+    /// Update the parameter values with the set ones from `other`.
+    pub fn update(&mut self, other: ComputeParameters) {
+        let ComputeParameters {
+            max_result_size,
+            persist_source_yield_threshold,
+            persist,
+        } = other;
+
+        if max_result_size.is_some() {
+            self.max_result_size = max_result_size;
+        }
+        if persist_source_yield_threshold.is_some() {
+            self.persist_source_yield_threshold = persist_source_yield_threshold;
+        }
+        self.persist.update(persist);
+    }
+
+    /// Return whether all parameters are unset.
+    pub fn all_unset(&self) -> bool {
+        *self == Self::default()
+    }
 }
 
 /// Configuration of the cluster we will spin up
@@ -319,7 +361,6 @@ impl RustType<ProtoInstanceConfig> for InstanceConfig {
     fn into_proto(&self) -> ProtoInstanceConfig {
         ProtoInstanceConfig {
             logging: Some(self.logging.into_proto()),
-            max_result_size: self.max_result_size,
         }
     }
 
@@ -328,7 +369,26 @@ impl RustType<ProtoInstanceConfig> for InstanceConfig {
             logging: proto
                 .logging
                 .into_rust_if_some("ProtoInstanceConfig::logging")?,
+        })
+    }
+}
+
+impl RustType<ProtoComputeParameters> for ComputeParameters {
+    fn into_proto(&self) -> ProtoComputeParameters {
+        ProtoComputeParameters {
+            max_result_size: self.max_result_size,
+            persist_source_yield_threshold: self.persist_source_yield_threshold,
+            persist: Some(self.persist.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: ProtoComputeParameters) -> Result<Self, TryFromProtoError> {
+        Ok(Self {
             max_result_size: proto.max_result_size,
+            persist_source_yield_threshold: proto.persist_source_yield_threshold,
+            persist: proto
+                .persist
+                .into_rust_if_some("ProtoComputeParameters::persist")?,
         })
     }
 }
@@ -432,18 +492,6 @@ impl RustType<ProtoPeek> for Peek {
     }
 }
 
-impl RustType<ProtoUpdateMaxResultSize> for u32 {
-    fn into_proto(&self) -> ProtoUpdateMaxResultSize {
-        ProtoUpdateMaxResultSize {
-            max_result_size: *self,
-        }
-    }
-
-    fn from_proto(proto: ProtoUpdateMaxResultSize) -> Result<Self, TryFromProtoError> {
-        Ok(proto.max_result_size)
-    }
-}
-
 fn empty_otel_ctx() -> impl Strategy<Value = OpenTelemetryContext> {
     (0..1).prop_map(|_| OpenTelemetryContext::empty())
 }
@@ -509,7 +557,7 @@ impl<T: timely::progress::Timestamp> ComputeCommandHistory<T> {
 
         let mut create_inst_command = None;
         let mut create_timely_command = None;
-        let mut update_max_result_size_command = None;
+        let mut final_configuration = ComputeParameters::default();
 
         let mut initialization_complete = false;
 
@@ -541,8 +589,8 @@ impl<T: timely::progress::Timestamp> ComputeCommandHistory<T> {
                 ComputeCommand::CancelPeeks { uuids } => {
                     live_cancels.extend(uuids);
                 }
-                update @ ComputeCommand::UpdateMaxResultSize(_) => {
-                    update_max_result_size_command = Some(update);
+                ComputeCommand::UpdateConfiguration(params) => {
+                    final_configuration.update(params);
                 }
             }
         }
@@ -595,7 +643,7 @@ impl<T: timely::progress::Timestamp> ComputeCommandHistory<T> {
         command_count += final_frontiers.len();
         command_count += live_peeks.len();
         command_count += live_cancels.len();
-        if update_max_result_size_command.is_some() {
+        if !final_configuration.all_unset() {
             command_count += 1;
         }
 
@@ -606,6 +654,11 @@ impl<T: timely::progress::Timestamp> ComputeCommandHistory<T> {
         if let Some(create_inst_command) = create_inst_command {
             self.commands.push(create_inst_command);
         }
+        // Apply the configuration before any commands whose handling might depend on it.
+        if !final_configuration.all_unset() {
+            self.commands
+                .push(ComputeCommand::UpdateConfiguration(final_configuration));
+        }
         self.dataflow_count = live_dataflows.len();
         if !live_dataflows.is_empty() {
             self.commands
@@ -627,9 +680,6 @@ impl<T: timely::progress::Timestamp> ComputeCommandHistory<T> {
         if initialization_complete {
             self.commands.push(ComputeCommand::InitializationComplete);
         }
-        if let Some(update_max_result_size_command) = update_max_result_size_command {
-            self.commands.push(update_max_result_size_command)
-        }
 
         self.reduced_count = command_count;
     }
@@ -683,6 +733,13 @@ mod tests {
             assert_eq!(actual.unwrap(), expect);
         }
 
+        #[test]
+        fn compute_parameters_protobuf_roundtrip(expect in any::<ComputeParameters>() ) {
+            let actual = protobuf_roundtrip::<_, ProtoComputeParameters>(&expect);
+            assert!(actual.is_ok());
+            assert_eq!(actual.unwrap(), expect);
+        }
+
         // TODO: Unignore after fixing #14543.
         #[test]
         #[ignore]
@@ -692,4 +749,50 @@ mod tests {
             assert_eq!(actual.unwrap(), expect);
         }
     }
+
+    #[test]
+    fn reduce_merges_configuration_updates() {
+        let peeks = std::collections::HashMap::<uuid::Uuid, ()>::new();
+        let instance_config = InstanceConfig {
+            logging: LoggingConfig::default(),
+        };
+
+        let mut history = ComputeCommandHistory::<mz_repr::Timestamp>::default();
+        history.push(
+            ComputeCommand::UpdateConfiguration(ComputeParameters {
+                max_result_size: Some(1),
+                ..Default::default()
+            }),
+            &peeks,
+        );
+        history.push(
+            ComputeCommand::CreateInstance(instance_config.clone()),
+            &peeks,
+        );
+        history.push(
+            ComputeCommand::UpdateConfiguration(ComputeParameters {
+                persist_source_yield_threshold: Some(10),
+                ..Default::default()
+            }),
+            &peeks,
+        );
+        history.push(
+            ComputeCommand::UpdateConfiguration(ComputeParameters {
+                max_result_size: Some(2),
+                ..Default::default()
+            }),
+            &peeks,
+        );
+        history.reduce();
+
+        let expected = vec![
+            ComputeCommand::CreateInstance(instance_config),
+            ComputeCommand::UpdateConfiguration(ComputeParameters {
+                max_result_size: Some(2),
+                persist_source_yield_threshold: Some(10),
+                ..Default::default()
+            }),
+        ];
+        assert_eq!(history.iter().cloned().collect::<Vec<_>>(), expected);
+    }
 }
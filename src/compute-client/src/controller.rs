@@ -53,9 +53,11 @@ use mz_expr::RowSetFinishing;
 use mz_orchestrator::{CpuLimit, MemoryLimit, NamespacedOrchestrator, ServiceProcessMetrics};
 use mz_ore::halt;
 use mz_ore::tracing::OpenTelemetryContext;
+use mz_persist_client::encryption::BlobEncryptionCliArgs;
 use mz_repr::{GlobalId, Row};
 use mz_storage_client::controller::{ReadPolicy, StorageController};
 
+use crate::command::ComputeParameters;
 use crate::logging::{LogVariant, LogView, LoggingConfig};
 use crate::response::{ComputeResponse, PeekResponse, SubscribeResponse};
 use crate::service::{ComputeClient, ComputeGrpcClient};
@@ -284,6 +286,8 @@ pub struct ComputeController<T> {
     stats_update_ticker: tokio::time::Interval,
     /// Set to `true` if `process` should produce a `ReplicaWriteFrontiers` next.
     stats_update_pending: bool,
+    /// Compute configuration to apply to new instances.
+    config: ComputeParameters,
 }
 
 impl<T> ComputeController<T> {
@@ -293,6 +297,7 @@ impl<T> ComputeController<T> {
         orchestrator: Arc<dyn NamespacedOrchestrator>,
         computed_image: String,
         init_container_image: Option<String>,
+        persist_blob_encryption: BlobEncryptionCliArgs,
         envd_epoch: NonZeroI64,
     ) -> Self {
         let mut stats_update_ticker = tokio::time::interval(Duration::from_secs(1));
@@ -305,6 +310,7 @@ impl<T> ComputeController<T> {
                 orchestrator,
                 computed_image,
                 init_container_image,
+                persist_blob_encryption,
             ),
             initialized: false,
             stashed_response: None,
@@ -313,6 +319,7 @@ impl<T> ComputeController<T> {
             envd_epoch,
             stats_update_ticker,
             stats_update_pending: false,
+            config: Default::default(),
         }
     }
 
@@ -406,7 +413,6 @@ where
         &mut self,
         id: ComputeInstanceId,
         arranged_logs: BTreeMap<LogVariant, GlobalId>,
-        max_result_size: u32,
     ) -> Result<(), InstanceExists> {
         if self.instances.contains_key(&id) {
             return Err(InstanceExists(id));
@@ -418,7 +424,7 @@ where
                 id,
                 self.build_info,
                 arranged_logs,
-                max_result_size,
+                self.config.clone(),
                 self.orchestrator.clone(),
                 self.envd_epoch,
             ),
@@ -445,6 +451,18 @@ where
         }
     }
 
+    /// Update compute configuration.
+    ///
+    /// The update is applied to all existing instances and remembered for instances created
+    /// afterwards. Parameters that are not set in `config_params` retain their current values.
+    pub fn update_configuration(&mut self, config_params: ComputeParameters) {
+        for instance in self.instances.values_mut() {
+            instance.update_configuration(config_params.clone());
+        }
+
+        self.config.update(config_params);
+    }
+
     /// Mark the end of any initialization commands.
     ///
     /// The implementor may wait for this method to be called before implementing prior commands,
@@ -693,17 +711,6 @@ where
         Ok(())
     }
 
-    /// Update the max size in bytes of any result.
-    pub fn update_max_result_size(
-        &mut self,
-        instance_id: ComputeInstanceId,
-        max_result_size: u32,
-    ) -> Result<(), InstanceMissing> {
-        self.instance(instance_id)?
-            .update_max_result_size(max_result_size);
-        Ok(())
-    }
-
     /// Processes the work queued by [`ComputeController::ready`].
     pub fn process(&mut self) -> Option<ComputeControllerResponse<T>> {
         // Rehydrate any failed replicas.
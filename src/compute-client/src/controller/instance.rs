@@ -27,7 +27,8 @@ use mz_repr::{GlobalId, Row};
 use mz_storage_client::controller::{ReadPolicy, StorageController};
 
 use crate::command::{
-    ComputeCommand, ComputeCommandHistory, ComputeStartupEpoch, InstanceConfig, Peek,
+    ComputeCommand, ComputeCommandHistory, ComputeParameters, ComputeStartupEpoch, InstanceConfig,
+    Peek,
 };
 use crate::logging::{LogVariant, LoggingConfig};
 use crate::response::{ComputeResponse, PeekResponse, SubscribeBatch, SubscribeResponse};
@@ -165,7 +166,7 @@ where
         instance_id: ComputeInstanceId,
         build_info: &'static BuildInfo,
         arranged_logs: BTreeMap<LogVariant, GlobalId>,
-        max_result_size: u32,
+        config: ComputeParameters,
         orchestrator: ComputeOrchestrator,
         envd_epoch: NonZeroI64,
     ) -> Self {
@@ -199,12 +200,17 @@ where
         });
         instance.send(ComputeCommand::CreateInstance(InstanceConfig {
             logging: Default::default(),
-            max_result_size,
         }));
+        instance.update_configuration(config);
 
         instance
     }
 
+    /// Update instance configuration.
+    pub fn update_configuration(&mut self, config_params: ComputeParameters) {
+        self.send(ComputeCommand::UpdateConfiguration(config_params));
+    }
+
     /// Marks the end of any initialization commands.
     ///
     /// Intended to be called by `Controller`, rather than by other code (to avoid repeated calls).
@@ -748,12 +754,6 @@ where
         Ok(())
     }
 
-    /// Update the max size in bytes of any result.
-    pub fn update_max_result_size(&mut self, max_result_size: u32) {
-        self.compute
-            .send(ComputeCommand::UpdateMaxResultSize(max_result_size))
-    }
-
     /// Validate that a collection exists for all identifiers, and error if any do not.
     fn validate_ids(&self, ids: impl Iterator<Item = GlobalId>) -> Result<(), CollectionMissing> {
         for id in ids {
@@ -20,6 +20,7 @@ use mz_orchestrator::{
     LabelSelectionLogic, LabelSelector, NamespacedOrchestrator, Service, ServiceConfig,
     ServiceEvent, ServicePort, ServiceProcessMetrics,
 };
+use mz_persist_client::encryption::BlobEncryptionCliArgs;
 
 use crate::command::CommunicationConfig;
 
@@ -33,6 +34,7 @@ pub(super) struct ComputeOrchestrator {
     inner: Arc<dyn NamespacedOrchestrator>,
     computed_image: String,
     init_container_image: Option<String>,
+    persist_blob_encryption: BlobEncryptionCliArgs,
 }
 
 impl ComputeOrchestrator {
@@ -40,11 +42,13 @@ impl ComputeOrchestrator {
         inner: Arc<dyn NamespacedOrchestrator>,
         computed_image: String,
         init_container_image: Option<String>,
+        persist_blob_encryption: BlobEncryptionCliArgs,
     ) -> Self {
         Self {
             inner,
             computed_image,
             init_container_image,
+            persist_blob_encryption,
         }
     }
     pub(super) async fn ensure_replica_location(
@@ -106,12 +110,14 @@ impl ComputeOrchestrator {
                     image: self.computed_image.clone(),
                     init_container_image: self.init_container_image.clone(),
                     args: &|assigned| {
-                        vec![
+                        let mut args = vec![
                             format!("--controller-listen-addr={}", assigned["controller"]),
                             format!("--internal-http-listen-addr={}", assigned["internal-http"]),
                             format!("--opentelemetry-resource=instance_id={}", instance_id),
                             format!("--opentelemetry-resource=replica_id={}", replica_id),
-                        ]
+                        ];
+                        args.extend(self.persist_blob_encryption.to_args());
+                        args
                     },
                     ports: vec![
                         ServicePort {
@@ -158,6 +164,7 @@ impl ComputeOrchestrator {
                             },
                         },
                     ]),
+                    scratch_directory: false,
                 },
             )
             .await?;
@@ -238,6 +238,7 @@ pub enum ComputeLog {
     PeekDuration,
     FrontierDelay,
     SourceFrontierCurrent,
+    LirMapping,
 }
 
 impl RustType<ProtoComputeLog> for ComputeLog {
@@ -252,6 +253,7 @@ impl RustType<ProtoComputeLog> for ComputeLog {
                 ComputeLog::PeekDuration => PeekDuration(()),
                 ComputeLog::FrontierDelay => FrontierDelay(()),
                 ComputeLog::SourceFrontierCurrent => SourceFrontierCurrent(()),
+                ComputeLog::LirMapping => LirMapping(()),
             }),
         }
     }
@@ -266,6 +268,7 @@ impl RustType<ProtoComputeLog> for ComputeLog {
             Some(PeekDuration(())) => Ok(ComputeLog::PeekDuration),
             Some(FrontierDelay(())) => Ok(ComputeLog::FrontierDelay),
             Some(SourceFrontierCurrent(())) => Ok(ComputeLog::SourceFrontierCurrent),
+            Some(LirMapping(())) => Ok(ComputeLog::LirMapping),
             None => Err(TryFromProtoError::missing_field("ProtoComputeLog::kind")),
         }
     }
@@ -292,6 +295,7 @@ pub static DEFAULT_LOG_VARIANTS: Lazy<Vec<LogVariant>> = Lazy::new(|| {
         LogVariant::Compute(ComputeLog::FrontierDelay),
         LogVariant::Compute(ComputeLog::PeekCurrent),
         LogVariant::Compute(ComputeLog::PeekDuration),
+        LogVariant::Compute(ComputeLog::LirMapping),
     ];
 
     default_logs
@@ -738,6 +742,17 @@ impl LogVariant {
                 .with_column("duration_ns", ScalarType::UInt64.nullable(false))
                 .with_column("count", ScalarType::UInt64.nullable(false))
                 .with_key(vec![0, 1]),
+
+            LogVariant::Compute(ComputeLog::LirMapping) => RelationDesc::empty()
+                .with_column("export_id", ScalarType::String.nullable(false))
+                .with_column("lir_id", ScalarType::UInt64.nullable(false))
+                .with_column("worker_id", ScalarType::UInt64.nullable(false))
+                .with_column("operator", ScalarType::String.nullable(false))
+                .with_column("parent_lir_id", ScalarType::UInt64.nullable(true))
+                .with_column("nesting", ScalarType::UInt64.nullable(false))
+                .with_column("operator_id_start", ScalarType::UInt64.nullable(false))
+                .with_column("operator_id_end", ScalarType::UInt64.nullable(false))
+                .with_key(vec![0, 1, 2]),
         }
     }
 
@@ -787,6 +802,7 @@ impl LogVariant {
             LogVariant::Compute(ComputeLog::FrontierDelay) => vec![],
             LogVariant::Compute(ComputeLog::PeekCurrent) => vec![],
             LogVariant::Compute(ComputeLog::PeekDuration) => vec![],
+            LogVariant::Compute(ComputeLog::LirMapping) => vec![],
         }
     }
 }
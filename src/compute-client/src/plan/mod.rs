@@ -29,15 +29,17 @@ use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
 use mz_repr::{Diff, GlobalId, Row};
 
 use crate::plan::join::{DeltaJoinPlan, JoinPlan, LinearJoinPlan};
-use crate::plan::reduce::{KeyValPlan, ReducePlan};
+use crate::plan::reduce::{BasicPlan, KeyValPlan, ReducePlan};
 use crate::plan::threshold::ThresholdPlan;
 use crate::plan::top_k::TopKPlan;
+use crate::plan::window::WindowPlan;
 use crate::types::dataflows::{BuildDesc, DataflowDescription};
 
 pub mod join;
 pub mod reduce;
 pub mod threshold;
 pub mod top_k;
+pub mod window;
 
 include!(concat!(env!("OUT_DIR"), "/mz_compute_client.plan.rs"));
 
@@ -168,6 +170,38 @@ impl AvailableCollections {
     }
 }
 
+/// An identifier for a node of a [`Plan`] within a dataflow.
+///
+/// Identifiers are assigned by rendering, in pre-order and in the order of
+/// [`Plan::children`], starting from one for the root of the first object the
+/// dataflow builds and continuing through its subsequent objects. They are not
+/// stored in the plan itself, so any consumer that walks the plans of a
+/// dataflow in the same order arrives at the same identifiers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct LirId(u64);
+
+impl LirId {
+    /// The identifier of the first node rendered in a dataflow.
+    pub const FIRST: LirId = LirId(1);
+
+    /// Returns the identifier following this one.
+    pub fn next(self) -> LirId {
+        LirId(self.0 + 1)
+    }
+}
+
+impl From<LirId> for u64 {
+    fn from(id: LirId) -> u64 {
+        id.0
+    }
+}
+
+impl std::fmt::Display for LirId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 /// A rendering plan with as much conditional logic as possible removed.
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
 pub enum Plan<T = mz_repr::Timestamp> {
@@ -212,6 +246,26 @@ pub enum Plan<T = mz_repr::Timestamp> {
         /// that reference `Id::Local(id)`.
         body: Box<Plan<T>>,
     },
+    /// Binds `values` to `ids`, evaluates them potentially recursively, and returns `body`.
+    ///
+    /// All bindings are available to all bindings, and to `body`.
+    /// The contents of each binding are initially empty, and then updated through a sequence
+    /// of iterations in which each binding is updated in sequence, from the most recent values
+    /// of all bindings.
+    LetRec {
+        /// The local identifiers to be used, available to `body` as `Id::Local(id)`.
+        ids: Vec<LocalId>,
+        /// The collection that should be bound to `id`.
+        values: Vec<Plan<T>>,
+        /// The maximum number of iterations to evaluate, if any.
+        ///
+        /// Collections that have not stopped changing after this many iterations
+        /// produce an error instead.
+        max_iters: Option<u64>,
+        /// The collection that results, which is allowed to contain `Get` stages
+        /// that reference `Id::Local(id)`.
+        body: Box<Plan<T>>,
+    },
     /// Map, Filter, and Project operators.
     ///
     /// This stage contains work that we would ideally like to fuse to other plan
@@ -293,6 +347,30 @@ pub enum Plan<T = mz_repr::Timestamp> {
         /// out the documentation for this type for more detail.
         top_k_plan: TopKPlan,
     },
+    /// Window function evaluation over ordered partitions, with fused map, filter, and projection.
+    ///
+    /// Each output record is formed from the key columns of its partition and a
+    /// single column presenting the result of the window function, to which `mfp`
+    /// is then applied. This matches a `Reduce` computing the window function
+    /// followed by a `FlatMap` unnesting its results, with the column holding
+    /// the unnested list removed.
+    ///
+    /// The stage relies on totally ordered timestamps, and must not be used in
+    /// dataflows that are rendered in iterative scopes.
+    Window {
+        /// The input collection.
+        input: Box<Plan<T>>,
+        /// A plan for changing input records into partition keys and window
+        /// function inputs.
+        key_val_plan: KeyValPlan,
+        /// A plan for maintaining the window function.
+        window_plan: WindowPlan,
+        /// Linear operator to apply to each record produced by the window function.
+        mfp: MapFilterProject,
+        /// The particular arrangement of the input we expect to use,
+        /// if any
+        input_key: Option<Vec<MirScalarExpr>>,
+    },
     /// Inverts the sign of each update.
     Negate {
         /// The input collection.
@@ -344,11 +422,52 @@ pub enum Plan<T = mz_repr::Timestamp> {
 }
 
 impl<T> Plan<T> {
+    /// Iterates through references to child expressions.
+    pub fn children(&self) -> impl Iterator<Item = &Self> {
+        let mut first = None;
+        let mut second = None;
+        let mut rest = None;
+        let mut last = None;
+
+        use Plan::*;
+        match self {
+            Constant { .. } | Get { .. } => (),
+            Let { value, body, .. } => {
+                first = Some(&**value);
+                second = Some(&**body);
+            }
+            LetRec { values, body, .. } => {
+                rest = Some(values);
+                last = Some(&**body);
+            }
+            Mfp { input, .. }
+            | FlatMap { input, .. }
+            | Reduce { input, .. }
+            | TopK { input, .. }
+            | Window { input, .. }
+            | Negate { input }
+            | Threshold { input, .. }
+            | ArrangeBy { input, .. } => {
+                first = Some(&**input);
+            }
+            Join { inputs, .. } | Union { inputs } => {
+                rest = Some(inputs);
+            }
+        }
+
+        first
+            .into_iter()
+            .chain(second)
+            .chain(rest.into_iter().flatten())
+            .chain(last)
+    }
+
     /// Iterates through mutable references to child expressions.
     pub fn children_mut(&mut self) -> impl Iterator<Item = &mut Self> {
         let mut first = None;
         let mut second = None;
         let mut rest = None;
+        let mut last = None;
 
         use Plan::*;
         match self {
@@ -357,10 +476,15 @@ impl<T> Plan<T> {
                 first = Some(&mut **value);
                 second = Some(&mut **body);
             }
+            LetRec { values, body, .. } => {
+                rest = Some(values);
+                last = Some(&mut **body);
+            }
             Mfp { input, .. }
             | FlatMap { input, .. }
             | Reduce { input, .. }
             | TopK { input, .. }
+            | Window { input, .. }
             | Negate { input }
             | Threshold { input, .. }
             | ArrangeBy { input, .. } => {
@@ -375,6 +499,69 @@ impl<T> Plan<T> {
             .into_iter()
             .chain(second)
             .chain(rest.into_iter().flatten())
+            .chain(last)
+    }
+
+    /// True when the plan contains a `LetRec` stage.
+    pub fn is_recursive(&self) -> bool {
+        let mut worklist = vec![self];
+        while let Some(plan) = worklist.pop() {
+            if let Plan::LetRec { .. } = plan {
+                return true;
+            }
+            worklist.extend(plan.children());
+        }
+        false
+    }
+
+    /// A short description of this node, without its children.
+    ///
+    /// This matches the heading `EXPLAIN PHYSICAL PLAN` prints for the node,
+    /// except that identifiers are not humanized.
+    pub fn node_name(&self) -> String {
+        use Plan::*;
+        match self {
+            Constant { rows: Ok(_) } => "Constant".to_string(),
+            Constant { rows: Err(_) } => "Error".to_string(),
+            Get { id, plan, .. } => match plan {
+                GetPlan::PassArrangements => format!("Get::PassArrangements {}", id),
+                GetPlan::Arrangement(..) => format!("Get::Arrangement {}", id),
+                GetPlan::Collection(_) => format!("Get::Collection {}", id),
+            },
+            Let { id, .. } => format!("Let {}", id),
+            LetRec { ids, .. } => format!("LetRec {}", itertools::join(ids, ", ")),
+            Mfp { .. } => "Mfp".to_string(),
+            FlatMap { func, .. } => format!("FlatMap {}", func),
+            Join { plan, .. } => match plan {
+                JoinPlan::Linear(_) => "Join::Linear".to_string(),
+                JoinPlan::Delta(_) => "Join::Delta".to_string(),
+            },
+            Reduce { plan, .. } => match plan {
+                ReducePlan::Distinct => "Reduce::Distinct".to_string(),
+                ReducePlan::DistinctNegated => "Reduce::DistinctNegated".to_string(),
+                ReducePlan::Accumulable(_) => "Reduce::Accumulable".to_string(),
+                ReducePlan::Hierarchical(_) => "Reduce::Hierarchical".to_string(),
+                ReducePlan::Basic(_) => "Reduce::Basic".to_string(),
+                ReducePlan::Collation(_) => "Reduce::Collation".to_string(),
+            },
+            TopK { top_k_plan, .. } => match top_k_plan {
+                TopKPlan::MonotonicTop1(_) => "TopK::MonotonicTop1".to_string(),
+                TopKPlan::MonotonicTopK(_) => "TopK::MonotonicTopK".to_string(),
+                TopKPlan::Basic(_) => "TopK::Basic".to_string(),
+            },
+            Window { window_plan, .. } => match window_plan {
+                WindowPlan::RowNumber(_) => "Window::RowNumber".to_string(),
+                WindowPlan::LagLead(_) => "Window::LagLead".to_string(),
+                WindowPlan::PrefixSum(_) => "Window::PrefixSum".to_string(),
+            },
+            Negate { .. } => "Negate".to_string(),
+            Threshold { threshold_plan, .. } => match threshold_plan {
+                ThresholdPlan::Basic(_) => "Threshold::Basic".to_string(),
+                ThresholdPlan::Retractions(_) => "Threshold::Retractions".to_string(),
+            },
+            Union { .. } => "Union".to_string(),
+            ArrangeBy { .. } => "ArrangeBy".to_string(),
+        }
     }
 }
 
@@ -409,6 +596,22 @@ impl Arbitrary for Plan {
                         body: body.into(),
                     })
                     .boxed(),
+                //Plan::LetRec
+                (
+                    prop::collection::vec((any::<LocalId>(), inner.clone()), 0..2),
+                    any::<Option<u64>>(),
+                    inner.clone(),
+                )
+                    .prop_map(|(bindings, max_iters, body)| {
+                        let (ids, values) = bindings.into_iter().unzip();
+                        Plan::LetRec {
+                            ids,
+                            values,
+                            max_iters,
+                            body: body.into(),
+                        }
+                    })
+                    .boxed(),
                 //Plan::Mfp
                 (
                     inner.clone(),
@@ -465,6 +668,24 @@ impl Arbitrary for Plan {
                         top_k_plan,
                     })
                     .boxed(),
+                //Plan::Window
+                (
+                    inner.clone(),
+                    any::<KeyValPlan>(),
+                    any::<WindowPlan>(),
+                    any::<MapFilterProject>(),
+                    any::<Option<Vec<MirScalarExpr>>>(),
+                )
+                    .prop_map(
+                        |(input, key_val_plan, window_plan, mfp, input_key)| Plan::Window {
+                            input: input.into(),
+                            key_val_plan,
+                            window_plan,
+                            mfp,
+                            input_key,
+                        },
+                    )
+                    .boxed(),
                 //Plan::Negate
                 inner
                     .clone()
@@ -562,6 +783,20 @@ impl RustType<ProtoPlan> for Plan {
                     body: Some(body.into_proto()),
                 }
                 .into()),
+                Plan::LetRec {
+                    ids,
+                    values,
+                    max_iters,
+                    body,
+                } => LetRec(
+                    ProtoPlanLetRec {
+                        ids: ids.into_proto(),
+                        values: values.into_proto(),
+                        max_iters: max_iters.into_proto(),
+                        body: Some(body.into_proto()),
+                    }
+                    .into(),
+                ),
                 Plan::Mfp {
                     input,
                     mfp,
@@ -613,6 +848,22 @@ impl RustType<ProtoPlan> for Plan {
                     }
                     .into(),
                 ),
+                Plan::Window {
+                    input,
+                    key_val_plan,
+                    window_plan,
+                    mfp,
+                    input_key,
+                } => Window(
+                    ProtoPlanWindow {
+                        input: Some(input.into_proto()),
+                        key_val_plan: Some(key_val_plan.into_proto()),
+                        window_plan: Some(window_plan.into_proto()),
+                        mfp: Some(mfp.into_proto()),
+                        input_key: input_k_into(input_key.as_ref()),
+                    }
+                    .into(),
+                ),
                 Plan::Negate { input } => Negate(input.into_proto()),
                 Plan::Threshold {
                     input,
@@ -693,6 +944,12 @@ impl RustType<ProtoPlan> for Plan {
                 value: proto.value.into_rust_if_some("ProtoPlanLet::value")?,
                 body: proto.body.into_rust_if_some("ProtoPlanLet::body")?,
             },
+            LetRec(proto) => Plan::LetRec {
+                ids: proto.ids.into_rust()?,
+                values: proto.values.into_rust()?,
+                max_iters: proto.max_iters.into_rust()?,
+                body: proto.body.into_rust_if_some("ProtoPlanLetRec::body")?,
+            },
             Mfp(proto) => Plan::Mfp {
                 input: proto.input.into_rust_if_some("ProtoPlanMfp::input")?,
                 input_key_val: input_kv_try_into(proto.input_key_val)?,
@@ -723,6 +980,17 @@ impl RustType<ProtoPlan> for Plan {
                     .top_k_plan
                     .into_rust_if_some("ProtoPlanTopK::top_k_plan")?,
             },
+            Window(proto) => Plan::Window {
+                input: proto.input.into_rust_if_some("ProtoPlanWindow::input")?,
+                key_val_plan: proto
+                    .key_val_plan
+                    .into_rust_if_some("ProtoPlanWindow::key_val_plan")?,
+                window_plan: proto
+                    .window_plan
+                    .into_rust_if_some("ProtoPlanWindow::window_plan")?,
+                mfp: proto.mfp.into_rust_if_some("ProtoPlanWindow::mfp")?,
+                input_key: input_k_try_into(proto.input_key)?,
+            },
             Negate(proto) => Plan::Negate {
                 input: proto.into_rust()?,
             },
@@ -911,14 +1179,9 @@ impl<T: timely::progress::Timestamp> Plan<T> {
         arrangements: &mut BTreeMap<Id, AvailableCollections>,
         debug_info: LirDebugInfo<'_>,
     ) -> Result<(Self, AvailableCollections), ()> {
-        // TODO: This block should be removed once we can lower `MirRelationExpr::LetRec`.
-        let mut non_recursive = expr.clone();
-        non_recursive.make_nonrecursive();
-        assert!(!non_recursive.is_recursive());
-
         // We don't want to trace recursive calls, which is why the public `from_mir`
         // is annotated and delecates the work to a private (recursive) from_mir_inner.
-        Plan::from_mir_inner(&non_recursive, arrangements, debug_info)
+        Plan::from_mir_inner(expr, arrangements, debug_info)
     }
 
     fn from_mir_inner(
@@ -963,11 +1226,6 @@ impl<T: timely::progress::Timestamp> Plan<T> {
             MirRelationExpr::Project { .. } => {
                 panic!("This operator should have been extracted");
             }
-            MirRelationExpr::LetRec { .. } => {
-                panic!(
-                    "We must extract potentially recursive objects into dataflow objects to build"
-                );
-            }
             // These operators may not have been extracted, and need to result in a `Plan`.
             MirRelationExpr::Constant { rows, typ: _ } => {
                 let plan = Plan::Constant {
@@ -1064,6 +1322,50 @@ impl<T: timely::progress::Timestamp> Plan<T> {
                     b_keys,
                 )
             }
+            MirRelationExpr::LetRec {
+                ids,
+                values,
+                max_iters,
+                body,
+            } => {
+                // Recursive bindings are rendered as collections, as their values
+                // must be known before any of them are planned. Each value is
+                // planned to produce at least a collection.
+                for id in ids.iter() {
+                    let pre_existing =
+                        arrangements.insert(Id::Local(*id), AvailableCollections::new_raw());
+                    assert!(pre_existing.is_none());
+                }
+                let mut lir_values = Vec::with_capacity(values.len());
+                for value in values.iter() {
+                    let (mut lir_value, v_keys) =
+                        Plan::from_mir_inner(value, arrangements, debug_info)?;
+                    if !v_keys.raw {
+                        lir_value = lir_value.arrange_by(
+                            AvailableCollections::new_raw(),
+                            &v_keys,
+                            value.arity(),
+                        );
+                    }
+                    lir_values.push(lir_value);
+                }
+                // Plan the body using initial and recursive arrangements,
+                // and then remove reference to the recursive arrangements.
+                let (body, b_keys) = Plan::from_mir_inner(body, arrangements, debug_info)?;
+                for id in ids.iter() {
+                    arrangements.remove(&Id::Local(*id));
+                }
+                // Return the plan, and any `body` arrangements.
+                (
+                    Plan::LetRec {
+                        ids: ids.clone(),
+                        values: lir_values,
+                        max_iters: *max_iters,
+                        body: Box::new(body),
+                    },
+                    b_keys,
+                )
+            }
             MirRelationExpr::FlatMap { input, func, exprs } => {
                 let (input, keys) = Plan::from_mir_inner(input, arrangements, debug_info)?;
                 // This stage can absorb arbitrary MFP instances.
@@ -1570,6 +1872,14 @@ This is not expected to cause incorrect results, but could indicate a performanc
             debug_name: desc.debug_name,
         };
 
+        // Window functions can only be maintained incrementally outside of
+        // iterative scopes, whose timestamps are not totally ordered.
+        if !dataflow.is_recursive() {
+            for build_desc in dataflow.objects_to_build.iter_mut() {
+                build_desc.plan.plan_windows();
+            }
+        }
+
         // Extract MFPs from Get operators for sources, and extract what we can for the source.
         // For each source, we want to find `&mut MapFilterProject` for each `Get` expression.
         for (source_id, (source, _monotonic)) in dataflow.source_imports.iter_mut() {
@@ -1622,6 +1932,69 @@ This is not expected to cause incorrect results, but could indicate a performanc
         Ok(dataflow)
     }
 
+    /// Replaces the `FlatMap` stages that unnest the results of a `Reduce` computing
+    /// a window function with a [`Plan::Window`] stage, where the window function
+    /// can be maintained incrementally.
+    fn plan_windows(&mut self) {
+        let mut todo = vec![self];
+        while let Some(plan) = todo.pop() {
+            if let Some(window) = plan.as_window() {
+                *plan = window;
+            }
+            todo.extend(plan.children_mut());
+        }
+    }
+
+    /// Returns a [`Plan::Window`] stage equivalent to `self`, if `self` is a
+    /// `FlatMap` stage unnesting the results of a `Reduce` computing a window
+    /// function that can be maintained incrementally.
+    fn as_window(&self) -> Option<Self> {
+        let Plan::FlatMap {
+            input,
+            func: TableFunc::UnnestList { .. },
+            exprs,
+            mfp,
+            input_key: _,
+        } = self
+        else {
+            return None;
+        };
+        let Plan::Reduce {
+            input,
+            key_val_plan,
+            plan: ReducePlan::Basic(BasicPlan::Single(0, aggregate)),
+            input_key,
+        } = &**input
+        else {
+            return None;
+        };
+        let key_arity = key_val_plan.key_arity();
+        if aggregate.distinct || exprs[..] != [MirScalarExpr::Column(key_arity)] {
+            return None;
+        }
+        let window_plan = WindowPlan::create_from(&aggregate.func)?;
+
+        // The output of the `FlatMap` holds the key columns, the unnested list, and an
+        // element of that list. We only produce the key columns and the element, and so
+        // cannot support an `mfp` that uses the list.
+        let mut mfp = mfp.clone();
+        mfp.optimize();
+        if mfp.demand().contains(&key_arity) {
+            return None;
+        }
+        let mut shuffle: HashMap<_, _> = (0..key_arity).map(|c| (c, c)).collect();
+        shuffle.insert(key_arity + 1, key_arity);
+        mfp.permute(shuffle, key_arity + 1);
+
+        Some(Plan::Window {
+            input: input.clone(),
+            key_val_plan: key_val_plan.clone(),
+            window_plan,
+            mfp,
+            input_key: input_key.clone(),
+        })
+    }
+
     /// Partitions the plan into `parts` many disjoint pieces.
     ///
     /// This is used to partition `Plan::Constant` stages so that the work
@@ -1673,6 +2046,31 @@ This is not expected to cause incorrect results, but could indicate a performanc
                         })
                         .collect()
                 }
+                Plan::LetRec {
+                    ids,
+                    values,
+                    max_iters,
+                    body,
+                } => {
+                    let mut values_parts = vec![Vec::new(); parts];
+                    for value in values.into_iter() {
+                        for (index, value_part) in
+                            value.partition_among(parts).into_iter().enumerate()
+                        {
+                            values_parts[index].push(value_part);
+                        }
+                    }
+                    values_parts
+                        .into_iter()
+                        .zip(body.partition_among(parts))
+                        .map(|(values, body)| Plan::LetRec {
+                            ids: ids.clone(),
+                            values,
+                            max_iters,
+                            body: Box::new(body),
+                        })
+                        .collect()
+                }
                 Plan::Mfp {
                     input,
                     input_key_val,
@@ -1743,6 +2141,23 @@ This is not expected to cause incorrect results, but could indicate a performanc
                         top_k_plan: top_k_plan.clone(),
                     })
                     .collect(),
+                Plan::Window {
+                    input,
+                    key_val_plan,
+                    window_plan,
+                    mfp,
+                    input_key,
+                } => input
+                    .partition_among(parts)
+                    .into_iter()
+                    .map(|input| Plan::Window {
+                        input: Box::new(input),
+                        key_val_plan: key_val_plan.clone(),
+                        window_plan: window_plan.clone(),
+                        mfp: mfp.clone(),
+                        input_key: input_key.clone(),
+                    })
+                    .collect(),
                 Plan::Negate { input } => input
                     .partition_among(parts)
                     .into_iter()
@@ -1813,6 +2228,17 @@ impl<T> CollectionPlan for Plan<T> {
                 value.depends_on_into(out);
                 body.depends_on_into(out);
             }
+            Plan::LetRec {
+                ids: _,
+                values,
+                max_iters: _,
+                body,
+            } => {
+                for value in values.iter() {
+                    value.depends_on_into(out);
+                }
+                body.depends_on_into(out);
+            }
             Plan::Join { inputs, plan: _ } | Plan::Union { inputs } => {
                 for input in inputs {
                     input.depends_on_into(out);
@@ -1846,6 +2272,13 @@ impl<T> CollectionPlan for Plan<T> {
                 input,
                 top_k_plan: _,
             }
+            | Plan::Window {
+                input,
+                key_val_plan: _,
+                window_plan: _,
+                mfp: _,
+                input_key: _,
+            }
             | Plan::Negate { input }
             | Plan::Threshold {
                 input,
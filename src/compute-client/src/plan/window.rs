@@ -0,0 +1,311 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Window function planning logic.
+//!
+//! Window functions are lowered to a `Reduce` whose single aggregation collects
+//! each partition into a list, followed by a `FlatMap` that unnests that list.
+//! Rendered naively, any change to a partition re-sorts it and retracts and
+//! re-emits every row of it. We provide a plan ([WindowPlan]) for the window
+//! functions that we can instead maintain incrementally, by keeping partitions
+//! in window order and only revisiting the positions a change can affect.
+//!
+//! The window function variants can be distinguished as follows:
+//! * A [RowNumberPlan] numbers the rows of a partition. A change affects the
+//!   rows from its position to the end of the partition.
+//! * A [LagLeadPlan] looks up the row at some offset from each row. A change
+//!   only affects the rows within that offset of it.
+//! * A [PrefixSumPlan] aggregates the rows of a partition up to each row. A
+//!   change affects the rows from its position to the end of the partition.
+//!
+//! Changes to the partitions of a [RowNumberPlan] or a [PrefixSumPlan] also
+//! leave the output for the rows after them unchanged if the number of rows, or
+//! the aggregate, respectively, before those rows are unchanged, which bounds
+//! the output that needs to be revisited.
+
+use proptest_derive::Arbitrary;
+use serde::{Deserialize, Serialize};
+
+use mz_expr::{AggregateFunc, ColumnOrder, LagLeadType, WindowFrameBound, WindowFrameUnits};
+use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
+
+include!(concat!(
+    env!("OUT_DIR"),
+    "/mz_compute_client.plan.window.rs"
+));
+
+/// A plan encapsulating the window functions that can be maintained incrementally.
+#[derive(Arbitrary, Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub enum WindowPlan {
+    /// A plan for `row_number`.
+    RowNumber(RowNumberPlan),
+    /// A plan for `lag` and `lead`.
+    LagLead(LagLeadPlan),
+    /// A plan for `count` and `sum` over the rows up to the current row.
+    PrefixSum(PrefixSumPlan),
+}
+
+impl WindowPlan {
+    /// Create a plan for the window function `func`, if it is one that can be
+    /// maintained incrementally.
+    pub(crate) fn create_from(func: &AggregateFunc) -> Option<Self> {
+        match func {
+            AggregateFunc::RowNumber { order_by } => Some(WindowPlan::RowNumber(RowNumberPlan {
+                order_by: order_by.clone(),
+            })),
+            AggregateFunc::LagLead { order_by, lag_lead } => {
+                Some(WindowPlan::LagLead(LagLeadPlan {
+                    order_by: order_by.clone(),
+                    lag_lead: lag_lead.clone(),
+                }))
+            }
+            AggregateFunc::WindowAggregate {
+                wrapped_aggregate,
+                order_by,
+                window_frame,
+            } if window_frame.units == WindowFrameUnits::Rows
+                && window_frame.start_bound == WindowFrameBound::UnboundedPreceding
+                && window_frame.end_bound == WindowFrameBound::CurrentRow
+                && PrefixSumPlan::supports(wrapped_aggregate) =>
+            {
+                Some(WindowPlan::PrefixSum(PrefixSumPlan {
+                    order_by: order_by.clone(),
+                    aggregate: (**wrapped_aggregate).clone(),
+                }))
+            }
+            _ => None,
+        }
+    }
+
+    /// The ordering of rows within each partition.
+    pub fn order_by(&self) -> &[ColumnOrder] {
+        match self {
+            WindowPlan::RowNumber(plan) => &plan.order_by,
+            WindowPlan::LagLead(plan) => &plan.order_by,
+            WindowPlan::PrefixSum(plan) => &plan.order_by,
+        }
+    }
+}
+
+impl RustType<ProtoWindowPlan> for WindowPlan {
+    fn into_proto(&self) -> ProtoWindowPlan {
+        use proto_window_plan::Kind::*;
+
+        ProtoWindowPlan {
+            kind: Some(match self {
+                WindowPlan::RowNumber(plan) => RowNumber(plan.into_proto()),
+                WindowPlan::LagLead(plan) => LagLead(plan.into_proto()),
+                WindowPlan::PrefixSum(plan) => PrefixSum(plan.into_proto()),
+            }),
+        }
+    }
+
+    fn from_proto(proto: ProtoWindowPlan) -> Result<Self, TryFromProtoError> {
+        use proto_window_plan::Kind::*;
+
+        match proto.kind {
+            Some(RowNumber(plan)) => Ok(WindowPlan::RowNumber(plan.into_rust()?)),
+            Some(LagLead(plan)) => Ok(WindowPlan::LagLead(plan.into_rust()?)),
+            Some(PrefixSum(plan)) => Ok(WindowPlan::PrefixSum(plan.into_rust()?)),
+            None => Err(TryFromProtoError::missing_field("ProtoWindowPlan::kind")),
+        }
+    }
+}
+
+/// A plan for `row_number`.
+///
+/// Each input record presents a list of rows and the `order_by` expressions,
+/// and each of the rows is output along with its position in the partition.
+#[derive(Arbitrary, Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct RowNumberPlan {
+    /// Ordering that is used within each partition.
+    pub order_by: Vec<ColumnOrder>,
+}
+
+impl RustType<ProtoRowNumberPlan> for RowNumberPlan {
+    fn into_proto(&self) -> ProtoRowNumberPlan {
+        ProtoRowNumberPlan {
+            order_by: self.order_by.into_proto(),
+        }
+    }
+
+    fn from_proto(proto: ProtoRowNumberPlan) -> Result<Self, TryFromProtoError> {
+        Ok(RowNumberPlan {
+            order_by: proto.order_by.into_rust()?,
+        })
+    }
+}
+
+/// A plan for `lag` and `lead`.
+///
+/// Each input record presents a row, the arguments of the function, and the
+/// `order_by` expressions, and the row is output along with the value found at
+/// the requested offset.
+#[derive(Arbitrary, Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct LagLeadPlan {
+    /// Ordering that is used within each partition.
+    pub order_by: Vec<ColumnOrder>,
+    /// Whether offsets are applied backwards (`lag`) or forwards (`lead`).
+    pub lag_lead: LagLeadType,
+}
+
+impl RustType<ProtoLagLeadPlan> for LagLeadPlan {
+    fn into_proto(&self) -> ProtoLagLeadPlan {
+        use proto_lag_lead_plan::LagLead;
+
+        ProtoLagLeadPlan {
+            order_by: self.order_by.into_proto(),
+            lag_lead: Some(match self.lag_lead {
+                LagLeadType::Lag => LagLead::Lag(()),
+                LagLeadType::Lead => LagLead::Lead(()),
+            }),
+        }
+    }
+
+    fn from_proto(proto: ProtoLagLeadPlan) -> Result<Self, TryFromProtoError> {
+        use proto_lag_lead_plan::LagLead;
+
+        Ok(LagLeadPlan {
+            order_by: proto.order_by.into_rust()?,
+            lag_lead: match proto.lag_lead {
+                Some(LagLead::Lag(())) => LagLeadType::Lag,
+                Some(LagLead::Lead(())) => LagLeadType::Lead,
+                None => {
+                    return Err(TryFromProtoError::missing_field(
+                        "ProtoLagLeadPlan::lag_lead",
+                    ))
+                }
+            },
+        })
+    }
+}
+
+/// A plan for `count` and `sum` with the frame `ROWS BETWEEN UNBOUNDED PRECEDING
+/// AND CURRENT ROW`, which `avg` is planned in terms of.
+///
+/// Each input record presents a row, the input of the aggregate, and the
+/// `order_by` expressions, and the row is output along with the aggregate of
+/// the inputs of all rows up to and including it.
+#[derive(Arbitrary, Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct PrefixSumPlan {
+    /// Ordering that is used within each partition.
+    pub order_by: Vec<ColumnOrder>,
+    /// The aggregate that is applied to each prefix of a partition.
+    pub aggregate: AggregateFunc,
+}
+
+impl PrefixSumPlan {
+    /// Reports whether the aggregate of a prefix can be formed from the
+    /// aggregate of the prefix one row shorter, as is needed by this plan.
+    fn supports(aggregate: &AggregateFunc) -> bool {
+        matches!(
+            aggregate,
+            AggregateFunc::SumInt16
+                | AggregateFunc::SumInt32
+                | AggregateFunc::SumInt64
+                | AggregateFunc::SumUInt16
+                | AggregateFunc::SumUInt32
+                | AggregateFunc::SumUInt64
+                | AggregateFunc::SumFloat32
+                | AggregateFunc::SumFloat64
+                | AggregateFunc::SumNumeric
+                | AggregateFunc::Count
+        )
+    }
+}
+
+impl RustType<ProtoPrefixSumPlan> for PrefixSumPlan {
+    fn into_proto(&self) -> ProtoPrefixSumPlan {
+        ProtoPrefixSumPlan {
+            order_by: self.order_by.into_proto(),
+            aggregate: Some(self.aggregate.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: ProtoPrefixSumPlan) -> Result<Self, TryFromProtoError> {
+        Ok(PrefixSumPlan {
+            order_by: proto.order_by.into_rust()?,
+            aggregate: proto
+                .aggregate
+                .into_rust_if_some("ProtoPrefixSumPlan::aggregate")?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mz_expr::WindowFrame;
+    use mz_proto::protobuf_roundtrip;
+    use proptest::prelude::*;
+
+    #[test]
+    fn prefix_sum_plan() {
+        let order_by = vec![ColumnOrder {
+            column: 0,
+            desc: false,
+            nulls_last: true,
+        }];
+        let window_aggregate =
+            |wrapped_aggregate, units, end_bound| AggregateFunc::WindowAggregate {
+                wrapped_aggregate: Box::new(wrapped_aggregate),
+                order_by: order_by.clone(),
+                window_frame: WindowFrame {
+                    units,
+                    start_bound: WindowFrameBound::UnboundedPreceding,
+                    end_bound,
+                },
+            };
+
+        let func = window_aggregate(
+            AggregateFunc::SumInt32,
+            WindowFrameUnits::Rows,
+            WindowFrameBound::CurrentRow,
+        );
+        assert_eq!(
+            WindowPlan::create_from(&func),
+            Some(WindowPlan::PrefixSum(PrefixSumPlan {
+                order_by: order_by.clone(),
+                aggregate: AggregateFunc::SumInt32,
+            }))
+        );
+
+        // Peers of the current row are part of `RANGE` frames.
+        let func = window_aggregate(
+            AggregateFunc::Count,
+            WindowFrameUnits::Range,
+            WindowFrameBound::CurrentRow,
+        );
+        assert_eq!(WindowPlan::create_from(&func), None);
+
+        let func = window_aggregate(
+            AggregateFunc::Count,
+            WindowFrameUnits::Rows,
+            WindowFrameBound::OffsetFollowing(1),
+        );
+        assert_eq!(WindowPlan::create_from(&func), None);
+
+        // The maximum of a prefix cannot be maintained under retractions.
+        let func = window_aggregate(
+            AggregateFunc::MaxInt32,
+            WindowFrameUnits::Rows,
+            WindowFrameBound::CurrentRow,
+        );
+        assert_eq!(WindowPlan::create_from(&func), None);
+    }
+
+    proptest! {
+        #[test]
+        fn window_plan_protobuf_roundtrip(expect in any::<WindowPlan>()) {
+            let actual = protobuf_roundtrip::<_, ProtoWindowPlan>(&expect);
+            assert!(actual.is_ok());
+            assert_eq!(actual.unwrap(), expect);
+        }
+    }
+}
@@ -288,6 +288,20 @@ where
     }
 }
 
+impl<PT, S, T> DataflowDescription<Plan<PT>, S, T> {
+    /// Reports whether the dataflow must be rendered in an iterative scope.
+    ///
+    /// This is the case if an object depends on itself or on an object built
+    /// after it, or if the plan of an object contains a `LetRec` stage.
+    pub fn is_recursive(&self) -> bool {
+        self.objects_to_build.iter().any(|object| {
+            let mut depends_on = BTreeSet::new();
+            object.plan.depends_on_into(&mut depends_on);
+            depends_on.into_iter().any(|id| id >= object.id) || object.plan.is_recursive()
+        })
+    }
+}
+
 impl<P: PartialEq, S: PartialEq, T: timely::PartialOrder> DataflowDescription<P, S, T> {
     /// Determine if a dataflow description is compatible with this dataflow description.
     ///
@@ -109,7 +109,9 @@ impl RustType<ProtoComputeSinkConnection> for ComputeSinkConnection<CollectionMe
         use proto_compute_sink_connection::Kind;
         ProtoComputeSinkConnection {
             kind: Some(match self {
-                ComputeSinkConnection::Subscribe(_) => Kind::Subscribe(()),
+                ComputeSinkConnection::Subscribe(subscribe) => {
+                    Kind::Subscribe(subscribe.into_proto())
+                }
                 ComputeSinkConnection::Persist(persist) => Kind::Persist(persist.into_proto()),
             }),
         }
@@ -121,14 +123,98 @@ impl RustType<ProtoComputeSinkConnection> for ComputeSinkConnection<CollectionMe
             .kind
             .ok_or_else(|| TryFromProtoError::missing_field("ProtoComputeSinkConnection::kind"))?;
         Ok(match kind {
-            Kind::Subscribe(_) => ComputeSinkConnection::Subscribe(SubscribeSinkConnection {}),
+            Kind::Subscribe(subscribe) => ComputeSinkConnection::Subscribe(subscribe.into_rust()?),
             Kind::Persist(persist) => ComputeSinkConnection::Persist(persist.into_rust()?),
         })
     }
 }
 
 #[derive(Arbitrary, Default, Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
-pub struct SubscribeSinkConnection {}
+pub struct SubscribeSinkConnection {
+    /// How to present the updates, if not as the raw changes to rows.
+    pub envelope: Option<SubscribeEnvelope>,
+    /// The frontier at which the subscribe completes.
+    ///
+    /// Only updates at times not beyond it are emitted, and the subscribe
+    /// completes once its input frontier reaches it. If empty, the subscribe
+    /// runs until it is dropped.
+    #[proptest(
+        strategy = "proptest::collection::vec(any::<mz_repr::Timestamp>(), 0..2).prop_map(Antichain::from)"
+    )]
+    pub up_to: Antichain<mz_repr::Timestamp>,
+}
+
+impl RustType<ProtoSubscribeSinkConnection> for SubscribeSinkConnection {
+    fn into_proto(&self) -> ProtoSubscribeSinkConnection {
+        ProtoSubscribeSinkConnection {
+            envelope: self.envelope.into_proto(),
+            up_to: Some(self.up_to.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: ProtoSubscribeSinkConnection) -> Result<Self, TryFromProtoError> {
+        Ok(SubscribeSinkConnection {
+            envelope: proto.envelope.into_rust()?,
+            up_to: proto
+                .up_to
+                .into_rust_if_some("ProtoSubscribeSinkConnection::up_to")?,
+        })
+    }
+}
+
+/// Presents the updates of a subscribe per key, consolidating all changes to
+/// a key at a timestamp into a single update.
+#[derive(Arbitrary, Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct SubscribeEnvelope {
+    pub kind: SubscribeEnvelopeKind,
+    /// The columns that make up the key.
+    pub key_indices: Vec<usize>,
+    /// Whether the key is known to be unique in the subscribed collection.
+    ///
+    /// If it isn't, the sink must track the rows of each key to detect
+    /// violations of the key.
+    pub key_is_unique: bool,
+}
+
+#[derive(Arbitrary, Clone, Copy, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub enum SubscribeEnvelopeKind {
+    /// Each update is the new value of a key, or its deletion.
+    Upsert,
+    /// Each update is the old and the new value of a key.
+    Debezium,
+}
+
+impl RustType<ProtoSubscribeEnvelope> for SubscribeEnvelope {
+    fn into_proto(&self) -> ProtoSubscribeEnvelope {
+        use proto_subscribe_envelope::Kind;
+        ProtoSubscribeEnvelope {
+            kind: Some(match self.kind {
+                SubscribeEnvelopeKind::Upsert => Kind::Upsert(()),
+                SubscribeEnvelopeKind::Debezium => Kind::Debezium(()),
+            }),
+            key_indices: self.key_indices.into_proto(),
+            key_is_unique: self.key_is_unique,
+        }
+    }
+
+    fn from_proto(proto: ProtoSubscribeEnvelope) -> Result<Self, TryFromProtoError> {
+        use proto_subscribe_envelope::Kind;
+        let kind = match proto.kind {
+            Some(Kind::Upsert(())) => SubscribeEnvelopeKind::Upsert,
+            Some(Kind::Debezium(())) => SubscribeEnvelopeKind::Debezium,
+            None => {
+                return Err(TryFromProtoError::missing_field(
+                    "ProtoSubscribeEnvelope::kind",
+                ))
+            }
+        };
+        Ok(SubscribeEnvelope {
+            kind,
+            key_indices: proto.key_indices.into_rust()?,
+            key_is_unique: proto.key_is_unique,
+        })
+    }
+}
 
 #[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct PersistSinkConnection<S> {
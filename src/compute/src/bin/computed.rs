@@ -10,6 +10,7 @@
 use std::path::PathBuf;
 use std::process;
 
+use anyhow::Context;
 use axum::routing;
 use once_cell::sync::Lazy;
 use tracing::info;
@@ -22,6 +23,7 @@ use mz_ore::metrics::MetricsRegistry;
 use mz_ore::netio::{Listener, SocketAddr};
 use mz_ore::now::SYSTEM_TIME;
 use mz_ore::tracing::TracingHandle;
+use mz_persist_client::encryption::BlobEncryptionCliArgs;
 use mz_pid_file::PidFile;
 use mz_service::grpc::GrpcServer;
 use mz_service::secrets::SecretsReaderCliArgs;
@@ -67,6 +69,23 @@ struct Args {
     )]
     internal_http_listen_addr: SocketAddr,
 
+    // === Persist options. ===
+    /// A local directory in which to cache blobs fetched from persist.
+    ///
+    /// The cache is disabled if this is not set.
+    #[clap(long, env = "PERSIST_BLOB_CACHE_DIR", value_name = "PATH")]
+    persist_blob_cache_dir: Option<PathBuf>,
+    /// The maximum total size of the blobs kept in the persist blob cache.
+    #[clap(
+        long,
+        env = "PERSIST_BLOB_CACHE_SIZE_BYTES",
+        value_name = "N",
+        default_value = "1073741824"
+    )]
+    persist_blob_cache_size_bytes: usize,
+    #[clap(flatten)]
+    persist_blob_encryption: BlobEncryptionCliArgs,
+
     // === Process orchestrator options. ===
     /// Where to write a PID lock file.
     ///
@@ -167,10 +186,20 @@ async fn run(args: Args) -> Result<(), anyhow::Error> {
         )
     });
 
+    let secrets_reader = args
+        .secrets
+        .load()
+        .await
+        .context("loading secrets reader")?;
+    let persist_blob_encryption_keys = args.persist_blob_encryption.load(&*secrets_reader).await?;
+
     let config = mz_compute::server::Config {
         build_info: &BUILD_INFO,
         metrics_registry,
         now: SYSTEM_TIME.clone(),
+        persist_blob_cache_dir: args.persist_blob_cache_dir,
+        persist_blob_cache_size_bytes: args.persist_blob_cache_size_bytes,
+        persist_blob_encryption_keys,
     };
 
     let (_server, client_builder) = mz_compute::server::serve(config)?;
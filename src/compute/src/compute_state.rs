@@ -27,7 +27,9 @@ use timely::worker::Worker as TimelyWorker;
 use tokio::sync::{mpsc, Mutex};
 use uuid::Uuid;
 
-use mz_compute_client::command::{ComputeCommand, ComputeCommandHistory, InstanceConfig, Peek};
+use mz_compute_client::command::{
+    ComputeCommand, ComputeCommandHistory, ComputeParameters, InstanceConfig, Peek,
+};
 use mz_compute_client::logging::LoggingConfig;
 use mz_compute_client::metrics::ComputeMetrics;
 use mz_compute_client::plan::Plan;
@@ -80,6 +82,8 @@ pub struct ComputeState {
     pub command_history: ComputeCommandHistory,
     /// Max size in bytes of any result.
     pub max_result_size: u32,
+    /// The number of updates a persist source emits before yielding.
+    pub persist_source_yield_threshold: usize,
     /// Metrics for this replica.
     pub metrics: ComputeMetrics,
 }
@@ -138,9 +142,7 @@ impl<'a, A: Allocate> ActiveComputeState<'a, A> {
                 self.handle_peek(peek)
             }
             CancelPeeks { uuids } => self.handle_cancel_peeks(uuids),
-            UpdateMaxResultSize(max_result_size) => {
-                self.compute_state.max_result_size = max_result_size
-            }
+            UpdateConfiguration(params) => self.handle_update_configuration(params),
         }
     }
 
@@ -148,6 +150,26 @@ impl<'a, A: Allocate> ActiveComputeState<'a, A> {
         self.initialize_logging(&config.logging);
     }
 
+    fn handle_update_configuration(&mut self, params: ComputeParameters) {
+        let ComputeParameters {
+            max_result_size,
+            persist_source_yield_threshold,
+            persist,
+        } = params;
+
+        if let Some(v) = max_result_size {
+            self.compute_state.max_result_size = v;
+        }
+        // Only affects persist sources of dataflows created after this update.
+        if let Some(v) = persist_source_yield_threshold {
+            self.compute_state.persist_source_yield_threshold = usize::cast_from(v);
+        }
+
+        // The persist config is shared by all clients in the cache, so this
+        // also affects the dataflows that are already running.
+        persist.apply(self.compute_state.persist_clients.blocking_lock().cfg());
+    }
+
     fn handle_create_dataflows(
         &mut self,
         dataflows: Vec<DataflowDescription<Plan, CollectionMetadata>>,
@@ -25,6 +25,7 @@ use timely::logging::WorkerIdentifier;
 use tracing::error;
 use uuid::Uuid;
 
+use mz_compute_client::plan::LirId;
 use mz_expr::{permutation_for_arrangement, MirScalarExpr};
 use mz_ore::cast::CastFrom;
 use mz_repr::{Datum, DatumVec, GlobalId, Row, Timestamp};
@@ -57,6 +58,22 @@ pub enum ComputeEvent {
     Frontier(GlobalId, Timestamp, i64),
     // Available frontier information for source instantiations.
     SourceFrontier(GlobalId, GlobalId, Timestamp, i8),
+    /// A node of a rendered plan, and the Timely operators built for it.
+    LirMapping {
+        /// Globally unique identifier for the exported collection.
+        export_id: GlobalId,
+        /// The identifier of the plan node within its dataflow.
+        lir_id: LirId,
+        /// A short description of the plan node.
+        operator: String,
+        /// The identifier of the parent plan node, if any.
+        parent_lir_id: Option<LirId>,
+        /// The depth of the plan node in its plan.
+        nesting: u64,
+        /// The half-open range of operator identifiers allocated while
+        /// rendering the plan node, including those of its inputs.
+        operator_span: (usize, usize),
+    },
 }
 
 /// A logged peek event.
@@ -119,11 +136,13 @@ pub fn construct<A: Allocate>(
         let (mut frontier_delay_out, frontier_delay) = demux.new_output();
         let (mut peek_out, peek) = demux.new_output();
         let (mut peek_duration_out, peek_duration) = demux.new_output();
+        let (mut lir_mapping_out, lir_mapping) = demux.new_output();
 
         let mut demux_buffer = Vec::new();
         demux.build(move |_capability| {
             let mut active_dataflows = HashMap::new();
             let mut peek_stash = HashMap::new();
+            let mut lir_mappings = HashMap::<(GlobalId, usize), Vec<Row>>::new();
             let mut storage_sources = HashMap::<
                 (GlobalId, usize),
                 HashMap<GlobalId, (VecDeque<(mz_repr::Timestamp, u128)>, HashMap<u128, i32>)>,
@@ -136,6 +155,7 @@ pub fn construct<A: Allocate>(
                 let mut frontier_delay = frontier_delay_out.activate();
                 let mut peek = peek_out.activate();
                 let mut peek_duration = peek_duration_out.activate();
+                let mut lir_mapping = lir_mapping_out.activate();
 
                 input.for_each(|time, data| {
                     data.swap(&mut demux_buffer);
@@ -147,6 +167,7 @@ pub fn construct<A: Allocate>(
                     let mut frontier_delay_session = frontier_delay.session(&time);
                     let mut peek_session = peek.session(&time);
                     let mut peek_duration_session = peek_duration.session(&time);
+                    let mut lir_mapping_session = lir_mapping.session(&time);
 
                     for (time, worker, datum) in demux_buffer.drain(..) {
                         let time_ms = (((time.as_millis() / interval_ms) + 1) * interval_ms)
@@ -184,6 +205,12 @@ pub fn construct<A: Allocate>(
                                             key.0, worker
                                         ),
                                     }
+                                    // Retract the plan nodes rendered for the dataflow.
+                                    if let Some(rows) = lir_mappings.remove(key) {
+                                        for row in rows {
+                                            lir_mapping_session.give((row, time_ms, -1));
+                                        }
+                                    }
                                     // dataflow may or may not be associated to a storage
                                     // source instantiation. Report removal if so.
                                     if let Some(source_map) = storage_sources.remove(key) {
@@ -286,6 +313,38 @@ pub fn construct<A: Allocate>(
                                     }
                                 }
                             }
+                            ComputeEvent::LirMapping {
+                                export_id,
+                                lir_id,
+                                operator,
+                                parent_lir_id,
+                                nesting,
+                                operator_span,
+                            } => {
+                                let row = Row::pack_slice(&[
+                                    Datum::String(&export_id.to_string()),
+                                    Datum::UInt64(lir_id.into()),
+                                    Datum::UInt64(u64::cast_from(worker)),
+                                    Datum::String(&operator),
+                                    parent_lir_id
+                                        .map(|id| Datum::UInt64(id.into()))
+                                        .unwrap_or(Datum::Null),
+                                    Datum::UInt64(nesting),
+                                    Datum::UInt64(u64::cast_from(operator_span.0)),
+                                    Datum::UInt64(u64::cast_from(operator_span.1)),
+                                ]);
+                                let key = (export_id, worker);
+                                if active_dataflows.contains_key(&key) {
+                                    lir_mapping_session.give((row.clone(), time_ms, 1));
+                                    lir_mappings.entry(key).or_insert_with(Vec::new).push(row);
+                                } else {
+                                    error!(
+                                        "tried to map plan node for dataflow that doesn't exist: \
+                                         dataflow={} lir_id={} worker={}",
+                                        key.0, lir_id, worker,
+                                    );
+                                }
+                            }
                             ComputeEvent::Peek(peek, is_install) => {
                                 let key = (worker, peek.uuid);
                                 if is_install {
@@ -387,6 +446,8 @@ pub fn construct<A: Allocate>(
                 }
             });
 
+        let lir_mapping_current = lir_mapping.as_collection();
+
         let logs = vec![
             (
                 LogVariant::Compute(ComputeLog::DataflowCurrent),
@@ -410,6 +471,10 @@ pub fn construct<A: Allocate>(
             ),
             (LogVariant::Compute(ComputeLog::PeekCurrent), peek_current),
             (LogVariant::Compute(ComputeLog::PeekDuration), peek_duration),
+            (
+                LogVariant::Compute(ComputeLog::LirMapping),
+                lir_mapping_current,
+            ),
         ];
 
         let mut result = std::collections::HashMap::new();
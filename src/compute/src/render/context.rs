@@ -21,7 +21,7 @@ use differential_dataflow::trace::BatchReader;
 use differential_dataflow::trace::{Cursor, TraceReader};
 use differential_dataflow::Collection;
 use differential_dataflow::Data;
-use mz_compute_client::plan::AvailableCollections;
+use mz_compute_client::plan::{AvailableCollections, LirId};
 use timely::communication::message::RefOrMut;
 use timely::container::columnation;
 use timely::dataflow::channels::pact::Pipeline;
@@ -36,6 +36,7 @@ use mz_storage_client::controller::CollectionMetadata;
 use mz_storage_client::types::errors::DataflowError;
 use mz_timely_util::operator::CollectionExt;
 
+use crate::logging::compute::Logger;
 use crate::typedefs::{ErrSpine, RowSpine, TraceErrHandle, TraceRowHandle};
 
 // Local type definition to avoid the horror in signatures.
@@ -84,6 +85,14 @@ where
     pub until: Antichain<T>,
     /// Bindings of identifiers to collections.
     pub bindings: BTreeMap<Id, CollectionBundle<S, V, T>>,
+    /// The logger, from Timely's logging framework, if logs are enabled.
+    pub compute_logger: Option<Logger>,
+    /// The identifiers of the collections exported by the dataflow.
+    pub export_ids: Vec<GlobalId>,
+    /// The identifier to assign to the next rendered plan node.
+    pub next_lir_id: LirId,
+    /// The identifiers of the plan nodes currently being rendered, outermost first.
+    pub lir_stack: Vec<LirId>,
 }
 
 impl<S: Scope, V: Data + columnation::Columnation> Context<S, V>
@@ -94,6 +103,7 @@ where
     pub fn for_dataflow<Plan>(
         dataflow: &DataflowDescription<Plan, CollectionMetadata>,
         dataflow_id: usize,
+        compute_logger: Option<Logger>,
     ) -> Self {
         let as_of_frontier = dataflow
             .as_of
@@ -106,6 +116,10 @@ where
             as_of_frontier,
             until: dataflow.until.clone(),
             bindings: BTreeMap::new(),
+            compute_logger,
+            export_ids: dataflow.export_ids().collect(),
+            next_lir_id: LirId::FIRST,
+            lir_stack: Vec::new(),
         }
     }
 }
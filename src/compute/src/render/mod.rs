@@ -113,12 +113,13 @@ use timely::dataflow::scopes::Child;
 use timely::dataflow::{Scope, Stream};
 use timely::order::Product;
 use timely::progress::Timestamp;
-use timely::worker::Worker as TimelyWorker;
+use timely::worker::{AsWorker, Worker as TimelyWorker};
 use timely::PartialOrder;
 
 use mz_compute_client::plan::Plan;
 use mz_compute_client::types::dataflows::{BuildDesc, DataflowDescription, IndexDesc};
-use mz_expr::Id;
+use mz_expr::{EvalError, Id};
+use mz_ore::cast::CastFrom;
 use mz_ore::collections::CollectionExt as IteratorExt;
 use mz_repr::{Diff, GlobalId, Row};
 use mz_storage_client::controller::CollectionMetadata;
@@ -139,6 +140,7 @@ mod reduce;
 pub mod sinks;
 mod threshold;
 mod top_k;
+mod window;
 
 /// Assemble the "compute"  side of a dataflow, i.e. all but the sources.
 ///
@@ -151,12 +153,7 @@ pub fn build_compute_dataflow<A: Allocate>(
     dataflow: DataflowDescription<Plan, CollectionMetadata>,
 ) {
     // Mutually recursive view definitions require special handling.
-    let recursive = dataflow.objects_to_build.iter().any(|object| {
-        use mz_expr::CollectionPlan;
-        let mut depends_on = BTreeSet::new();
-        object.plan.depends_on_into(&mut depends_on);
-        depends_on.into_iter().any(|id| id >= object.id)
-    });
+    let recursive = dataflow.is_recursive();
 
     // Determine indexes to export
     let indexes = dataflow
@@ -195,6 +192,7 @@ pub fn build_compute_dataflow<A: Allocate>(
 
                 // Note: For correctness, we require that sources only emit times advanced by
                 // `dataflow.as_of`. `persist_source` is documented to provide this guarantee.
+                let yield_threshold = compute_state.persist_source_yield_threshold;
                 let (mut ok_stream, err_stream, token) = persist_source::persist_source(
                     region,
                     *source_id,
@@ -206,8 +204,7 @@ pub fn build_compute_dataflow<A: Allocate>(
                     // TODO: provide a more meaningful flow control input
                     &timely::dataflow::operators::generic::operator::empty(region),
                     NO_FLOW_CONTROL,
-                    // Copy the logic in DeltaJoin/Get/Join to start.
-                    |_timer, count| count > 1_000_000,
+                    move |_timer, count| count > yield_threshold,
                 );
 
                 // If `mfp` is non-identity, we need to apply what remains.
@@ -241,10 +238,11 @@ pub fn build_compute_dataflow<A: Allocate>(
         });
 
         if recursive {
-            scope.clone().iterative::<usize, _, _>(|region| {
+            scope.clone().iterative::<u64, _, _>(|region| {
                 let mut context = crate::render::context::Context::for_dataflow(
                     &dataflow,
                     scope.addr().into_element(),
+                    compute_state.compute_logger.clone(),
                 );
 
                 for (id, (oks, errs)) in imported_sources.into_iter() {
@@ -317,6 +315,7 @@ pub fn build_compute_dataflow<A: Allocate>(
                 let mut context = crate::render::context::Context::for_dataflow(
                     &dataflow,
                     scope.addr().into_element(),
+                    compute_state.compute_logger.clone(),
                 );
 
                 for (id, (oks, errs)) in imported_sources.into_iter() {
@@ -601,11 +600,50 @@ where
     ///
     /// The return type reflects the uncertainty about the data representation, perhaps
     /// as a stream of data, perhaps as an arrangement, perhaps as a stream of batches.
+    ///
+    /// Each plan node is assigned its [`LirId`](mz_compute_client::plan::LirId) and, if
+    /// logging is enabled, logged along with the range of Timely operators built for it.
     pub fn render_plan(
         &mut self,
         plan: Plan,
         scope: &mut G,
         worker_index: usize,
+    ) -> CollectionBundle<G, Row> {
+        let lir_id = self.next_lir_id;
+        self.next_lir_id = lir_id.next();
+        let parent_lir_id = self.lir_stack.last().copied();
+        let nesting = u64::cast_from(self.lir_stack.len());
+        let operator = self.compute_logger.is_some().then(|| plan.node_name());
+
+        // Timely hands out operator identifiers in increasing order, so bracketing the
+        // rendering with fresh identifiers captures the operators built for this node and
+        // its inputs.
+        let operator_id_start = scope.new_identifier() + 1;
+        self.lir_stack.push(lir_id);
+        let bundle = self.render_plan_node(plan, scope, worker_index);
+        self.lir_stack.pop();
+        let operator_id_end = scope.new_identifier();
+
+        if let (Some(logger), Some(operator)) = (&self.compute_logger, operator) {
+            for export_id in self.export_ids.iter() {
+                logger.log(ComputeEvent::LirMapping {
+                    export_id: *export_id,
+                    lir_id,
+                    operator: operator.clone(),
+                    parent_lir_id,
+                    nesting,
+                    operator_span: (operator_id_start, operator_id_end),
+                });
+            }
+        }
+        bundle
+    }
+
+    fn render_plan_node(
+        &mut self,
+        plan: Plan,
+        scope: &mut G,
+        worker_index: usize,
     ) -> CollectionBundle<G, Row> {
         match plan {
             Plan::Constant { rows } => {
@@ -696,6 +734,71 @@ where
                 self.remove_id(Id::Local(id));
                 body
             }
+            Plan::LetRec {
+                ids,
+                values,
+                max_iters,
+                body,
+            } => {
+                use differential_dataflow::operators::iterate::Variable;
+                use differential_dataflow::operators::{Consolidate, Threshold};
+                use timely::dataflow::operators::{Branch, Map};
+
+                // Bind each `id` to a variable, which presents the contents of its
+                // value from the prior iteration. Complain if this shadows an id.
+                let mut variables = BTreeMap::new();
+                for id in ids.iter() {
+                    let oks_v =
+                        Variable::new(scope, <G::Timestamp as RenderTimestamp>::iteration_delay(1));
+                    let err_v =
+                        Variable::new(scope, <G::Timestamp as RenderTimestamp>::iteration_delay(1));
+                    let prebound = self.insert_id(
+                        Id::Local(*id),
+                        CollectionBundle::from_collections(oks_v.clone(), err_v.clone()),
+                    );
+                    assert!(prebound.is_none());
+                    variables.insert(Id::Local(*id), (oks_v, err_v));
+                }
+                // Render each value in sequence, and rebind `id` to its result, so that
+                // subsequent values observe the contents from the current iteration.
+                for (id, value) in ids.iter().zip(values.into_iter()) {
+                    let bundle = self.render_plan(value, scope, worker_index);
+                    let (oks, mut errs) = bundle.as_specific_collection(None);
+                    // Consolidation is required for the iteration to come to rest once
+                    // the contents have stopped changing.
+                    let mut oks = oks.consolidate();
+                    if let Some(max_iters) = max_iters {
+                        // Changes at or after iteration `max_iters` are withheld, and
+                        // reported as errors instead.
+                        let (in_limit, over_limit) = oks
+                            .inner
+                            .branch(move |_, (_, time, _)| time.iteration() >= max_iters);
+                        oks = in_limit.as_collection();
+                        let limit_errs = over_limit
+                            .map(move |(_, time, _)| {
+                                let err = EvalError::LetRecLimitExceeded(max_iters);
+                                (DataflowError::from(err), time, 1)
+                            })
+                            .as_collection();
+                        errs = errs.concat(&limit_errs);
+                    }
+                    // Errors are reported at most once, which also brings them to rest.
+                    let errs = errs.distinct_core::<Diff>();
+                    let (oks_v, err_v) = variables.remove(&Id::Local(*id)).unwrap();
+                    oks_v.set(&oks);
+                    err_v.set(&errs);
+                    self.insert_id(
+                        Id::Local(*id),
+                        CollectionBundle::from_collections(oks, errs),
+                    );
+                }
+
+                let body = self.render_plan(*body, scope, worker_index);
+                for id in ids {
+                    self.remove_id(Id::Local(id));
+                }
+                body
+            }
             Plan::Mfp {
                 input,
                 mfp,
@@ -748,6 +851,16 @@ where
                 let input = self.render_plan(*input, scope, worker_index);
                 self.render_topk(input, top_k_plan)
             }
+            Plan::Window {
+                input,
+                key_val_plan,
+                window_plan,
+                mfp,
+                input_key,
+            } => {
+                let input = self.render_plan(*input, scope, worker_index);
+                self.render_window(input, key_val_plan, window_plan, mfp, input_key)
+            }
             Plan::Negate { input } => {
                 let input = self.render_plan(*input, scope, worker_index);
                 let (oks, errs) = input.as_specific_collection(None);
@@ -807,6 +920,13 @@ pub trait RenderTimestamp: Timestamp + Lattice + Refines<mz_repr::Timestamp> {
     /// Steps the timestamp back so that logical compaction to the output will
     /// not conflate `self` with any historical times.
     fn step_back(&self) -> Self;
+    /// The iteration component of the timestamp, which is zero outside of
+    /// iterative scopes.
+    fn iteration(&self) -> u64;
+    /// Effects an iteration delay in terms of the timestamp summary.
+    ///
+    /// Panics if the timestamp is not that of an iterative scope.
+    fn iteration_delay(delay: u64) -> <Self as Timestamp>::Summary;
 }
 
 impl RenderTimestamp for mz_repr::Timestamp {
@@ -825,9 +945,15 @@ impl RenderTimestamp for mz_repr::Timestamp {
     fn step_back(&self) -> Self {
         self.saturating_sub(1)
     }
+    fn iteration(&self) -> u64 {
+        0
+    }
+    fn iteration_delay(_delay: u64) -> <Self as Timestamp>::Summary {
+        panic!("iterations can only be delayed in iterative scopes")
+    }
 }
 
-impl<T: Timestamp + Lattice> RenderTimestamp for Product<mz_repr::Timestamp, T> {
+impl RenderTimestamp for Product<mz_repr::Timestamp, u64> {
     fn system_time(&mut self) -> &mut mz_repr::Timestamp {
         &mut self.outer
     }
@@ -841,6 +967,12 @@ impl<T: Timestamp + Lattice> RenderTimestamp for Product<mz_repr::Timestamp, T>
         Product::new(delay, Default::default())
     }
     fn step_back(&self) -> Self {
-        Product::new(self.outer.saturating_sub(1), self.inner.clone())
+        Product::new(self.outer.saturating_sub(1), self.inner)
+    }
+    fn iteration(&self) -> u64 {
+        self.inner
+    }
+    fn iteration_delay(delay: u64) -> <Self as Timestamp>::Summary {
+        Product::new(Default::default(), delay)
     }
 }
@@ -0,0 +1,863 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Window function execution logic.
+//!
+//! Consult [WindowPlan] documentation for details.
+
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+use differential_dataflow::consolidation::consolidate;
+use differential_dataflow::hashable::Hashable;
+use differential_dataflow::{AsCollection, Collection};
+use timely::dataflow::channels::pact::Exchange;
+use timely::dataflow::operators::{Capability, Operator};
+use timely::dataflow::Scope;
+
+use mz_compute_client::plan::reduce::KeyValPlan;
+use mz_compute_client::plan::window::{LagLeadPlan, PrefixSumPlan, WindowPlan};
+use mz_expr::{AggregateFunc, ColumnOrder, LagLeadType, MapFilterProject, MirScalarExpr};
+use mz_ore::soft_assert_or_log;
+use mz_repr::adt::numeric::{self, Numeric};
+use mz_repr::{Datum, DatumList, DatumVec, Diff, Row};
+
+use crate::render::context::{CollectionBundle, Context};
+use crate::render::reduce::render_key_val;
+
+impl<G> Context<G, Row>
+where
+    G: Scope,
+    G::Timestamp: crate::render::RenderTimestamp,
+{
+    /// Renders a window function by maintaining each partition in window order,
+    /// and only revisiting the positions a change can affect.
+    pub(crate) fn render_window(
+        &mut self,
+        input: CollectionBundle<G, Row>,
+        key_val_plan: KeyValPlan,
+        window_plan: WindowPlan,
+        mfp: MapFilterProject,
+        input_key: Option<Vec<MirScalarExpr>>,
+    ) -> CollectionBundle<G, Row> {
+        let (ok_input, err_input) = render_key_val(input, key_val_plan, input_key);
+
+        // We create a new region to compartmentalize the window logic.
+        let ok_result = ok_input.scope().region_named("Window", |inner| {
+            let ok_input = ok_input.enter(inner);
+            match window_plan {
+                WindowPlan::LagLead(plan) => {
+                    let mut window = LagLeadWindow::new(plan);
+                    build_window(ok_input, move |key, partition, updates, results| {
+                        window.update(key, partition, updates, results)
+                    })
+                }
+                plan @ (WindowPlan::RowNumber(_) | WindowPlan::PrefixSum(_)) => {
+                    let mut window = PrefixWindow::new(plan);
+                    build_window(ok_input, move |key, partition, updates, results| {
+                        window.update(key, partition, updates, results);
+                    })
+                }
+            }
+            .leave_region()
+        });
+
+        let bundle = CollectionBundle::from_collections(ok_result, err_input);
+        if mfp.is_identity() {
+            bundle
+        } else {
+            let (oks, errs) = bundle.as_collection_core(mfp, None, self.until.clone());
+            CollectionBundle::from_collections(oks, errs)
+        }
+    }
+}
+
+/// Constructs the operator maintaining a window function, where `apply` applies
+/// the updates of a partition to it, and appends the resulting changes to the
+/// output to its last argument.
+///
+/// Updates are exchanged by partition key, and each worker keeps the partitions it
+/// owns in window order. The updates of a time are applied once the time is
+/// complete, in timestamp order, which is why timestamps must be totally ordered.
+fn build_window<G, S, F>(
+    collection: Collection<G, (Row, Row), Diff>,
+    mut apply: F,
+) -> Collection<G, Row, Diff>
+where
+    G: Scope,
+    S: Default + 'static,
+    F: FnMut(&Row, &mut Partition<S>, &[((Row, Row), Diff)], &mut Vec<(Row, Diff)>) + 'static,
+{
+    let exchange = Exchange::new(
+        |((key, _val), _time, _diff): &((Row, Row), G::Timestamp, Diff)| key.hashed(),
+    );
+    collection
+        .inner
+        .unary_frontier(exchange, "Window", move |_capability, _info| {
+            let mut buffer = Vec::new();
+            // Updates that have not been applied yet, with a capability for their time.
+            let mut pending: BTreeMap<G::Timestamp, (Capability<G::Timestamp>, Vec<_>)> =
+                BTreeMap::new();
+            let mut partitions: BTreeMap<Row, Partition<S>> = BTreeMap::new();
+            move |input, output| {
+                input.for_each(|capability, data| {
+                    data.swap(&mut buffer);
+                    for (update, time, diff) in buffer.drain(..) {
+                        pending
+                            .entry(time.clone())
+                            .or_insert_with(|| (capability.delayed(&time), Vec::new()))
+                            .1
+                            .push((update, diff));
+                    }
+                });
+
+                while let Some(time) = pending.keys().next().cloned() {
+                    if input.frontier().less_equal(&time) {
+                        break;
+                    }
+                    let (capability, mut updates) = pending.remove(&time).unwrap();
+                    consolidate(&mut updates);
+
+                    let mut results = Vec::new();
+                    let mut updates = &updates[..];
+                    while let Some(((key, _val), _diff)) = updates.first() {
+                        let len = updates.iter().take_while(|((k, _), _)| k == key).count();
+                        let partition = partitions.entry(key.clone()).or_default();
+                        apply(key, partition, &updates[..len], &mut results);
+                        if partition.chunks.is_empty() {
+                            partitions.remove(key);
+                        }
+                        updates = &updates[len..];
+                    }
+
+                    consolidate(&mut results);
+                    output.session(&capability).give_iterator(
+                        results
+                            .into_iter()
+                            .map(|(row, diff)| (row, time.clone(), diff)),
+                    );
+                }
+            }
+        })
+        .as_collection()
+}
+
+/// The number of entries at which a chunk of a partition is split in two.
+const CHUNK_CAPACITY: usize = 2048;
+
+/// Copies of a single window function input within a partition.
+struct Entry<S = ()> {
+    /// The window function input, as produced by the key-value plan.
+    val: Row,
+    /// The number of copies of the input.
+    count: Diff,
+    /// State that the window function keeps for the entry.
+    state: S,
+}
+
+impl<S> Entry<S> {
+    /// The number of positions the entry occupies.
+    ///
+    /// Entries with non-positive counts occupy no positions.
+    fn positions(&self) -> usize {
+        usize::try_from(self.count).unwrap_or(0)
+    }
+}
+
+/// A run of consecutive entries of a partition.
+#[derive(Default)]
+struct Chunk<S = ()> {
+    entries: Vec<Entry<S>>,
+    /// The number of positions the entries occupy.
+    positions: usize,
+}
+
+/// The entries of a partition, in window order.
+///
+/// Entries are kept in chunks of bounded length that record the number of
+/// positions their entries occupy. Finding an entry or a position thus consults
+/// the chunk summaries and then a single chunk, rather than the whole partition.
+/// Each entry also holds state of type `S` that the window function maintains.
+#[derive(Default)]
+struct Partition<S = ()> {
+    chunks: Vec<Chunk<S>>,
+    /// The number of positions the entries occupy.
+    positions: usize,
+    /// The number of copies of each signed offset used by `lag` and `lead`,
+    /// where positive offsets refer to earlier positions.
+    offsets: BTreeMap<i64, Diff>,
+}
+
+impl<S: Default> Partition<S> {
+    /// The number of positions occupied by entries that order before `val`, or
+    /// also by `val` itself if `inclusive` is set.
+    fn position(&self, val: &Row, inclusive: bool, order: &mut WindowOrder) -> usize {
+        let before = |entry: &Entry<S>, order: &mut WindowOrder| match order.cmp(&entry.val, val) {
+            Ordering::Less => true,
+            Ordering::Equal => inclusive,
+            Ordering::Greater => false,
+        };
+        let chunk_index = self
+            .chunks
+            .partition_point(|chunk| before(chunk.entries.last().unwrap(), order));
+        let mut position = self.chunks[..chunk_index]
+            .iter()
+            .map(|chunk| chunk.positions)
+            .sum();
+        if let Some(chunk) = self.chunks.get(chunk_index) {
+            let entry_index = chunk.entries.partition_point(|entry| before(entry, order));
+            position += chunk.entries[..entry_index]
+                .iter()
+                .map(Entry::positions)
+                .sum::<usize>();
+        }
+        position
+    }
+
+    /// Adds `diff` copies of `val`.
+    fn update(&mut self, val: &Row, diff: Diff, order: &mut WindowOrder) {
+        if self.chunks.is_empty() {
+            self.chunks.push(Chunk::default());
+        }
+        // The first chunk whose entries do not all order before `val`, or the
+        // last chunk if there is none.
+        let chunk_index = self
+            .chunks
+            .partition_point(|chunk| {
+                order.cmp(&chunk.entries.last().unwrap().val, val) == Ordering::Less
+            })
+            .min(self.chunks.len() - 1);
+        let chunk = &mut self.chunks[chunk_index];
+
+        let before = chunk.positions;
+        match chunk
+            .entries
+            .binary_search_by(|entry| order.cmp(&entry.val, val))
+        {
+            Ok(index) => {
+                let entry = &mut chunk.entries[index];
+                chunk.positions -= entry.positions();
+                entry.count += diff;
+                chunk.positions += entry.positions();
+                soft_assert_or_log!(
+                    entry.count >= 0,
+                    "[customer-data] Negative accumulation in Window: {:?} with count {:?}",
+                    entry.val,
+                    entry.count,
+                );
+                if entry.count == 0 {
+                    chunk.entries.remove(index);
+                }
+            }
+            Err(index) => {
+                let entry = Entry {
+                    val: val.clone(),
+                    count: diff,
+                    state: S::default(),
+                };
+                chunk.positions += entry.positions();
+                chunk.entries.insert(index, entry);
+            }
+        }
+        self.positions = self.positions - before + chunk.positions;
+
+        if chunk.entries.is_empty() {
+            self.chunks.remove(chunk_index);
+        } else if chunk.entries.len() >= CHUNK_CAPACITY {
+            let entries = chunk.entries.split_off(CHUNK_CAPACITY / 2);
+            let positions = entries.iter().map(Entry::positions).sum();
+            chunk.positions -= positions;
+            self.chunks
+                .insert(chunk_index + 1, Chunk { entries, positions });
+        }
+    }
+
+    /// Returns the entries occupying the positions from `start` onwards, once per
+    /// position.
+    fn iter_from(&self, start: usize) -> impl Iterator<Item = &Entry<S>> {
+        let mut skip = start;
+        let mut chunk_index = 0;
+        while chunk_index < self.chunks.len() && skip >= self.chunks[chunk_index].positions {
+            skip -= self.chunks[chunk_index].positions;
+            chunk_index += 1;
+        }
+        self.chunks[chunk_index..]
+            .iter()
+            .flat_map(|chunk| chunk.entries.iter())
+            .flat_map(move |entry| {
+                let positions = entry.positions();
+                let first = std::cmp::min(skip, positions);
+                skip -= first;
+                (first..positions).map(move |_| entry)
+            })
+    }
+
+    /// Returns the entry occupying `position`.
+    fn get(&self, position: usize) -> Option<&Entry<S>> {
+        self.iter_from(position).next()
+    }
+
+    /// The index of the first entry that does not order before `val`, as the
+    /// index of its chunk and its index within the chunk.
+    fn locate(&self, val: &Row, order: &mut WindowOrder) -> (usize, usize) {
+        let chunk_index = self.chunks.partition_point(|chunk| {
+            order.cmp(&chunk.entries.last().unwrap().val, val) == Ordering::Less
+        });
+        let entry_index = self.chunks.get(chunk_index).map_or(0, |chunk| {
+            chunk
+                .entries
+                .partition_point(|entry| order.cmp(&entry.val, val) == Ordering::Less)
+        });
+        (chunk_index, entry_index)
+    }
+
+    /// The entry at `index`, as returned by [Partition::locate].
+    fn entry(&self, (chunk_index, entry_index): (usize, usize)) -> Option<&Entry<S>> {
+        self.chunks.get(chunk_index)?.entries.get(entry_index)
+    }
+
+    /// The entry at `index`, as returned by [Partition::locate].
+    fn entry_mut(&mut self, (chunk_index, entry_index): (usize, usize)) -> Option<&mut Entry<S>> {
+        self.chunks
+            .get_mut(chunk_index)?
+            .entries
+            .get_mut(entry_index)
+    }
+
+    /// The entry before the one at `index`.
+    fn previous(&self, (chunk_index, entry_index): (usize, usize)) -> Option<&Entry<S>> {
+        match entry_index.checked_sub(1) {
+            Some(entry_index) => self.entry((chunk_index, entry_index)),
+            None => self.chunks.get(chunk_index.checked_sub(1)?)?.entries.last(),
+        }
+    }
+
+    /// The index of the entry after the one at `index`.
+    fn next_index(&self, (chunk_index, entry_index): (usize, usize)) -> (usize, usize) {
+        if entry_index + 1 < self.chunks[chunk_index].entries.len() {
+            (chunk_index, entry_index + 1)
+        } else {
+            (chunk_index + 1, 0)
+        }
+    }
+
+    /// The largest number of positions that `lag` and `lead` look backwards and
+    /// forwards, respectively.
+    fn reach(&self) -> (usize, usize) {
+        let backwards = self.offsets.keys().next_back().copied().unwrap_or(0);
+        let forwards = self.offsets.keys().next().copied().unwrap_or(0);
+        (
+            usize::try_from(backwards).unwrap_or(0),
+            usize::try_from(-forwards).unwrap_or(0),
+        )
+    }
+}
+
+/// Compares window function inputs by their `order_by` expressions.
+struct WindowOrder {
+    order_by: Vec<ColumnOrder>,
+    left: DatumVec,
+    right: DatumVec,
+}
+
+impl WindowOrder {
+    fn new(order_by: &[ColumnOrder]) -> Self {
+        Self {
+            order_by: order_by.to_vec(),
+            left: DatumVec::new(),
+            right: DatumVec::new(),
+        }
+    }
+
+    fn cmp(&mut self, left: &Row, right: &Row) -> Ordering {
+        let mut left_datums = self.left.borrow();
+        left_datums.extend(window_input(left).iter().skip(1));
+        let mut right_datums = self.right.borrow();
+        right_datums.extend(window_input(right).iter().skip(1));
+        mz_expr::compare_columns(&self.order_by, &left_datums, &right_datums, || {
+            left.cmp(right)
+        })
+    }
+}
+
+/// Returns the record a window function is applied to, which holds the input of
+/// the function followed by the `order_by` expressions.
+fn window_input(val: &Row) -> DatumList<'_> {
+    val.unpack_first().unwrap_list()
+}
+
+/// The arguments of `lag` and `lead` presented by a window function input.
+struct LagLeadArgs<'a> {
+    original_row: Datum<'a>,
+    value: Datum<'a>,
+    /// The signed offset, where positive offsets refer to earlier positions.
+    offset: Option<i64>,
+    default: Datum<'a>,
+}
+
+impl<'a> LagLeadArgs<'a> {
+    /// Decodes `((OriginalRow, (InputValue, Offset, Default)), OrderByExprs...)`.
+    fn decode(val: &'a Row, lag_lead: &LagLeadType) -> Self {
+        let mut input = window_input(val)
+            .iter()
+            .next()
+            .unwrap()
+            .unwrap_list()
+            .iter();
+        let original_row = input.next().unwrap();
+        let mut args = input.next().unwrap().unwrap_list().iter();
+        let (value, offset, default) = (
+            args.next().unwrap(),
+            args.next().unwrap(),
+            args.next().unwrap(),
+        );
+        let offset = (!offset.is_null()).then(|| {
+            let offset = i64::from(offset.unwrap_int32());
+            match lag_lead {
+                LagLeadType::Lag => offset,
+                LagLeadType::Lead => -offset,
+            }
+        });
+        Self {
+            original_row,
+            value,
+            offset,
+            default,
+        }
+    }
+}
+
+/// The state and logic needed to maintain `lag` or `lead` across partitions.
+struct LagLeadWindow {
+    plan: LagLeadPlan,
+    order: WindowOrder,
+    row_buf: Row,
+}
+
+impl LagLeadWindow {
+    fn new(plan: LagLeadPlan) -> Self {
+        let order = WindowOrder::new(&plan.order_by);
+        Self {
+            plan,
+            order,
+            row_buf: Row::default(),
+        }
+    }
+
+    /// Applies `updates` to `partition`, and appends the resulting changes to the
+    /// output to `results`.
+    ///
+    /// The changes are formed by retracting the output for the positions the
+    /// updates can affect, and re-emitting it after the updates are applied.
+    /// Outputs that do not change cancel once `results` is consolidated.
+    fn update(
+        &mut self,
+        key: &Row,
+        partition: &mut Partition,
+        updates: &[((Row, Row), Diff)],
+        results: &mut Vec<(Row, Diff)>,
+    ) {
+        let mut vals = updates
+            .iter()
+            .map(|((_key, val), _diff)| val)
+            .collect::<Vec<_>>();
+        vals.sort_by(|left, right| self.order.cmp(left, right));
+        let (first, last) = (vals[0], vals[vals.len() - 1]);
+
+        // The positions before `start` and after `end` hold the same entries before and
+        // after the updates, and the entries after `end` are shifted by the same amount.
+        let start = partition.position(first, false, &mut self.order);
+        let old_end = partition.position(last, true, &mut self.order);
+
+        // The number of positions around the updates whose output can change.
+        for ((_key, val), diff) in updates {
+            if let Some(offset) = LagLeadArgs::decode(val, &self.plan.lag_lead).offset {
+                let count = partition.offsets.entry(offset).or_default();
+                *count += diff;
+            }
+        }
+        let (backwards, forwards) = partition.reach();
+        partition.offsets.retain(|_offset, count| *count != 0);
+        let (before, after) = (forwards, backwards);
+
+        let old_range = start.saturating_sub(before)..old_end.saturating_add(after);
+        self.emit(key, partition, old_range, -1, results);
+
+        for ((_key, val), diff) in updates {
+            partition.update(val, *diff, &mut self.order);
+        }
+
+        let new_end = partition.position(last, true, &mut self.order);
+        let new_range = start.saturating_sub(before)..new_end.saturating_add(after);
+        self.emit(key, partition, new_range, 1, results);
+    }
+
+    /// Appends the output for the positions in `range` to `results`, with `diff`.
+    fn emit(
+        &mut self,
+        key: &Row,
+        partition: &Partition,
+        range: Range<usize>,
+        diff: Diff,
+        results: &mut Vec<(Row, Diff)>,
+    ) {
+        let lag_lead = &self.plan.lag_lead;
+        let len = range
+            .end
+            .min(partition.positions)
+            .saturating_sub(range.start);
+        let entries = partition.iter_from(range.start).take(len);
+        for (position, entry) in (range.start..).zip(entries) {
+            let args = LagLeadArgs::decode(&entry.val, lag_lead);
+            let value = match args.offset {
+                // Null offsets are acceptable, and always return null.
+                None => Datum::Null,
+                Some(offset) => i64::try_from(position)
+                    .ok()
+                    .and_then(|position| position.checked_sub(offset))
+                    .and_then(|position| usize::try_from(position).ok())
+                    .and_then(|position| partition.get(position))
+                    .map(|other| LagLeadArgs::decode(&other.val, lag_lead).value)
+                    .unwrap_or(args.default),
+            };
+            let mut packer = self.row_buf.packer();
+            packer.extend_by_row(key);
+            packer.push_list(vec![value, args.original_row]);
+            results.push((self.row_buf.clone(), diff));
+        }
+    }
+}
+
+/// The state and logic needed to maintain `row_number` or a [PrefixSumPlan]
+/// across partitions.
+///
+/// Each entry of a partition holds the state of the prefix before it, which its
+/// output was last formed from. The state is kept per entry, rather than summed
+/// per chunk, because floating point sums depend on the order of their inputs,
+/// and the output must be retracted exactly as it was emitted.
+struct PrefixWindow {
+    plan: WindowPlan,
+    order: WindowOrder,
+    row_buf: Row,
+}
+
+impl PrefixWindow {
+    fn new(plan: WindowPlan) -> Self {
+        let order = WindowOrder::new(plan.order_by());
+        Self {
+            plan,
+            order,
+            row_buf: Row::default(),
+        }
+    }
+
+    /// Applies `updates` to `partition`, and appends the resulting changes to the
+    /// output to `results`. Returns the number of entries whose output was
+    /// revisited.
+    ///
+    /// The output for the entries before the first update does not change, and
+    /// the state before the first update is formed from the state held by the
+    /// entry before it. The output for the entries after the last update only
+    /// changes up to the first entry whose state does not change, as the states
+    /// after it do not change either.
+    fn update(
+        &mut self,
+        key: &Row,
+        partition: &mut Partition<PrefixState>,
+        updates: &[((Row, Row), Diff)],
+        results: &mut Vec<(Row, Diff)>,
+    ) -> usize {
+        let vals = || updates.iter().map(|((_key, val), _diff)| val);
+        let first = vals().min_by(|l, r| self.order.cmp(l, r)).unwrap();
+        let last = vals().max_by(|l, r| self.order.cmp(l, r)).unwrap();
+        let mut revisited = 0;
+
+        let index = partition.locate(first, &mut self.order);
+        let mut state = match partition.previous(index) {
+            Some(entry) => {
+                let mut state = entry.state.clone();
+                self.walk(key, entry, &mut state, 0, results);
+                state
+            }
+            None => PrefixState::new(&self.plan),
+        };
+
+        let mut index = index;
+        while let Some(entry) = partition.entry(index) {
+            if self.order.cmp(&entry.val, last) == Ordering::Greater {
+                break;
+            }
+            self.retract(key, entry, results);
+            revisited += 1;
+            index = partition.next_index(index);
+        }
+
+        for ((_key, val), diff) in updates {
+            partition.update(val, *diff, &mut self.order);
+        }
+
+        let mut index = partition.locate(first, &mut self.order);
+        let mut after_updates = false;
+        while let Some(entry) = partition.entry_mut(index) {
+            after_updates = after_updates || self.order.cmp(&entry.val, last) == Ordering::Greater;
+            if after_updates {
+                if entry.state == state {
+                    break;
+                }
+                self.retract(key, entry, results);
+            }
+            entry.state = state.clone();
+            self.walk(key, entry, &mut state, 1, results);
+            revisited += 1;
+            index = partition.next_index(index);
+        }
+        revisited
+    }
+
+    /// Appends the retraction of the output of `entry` to `results`.
+    fn retract(&mut self, key: &Row, entry: &Entry<PrefixState>, results: &mut Vec<(Row, Diff)>) {
+        let mut state = entry.state.clone();
+        self.walk(key, entry, &mut state, -1, results);
+    }
+
+    /// Advances `state` past `entry`, and appends its output to `results` with
+    /// `diff`. A `diff` of zero only advances `state`.
+    fn walk(
+        &mut self,
+        key: &Row,
+        entry: &Entry<PrefixState>,
+        state: &mut PrefixState,
+        diff: Diff,
+        results: &mut Vec<(Row, Diff)>,
+    ) {
+        let input = window_input(&entry.val)
+            .iter()
+            .next()
+            .unwrap()
+            .unwrap_list();
+        for _ in 0..entry.positions() {
+            match &self.plan {
+                WindowPlan::RowNumber(_) => {
+                    for row in input.iter() {
+                        state.position += 1;
+                        if diff != 0 {
+                            let row_number =
+                                i64::try_from(state.position).expect("row number fits in i64");
+                            let mut packer = self.row_buf.packer();
+                            packer.extend_by_row(key);
+                            packer.push_list(vec![Datum::Int64(row_number), row]);
+                            results.push((self.row_buf.clone(), diff));
+                        }
+                    }
+                }
+                WindowPlan::PrefixSum(PrefixSumPlan { aggregate, .. }) => {
+                    // The input is `(OriginalRow, InputValue)`.
+                    let mut args = input.iter();
+                    let (original_row, value) = (args.next().unwrap(), args.next().unwrap());
+                    state.add(value);
+                    if diff != 0 {
+                        let mut packer = self.row_buf.packer();
+                        packer.extend_by_row(key);
+                        packer.push_list(vec![state.value(aggregate), original_row]);
+                        results.push((self.row_buf.clone(), diff));
+                    }
+                }
+                WindowPlan::LagLead(_) => unreachable!(),
+            }
+        }
+    }
+}
+
+/// The state of a prefix of a partition, from which the output for the next
+/// position is formed.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct PrefixState {
+    /// The number of positions in the prefix, which only `row_number` counts.
+    position: usize,
+    /// The number of non-null aggregate inputs in the prefix.
+    non_null: i64,
+    /// The sum of the non-null aggregate inputs in the prefix.
+    sum: PrefixSum,
+}
+
+impl PrefixState {
+    fn new(plan: &WindowPlan) -> Self {
+        let sum = match plan {
+            WindowPlan::PrefixSum(PrefixSumPlan { aggregate, .. }) => match aggregate {
+                AggregateFunc::SumInt16 | AggregateFunc::SumInt32 | AggregateFunc::SumInt64 => {
+                    PrefixSum::Int(0)
+                }
+                AggregateFunc::SumUInt16 | AggregateFunc::SumUInt32 | AggregateFunc::SumUInt64 => {
+                    PrefixSum::UInt(0)
+                }
+                AggregateFunc::SumFloat32 => PrefixSum::Float32(0.0),
+                AggregateFunc::SumFloat64 => PrefixSum::Float64(0.0),
+                AggregateFunc::SumNumeric => PrefixSum::Numeric(Numeric::zero()),
+                _ => PrefixSum::None,
+            },
+            _ => PrefixSum::None,
+        };
+        Self {
+            position: 0,
+            non_null: 0,
+            sum,
+        }
+    }
+
+    /// Adds an aggregate input to the prefix.
+    fn add(&mut self, value: Datum) {
+        if value.is_null() {
+            return;
+        }
+        self.non_null += 1;
+        match (&mut self.sum, value) {
+            (PrefixSum::None, _) => {}
+            (PrefixSum::Int(sum), Datum::Int16(v)) => *sum += i128::from(v),
+            (PrefixSum::Int(sum), Datum::Int32(v)) => *sum += i128::from(v),
+            (PrefixSum::Int(sum), Datum::Int64(v)) => *sum += i128::from(v),
+            (PrefixSum::UInt(sum), Datum::UInt16(v)) => *sum += u128::from(v),
+            (PrefixSum::UInt(sum), Datum::UInt32(v)) => *sum += u128::from(v),
+            (PrefixSum::UInt(sum), Datum::UInt64(v)) => *sum += u128::from(v),
+            (PrefixSum::Float32(sum), Datum::Float32(v)) => *sum += *v,
+            (PrefixSum::Float64(sum), Datum::Float64(v)) => *sum += *v,
+            (PrefixSum::Numeric(sum), Datum::Numeric(v)) => {
+                numeric::cx_datum().add(sum, &v.0);
+            }
+            (sum, value) => unreachable!("cannot add {:?} to {:?}", value, sum),
+        }
+    }
+
+    /// The value of `aggregate` over the prefix, matching that of
+    /// [AggregateFunc::eval].
+    fn value(&self, aggregate: &AggregateFunc) -> Datum<'static> {
+        match (aggregate, &self.sum) {
+            (AggregateFunc::Count, _) => Datum::Int64(self.non_null),
+            _ if self.non_null == 0 => Datum::Null,
+            (AggregateFunc::SumInt16 | AggregateFunc::SumInt32, PrefixSum::Int(sum)) => {
+                Datum::from(i64::try_from(*sum).expect("sum of 32-bit integers fits in i64"))
+            }
+            (AggregateFunc::SumInt64, PrefixSum::Int(sum)) => Datum::from(*sum),
+            (AggregateFunc::SumUInt16 | AggregateFunc::SumUInt32, PrefixSum::UInt(sum)) => {
+                Datum::from(u64::try_from(*sum).expect("sum of 32-bit integers fits in u64"))
+            }
+            (AggregateFunc::SumUInt64, PrefixSum::UInt(sum)) => Datum::from(*sum),
+            (AggregateFunc::SumFloat32, PrefixSum::Float32(sum)) => Datum::from(*sum),
+            (AggregateFunc::SumFloat64, PrefixSum::Float64(sum)) => Datum::from(*sum),
+            (AggregateFunc::SumNumeric, PrefixSum::Numeric(sum)) => Datum::from(*sum),
+            (aggregate, sum) => unreachable!("{} cannot be formed from {:?}", aggregate, sum),
+        }
+    }
+}
+
+/// The sum of the aggregate inputs of a prefix, in the representation that the
+/// aggregate sums them in.
+#[derive(Clone, Debug, Default)]
+enum PrefixSum {
+    /// `row_number` and `count` only need the number of positions and inputs.
+    #[default]
+    None,
+    Int(i128),
+    UInt(u128),
+    Float32(f32),
+    Float64(f64),
+    Numeric(Numeric),
+}
+
+impl PartialEq for PrefixSum {
+    fn eq(&self, other: &Self) -> bool {
+        // Sums are only equal if they are represented identically, as only then
+        // do they produce the same output once the same inputs are added to them.
+        match (self, other) {
+            (PrefixSum::None, PrefixSum::None) => true,
+            (PrefixSum::Int(left), PrefixSum::Int(right)) => left == right,
+            (PrefixSum::UInt(left), PrefixSum::UInt(right)) => left == right,
+            (PrefixSum::Float32(left), PrefixSum::Float32(right)) => {
+                left.to_bits() == right.to_bits()
+            }
+            (PrefixSum::Float64(left), PrefixSum::Float64(right)) => {
+                left.to_bits() == right.to_bits()
+            }
+            (PrefixSum::Numeric(left), PrefixSum::Numeric(right)) => {
+                left == right && left.exponent() == right.exponent()
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The input of a running sum of `value`, ordered by `order`, as produced by
+    /// the key-value plan.
+    fn sum_input(order: i64, value: i64) -> Row {
+        let mut row = Row::default();
+        row.packer().push_list_with(|packer| {
+            packer.push_list([Datum::Int64(order), Datum::Int64(value)]);
+            packer.push(Datum::Int64(order));
+        });
+        row
+    }
+
+    #[test]
+    fn prefix_sum_revisits_neighbourhood() {
+        let plan = WindowPlan::PrefixSum(PrefixSumPlan {
+            order_by: vec![ColumnOrder {
+                column: 0,
+                desc: false,
+                nulls_last: false,
+            }],
+            aggregate: AggregateFunc::SumInt64,
+        });
+        let key = Row::pack_slice(&[Datum::Int64(0)]);
+
+        for len in [1_000, 100_000] {
+            let mut window = PrefixWindow::new(plan.clone());
+            let mut partition = Partition::default();
+            let updates: Vec<_> = (0..len)
+                .map(|i| ((key.clone(), sum_input(i, i)), 1))
+                .collect();
+            let mut results = Vec::new();
+            window.update(&key, &mut partition, &updates, &mut results);
+            assert_eq!(results.len(), usize::try_from(len).unwrap());
+
+            // Swapping the values of two adjacent rows leaves the sums after them
+            // unchanged, so only those rows are revisited, however long the
+            // partition is.
+            let mid = len / 2;
+            let updates = vec![
+                ((key.clone(), sum_input(mid, mid)), -1),
+                ((key.clone(), sum_input(mid, mid + 1)), 1),
+                ((key.clone(), sum_input(mid + 1, mid + 1)), -1),
+                ((key.clone(), sum_input(mid + 1, mid)), 1),
+            ];
+            let mut results = Vec::new();
+            let revisited = window.update(&key, &mut partition, &updates, &mut results);
+            assert_eq!(revisited, 4);
+
+            consolidate(&mut results);
+            let sum_before = mid * (mid - 1) / 2;
+            let output = |sum: i64, order: i64| {
+                let mut row = Row::default();
+                let mut packer = row.packer();
+                packer.extend_by_row(&key);
+                packer.push_list([Datum::Int64(sum), Datum::Int64(order)]);
+                row
+            };
+            let mut expected = vec![
+                (output(sum_before + mid, mid), -1),
+                (output(sum_before + mid + 1, mid), 1),
+            ];
+            consolidate(&mut expected);
+            assert_eq!(results, expected);
+        }
+    }
+}
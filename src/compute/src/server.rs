@@ -12,6 +12,7 @@
 use std::cell::RefCell;
 use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
 use std::fmt::{Debug, Formatter};
+use std::path::PathBuf;
 use std::rc::Rc;
 use std::sync::{Arc, Mutex};
 
@@ -44,6 +45,7 @@ use mz_ore::halt;
 use mz_ore::metrics::MetricsRegistry;
 use mz_ore::now::NowFn;
 use mz_persist_client::cache::PersistClientCache;
+use mz_persist_client::encryption::KeyEncryptionKeys;
 use mz_persist_client::PersistConfig;
 use mz_service::client::{GenericClient, Partitioned};
 use mz_service::local::LocalClient;
@@ -63,6 +65,12 @@ pub struct Config {
     pub now: NowFn,
     /// Metrics registry through which dataflow metrics will be reported.
     pub metrics_registry: MetricsRegistry,
+    /// A local directory in which to cache persist blobs, if any.
+    pub persist_blob_cache_dir: Option<PathBuf>,
+    /// The maximum size of the persist blob cache.
+    pub persist_blob_cache_size_bytes: usize,
+    /// The keys with which to encrypt persist batch parts, if any.
+    pub persist_blob_encryption_keys: Option<Arc<KeyEncryptionKeys>>,
 }
 
 /// A client managing access to the local portion of a Timely cluster
@@ -108,10 +116,11 @@ pub fn serve(
     let trace_metrics = TraceMetrics::register_with(&config.metrics_registry);
     let compute_metrics = ComputeMetrics::register_with(&config.metrics_registry);
 
-    let persist_clients = PersistClientCache::new(
-        PersistConfig::new(config.build_info, config.now.clone()),
-        &config.metrics_registry,
-    );
+    let mut persist_cfg = PersistConfig::new(config.build_info, config.now.clone());
+    persist_cfg.blob_cache_dir = config.persist_blob_cache_dir;
+    persist_cfg.blob_cache_size_bytes = config.persist_blob_cache_size_bytes;
+    persist_cfg.blob_encryption_keys = config.persist_blob_encryption_keys;
+    let persist_clients = PersistClientCache::new(persist_cfg, &config.metrics_registry);
     let persist_clients = Arc::new(tokio::sync::Mutex::new(persist_clients));
     let tokio_executor = tokio::runtime::Handle::current();
     let timely_container = Arc::new(tokio::sync::Mutex::new(None));
@@ -597,7 +606,7 @@ impl<'w, A: Allocate> Worker<'w, A> {
 
     fn handle_command(&mut self, response_tx: &mut ResponseSender, cmd: ComputeCommand) {
         match &cmd {
-            ComputeCommand::CreateInstance(config) => {
+            ComputeCommand::CreateInstance(_) => {
                 self.compute_state = Some(ComputeState {
                     traces: TraceManager::new(
                         self.trace_metrics.clone(),
@@ -614,7 +623,8 @@ impl<'w, A: Allocate> Worker<'w, A> {
                     compute_logger: None,
                     persist_clients: Arc::clone(&self.persist_clients),
                     command_history: ComputeCommandHistory::default(),
-                    max_result_size: config.max_result_size,
+                    max_result_size: u32::MAX,
+                    persist_source_yield_threshold: 1_000_000,
                     metrics: self.compute_metrics.clone(),
                 });
             }
@@ -87,6 +87,7 @@ where
     // `persist_source` to select an appropriate `as_of`. We only care about times beyond the
     // current shard upper anyway.
     let source_as_of = None;
+    let yield_threshold = compute_state.persist_source_yield_threshold;
     let (ok_stream, err_stream, token) = mz_storage_client::source::persist_source::persist_source(
         &desired_collection.scope(),
         sink_id,
@@ -98,8 +99,7 @@ where
         // TODO: provide a more meaningful flow control input
         &timely::dataflow::operators::generic::operator::empty(scope),
         NO_FLOW_CONTROL,
-        // Copy the logic in DeltaJoin/Get/Join to start.
-        |_timer, count| count > 1_000_000,
+        move |_timer, count| count > yield_threshold,
     );
     use differential_dataflow::AsCollection;
     let persist_collection = ok_stream
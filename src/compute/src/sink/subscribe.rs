@@ -9,20 +9,25 @@
 
 use std::any::Any;
 use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
 use std::ops::DerefMut;
 use std::rc::Rc;
 
-use differential_dataflow::Collection;
+use differential_dataflow::consolidation::consolidate;
+use differential_dataflow::{Collection, Hashable};
 
 use timely::dataflow::channels::pact::Pipeline;
-use timely::dataflow::operators::Operator;
+use timely::dataflow::operators::{Exchange, Operator};
 use timely::dataflow::Scope;
 use timely::progress::timestamp::Timestamp as TimelyTimestamp;
 use timely::progress::Antichain;
+use timely::PartialOrder;
 
 use mz_compute_client::response::{SubscribeBatch, SubscribeResponse};
-use mz_compute_client::types::sinks::{ComputeSinkDesc, SinkAsOf, SubscribeSinkConnection};
-use mz_repr::{Diff, GlobalId, Row, Timestamp};
+use mz_compute_client::types::sinks::{
+    ComputeSinkDesc, SinkAsOf, SubscribeEnvelope, SubscribeEnvelopeKind, SubscribeSinkConnection,
+};
+use mz_repr::{Datum, Diff, GlobalId, Row, Timestamp};
 use mz_storage_client::controller::CollectionMetadata;
 use mz_storage_client::types::errors::DataflowError;
 
@@ -55,10 +60,16 @@ where
         })));
         let subscribe_protocol_weak = Rc::downgrade(&subscribe_protocol_handle);
 
+        let presenter = self
+            .envelope
+            .clone()
+            .map(|envelope| EnvelopePresenter::new(envelope, sink.from_desc.arity()));
         subscribe(
             sinked_collection,
             sink_id,
             sink.as_of.clone(),
+            self.up_to.clone(),
+            presenter,
             subscribe_protocol_handle,
         );
 
@@ -77,31 +88,196 @@ fn subscribe<G>(
     sinked_collection: Collection<G, Row, Diff>,
     sink_id: GlobalId,
     as_of: SinkAsOf,
+    up_to: Antichain<Timestamp>,
+    mut presenter: Option<EnvelopePresenter>,
     subscribe_protocol_handle: Rc<RefCell<Option<SubscribeProtocol>>>,
 ) where
     G: Scope<Timestamp = Timestamp>,
 {
     let mut results = Vec::new();
-    sinked_collection
-        .inner
-        .sink(Pipeline, &format!("subscribe-{}", sink_id), move |input| {
-            input.for_each(|_, rows| {
-                for (row, time, diff) in rows.iter() {
-                    let should_emit = if as_of.strict {
-                        as_of.frontier.less_than(time)
-                    } else {
-                        as_of.frontier.less_equal(time)
-                    };
-                    if should_emit {
-                        results.push((*time, row.clone(), *diff));
+    // Envelopes present all updates to a key together, so each key must be
+    // handled by a single worker.
+    let updates = match &presenter {
+        Some(presenter) => {
+            let key_indices = presenter.envelope.key_indices.clone();
+            sinked_collection
+                .inner
+                .exchange(move |(row, _time, _diff)| key_of(&key_indices, row).hashed())
+        }
+        None => sinked_collection.inner,
+    };
+    updates.sink(Pipeline, &format!("subscribe-{}", sink_id), move |input| {
+        input.for_each(|_, rows| {
+            for (row, time, diff) in rows.iter() {
+                if up_to.less_equal(time) {
+                    // The subscribe completes before this update.
+                    continue;
+                }
+                let should_emit = if as_of.strict {
+                    as_of.frontier.less_than(time)
+                } else {
+                    as_of.frontier.less_equal(time)
+                };
+                if should_emit {
+                    results.push((*time, row.clone(), *diff));
+                } else if let Some(presenter) = &mut presenter {
+                    presenter.absorb(row, *diff);
+                }
+            }
+        });
+
+        // Once the input frontier reaches `up_to`, there are no more updates
+        // to emit, and we can report the subscribe as complete.
+        let mut upper = input.frontier().frontier().to_owned();
+        if PartialOrder::less_equal(&up_to, &upper) {
+            upper = Antichain::new();
+        }
+        if let Some(subscribe_protocol) = subscribe_protocol_handle.borrow_mut().deref_mut() {
+            subscribe_protocol.send_batch(upper, &mut results, presenter.as_mut());
+        }
+    })
+}
+
+/// Returns the key of `row`.
+fn key_of(key_indices: &[usize], row: &Row) -> Row {
+    let datums = row.unpack();
+    Row::pack(key_indices.iter().map(|idx| datums[*idx]))
+}
+
+/// Presents the updates of a subscribe per key, as described by a
+/// [SubscribeEnvelope].
+///
+/// Each key that changes at a timestamp is presented as a single row of its
+/// state (`insert`, `upsert`, `delete` or `key_violation`), followed by the
+/// key, followed by the values of the non-key columns: the new values for
+/// `ENVELOPE UPSERT`, and both the old and the new values for
+/// `ENVELOPE DEBEZIUM`. Values that don't exist are null.
+struct EnvelopePresenter {
+    envelope: SubscribeEnvelope,
+    /// The number of non-key columns.
+    value_arity: usize,
+    /// The current rows of each key, if the key isn't known to be unique.
+    ///
+    /// When a key is unique, its old and new values at a timestamp are exactly
+    /// its retracted and inserted rows. Otherwise, we need the rows of the key
+    /// to tell its values apart from a violation of the key.
+    rows_by_key: Option<HashMap<Row, Vec<(Row, Diff)>>>,
+}
+
+impl EnvelopePresenter {
+    fn new(envelope: SubscribeEnvelope, arity: usize) -> Self {
+        let rows_by_key = (!envelope.key_is_unique).then(HashMap::new);
+        EnvelopePresenter {
+            value_arity: arity - envelope.key_indices.len(),
+            envelope,
+            rows_by_key,
+        }
+    }
+
+    /// Records an update that is not presented, because it's part of a
+    /// snapshot that wasn't requested.
+    fn absorb(&mut self, row: &Row, diff: Diff) {
+        if let Some(rows_by_key) = &mut self.rows_by_key {
+            let key = key_of(&self.envelope.key_indices, row);
+            let rows = rows_by_key.entry(key.clone()).or_default();
+            rows.push((row.clone(), diff));
+            consolidate(rows);
+            if rows.is_empty() {
+                rows_by_key.remove(&key);
+            }
+        }
+    }
+
+    /// Presents the given consolidated updates, which must be sorted by time.
+    fn present(&mut self, updates: Vec<(Timestamp, Row, Diff)>) -> Vec<(Timestamp, Row, Diff)> {
+        let mut presented = Vec::new();
+        let mut updates = updates.into_iter().peekable();
+        while let Some((time, _, _)) = updates.peek() {
+            let time = *time;
+            let mut changes_by_key = BTreeMap::<_, Vec<_>>::new();
+            while let Some((_, row, diff)) = updates.next_if(|(t, _, _)| *t == time) {
+                let key = key_of(&self.envelope.key_indices, &row);
+                changes_by_key.entry(key).or_default().push((row, diff));
+            }
+            for (key, changes) in changes_by_key {
+                let (before, after) = match &mut self.rows_by_key {
+                    Some(rows_by_key) => {
+                        let before = rows_by_key.remove(&key).unwrap_or_default();
+                        let mut after = before.clone();
+                        after.extend(changes);
+                        consolidate(&mut after);
+                        if !after.is_empty() {
+                            rows_by_key.insert(key.clone(), after.clone());
+                        }
+                        (before, after)
+                    }
+                    None => {
+                        let (mut before, after): (Vec<_>, Vec<_>) =
+                            changes.into_iter().partition(|(_, diff)| *diff < 0);
+                        for (_, diff) in before.iter_mut() {
+                            *diff = -*diff;
+                        }
+                        (before, after)
                     }
+                };
+                if let Some(row) = self.present_key(&key, &before, &after) {
+                    presented.push((time, row, 1));
                 }
-            });
+            }
+        }
+        presented
+    }
+
+    /// Presents the change of `key` from the rows in `before` to the rows in
+    /// `after`, or returns None if its value didn't change.
+    fn present_key(&self, key: &Row, before: &[(Row, Diff)], after: &[(Row, Diff)]) -> Option<Row> {
+        // The value of a key, or Err if it has more than one.
+        fn value(rows: &[(Row, Diff)]) -> Result<Option<&Row>, ()> {
+            match rows {
+                [] => Ok(None),
+                [(row, 1)] => Ok(Some(row)),
+                _ => Err(()),
+            }
+        }
+        let (before, after) = (value(before), value(after));
+        if before == after {
+            return None;
+        }
+        let (state, before, after) = match (before, after) {
+            (_, Err(())) => ("key_violation", None, None),
+            (Ok(None), Ok(Some(after))) => ("insert", None, Some(after)),
+            (before, Ok(Some(after))) => ("upsert", before.ok().flatten(), Some(after)),
+            (before, Ok(None)) => ("delete", before.ok().flatten(), None),
+        };
+        let state = match (self.envelope.kind, state) {
+            (SubscribeEnvelopeKind::Upsert, "insert") => "upsert",
+            (_, state) => state,
+        };
 
-            if let Some(subscribe_protocol) = subscribe_protocol_handle.borrow_mut().deref_mut() {
-                subscribe_protocol.send_batch(input.frontier().frontier().to_owned(), &mut results);
+        let key_indices = &self.envelope.key_indices;
+        let mut row = Row::default();
+        let mut packer = row.packer();
+        packer.push(Datum::String(state));
+        packer.extend(key.iter());
+        let mut push_value = |value: Option<&Row>| match value {
+            Some(value) => packer.extend(
+                value
+                    .iter()
+                    .enumerate()
+                    .filter(|(idx, _)| !key_indices.contains(idx))
+                    .map(|(_, datum)| datum),
+            ),
+            None => packer.extend(std::iter::repeat(Datum::Null).take(self.value_arity)),
+        };
+        match self.envelope.kind {
+            SubscribeEnvelopeKind::Upsert => push_value(after),
+            SubscribeEnvelopeKind::Debezium => {
+                push_value(before);
+                push_value(after);
             }
-        })
+        }
+        Some(row)
+    }
 }
 
 /// A type that guides the transmission of rows back to the coordinator.
@@ -116,7 +292,12 @@ struct SubscribeProtocol {
 }
 
 impl SubscribeProtocol {
-    fn send_batch(&mut self, upper: Antichain<Timestamp>, rows: &mut Vec<(Timestamp, Row, Diff)>) {
+    fn send_batch(
+        &mut self,
+        upper: Antichain<Timestamp>,
+        rows: &mut Vec<(Timestamp, Row, Diff)>,
+        presenter: Option<&mut EnvelopePresenter>,
+    ) {
         if self.prev_upper != upper {
             let mut ship = Vec::new();
             let mut keep = Vec::new();
@@ -129,6 +310,9 @@ impl SubscribeProtocol {
                 }
             }
             *rows = keep;
+            if let Some(presenter) = presenter {
+                ship = presenter.present(ship);
+            }
 
             let input_exhausted = upper.is_empty();
             let buffer = self
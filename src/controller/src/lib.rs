@@ -44,6 +44,7 @@ use mz_orchestrator::{Orchestrator, ServiceProcessMetrics};
 use mz_ore::now::{EpochMillis, NowFn};
 use mz_ore::tracing::OpenTelemetryContext;
 use mz_persist_client::cache::PersistClientCache;
+use mz_persist_client::encryption::BlobEncryptionCliArgs;
 use mz_persist_client::PersistLocation;
 use mz_persist_types::Codec64;
 use mz_proto::RustType;
@@ -67,6 +68,9 @@ pub struct ControllerConfig {
     /// PersistClient.
     /// This is intentionally shared between workers.
     pub persist_clients: Arc<Mutex<PersistClientCache>>,
+    /// The persist blob encryption keys to configure storaged and computed
+    /// processes with. These must match the keys of `persist_clients`.
+    pub persist_blob_encryption: BlobEncryptionCliArgs,
     /// The stash URL for the storage controller.
     pub storage_stash_url: String,
     /// The storaged image to use when starting new storage processes.
@@ -256,6 +260,7 @@ where
             config.orchestrator.namespace("storage"),
             config.storaged_image,
             config.init_container_image.clone(),
+            config.persist_blob_encryption.clone(),
             config.now,
             &config.postgres_factory,
             envd_epoch,
@@ -267,6 +272,7 @@ where
             config.orchestrator.namespace("compute"),
             config.computed_image,
             config.init_container_image,
+            config.persist_blob_encryption,
             envd_epoch,
         );
 
@@ -57,6 +57,7 @@ use mz_ore::metric;
 use mz_ore::metrics::MetricsRegistry;
 use mz_ore::now::SYSTEM_TIME;
 use mz_persist_client::cache::PersistClientCache;
+use mz_persist_client::encryption::BlobEncryptionCliArgs;
 use mz_persist_client::{PersistConfig, PersistLocation};
 use mz_secrets::SecretsController;
 use mz_sql::catalog::EnvironmentId;
@@ -317,6 +318,8 @@ pub struct Args {
     /// Where the persist library should perform consensus.
     #[clap(long, env = "PERSIST_CONSENSUS_URL")]
     persist_consensus_url: Url,
+    #[clap(flatten)]
+    persist_blob_encryption: BlobEncryptionCliArgs,
     /// The PostgreSQL URL for the storage stash.
     #[clap(long, env = "STORAGE_STASH_URL", value_name = "POSTGRES_URL")]
     storage_stash_url: String,
@@ -653,10 +656,10 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
     };
     let secrets_reader = secrets_controller.reader();
     let now = SYSTEM_TIME.clone();
-    let persist_clients = PersistClientCache::new(
-        PersistConfig::new(&mz_environmentd::BUILD_INFO, now.clone()),
-        &metrics_registry,
-    );
+    let mut persist_cfg = PersistConfig::new(&mz_environmentd::BUILD_INFO, now.clone());
+    persist_cfg.blob_encryption_keys =
+        runtime.block_on(args.persist_blob_encryption.load(&*secrets_reader))?;
+    let persist_clients = PersistClientCache::new(persist_cfg, &metrics_registry);
     let persist_clients = Arc::new(Mutex::new(persist_clients));
     let orchestrator = Arc::new(TracingOrchestrator::new(orchestrator, args.tracing.clone()));
     let controller = ControllerConfig {
@@ -667,6 +670,7 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
             consensus_uri: args.persist_consensus_url.to_string(),
         },
         persist_clients,
+        persist_blob_encryption: args.persist_blob_encryption,
         storage_stash_url: args.storage_stash_url,
         storaged_image: args.storaged_image.expect("clap enforced"),
         computed_image: args.computed_image.expect("clap enforced"),
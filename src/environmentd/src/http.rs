@@ -58,6 +58,7 @@ mod catalog;
 mod memory;
 mod root;
 mod sql;
+mod webhook;
 
 #[derive(Debug, Clone)]
 pub struct HttpConfig {
@@ -442,6 +443,10 @@ fn base_router(BaseRouterConfig { profiling }: BaseRouterConfig) -> Router {
         )
         .route("/api/experimental/sql", routing::get(sql::handle_sql_ws))
         .route("/api/sql", routing::post(sql::handle_sql))
+        .route(
+            "/api/webhook/:database/:schema/:name",
+            routing::post(webhook::handle_webhook),
+        )
         .route("/memory", routing::get(memory::handle_memory))
         .route(
             "/hierarchical-memory",
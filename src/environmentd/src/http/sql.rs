@@ -704,8 +704,10 @@ async fn execute_stmt<S: ResultSender>(
         | ExecuteResponse::DroppedSecret
         | ExecuteResponse::DroppedConnection
         | ExecuteResponse::EmptyQuery
+        | ExecuteResponse::GrantedPrivilege
         | ExecuteResponse::Inserted(_)
         | ExecuteResponse::Raised
+        | ExecuteResponse::RevokedPrivilege
         | ExecuteResponse::SetVariable { .. }
         | ExecuteResponse::StartedTransaction { .. }
         | ExecuteResponse::TransactionCommitted
@@ -714,6 +716,7 @@ async fn execute_stmt<S: ResultSender>(
         | ExecuteResponse::AlteredObject(_)
         | ExecuteResponse::AlteredIndexLogicalCompaction
         | ExecuteResponse::AlteredSystemConfiguration
+        | ExecuteResponse::Analyzed
         | ExecuteResponse::Deallocate { .. }
         | ExecuteResponse::Prepare => SqlResult::ok(client, tag.expect("ok only called on tag-generating results")).into(),
         ExecuteResponse::SendingRows {
@@ -0,0 +1,190 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Webhook source HTTP endpoint.
+//!
+//! Each request to `/api/webhook/:database/:schema/:name` appends one row to
+//! the named webhook source. The response is not sent until the row has been
+//! durably written to the source's persist shard.
+
+use std::collections::BTreeMap;
+
+use axum::extract::Path;
+use axum::response::{IntoResponse, Response};
+use bytes::Bytes;
+use http::{HeaderMap, StatusCode};
+use openssl::hash::MessageDigest;
+use openssl::pkey::PKey;
+use openssl::sign::Signer;
+use thiserror::Error;
+use tracing::warn;
+
+use mz_adapter::session::EndTransactionAction;
+use mz_adapter::{AdapterError, GetWebhookResponse, SessionClient};
+use mz_repr::adt::jsonb::JsonbPacker;
+use mz_repr::{Datum, GlobalId, Row};
+use mz_sql::plan::WebhookValidation;
+use mz_storage_client::types::sources::encoding::DataEncodingInner;
+
+use crate::http::AuthedClient;
+
+pub async fn handle_webhook(
+    AuthedClient(mut client): AuthedClient,
+    Path((database, schema, name)): Path<(String, String, String)>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<StatusCode, WebhookError> {
+    let GetWebhookResponse {
+        id,
+        webhook,
+        secrets_reader,
+    } = client.get_webhook(database, schema, name).await?;
+
+    if let Some(WebhookValidation { secret_id, header }) = &webhook.validation {
+        let secret = secrets_reader
+            .read(*secret_id)
+            .await
+            .map_err(WebhookError::Signing)?;
+        validate_signature(&secret, header, &headers, &body)?;
+    }
+
+    let mut row = Row::default();
+    let mut packer = row.packer();
+    match &webhook.body_encoding {
+        DataEncodingInner::Bytes => packer.push(Datum::Bytes(&body)),
+        DataEncodingInner::Text => {
+            let body = std::str::from_utf8(&body).map_err(|_| WebhookError::InvalidUtf8Body)?;
+            packer.push(Datum::String(body));
+        }
+        DataEncodingInner::Json => JsonbPacker::new(&mut packer)
+            .pack_slice(&body)
+            .map_err(WebhookError::InvalidJsonBody)?,
+        encoding => return Err(WebhookError::UnsupportedEncoding(format!("{encoding:?}"))),
+    }
+    if webhook.include_headers {
+        // Repeated headers are combined into a single comma-separated value,
+        // as permitted by RFC 9110.
+        let mut combined = BTreeMap::new();
+        for (name, value) in &headers {
+            let value = value
+                .to_str()
+                .map_err(|_| WebhookError::InvalidHeader(name.to_string()))?;
+            combined
+                .entry(name.as_str())
+                .and_modify(|combined: &mut String| {
+                    combined.push_str(", ");
+                    combined.push_str(value);
+                })
+                .or_insert_with(|| value.to_string());
+        }
+        packer.push_dict(
+            combined
+                .iter()
+                .map(|(name, value)| (*name, Datum::String(value))),
+        );
+    }
+
+    append_row(&mut client, id, row).await?;
+    Ok(StatusCode::OK)
+}
+
+/// Appends `row` to the webhook source `id` in its own transaction, returning
+/// once the transaction has committed.
+async fn append_row(
+    client: &mut SessionClient,
+    id: GlobalId,
+    row: Row,
+) -> Result<(), AdapterError> {
+    client.start_transaction(Some(1)).await?;
+    if let Err(e) = client.append_webhook(id, vec![row]).await {
+        client
+            .end_transaction(EndTransactionAction::Rollback)
+            .await?;
+        return Err(e);
+    }
+    client.end_transaction(EndTransactionAction::Commit).await?;
+    Ok(())
+}
+
+/// Validates that the hex-encoded HMAC-SHA256 signature of `body`, keyed by
+/// `secret`, is present in the header named `header`.
+///
+/// The signature may optionally be prefixed with `sha256=`.
+fn validate_signature(
+    secret: &[u8],
+    header: &str,
+    headers: &HeaderMap,
+    body: &[u8],
+) -> Result<(), WebhookError> {
+    let signature = headers
+        .get(header)
+        .ok_or_else(|| WebhookError::MissingSignature(header.to_string()))?
+        .to_str()
+        .map_err(|_| WebhookError::InvalidSignature)?;
+    let signature = signature.strip_prefix("sha256=").unwrap_or(signature);
+    let signature = hex::decode(signature).map_err(|_| WebhookError::InvalidSignature)?;
+
+    let key = PKey::hmac(secret).map_err(|e| WebhookError::Signing(e.into()))?;
+    let mut signer =
+        Signer::new(MessageDigest::sha256(), &key).map_err(|e| WebhookError::Signing(e.into()))?;
+    signer
+        .update(body)
+        .map_err(|e| WebhookError::Signing(e.into()))?;
+    let expected = signer
+        .sign_to_vec()
+        .map_err(|e| WebhookError::Signing(e.into()))?;
+
+    // Compare in constant time so as not to leak the expected signature.
+    if signature.len() != expected.len() || !openssl::memcmp::eq(&signature, &expected) {
+        return Err(WebhookError::InvalidSignature);
+    }
+    Ok(())
+}
+
+#[derive(Debug, Error)]
+pub enum WebhookError {
+    #[error("{0}")]
+    Adapter(#[from] AdapterError),
+    #[error("missing signature header '{0}'")]
+    MissingSignature(String),
+    #[error("invalid signature")]
+    InvalidSignature,
+    #[error("failed to validate signature: {0:#}")]
+    Signing(anyhow::Error),
+    #[error("request body is not valid UTF-8")]
+    InvalidUtf8Body,
+    #[error("request body is not valid JSON: {0:#}")]
+    InvalidJsonBody(anyhow::Error),
+    #[error("value of header '{0}' is not valid UTF-8")]
+    InvalidHeader(String),
+    #[error("unsupported webhook body encoding: {0}")]
+    UnsupportedEncoding(String),
+}
+
+impl IntoResponse for WebhookError {
+    fn into_response(self) -> Response {
+        let status = match &self {
+            WebhookError::Adapter(AdapterError::UnknownWebhook { .. }) => StatusCode::NOT_FOUND,
+            WebhookError::Adapter(AdapterError::Unauthorized(_)) => StatusCode::FORBIDDEN,
+            WebhookError::Adapter(_)
+            | WebhookError::Signing(_)
+            | WebhookError::UnsupportedEncoding(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            WebhookError::MissingSignature(_) | WebhookError::InvalidSignature => {
+                warn!("webhook request failed validation: {}", self);
+                // As with authentication failures, omit detail from the error
+                // message we send to the client.
+                return (StatusCode::UNAUTHORIZED, "unauthorized").into_response();
+            }
+            WebhookError::InvalidUtf8Body
+            | WebhookError::InvalidJsonBody(_)
+            | WebhookError::InvalidHeader(_) => StatusCode::BAD_REQUEST,
+        };
+        (status, self.to_string()).into_response()
+    }
+}
@@ -596,6 +596,80 @@ fn test_auth_expiry() {
     assert!(pg_client.query_one("SELECT current_user", &[]).is_err());
 }
 
+#[test]
+fn test_auth_auto_created_role() {
+    // Roles that are created on first login must not be superusers, but must
+    // be able to create objects in the defaults.
+
+    let ca = Ca::new_root("test ca").unwrap();
+    let (server_cert, server_key) = ca
+        .request_cert("server", vec![IpAddr::V4(Ipv4Addr::LOCALHOST)])
+        .unwrap();
+
+    let tenant_id = Uuid::new_v4();
+    let client_id = Uuid::new_v4();
+    let secret = Uuid::new_v4();
+    let users = HashMap::from([(
+        (client_id.to_string(), secret.to_string()),
+        "user@_.com".to_string(),
+    )]);
+    let encoding_key =
+        EncodingKey::from_rsa_pem(&ca.pkey.private_key_to_pem_pkcs8().unwrap()).unwrap();
+
+    let frontegg_server =
+        start_mzcloud(encoding_key, tenant_id, users, SYSTEM_TIME.clone(), 3600).unwrap();
+    let frontegg_auth = FronteggAuthentication::new(FronteggConfig {
+        admin_api_token_url: frontegg_server.url.clone(),
+        decoding_key: DecodingKey::from_rsa_pem(&ca.pkey.public_key_to_pem().unwrap()).unwrap(),
+        tenant_id,
+        now: SYSTEM_TIME.clone(),
+        refresh_before_secs: 60,
+        password_prefix: "mzauth_".to_string(),
+    });
+    let frontegg_user = "user@_.com";
+    let frontegg_password = &format!("mzauth_{client_id}{secret}");
+
+    let config = util::Config::default()
+        .with_tls(TlsMode::Require, &server_cert, &server_key)
+        .with_frontegg(&frontegg_auth);
+    let server = util::start_server(config).unwrap();
+
+    let mut pg_client = server
+        .pg_config()
+        .ssl_mode(SslMode::Require)
+        .user(frontegg_user)
+        .password(frontegg_password)
+        .connect(make_pg_tls(Box::new(|b: &mut SslConnectorBuilder| {
+            Ok(b.set_verify(SslVerifyMode::NONE))
+        })))
+        .unwrap();
+
+    let superuser: bool = pg_client
+        .query_one(
+            "SELECT superuser FROM mz_roles WHERE name = current_user",
+            &[],
+        )
+        .unwrap()
+        .get(0);
+    assert!(!superuser);
+    let err = pg_client.batch_execute("CREATE ROLE other").unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("must be superuser to create roles"),
+        "unexpected error: {err}"
+    );
+
+    // But they can create objects in the default database, schema, and
+    // cluster.
+    pg_client
+        .batch_execute(
+            "CREATE SCHEMA s;
+             CREATE TABLE t (a int);
+             CREATE MATERIALIZED VIEW mv AS SELECT a FROM t;",
+        )
+        .unwrap();
+}
+
 #[allow(clippy::unit_arg)]
 #[test]
 fn test_auth() {
@@ -17,6 +17,7 @@ use std::thread;
 use std::time::Duration;
 use tungstenite::Message;
 
+use mz_adapter::catalog::HTTP_DEFAULT_USER;
 use mz_ore::retry::Retry;
 use reqwest::{blocking::Client, Url};
 use tokio_postgres::types::{FromSql, Type};
@@ -120,6 +121,62 @@ fn test_persistence() {
     );
 }
 
+// Test that data written through persist by environmentd, storaged, and
+// computed is encrypted when blob encryption keys are configured, and that it
+// can be read back.
+#[test]
+fn test_persist_blob_encryption() {
+    let data_dir = tempfile::tempdir().unwrap();
+    let config = util::Config::default()
+        .data_directory(data_dir.path())
+        .with_persist_blob_encryption_key(vec![7; 32]);
+    let server = util::start_server(config).unwrap();
+    let mut client = server.connect(postgres::NoTls).unwrap();
+
+    client
+        .batch_execute("CREATE TABLE t (a int); INSERT INTO t VALUES (1), (2), (3)")
+        .unwrap();
+    client
+        .batch_execute("CREATE MATERIALIZED VIEW mv AS SELECT sum(a) AS s FROM t")
+        .unwrap();
+    client
+        .batch_execute(
+            "CREATE SOURCE counter FROM LOAD GENERATOR COUNTER (TICK INTERVAL '10ms') WITH (SIZE '1')",
+        )
+        .unwrap();
+
+    let sum: i64 = client.query_one("SELECT s FROM mv", &[]).unwrap().get(0);
+    assert_eq!(sum, 6);
+    Retry::default()
+        .max_duration(Duration::from_secs(30))
+        .retry(|_| {
+            let count: i64 = client
+                .query_one("SELECT count(*) FROM counter", &[])
+                .unwrap()
+                .get(0);
+            if count > 0 {
+                Ok(())
+            } else {
+                Err("counter source is still empty")
+            }
+        })
+        .unwrap();
+
+    let mut dirs = vec![data_dir.path().join("persist").join("blob")];
+    let mut encrypted = 0;
+    while let Some(dir) = dirs.pop() {
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else if std::fs::read(&path).unwrap().starts_with(b"MZPENC01") {
+                encrypted += 1;
+            }
+        }
+    }
+    assert!(encrypted > 0, "no encrypted blobs found");
+}
+
 // Test that sources and sinks require an explicit `SIZE` parameter outside of
 // unsafe mode.
 #[test]
@@ -259,6 +316,103 @@ fn test_http_sql() {
     });
 }
 
+// Test that rows POSTed to a webhook source's endpoint are appended to it, and
+// that requests with a missing or invalid signature, or from a role that may
+// not write to the source, are rejected.
+#[test]
+fn test_http_webhook() {
+    let server = util::start_server(util::Config::default()).unwrap();
+    let mut client = server.connect(postgres::NoTls).unwrap();
+    // Unauthenticated HTTP requests execute as the anonymous HTTP user, which
+    // must own the sources to append to them.
+    client
+        .batch_execute(&format!(
+            "CREATE ROLE {user} LOGIN;
+             CREATE SOURCE wh FROM WEBHOOK FORMAT TEXT INCLUDE HEADERS;
+             CREATE SECRET s AS 'shh';
+             CREATE SOURCE wh_signed FROM WEBHOOK (SECRET = SECRET s) FORMAT JSON;
+             CREATE SOURCE wh_unowned FROM WEBHOOK FORMAT TEXT;
+             ALTER SOURCE wh OWNER TO {user};
+             ALTER SOURCE wh_signed OWNER TO {user};",
+            user = HTTP_DEFAULT_USER.name,
+        ))
+        .unwrap();
+
+    let url = |name: &str| {
+        format!(
+            "http://{}/api/webhook/materialize/public/{name}",
+            server.inner.http_local_addr()
+        )
+    };
+    let http = Client::new();
+
+    let res = http
+        .post(url("wh"))
+        .header("x-test", "a")
+        .body("hello")
+        .send()
+        .unwrap();
+    assert_eq!(res.status(), reqwest::StatusCode::OK);
+    // The row is durable before the request is acknowledged, so it must be
+    // visible to a subsequent query.
+    let row = client
+        .query_one("SELECT text, headers -> 'x-test' FROM wh", &[])
+        .unwrap();
+    assert_eq!(row.get::<_, String>(0), "hello");
+    assert_eq!(row.get::<_, String>(1), "a");
+
+    let res = http.post(url("missing")).body("hello").send().unwrap();
+    assert_eq!(res.status(), reqwest::StatusCode::NOT_FOUND);
+
+    let res = http.post(url("wh_unowned")).body("hello").send().unwrap();
+    assert_eq!(res.status(), reqwest::StatusCode::FORBIDDEN);
+    assert!(res
+        .text()
+        .unwrap()
+        .contains("permission denied for source materialize.public.wh_unowned"));
+    let count = client
+        .query_one("SELECT count(*) FROM wh_unowned", &[])
+        .unwrap();
+    assert_eq!(count.get::<_, i64>(0), 0);
+    // Roles that hold INSERT may append without owning the source.
+    client
+        .batch_execute(&format!(
+            "GRANT INSERT ON wh_unowned TO {}",
+            HTTP_DEFAULT_USER.name
+        ))
+        .unwrap();
+    let res = http.post(url("wh_unowned")).body("hello").send().unwrap();
+    assert_eq!(res.status(), reqwest::StatusCode::OK);
+
+    let body = r#"{"a": 1}"#;
+    let res = http.post(url("wh_signed")).body(body).send().unwrap();
+    assert_eq!(res.status(), reqwest::StatusCode::UNAUTHORIZED);
+    let res = http
+        .post(url("wh_signed"))
+        .header("x-signature", "sha256=00")
+        .body(body)
+        .send()
+        .unwrap();
+    assert_eq!(res.status(), reqwest::StatusCode::UNAUTHORIZED);
+
+    let key = openssl::pkey::PKey::hmac(b"shh").unwrap();
+    let mut signer =
+        openssl::sign::Signer::new(openssl::hash::MessageDigest::sha256(), &key).unwrap();
+    signer.update(body.as_bytes()).unwrap();
+    let signature = hex::encode(signer.sign_to_vec().unwrap());
+    let res = http
+        .post(url("wh_signed"))
+        .header("x-signature", format!("sha256={signature}"))
+        .body(body)
+        .send()
+        .unwrap();
+    assert_eq!(res.status(), reqwest::StatusCode::OK);
+    let row = client
+        .query_one("SELECT (data -> 'a')::text FROM wh_signed", &[])
+        .unwrap();
+    assert_eq!(row.get::<_, String>(0), "1");
+}
+
 // Test that the server properly handles cancellation requests.
 #[test]
 fn test_cancel_long_running_query() {
@@ -14,6 +14,7 @@
 //! in testdrive, e.g., because they depend on the current time.
 
 use std::error::Error;
+use std::io::Read;
 use std::net::{Ipv4Addr, SocketAddr};
 use std::sync::Arc;
 use std::sync::Mutex;
@@ -918,6 +919,37 @@ fn test_subscribe_empty_upper_frontier() {
     assert_eq!(3, subscribe.len());
 }
 
+// Tests that a SUBSCRIBE with UP TO terminates when run through COPY, and emits
+// exactly the updates before the bound.
+#[test]
+fn test_subscribe_up_to_copy() {
+    let server = util::start_server(util::Config::default()).unwrap();
+    let mut client = server.connect(postgres::NoTls).unwrap();
+
+    client.batch_execute("CREATE TABLE t (a int)").unwrap();
+    client.batch_execute("INSERT INTO t VALUES (1)").unwrap();
+    let as_of: Timestamp = client
+        .query_one("SELECT mz_now()::text FROM t LIMIT 1", &[])
+        .unwrap()
+        .get::<_, String>(0)
+        .parse()
+        .unwrap();
+    // This happens after `as_of`, so it is past the bound.
+    client.batch_execute("INSERT INTO t VALUES (2)").unwrap();
+
+    // If the subscribe didn't terminate, the COPY wouldn't either.
+    let mut out = String::new();
+    client
+        .copy_out(&*format!(
+            "COPY (SUBSCRIBE t AS OF {as_of} UP TO {}) TO STDOUT",
+            as_of.step_forward()
+        ))
+        .unwrap()
+        .read_to_string(&mut out)
+        .unwrap();
+    assert_eq!(out, format!("{as_of}\t1\t1\n"));
+}
+
 // Tests that a client that launches a non-terminating SUBSCRIBE and disconnects
 // does not keep the server alive forever.
 #[test]
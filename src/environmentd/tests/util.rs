@@ -38,7 +38,9 @@ use mz_ore::retry::Retry;
 use mz_ore::task;
 use mz_ore::tracing::TracingHandle;
 use mz_persist_client::cache::PersistClientCache;
+use mz_persist_client::encryption::BlobEncryptionCliArgs;
 use mz_persist_client::{PersistConfig, PersistLocation};
+use mz_repr::GlobalId;
 use mz_secrets::SecretsController;
 use mz_sql::catalog::EnvironmentId;
 use mz_stash::PostgresFactory;
@@ -60,6 +62,7 @@ pub struct Config {
     default_cluster_replica_size: String,
     builtin_cluster_replica_size: String,
     propagate_crashes: bool,
+    persist_blob_encryption_key: Option<Vec<u8>>,
 }
 
 impl Default for Config {
@@ -76,6 +79,7 @@ impl Default for Config {
             default_cluster_replica_size: "1".to_string(),
             builtin_cluster_replica_size: "1".to_string(),
             propagate_crashes: false,
+            persist_blob_encryption_key: None,
         }
     }
 }
@@ -148,6 +152,11 @@ impl Config {
         self.propagate_crashes = propagate_crashes;
         self
     }
+
+    pub fn with_persist_blob_encryption_key(mut self, key: Vec<u8>) -> Self {
+        self.persist_blob_encryption_key = Some(key);
+        self
+    }
 }
 
 pub fn start_server(config: Config) -> Result<Server, anyhow::Error> {
@@ -203,6 +212,20 @@ pub fn start_server(config: Config) -> Result<Server, anyhow::Error> {
     // Tune down the number of connections to make this all work a little easier
     // with local postgres.
     persist_cfg.consensus_connection_pool_max_size = 1;
+    let persist_blob_encryption = match config.persist_blob_encryption_key {
+        None => BlobEncryptionCliArgs::default(),
+        Some(key) => {
+            // Secrets are looked up by ID, so use one that can't collide with
+            // a secret created by the test.
+            let id = GlobalId::System(u64::MAX);
+            runtime.block_on(orchestrator.ensure(id, &key))?;
+            BlobEncryptionCliArgs {
+                persist_blob_encryption_key_id: vec![id],
+            }
+        }
+    };
+    persist_cfg.blob_encryption_keys =
+        runtime.block_on(persist_blob_encryption.load(&*orchestrator.reader()))?;
     let persist_clients = PersistClientCache::new(persist_cfg, &metrics_registry);
     let persist_clients = Arc::new(Mutex::new(persist_clients));
     let postgres_factory = PostgresFactory::new(&metrics_registry);
@@ -221,6 +244,7 @@ pub fn start_server(config: Config) -> Result<Server, anyhow::Error> {
                 consensus_uri,
             },
             persist_clients,
+            persist_blob_encryption,
             storage_stash_url,
             now: SYSTEM_TIME.clone(),
             postgres_factory,
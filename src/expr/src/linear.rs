@@ -1424,7 +1424,7 @@ pub mod util {
 }
 
 pub mod plan {
-    use std::collections::HashMap;
+    use std::collections::{HashMap, HashSet};
     use std::iter;
 
     use proptest::prelude::*;
@@ -1742,6 +1742,37 @@ pub mod plan {
             }
         }
 
+        /// Returns the non-temporal part of the plan.
+        ///
+        /// Rows that don't pass this part of the plan are discarded before any
+        /// temporal bounds are evaluated.
+        pub fn non_temporal(&self) -> &SafeMfpPlan {
+            &self.mfp
+        }
+
+        /// Lists input columns whose values are used in outputs or in the
+        /// temporal bounds.
+        ///
+        /// See [MapFilterProject::demand].
+        pub fn demand(&self) -> HashSet<usize> {
+            let mfp = &self.mfp.mfp;
+            let mut demanded = HashSet::new();
+            for (_index, pred) in mfp.predicates.iter() {
+                demanded.extend(pred.support());
+            }
+            for bound in self.lower_bounds.iter().chain(self.upper_bounds.iter()) {
+                demanded.extend(bound.support());
+            }
+            demanded.extend(mfp.projection.iter().cloned());
+            for index in (0..mfp.expressions.len()).rev() {
+                if demanded.contains(&(mfp.input_arity + index)) {
+                    demanded.extend(mfp.expressions[index].support());
+                }
+            }
+            demanded.retain(|col| col < &mfp.input_arity);
+            demanded
+        }
+
         /// Returns an iterator over mutable references to all non-temporal
         /// scalar expressions in the plan.
         ///
@@ -9,6 +9,7 @@
 
 #![allow(missing_docs)]
 
+use std::cmp::Ordering;
 use std::fmt;
 use std::iter;
 use std::ops::Deref;
@@ -16,7 +17,7 @@ use std::ops::Deref;
 use chrono::{DateTime, NaiveDateTime, Utc};
 use dec::OrderedDecimal;
 use itertools::Itertools;
-use num::{CheckedAdd, Integer, Signed};
+use num::{CheckedAdd, CheckedNeg, Integer, Signed};
 use ordered_float::OrderedFloat;
 use proptest::prelude::{Arbitrary, Just};
 use proptest::prop_oneof;
@@ -42,7 +43,7 @@ use crate::relation::{
     proto_table_func, ColumnOrder, ProtoAggregateFunc, ProtoTableFunc, WindowFrame,
     WindowFrameBound, WindowFrameUnits,
 };
-use crate::scalar::func::{add_timestamp_months, jsonb_stringify};
+use crate::scalar::func::{add_timestamp_months, add_timestamplike_interval, jsonb_stringify};
 use crate::EvalError;
 
 include!(concat!(env!("OUT_DIR"), "/mz_expr.relation.func.rs"));
@@ -938,7 +939,9 @@ where
                 }
             }
             // Forbidden during planning
-            WindowFrameBound::UnboundedFollowing => unreachable!(),
+            WindowFrameBound::UnboundedFollowing
+            | WindowFrameBound::IntervalPreceding(_)
+            | WindowFrameBound::IntervalFollowing(_) => unreachable!(),
         };
 
         result.push((first_value, *original_row));
@@ -1061,7 +1064,9 @@ where
                 }
             }
             // Forbidden during planning
-            WindowFrameBound::UnboundedPreceding => unreachable!(),
+            WindowFrameBound::UnboundedPreceding
+            | WindowFrameBound::IntervalPreceding(_)
+            | WindowFrameBound::IntervalFollowing(_) => unreachable!(),
         };
 
         result.push((last_value, *original_row));
@@ -1078,6 +1083,227 @@ where
     })
 }
 
+// The expected input is in the format of [((OriginalRow, InputValue), OrderByExprs...)]
+fn window_aggregate<'a, I>(
+    datums: I,
+    temp_storage: &'a RowArena,
+    wrapped_aggregate: &AggregateFunc,
+    order_by: &[ColumnOrder],
+    window_frame: &WindowFrame,
+) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    // Sort the datums according to the ORDER BY expressions and return the ((OriginalRow, InputValue), OrderByRow) record
+    // The OrderByRow is kept around because it is required to compute the peer groups and RANGE offsets
+    let datums = order_aggregate_datums_with_rank(datums, order_by);
+
+    // Decode the input (OriginalRow, InputValue) into separate datums, while keeping the OrderByRow
+    let datums = datums
+        .into_iter()
+        .map(|(d, order_by_row)| {
+            let mut iter = d.unwrap_list().iter();
+            let original_row = iter.next().unwrap();
+            let input_value = iter.next().unwrap();
+
+            (input_value, original_row, order_by_row)
+        })
+        .collect_vec();
+
+    // The peer group of each row, and the index of the first row of each peer group followed by
+    // the number of rows. A peer group is a run of rows with the same ORDER BY values.
+    let length = datums.len();
+    let mut peer_groups = Vec::with_capacity(length);
+    let mut peer_group_starts = Vec::new();
+    for (idx, (_, _, order_by_row)) in datums.iter().enumerate() {
+        if idx == 0 || datums[idx - 1].2 != *order_by_row {
+            peer_group_starts.push(idx);
+        }
+        peer_groups.push(peer_group_starts.len() - 1);
+    }
+    peer_group_starts.push(length);
+    let peer_group_count = peer_group_starts.len() - 1;
+
+    // Computes the index of the first row of the frame of the row at `idx` if `is_start`, and the
+    // index after the last row of the frame otherwise.
+    let frame_bound_index = |idx: usize, bound: &WindowFrameBound, is_start: bool| {
+        // The row or peer group `bound` refers to, relative to `current` out of `count`
+        let offset_index = |current: usize, count: usize| {
+            let target = match bound {
+                WindowFrameBound::OffsetPreceding(offset) => {
+                    current.checked_sub(usize::cast_from(*offset))
+                }
+                WindowFrameBound::OffsetFollowing(offset) => {
+                    Some(current.saturating_add(usize::cast_from(*offset)))
+                }
+                WindowFrameBound::CurrentRow => Some(current),
+                // Handled by the caller, or forbidden during planning
+                WindowFrameBound::UnboundedPreceding
+                | WindowFrameBound::UnboundedFollowing
+                | WindowFrameBound::IntervalPreceding(_)
+                | WindowFrameBound::IntervalFollowing(_) => unreachable!(),
+            };
+            match target {
+                // The bound lies before the first row or peer group
+                None => 0,
+                Some(target) if is_start => std::cmp::min(target, count),
+                Some(target) => std::cmp::min(target.saturating_add(1), count),
+            }
+        };
+        let peer_group_bound =
+            || peer_group_starts[offset_index(peer_groups[idx], peer_group_count)];
+
+        match (bound, &window_frame.units) {
+            (WindowFrameBound::UnboundedPreceding, _) => 0,
+            (WindowFrameBound::UnboundedFollowing, _) => length,
+            (_, WindowFrameUnits::Rows) => offset_index(idx, length),
+            (_, WindowFrameUnits::Groups)
+            | (WindowFrameBound::CurrentRow, WindowFrameUnits::Range) => peer_group_bound(),
+            (_, WindowFrameUnits::Range) => {
+                // RANGE offsets are only allowed with a single ORDER BY expression
+                let column = order_by[0].column;
+                let order = ColumnOrder {
+                    column: 0,
+                    ..order_by[0].clone()
+                };
+                let value = datums[idx].2.iter().nth(column).unwrap();
+                if value.is_null() {
+                    // A null is only within an offset of other nulls, i.e., its peer group
+                    return peer_group_starts[if is_start {
+                        peer_groups[idx]
+                    } else {
+                        peer_groups[idx] + 1
+                    }];
+                }
+
+                // Whether the bound lies after the current row in the window order
+                let after = matches!(
+                    bound,
+                    WindowFrameBound::OffsetFollowing(_) | WindowFrameBound::IntervalFollowing(_)
+                );
+                let target = range_frame_bound_value(value, bound, after != order.desc);
+                let compare_to_target = |(_, _, order_by_row): &(Datum, Datum, Row)| {
+                    let row_value = order_by_row.iter().nth(column).unwrap();
+                    match target {
+                        Some(target) => compare_columns(
+                            std::slice::from_ref(&order),
+                            &[row_value],
+                            &[target],
+                            || Ordering::Equal,
+                        ),
+                        // The target is beyond all non-null values, so only the position of
+                        // nulls needs to be determined
+                        None if row_value.is_null() && order.nulls_last => Ordering::Greater,
+                        None if row_value.is_null() => Ordering::Less,
+                        None if after => Ordering::Less,
+                        None => Ordering::Greater,
+                    }
+                };
+                if is_start {
+                    datums.partition_point(|row| compare_to_target(row) == Ordering::Less)
+                } else {
+                    datums.partition_point(|row| compare_to_target(row) != Ordering::Greater)
+                }
+            }
+        }
+    };
+
+    // The aggregate is evaluated from scratch for the frame of each row, which takes time
+    // quadratic in the size of the partition for frames that grow with it, e.g. those that start
+    // at `UNBOUNDED PRECEDING`. This is tolerable because the whole partition is re-evaluated on
+    // each change anyway. Incrementally maintained dataflows instead plan `count` and `sum` over
+    // `ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW` as prefix sums, see `WindowPlan`.
+    let result = datums
+        .iter()
+        .enumerate()
+        .map(|(idx, (_, original_row, _))| {
+            let start = frame_bound_index(idx, &window_frame.start_bound, true);
+            let end = frame_bound_index(idx, &window_frame.end_bound, false);
+            // The frame is empty if it ends before it starts
+            let frame = datums[start..std::cmp::max(start, end)]
+                .iter()
+                .map(|(input_value, _, _)| *input_value);
+            let value = wrapped_aggregate.eval(frame, temp_storage);
+            temp_storage.make_datum(|packer| {
+                packer.push_list(vec![value, *original_row]);
+            })
+        })
+        .collect_vec();
+
+    temp_storage.make_datum(|packer| {
+        packer.push_list(result);
+    })
+}
+
+/// Shifts the ORDER BY value of a row by the offset of a `RANGE` frame bound, adding the offset
+/// if `add` and subtracting it otherwise. Returns `None` if the result does not fit the type of
+/// the value, i.e., if the bound lies beyond all non-null values.
+fn range_frame_bound_value<'a>(
+    value: Datum<'a>,
+    bound: &WindowFrameBound,
+    add: bool,
+) -> Option<Datum<'a>> {
+    match bound {
+        WindowFrameBound::OffsetPreceding(offset) | WindowFrameBound::OffsetFollowing(offset) => {
+            let offset = *offset;
+            match value {
+                Datum::Int16(v) => shift_integer(v, offset, add).map(Datum::Int16),
+                Datum::Int32(v) => shift_integer(v, offset, add).map(Datum::Int32),
+                Datum::Int64(v) => shift_integer(v, offset, add).map(Datum::Int64),
+                Datum::UInt16(v) => shift_integer(v, offset, add).map(Datum::UInt16),
+                Datum::UInt32(v) => shift_integer(v, offset, add).map(Datum::UInt32),
+                Datum::UInt64(v) => shift_integer(v, offset, add).map(Datum::UInt64),
+                Datum::Numeric(v) => {
+                    let mut cx = numeric::cx_datum();
+                    let mut v = v.0;
+                    let offset = numeric::Numeric::from(offset);
+                    if add {
+                        cx.add(&mut v, &offset);
+                    } else {
+                        cx.sub(&mut v, &offset);
+                    }
+                    if cx.status().overflow() {
+                        None
+                    } else {
+                        Some(Datum::from(v))
+                    }
+                }
+                // Forbidden during planning
+                _ => unreachable!(),
+            }
+        }
+        WindowFrameBound::IntervalPreceding(interval)
+        | WindowFrameBound::IntervalFollowing(interval) => {
+            let interval = if add {
+                Some(*interval)
+            } else {
+                interval.checked_neg()
+            }?;
+            match value {
+                Datum::Timestamp(ts) => add_timestamplike_interval(ts, interval).ok(),
+                Datum::TimestampTz(ts) => add_timestamplike_interval(ts, interval).ok(),
+                // Forbidden during planning
+                _ => unreachable!(),
+            }
+        }
+        // Bounds without an offset are handled by the caller
+        WindowFrameBound::UnboundedPreceding
+        | WindowFrameBound::CurrentRow
+        | WindowFrameBound::UnboundedFollowing => unreachable!(),
+    }
+}
+
+/// Shifts an integer by `offset`, adding it if `add` and subtracting it otherwise. Returns `None`
+/// if the result does not fit the type of the integer.
+fn shift_integer<T>(value: T, offset: u64, add: bool) -> Option<T>
+where
+    T: Into<i128> + TryFrom<i128>,
+{
+    let (value, offset) = (value.into(), i128::from(offset));
+    let shifted = if add { value + offset } else { value - offset };
+    T::try_from(shifted).ok()
+}
+
 /// Identify whether the given aggregate function is Lag or Lead, since they share
 /// implementations.
 #[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect)]
@@ -1179,6 +1405,13 @@ pub enum AggregateFunc {
         order_by: Vec<ColumnOrder>,
         window_frame: WindowFrame,
     },
+    /// Evaluates `wrapped_aggregate` over the window frame of each row, which is
+    /// determined by `order_by` and `window_frame`.
+    WindowAggregate {
+        wrapped_aggregate: Box<AggregateFunc>,
+        order_by: Vec<ColumnOrder>,
+        window_frame: WindowFrame,
+    },
     /// Accumulates any number of `Datum::Dummy`s into `Datum::Dummy`.
     ///
     /// Useful for removing an expensive aggregation while maintaining the shape
@@ -1278,6 +1511,22 @@ impl Arbitrary for AggregateFunc {
                     order_by,
                     window_frame,
                 }),
+            (
+                prop_oneof![
+                    Just(AggregateFunc::SumInt64),
+                    Just(AggregateFunc::MaxTimestamp),
+                    Just(AggregateFunc::Count),
+                ],
+                vec(proptest_any::<ColumnOrder>(), 1..4),
+                proptest_any::<WindowFrame>()
+            )
+                .prop_map(|(wrapped_aggregate, order_by, window_frame)| {
+                    AggregateFunc::WindowAggregate {
+                        wrapped_aggregate: Box::new(wrapped_aggregate),
+                        order_by,
+                        window_frame,
+                    }
+                }),
             Just(AggregateFunc::Dummy)
         ]
     }
@@ -1378,6 +1627,15 @@ impl RustType<ProtoAggregateFunc> for AggregateFunc {
                     order_by: Some(order_by.into_proto()),
                     window_frame: Some(window_frame.into_proto()),
                 }),
+                AggregateFunc::WindowAggregate {
+                    wrapped_aggregate,
+                    order_by,
+                    window_frame,
+                } => Kind::WindowAggregate(Box::new(proto_aggregate_func::ProtoWindowAggregate {
+                    wrapped_aggregate: Some(wrapped_aggregate.into_proto()),
+                    order_by: Some(order_by.into_proto()),
+                    window_frame: Some(window_frame.into_proto()),
+                })),
                 AggregateFunc::Dummy => Kind::Dummy(()),
             }),
         }
@@ -1484,6 +1742,17 @@ impl RustType<ProtoAggregateFunc> for AggregateFunc {
                     .window_frame
                     .into_rust_if_some("ProtoWindowFrame::window_frame")?,
             },
+            Kind::WindowAggregate(pwa) => AggregateFunc::WindowAggregate {
+                wrapped_aggregate: pwa
+                    .wrapped_aggregate
+                    .into_rust_if_some("ProtoWindowAggregate::wrapped_aggregate")?,
+                order_by: pwa
+                    .order_by
+                    .into_rust_if_some("ProtoWindowAggregate::order_by")?,
+                window_frame: pwa
+                    .window_frame
+                    .into_rust_if_some("ProtoWindowAggregate::window_frame")?,
+            },
             Kind::Dummy(()) => AggregateFunc::Dummy,
         })
     }
@@ -1558,6 +1827,17 @@ impl AggregateFunc {
                 order_by,
                 window_frame,
             } => last_value(datums, temp_storage, order_by, window_frame),
+            AggregateFunc::WindowAggregate {
+                wrapped_aggregate,
+                order_by,
+                window_frame,
+            } => window_aggregate(
+                datums,
+                temp_storage,
+                wrapped_aggregate,
+                order_by,
+                window_frame,
+            ),
             AggregateFunc::Dummy => Datum::Dummy,
         }
     }
@@ -1588,6 +1868,7 @@ impl AggregateFunc {
             AggregateFunc::LagLead { .. } => Datum::empty_list(),
             AggregateFunc::FirstValue { .. } => Datum::empty_list(),
             AggregateFunc::LastValue { .. } => Datum::empty_list(),
+            AggregateFunc::WindowAggregate { .. } => Datum::empty_list(),
             _ => Datum::Null,
         }
     }
@@ -1734,6 +2015,34 @@ impl AggregateFunc {
                     custom_id: None,
                 }
             }
+            AggregateFunc::WindowAggregate {
+                wrapped_aggregate, ..
+            } => {
+                // The input type for WindowAggregate is ((OriginalRow, InputValue), OrderByExprs...)
+                let fields = input_type.scalar_type.unwrap_record_element_type();
+                let original_row_type = fields[0].unwrap_record_element_type()[0]
+                    .clone()
+                    .nullable(false);
+                let input_value_type = fields[0].unwrap_record_element_type()[1]
+                    .clone()
+                    .nullable(true);
+                // Frames can be empty, so only a count is never null
+                let value_type = wrapped_aggregate
+                    .output_type(input_value_type)
+                    .scalar_type
+                    .nullable(!matches!(**wrapped_aggregate, AggregateFunc::Count));
+
+                ScalarType::List {
+                    element_type: Box::new(ScalarType::Record {
+                        fields: vec![
+                            (ColumnName::from("?window_agg?"), value_type),
+                            (ColumnName::from("?record?"), original_row_type),
+                        ],
+                        custom_id: None,
+                    }),
+                    custom_id: None,
+                }
+            }
             // Note AggregateFunc::MaxString, MinString rely on returning input
             // type as output type to support the proper return type for
             // character input.
@@ -2055,6 +2364,9 @@ impl fmt::Display for AggregateFunc {
             } => f.write_str("lead"),
             AggregateFunc::FirstValue { .. } => f.write_str("first_value"),
             AggregateFunc::LastValue { .. } => f.write_str("last_value"),
+            AggregateFunc::WindowAggregate {
+                wrapped_aggregate, ..
+            } => wrapped_aggregate.fmt(f),
             AggregateFunc::Dummy => f.write_str("dummy"),
         }
     }
@@ -2521,10 +2833,22 @@ impl fmt::Display for TableFunc {
 
 #[cfg(test)]
 mod tests {
-    use super::{AggregateFunc, ProtoAggregateFunc, ProtoTableFunc, TableFunc};
+    use super::{shift_integer, AggregateFunc, ProtoAggregateFunc, ProtoTableFunc, TableFunc};
     use mz_proto::protobuf_roundtrip;
     use proptest::prelude::*;
 
+    #[test]
+    fn shift_integer_bounds() {
+        assert_eq!(shift_integer(10i16, 5, true), Some(15));
+        assert_eq!(shift_integer(10i16, 5, false), Some(5));
+        // Offsets need not fit the type of the value, only the result does.
+        assert_eq!(shift_integer(i16::MIN, 32768, true), Some(0));
+        assert_eq!(shift_integer(i16::MAX, 1, true), None);
+        assert_eq!(shift_integer(0u32, 1, false), None);
+        assert_eq!(shift_integer(u64::MAX, u64::MAX, false), Some(0));
+        assert_eq!(shift_integer(i64::MIN, u64::MAX, true), Some(i64::MAX));
+    }
+
     proptest! {
        #[test]
         fn aggregate_func_protobuf_roundtrip(expect in any::<AggregateFunc>() ) {
@@ -16,6 +16,7 @@ use std::num::NonZeroUsize;
 
 use bytesize::ByteSize;
 use itertools::Itertools;
+use proptest::prelude::{any, Strategy};
 use proptest_derive::Arbitrary;
 use serde::{Deserialize, Serialize};
 
@@ -26,6 +27,7 @@ use mz_ore::id_gen::IdGen;
 use mz_ore::stack::RecursionLimitError;
 use mz_ore::str::{separated, Indent};
 use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
+use mz_repr::adt::interval::Interval;
 use mz_repr::adt::numeric::NumericMaxScale;
 use mz_repr::explain_new::DisplayText;
 use mz_repr::explain_new::{DummyHumanizer, ExprHumanizer};
@@ -125,8 +127,10 @@ pub enum MirRelationExpr {
     /// The resulting value of the expression is `body` evaluated once in the
     /// context of the final iterates.
     ///
+    /// If `max_iters` is set and the bindings have not stopped changing after
+    /// that many iterations, the expression evaluates to an error.
+    ///
     /// A zero-binding instance can be replaced by `body`.
-    /// A single-binding instance is equivalent to `MirRelationExpr::Let`.
     ///
     /// The runtime memory footprint of this operator is zero.
     LetRec {
@@ -135,6 +139,8 @@ pub enum MirRelationExpr {
         ids: Vec<LocalId>,
         /// The collections to be bound to each `id`.
         values: Vec<MirRelationExpr>,
+        /// The maximum number of iterations to evaluate, if any.
+        max_iters: Option<u64>,
         /// The result of the `Let`, evaluated with `id` bound to `value`.
         body: Box<MirRelationExpr>,
     },
@@ -1682,7 +1688,6 @@ impl MirRelationExpr {
     }
 }
 
-// Temporary implementation for working with `LetRec`.
 impl MirRelationExpr {
     /// True when `expr` contains a `LetRec` AST node.
     pub fn is_recursive(self: &MirRelationExpr) -> bool {
@@ -1695,49 +1700,6 @@ impl MirRelationExpr {
         }
         false
     }
-
-    /// Replaces `LetRec` nodes with a stack of `Let` nodes.
-    ///
-    /// In each `Let` binding, uses of `Get` in `value` that are not at strictly greater
-    /// identifiers are rewritten to be the constant collection.
-    /// This makes the computation perform exactly "one" iteration.
-    pub fn make_nonrecursive(self: &mut MirRelationExpr) {
-        let mut deadlist = std::collections::HashSet::new();
-        let mut worklist = vec![self];
-        while let Some(expr) = worklist.pop() {
-            if let MirRelationExpr::LetRec { ids, values, body } = expr {
-                let ids_values = values
-                    .drain(..)
-                    .zip(ids)
-                    .map(|(value, id)| (*id, value))
-                    .collect::<Vec<_>>();
-                *expr = body.take_dangerous();
-                for (id, mut value) in ids_values.into_iter().rev() {
-                    // Remove references to potentially recursive identifiers.
-                    deadlist.insert(id);
-                    value.visit_pre_mut(|e| {
-                        if let MirRelationExpr::Get {
-                            id: crate::Id::Local(id),
-                            ..
-                        } = e
-                        {
-                            if deadlist.contains(id) {
-                                e.take_safely();
-                            }
-                        }
-                    });
-                    *expr = MirRelationExpr::Let {
-                        id,
-                        value: Box::new(value),
-                        body: Box::new(expr.take_dangerous()),
-                    };
-                }
-                worklist.push(expr);
-            } else {
-                worklist.extend(expr.children_mut().rev());
-            }
-        }
-    }
 }
 
 impl CollectionPlan for MirRelationExpr {
@@ -2364,6 +2326,62 @@ impl AggregateExpr {
                 }
             }
 
+            // The input type for WindowAggregate is a ((OriginalRow, InputValue), OrderByExprs...)
+            AggregateFunc::WindowAggregate {
+                wrapped_aggregate,
+                window_frame,
+                ..
+            } => {
+                let tuple = self
+                    .expr
+                    .clone()
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+
+                // Get the overall return type
+                let return_type = self
+                    .typ(input_type)
+                    .scalar_type
+                    .unwrap_list_element_type()
+                    .clone();
+                let window_agg_return_type = return_type.unwrap_record_element_type()[0].clone();
+
+                // Extract the original row
+                let original_row = tuple
+                    .clone()
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+
+                // Extract the input value
+                let expr = tuple.call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(1)));
+
+                // If the window frame includes the current (single) row, aggregate its value,
+                // otherwise aggregate the empty frame
+                let value = if window_frame.includes_current_row() {
+                    AggregateExpr {
+                        func: (**wrapped_aggregate).clone(),
+                        expr,
+                        distinct: false,
+                    }
+                    .on_unique(input_type)
+                } else {
+                    MirScalarExpr::literal_ok(wrapped_aggregate.default(), window_agg_return_type)
+                };
+
+                MirScalarExpr::CallVariadic {
+                    func: VariadicFunc::ListCreate {
+                        elem_type: return_type,
+                    },
+                    exprs: vec![MirScalarExpr::CallVariadic {
+                        func: VariadicFunc::RecordCreate {
+                            field_names: vec![
+                                ColumnName::from("?window_agg?"),
+                                ColumnName::from("?record?"),
+                            ],
+                        },
+                        exprs: vec![value, original_row],
+                    }],
+                }
+            }
+
             // All other variants should return the argument to the aggregation.
             AggregateFunc::MaxNumeric
             | AggregateFunc::MaxInt16
@@ -2507,13 +2525,18 @@ impl JoinImplementation {
 ///
 /// A candidate is described by a collection and a key, and may have various liabilities.
 /// Primarily, the candidate may risk substantial inflation of records, which is something
-/// that concerns us greatly. Additionally the candidate may be unarranged, and we would
-/// prefer candidates that do not require additional memory. Finally, we prefer lower id
-/// collections in the interest of consistent tie-breaking.
+/// that concerns us greatly. When statistics are available, we next prefer candidates that
+/// are estimated to produce fewer records for each key, as this is a more direct measure
+/// of inflation than the length of the key. Additionally the candidate may be unarranged,
+/// and we would prefer candidates that do not require additional memory. Finally, we prefer
+/// lower id collections in the interest of consistent tie-breaking.
 #[derive(Eq, PartialEq, Ord, PartialOrd, Debug, Clone, Serialize, Deserialize, Hash, MzReflect)]
 pub struct JoinInputCharacteristics {
     /// An excellent indication that record count will not increase.
     pub unique_key: bool,
+    /// The estimated number of records of the input that match each key, if statistics are
+    /// available for all inputs of the join.
+    pub cardinality: Option<std::cmp::Reverse<usize>>,
     /// A weaker signal that record count will not increase.
     pub key_length: usize,
     /// Indicates that there will be no additional in-memory footprint.
@@ -2530,11 +2553,13 @@ impl JoinInputCharacteristics {
         unique_key: bool,
         key_length: usize,
         arranged: bool,
+        cardinality: Option<usize>,
         filters: FilterCharacteristics,
         input: usize,
     ) -> Self {
         Self {
             unique_key,
+            cardinality: cardinality.map(std::cmp::Reverse),
             key_length,
             arranged,
             filters,
@@ -2555,6 +2580,9 @@ impl JoinInputCharacteristics {
             e.push_str("A");
         }
         e.push_str(&self.filters.explain());
+        if let Some(std::cmp::Reverse(cardinality)) = self.cardinality {
+            e.push_str(&format!("~{}", cardinality));
+        }
         e
     }
 }
@@ -2810,16 +2838,29 @@ impl WindowFrame {
 
     fn includes_current_row(&self) -> bool {
         use WindowFrameBound::*;
-        match self.start_bound {
-            UnboundedPreceding => match self.end_bound {
+        // A row is within an interval offset of itself exactly when the
+        // interval is zero, which is how row offsets of zero behave.
+        let as_row_offset = |bound: &WindowFrameBound| match bound {
+            IntervalPreceding(interval) => {
+                OffsetPreceding(u64::from(*interval != Interval::default()))
+            }
+            IntervalFollowing(interval) => {
+                OffsetFollowing(u64::from(*interval != Interval::default()))
+            }
+            bound => bound.clone(),
+        };
+        let end_bound = as_row_offset(&self.end_bound);
+        match as_row_offset(&self.start_bound) {
+            UnboundedPreceding => match end_bound {
                 UnboundedPreceding => false,
                 OffsetPreceding(0) => true,
                 OffsetPreceding(_) => false,
                 CurrentRow => true,
                 OffsetFollowing(_) => true,
                 UnboundedFollowing => true,
+                IntervalPreceding(_) | IntervalFollowing(_) => unreachable!(),
             },
-            OffsetPreceding(0) => match self.end_bound {
+            OffsetPreceding(0) => match end_bound {
                 UnboundedPreceding => unreachable!(),
                 OffsetPreceding(0) => true,
                 // Any nonzero offsets here will create an empty window
@@ -2827,8 +2868,9 @@ impl WindowFrame {
                 CurrentRow => true,
                 OffsetFollowing(_) => true,
                 UnboundedFollowing => true,
+                IntervalPreceding(_) | IntervalFollowing(_) => unreachable!(),
             },
-            OffsetPreceding(_) => match self.end_bound {
+            OffsetPreceding(_) => match end_bound {
                 UnboundedPreceding => unreachable!(),
                 // Window ends at the current row
                 OffsetPreceding(0) => true,
@@ -2836,23 +2878,27 @@ impl WindowFrame {
                 CurrentRow => true,
                 OffsetFollowing(_) => true,
                 UnboundedFollowing => true,
+                IntervalPreceding(_) | IntervalFollowing(_) => unreachable!(),
             },
             CurrentRow => true,
-            OffsetFollowing(0) => match self.end_bound {
+            OffsetFollowing(0) => match end_bound {
                 UnboundedPreceding => unreachable!(),
                 OffsetPreceding(_) => unreachable!(),
                 CurrentRow => unreachable!(),
                 OffsetFollowing(_) => true,
                 UnboundedFollowing => true,
+                IntervalPreceding(_) | IntervalFollowing(_) => unreachable!(),
             },
-            OffsetFollowing(_) => match self.end_bound {
+            OffsetFollowing(_) => match end_bound {
                 UnboundedPreceding => unreachable!(),
                 OffsetPreceding(_) => unreachable!(),
                 CurrentRow => unreachable!(),
                 OffsetFollowing(_) => false,
                 UnboundedFollowing => false,
+                IntervalPreceding(_) | IntervalFollowing(_) => unreachable!(),
             },
             UnboundedFollowing => false,
+            IntervalPreceding(_) | IntervalFollowing(_) => unreachable!(),
         }
     }
 }
@@ -2933,14 +2979,32 @@ pub enum WindowFrameBound {
     UnboundedPreceding,
     /// `<N> PRECEDING`
     OffsetPreceding(u64),
+    /// `<interval> PRECEDING`, only used with `RANGE` units
+    IntervalPreceding(
+        #[mzreflect(ignore)]
+        #[proptest(strategy = "any_interval()")]
+        Interval,
+    ),
     /// `CURRENT ROW`
     CurrentRow,
     /// `<N> FOLLOWING`
     OffsetFollowing(u64),
+    /// `<interval> FOLLOWING`, only used with `RANGE` units
+    IntervalFollowing(
+        #[mzreflect(ignore)]
+        #[proptest(strategy = "any_interval()")]
+        Interval,
+    ),
     /// `UNBOUNDED FOLLOWING`.
     UnboundedFollowing,
 }
 
+/// A strategy for generating the intervals used by [WindowFrameBound].
+fn any_interval() -> impl Strategy<Value = Interval> {
+    (any::<i32>(), any::<i32>(), any::<i64>())
+        .prop_map(|(months, days, micros)| Interval::new(months, days, micros))
+}
+
 impl RustType<proto_window_frame::ProtoWindowFrameBound> for WindowFrameBound {
     fn into_proto(&self) -> proto_window_frame::ProtoWindowFrameBound {
         use proto_window_frame::proto_window_frame_bound::Kind::*;
@@ -2948,8 +3012,14 @@ impl RustType<proto_window_frame::ProtoWindowFrameBound> for WindowFrameBound {
             kind: Some(match self {
                 WindowFrameBound::UnboundedPreceding => UnboundedPreceding(()),
                 WindowFrameBound::OffsetPreceding(offset) => OffsetPreceding(*offset),
+                WindowFrameBound::IntervalPreceding(interval) => {
+                    IntervalPreceding(interval.into_proto())
+                }
                 WindowFrameBound::CurrentRow => CurrentRow(()),
                 WindowFrameBound::OffsetFollowing(offset) => OffsetFollowing(*offset),
+                WindowFrameBound::IntervalFollowing(interval) => {
+                    IntervalFollowing(interval.into_proto())
+                }
                 WindowFrameBound::UnboundedFollowing => UnboundedFollowing(()),
             }),
         }
@@ -2960,8 +3030,14 @@ impl RustType<proto_window_frame::ProtoWindowFrameBound> for WindowFrameBound {
         Ok(match x.kind {
             Some(UnboundedPreceding(())) => WindowFrameBound::UnboundedPreceding,
             Some(OffsetPreceding(offset)) => WindowFrameBound::OffsetPreceding(offset),
+            Some(IntervalPreceding(interval)) => {
+                WindowFrameBound::IntervalPreceding(interval.into_rust()?)
+            }
             Some(CurrentRow(())) => WindowFrameBound::CurrentRow,
             Some(OffsetFollowing(offset)) => WindowFrameBound::OffsetFollowing(offset),
+            Some(IntervalFollowing(interval)) => {
+                WindowFrameBound::IntervalFollowing(interval.into_rust()?)
+            }
             Some(UnboundedFollowing(())) => WindowFrameBound::UnboundedFollowing,
             None => {
                 return Err(TryFromProtoError::missing_field(
@@ -43,6 +43,7 @@ use mz_repr::adt::datetime::Timezone;
 use mz_repr::adt::interval::Interval;
 use mz_repr::adt::jsonb::JsonbRef;
 use mz_repr::adt::numeric::{self, DecimalLike, Numeric, NumericMaxScale};
+use mz_repr::adt::range::{RangeBoundDesc, RangeDesc, RangeError};
 use mz_repr::adt::regex::any_regex;
 use mz_repr::adt::timestamp::{CheckedTimestamp, TimestampLike};
 use mz_repr::chrono::any_naive_datetime;
@@ -301,7 +302,7 @@ fn add_float64<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
     }
 }
 
-fn add_timestamplike_interval<'a, T>(
+pub(crate) fn add_timestamplike_interval<'a, T>(
     a: CheckedTimestamp<T>,
     b: Interval,
 ) -> Result<Datum<'a>, EvalError>
@@ -1893,6 +1894,13 @@ pub enum BinaryFunc {
     Power,
     PowerNumeric,
     GetByte,
+    RangeContainsElem { rev: bool },
+    RangeContainsRange { rev: bool },
+    RangeOverlaps,
+    RangeAdjacent,
+    RangeUnion,
+    RangeIntersection,
+    RangeDifference,
 }
 
 impl BinaryFunc {
@@ -2190,6 +2198,13 @@ impl BinaryFunc {
             BinaryFunc::PowerNumeric => eager!(power_numeric),
             BinaryFunc::RepeatString => eager!(repeat_string, temp_storage),
             BinaryFunc::GetByte => eager!(get_byte),
+            BinaryFunc::RangeContainsElem { rev } => Ok(eager!(range_contains_elem, *rev)),
+            BinaryFunc::RangeContainsRange { rev } => Ok(eager!(range_contains_range, *rev)),
+            BinaryFunc::RangeOverlaps => Ok(eager!(range_overlaps)),
+            BinaryFunc::RangeAdjacent => Ok(eager!(range_adjacent)),
+            BinaryFunc::RangeUnion => eager!(range_union),
+            BinaryFunc::RangeIntersection => Ok(eager!(range_intersection)),
+            BinaryFunc::RangeDifference => eager!(range_difference),
         }
     }
 
@@ -2342,6 +2357,16 @@ impl BinaryFunc {
             }
 
             GetByte => ScalarType::Int32.nullable(in_nullable),
+
+            RangeContainsElem { .. }
+            | RangeContainsRange { .. }
+            | RangeOverlaps
+            | RangeAdjacent => ScalarType::Bool.nullable(in_nullable),
+
+            RangeUnion | RangeIntersection | RangeDifference => input1_type
+                .scalar_type
+                .without_modifiers()
+                .nullable(in_nullable),
         }
     }
 
@@ -2465,6 +2490,13 @@ impl BinaryFunc {
                 | ModFloat32
                 | ModFloat64
                 | ModNumeric
+                | RangeContainsElem { .. }
+                | RangeContainsRange { .. }
+                | RangeOverlaps
+                | RangeAdjacent
+                | RangeUnion
+                | RangeIntersection
+                | RangeDifference
         )
     }
 
@@ -2593,7 +2625,14 @@ impl BinaryFunc {
             | ArrayArrayConcat
             | ListListConcat
             | ListElementConcat
-            | ElementListConcat => true,
+            | ElementListConcat
+            | RangeContainsElem { .. }
+            | RangeContainsRange { .. }
+            | RangeOverlaps
+            | RangeAdjacent
+            | RangeUnion
+            | RangeIntersection
+            | RangeDifference => true,
             ToCharTimestamp
             | ToCharTimestampTz
             | DateBinTimestamp
@@ -2849,6 +2888,14 @@ impl fmt::Display for BinaryFunc {
             BinaryFunc::PowerNumeric => f.write_str("power_numeric"),
             BinaryFunc::RepeatString => f.write_str("repeat"),
             BinaryFunc::GetByte => f.write_str("get_byte"),
+            BinaryFunc::RangeContainsElem { rev } | BinaryFunc::RangeContainsRange { rev } => {
+                f.write_str(if *rev { "<@" } else { "@>" })
+            }
+            BinaryFunc::RangeOverlaps => f.write_str("&&"),
+            BinaryFunc::RangeAdjacent => f.write_str("-|-"),
+            BinaryFunc::RangeUnion => f.write_str("+"),
+            BinaryFunc::RangeIntersection => f.write_str("*"),
+            BinaryFunc::RangeDifference => f.write_str("-"),
         }
     }
 }
@@ -3035,6 +3082,13 @@ impl Arbitrary for BinaryFunc {
             Just(BinaryFunc::LogNumeric),
             Just(BinaryFunc::Power),
             Just(BinaryFunc::PowerNumeric),
+            bool::arbitrary().prop_map(|rev| BinaryFunc::RangeContainsElem { rev }),
+            bool::arbitrary().prop_map(|rev| BinaryFunc::RangeContainsRange { rev }),
+            Just(BinaryFunc::RangeOverlaps),
+            Just(BinaryFunc::RangeAdjacent),
+            Just(BinaryFunc::RangeUnion),
+            Just(BinaryFunc::RangeIntersection),
+            Just(BinaryFunc::RangeDifference),
         ]
     }
 }
@@ -3211,6 +3265,13 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
             BinaryFunc::Power => Power(()),
             BinaryFunc::PowerNumeric => PowerNumeric(()),
             BinaryFunc::GetByte => GetByte(()),
+            BinaryFunc::RangeContainsElem { rev } => RangeContainsElem(*rev),
+            BinaryFunc::RangeContainsRange { rev } => RangeContainsRange(*rev),
+            BinaryFunc::RangeOverlaps => RangeOverlaps(()),
+            BinaryFunc::RangeAdjacent => RangeAdjacent(()),
+            BinaryFunc::RangeUnion => RangeUnion(()),
+            BinaryFunc::RangeIntersection => RangeIntersection(()),
+            BinaryFunc::RangeDifference => RangeDifference(()),
         };
         ProtoBinaryFunc { kind: Some(kind) }
     }
@@ -3393,6 +3454,13 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
                 Power(()) => Ok(BinaryFunc::Power),
                 PowerNumeric(()) => Ok(BinaryFunc::PowerNumeric),
                 GetByte(()) => Ok(BinaryFunc::GetByte),
+                RangeContainsElem(rev) => Ok(BinaryFunc::RangeContainsElem { rev }),
+                RangeContainsRange(rev) => Ok(BinaryFunc::RangeContainsRange { rev }),
+                RangeOverlaps(()) => Ok(BinaryFunc::RangeOverlaps),
+                RangeAdjacent(()) => Ok(BinaryFunc::RangeAdjacent),
+                RangeUnion(()) => Ok(BinaryFunc::RangeUnion),
+                RangeIntersection(()) => Ok(BinaryFunc::RangeIntersection),
+                RangeDifference(()) => Ok(BinaryFunc::RangeDifference),
             }
         } else {
             Err(TryFromProtoError::missing_field("ProtoBinaryFunc::kind"))
@@ -3686,6 +3754,7 @@ derive_unary!(
     CastStringToArray,
     CastStringToList,
     CastStringToMap,
+    CastStringToRange,
     CastStringToTime,
     CastStringToTimestamp,
     CastStringToTimestampTz,
@@ -3733,6 +3802,7 @@ derive_unary!(
     CastList1ToList2,
     CastArrayToListOneDim,
     CastMapToString,
+    CastRangeToString,
     CastInt2VectorToString,
     CeilFloat32,
     CeilFloat64,
@@ -3764,6 +3834,8 @@ derive_unary!(
     TimezoneTimestamp,
     TimezoneTimestampTz,
     TimezoneTime,
+    CastTimestampTzToStringInZone,
+    CastStringToTimestampTzInZone,
     ToTimestamp,
     JustifyDays,
     JustifyHours,
@@ -3813,7 +3885,14 @@ derive_unary!(
     PgColumnSize,
     MzRowSize,
     MzTypeName,
-    StepMzTimestamp
+    StepMzTimestamp,
+    RangeLower,
+    RangeUpper,
+    RangeEmpty,
+    RangeLowerInc,
+    RangeUpperInc,
+    RangeLowerInf,
+    RangeUpperInf
 );
 
 impl UnaryFunc {
@@ -4015,6 +4094,12 @@ impl Arbitrary for UnaryFunc {
                     cast_expr: Box::new(expr),
                 })
             }),
+            (any::<ScalarType>(), any::<MirScalarExpr>()).prop_map(|(return_ty, expr)| {
+                UnaryFunc::CastStringToRange(CastStringToRange {
+                    return_ty,
+                    cast_expr: Box::new(expr),
+                })
+            }),
             CastStringToTime::arbitrary().prop_map_into(),
             CastStringToTimestamp::arbitrary().prop_map_into(),
             CastStringToTimestampTz::arbitrary().prop_map_into(),
@@ -4108,6 +4193,8 @@ impl Arbitrary for UnaryFunc {
             TimezoneTimestamp::arbitrary().prop_map_into(),
             TimezoneTimestampTz::arbitrary().prop_map_into(),
             TimezoneTime::arbitrary().prop_map_into(),
+            CastTimestampTzToStringInZone::arbitrary().prop_map_into(),
+            CastStringToTimestampTzInZone::arbitrary().prop_map_into(),
             ToTimestamp::arbitrary().prop_map_into(),
             JustifyDays::arbitrary().prop_map_into(),
             JustifyHours::arbitrary().prop_map_into(),
@@ -4157,6 +4244,14 @@ impl Arbitrary for UnaryFunc {
             PgColumnSize::arbitrary().prop_map_into(),
             MzRowSize::arbitrary().prop_map_into(),
             MzTypeName::arbitrary().prop_map_into(),
+            CastRangeToString::arbitrary().prop_map_into(),
+            RangeLower::arbitrary().prop_map_into(),
+            RangeUpper::arbitrary().prop_map_into(),
+            RangeEmpty::arbitrary().prop_map_into(),
+            RangeLowerInc::arbitrary().prop_map_into(),
+            RangeUpperInc::arbitrary().prop_map_into(),
+            RangeLowerInf::arbitrary().prop_map_into(),
+            RangeUpperInf::arbitrary().prop_map_into(),
         ]
     }
 }
@@ -4324,6 +4419,12 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
                     cast_expr: Some(inner.cast_expr.into_proto()),
                 }))
             }
+            UnaryFunc::CastStringToRange(inner) => {
+                CastStringToRange(Box::new(ProtoCastToVariableType {
+                    return_ty: Some(inner.return_ty.into_proto()),
+                    cast_expr: Some(inner.cast_expr.into_proto()),
+                }))
+            }
             UnaryFunc::CastStringToTime(_) => CastStringToTime(()),
             UnaryFunc::CastStringToTimestamp(_) => CastStringToTimestamp(()),
             UnaryFunc::CastStringToTimestampTz(_) => CastStringToTimestampTz(()),
@@ -4423,6 +4524,12 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
                 tz: Some(func.tz.into_proto()),
                 wall_time: Some(func.wall_time.into_proto()),
             }),
+            UnaryFunc::CastTimestampTzToStringInZone(func) => {
+                CastTimestampTzToStringInZone(func.0.into_proto())
+            }
+            UnaryFunc::CastStringToTimestampTzInZone(func) => {
+                CastStringToTimestampTzInZone(func.0.into_proto())
+            }
             UnaryFunc::ToTimestamp(_) => ToTimestamp(()),
             UnaryFunc::JustifyDays(_) => JustifyDays(()),
             UnaryFunc::JustifyHours(_) => JustifyHours(()),
@@ -4482,6 +4589,14 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
             UnaryFunc::CastTimestampToMzTimestamp(_) => CastTimestampToMzTimestamp(()),
             UnaryFunc::CastTimestampTzToMzTimestamp(_) => CastTimestampTzToMzTimestamp(()),
             UnaryFunc::StepMzTimestamp(_) => StepMzTimestamp(()),
+            UnaryFunc::CastRangeToString(func) => CastRangeToString(func.ty.into_proto()),
+            UnaryFunc::RangeLower(_) => RangeLower(()),
+            UnaryFunc::RangeUpper(_) => RangeUpper(()),
+            UnaryFunc::RangeEmpty(_) => RangeEmpty(()),
+            UnaryFunc::RangeLowerInc(_) => RangeLowerInc(()),
+            UnaryFunc::RangeUpperInc(_) => RangeUpperInc(()),
+            UnaryFunc::RangeLowerInf(_) => RangeLowerInf(()),
+            UnaryFunc::RangeUpperInf(_) => RangeUpperInf(()),
         };
         ProtoUnaryFunc { kind: Some(kind) }
     }
@@ -4806,6 +4921,12 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
                         .into_rust_if_some("ProtoTimezoneTime::wall_time")?,
                 }
                 .into()),
+                CastTimestampTzToStringInZone(tz) => {
+                    Ok(impls::CastTimestampTzToStringInZone(tz.into_rust()?).into())
+                }
+                CastStringToTimestampTzInZone(tz) => {
+                    Ok(impls::CastStringToTimestampTzInZone(tz.into_rust()?).into())
+                }
                 ToTimestamp(()) => Ok(impls::ToTimestamp.into()),
                 JustifyDays(()) => Ok(impls::JustifyDays.into()),
                 JustifyHours(()) => Ok(impls::JustifyHours.into()),
@@ -4868,6 +4989,26 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
                 CastTimestampToMzTimestamp(()) => Ok(impls::CastTimestampToMzTimestamp.into()),
                 CastTimestampTzToMzTimestamp(()) => Ok(impls::CastTimestampTzToMzTimestamp.into()),
                 StepMzTimestamp(()) => Ok(impls::StepMzTimestamp.into()),
+                CastStringToRange(inner) => Ok(impls::CastStringToRange {
+                    return_ty: inner
+                        .return_ty
+                        .into_rust_if_some("ProtoCastStringToRange::return_ty")?,
+                    cast_expr: inner
+                        .cast_expr
+                        .into_rust_if_some("ProtoCastStringToRange::cast_expr")?,
+                }
+                .into()),
+                CastRangeToString(ty) => Ok(impls::CastRangeToString {
+                    ty: ty.into_rust()?,
+                }
+                .into()),
+                RangeLower(()) => Ok(impls::RangeLower.into()),
+                RangeUpper(()) => Ok(impls::RangeUpper.into()),
+                RangeEmpty(()) => Ok(impls::RangeEmpty.into()),
+                RangeLowerInc(()) => Ok(impls::RangeLowerInc.into()),
+                RangeUpperInc(()) => Ok(impls::RangeUpperInc.into()),
+                RangeLowerInf(()) => Ok(impls::RangeLowerInf.into()),
+                RangeUpperInf(()) => Ok(impls::RangeUpperInf.into()),
             }
         } else {
             Err(TryFromProtoError::missing_field("ProtoUnaryFunc::kind"))
@@ -5466,6 +5607,11 @@ where
             stringify_datum(buf.nonnull_buffer(), d, &ScalarType::Int16)
         }),
         MzTimestamp { .. } => Ok(strconv::format_mz_timestamp(buf, d.unwrap_mz_timestamp())),
+        Range { element_type } => {
+            strconv::format_range(buf, d.unwrap_range().desc().as_ref(), |buf, d| {
+                stringify_datum(buf, *d, element_type)
+            })
+        }
     }
 }
 
@@ -6000,6 +6146,85 @@ fn list_remove<'a>(a: Datum<'a>, b: Datum<'a>, temp_storage: &'a RowArena) -> Da
     })
 }
 
+fn range_contains_elem<'a>(a: Datum<'a>, b: Datum<'a>, rev: bool) -> Datum<'a> {
+    let (range, elem) = if rev { (b, a) } else { (a, b) };
+    Datum::from(range.unwrap_range().contains(&elem))
+}
+
+fn range_contains_range<'a>(a: Datum<'a>, b: Datum<'a>, rev: bool) -> Datum<'a> {
+    let (l, r) = if rev { (b, a) } else { (a, b) };
+    Datum::from(l.unwrap_range().contains_range(&r.unwrap_range()))
+}
+
+fn range_overlaps<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
+    Datum::from(a.unwrap_range().overlaps(&b.unwrap_range()))
+}
+
+fn range_adjacent<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
+    Datum::from(a.unwrap_range().adjacent(&b.unwrap_range()))
+}
+
+fn range_union<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
+    Ok(Datum::Range(a.unwrap_range().union(&b.unwrap_range())?))
+}
+
+fn range_intersection<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
+    Datum::Range(a.unwrap_range().intersection(&b.unwrap_range()))
+}
+
+fn range_difference<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
+    Ok(Datum::Range(
+        a.unwrap_range().difference(&b.unwrap_range())?,
+    ))
+}
+
+/// Constructs a range from its lower bound, its upper bound and, optionally,
+/// its bound flags (e.g. `[)`). `NULL` bounds are infinite.
+fn range_create<'a>(
+    datums: &[Datum<'a>],
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let flags = match datums.get(2) {
+        None => "[)",
+        Some(Datum::Null) => {
+            return Err(EvalError::InvalidParameterValue(
+                "range constructor flags argument must not be null".into(),
+            ))
+        }
+        Some(flags) => flags.unwrap_str(),
+    };
+    let (lower_inclusive, upper_inclusive) = match flags {
+        "[)" => (true, false),
+        "[]" => (true, true),
+        "(]" => (false, true),
+        "()" => (false, false),
+        _ => return Err(RangeError::InvalidRangeBoundFlags.into()),
+    };
+    let range = RangeDesc {
+        lower: RangeBoundDesc::new(datums[0], lower_inclusive),
+        upper: RangeBoundDesc::new(datums[1], upper_inclusive),
+    };
+    canonical_range_datum(Some(range), temp_storage)
+}
+
+/// Packs the canonical form of `range` into `temp_storage`. `None` represents
+/// the empty range.
+fn canonical_range_datum<'a>(
+    range: Option<RangeDesc<Datum>>,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let range = match range {
+        None => None,
+        Some(range) => range.canonicalize()?,
+    };
+    Ok(temp_storage.make_datum(|packer| match range {
+        None => packer.push_empty_range(),
+        Some(range) => packer
+            .push_range(range.lower, range.upper)
+            .expect("canonical range bounds are ordered"),
+    }))
+}
+
 fn digest_string<'a>(
     a: Datum<'a>,
     b: Datum<'a>,
@@ -6094,6 +6319,9 @@ pub enum VariadicFunc {
     DateBinTimestampTz,
     And,
     Or,
+    RangeCreate {
+        elem_type: ScalarType,
+    },
 }
 
 impl VariadicFunc {
@@ -6157,6 +6385,7 @@ impl VariadicFunc {
             )),
             VariadicFunc::And => and(datums, temp_storage, exprs),
             VariadicFunc::Or => or(datums, temp_storage, exprs),
+            VariadicFunc::RangeCreate { .. } => eager!(range_create, temp_storage),
         }
     }
 
@@ -6188,7 +6417,8 @@ impl VariadicFunc {
             | VariadicFunc::HmacBytes
             | VariadicFunc::ErrorIfNull
             | VariadicFunc::DateBinTimestamp
-            | VariadicFunc::DateBinTimestampTz => false,
+            | VariadicFunc::DateBinTimestampTz
+            | VariadicFunc::RangeCreate { .. } => false,
         }
     }
 
@@ -6257,6 +6487,10 @@ impl VariadicFunc {
             DateBinTimestamp => ScalarType::Timestamp.nullable(true),
             DateBinTimestampTz => ScalarType::TimestampTz.nullable(true),
             And | Or => ScalarType::Bool.nullable(in_nullable),
+            RangeCreate { elem_type } => ScalarType::Range {
+                element_type: Box::new(elem_type.clone()),
+            }
+            .nullable(false),
         }
     }
 
@@ -6279,6 +6513,7 @@ impl VariadicFunc {
                 | VariadicFunc::ArrayCreate { .. }
                 | VariadicFunc::ArrayToString { .. }
                 | VariadicFunc::ErrorIfNull
+                | VariadicFunc::RangeCreate { .. }
         )
     }
 
@@ -6292,7 +6527,7 @@ impl VariadicFunc {
         // Note the negation
         !matches!(
             self,
-            And | Or //todo: add more
+            And | Or | RangeCreate { .. } //todo: add more
         )
     }
 
@@ -6366,6 +6601,7 @@ impl fmt::Display for VariadicFunc {
             VariadicFunc::DateBinTimestampTz => f.write_str("timestamptz_bin"),
             VariadicFunc::And => f.write_str("AND"),
             VariadicFunc::Or => f.write_str("OR"),
+            VariadicFunc::RangeCreate { .. } => f.write_str("range_create"),
         }
     }
 }
@@ -6410,6 +6646,7 @@ impl Arbitrary for VariadicFunc {
             Just(VariadicFunc::DateBinTimestampTz),
             Just(VariadicFunc::And),
             Just(VariadicFunc::Or),
+            ScalarType::arbitrary().prop_map(|elem_type| VariadicFunc::RangeCreate { elem_type }),
         ]
     }
 }
@@ -6447,6 +6684,7 @@ impl RustType<ProtoVariadicFunc> for VariadicFunc {
             VariadicFunc::DateBinTimestampTz => DateBinTimestampTz(()),
             VariadicFunc::And => And(()),
             VariadicFunc::Or => Or(()),
+            VariadicFunc::RangeCreate { elem_type } => RangeCreate(elem_type.into_proto()),
         };
         ProtoVariadicFunc { kind: Some(kind) }
     }
@@ -6492,6 +6730,9 @@ impl RustType<ProtoVariadicFunc> for VariadicFunc {
                 DateBinTimestampTz(()) => Ok(VariadicFunc::DateBinTimestampTz),
                 And(()) => Ok(VariadicFunc::And),
                 Or(()) => Ok(VariadicFunc::Or),
+                RangeCreate(elem_type) => Ok(VariadicFunc::RangeCreate {
+                    elem_type: elem_type.into_rust()?,
+                }),
             }
         } else {
             Err(TryFromProtoError::missing_field(
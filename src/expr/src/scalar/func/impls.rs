@@ -27,6 +27,7 @@ mod mz_timestamp;
 mod numeric;
 mod oid;
 mod pg_legacy_char;
+mod range;
 mod record;
 mod regproc;
 mod string;
@@ -59,6 +60,7 @@ pub use mz_timestamp::*;
 pub use numeric::*;
 pub use oid::*;
 pub use pg_legacy_char::*;
+pub use range::*;
 pub use record::*;
 pub use regproc::*;
 pub use string::*;
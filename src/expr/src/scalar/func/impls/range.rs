@@ -0,0 +1,167 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::fmt;
+
+use proptest_derive::Arbitrary;
+use serde::{Deserialize, Serialize};
+
+use mz_lowertest::MzReflect;
+use mz_repr::adt::range::Range;
+use mz_repr::{ColumnType, Datum, RowArena, ScalarType};
+
+use crate::scalar::func::{stringify_datum, EagerUnaryFunc, LazyUnaryFunc};
+use crate::{EvalError, MirScalarExpr};
+
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct CastRangeToString {
+    pub ty: ScalarType,
+}
+
+impl LazyUnaryFunc for CastRangeToString {
+    fn eval<'a>(
+        &'a self,
+        datums: &[Datum<'a>],
+        temp_storage: &'a RowArena,
+        a: &'a MirScalarExpr,
+    ) -> Result<Datum<'a>, EvalError> {
+        let a = a.eval(datums, temp_storage)?;
+        if a.is_null() {
+            return Ok(Datum::Null);
+        }
+        let mut buf = String::new();
+        stringify_datum(&mut buf, a, &self.ty)?;
+        Ok(Datum::String(temp_storage.push_string(buf)))
+    }
+
+    fn output_type(&self, input_type: ColumnType) -> ColumnType {
+        ScalarType::String.nullable(input_type.nullable)
+    }
+
+    fn propagates_nulls(&self) -> bool {
+        true
+    }
+
+    fn introduces_nulls(&self) -> bool {
+        false
+    }
+
+    fn preserves_uniqueness(&self) -> bool {
+        true
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        None
+    }
+}
+
+impl fmt::Display for CastRangeToString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("rangetostr")
+    }
+}
+
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct RangeLower;
+
+impl<'a> EagerUnaryFunc<'a> for RangeLower {
+    type Input = Range<'a>;
+    type Output = Datum<'a>;
+
+    fn call(&self, a: Range<'a>) -> Datum<'a> {
+        // Empty ranges and infinite bounds have no lower value.
+        a.lower()
+            .and_then(|lower| lower.datum())
+            .unwrap_or(Datum::Null)
+    }
+
+    fn output_type(&self, input_type: ColumnType) -> ColumnType {
+        input_type
+            .scalar_type
+            .unwrap_range_element_type()
+            .clone()
+            .nullable(true)
+    }
+}
+
+impl fmt::Display for RangeLower {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("rangelower")
+    }
+}
+
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct RangeUpper;
+
+impl<'a> EagerUnaryFunc<'a> for RangeUpper {
+    type Input = Range<'a>;
+    type Output = Datum<'a>;
+
+    fn call(&self, a: Range<'a>) -> Datum<'a> {
+        // Empty ranges and infinite bounds have no upper value.
+        a.upper()
+            .and_then(|upper| upper.datum())
+            .unwrap_or(Datum::Null)
+    }
+
+    fn output_type(&self, input_type: ColumnType) -> ColumnType {
+        input_type
+            .scalar_type
+            .unwrap_range_element_type()
+            .clone()
+            .nullable(true)
+    }
+}
+
+impl fmt::Display for RangeUpper {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("rangeupper")
+    }
+}
+
+sqlfunc!(
+    #[sqlname = "range_empty"]
+    fn range_empty<'a>(a: Range<'a>) -> bool {
+        a.inner.is_none()
+    }
+);
+
+sqlfunc!(
+    #[sqlname = "range_lower_inc"]
+    fn range_lower_inc<'a>(a: Range<'a>) -> bool {
+        a.lower().map_or(false, |lower| lower.inclusive)
+    }
+);
+
+sqlfunc!(
+    #[sqlname = "range_upper_inc"]
+    fn range_upper_inc<'a>(a: Range<'a>) -> bool {
+        a.upper().map_or(false, |upper| upper.inclusive)
+    }
+);
+
+sqlfunc!(
+    #[sqlname = "range_lower_inf"]
+    fn range_lower_inf<'a>(a: Range<'a>) -> bool {
+        a.lower().map_or(false, |lower| lower.bound.is_none())
+    }
+);
+
+sqlfunc!(
+    #[sqlname = "range_upper_inf"]
+    fn range_upper_inf<'a>(a: Range<'a>) -> bool {
+        a.upper().map_or(false, |upper| upper.bound.is_none())
+    }
+);
@@ -24,6 +24,7 @@ use mz_ore::result::ResultExt;
 use mz_ore::str::StrExt;
 use mz_repr::adt::array::ArrayDimension;
 use mz_repr::adt::char::{format_str_trim, Char};
+use mz_repr::adt::datetime::Timezone;
 use mz_repr::adt::interval::Interval;
 use mz_repr::adt::jsonb::Jsonb;
 use mz_repr::adt::numeric::{self, Numeric, NumericMaxScale};
@@ -32,7 +33,9 @@ use mz_repr::adt::system::{Oid, PgLegacyChar};
 use mz_repr::adt::varchar::{VarChar, VarCharMaxLength};
 use mz_repr::{strconv, ColumnType, Datum, Row, RowArena, ScalarType};
 
-use crate::scalar::func::{array_create_scalar, EagerUnaryFunc, LazyUnaryFunc};
+use crate::scalar::func::{
+    array_create_scalar, canonical_range_datum, EagerUnaryFunc, LazyUnaryFunc,
+};
 use crate::{like_pattern, EvalError, MirScalarExpr, UnaryFunc};
 
 sqlfunc!(
@@ -217,6 +220,37 @@ sqlfunc!(
     }
 );
 
+/// Like [`CastStringToTimestampTz`], but interprets timestamps without an
+/// explicit time zone as local times in the time zone `self.0`, which is how
+/// one-shot queries honor the session's `TimeZone`.
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct CastStringToTimestampTzInZone(pub Timezone);
+
+impl<'a> EagerUnaryFunc<'a> for CastStringToTimestampTzInZone {
+    type Input = &'a str;
+    type Output = Result<CheckedTimestamp<DateTime<Utc>>, EvalError>;
+
+    fn call(&self, a: &'a str) -> Result<CheckedTimestamp<DateTime<Utc>>, EvalError> {
+        strconv::parse_timestamptz_in_zone(a, &self.0).err_into()
+    }
+
+    fn output_type(&self, input: ColumnType) -> ColumnType {
+        ScalarType::TimestampTz.nullable(input.nullable)
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        to_unary!(super::CastTimestampTzToStringInZone(self.0))
+    }
+}
+
+impl fmt::Display for CastStringToTimestampTzInZone {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "text_to_timestamp_with_time_zone_{}", self.0)
+    }
+}
+
 sqlfunc!(
     #[sqlname = "text_to_interval"]
     #[preserves_uniqueness = false]
@@ -457,6 +491,72 @@ impl fmt::Display for CastStringToMap {
     }
 }
 
+#[derive(Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect)]
+pub struct CastStringToRange {
+    // Target range's type
+    pub return_ty: ScalarType,
+    // The expression to cast the discovered range bounds to the range's
+    // element type.
+    pub cast_expr: Box<MirScalarExpr>,
+}
+
+impl LazyUnaryFunc for CastStringToRange {
+    fn eval<'a>(
+        &'a self,
+        datums: &[Datum<'a>],
+        temp_storage: &'a RowArena,
+        a: &'a MirScalarExpr,
+    ) -> Result<Datum<'a>, EvalError> {
+        let a = a.eval(datums, temp_storage)?;
+        if a.is_null() {
+            return Ok(Datum::Null);
+        }
+        let range = strconv::parse_range(a.unwrap_str(), |elem_text| {
+            let elem_text = match elem_text {
+                Cow::Owned(s) => temp_storage.push_string(s),
+                Cow::Borrowed(s) => s,
+            };
+            self.cast_expr
+                .eval(&[Datum::String(elem_text)], temp_storage)
+        })?;
+        canonical_range_datum(range, temp_storage)
+    }
+
+    /// The output ColumnType of this function
+    fn output_type(&self, input_type: ColumnType) -> ColumnType {
+        self.return_ty
+            .without_modifiers()
+            .nullable(input_type.nullable)
+    }
+
+    /// Whether this function will produce NULL on NULL input
+    fn propagates_nulls(&self) -> bool {
+        true
+    }
+
+    /// Whether this function will produce NULL on non-NULL input
+    fn introduces_nulls(&self) -> bool {
+        false
+    }
+
+    /// Whether this function preserves uniqueness
+    fn preserves_uniqueness(&self) -> bool {
+        false
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        to_unary!(super::CastRangeToString {
+            ty: self.return_ty.clone(),
+        })
+    }
+}
+
+impl fmt::Display for CastStringToRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("strtorange")
+    }
+}
+
 #[derive(
     Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
 )]
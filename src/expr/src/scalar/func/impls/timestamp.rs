@@ -47,6 +47,43 @@ sqlfunc!(
     }
 );
 
+/// Like [`CastTimestampTzToString`], but renders the timestamp as a local time
+/// in the time zone `self.0`, which is how one-shot queries honor the session's
+/// `TimeZone`.
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct CastTimestampTzToStringInZone(pub Timezone);
+
+impl<'a> EagerUnaryFunc<'a> for CastTimestampTzToStringInZone {
+    type Input = CheckedTimestamp<DateTime<Utc>>;
+    type Output = String;
+
+    fn call(&self, a: CheckedTimestamp<DateTime<Utc>>) -> String {
+        let mut buf = String::new();
+        strconv::format_timestamptz_in_zone(&mut buf, &a, &self.0);
+        buf
+    }
+
+    fn output_type(&self, input: ColumnType) -> ColumnType {
+        ScalarType::String.nullable(input.nullable)
+    }
+
+    fn preserves_uniqueness(&self) -> bool {
+        true
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        to_unary!(super::CastStringToTimestampTzInZone(self.0))
+    }
+}
+
+impl fmt::Display for CastTimestampTzToStringInZone {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "timestamp_with_time_zone_to_text_{}", self.0)
+    }
+}
+
 sqlfunc!(
     #[sqlname = "timestamp_to_date"]
     #[preserves_uniqueness = false]
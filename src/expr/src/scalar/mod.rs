@@ -30,6 +30,7 @@ use mz_pgrepr::TypeFromOidError;
 use mz_proto::{ProtoType, RustType, TryFromProtoError};
 use mz_repr::adt::array::InvalidArrayError;
 use mz_repr::adt::datetime::DateTimeUnits;
+use mz_repr::adt::range::RangeError;
 use mz_repr::adt::regex::Regex;
 use mz_repr::arb_datum;
 use mz_repr::strconv::{ParseError, ParseHexError};
@@ -2248,6 +2249,8 @@ pub enum EvalError {
         dims: Option<(usize, usize)>,
     },
     TypeFromOid(String),
+    LetRecLimitExceeded(u64),
+    InvalidRange(RangeError),
 }
 
 impl fmt::Display for EvalError {
@@ -2398,6 +2401,13 @@ impl fmt::Display for EvalError {
                 write!(f, "cannot concatenate incompatible arrays")
             }
             EvalError::TypeFromOid(msg) => write!(f, "{msg}"),
+            EvalError::LetRecLimitExceeded(max_iters) => {
+                write!(
+                    f,
+                    "WITH MUTUALLY RECURSIVE did not converge within {max_iters} iterations"
+                )
+            }
+            EvalError::InvalidRange(e) => e.fmt(f),
         }
     }
 }
@@ -2433,6 +2443,10 @@ impl EvalError {
                 converted to mz_timestamp."
                     .into(),
             ),
+            EvalError::LetRecLimitExceeded(_) => Some(
+                "Use the RECURSION LIMIT option of WITH MUTUALLY RECURSIVE to raise the limit."
+                    .into(),
+            ),
             _ => None,
         }
     }
@@ -2458,6 +2472,12 @@ impl From<InvalidArrayError> for EvalError {
     }
 }
 
+impl From<RangeError> for EvalError {
+    fn from(e: RangeError) -> EvalError {
+        EvalError::InvalidRange(e)
+    }
+}
+
 impl From<regex::Error> for EvalError {
     fn from(e: regex::Error) -> EvalError {
         EvalError::InvalidRegex(e.to_string())
@@ -2592,6 +2612,8 @@ impl RustType<ProtoEvalError> for EvalError {
                 })
             }
             EvalError::TypeFromOid(v) => TypeFromOid(v.clone()),
+            EvalError::LetRecLimitExceeded(v) => LetRecLimitExceeded(*v),
+            EvalError::InvalidRange(error) => InvalidRange(error.into_proto()),
         };
         ProtoEvalError { kind: Some(kind) }
     }
@@ -2687,6 +2709,8 @@ impl RustType<ProtoEvalError> for EvalError {
                     dims: v.dims.into_rust()?,
                 }),
                 TypeFromOid(v) => Ok(EvalError::TypeFromOid(v)),
+                LetRecLimitExceeded(v) => Ok(EvalError::LetRecLimitExceeded(v)),
+                InvalidRange(error) => Ok(EvalError::InvalidRange(error.into_rust()?)),
             },
             None => Err(TryFromProtoError::missing_field("ProtoEvalError::kind")),
         }
@@ -8,7 +8,7 @@
 // by the Apache License, Version 2.0.
 
 use anyhow::{bail, Result};
-use byteorder::{BigEndian, ByteOrder};
+use byteorder::{BigEndian, ByteOrder, NetworkEndian, WriteBytesExt};
 
 /// Extracts the schema_id placed in front of the serialized message by the confluent stack
 /// Optionally expect an empty
@@ -70,3 +70,16 @@ pub fn extract_protobuf_header(buf: &[u8]) -> Result<(i32, &[u8])> {
         ),
     }
 }
+
+/// Writes the Confluent-style header for a protobuf message with the given
+/// schema ID.
+///
+/// The message is always described as the first message in its schema, which
+/// is the only layout that [`extract_protobuf_header`] accepts.
+pub fn encode_protobuf_header(buf: &mut Vec<u8>, schema_id: i32) {
+    buf.write_u8(0).expect("writing to vec cannot fail");
+    buf.write_i32::<NetworkEndian>(schema_id)
+        .expect("writing to vec cannot fail");
+    // A single zero byte is shorthand for the message index array `[0]`.
+    buf.write_u8(0).expect("writing to vec cannot fail");
+}
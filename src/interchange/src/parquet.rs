@@ -0,0 +1,531 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Decoding of Apache Parquet files.
+//!
+//! A Parquet schema is supplied by the user as a message type, e.g.
+//!
+//! ```text
+//! message m {
+//!   required int64 id;
+//!   optional binary name (STRING);
+//! }
+//! ```
+//!
+//! Each field of the message becomes a column, and Parquet logical types are
+//! mapped onto the closest [`ScalarType`]. Files are read one row group at a
+//! time, so memory usage is bounded by the largest row group rather than the
+//! size of the file.
+
+use std::io::{Read, Seek};
+
+use anyhow::{anyhow, bail, Context};
+use arrow2::array::{
+    Array, BinaryArray, BooleanArray, FixedSizeBinaryArray, ListArray, PrimitiveArray, StructArray,
+    Utf8Array,
+};
+use arrow2::datatypes::{DataType, Field, Schema, TimeUnit};
+use arrow2::io::parquet::read::schema::parquet_to_arrow_schema;
+use arrow2::io::parquet::read::{infer_schema, read_metadata, FileReader};
+use chrono::{NaiveDateTime, NaiveTime};
+use ordered_float::OrderedFloat;
+use parquet2::metadata::SchemaDescriptor;
+
+use mz_ore::cast::CastFrom;
+use mz_repr::adt::date::Date;
+use mz_repr::adt::numeric::{self, NumericMaxScale, NUMERIC_DATUM_MAX_PRECISION};
+use mz_repr::adt::timestamp::CheckedTimestamp;
+use mz_repr::{ColumnName, ColumnType, Datum, RelationDesc, Row, RowPacker, ScalarType};
+
+/// Parses a Parquet message type into the Arrow fields it describes.
+pub fn parse_schema(schema: &str) -> Result<Vec<Field>, anyhow::Error> {
+    let descriptor = SchemaDescriptor::try_from_message(schema)
+        .map_err(|e| anyhow!("invalid parquet message type: {}", e))?;
+    let fields = parquet_to_arrow_schema(descriptor.fields());
+    if fields.is_empty() {
+        bail!("parquet schema must contain at least one field");
+    }
+    Ok(fields)
+}
+
+/// Converts a set of Parquet fields, as returned by [`parse_schema`], into a
+/// [`RelationDesc`].
+pub fn schema_to_relationdesc(fields: &[Field]) -> Result<RelationDesc, anyhow::Error> {
+    let mut desc = RelationDesc::empty();
+    for field in fields {
+        let ty = column_type(field).with_context(|| format!("column {}", field.name))?;
+        desc = desc.with_column(&field.name, ty);
+    }
+    Ok(desc)
+}
+
+fn column_type(field: &Field) -> Result<ColumnType, anyhow::Error> {
+    Ok(scalar_type(&field.data_type)?.nullable(field.is_nullable))
+}
+
+fn scalar_type(data_type: &DataType) -> Result<ScalarType, anyhow::Error> {
+    Ok(match data_type {
+        DataType::Boolean => ScalarType::Bool,
+        DataType::Int8 | DataType::Int16 => ScalarType::Int16,
+        DataType::Int32 => ScalarType::Int32,
+        DataType::Int64 => ScalarType::Int64,
+        DataType::UInt8 | DataType::UInt16 => ScalarType::UInt16,
+        DataType::UInt32 => ScalarType::UInt32,
+        DataType::UInt64 => ScalarType::UInt64,
+        DataType::Float32 => ScalarType::Float32,
+        DataType::Float64 => ScalarType::Float64,
+        DataType::Utf8 | DataType::LargeUtf8 => ScalarType::String,
+        DataType::Binary | DataType::LargeBinary | DataType::FixedSizeBinary(_) => {
+            ScalarType::Bytes
+        }
+        DataType::Date32 | DataType::Date64 => ScalarType::Date,
+        DataType::Time32(_) | DataType::Time64(_) => ScalarType::Time,
+        // Parquet timestamps that are "adjusted to UTC" carry a time zone.
+        DataType::Timestamp(_, None) => ScalarType::Timestamp,
+        DataType::Timestamp(_, Some(_)) => ScalarType::TimestampTz,
+        DataType::Decimal(precision, scale) => {
+            if *precision > usize::cast_from(NUMERIC_DATUM_MAX_PRECISION) {
+                bail!(
+                    "decimals with precision greater than {} are not supported",
+                    NUMERIC_DATUM_MAX_PRECISION
+                )
+            }
+            ScalarType::Numeric {
+                max_scale: Some(NumericMaxScale::try_from(*scale)?),
+            }
+        }
+        DataType::List(field) | DataType::LargeList(field) => ScalarType::List {
+            element_type: Box::new(scalar_type(&field.data_type)?),
+            custom_id: None,
+        },
+        DataType::Struct(fields) => ScalarType::Record {
+            fields: fields
+                .iter()
+                .map(|f| Ok((ColumnName::from(f.name.as_str()), column_type(f)?)))
+                .collect::<Result<_, anyhow::Error>>()?,
+            custom_id: None,
+        },
+        other => bail!("parquet type {:?} is not supported", other),
+    })
+}
+
+/// The result of decoding a single row.
+///
+/// Errors are scoped to the row that produced them, so that one bad value
+/// does not prevent the rest of the row group from being ingested.
+pub type DecodedRow = Result<Row, String>;
+
+/// Decodes Parquet files into rows matching a fixed schema.
+#[derive(Debug, Clone)]
+pub struct ParquetDecoder {
+    fields: Vec<Field>,
+}
+
+impl ParquetDecoder {
+    /// Constructs a decoder for the given Parquet message type.
+    pub fn new(schema: &str) -> Result<ParquetDecoder, anyhow::Error> {
+        let fields = parse_schema(schema)?;
+        // Validate that every column maps onto a `ScalarType` up front, so
+        // that decoding never encounters an unsupported type.
+        schema_to_relationdesc(&fields)?;
+        Ok(ParquetDecoder { fields })
+    }
+
+    /// Opens a Parquet file, returning an iterator over its row groups.
+    ///
+    /// Only the file footer is read eagerly; each row group is read and
+    /// decoded when the iterator is advanced. Columns present in the file but
+    /// absent from the schema are not read at all.
+    pub fn read<R: Read + Seek>(&self, mut reader: R) -> Result<RowGroups<R>, anyhow::Error> {
+        let metadata = read_metadata(&mut reader).context("reading parquet footer")?;
+        let file_schema = infer_schema(&metadata).context("reading parquet schema")?;
+
+        let mut projected = Vec::with_capacity(self.fields.len());
+        for expected in &self.fields {
+            let actual = file_schema
+                .fields
+                .iter()
+                .find(|f| f.name == expected.name)
+                .ok_or_else(|| anyhow!("parquet file is missing column {}", expected.name))?;
+            // Different physical encodings of the same logical type (e.g.
+            // millisecond vs. microsecond timestamps) are interchangeable.
+            if scalar_type(&actual.data_type)? != scalar_type(&expected.data_type)? {
+                bail!(
+                    "parquet column {} has type {:?}, but the schema specifies {:?}",
+                    expected.name,
+                    actual.data_type,
+                    expected.data_type
+                );
+            }
+            projected.push(actual.clone());
+        }
+
+        let reader = FileReader::new(
+            reader,
+            metadata.row_groups,
+            Schema::from(projected),
+            None,
+            None,
+            None,
+        );
+        Ok(RowGroups {
+            reader,
+            fields: self.fields.clone(),
+            row: Row::default(),
+        })
+    }
+}
+
+/// An iterator over the row groups of a Parquet file.
+///
+/// Each item holds the decoded rows of one row group, or an error if the row
+/// group itself could not be read.
+pub struct RowGroups<R: Read + Seek> {
+    reader: FileReader<R>,
+    fields: Vec<Field>,
+    row: Row,
+}
+
+impl<R: Read + Seek> std::fmt::Debug for RowGroups<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RowGroups")
+            .field("fields", &self.fields)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: Read + Seek> Iterator for RowGroups<R> {
+    type Item = Result<Vec<DecodedRow>, anyhow::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunk = match self.reader.next()? {
+            Ok(chunk) => chunk,
+            Err(e) => return Some(Err(anyhow!("reading parquet row group: {}", e))),
+        };
+        let arrays = chunk.arrays();
+        let mut rows = Vec::with_capacity(chunk.len());
+        for i in 0..chunk.len() {
+            let mut packer = self.row.packer();
+            let mut result = Ok(());
+            for (field, array) in self.fields.iter().zip(arrays) {
+                result = push_column(&mut packer, field, array.as_ref(), i);
+                if result.is_err() {
+                    break;
+                }
+            }
+            rows.push(result.map(|()| self.row.clone()));
+        }
+        Some(Ok(rows))
+    }
+}
+
+fn push_column(
+    packer: &mut RowPacker,
+    field: &Field,
+    array: &dyn Array,
+    i: usize,
+) -> Result<(), String> {
+    if !field.is_nullable && array.is_null(i) {
+        return Err(format!(
+            "column {} is declared required but contains a null value",
+            field.name
+        ));
+    }
+    push_value(packer, array, i).map_err(|e| format!("column {}: {}", field.name, e))
+}
+
+/// Downcasts `array` to the concrete array type `$t`.
+macro_rules! downcast {
+    ($array:expr, $t:ty) => {
+        $array
+            .as_any()
+            .downcast_ref::<$t>()
+            .expect("array type matches its data type")
+    };
+}
+
+fn push_value(packer: &mut RowPacker, array: &dyn Array, i: usize) -> Result<(), String> {
+    if array.is_null(i) {
+        packer.push(Datum::Null);
+        return Ok(());
+    }
+    let datum = match array.data_type() {
+        DataType::Boolean => Datum::from(downcast!(array, BooleanArray).value(i)),
+        DataType::Int8 => Datum::Int16(downcast!(array, PrimitiveArray<i8>).value(i).into()),
+        DataType::Int16 => Datum::Int16(downcast!(array, PrimitiveArray<i16>).value(i)),
+        DataType::Int32 => Datum::Int32(downcast!(array, PrimitiveArray<i32>).value(i)),
+        DataType::Int64 => Datum::Int64(downcast!(array, PrimitiveArray<i64>).value(i)),
+        DataType::UInt8 => Datum::UInt16(downcast!(array, PrimitiveArray<u8>).value(i).into()),
+        DataType::UInt16 => Datum::UInt16(downcast!(array, PrimitiveArray<u16>).value(i)),
+        DataType::UInt32 => Datum::UInt32(downcast!(array, PrimitiveArray<u32>).value(i)),
+        DataType::UInt64 => Datum::UInt64(downcast!(array, PrimitiveArray<u64>).value(i)),
+        DataType::Float32 => {
+            Datum::Float32(OrderedFloat(downcast!(array, PrimitiveArray<f32>).value(i)))
+        }
+        DataType::Float64 => {
+            Datum::Float64(OrderedFloat(downcast!(array, PrimitiveArray<f64>).value(i)))
+        }
+        DataType::Utf8 => Datum::String(downcast!(array, Utf8Array<i32>).value(i)),
+        DataType::LargeUtf8 => Datum::String(downcast!(array, Utf8Array<i64>).value(i)),
+        DataType::Binary => Datum::Bytes(downcast!(array, BinaryArray<i32>).value(i)),
+        DataType::LargeBinary => Datum::Bytes(downcast!(array, BinaryArray<i64>).value(i)),
+        DataType::FixedSizeBinary(_) => {
+            Datum::Bytes(downcast!(array, FixedSizeBinaryArray).value(i))
+        }
+        DataType::Date32 => {
+            let days = downcast!(array, PrimitiveArray<i32>).value(i);
+            Datum::Date(Date::from_unix_epoch(days).map_err(|e| e.to_string())?)
+        }
+        DataType::Date64 => {
+            let millis = downcast!(array, PrimitiveArray<i64>).value(i);
+            let days = i32::try_from(millis.div_euclid(86_400_000))
+                .map_err(|_| format!("date out of range: {}", millis))?;
+            Datum::Date(Date::from_unix_epoch(days).map_err(|e| e.to_string())?)
+        }
+        DataType::Time32(unit) => {
+            let t = downcast!(array, PrimitiveArray<i32>).value(i);
+            Datum::Time(time_from_unit(i64::from(t), *unit)?)
+        }
+        DataType::Time64(unit) => {
+            let t = downcast!(array, PrimitiveArray<i64>).value(i);
+            Datum::Time(time_from_unit(t, *unit)?)
+        }
+        DataType::Timestamp(unit, tz) => {
+            let t = downcast!(array, PrimitiveArray<i64>).value(i);
+            let dt = timestamp_from_unit(t, *unit)?;
+            if tz.is_some() {
+                let dt = chrono::DateTime::from_utc(dt, chrono::Utc);
+                Datum::TimestampTz(
+                    CheckedTimestamp::from_timestamplike(dt).map_err(|e| e.to_string())?,
+                )
+            } else {
+                Datum::Timestamp(
+                    CheckedTimestamp::from_timestamplike(dt).map_err(|e| e.to_string())?,
+                )
+            }
+        }
+        DataType::Decimal(_, scale) => {
+            let unscaled = downcast!(array, PrimitiveArray<i128>).value(i);
+            let scale = u8::try_from(*scale).map_err(|_| format!("invalid scale {}", scale))?;
+            let n = numeric::twos_complement_be_to_numeric(&mut unscaled.to_be_bytes(), scale)
+                .map_err(|e| e.to_string())?;
+            Datum::from(n)
+        }
+        DataType::List(_) => {
+            let values = downcast!(array, ListArray<i32>).value(i);
+            return push_list(packer, values.as_ref());
+        }
+        DataType::LargeList(_) => {
+            let values = downcast!(array, ListArray<i64>).value(i);
+            return push_list(packer, values.as_ref());
+        }
+        DataType::Struct(fields) => {
+            let values = downcast!(array, StructArray).values();
+            return packer.push_list_with(|packer| {
+                for (field, values) in fields.iter().zip(values) {
+                    push_column(packer, field, values.as_ref(), i)?;
+                }
+                Ok(())
+            });
+        }
+        other => return Err(format!("parquet type {:?} is not supported", other)),
+    };
+    packer.push(datum);
+    Ok(())
+}
+
+fn push_list(packer: &mut RowPacker, values: &dyn Array) -> Result<(), String> {
+    packer.push_list_with(|packer| {
+        for j in 0..values.len() {
+            push_value(packer, values, j)?;
+        }
+        Ok(())
+    })
+}
+
+/// Splits a count of `unit`s into whole seconds and nanoseconds.
+fn split_unit(t: i64, unit: TimeUnit) -> (i64, u32) {
+    let per_second = match unit {
+        TimeUnit::Second => 1,
+        TimeUnit::Millisecond => 1_000,
+        TimeUnit::Microsecond => 1_000_000,
+        TimeUnit::Nanosecond => 1_000_000_000,
+    };
+    let nanos_per_unit = 1_000_000_000 / per_second;
+    let secs = t.div_euclid(per_second);
+    let nanos = u32::try_from(t.rem_euclid(per_second) * nanos_per_unit)
+        .expect("nanoseconds within one second fit in u32");
+    (secs, nanos)
+}
+
+fn time_from_unit(t: i64, unit: TimeUnit) -> Result<NaiveTime, String> {
+    let (secs, nanos) = split_unit(t, unit);
+    u32::try_from(secs)
+        .ok()
+        .and_then(|secs| NaiveTime::from_num_seconds_from_midnight_opt(secs, nanos))
+        .ok_or_else(|| format!("time out of range: {}", t))
+}
+
+fn timestamp_from_unit(t: i64, unit: TimeUnit) -> Result<NaiveDateTime, String> {
+    let (secs, nanos) = split_unit(t, unit);
+    NaiveDateTime::from_timestamp_opt(secs, nanos)
+        .ok_or_else(|| format!("timestamp out of range: {}", t))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema_to_relationdesc() {
+        let fields = parse_schema(
+            "message m {
+                required int64 id;
+                optional binary name (STRING);
+                optional int32 day (DATE);
+                optional int64 at (TIMESTAMP(MICROS, true));
+                optional int64 local (TIMESTAMP(MILLIS, false));
+                optional fixed_len_byte_array(16) amount (DECIMAL(38, 4));
+                optional group tags (LIST) {
+                    repeated group list {
+                        optional binary element (STRING);
+                    }
+                }
+            }",
+        )
+        .unwrap();
+        let desc = schema_to_relationdesc(&fields).unwrap();
+        let columns: Vec<_> = desc
+            .iter()
+            .map(|(name, ty)| (name.as_str().to_string(), ty.clone()))
+            .collect();
+        assert_eq!(
+            columns,
+            vec![
+                ("id".into(), ScalarType::Int64.nullable(false)),
+                ("name".into(), ScalarType::String.nullable(true)),
+                ("day".into(), ScalarType::Date.nullable(true)),
+                ("at".into(), ScalarType::TimestampTz.nullable(true)),
+                ("local".into(), ScalarType::Timestamp.nullable(true)),
+                (
+                    "amount".into(),
+                    ScalarType::Numeric {
+                        max_scale: Some(NumericMaxScale::try_from(4usize).unwrap())
+                    }
+                    .nullable(true)
+                ),
+                (
+                    "tags".into(),
+                    ScalarType::List {
+                        element_type: Box::new(ScalarType::String),
+                        custom_id: None,
+                    }
+                    .nullable(true)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_schema() {
+        assert!(parse_schema("not a message").is_err());
+        assert!(ParquetDecoder::new("message m { required int96 x (INTERVAL); }").is_err());
+    }
+
+    #[test]
+    fn test_read_row_groups() {
+        use arrow2::chunk::Chunk;
+        use arrow2::io::parquet::write::{
+            CompressionOptions, Encoding, FileWriter, RowGroupIterator, Version, WriteOptions,
+        };
+
+        let schema = Schema::from(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, true),
+            Field::new("extra", DataType::Boolean, true),
+        ]);
+        let chunk = |ids: Vec<i64>, names: Vec<Option<&str>>| {
+            let len = ids.len();
+            Ok::<_, arrow2::error::Error>(Chunk::new(vec![
+                PrimitiveArray::from_vec(ids).boxed(),
+                Utf8Array::<i32>::from(names).boxed(),
+                BooleanArray::from(vec![Some(true); len]).boxed(),
+            ]))
+        };
+        let chunks = vec![
+            chunk(vec![1, 2], vec![Some("a"), None]),
+            chunk(vec![3], vec![Some("c")]),
+        ];
+        let options = WriteOptions {
+            write_statistics: false,
+            compression: CompressionOptions::Uncompressed,
+            version: Version::V2,
+        };
+        let row_groups = RowGroupIterator::try_new(
+            chunks.into_iter(),
+            &schema,
+            options,
+            vec![vec![Encoding::Plain]; schema.fields.len()],
+        )
+        .unwrap();
+        let mut file = vec![];
+        let mut writer = FileWriter::try_new(&mut file, schema, options).unwrap();
+        for group in row_groups {
+            writer.write(group.unwrap()).unwrap();
+        }
+        writer.end(None).unwrap();
+
+        // The `extra` column is not part of the decoder's schema, and the
+        // decoder's columns are in a different order than the file's.
+        let decoder = ParquetDecoder::new(
+            "message m {
+                optional binary name (STRING);
+                required int64 id;
+            }",
+        )
+        .unwrap();
+        let groups = decoder
+            .read(std::io::Cursor::new(file))
+            .unwrap()
+            .map(|group| {
+                group
+                    .unwrap()
+                    .into_iter()
+                    .map(|row| row.unwrap())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            groups,
+            vec![
+                vec![
+                    Row::pack_slice(&[Datum::String("a"), Datum::Int64(1)]),
+                    Row::pack_slice(&[Datum::Null, Datum::Int64(2)]),
+                ],
+                vec![Row::pack_slice(&[Datum::String("c"), Datum::Int64(3)])],
+            ]
+        );
+
+        // A file that lacks one of the schema's columns is rejected up front.
+        let decoder = ParquetDecoder::new("message m { required int64 missing; }").unwrap();
+        let mut file = vec![];
+        let schema = Schema::from(vec![Field::new("id", DataType::Int64, false)]);
+        let mut writer = FileWriter::try_new(&mut file, schema, options).unwrap();
+        writer.end(None).unwrap();
+        let err = decoder.read(std::io::Cursor::new(file)).unwrap_err();
+        assert_eq!(err.to_string(), "parquet file is missing column missing");
+    }
+
+    #[test]
+    fn test_split_unit() {
+        assert_eq!(split_unit(1_500, TimeUnit::Millisecond), (1, 500_000_000));
+        assert_eq!(split_unit(-1, TimeUnit::Microsecond), (-1, 999_999_000));
+        assert_eq!(split_unit(7, TimeUnit::Second), (7, 0));
+    }
+}
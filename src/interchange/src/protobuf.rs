@@ -11,13 +11,16 @@ use std::collections::HashSet;
 
 use anyhow::{anyhow, bail, Context};
 
+use prost::Message;
 use prost_reflect::{
     Cardinality, DescriptorPool, DynamicMessage, FieldDescriptor, Kind, MessageDescriptor,
     ReflectMessage, Value,
 };
 
 use mz_ore::str::StrExt;
-use mz_repr::{ColumnName, ColumnType, Datum, Row, RowPacker, ScalarType};
+use mz_repr::{ColumnName, ColumnType, Datum, RelationDesc, Row, RowPacker, ScalarType};
+
+use crate::encode::{column_names_and_types, Encode};
 
 /// A decoded description of the schema of a Protobuf message.
 #[derive(Debug, PartialEq)]
@@ -31,13 +34,7 @@ impl DecodedDescriptors {
     /// Builds a `DecodedDescriptors` from an encoded `FileDescriptorSet` and
     /// the fully qualified name of a message inside that file descriptor set.
     pub fn from_bytes(bytes: &[u8], message_name: String) -> Result<Self, anyhow::Error> {
-        let fds = DescriptorPool::decode(bytes).context("decoding file descriptor set")?;
-        let message_descriptor = fds.get_message_by_name(&message_name).ok_or_else(|| {
-            anyhow!(
-                "protobuf message {} not found in file descriptor set",
-                message_name.quoted(),
-            )
-        })?;
+        let message_descriptor = decode_message_descriptor(bytes, &message_name)?;
         let mut seen_messages = HashSet::new();
         seen_messages.insert(message_descriptor.name().to_owned());
         let mut columns = vec![];
@@ -63,6 +60,21 @@ impl DecodedDescriptors {
     }
 }
 
+/// Looks up the message named `message_name` in an encoded
+/// `FileDescriptorSet`.
+fn decode_message_descriptor(
+    bytes: &[u8],
+    message_name: &str,
+) -> Result<MessageDescriptor, anyhow::Error> {
+    let fds = DescriptorPool::decode(bytes).context("decoding file descriptor set")?;
+    fds.get_message_by_name(message_name).ok_or_else(|| {
+        anyhow!(
+            "protobuf message {} not found in file descriptor set",
+            message_name.quoted(),
+        )
+    })
+}
+
 /// Decodes a particular Protobuf message from its wire format.
 #[derive(Debug)]
 pub struct Decoder {
@@ -240,3 +252,288 @@ fn pack_value(
     }
     Ok(())
 }
+
+/// Encodes rows of a particular shape as a particular Protobuf message.
+///
+/// Columns are mapped onto the fields of the message by name. Fields that do
+/// not correspond to any column, as well as fields whose column is `NULL`, are
+/// left unset.
+#[derive(Debug)]
+pub struct MessageEncoder {
+    message_descriptor: MessageDescriptor,
+    columns: Vec<(ColumnName, ColumnType)>,
+    schema_id: Option<i32>,
+}
+
+impl MessageEncoder {
+    /// Constructs an encoder that encodes rows described by `desc` as the
+    /// message named `message_name` in the encoded `FileDescriptorSet`
+    /// `bytes`.
+    ///
+    /// If `schema_id` is present, encoded messages are prefixed with the
+    /// Confluent wire format header that refers to that schema.
+    ///
+    /// Returns an error if any column cannot be represented by the message.
+    pub fn new(
+        bytes: &[u8],
+        message_name: &str,
+        desc: RelationDesc,
+        schema_id: Option<i32>,
+    ) -> Result<Self, anyhow::Error> {
+        let message_descriptor = decode_message_descriptor(bytes, message_name)?;
+        let columns = column_names_and_types(desc);
+        let fields: Vec<_> = columns
+            .iter()
+            .map(|(name, typ)| (name.as_str(), &typ.scalar_type))
+            .collect();
+        check_message(&message_descriptor, &fields)?;
+        Ok(MessageEncoder {
+            message_descriptor,
+            columns,
+            schema_id,
+        })
+    }
+
+    fn encode(&self, row: Row) -> Vec<u8> {
+        let mut buf = vec![];
+        if let Some(schema_id) = self.schema_id {
+            crate::confluent::encode_protobuf_header(&mut buf, schema_id);
+        }
+        let fields = self
+            .columns
+            .iter()
+            .map(|(name, typ)| (name.as_str(), &typ.scalar_type));
+        let message = encode_message(&self.message_descriptor, fields.zip(row.iter()));
+        message
+            .encode(&mut buf)
+            .expect("writing to vec cannot fail");
+        buf
+    }
+}
+
+/// Encodes the keys and values of a sink as Protobuf messages.
+#[derive(Debug)]
+pub struct Encoder {
+    key: Option<MessageEncoder>,
+    value: MessageEncoder,
+}
+
+impl Encoder {
+    /// Constructs an encoder from encoders for keys and values.
+    pub fn new(key: Option<MessageEncoder>, value: MessageEncoder) -> Self {
+        Encoder { key, value }
+    }
+}
+
+impl Encode for Encoder {
+    fn get_format_name(&self) -> &str {
+        "protobuf"
+    }
+
+    fn encode_key_unchecked(&self, row: Row) -> Vec<u8> {
+        self.key
+            .as_ref()
+            .expect("key encoder must exist")
+            .encode(row)
+    }
+
+    fn encode_value_unchecked(&self, row: Row) -> Vec<u8> {
+        self.value.encode(row)
+    }
+}
+
+/// Verifies that each of `fields` can be encoded as the field of the same name
+/// in `message`.
+fn check_message(
+    message: &MessageDescriptor,
+    fields: &[(&str, &ScalarType)],
+) -> Result<(), anyhow::Error> {
+    for (name, scalar_type) in fields {
+        let field = message.get_field_by_name(name).ok_or_else(|| {
+            anyhow!(
+                "column {} has no corresponding field in protobuf message {}",
+                name.quoted(),
+                message.full_name().quoted(),
+            )
+        })?;
+        check_field(&field, scalar_type).with_context(|| {
+            format!(
+                "column {} cannot be encoded as field {} of protobuf message {}",
+                name.quoted(),
+                field.name().quoted(),
+                message.full_name().quoted(),
+            )
+        })?;
+    }
+    for field in message.fields() {
+        if field.cardinality() == Cardinality::Required
+            && !fields.iter().any(|(name, _)| *name == field.name())
+        {
+            bail!(
+                "required field {} of protobuf message {} has no corresponding column",
+                field.name().quoted(),
+                message.full_name().quoted(),
+            );
+        }
+    }
+    Ok(())
+}
+
+fn check_field(field: &FieldDescriptor, scalar_type: &ScalarType) -> Result<(), anyhow::Error> {
+    if field.is_map() {
+        bail!("Protobuf map fields are not supported");
+    }
+    if field.is_list() {
+        match scalar_type {
+            ScalarType::List { element_type, .. } => check_kind(&field.kind(), element_type),
+            _ => bail!("repeated fields can only be encoded from list columns"),
+        }
+    } else {
+        check_kind(&field.kind(), scalar_type)
+    }
+}
+
+fn check_kind(kind: &Kind, scalar_type: &ScalarType) -> Result<(), anyhow::Error> {
+    let compatible = match (scalar_type, kind) {
+        (ScalarType::Bool, Kind::Bool) => true,
+        (
+            ScalarType::Int16 | ScalarType::Int32,
+            Kind::Int32
+            | Kind::Sint32
+            | Kind::Sfixed32
+            | Kind::Int64
+            | Kind::Sint64
+            | Kind::Sfixed64,
+        ) => true,
+        (ScalarType::Int64, Kind::Int64 | Kind::Sint64 | Kind::Sfixed64) => true,
+        (
+            ScalarType::UInt16 | ScalarType::UInt32,
+            Kind::Uint32 | Kind::Fixed32 | Kind::Uint64 | Kind::Fixed64,
+        ) => true,
+        (ScalarType::UInt64, Kind::Uint64 | Kind::Fixed64) => true,
+        (ScalarType::Float32, Kind::Float | Kind::Double) => true,
+        (ScalarType::Float64, Kind::Double) => true,
+        (ScalarType::String | ScalarType::VarChar { .. }, Kind::String) => true,
+        (ScalarType::Bytes, Kind::Bytes) => true,
+        (ScalarType::Record { fields, .. }, Kind::Message(message)) => {
+            let fields: Vec<_> = fields
+                .iter()
+                .map(|(name, typ)| (name.as_str(), &typ.scalar_type))
+                .collect();
+            check_message(message, &fields)?;
+            true
+        }
+        _ => false,
+    };
+    if !compatible {
+        bail!(
+            "values of type {:?} cannot be represented by protobuf type {}",
+            scalar_type,
+            kind_name(kind)
+        );
+    }
+    Ok(())
+}
+
+fn kind_name(kind: &Kind) -> String {
+    match kind {
+        Kind::Double => "double".into(),
+        Kind::Float => "float".into(),
+        Kind::Int32 => "int32".into(),
+        Kind::Int64 => "int64".into(),
+        Kind::Uint32 => "uint32".into(),
+        Kind::Uint64 => "uint64".into(),
+        Kind::Sint32 => "sint32".into(),
+        Kind::Sint64 => "sint64".into(),
+        Kind::Fixed32 => "fixed32".into(),
+        Kind::Fixed64 => "fixed64".into(),
+        Kind::Sfixed32 => "sfixed32".into(),
+        Kind::Sfixed64 => "sfixed64".into(),
+        Kind::Bool => "bool".into(),
+        Kind::String => "string".into(),
+        Kind::Bytes => "bytes".into(),
+        Kind::Message(m) => format!("message {}", m.full_name()),
+        Kind::Enum(e) => format!("enum {}", e.full_name()),
+    }
+}
+
+/// Encodes `fields` as `message`. The fields must have been validated by
+/// [`check_message`].
+fn encode_message<'a, I>(message: &MessageDescriptor, fields: I) -> DynamicMessage
+where
+    I: IntoIterator<Item = ((&'a str, &'a ScalarType), Datum<'a>)>,
+{
+    let mut out = DynamicMessage::new(message.clone());
+    for ((name, scalar_type), datum) in fields {
+        if datum.is_null() {
+            continue;
+        }
+        let field = message
+            .get_field_by_name(name)
+            .expect("field validated to exist");
+        let value = if field.is_list() {
+            let element_type = match scalar_type {
+                ScalarType::List { element_type, .. } => element_type,
+                _ => unreachable!("repeated fields validated to be lists"),
+            };
+            // Protobuf lists cannot contain nulls, so null elements are
+            // omitted.
+            let elements = datum
+                .unwrap_list()
+                .iter()
+                .filter(|datum| !datum.is_null())
+                .map(|datum| encode_value(&field.kind(), element_type, datum))
+                .collect();
+            Value::List(elements)
+        } else {
+            encode_value(&field.kind(), scalar_type, datum)
+        };
+        out.set_field(&field, value);
+    }
+    out
+}
+
+fn encode_value(kind: &Kind, scalar_type: &ScalarType, datum: Datum) -> Value {
+    match kind {
+        Kind::Bool => Value::Bool(datum.unwrap_bool()),
+        Kind::Int32 | Kind::Sint32 | Kind::Sfixed32 => Value::I32(match scalar_type {
+            ScalarType::Int16 => i32::from(datum.unwrap_int16()),
+            _ => datum.unwrap_int32(),
+        }),
+        Kind::Int64 | Kind::Sint64 | Kind::Sfixed64 => Value::I64(match scalar_type {
+            ScalarType::Int16 => i64::from(datum.unwrap_int16()),
+            ScalarType::Int32 => i64::from(datum.unwrap_int32()),
+            _ => datum.unwrap_int64(),
+        }),
+        Kind::Uint32 | Kind::Fixed32 => Value::U32(match scalar_type {
+            ScalarType::UInt16 => u32::from(datum.unwrap_uint16()),
+            _ => datum.unwrap_uint32(),
+        }),
+        Kind::Uint64 | Kind::Fixed64 => Value::U64(match scalar_type {
+            ScalarType::UInt16 => u64::from(datum.unwrap_uint16()),
+            ScalarType::UInt32 => u64::from(datum.unwrap_uint32()),
+            _ => datum.unwrap_uint64(),
+        }),
+        Kind::Float => Value::F32(datum.unwrap_float32()),
+        Kind::Double => Value::F64(match scalar_type {
+            ScalarType::Float32 => f64::from(datum.unwrap_float32()),
+            _ => datum.unwrap_float64(),
+        }),
+        Kind::String => Value::String(datum.unwrap_str().to_owned()),
+        Kind::Bytes => Value::Bytes(datum.unwrap_bytes().to_vec().into()),
+        Kind::Message(message) => {
+            let fields = match scalar_type {
+                ScalarType::Record { fields, .. } => fields,
+                _ => unreachable!("message fields validated to be records"),
+            };
+            let fields = fields
+                .iter()
+                .map(|(name, typ)| (name.as_str(), &typ.scalar_type));
+            Value::Message(encode_message(
+                message,
+                fields.zip(datum.unwrap_list().iter()),
+            ))
+        }
+        Kind::Enum(_) => unreachable!("enum fields are never validated"),
+    }
+}
@@ -0,0 +1,212 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Descriptions of MySQL objects.
+
+use proptest::prelude::{any, Arbitrary};
+use proptest::strategy::{BoxedStrategy, Strategy};
+use serde::{Deserialize, Serialize};
+
+use mz_proto::{RustType, TryFromProtoError};
+use mz_repr::adt::numeric::{NumericMaxScale, NUMERIC_DATUM_MAX_PRECISION};
+use mz_repr::ScalarType;
+
+include!(concat!(env!("OUT_DIR"), "/mz_mysql_util.desc.rs"));
+
+/// Describes a table in a MySQL database.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MySqlTableDesc {
+    /// The name of the schema (i.e., the MySQL database) that the table
+    /// belongs to.
+    pub schema_name: String,
+    /// The name of the table.
+    pub name: String,
+    /// The description of each column, in order.
+    pub columns: Vec<MySqlColumnDesc>,
+}
+
+impl RustType<ProtoMySqlTableDesc> for MySqlTableDesc {
+    fn into_proto(&self) -> ProtoMySqlTableDesc {
+        ProtoMySqlTableDesc {
+            schema_name: self.schema_name.clone(),
+            name: self.name.clone(),
+            columns: self.columns.iter().map(|c| c.into_proto()).collect(),
+        }
+    }
+
+    fn from_proto(proto: ProtoMySqlTableDesc) -> Result<Self, TryFromProtoError> {
+        Ok(MySqlTableDesc {
+            schema_name: proto.schema_name,
+            name: proto.name,
+            columns: proto
+                .columns
+                .into_iter()
+                .map(MySqlColumnDesc::from_proto)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl Arbitrary for MySqlTableDesc {
+    type Strategy = BoxedStrategy<Self>;
+    type Parameters = ();
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<String>(),
+            any::<String>(),
+            any::<Vec<MySqlColumnDesc>>(),
+        )
+            .prop_map(|(schema_name, name, columns)| MySqlTableDesc {
+                schema_name,
+                name,
+                columns,
+            })
+            .boxed()
+    }
+}
+
+/// Describes a column in a [`MySqlTableDesc`].
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MySqlColumnDesc {
+    /// The name of the column.
+    pub name: String,
+    /// The name of the column's type, e.g. `int` or `varchar`, as reported by
+    /// `information_schema.columns.data_type`.
+    pub data_type: String,
+    /// The full type of the column, e.g. `int unsigned` or `varchar(255)`, as
+    /// reported by `information_schema.columns.column_type`.
+    pub column_type: String,
+    /// The precision of the column, if it has a numeric type.
+    pub numeric_precision: Option<u32>,
+    /// The scale of the column, if it has a numeric type.
+    pub numeric_scale: Option<u32>,
+    /// True if the column lacks a `NOT NULL` constraint.
+    pub nullable: bool,
+    /// Whether the column is part of the table's primary key.
+    pub primary_key: bool,
+}
+
+impl MySqlColumnDesc {
+    /// Reports whether the column has an `UNSIGNED` integer type.
+    pub fn is_unsigned(&self) -> bool {
+        self.column_type.split_whitespace().any(|w| w == "unsigned")
+    }
+
+    /// Returns the width in bits of the column's integer type, if it has one.
+    pub fn integer_bits(&self) -> Option<u32> {
+        match self.data_type.as_str() {
+            "tinyint" => Some(8),
+            "smallint" => Some(16),
+            "mediumint" => Some(24),
+            "int" => Some(32),
+            "bigint" => Some(64),
+            _ => None,
+        }
+    }
+
+    /// Returns the type that Materialize ingests values of this column as, or
+    /// `None` if the column's type is not supported.
+    pub fn scalar_type(&self) -> Option<ScalarType> {
+        let unsigned = self.is_unsigned();
+        Some(match self.data_type.as_str() {
+            "tinyint" | "year" => ScalarType::Int16,
+            "smallint" if unsigned => ScalarType::UInt16,
+            "smallint" => ScalarType::Int16,
+            "mediumint" | "int" if unsigned => ScalarType::UInt32,
+            "mediumint" | "int" => ScalarType::Int32,
+            "bigint" if unsigned => ScalarType::UInt64,
+            "bigint" => ScalarType::Int64,
+            "float" => ScalarType::Float32,
+            "double" => ScalarType::Float64,
+            "decimal" => {
+                if self.numeric_precision? > u32::from(NUMERIC_DATUM_MAX_PRECISION) {
+                    return None;
+                }
+                let max_scale = NumericMaxScale::try_from(i64::from(self.numeric_scale?)).ok()?;
+                ScalarType::Numeric {
+                    max_scale: Some(max_scale),
+                }
+            }
+            "char" | "varchar" | "tinytext" | "text" | "mediumtext" | "longtext" => {
+                ScalarType::String
+            }
+            "binary" | "varbinary" | "tinyblob" | "blob" | "mediumblob" | "longblob" => {
+                ScalarType::Bytes
+            }
+            "date" => ScalarType::Date,
+            "datetime" => ScalarType::Timestamp,
+            "timestamp" => ScalarType::TimestampTz,
+            _ => return None,
+        })
+    }
+}
+
+impl RustType<ProtoMySqlColumnDesc> for MySqlColumnDesc {
+    fn into_proto(&self) -> ProtoMySqlColumnDesc {
+        ProtoMySqlColumnDesc {
+            name: self.name.clone(),
+            data_type: self.data_type.clone(),
+            column_type: self.column_type.clone(),
+            numeric_precision: self.numeric_precision,
+            numeric_scale: self.numeric_scale,
+            nullable: self.nullable,
+            primary_key: self.primary_key,
+        }
+    }
+
+    fn from_proto(proto: ProtoMySqlColumnDesc) -> Result<Self, TryFromProtoError> {
+        Ok(MySqlColumnDesc {
+            name: proto.name,
+            data_type: proto.data_type,
+            column_type: proto.column_type,
+            numeric_precision: proto.numeric_precision,
+            numeric_scale: proto.numeric_scale,
+            nullable: proto.nullable,
+            primary_key: proto.primary_key,
+        })
+    }
+}
+
+impl Arbitrary for MySqlColumnDesc {
+    type Strategy = BoxedStrategy<Self>;
+    type Parameters = ();
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<String>(),
+            any::<String>(),
+            any::<String>(),
+            any::<Option<u32>>(),
+            any::<Option<u32>>(),
+            any::<bool>(),
+            any::<bool>(),
+        )
+            .prop_map(
+                |(
+                    name,
+                    data_type,
+                    column_type,
+                    numeric_precision,
+                    numeric_scale,
+                    nullable,
+                    primary_key,
+                )| MySqlColumnDesc {
+                    name,
+                    data_type,
+                    column_type,
+                    numeric_precision,
+                    numeric_scale,
+                    nullable,
+                    primary_key,
+                },
+            )
+            .boxed()
+    }
+}
@@ -0,0 +1,398 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! MySQL utility library.
+
+use std::collections::BTreeMap;
+use std::ops::{Deref, DerefMut};
+
+use mysql_async::prelude::Queryable;
+use mysql_async::{BinlogRequest, BinlogStream, Conn, OptsBuilder, Row, SslOpts};
+use tokio::sync::oneshot;
+
+use mz_ore::task;
+use mz_repr::GlobalId;
+use mz_ssh_util::tunnel::SshTunnelConfig;
+
+use crate::desc::{MySqlColumnDesc, MySqlTableDesc};
+
+pub mod desc;
+
+/// The schemas that MySQL uses for its own bookkeeping. Tables in these
+/// schemas are never replicated.
+pub const SYSTEM_SCHEMAS: &[&str] = &["information_schema", "mysql", "performance_schema", "sys"];
+
+/// An error representing mysql, ssh, and other failures.
+#[derive(Debug, thiserror::Error)]
+pub enum MySqlError {
+    /// Any other error we bail on.
+    #[error(transparent)]
+    Generic(#[from] anyhow::Error),
+    /// Error using ssh.
+    #[error(transparent)]
+    Ssh(#[from] openssh::Error),
+    /// Error doing io to setup an ssh connection.
+    #[error(transparent)]
+    SshIo(#[from] std::io::Error),
+    /// A mysql error.
+    #[error(transparent)]
+    MySql(#[from] mysql_async::Error),
+}
+
+macro_rules! bail_generic {
+    ($fmt:expr, $($arg:tt)*) => {
+        return Err(MySqlError::Generic(anyhow::anyhow!($fmt, $($arg)*)))
+    };
+    ($err:expr $(,)?) => {
+        return Err(MySqlError::Generic(anyhow::anyhow!($err)))
+    };
+}
+
+/// Quotes `ident` for use as an identifier in a MySQL query.
+pub fn quote_identifier(ident: &str) -> String {
+    format!("`{}`", ident.replace('`', "``"))
+}
+
+/// Fetches schema information for every table in the upstream MySQL server
+/// that is not in one of the [`SYSTEM_SCHEMAS`].
+///
+/// Tables are returned ordered by schema name and then by table name.
+///
+/// # Errors
+///
+/// - Insufficient user permissions.
+pub async fn schema_info(conn: &mut Connection) -> Result<Vec<MySqlTableDesc>, MySqlError> {
+    let query = format!(
+        "SELECT
+            c.table_schema, c.table_name, c.column_name, c.data_type, c.column_type,
+            c.numeric_precision, c.numeric_scale, c.is_nullable, c.column_key
+        FROM information_schema.columns AS c
+            JOIN information_schema.tables AS t
+                ON c.table_schema = t.table_schema AND c.table_name = t.table_name
+        WHERE t.table_type = 'BASE TABLE' AND c.table_schema NOT IN ({})
+        ORDER BY c.table_schema, c.table_name, c.ordinal_position",
+        SYSTEM_SCHEMAS
+            .iter()
+            .map(|s| format!("'{s}'"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    let rows: Vec<Row> = conn.query(query).await?;
+
+    let mut tables: BTreeMap<(String, String), Vec<MySqlColumnDesc>> = BTreeMap::new();
+    for row in rows {
+        let (
+            schema_name,
+            table_name,
+            name,
+            data_type,
+            column_type,
+            numeric_precision,
+            numeric_scale,
+            is_nullable,
+            column_key,
+        ): (
+            String,
+            String,
+            String,
+            String,
+            String,
+            Option<u64>,
+            Option<u64>,
+            String,
+            String,
+        ) = mysql_async::from_row_opt(row)
+            .map_err(|e| anyhow::anyhow!("unexpected column description: {e}"))?;
+
+        let to_u32 = |v: Option<u64>| -> Result<Option<u32>, MySqlError> {
+            v.map(|v| u32::try_from(v).map_err(|e| MySqlError::Generic(e.into())))
+                .transpose()
+        };
+
+        tables
+            .entry((schema_name, table_name))
+            .or_default()
+            .push(MySqlColumnDesc {
+                name,
+                data_type: data_type.to_lowercase(),
+                column_type: column_type.to_lowercase(),
+                numeric_precision: to_u32(numeric_precision)?,
+                numeric_scale: to_u32(numeric_scale)?,
+                nullable: is_nullable == "YES",
+                primary_key: column_key == "PRI",
+            });
+    }
+
+    Ok(tables
+        .into_iter()
+        .map(|((schema_name, name), columns)| MySqlTableDesc {
+            schema_name,
+            name,
+            columns,
+        })
+        .collect())
+}
+
+/// Verifies that the upstream MySQL server writes a binary log that contains
+/// the full contents of every changed row.
+///
+/// # Errors
+///
+/// - Binary logging is disabled.
+/// - `binlog_format` is not `ROW`.
+/// - `binlog_row_image` is not `FULL`.
+pub async fn validate_binlog_config(conn: &mut Connection) -> Result<(), MySqlError> {
+    let row: Option<(i64, String, String)> = conn
+        .query_first("SELECT @@log_bin, @@binlog_format, @@binlog_row_image")
+        .await?;
+    let Some((log_bin, binlog_format, binlog_row_image)) = row else {
+        bail_generic!("unable to read binary log configuration");
+    };
+    if log_bin != 1 {
+        bail_generic!("binary logging is disabled (log_bin = OFF)");
+    }
+    if !binlog_format.eq_ignore_ascii_case("ROW") {
+        bail_generic!(
+            "binlog_format must be ROW, but is {}",
+            binlog_format.to_uppercase()
+        );
+    }
+    if !binlog_row_image.eq_ignore_ascii_case("FULL") {
+        bail_generic!(
+            "binlog_row_image must be FULL, but is {}",
+            binlog_row_image.to_uppercase()
+        );
+    }
+    Ok(())
+}
+
+/// A position in the binary log of a MySQL server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BinlogPosition {
+    /// The name of the binary log file, e.g. `binlog.000003`.
+    pub file: String,
+    /// The byte offset into `file`.
+    pub position: u64,
+}
+
+impl BinlogPosition {
+    /// Reads the position that the server will write its next event at.
+    pub async fn current(conn: &mut Connection) -> Result<Self, MySqlError> {
+        let row: Option<Row> = conn.query_first("SHOW MASTER STATUS").await?;
+        let Some(row) = row else {
+            bail_generic!("binary logging is disabled");
+        };
+        match (row.get_opt(0), row.get_opt(1)) {
+            (Some(Ok(file)), Some(Ok(position))) => Ok(BinlogPosition { file, position }),
+            _ => bail_generic!("unexpected SHOW MASTER STATUS output"),
+        }
+    }
+
+    /// Returns the name of the binary log file without its sequence number
+    /// suffix, e.g. `binlog` for `binlog.000003`.
+    pub fn basename(&self) -> &str {
+        match self.file.rsplit_once('.') {
+            Some((basename, _)) => basename,
+            None => &self.file,
+        }
+    }
+
+    /// Encodes the position as a single, totally ordered `u64`.
+    ///
+    /// The sequence number of the binary log file occupies the high 32 bits
+    /// and the position within the file occupies the low 32 bits. MySQL caps
+    /// binary log files at 1GiB, so positions always fit.
+    pub fn to_offset(&self) -> Result<u64, MySqlError> {
+        let seq: u64 = match self.file.rsplit_once('.').map(|(_, seq)| seq.parse()) {
+            Some(Ok(seq)) => seq,
+            _ => bail_generic!("unexpected binary log file name {}", self.file),
+        };
+        if seq > u64::from(u32::MAX) || self.position > u64::from(u32::MAX) {
+            bail_generic!(
+                "binary log position {}:{} out of range",
+                self.file,
+                self.position
+            );
+        }
+        Ok((seq << 32) | self.position)
+    }
+
+    /// Decodes a position produced by [`BinlogPosition::to_offset`], given the
+    /// basename of the server's binary log files.
+    pub fn from_offset(basename: &str, offset: u64) -> Self {
+        BinlogPosition {
+            file: format!("{}.{:06}", basename, offset >> 32),
+            position: offset & u64::from(u32::MAX),
+        }
+    }
+}
+
+/// The TLS requirements of a MySQL connection.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SslMode {
+    /// Do not use TLS.
+    Disabled,
+    /// Require TLS, but do not verify the server's certificate.
+    Required,
+    /// Require TLS and verify that the server's certificate is signed by a
+    /// trusted certificate authority.
+    VerifyCa,
+    /// Like [`SslMode::VerifyCa`], but additionally verify that the server's
+    /// certificate matches its hostname.
+    VerifyIdentity,
+}
+
+/// Configures an optional tunnel for use when connecting to a MySQL
+/// server.
+#[derive(Debug, PartialEq, Clone)]
+pub enum TunnelConfig {
+    /// Establish a direct TCP connection to the server.
+    Direct,
+    /// Establish a TCP connection to the server via an SSH tunnel.
+    Ssh(SshTunnelConfig),
+    /// Establish a TCP connection to the server via an AWS PrivateLink
+    /// service.
+    AwsPrivatelink {
+        /// The ID of the AWS PrivateLink service.
+        connection_id: GlobalId,
+    },
+}
+
+/// Configuration for MySQL connections.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Config {
+    host: String,
+    port: u16,
+    user: String,
+    password: Option<String>,
+    ssl_mode: SslMode,
+    tunnel: TunnelConfig,
+}
+
+impl Config {
+    pub fn new(
+        host: String,
+        port: u16,
+        user: String,
+        password: Option<String>,
+        ssl_mode: SslMode,
+        tunnel: TunnelConfig,
+    ) -> Result<Self, MySqlError> {
+        // When tunneling, the TCP connection terminates somewhere other than
+        // `host`, so hostname verification can never succeed.
+        if ssl_mode == SslMode::VerifyIdentity && tunnel != TunnelConfig::Direct {
+            bail_generic!("SSL MODE verify_identity is not supported with tunneled connections");
+        }
+        Ok(Config {
+            host,
+            port,
+            user,
+            password,
+            ssl_mode,
+            tunnel,
+        })
+    }
+
+    /// Connects to the configured MySQL server.
+    pub async fn connect(&self, task_name: &str) -> Result<Connection, MySqlError> {
+        let (host, port, tunnel) = match &self.tunnel {
+            TunnelConfig::Direct => (self.host.clone(), self.port, TunnelGuard(None)),
+            TunnelConfig::Ssh(tunnel) => {
+                let (session, local_port) = tunnel.connect(&self.host, self.port).await?;
+                // Shut down the SSH session once the connection that uses it
+                // is dropped.
+                let (tx, rx) = oneshot::channel();
+                task::spawn(|| format!("{task_name}_ssh_session"), async move {
+                    let _: Result<(), _> = rx.await;
+                    session
+                        .close()
+                        .await
+                        .err()
+                        .map(|e| tracing::error!("failed to close ssh tunnel: {e}"));
+                });
+                ("localhost".into(), local_port, TunnelGuard(Some(tx)))
+            }
+            TunnelConfig::AwsPrivatelink { connection_id } => (
+                mz_cloud_resources::vpc_endpoint_name(*connection_id),
+                self.port,
+                TunnelGuard(None),
+            ),
+        };
+
+        let ssl_opts = match self.ssl_mode {
+            SslMode::Disabled => None,
+            SslMode::Required => Some(
+                SslOpts::default()
+                    .with_danger_accept_invalid_certs(true)
+                    .with_danger_skip_domain_validation(true),
+            ),
+            SslMode::VerifyCa => Some(SslOpts::default().with_danger_skip_domain_validation(true)),
+            SslMode::VerifyIdentity => Some(SslOpts::default()),
+        };
+
+        let opts = OptsBuilder::default()
+            .ip_or_hostname(host)
+            .tcp_port(port)
+            .user(Some(&self.user))
+            .pass(self.password.as_ref())
+            .ssl_opts(ssl_opts);
+        let conn = Conn::new(opts).await?;
+        Ok(Connection { conn, tunnel })
+    }
+}
+
+/// Keeps the tunnel that a MySQL connection runs over open until dropped.
+#[derive(Debug)]
+pub struct TunnelGuard(Option<oneshot::Sender<()>>);
+
+/// A connection to a MySQL server, along with the tunnel it runs over.
+#[derive(Debug)]
+pub struct Connection {
+    conn: Conn,
+    tunnel: TunnelGuard,
+}
+
+impl Connection {
+    /// Converts the connection into a stream of binary log events starting at
+    /// `position`.
+    ///
+    /// The returned [`TunnelGuard`] must be kept alive for as long as the
+    /// stream is in use.
+    pub async fn into_binlog_stream(
+        self,
+        server_id: u32,
+        position: &BinlogPosition,
+    ) -> Result<(BinlogStream, TunnelGuard), MySqlError> {
+        let request = BinlogRequest::new(server_id)
+            .with_filename(position.file.as_bytes())
+            .with_pos(position.position);
+        let stream = self.conn.get_binlog_stream(request).await?;
+        Ok((stream, self.tunnel))
+    }
+
+    /// Gracefully closes the connection.
+    pub async fn disconnect(self) -> Result<(), MySqlError> {
+        self.conn.disconnect().await?;
+        Ok(())
+    }
+}
+
+impl Deref for Connection {
+    type Target = Conn;
+
+    fn deref(&self) -> &Conn {
+        &self.conn
+    }
+}
+
+impl DerefMut for Connection {
+    fn deref_mut(&mut self) -> &mut Conn {
+        &mut self.conn
+    }
+}
@@ -19,9 +19,10 @@ use clap::ArgEnum;
 use futures::stream::{BoxStream, StreamExt};
 use k8s_openapi::api::apps::v1::{StatefulSet, StatefulSetSpec};
 use k8s_openapi::api::core::v1::{
-    Affinity, Container, ContainerPort, EnvVar, EnvVarSource, ObjectFieldSelector, Pod,
-    PodAffinityTerm, PodAntiAffinity, PodSpec, PodTemplateSpec, ResourceRequirements, Secret,
-    Service as K8sService, ServicePort, ServiceSpec,
+    Affinity, Container, ContainerPort, EmptyDirVolumeSource, EnvVar, EnvVarSource,
+    ObjectFieldSelector, Pod, PodAffinityTerm, PodAntiAffinity, PodSpec, PodTemplateSpec,
+    ResourceRequirements, Secret, Service as K8sService, ServicePort, ServiceSpec, Volume,
+    VolumeMount,
 };
 use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
 use k8s_openapi::apimachinery::pkg::apis::meta::v1::{LabelSelector, LabelSelectorRequirement};
@@ -48,6 +49,8 @@ pub mod secrets;
 pub mod util;
 
 const FIELD_MANAGER: &str = "environmentd";
+/// Where the scratch directory of services that request one is mounted.
+const SCRATCH_DIRECTORY_PATH: &str = "/scratch";
 
 /// Configures a [`KubernetesOrchestrator`].
 #[derive(Debug, Clone)]
@@ -378,7 +381,15 @@ impl NamespacedOrchestrator for NamespacedKubernetesOrchestrator {
                     return ServiceProcessMetrics::default();
                 }
             };
-            let Some(PodMetricsContainer { usage: PodMetricsContainerUsage { cpu: Quantity(cpu_str), memory: Quantity(mem_str) }, .. }) = metrics.containers.get(0) else {
+            let Some(PodMetricsContainer {
+                usage:
+                    PodMetricsContainerUsage {
+                        cpu: Quantity(cpu_str),
+                        memory: Quantity(mem_str),
+                    },
+                ..
+            }) = metrics.containers.get(0)
+            else {
                 warn!("metrics result contained no containers for {name}");
                 return ServiceProcessMetrics::default();
             };
@@ -434,6 +445,7 @@ impl NamespacedOrchestrator for NamespacedKubernetesOrchestrator {
             labels: labels_in,
             availability_zone,
             anti_affinity,
+            scratch_directory,
         }: ServiceConfig<'_>,
     ) -> Result<Box<dyn Service>, anyhow::Error> {
         let name = format!("{}-{id}", self.namespace);
@@ -518,6 +530,25 @@ impl NamespacedOrchestrator for NamespacedKubernetesOrchestrator {
             self.config.context
         ));
 
+        // The scratch directory is an `emptyDir` volume, which lives on the
+        // node's local disk for as long as the pod does.
+        let (volumes, volume_mounts) = if scratch_directory {
+            args.push(format!("--scratch-directory={SCRATCH_DIRECTORY_PATH}"));
+            let volume = Volume {
+                name: "scratch".into(),
+                empty_dir: Some(EmptyDirVolumeSource::default()),
+                ..Default::default()
+            };
+            let volume_mount = VolumeMount {
+                name: "scratch".into(),
+                mount_path: SCRATCH_DIRECTORY_PATH.into(),
+                ..Default::default()
+            };
+            (Some(vec![volume]), Some(vec![volume_mount]))
+        } else {
+            (None, None)
+        };
+
         let anti_affinity = anti_affinity
             .map(|label_selectors| -> Result<_, anyhow::Error> {
                 let label_selector_requirements = label_selectors
@@ -640,8 +671,10 @@ impl NamespacedOrchestrator for NamespacedKubernetesOrchestrator {
                         limits: Some(limits),
                         ..Default::default()
                     }),
+                    volume_mounts,
                     ..Default::default()
                 }],
+                volumes,
                 node_selector: Some(node_selector),
                 service_account: self.config.service_account.clone(),
                 affinity: Some(Affinity {
@@ -12,6 +12,7 @@ use std::env;
 use std::fmt::Debug;
 use std::fs::Permissions;
 use std::future::Future;
+use std::io;
 use std::os::unix::fs::PermissionsExt;
 use std::os::unix::process::ExitStatusExt;
 use std::path::{Path, PathBuf};
@@ -73,7 +74,8 @@ pub struct ProcessOrchestratorConfig {
 ///
 /// Processes launched by this orchestrator must support a `--pid-file-location`
 /// command line flag which causes a PID file to be emitted at the specified
-/// path.
+/// path. Processes of services that request a scratch directory must also
+/// support a `--scratch-directory` flag.
 #[derive(Debug)]
 pub struct ProcessOrchestrator {
     image_dir: PathBuf,
@@ -211,6 +213,7 @@ impl NamespacedOrchestrator for NamespacedProcessOrchestrator {
             labels: _,
             availability_zone: _,
             anti_affinity: _,
+            scratch_directory,
         }: ServiceConfig<'_>,
     ) -> Result<Box<dyn Service>, anyhow::Error> {
         let full_id = format!("{}-{}", self.namespace, id);
@@ -237,6 +240,7 @@ impl NamespacedOrchestrator for NamespacedProcessOrchestrator {
                     image: image.clone(),
                     args,
                     ports: ports.clone(),
+                    scratch_directory,
                 }),
             );
             process_states.push(ProcessState {
@@ -300,6 +304,7 @@ impl NamespacedProcessOrchestrator {
             image,
             args,
             ports,
+            scratch_directory,
         }: ServiceProcessConfig,
     ) -> impl Future<Output = ()> {
         let suppress_output = self.suppress_output;
@@ -331,6 +336,10 @@ impl NamespacedProcessOrchestrator {
             "--secrets-reader-process-dir={}",
             self.secrets_dir.display()
         ));
+        let scratch_dir = scratch_directory.then(|| run_dir.join(format!("{i}.scratch")));
+        if let Some(scratch_dir) = &scratch_dir {
+            args.push(format!("--scratch-directory={}", scratch_dir.display()));
+        }
 
         async move {
             supervise_existing_process(&state_updater, &pid_file).await;
@@ -341,6 +350,24 @@ impl NamespacedProcessOrchestrator {
                         warn!("unable to remove {path} while launching {full_id}-{i}: {e}")
                     }
                 }
+                if let Some(scratch_dir) = &scratch_dir {
+                    // Start each incarnation of the process with an empty
+                    // scratch directory.
+                    if let Err(e) = fs::remove_dir_all(scratch_dir).await {
+                        if e.kind() != io::ErrorKind::NotFound {
+                            warn!(
+                                "unable to remove {} while launching {full_id}-{i}: {e}",
+                                scratch_dir.display()
+                            )
+                        }
+                    }
+                    if let Err(e) = fs::create_dir_all(scratch_dir).await {
+                        warn!(
+                            "unable to create {} while launching {full_id}-{i}: {e}",
+                            scratch_dir.display()
+                        )
+                    }
+                }
 
                 let mut cmd = if command_wrapper.is_empty() {
                     let mut cmd = Command::new(&image);
@@ -393,6 +420,7 @@ struct ServiceProcessConfig<'a> {
     image: String,
     args: &'a (dyn Fn(&HashMap<String, String>) -> Vec<String> + Send + Sync),
     ports: Vec<ServicePort>,
+    scratch_directory: bool,
 }
 
 /// Supervises an existing process, if it exists.
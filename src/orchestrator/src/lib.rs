@@ -196,6 +196,13 @@ pub struct ServiceConfig<'a> {
     ///
     /// The orchestrator backend may or may not actually implement anti-affinity functionality.
     pub anti_affinity: Option<Vec<LabelSelector>>,
+    /// Whether each process of the service needs a scratch directory on local
+    /// disk.
+    ///
+    /// If set, the orchestrator provisions a directory for each process and
+    /// passes its path to the process with a `--scratch-directory` flag. The
+    /// directory is not guaranteed to be empty when a process restarts.
+    pub scratch_directory: bool,
 }
 
 /// A named port associated with a service.
@@ -14,6 +14,12 @@ fn main() {
 
     prost_build::Config::new()
         .type_attribute(".", "#[derive(serde::Serialize)]")
-        .compile_protos(&["persist-client/src/internal/state.proto"], &[".."])
+        .compile_protos(
+            &[
+                "persist-client/src/cfg.proto",
+                "persist-client/src/internal/state.proto",
+            ],
+            &[".."],
+        )
         .unwrap();
 }
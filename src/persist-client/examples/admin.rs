@@ -11,7 +11,8 @@
 
 use mz_ore::metrics::MetricsRegistry;
 use mz_ore::now::SYSTEM_TIME;
-use mz_persist_client::{PersistConfig, ShardId};
+use mz_persist_client::read::LeasedReaderId;
+use mz_persist_client::{PersistConfig, PersistLocation, ShardId};
 
 use prometheus::proto::{MetricFamily, MetricType};
 use std::str::FromStr;
@@ -36,6 +37,14 @@ pub struct AdminArgs {
 pub(crate) enum Command {
     /// Manually completes all fueled compactions in a shard.
     ForceCompaction(ForceCompactionArgs),
+    /// Copies a shard to the same shard in another location.
+    Backup(BackupArgs),
+    /// Copies a shard from another location back to this one.
+    Restore(RestoreArgs),
+    /// Clones a shard, as of some time, to a new shard in the same location.
+    CloneShard(CloneShardArgs),
+    /// Releases the parts of a shard held by a clone of it.
+    ReleaseClone(ReleaseCloneArgs),
 }
 
 /// Manually completes all fueled compactions in a shard.
@@ -49,6 +58,79 @@ pub(crate) struct ForceCompactionArgs {
     compaction_memory_bound_bytes: usize,
 }
 
+/// Copies a shard to the same shard in another location.
+#[derive(Debug, clap::Parser)]
+pub(crate) struct BackupArgs {
+    #[clap(flatten)]
+    state: StateArgs,
+
+    /// Consensus to copy the shard to.
+    #[clap(long)]
+    to_consensus_uri: String,
+
+    /// Blob to copy the shard to.
+    #[clap(long)]
+    to_blob_uri: String,
+
+    /// The time the copy must be readable as of (defaults to copying all of it).
+    #[clap(long)]
+    as_of: Option<u64>,
+}
+
+/// Copies a shard from another location back to this one.
+#[derive(Debug, clap::Parser)]
+pub(crate) struct RestoreArgs {
+    #[clap(flatten)]
+    state: StateArgs,
+
+    /// Consensus of the backup to copy the shard from.
+    #[clap(long)]
+    from_consensus_uri: String,
+
+    /// Blob of the backup to copy the shard from.
+    #[clap(long)]
+    from_blob_uri: String,
+
+    /// Shard to restore into (defaults to the backed up shard).
+    #[clap(long)]
+    to_shard_id: Option<String>,
+
+    /// The time the copy must be readable as of (defaults to copying all of it).
+    #[clap(long)]
+    as_of: Option<u64>,
+}
+
+/// Clones a shard, as of some time, to a new shard in the same location.
+///
+/// The clone shares the shard's parts, which the shard holds onto until the
+/// clone is released.
+#[derive(Debug, clap::Parser)]
+pub(crate) struct CloneShardArgs {
+    #[clap(flatten)]
+    state: StateArgs,
+
+    /// The time the clone must be readable as of (defaults to cloning all of
+    /// it).
+    #[clap(long)]
+    as_of: Option<u64>,
+}
+
+/// Releases the parts of a shard held by a clone of it, once the clone no
+/// longer shares any of them.
+#[derive(Debug, clap::Parser)]
+pub(crate) struct ReleaseCloneArgs {
+    #[clap(flatten)]
+    state: StateArgs,
+
+    /// The clone to release.
+    #[clap(long)]
+    clone_shard_id: String,
+
+    /// The reader holding the shard's parts for the clone.
+    #[clap(long)]
+    reader_id: String,
+}
+
 pub async fn run(command: AdminArgs) -> Result<(), anyhow::Error> {
     match command.command {
         Command::ForceCompaction(args) => {
@@ -69,6 +151,113 @@ pub async fn run(command: AdminArgs) -> Result<(), anyhow::Error> {
             .await?;
             info_log_non_zero_metrics(&metrics_registry.gather());
         }
+        Command::Backup(args) => {
+            let shard_id = ShardId::from_str(&args.state.shard_id).expect("invalid shard id");
+            let src = PersistLocation {
+                blob_uri: args.state.blob_uri,
+                consensus_uri: args.state.consensus_uri,
+            };
+            let dst = PersistLocation {
+                blob_uri: args.to_blob_uri,
+                consensus_uri: args.to_consensus_uri,
+            };
+            let cfg = PersistConfig::new(&BUILD_INFO, SYSTEM_TIME.clone());
+            let metrics_registry = MetricsRegistry::new();
+            let copied = mz_persist_client::admin::copy_shard(
+                cfg,
+                &metrics_registry,
+                &src,
+                shard_id,
+                &dst,
+                shard_id,
+                args.as_of,
+                command.commit,
+            )
+            .await?;
+            info!("backed up {} at {}", shard_id, copied.seqno);
+        }
+        Command::Restore(args) => {
+            let shard_id = ShardId::from_str(&args.state.shard_id).expect("invalid shard id");
+            let to_shard_id = match args.to_shard_id {
+                Some(x) => ShardId::from_str(&x).expect("invalid shard id"),
+                None => shard_id,
+            };
+            let src = PersistLocation {
+                blob_uri: args.from_blob_uri,
+                consensus_uri: args.from_consensus_uri,
+            };
+            let dst = PersistLocation {
+                blob_uri: args.state.blob_uri,
+                consensus_uri: args.state.consensus_uri,
+            };
+            let cfg = PersistConfig::new(&BUILD_INFO, SYSTEM_TIME.clone());
+            let metrics_registry = MetricsRegistry::new();
+            let copied = mz_persist_client::admin::copy_shard(
+                cfg,
+                &metrics_registry,
+                &src,
+                shard_id,
+                &dst,
+                to_shard_id,
+                args.as_of,
+                command.commit,
+            )
+            .await?;
+            info!(
+                "restored {} to {} at {}",
+                shard_id, to_shard_id, copied.seqno
+            );
+        }
+        Command::CloneShard(args) => {
+            let shard_id = ShardId::from_str(&args.state.shard_id).expect("invalid shard id");
+            let location = PersistLocation {
+                blob_uri: args.state.blob_uri,
+                consensus_uri: args.state.consensus_uri,
+            };
+            let new_shard_id = ShardId::new();
+            let cfg = PersistConfig::new(&BUILD_INFO, SYSTEM_TIME.clone());
+            let metrics_registry = MetricsRegistry::new();
+            let copied = mz_persist_client::admin::copy_shard(
+                cfg,
+                &metrics_registry,
+                &location,
+                shard_id,
+                &location,
+                new_shard_id,
+                args.as_of,
+                command.commit,
+            )
+            .await?;
+            info!(
+                "cloned {} at {} to {}",
+                shard_id, copied.seqno, new_shard_id
+            );
+            match copied.source_reader_id {
+                Some(reader_id) => println!("{} {}", new_shard_id, reader_id),
+                None => println!("{}", new_shard_id),
+            }
+        }
+        Command::ReleaseClone(args) => {
+            let shard_id = ShardId::from_str(&args.state.shard_id).expect("invalid shard id");
+            let clone_shard_id = ShardId::from_str(&args.clone_shard_id).expect("invalid shard id");
+            let reader_id = LeasedReaderId::from_str(&args.reader_id).expect("invalid reader id");
+            let location = PersistLocation {
+                blob_uri: args.state.blob_uri,
+                consensus_uri: args.state.consensus_uri,
+            };
+            let cfg = PersistConfig::new(&BUILD_INFO, SYSTEM_TIME.clone());
+            let metrics_registry = MetricsRegistry::new();
+            let () = mz_persist_client::admin::release_shard_clone(
+                cfg,
+                &metrics_registry,
+                &location,
+                shard_id,
+                clone_shard_id,
+                reader_id,
+                command.commit,
+            )
+            .await?;
+        }
     }
     Ok(())
 }
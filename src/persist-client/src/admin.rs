@@ -10,21 +10,29 @@
 //! CLI introspection tools for persist
 
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
-use anyhow::anyhow;
+use anyhow::{anyhow, bail};
 use mz_ore::metrics::MetricsRegistry;
 use mz_persist::cfg::{BlobConfig, ConsensusConfig};
-use mz_persist::location::{Blob, Consensus};
+use mz_persist::location::{Atomicity, Blob, Consensus, SeqNo};
+use timely::progress::Antichain;
+use timely::PartialOrder;
 use tracing::info;
 
 use crate::async_runtime::CpuHeavyRuntime;
 use crate::internal::compact::{CompactReq, Compactor};
 use crate::internal::machine::Machine;
 use crate::internal::metrics::{MetricsBlob, MetricsConsensus};
-use crate::internal::trace::{ApplyMergeResult, FueledMergeRes};
+use crate::internal::paths::{
+    BlobKey, PartialBatchKey, PartialBlobKey, PartialRollupKey, RollupId,
+};
+use crate::internal::state::{HollowBatch, HollowBatchPart, State};
+use crate::internal::state_diff::StateDiff;
+use crate::internal::trace::{ApplyMergeResult, FueledMergeRes, Trace};
+use crate::read::LeasedReaderId;
 use crate::write::WriterId;
-use crate::{Metrics, PersistConfig, ShardId, StateVersions};
+use crate::{Metrics, PersistConfig, PersistLocation, ShardId, StateVersions};
 
 /// Manually completes all fueled compactions in a shard.
 pub async fn force_compaction(
@@ -184,3 +192,525 @@ pub async fn force_compaction(
         return Ok(());
     }
 }
+
+/// The result of [copy_shard].
+#[derive(Debug)]
+pub struct CopiedShard {
+    /// The version of the source shard's state that was copied.
+    pub seqno: SeqNo,
+    /// The reader that holds the source's parts for a clone that shares them,
+    /// to be released with [release_shard_clone].
+    pub source_reader_id: Option<LeasedReaderId>,
+}
+
+/// Copies a consistent version of a shard's state, along with every part it
+/// references, to a (possibly different) shard in a (possibly different)
+/// location.
+///
+/// This is the basis of backup (a shard to the same shard in another
+/// location), restore (the reverse), and cloning (a shard to a new shard in the
+/// same location). The copy is readable as of `as_of`, if given, and contains
+/// none of the source's batches that start after it.
+///
+/// The destination shard must not already exist. The source's current seqno is
+/// held by a leased reader for the duration of the copy, so garbage collection
+/// can't delete any of the parts being copied. Parts are copied before the
+/// destination's state is written, so it never references a missing part.
+///
+/// A clone in the same blob doesn't copy parts, it shares them with the source
+/// by reference. It then keeps holding the source's seqno, and with it the
+/// shared parts, until it's released with [release_shard_clone]. The clone's
+/// compactions replace the shared parts with parts of its own over time.
+///
+/// Leased readers and writers are not copied: they belong to processes that
+/// are using the source.
+pub async fn copy_shard(
+    cfg: PersistConfig,
+    metrics_registry: &MetricsRegistry,
+    src: &PersistLocation,
+    src_shard_id: ShardId,
+    dst: &PersistLocation,
+    dst_shard_id: ShardId,
+    as_of: Option<u64>,
+    commit: bool,
+) -> Result<CopiedShard, anyhow::Error> {
+    let metrics = Arc::new(Metrics::new(&cfg, metrics_registry));
+    let src_versions = Arc::new(open_state_versions(&cfg, &metrics, src).await?);
+    let dst_versions = open_state_versions(&cfg, &metrics, dst).await?;
+    let share_parts = src.blob_uri == dst.blob_uri && src_shard_id != dst_shard_id;
+
+    if !dst_versions
+        .fetch_recent_live_diffs::<u64>(&dst_shard_id)
+        .await
+        .0
+        .is_empty()
+    {
+        bail!("destination shard {} already exists", dst_shard_id);
+    }
+
+    let src_state = fetch_current_state(&src_versions, &src_shard_id)
+        .await?
+        .ok_or_else(|| anyhow!("shard {} does not exist", src_shard_id))?;
+    if cfg.build_version != src_state.applier_version {
+        bail!(
+            "version of this tool {} does not match version of state {}",
+            cfg.build_version,
+            src_state.applier_version
+        );
+    }
+
+    let batches = batches_as_of(&src_state, as_of)?;
+    let parts = batches.iter().map(|x| x.parts.len()).sum::<usize>();
+    let bytes = batches
+        .iter()
+        .flat_map(|x| x.parts.iter().map(|x| x.encoded_size_bytes))
+        .sum::<usize>();
+    info!(
+        "{} {} {} to {} {} as of {:?}: {} parts totaling {} bytes",
+        if share_parts { "cloning" } else { "copying" },
+        src.blob_uri,
+        src_shard_id,
+        dst.blob_uri,
+        dst_shard_id,
+        as_of,
+        parts,
+        bytes
+    );
+    if !commit {
+        info!("skipping copy because --commit is not set");
+        return Ok(CopiedShard {
+            seqno: src_state.seqno,
+            source_reader_id: None,
+        });
+    }
+
+    let mut machine = Machine::<crate::inspect::K, crate::inspect::V, u64, i64>::new(
+        cfg.clone(),
+        src_shard_id,
+        Arc::clone(&metrics),
+        Arc::clone(&src_versions),
+    )
+    .await?;
+    let reader_id = LeasedReaderId::new();
+    let lease_duration = if share_parts {
+        // The clone holds the source's parts until it's released.
+        Duration::from_millis(u64::MAX)
+    } else {
+        cfg.reader_lease_duration
+    };
+    let (_, read_cap) = machine
+        .register_leased_reader(
+            &reader_id,
+            &format!("persistcli admin copy to {}", dst_shard_id),
+            lease_duration,
+            (cfg.now)(),
+        )
+        .await;
+    let seqno = read_cap.seqno;
+    let src_state = machine
+        .state()
+        .clone(cfg.build_version.clone(), cfg.hostname.clone());
+
+    let copy_res = async {
+        let mut dst_batches = Vec::new();
+        let mut last_heartbeat = Instant::now();
+        for batch in batches_as_of(&src_state, as_of)? {
+            let mut parts = Vec::with_capacity(batch.parts.len());
+            for part in batch.parts.iter() {
+                let key = if share_parts {
+                    if part.key.is_shared() {
+                        bail!(
+                            "shard {} shares parts with another shard and can't be cloned",
+                            src_shard_id
+                        );
+                    }
+                    PartialBatchKey::shared(&src_shard_id, &part.key)
+                } else {
+                    copy_part(
+                        &src_versions,
+                        &part.key,
+                        src_shard_id,
+                        &dst_versions,
+                        dst_shard_id,
+                    )
+                    .await?
+                };
+                parts.push(HollowBatchPart {
+                    key,
+                    ..part.clone()
+                });
+                if last_heartbeat.elapsed() > cfg.reader_lease_duration / 2 {
+                    let _ = machine
+                        .heartbeat_leased_reader(&reader_id, (cfg.now)())
+                        .await;
+                    last_heartbeat = Instant::now();
+                }
+            }
+            dst_batches.push(HollowBatch { parts, ..batch });
+        }
+
+        // Build a standalone version of the state, with a single rollup of
+        // itself.
+        let mut dst_state = src_state.clone(cfg.build_version.clone(), cfg.hostname.clone());
+        dst_state.shard_id = dst_shard_id;
+        let mut trace = Trace::default();
+        trace.downgrade_since(src_state.collections.trace.since());
+        for batch in dst_batches {
+            let _merge_reqs = trace.push_batch(batch);
+        }
+        dst_state.collections.trace = trace;
+        dst_state.collections.leased_readers.clear();
+        dst_state.collections.writers.clear();
+        dst_state.collections.last_gc_req = seqno;
+        let rollup_key = PartialRollupKey::new(seqno, &RollupId::new());
+        dst_state.collections.rollups.clear();
+        dst_state
+            .collections
+            .rollups
+            .insert(seqno, rollup_key.clone());
+
+        let shard_metrics = metrics.shards.shard(&dst_shard_id);
+        dst_versions
+            .write_rollup_blob(&shard_metrics, &dst_state, &rollup_key)
+            .await;
+        let empty_state = State::new(
+            cfg.build_version.clone(),
+            cfg.hostname.clone(),
+            dst_shard_id,
+        );
+        let diff = StateDiff::from_diff(&empty_state, &dst_state);
+        let cas_res = dst_versions
+            .try_compare_and_set_current("copy_shard", &shard_metrics, None, &dst_state, &diff)
+            .await?;
+        if cas_res.is_err() {
+            dst_versions.delete_rollup(&dst_shard_id, &rollup_key).await;
+            bail!(
+                "destination shard {} was concurrently created",
+                dst_shard_id
+            );
+        }
+        Ok::<_, anyhow::Error>(())
+    }
+    .await;
+
+    if copy_res.is_err() || !share_parts {
+        let _ = machine.expire_leased_reader(&reader_id).await;
+        copy_res?;
+        info!("copied {} to {} at {}", src_shard_id, dst_shard_id, seqno);
+        return Ok(CopiedShard {
+            seqno,
+            source_reader_id: None,
+        });
+    }
+
+    // The clone only needs to hold the source's seqno. If someone else holds
+    // its since, which the storage controller does for every collection, let
+    // the source keep compacting.
+    if !src_state.collections.critical_readers.is_empty() {
+        let _ = machine
+            .downgrade_since(&reader_id, Some(seqno), &Antichain::new(), (cfg.now)())
+            .await;
+    }
+    info!(
+        "cloned {} to {} at {}, holding its parts with reader {}",
+        src_shard_id, dst_shard_id, seqno, reader_id
+    );
+    Ok(CopiedShard {
+        seqno,
+        source_reader_id: Some(reader_id),
+    })
+}
+
+/// Releases the source's parts held by a clone made by [copy_shard], once the
+/// clone no longer shares any of them (or no longer exists).
+pub async fn release_shard_clone(
+    cfg: PersistConfig,
+    metrics_registry: &MetricsRegistry,
+    location: &PersistLocation,
+    src_shard_id: ShardId,
+    clone_shard_id: ShardId,
+    reader_id: LeasedReaderId,
+    commit: bool,
+) -> Result<(), anyhow::Error> {
+    let metrics = Arc::new(Metrics::new(&cfg, metrics_registry));
+    let state_versions = Arc::new(open_state_versions(&cfg, &metrics, location).await?);
+
+    if let Some(clone_state) = fetch_current_state(&state_versions, &clone_shard_id).await? {
+        let src_prefix = format!("{}/", src_shard_id);
+        let mut shared = 0;
+        clone_state.collections.trace.map_batches(|b| {
+            shared += b
+                .parts
+                .iter()
+                .filter(|x| x.key.is_shared() && x.key.starts_with(&src_prefix))
+                .count();
+        });
+        if shared > 0 {
+            bail!(
+                "clone {} still shares {} parts with {}",
+                clone_shard_id,
+                shared,
+                src_shard_id
+            );
+        }
+    }
+
+    let src_state = fetch_current_state(&state_versions, &src_shard_id)
+        .await?
+        .ok_or_else(|| anyhow!("shard {} does not exist", src_shard_id))?;
+    if cfg.build_version != src_state.applier_version {
+        bail!(
+            "version of this tool {} does not match version of state {}",
+            cfg.build_version,
+            src_state.applier_version
+        );
+    }
+    if !src_state
+        .collections
+        .leased_readers
+        .contains_key(&reader_id)
+    {
+        bail!("shard {} has no reader {}", src_shard_id, reader_id);
+    }
+    if !commit {
+        info!("skipping release because --commit is not set");
+        return Ok(());
+    }
+    let mut machine = Machine::<crate::inspect::K, crate::inspect::V, u64, i64>::new(
+        cfg.clone(),
+        src_shard_id,
+        Arc::clone(&metrics),
+        state_versions,
+    )
+    .await?;
+    let _ = machine.expire_leased_reader(&reader_id).await;
+    info!("released {} reader {}", src_shard_id, reader_id);
+    Ok(())
+}
+
+/// Copies a part of `src_shard_id` to the same part of `dst_shard_id`,
+/// returning its key in the latter.
+async fn copy_part(
+    src_versions: &StateVersions,
+    key: &PartialBatchKey,
+    src_shard_id: ShardId,
+    dst_versions: &StateVersions,
+    dst_shard_id: ShardId,
+) -> Result<PartialBatchKey, anyhow::Error> {
+    let src_key = key.complete(&src_shard_id);
+    // The part may be shared from another shard, but the copy is owned by the
+    // destination.
+    let (owner_shard_id, dst_key) = match BlobKey::parse_ids(&src_key) {
+        Ok((shard_id, PartialBlobKey::Batch(writer_id, part_id))) => {
+            (shard_id, PartialBatchKey::new(&writer_id, &part_id))
+        }
+        _ => bail!("invalid part key {}", src_key),
+    };
+    let buf = src_versions
+        .blob
+        .get(&src_key)
+        .await?
+        .ok_or_else(|| anyhow!("part {} is missing", src_key))?;
+    // Encrypted parts are bound to the shard that wrote them.
+    if owner_shard_id != dst_shard_id && crate::encryption::is_encrypted(&buf) {
+        bail!(
+            "part {} is encrypted and can't be copied to another shard",
+            src_key
+        );
+    }
+    dst_versions
+        .blob
+        .set(
+            &dst_key.complete(&dst_shard_id),
+            buf.into(),
+            Atomicity::AllowNonAtomic,
+        )
+        .await?;
+    Ok(dst_key)
+}
+
+async fn open_state_versions(
+    cfg: &PersistConfig,
+    metrics: &Arc<Metrics>,
+    location: &PersistLocation,
+) -> Result<StateVersions, anyhow::Error> {
+    let consensus = ConsensusConfig::try_from(
+        &location.consensus_uri,
+        Box::new(cfg.clone()),
+        metrics.postgres_consensus.clone(),
+    )?;
+    let consensus = consensus.open().await?;
+    let consensus: Arc<dyn Consensus + Send + Sync> =
+        Arc::new(MetricsConsensus::new(consensus, Arc::clone(metrics)));
+    let blob = BlobConfig::try_from(&location.blob_uri).await?;
+    let blob = blob.open().await?;
+    let blob: Arc<dyn Blob + Send + Sync> = Arc::new(MetricsBlob::new(blob, Arc::clone(metrics)));
+    Ok(StateVersions::new(
+        cfg.clone(),
+        consensus,
+        blob,
+        Arc::clone(metrics),
+    ))
+}
+
+type InspectState = State<crate::inspect::K, crate::inspect::V, u64, i64>;
+
+/// Returns the current version of the shard's state, or None if the shard
+/// doesn't exist.
+async fn fetch_current_state(
+    state_versions: &StateVersions,
+    shard_id: &ShardId,
+) -> Result<Option<InspectState>, anyhow::Error> {
+    loop {
+        let versions = state_versions
+            .fetch_recent_live_diffs::<u64>(shard_id)
+            .await;
+        if versions.0.is_empty() {
+            return Ok(None);
+        }
+        // Prime the K V codec magic
+        let state_res = state_versions
+            .fetch_current_state::<crate::inspect::K, crate::inspect::V, u64, i64>(
+                shard_id, versions.0,
+            )
+            .await;
+        match state_res {
+            Ok(state) => return Ok(Some(state)),
+            Err(codec) => {
+                let mut kvtd = crate::inspect::KVTD_CODECS.lock().expect("lockable");
+                *kvtd = codec.actual;
+            }
+        }
+    }
+}
+
+/// Returns the batches of the shard needed to read it as of `as_of`, or all of
+/// them if `as_of` is None.
+fn batches_as_of(
+    state: &InspectState,
+    as_of: Option<u64>,
+) -> Result<Vec<HollowBatch<u64>>, anyhow::Error> {
+    let trace = &state.collections.trace;
+    let batches = trace.batches().into_iter().cloned();
+    let Some(as_of) = as_of else {
+        return Ok(batches.collect());
+    };
+    let as_of = Antichain::from_elem(as_of);
+    if !PartialOrder::less_equal(trace.since(), &as_of)
+        || !PartialOrder::less_than(&as_of, trace.upper())
+    {
+        bail!(
+            "shard {} is not readable as of {:?}: since={:?} upper={:?}",
+            state.shard_id,
+            as_of.elements(),
+            trace.since().elements(),
+            trace.upper().elements()
+        );
+    }
+    // Batches that start after as_of have nothing in them as of it.
+    Ok(batches
+        .filter(|x| PartialOrder::less_equal(x.desc.lower(), &as_of))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cache::PersistClientCache;
+    use crate::tests::all_ok;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn clone_shard() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let location = PersistLocation {
+            blob_uri: format!("file://{}/blob", dir.path().display()),
+            consensus_uri: format!("sqlite://{}/consensus.db", dir.path().display()),
+        };
+        let data = vec![
+            (("1".to_owned(), "one".to_owned()), 1, 1),
+            (("2".to_owned(), "two".to_owned()), 2, 1),
+            (("3".to_owned(), "three".to_owned()), 3, 1),
+        ];
+
+        let mut cache = PersistClientCache::new_no_metrics();
+        let cfg = cache.cfg.clone();
+        let client = cache
+            .open(location.clone())
+            .await
+            .expect("client construction failed");
+        let shard_id = ShardId::new();
+        // Only a writer, so nothing but the clone holds the source's seqno.
+        let mut write = client
+            .open_writer::<String, String, u64, i64>(shard_id, "tests")
+            .await
+            .expect("codec mismatch");
+        write.expect_compare_and_append(&data[..2], 0, 3).await;
+        write.expect_compare_and_append(&data[2..], 3, 4).await;
+
+        // The clone only has the batches needed to read it as of 2, and shares
+        // their parts with the source.
+        let clone_shard_id = ShardId::new();
+        let copied = copy_shard(
+            cfg.clone(),
+            &MetricsRegistry::new(),
+            &location,
+            shard_id,
+            &location,
+            clone_shard_id,
+            Some(2),
+            true,
+        )
+        .await
+        .expect("clone");
+        let reader_id = copied.source_reader_id.expect("clone holds source parts");
+        let (_, mut clone_read) = client
+            .expect_open::<String, String, u64, i64>(clone_shard_id)
+            .await;
+        assert_eq!(
+            clone_read.expect_snapshot_and_fetch(2).await,
+            all_ok(&data[..2], 2)
+        );
+        assert_eq!(clone_read.machine.upper(), &Antichain::from_elem(3));
+        clone_read
+            .machine
+            .state()
+            .collections
+            .trace
+            .map_batches(|b| {
+                assert!(b.parts.iter().all(|x| x.key.is_shared()));
+            });
+
+        // The source can't garbage collect the shared parts until the clone is
+        // released, which it can't be while it shares them.
+        write.expect_compare_and_append(&data[..1], 4, 5).await;
+        write.machine.fetch_and_update_state().await;
+        assert_eq!(write.machine.seqno_since(), copied.seqno);
+        let err = release_shard_clone(
+            cfg.clone(),
+            &MetricsRegistry::new(),
+            &location,
+            shard_id,
+            clone_shard_id,
+            reader_id,
+            true,
+        )
+        .await
+        .expect_err("clone still shares parts");
+        assert!(err.to_string().contains("still shares"), "{}", err);
+
+        // A time the source isn't readable at can't be cloned.
+        assert!(copy_shard(
+            cfg,
+            &MetricsRegistry::new(),
+            &location,
+            shard_id,
+            &location,
+            ShardId::new(),
+            Some(5),
+            true,
+        )
+        .await
+        .is_err());
+    }
+}
@@ -23,6 +23,7 @@ use mz_ore::cast::CastFrom;
 use mz_persist::indexed::columnar::{ColumnarRecords, ColumnarRecordsVecBuilder};
 use mz_persist::indexed::encoding::BlobTraceBatchPart;
 use mz_persist::location::{Atomicity, Blob};
+use mz_persist_types::stats::PartStats;
 use mz_persist_types::{Codec, Codec64};
 use timely::progress::{Antichain, Timestamp};
 use timely::PartialOrder;
@@ -221,8 +222,10 @@ where
         shard_id: ShardId,
         writer_id: WriterId,
     ) -> Self {
-        let parts = BatchParts::new(
+        let parts = BatchParts::new::<K>(
             cfg.batch_builder_max_outstanding_parts,
+            cfg.should_collect_stats(),
+            cfg.dynamic.structured_parts_enabled(),
             Arc::clone(&metrics),
             shard_id,
             writer_id,
@@ -371,25 +374,51 @@ where
     }
 }
 
+/// Computes the [PartStats] of a part's updates, using the shard's key codec.
+type KeyStatsFn = fn(&ColumnarRecords) -> Result<Option<PartStats>, String>;
+
+fn key_stats<K: Codec>(updates: &ColumnarRecords) -> Result<Option<PartStats>, String> {
+    let keys = updates.iter().map(|((k, _v), _t, _d)| k);
+    Ok(K::stats(keys)?.map(|key| PartStats { key }))
+}
+
+/// Encodes a part, using the shard's key codec if it's to be structured.
+type EncodePartFn<T> = fn(&BlobTraceBatchPart<T>, &mut Vec<u8>);
+
+fn encode_part<T: Timestamp + Codec64>(part: &BlobTraceBatchPart<T>, buf: &mut Vec<u8>) {
+    part.encode(buf)
+}
+
+fn encode_part_structured<K: Codec, T: Timestamp + Codec64>(
+    part: &BlobTraceBatchPart<T>,
+    buf: &mut Vec<u8>,
+) {
+    part.encode_structured::<K, _>(buf)
+}
+
 // TODO: If this is dropped, cancel (and delete?) any writing parts and delete
 // any finished ones.
 #[derive(Debug)]
 pub(crate) struct BatchParts<T> {
     max_outstanding: usize,
+    key_stats: Option<KeyStatsFn>,
+    encode_part: EncodePartFn<T>,
     metrics: Arc<Metrics>,
     shard_id: ShardId,
     writer_id: WriterId,
     lower: Antichain<T>,
     blob: Arc<dyn Blob + Send + Sync>,
     cpu_heavy_runtime: Arc<CpuHeavyRuntime>,
-    writing_parts: VecDeque<(PartialBatchKey, JoinHandle<usize>)>,
+    writing_parts: VecDeque<(PartialBatchKey, JoinHandle<(usize, Option<PartStats>)>)>,
     finished_parts: Vec<HollowBatchPart>,
     batch_metrics: BatchWriteMetrics,
 }
 
 impl<T: Timestamp + Codec64> BatchParts<T> {
-    pub(crate) fn new(
+    pub(crate) fn new<K: Codec>(
         max_outstanding: usize,
+        collect_stats: bool,
+        structured_parts: bool,
         metrics: Arc<Metrics>,
         shard_id: ShardId,
         writer_id: WriterId,
@@ -400,6 +429,12 @@ impl<T: Timestamp + Codec64> BatchParts<T> {
     ) -> Self {
         BatchParts {
             max_outstanding,
+            key_stats: collect_stats.then_some(key_stats::<K> as KeyStatsFn),
+            encode_part: if structured_parts {
+                encode_part_structured::<K, T>
+            } else {
+                encode_part::<T>
+            },
             metrics,
             shard_id,
             writer_id,
@@ -423,6 +458,8 @@ impl<T: Timestamp + Codec64> BatchParts<T> {
         let blob = Arc::clone(&self.blob);
         let cpu_heavy_runtime = Arc::clone(&self.cpu_heavy_runtime);
         let batch_metrics = self.batch_metrics.clone();
+        let key_stats = self.key_stats;
+        let encode_part = self.encode_part;
         let partial_key = PartialBatchKey::new(&self.writer_id, &PartId::new());
         let key = partial_key.complete(&self.shard_id);
         let index = u64::cast_from(self.finished_parts.len() + self.writing_parts.len());
@@ -439,14 +476,27 @@ impl<T: Timestamp + Codec64> BatchParts<T> {
                 };
 
                 let start = Instant::now();
-                let buf = cpu_heavy_runtime
+                let stats_metrics = Arc::clone(&metrics);
+                let (buf, stats) = cpu_heavy_runtime
                     .spawn_named(|| "batch::encode_part", async move {
+                        // Stats are best-effort: a part without them is simply
+                        // never filtered out by readers.
+                        let stats =
+                            key_stats.and_then(|key_stats| match key_stats(&batch.updates[0]) {
+                                Ok(stats) => stats,
+                                Err(err) => {
+                                    stats_metrics.stats.compute_failed.inc();
+                                    warn!("failed to compute part stats: {}", err);
+                                    None
+                                }
+                            });
+
                         let mut buf = Vec::new();
-                        batch.encode(&mut buf);
+                        encode_part(&batch, &mut buf);
 
                         // Drop batch as soon as we can to reclaim its memory.
                         drop(batch);
-                        Bytes::from(buf)
+                        (Bytes::from(buf), stats)
                     })
                     .instrument(debug_span!("batch::encode_part"))
                     .await
@@ -470,7 +520,7 @@ impl<T: Timestamp + Codec64> BatchParts<T> {
                 batch_metrics.seconds.inc_by(start.elapsed().as_secs_f64());
                 batch_metrics.bytes.inc_by(u64::cast_from(payload_len));
                 batch_metrics.goodbytes.inc_by(u64::cast_from(goodbytes));
-                payload_len
+                (payload_len, stats)
             }
             .instrument(write_span),
         );
@@ -482,17 +532,18 @@ impl<T: Timestamp + Codec64> BatchParts<T> {
                 .writing_parts
                 .pop_front()
                 .expect("pop failed when len was just > some usize");
-            let encoded_size_bytes = match handle
+            let (encoded_size_bytes, stats) = match handle
                 .instrument(debug_span!("batch::max_outstanding"))
                 .await
             {
                 Ok(x) => x,
-                Err(err) if err.is_cancelled() => 0,
+                Err(err) if err.is_cancelled() => (0, None),
                 Err(err) => panic!("part upload task failed: {}", err),
             };
             self.finished_parts.push(HollowBatchPart {
                 key,
                 encoded_size_bytes,
+                stats,
             });
         }
     }
@@ -501,14 +552,15 @@ impl<T: Timestamp + Codec64> BatchParts<T> {
     pub(crate) async fn finish(self) -> Vec<HollowBatchPart> {
         let mut parts = self.finished_parts;
         for (key, handle) in self.writing_parts {
-            let encoded_size_bytes = match handle.await {
+            let (encoded_size_bytes, stats) = match handle.await {
                 Ok(x) => x,
-                Err(err) if err.is_cancelled() => 0,
+                Err(err) if err.is_cancelled() => (0, None),
                 Err(err) => panic!("part upload task failed: {}", err),
             };
             parts.push(HollowBatchPart {
                 key,
                 encoded_size_bytes,
+                stats,
             });
         }
         parts
@@ -11,18 +11,30 @@
 
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
 
 use mz_ore::metrics::MetricsRegistry;
 use mz_persist::cfg::{BlobConfig, ConsensusConfig};
 use mz_persist::location::{Blob, Consensus, ExternalError};
-use tracing::instrument;
+use sha2::{Digest, Sha256};
+use tracing::{instrument, warn};
 
 use crate::async_runtime::CpuHeavyRuntime;
+use crate::encryption::EncryptedBlob;
+use crate::internal::blob_cache::{remove_stale_cache_dirs, DiskCache, DiskCacheBlob};
 use crate::internal::machine::retry_external;
 use crate::internal::metrics::{Metrics, MetricsBlob, MetricsConsensus};
 use crate::{PersistClient, PersistConfig, PersistLocation};
 
+/// The blob cache directories that have already been swept of subdirectories
+/// left behind by previous processes.
+///
+/// This is process-global, rather than a part of [PersistClientCache], because
+/// a sweep must not remove the subdirectory of a location that was opened
+/// earlier by any cache in this process.
+static SWEPT_BLOB_CACHE_ROOTS: Mutex<Vec<PathBuf>> = Mutex::new(Vec::new());
+
 /// A cache of [PersistClient]s indexed by [PersistLocation]s.
 ///
 /// There should be at most one of these per process. All production
@@ -60,6 +72,11 @@ impl PersistClientCache {
         Self::new(PersistConfig::new_for_tests(), &MetricsRegistry::new())
     }
 
+    /// Returns the [PersistConfig] being used by this cache.
+    pub fn cfg(&self) -> &PersistConfig {
+        &self.cfg
+    }
+
     /// Returns a new [PersistClient] for interfacing with persist shards made
     /// durable to the given [PersistLocation].
     ///
@@ -113,10 +130,66 @@ impl PersistClientCache {
                     blob.clone().open()
                 })
                 .await;
+                let blob: Arc<dyn Blob + Send + Sync> =
+                    Arc::new(MetricsBlob::new(blob, Arc::clone(&self.metrics)));
+                let blob = Self::maybe_cache_blob(&self.cfg, &self.metrics, x.key(), blob).await;
+                // Encrypt outside the cache, so the cache only ever sees
+                // ciphertext.
+                let blob: Arc<dyn Blob + Send + Sync> = match &self.cfg.blob_encryption_keys {
+                    Some(keys) => Arc::new(EncryptedBlob::new(blob, Arc::clone(keys))),
+                    None => blob,
+                };
                 Arc::clone(x.insert(blob))
             }
         };
-        Ok(Arc::new(MetricsBlob::new(blob, Arc::clone(&self.metrics))))
+        Ok(blob)
+    }
+
+    /// Wraps `blob` in a local disk cache, if one is configured.
+    ///
+    /// The cache wraps the [MetricsBlob], so that the blob metrics only count
+    /// fetches that actually went to `blob`.
+    ///
+    /// The first time this process uses the cache directory, any subdirectories
+    /// left behind by previous processes are removed.
+    async fn maybe_cache_blob(
+        cfg: &PersistConfig,
+        metrics: &Metrics,
+        blob_uri: &str,
+        blob: Arc<dyn Blob + Send + Sync>,
+    ) -> Arc<dyn Blob + Send + Sync> {
+        let root = match &cfg.blob_cache_dir {
+            Some(root) if cfg.blob_cache_size_bytes > 0 => root,
+            _ => return blob,
+        };
+        let dir = root.join(Self::blob_cache_dir_name(blob_uri));
+        let first_use = {
+            let mut swept = SWEPT_BLOB_CACHE_ROOTS.lock().expect("lock poisoned");
+            let first_use = !swept.contains(root);
+            if first_use {
+                swept.push(root.clone());
+            }
+            first_use
+        };
+        if first_use {
+            remove_stale_cache_dirs(root.clone(), dir.clone()).await;
+        }
+        match DiskCache::open(dir, cfg.blob_cache_size_bytes, metrics.blob_cache.clone()).await {
+            Ok(cache) => Arc::new(DiskCacheBlob::new(blob, Arc::new(cache))),
+            Err(err) => {
+                warn!("failed to open blob cache, continuing without it: {}", err);
+                blob
+            }
+        }
+    }
+
+    /// Returns the name of the blob cache subdirectory for `blob_uri`.
+    ///
+    /// The URI may contain credentials, so this is a hash rather than the URI
+    /// itself. The cache outlives the process, so the hash must be stable
+    /// across processes and releases.
+    fn blob_cache_dir_name(blob_uri: &str) -> String {
+        format!("{:x}", Sha256::digest(blob_uri.as_bytes()))
     }
 }
 
@@ -192,4 +265,63 @@ mod tests {
         assert_eq!(cache.blob_by_uri.len(), 4);
         assert_eq!(cache.consensus_by_uri.len(), 4);
     }
+
+    #[tokio::test]
+    async fn blob_cache_dirs() {
+        let root = tempfile::tempdir().expect("tempdir");
+        let stale = root.path().join("stale");
+        std::fs::create_dir(&stale).expect("create stale dir");
+        std::fs::write(stale.join("part"), b"part").expect("write stale part");
+
+        let mut cfg = PersistConfig::new_for_tests();
+        cfg.blob_cache_dir = Some(root.path().to_owned());
+        cfg.blob_cache_size_bytes = 1024;
+        let mut cache = PersistClientCache::new(cfg, &MetricsRegistry::new());
+        let list_dirs = || {
+            let mut dirs = std::fs::read_dir(root.path())
+                .expect("read root")
+                .map(|x| x.expect("dir entry").file_name().into_string().unwrap())
+                .collect::<Vec<_>>();
+            dirs.sort();
+            dirs
+        };
+
+        // The directory is named by a stable hash of the blob URI, and any
+        // directories left behind by a previous process are removed.
+        let _ = cache
+            .open(PersistLocation {
+                blob_uri: "mem://blob_zero".to_owned(),
+                consensus_uri: "mem://consensus_zero".to_owned(),
+            })
+            .await
+            .expect("failed to open location");
+        let blob_zero = "d064edb444523b57f437054ce969d21a59d6fb4f57a1a503efeb472cfe65a915";
+        assert_eq!(list_dirs(), vec![blob_zero.to_owned()]);
+
+        // Directories in use by this process are kept.
+        let _ = cache
+            .open(PersistLocation {
+                blob_uri: "mem://blob_one".to_owned(),
+                consensus_uri: "mem://consensus_zero".to_owned(),
+            })
+            .await
+            .expect("failed to open location");
+        let blob_one = PersistClientCache::blob_cache_dir_name("mem://blob_one");
+        let mut expected = vec![blob_zero.to_owned(), blob_one];
+        expected.sort();
+        assert_eq!(list_dirs(), expected);
+
+        // Directories in use by other caches in this process are kept, too.
+        let mut other_cache = PersistClientCache::new(cache.cfg.clone(), &MetricsRegistry::new());
+        let _ = other_cache
+            .open(PersistLocation {
+                blob_uri: "mem://blob_two".to_owned(),
+                consensus_uri: "mem://consensus_zero".to_owned(),
+            })
+            .await
+            .expect("failed to open location");
+        expected.push(PersistClientCache::blob_cache_dir_name("mem://blob_two"));
+        expected.sort();
+        assert_eq!(list_dirs(), expected);
+    }
 }
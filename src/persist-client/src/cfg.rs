@@ -0,0 +1,201 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Persist configuration that can be changed while the process is running.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use mz_proto::{RustType, TryFromProtoError};
+use proptest_derive::Arbitrary;
+use serde::{Deserialize, Serialize};
+
+use crate::PersistConfig;
+
+include!(concat!(env!("OUT_DIR"), "/mz_persist_client.cfg.rs"));
+
+/// The knobs of a [PersistConfig] that can be changed while the process is
+/// running.
+///
+/// This is shared by every clone of a [PersistConfig], so an update is
+/// observed by all the clients created from it, including the ones that are
+/// already open.
+#[derive(Debug)]
+pub struct DynamicConfig {
+    stats_collection_enabled: AtomicBool,
+    stats_filter_enabled: AtomicBool,
+    structured_parts_enabled: AtomicBool,
+}
+
+impl DynamicConfig {
+    // TODO: Decide if we can relax these.
+    const LOAD_ORDERING: Ordering = Ordering::SeqCst;
+    const STORE_ORDERING: Ordering = Ordering::SeqCst;
+
+    pub(crate) fn new() -> Self {
+        DynamicConfig {
+            stats_collection_enabled: AtomicBool::new(false),
+            stats_filter_enabled: AtomicBool::new(false),
+            structured_parts_enabled: AtomicBool::new(false),
+        }
+    }
+
+    /// Whether to compute and record statistics about the data in each part
+    /// when it is written, for use in filter pushdown.
+    pub fn stats_collection_enabled(&self) -> bool {
+        self.stats_collection_enabled.load(Self::LOAD_ORDERING)
+    }
+
+    /// Whether readers may skip fetching parts that their statistics show
+    /// can't match a pushed-down filter.
+    pub fn stats_filter_enabled(&self) -> bool {
+        self.stats_filter_enabled.load(Self::LOAD_ORDERING)
+    }
+
+    /// Whether to write parts with their keys split into columns by the
+    /// shard's key codec, if it supports that.
+    ///
+    /// Parts written this way can only be read by versions that understand
+    /// the format, so this should only be enabled once every process that
+    /// might read the shard has been upgraded. Both formats are always
+    /// readable, and compaction rewrites parts in whichever format is enabled.
+    pub fn structured_parts_enabled(&self) -> bool {
+        self.structured_parts_enabled.load(Self::LOAD_ORDERING)
+    }
+
+    /// Sets [Self::stats_collection_enabled].
+    pub fn set_stats_collection_enabled(&self, enabled: bool) {
+        self.stats_collection_enabled
+            .store(enabled, Self::STORE_ORDERING);
+    }
+
+    /// Sets [Self::stats_filter_enabled].
+    pub fn set_stats_filter_enabled(&self, enabled: bool) {
+        self.stats_filter_enabled
+            .store(enabled, Self::STORE_ORDERING);
+    }
+
+    /// Sets [Self::structured_parts_enabled].
+    pub fn set_structured_parts_enabled(&self, enabled: bool) {
+        self.structured_parts_enabled
+            .store(enabled, Self::STORE_ORDERING);
+    }
+}
+
+/// Updates to the values of a [DynamicConfig].
+///
+/// Parameters can be set (`Some`) or unset (`None`). Unset parameters should
+/// be interpreted to mean "use the previous value".
+#[derive(Arbitrary, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PersistParameters {
+    /// Configures [DynamicConfig::stats_collection_enabled].
+    pub stats_collection_enabled: Option<bool>,
+    /// Configures [DynamicConfig::stats_filter_enabled].
+    pub stats_filter_enabled: Option<bool>,
+    /// Configures [DynamicConfig::structured_parts_enabled].
+    pub structured_parts_enabled: Option<bool>,
+}
+
+impl PersistParameters {
+    /// Update the parameter values with the set ones from `other`.
+    pub fn update(&mut self, other: PersistParameters) {
+        let PersistParameters {
+            stats_collection_enabled,
+            stats_filter_enabled,
+            structured_parts_enabled,
+        } = other;
+
+        if stats_collection_enabled.is_some() {
+            self.stats_collection_enabled = stats_collection_enabled;
+        }
+        if stats_filter_enabled.is_some() {
+            self.stats_filter_enabled = stats_filter_enabled;
+        }
+        if structured_parts_enabled.is_some() {
+            self.structured_parts_enabled = structured_parts_enabled;
+        }
+    }
+
+    /// Return whether all parameters are unset.
+    pub fn all_unset(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Applies the set parameter values to `cfg`.
+    pub fn apply(&self, cfg: &PersistConfig) {
+        let PersistParameters {
+            stats_collection_enabled,
+            stats_filter_enabled,
+            structured_parts_enabled,
+        } = self;
+
+        if let Some(enabled) = stats_collection_enabled {
+            cfg.dynamic.set_stats_collection_enabled(*enabled);
+        }
+        if let Some(enabled) = stats_filter_enabled {
+            cfg.dynamic.set_stats_filter_enabled(*enabled);
+        }
+        if let Some(enabled) = structured_parts_enabled {
+            cfg.dynamic.set_structured_parts_enabled(*enabled);
+        }
+    }
+}
+
+impl RustType<ProtoPersistParameters> for PersistParameters {
+    fn into_proto(&self) -> ProtoPersistParameters {
+        ProtoPersistParameters {
+            stats_collection_enabled: self.stats_collection_enabled,
+            stats_filter_enabled: self.stats_filter_enabled,
+            structured_parts_enabled: self.structured_parts_enabled,
+        }
+    }
+
+    fn from_proto(proto: ProtoPersistParameters) -> Result<Self, TryFromProtoError> {
+        Ok(Self {
+            stats_collection_enabled: proto.stats_collection_enabled,
+            stats_filter_enabled: proto.stats_filter_enabled,
+            structured_parts_enabled: proto.structured_parts_enabled,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_proto::protobuf_roundtrip;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn persist_parameters_protobuf_roundtrip(expect in any::<PersistParameters>()) {
+            let actual = protobuf_roundtrip::<_, ProtoPersistParameters>(&expect);
+            assert!(actual.is_ok());
+            assert_eq!(actual.unwrap(), expect);
+        }
+    }
+
+    #[test]
+    fn apply_only_set_parameters() {
+        let cfg = PersistConfig::new_for_tests();
+        cfg.dynamic.set_stats_collection_enabled(true);
+        cfg.dynamic.set_stats_filter_enabled(true);
+
+        let mut params = PersistParameters::default();
+        params.update(PersistParameters {
+            stats_collection_enabled: None,
+            stats_filter_enabled: Some(false),
+            structured_parts_enabled: Some(true),
+        });
+        // Clones share the same dynamic config.
+        params.apply(&cfg.clone());
+        assert!(cfg.dynamic.stats_collection_enabled());
+        assert!(!cfg.dynamic.stats_filter_enabled());
+        assert!(cfg.dynamic.structured_parts_enabled());
+    }
+}
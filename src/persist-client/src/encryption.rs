@@ -0,0 +1,471 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Client-side envelope encryption of persist blobs.
+//!
+//! Each batch part is encrypted with AES-256-GCM under a per-shard data key.
+//! The data key is itself encrypted ("wrapped") by a key-encryption key and
+//! stored, wrapped, in the header of every part, alongside the id of the
+//! key-encryption key that wrapped it. Rotating the key-encryption key is then
+//! a matter of making a new one current: new parts are written under it, and
+//! old parts stay readable for as long as the old key is still provided.
+//!
+//! Only batch parts, which contain row data, are encrypted. Rollups of shard
+//! metadata and blob keys and sizes are left as they are, so that usage and
+//! inspect tooling keep working without access to the keys.
+//!
+//! A part that can't be decrypted, because its key-encryption key is missing or
+//! wrong or because it is corrupt, halts the process: retrying can't fix it.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+
+use anyhow::{anyhow, bail, Context};
+use async_trait::async_trait;
+use bytes::Bytes;
+use mz_ore::halt;
+use mz_persist::location::{Atomicity, Blob, BlobMetadata, Determinate, ExternalError};
+use mz_repr::GlobalId;
+use mz_secrets::SecretsReader;
+use openssl::symm::{decrypt_aead, encrypt_aead, Cipher};
+
+use crate::internal::paths::{BlobKey, PartialBlobKey};
+use crate::ShardId;
+
+/// Identifies an encrypted blob. Anything without it is assumed to have been
+/// written before encryption was enabled, and is passed through as is.
+const MAGIC: &[u8; 8] = b"MZPENC01";
+const KEY_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+
+/// The key-encryption keys used to wrap the data keys of encrypted blobs.
+#[derive(Clone)]
+pub struct KeyEncryptionKeys {
+    current: String,
+    keys: BTreeMap<String, [u8; KEY_LEN]>,
+}
+
+impl Debug for KeyEncryptionKeys {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Intentionally omit the keys themselves.
+        f.debug_struct("KeyEncryptionKeys")
+            .field("current", &self.current)
+            .field("ids", &self.keys.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl KeyEncryptionKeys {
+    /// Returns a new [KeyEncryptionKeys] from pairs of key ids and 256-bit
+    /// keys.
+    ///
+    /// The first key is used to wrap new data keys. The rest are only used to
+    /// unwrap the data keys of blobs written before a rotation.
+    pub fn new<I>(keys: I) -> Result<Self, anyhow::Error>
+    where
+        I: IntoIterator<Item = (String, Vec<u8>)>,
+    {
+        let mut current = None;
+        let mut ret = BTreeMap::new();
+        for (id, key) in keys {
+            if id.len() > usize::from(u16::MAX) {
+                bail!("key-encryption key id is too long: {}", id);
+            }
+            let key = <[u8; KEY_LEN]>::try_from(key.as_slice()).map_err(|_| {
+                anyhow!(
+                    "key-encryption key {} must be {} bytes, got {}",
+                    id,
+                    KEY_LEN,
+                    key.len()
+                )
+            })?;
+            if ret.insert(id.clone(), key).is_some() {
+                bail!("duplicate key-encryption key id: {}", id);
+            }
+            current.get_or_insert(id);
+        }
+        let current = current.ok_or_else(|| anyhow!("at least one key-encryption key required"))?;
+        Ok(KeyEncryptionKeys { current, keys: ret })
+    }
+
+    fn get(&self, id: &str) -> Result<&[u8; KEY_LEN], anyhow::Error> {
+        self.keys
+            .get(id)
+            .ok_or_else(|| anyhow!("unknown key-encryption key: {}", id))
+    }
+}
+
+/// Command line arguments that configure the [KeyEncryptionKeys] of a process.
+///
+/// Every process that reads or writes the same shards must be configured with
+/// the same keys, so processes that orchestrate others pass these along with
+/// [Self::to_args].
+#[derive(clap::Parser, Clone, Debug, Default)]
+pub struct BlobEncryptionCliArgs {
+    /// The IDs of secrets holding the 256-bit keys with which to encrypt
+    /// persist batch parts.
+    ///
+    /// The first key encrypts newly written parts. Any others are only used to
+    /// read parts written before a key rotation. If no keys are specified,
+    /// parts are not encrypted.
+    #[clap(
+        long,
+        env = "PERSIST_BLOB_ENCRYPTION_KEY_ID",
+        value_name = "ID",
+        multiple = true,
+        use_value_delimiter = true
+    )]
+    pub persist_blob_encryption_key_id: Vec<GlobalId>,
+}
+
+impl BlobEncryptionCliArgs {
+    /// Reads the keys from `secrets_reader`, if any were specified.
+    pub async fn load(
+        &self,
+        secrets_reader: &dyn SecretsReader,
+    ) -> Result<Option<Arc<KeyEncryptionKeys>>, anyhow::Error> {
+        if self.persist_blob_encryption_key_id.is_empty() {
+            return Ok(None);
+        }
+        let mut keys = Vec::with_capacity(self.persist_blob_encryption_key_id.len());
+        for id in &self.persist_blob_encryption_key_id {
+            let key = secrets_reader
+                .read(*id)
+                .await
+                .with_context(|| format!("reading persist blob encryption key {}", id))?;
+            keys.push((id.to_string(), key));
+        }
+        Ok(Some(Arc::new(KeyEncryptionKeys::new(keys)?)))
+    }
+
+    /// Returns the command line arguments that configure the same keys in
+    /// another process.
+    pub fn to_args(&self) -> Vec<String> {
+        self.persist_blob_encryption_key_id
+            .iter()
+            .map(|id| format!("--persist-blob-encryption-key-id={}", id))
+            .collect()
+    }
+}
+
+/// A data key, in both plaintext and wrapped form.
+struct DataKey {
+    kek_id: String,
+    key: [u8; KEY_LEN],
+    wrapped: Vec<u8>,
+}
+
+impl Debug for DataKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DataKey")
+            .field("kek_id", &self.kek_id)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A [Blob] that encrypts batch parts on their way in and decrypts them on
+/// their way out.
+#[derive(Debug)]
+pub(crate) struct EncryptedBlob {
+    blob: Arc<dyn Blob + Send + Sync>,
+    keks: Arc<KeyEncryptionKeys>,
+    // Data keys are generated lazily, one per shard, the first time this
+    // process writes to it.
+    data_keys: Mutex<HashMap<ShardId, Arc<DataKey>>>,
+}
+
+impl EncryptedBlob {
+    pub fn new(blob: Arc<dyn Blob + Send + Sync>, keks: Arc<KeyEncryptionKeys>) -> Self {
+        EncryptedBlob {
+            blob,
+            keks,
+            data_keys: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the shard of `key`, if it's a batch part.
+    fn batch_shard(key: &str) -> Option<ShardId> {
+        match BlobKey::parse_ids(key) {
+            Ok((shard_id, PartialBlobKey::Batch(_, _))) => Some(shard_id),
+            _ => None,
+        }
+    }
+
+    fn data_key(&self, shard_id: ShardId) -> Result<Arc<DataKey>, anyhow::Error> {
+        let mut data_keys = self.data_keys.lock().expect("lock poisoned");
+        if let Some(data_key) = data_keys.get(&shard_id) {
+            return Ok(Arc::clone(data_key));
+        }
+        let mut key = [0u8; KEY_LEN];
+        openssl::rand::rand_bytes(&mut key)?;
+        let kek_id = self.keks.current.clone();
+        let kek = self.keks.get(&kek_id)?;
+        // Bind the wrapped key to its shard, so it can't be transplanted.
+        let wrapped = seal(kek, shard_id.to_string().as_bytes(), &key)?;
+        let data_key = Arc::new(DataKey {
+            kek_id,
+            key,
+            wrapped,
+        });
+        data_keys.insert(shard_id, Arc::clone(&data_key));
+        Ok(data_key)
+    }
+}
+
+#[async_trait]
+impl Blob for EncryptedBlob {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, ExternalError> {
+        let value = self.blob.get(key).await?;
+        let (Some(shard_id), Some(value)) = (Self::batch_shard(key), value) else {
+            return Ok(value);
+        };
+        let keks = Arc::clone(&self.keks);
+        let blob_key = key.to_owned();
+        let value = mz_ore::task::spawn_blocking(
+            || "persist::encryption::decrypt",
+            move || decrypt(&keks, shard_id, &blob_key, value),
+        )
+        .await?;
+        match value {
+            Ok(value) => Ok(Some(value)),
+            // A missing or wrong key, or corrupt ciphertext, won't go away on
+            // a retry, and callers retry blob errors indefinitely, so halt
+            // rather than hang.
+            Err(err) => {
+                let err = ExternalError::from(Determinate::new(
+                    err.context(format!("failed to decrypt blob {}", key)),
+                ));
+                halt!(
+                    "{:#}; check that the persist blob encryption keys include the key it was written with",
+                    err
+                )
+            }
+        }
+    }
+
+    async fn list_keys_and_metadata(
+        &self,
+        key_prefix: &str,
+        f: &mut (dyn FnMut(BlobMetadata) + Send + Sync),
+    ) -> Result<(), ExternalError> {
+        self.blob.list_keys_and_metadata(key_prefix, f).await
+    }
+
+    async fn set(&self, key: &str, value: Bytes, atomic: Atomicity) -> Result<(), ExternalError> {
+        let Some(shard_id) = Self::batch_shard(key) else {
+            return self.blob.set(key, value, atomic).await;
+        };
+        let data_key = self.data_key(shard_id)?;
+        let aad = key.to_owned();
+        let value = mz_ore::task::spawn_blocking(
+            || "persist::encryption::encrypt",
+            move || encrypt(&data_key, &aad, &value),
+        )
+        .await??;
+        self.blob.set(key, Bytes::from(value), atomic).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<Option<usize>, ExternalError> {
+        self.blob.delete(key).await
+    }
+}
+
+/// Encrypts `plaintext` with `key`, returning the nonce, ciphertext, and tag.
+fn seal(key: &[u8; KEY_LEN], aad: &[u8], plaintext: &[u8]) -> Result<Vec<u8>, anyhow::Error> {
+    let mut nonce = [0u8; NONCE_LEN];
+    openssl::rand::rand_bytes(&mut nonce)?;
+    let mut tag = [0u8; TAG_LEN];
+    let ciphertext = encrypt_aead(
+        Cipher::aes_256_gcm(),
+        key,
+        Some(&nonce),
+        aad,
+        plaintext,
+        &mut tag,
+    )?;
+    let mut ret = Vec::with_capacity(NONCE_LEN + ciphertext.len() + TAG_LEN);
+    ret.extend_from_slice(&nonce);
+    ret.extend_from_slice(&ciphertext);
+    ret.extend_from_slice(&tag);
+    Ok(ret)
+}
+
+/// The inverse of [seal].
+fn open(key: &[u8; KEY_LEN], aad: &[u8], sealed: &[u8]) -> Result<Vec<u8>, anyhow::Error> {
+    if sealed.len() < NONCE_LEN + TAG_LEN {
+        bail!("sealed data too short: {} bytes", sealed.len());
+    }
+    let (nonce, rest) = sealed.split_at(NONCE_LEN);
+    let (ciphertext, tag) = rest.split_at(rest.len() - TAG_LEN);
+    let plaintext = decrypt_aead(
+        Cipher::aes_256_gcm(),
+        key,
+        Some(nonce),
+        aad,
+        ciphertext,
+        tag,
+    )
+    .map_err(|err| anyhow!("failed to decrypt: {}", err))?;
+    Ok(plaintext)
+}
+
+/// Encodes an encrypted blob as:
+///
+/// - [MAGIC]
+/// - The length of the key-encryption key id (u16 BE) and the id itself.
+/// - The length of the wrapped data key (u16 BE) and the wrapped key itself.
+/// - The sealed contents of the blob, authenticated with its blob key.
+fn encrypt(data_key: &DataKey, blob_key: &str, value: &[u8]) -> Result<Vec<u8>, anyhow::Error> {
+    let sealed = seal(&data_key.key, blob_key.as_bytes(), value)?;
+    let kek_id_len = u16::try_from(data_key.kek_id.len())?;
+    let wrapped_len = u16::try_from(data_key.wrapped.len())?;
+    let mut ret = Vec::with_capacity(
+        MAGIC.len() + 2 + data_key.kek_id.len() + 2 + data_key.wrapped.len() + sealed.len(),
+    );
+    ret.extend_from_slice(MAGIC);
+    ret.extend_from_slice(&kek_id_len.to_be_bytes());
+    ret.extend_from_slice(data_key.kek_id.as_bytes());
+    ret.extend_from_slice(&wrapped_len.to_be_bytes());
+    ret.extend_from_slice(&data_key.wrapped);
+    ret.extend_from_slice(&sealed);
+    Ok(ret)
+}
+
+/// Returns whether the given blob contents were written by [encrypt].
+pub(crate) fn is_encrypted(value: &[u8]) -> bool {
+    value.starts_with(MAGIC)
+}
+
+/// The inverse of [encrypt]. Blobs without [MAGIC] are returned unchanged.
+fn decrypt(
+    keks: &KeyEncryptionKeys,
+    shard_id: ShardId,
+    blob_key: &str,
+    value: Vec<u8>,
+) -> Result<Vec<u8>, anyhow::Error> {
+    let Some(buf) = value.strip_prefix(MAGIC.as_slice()) else {
+        return Ok(value);
+    };
+    fn read_len_prefixed<'a>(buf: &mut &'a [u8]) -> Result<&'a [u8], anyhow::Error> {
+        if buf.len() < 2 {
+            bail!("truncated encrypted blob header");
+        }
+        let len = usize::from(u16::from_be_bytes([buf[0], buf[1]]));
+        if buf.len() < 2 + len {
+            bail!("truncated encrypted blob header");
+        }
+        let ret = &buf[2..2 + len];
+        *buf = &buf[2 + len..];
+        Ok(ret)
+    }
+    let mut buf = buf;
+    let kek_id = std::str::from_utf8(read_len_prefixed(&mut buf)?)?;
+    let wrapped = read_len_prefixed(&mut buf)?;
+    let kek = keks.get(kek_id)?;
+    let key = open(kek, shard_id.to_string().as_bytes(), wrapped)?;
+    let key = <[u8; KEY_LEN]>::try_from(key.as_slice())
+        .map_err(|_| anyhow!("invalid data key length: {}", key.len()))?;
+    open(&key, blob_key.as_bytes(), buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::AssertUnwindSafe;
+    use std::sync::atomic::Ordering;
+
+    use futures::FutureExt;
+    use mz_persist::location::SeqNo;
+    use mz_persist::mem::{MemBlob, MemBlobConfig};
+
+    use crate::internal::paths::{PartId, PartialBatchKey, PartialRollupKey, RollupId};
+    use crate::write::WriterId;
+
+    use super::*;
+
+    fn keks(keys: &[(&str, u8)]) -> Arc<KeyEncryptionKeys> {
+        let keys = keys
+            .iter()
+            .map(|(id, byte)| (id.to_string(), vec![*byte; KEY_LEN]));
+        Arc::new(KeyEncryptionKeys::new(keys).expect("valid keys"))
+    }
+
+    #[tokio::test]
+    async fn encrypted_blob() {
+        let shard_id = ShardId::new();
+        let part_key = PartialBatchKey::new(&WriterId::new(), &PartId::new()).complete(&shard_id);
+        let rollup_key = PartialRollupKey::new(SeqNo(1), &RollupId::new()).complete(&shard_id);
+        let value = Bytes::from("row data");
+
+        let mem: Arc<dyn Blob + Send + Sync> = Arc::new(MemBlob::open(MemBlobConfig::default()));
+        let blob = EncryptedBlob::new(Arc::clone(&mem), keks(&[("k1", 1)]));
+        for key in [&part_key, &rollup_key] {
+            blob.set(key, value.clone(), Atomicity::RequireAtomic)
+                .await
+                .expect("set");
+            assert_eq!(blob.get(key).await.expect("get"), Some(value.to_vec()));
+        }
+
+        // Parts are encrypted at rest, but rollups are not.
+        let raw = mem.get(&part_key).await.expect("get").expect("part");
+        assert!(raw.starts_with(MAGIC));
+        assert!(!raw.windows(value.len()).any(|x| x == value.as_ref()));
+        let raw = mem.get(&rollup_key).await.expect("get").expect("rollup");
+        assert_eq!(raw, value.to_vec());
+
+        // After a rotation, old parts are still readable as long as the old key
+        // is around, and new parts are written under the new one.
+        let rotated = EncryptedBlob::new(Arc::clone(&mem), keks(&[("k2", 2), ("k1", 1)]));
+        assert_eq!(
+            rotated.get(&part_key).await.expect("get"),
+            Some(value.to_vec())
+        );
+        let new_key = PartialBatchKey::new(&WriterId::new(), &PartId::new()).complete(&shard_id);
+        rotated
+            .set(&new_key, value.clone(), Atomicity::RequireAtomic)
+            .await
+            .expect("set");
+        let raw = mem.get(&new_key).await.expect("get").expect("part");
+        assert!(decrypt(&keks(&[("k1", 1)]), shard_id, &new_key, raw).is_err());
+        let raw = mem.get(&part_key).await.expect("get").expect("part");
+        assert!(decrypt(&keks(&[("k2", 2)]), shard_id, &part_key, raw).is_err());
+
+        // Parts written before encryption was enabled are passed through.
+        let plain_key = PartialBatchKey::new(&WriterId::new(), &PartId::new()).complete(&shard_id);
+        mem.set(&plain_key, value.clone(), Atomicity::RequireAtomic)
+            .await
+            .expect("set");
+        assert_eq!(
+            blob.get(&plain_key).await.expect("get"),
+            Some(value.to_vec())
+        );
+
+        // Ciphertext is bound to its blob key.
+        let moved_key = PartialBatchKey::new(&WriterId::new(), &PartId::new()).complete(&shard_id);
+        let raw = mem.get(&part_key).await.expect("get").expect("part");
+        mem.set(
+            &moved_key,
+            Bytes::from(raw.clone()),
+            Atomicity::RequireAtomic,
+        )
+        .await
+        .expect("set");
+        assert!(decrypt(&keks(&[("k1", 1)]), shard_id, &moved_key, raw).is_err());
+
+        // Reading a part that can't be decrypted halts, rather than returning
+        // an error that would be retried forever.
+        mz_ore::process::PANIC_ON_HALT.store(true, Ordering::SeqCst);
+        let new_only = EncryptedBlob::new(Arc::clone(&mem), keks(&[("k2", 2)]));
+        let res = AssertUnwindSafe(new_only.get(&part_key))
+            .catch_unwind()
+            .await;
+        assert!(res.is_err());
+    }
+}
@@ -9,6 +9,7 @@
 
 //! Fetching batches of data from persist's backing store
 
+use std::collections::BTreeSet;
 use std::fmt::Debug;
 use std::marker::PhantomData;
 use std::sync::Arc;
@@ -26,6 +27,7 @@ use tracing::{debug_span, trace_span, Instrument};
 
 use mz_persist::indexed::encoding::BlobTraceBatchPart;
 use mz_persist::location::{Blob, SeqNo};
+use mz_persist_types::stats::PartStats;
 use mz_persist_types::{Codec, Codec64};
 
 use crate::error::InvalidUsage;
@@ -33,7 +35,7 @@ use crate::internal::machine::retry_external;
 use crate::internal::metrics::{Metrics, ReadMetrics};
 use crate::internal::paths::PartialBatchKey;
 use crate::read::{LeasedReaderId, ReadHandle};
-use crate::ShardId;
+use crate::{PersistConfig, ShardId};
 
 /// Capable of fetching [`LeasedBatchPart`] while not holding any capabilities.
 #[derive(Debug)]
@@ -48,6 +50,9 @@ where
     pub(crate) blob: Arc<dyn Blob + Send + Sync>,
     pub(crate) metrics: Arc<Metrics>,
     pub(crate) shard_id: ShardId,
+    pub(crate) key_projection: Option<BTreeSet<String>>,
+    pub(crate) cfg: PersistConfig,
+    pub(crate) part_filter: Option<PartFilter>,
 
     // Ensures that `BatchFetcher` is of the same type as the `ReadHandle` it's
     // derived from.
@@ -66,12 +71,35 @@ where
             blob: Arc::clone(&handle.blob),
             metrics: Arc::clone(&handle.metrics),
             shard_id: handle.machine.shard_id(),
+            key_projection: None,
+            cfg: handle.cfg.clone(),
+            part_filter: None,
             _phantom: PhantomData,
         };
         handle.expire().await;
         b
     }
 
+    /// Only decodes the named key columns of parts whose keys were split into
+    /// columns by their codec. See [Codec::decode_columns] for how keys with
+    /// missing columns are represented.
+    ///
+    /// Parts whose keys weren't split into columns are always decoded in full.
+    pub fn set_key_projection(&mut self, key_projection: Option<BTreeSet<String>>) {
+        self.key_projection = key_projection;
+    }
+
+    /// Skips fetching parts whose [PartStats] are rejected by `part_filter`.
+    ///
+    /// This is only consulted while [DynamicConfig::stats_filter_enabled] is
+    /// set. A skipped part is returned as a [FetchedPart] without any updates,
+    /// so it's still on the caller to hand the lease back as usual.
+    ///
+    /// [DynamicConfig::stats_filter_enabled]: crate::cfg::DynamicConfig::stats_filter_enabled
+    pub fn set_part_filter(&mut self, part_filter: Option<PartFilter>) {
+        self.part_filter = part_filter;
+    }
+
     /// Takes a [`SerdeLeasedBatchPart`] into a [`LeasedBatchPart`].
     pub fn leased_part_from_exchangeable(&self, x: SerdeLeasedBatchPart) -> LeasedBatchPart<T> {
         LeasedBatchPart::from(x, Arc::clone(&self.metrics))
@@ -105,12 +133,42 @@ where
             Arc::clone(&self.metrics),
             &self.metrics.read.batch_fetcher,
             None,
+            self.key_projection.as_ref(),
+            self.part_filter
+                .as_ref()
+                .filter(|_| self.cfg.dynamic.stats_filter_enabled()),
         )
         .await;
         (part, Ok(fetched_part))
     }
 }
 
+/// A predicate over the [PartStats] of a part, which returns false if the
+/// part can't contain any updates of interest to the reader.
+#[derive(Clone)]
+pub struct PartFilter(Arc<dyn Fn(&PartStats) -> bool + Send + Sync>);
+
+impl PartFilter {
+    /// Returns a new [PartFilter] that uses `f` to decide if a part should be
+    /// fetched.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&PartStats) -> bool + Send + Sync + 'static,
+    {
+        PartFilter(Arc::new(f))
+    }
+
+    fn should_fetch(&self, stats: &PartStats) -> bool {
+        (self.0)(stats)
+    }
+}
+
+impl Debug for PartFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PartFilter").finish_non_exhaustive()
+    }
+}
+
 #[derive(Debug, Clone)]
 enum FetchBatchFilter<T> {
     Snapshot {
@@ -159,12 +217,18 @@ impl<T: Timestamp + Lattice> FetchBatchFilter<T> {
 ///
 /// Note to check the `LeasedBatchPart` documentation for how to handle the
 /// returned value.
+///
+/// If `part_filter` rejects the part's stats, the blob isn't fetched at all
+/// and the returned [FetchedPart] contains no updates. Parts without stats are
+/// always fetched.
 pub(crate) async fn fetch_leased_part<K, V, T, D>(
     part: LeasedBatchPart<T>,
     blob: &(dyn Blob + Send + Sync),
     metrics: Arc<Metrics>,
     read_metrics: &ReadMetrics,
     reader_id: Option<&LeasedReaderId>,
+    key_projection: Option<&BTreeSet<String>>,
+    part_filter: Option<&PartFilter>,
 ) -> (LeasedBatchPart<T>, FetchedPart<K, V, T, D>)
 where
     K: Debug + Codec,
@@ -184,13 +248,40 @@ where
         }
     };
 
-    let encoded_part = fetch_batch_part(
+    if let Some(part_filter) = part_filter {
+        let should_fetch = part
+            .stats
+            .as_ref()
+            .map_or(true, |x| part_filter.should_fetch(x));
+        let bytes = u64::cast_from(part.encoded_size_bytes);
+        if !should_fetch {
+            metrics.stats.parts_filtered_count.inc();
+            metrics.stats.parts_filtered_bytes.inc_by(bytes);
+            let empty = BlobTraceBatchPart {
+                desc: part.desc.clone(),
+                index: 0,
+                updates: Vec::new(),
+            };
+            let fetched_part = FetchedPart {
+                ts_filter,
+                part: EncodedPart::new(&part.key, part.desc.clone(), empty),
+                metrics,
+                _phantom: PhantomData,
+            };
+            return (part, fetched_part);
+        }
+        metrics.stats.parts_fetched_count.inc();
+        metrics.stats.parts_fetched_bytes.inc_by(bytes);
+    }
+
+    let encoded_part = fetch_batch_part::<K, T>(
         &part.shard_id,
         blob,
         &metrics,
         read_metrics,
         &part.key,
         &part.desc,
+        key_projection,
     )
     .await
     .unwrap_or_else(|err| {
@@ -220,15 +311,22 @@ where
     (part, fetched_part)
 }
 
-pub(crate) async fn fetch_batch_part<T>(
+/// Fetches and decodes the batch part at `key`, whose keys are encoded with
+/// `K`.
+///
+/// If `key_projection` is set, it's used to only decode some of the key
+/// columns of structured parts (see [BlobTraceBatchPart::decode_structured]).
+pub(crate) async fn fetch_batch_part<K, T>(
     shard_id: &ShardId,
     blob: &(dyn Blob + Send + Sync),
     metrics: &Metrics,
     read_metrics: &ReadMetrics,
     key: &PartialBatchKey,
     registered_desc: &Description<T>,
+    key_projection: Option<&BTreeSet<String>>,
 ) -> Result<EncodedPart<T>, anyhow::Error>
 where
+    K: Codec,
     T: Timestamp + Lattice + Codec64,
 {
     let now = Instant::now();
@@ -258,7 +356,7 @@ where
         let part = metrics
             .codecs
             .batch
-            .decode(|| BlobTraceBatchPart::decode(&value))
+            .decode(|| BlobTraceBatchPart::decode_structured::<K>(&value, key_projection))
             .map_err(|err| anyhow!("couldn't decode batch at key {}: {}", key, err))
             // We received a State that we couldn't decode. This could happen if
             // persist messes up backward/forward compatibility, if the durable
@@ -336,6 +434,7 @@ where
     pub(crate) desc: Description<T>,
     pub(crate) key: PartialBatchKey,
     pub(crate) encoded_size_bytes: usize,
+    pub(crate) stats: Option<PartStats>,
     /// The `SeqNo` from which this part originated; we track this value as
     /// long as necessary to ensure the `SeqNo` isn't garbage collected while a
     /// read still depends on it.
@@ -364,6 +463,7 @@ where
             since: self.desc.since().iter().map(T::encode).collect(),
             key: self.key.clone(),
             encoded_size_bytes: self.encoded_size_bytes,
+            stats: self.stats.clone(),
             leased_seqno: self.leased_seqno,
             reader_id: self.reader_id.clone(),
         };
@@ -394,6 +494,12 @@ where
     pub fn encoded_size_bytes(&self) -> usize {
         self.encoded_size_bytes
     }
+
+    /// Aggregate statistics about the data in this part, if any were recorded
+    /// when it was written.
+    pub fn stats(&self) -> Option<&PartStats> {
+        self.stats.as_ref()
+    }
 }
 
 impl<T> Drop for LeasedBatchPart<T>
@@ -585,6 +691,7 @@ pub struct SerdeLeasedBatchPart {
     since: Vec<[u8; 8]>,
     key: PartialBatchKey,
     encoded_size_bytes: usize,
+    stats: Option<PartStats>,
     leased_seqno: Option<SeqNo>,
     reader_id: LeasedReaderId,
 }
@@ -611,6 +718,7 @@ impl<T: Timestamp + Codec64> LeasedBatchPart<T> {
             ),
             key: x.key,
             encoded_size_bytes: x.encoded_size_bytes,
+            stats: x.stats,
             leased_seqno: x.leased_seqno,
             reader_id: x.reader_id,
         }
@@ -0,0 +1,465 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A size-bounded, local disk cache of batch parts.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Debug;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use mz_ore::cast::CastFrom;
+use mz_persist::location::{Atomicity, Blob, BlobMetadata, ExternalError};
+use tracing::{debug, warn};
+
+use crate::internal::metrics::BlobCacheMetrics;
+use crate::internal::paths::{BlobKey, PartialBlobKey};
+
+/// The suffix of files that are still being written. Any of these found on
+/// startup were left behind by a crash and are removed.
+const TMP_SUFFIX: &str = ".tmp";
+
+/// A [Blob] that serves fetches of batch parts from a [DiskCache] when
+/// possible, falling back to the wrapped [Blob].
+///
+/// This is safe because a batch part is never modified once it has been
+/// written, so a cached copy can't go stale. Rollups and anything else that
+/// isn't a batch part pass straight through to the wrapped [Blob].
+#[derive(Debug)]
+pub struct DiskCacheBlob {
+    blob: Arc<dyn Blob + Send + Sync>,
+    cache: Arc<DiskCache>,
+}
+
+impl DiskCacheBlob {
+    pub fn new(blob: Arc<dyn Blob + Send + Sync>, cache: Arc<DiskCache>) -> Self {
+        DiskCacheBlob { blob, cache }
+    }
+
+    fn is_cacheable(key: &str) -> bool {
+        matches!(
+            BlobKey::parse_ids(key),
+            Ok((_, PartialBlobKey::Batch(_, _)))
+        )
+    }
+}
+
+#[async_trait]
+impl Blob for DiskCacheBlob {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, ExternalError> {
+        if !Self::is_cacheable(key) {
+            return self.blob.get(key).await;
+        }
+        if let Some(value) = self.cache.get(key).await {
+            return Ok(Some(value));
+        }
+        match self.blob.get(key).await? {
+            Some(value) => Ok(Some(self.cache.insert(key, value).await)),
+            None => Ok(None),
+        }
+    }
+
+    async fn list_keys_and_metadata(
+        &self,
+        key_prefix: &str,
+        f: &mut (dyn FnMut(BlobMetadata) + Send + Sync),
+    ) -> Result<(), ExternalError> {
+        self.blob.list_keys_and_metadata(key_prefix, f).await
+    }
+
+    async fn set(&self, key: &str, value: Bytes, atomic: Atomicity) -> Result<(), ExternalError> {
+        self.blob.set(key, value, atomic).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<Option<usize>, ExternalError> {
+        // Remove the cached copy first, so a concurrent fetch can't serve it
+        // after the part is gone.
+        self.cache.remove(key).await;
+        self.blob.delete(key).await
+    }
+}
+
+/// A directory of cached blobs, bounded in total size by evicting the least
+/// recently used entries.
+///
+/// The cache survives restarts: any entries found in the directory when it is
+/// opened are indexed in order of their modification time.
+#[derive(Debug)]
+pub struct DiskCache {
+    dir: PathBuf,
+    max_size_bytes: usize,
+    metrics: BlobCacheMetrics,
+    state: Mutex<LruState>,
+    next_tmp_id: AtomicU64,
+}
+
+impl DiskCache {
+    /// Opens the cache in `dir`, creating the directory if necessary.
+    pub async fn open(
+        dir: PathBuf,
+        max_size_bytes: usize,
+        metrics: BlobCacheMetrics,
+    ) -> Result<Self, ExternalError> {
+        let scan_dir = dir.clone();
+        let existing = mz_ore::task::spawn_blocking(
+            || "persist::blob_cache::open",
+            move || scan_dir_entries(&scan_dir),
+        )
+        .await??;
+
+        let mut state = LruState::default();
+        for (key, size) in existing {
+            state.insert(key, size);
+        }
+        let evicted = state.evict(max_size_bytes);
+        metrics.size_bytes.set(u64::cast_from(state.size_bytes));
+        let cache = DiskCache {
+            dir,
+            max_size_bytes,
+            metrics,
+            state: Mutex::new(state),
+            next_tmp_id: AtomicU64::new(0),
+        };
+        cache.remove_files(evicted).await;
+        Ok(cache)
+    }
+
+    /// Returns the cached value of `key`, if any.
+    async fn get(&self, key: &str) -> Option<Vec<u8>> {
+        let expected_len = self.state.lock().expect("lock poisoned").touch(key);
+        let Some(expected_len) = expected_len else {
+            self.metrics.misses.inc();
+            return None;
+        };
+
+        let path = self.path(key);
+        let res = mz_ore::task::spawn_blocking(
+            || "persist::blob_cache::read",
+            move || std::fs::read(path),
+        )
+        .await;
+        match res {
+            Ok(Ok(value)) if value.len() == expected_len => {
+                self.metrics.hits.inc();
+                self.metrics.hit_bytes.inc_by(u64::cast_from(expected_len));
+                return Some(value);
+            }
+            Ok(Ok(value)) => warn!(
+                "blob cache entry {} had len {} but expected {}, refetching",
+                key,
+                value.len(),
+                expected_len
+            ),
+            Ok(Err(err)) => warn!(
+                "failed to read blob cache entry {}, refetching: {}",
+                key, err
+            ),
+            Err(err) => warn!(
+                "failed to read blob cache entry {}, refetching: {}",
+                key, err
+            ),
+        }
+        self.remove(key).await;
+        self.metrics.misses.inc();
+        None
+    }
+
+    /// Writes `value` to the cache as the contents of `key`, evicting other
+    /// entries as necessary, and hands `value` back.
+    ///
+    /// Failing to write the entry is logged but otherwise ignored.
+    async fn insert(&self, key: &str, value: Vec<u8>) -> Vec<u8> {
+        let len = value.len();
+        if len > self.max_size_bytes {
+            return value;
+        }
+
+        let path = self.path(key);
+        let tmp_id = self.next_tmp_id.fetch_add(1, Ordering::Relaxed);
+        let mut tmp_path = path.clone().into_os_string();
+        tmp_path.push(format!(".{}{}", tmp_id, TMP_SUFFIX));
+        let tmp_path = PathBuf::from(tmp_path);
+        let (value, res) = mz_ore::task::spawn_blocking(
+            || "persist::blob_cache::write",
+            move || {
+                let res = std::fs::write(&tmp_path, &value)
+                    .and_then(|()| std::fs::rename(&tmp_path, &path));
+                if res.is_err() {
+                    let _ = std::fs::remove_file(&tmp_path);
+                }
+                (value, res)
+            },
+        )
+        .await
+        .expect("blob cache write panicked");
+        if let Err(err) = res {
+            self.metrics.write_failures.inc();
+            warn!("failed to write blob cache entry {}: {}", key, err);
+            return value;
+        }
+
+        let evicted = {
+            let mut state = self.state.lock().expect("lock poisoned");
+            state.insert(key.to_owned(), len);
+            let evicted = state.evict(self.max_size_bytes);
+            self.metrics
+                .size_bytes
+                .set(u64::cast_from(state.size_bytes));
+            evicted
+        };
+        self.metrics.evictions.inc_by(u64::cast_from(evicted.len()));
+        self.remove_files(evicted).await;
+        value
+    }
+
+    /// Removes the entry for `key`, if any.
+    async fn remove(&self, key: &str) {
+        let removed = {
+            let mut state = self.state.lock().expect("lock poisoned");
+            let removed = state.remove(key);
+            self.metrics
+                .size_bytes
+                .set(u64::cast_from(state.size_bytes));
+            removed
+        };
+        if removed.is_some() {
+            self.remove_files(vec![key.to_owned()]).await;
+        }
+    }
+
+    async fn remove_files(&self, keys: Vec<String>) {
+        if keys.is_empty() {
+            return;
+        }
+        let paths = keys.iter().map(|key| self.path(key)).collect::<Vec<_>>();
+        let res = mz_ore::task::spawn_blocking(
+            || "persist::blob_cache::remove",
+            move || {
+                for path in paths {
+                    match std::fs::remove_file(&path) {
+                        Ok(()) => {}
+                        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+                        Err(err) => debug!("failed to remove {}: {}", path.display(), err),
+                    }
+                }
+            },
+        )
+        .await;
+        if let Err(err) = res {
+            warn!("failed to remove blob cache entries: {}", err);
+        }
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(key_to_file_name(key))
+    }
+}
+
+/// Blob keys are `/`-separated ids, none of which contain a `_`, so they can be
+/// flattened into file names reversibly.
+fn key_to_file_name(key: &str) -> String {
+    key.replace('/', "_")
+}
+
+fn file_name_to_key(name: &str) -> String {
+    name.replace('_', "/")
+}
+
+/// Removes every subdirectory of `root` other than `keep`.
+///
+/// Each blob location caches into its own subdirectory of the configured cache
+/// directory. Before this process has used any of them, the others were left
+/// behind by a previous process, e.g. one that used a different blob location,
+/// and would otherwise take up disk space forever.
+pub(crate) async fn remove_stale_cache_dirs(root: PathBuf, keep: PathBuf) {
+    let res = mz_ore::task::spawn_blocking(
+        || "persist::blob_cache::remove_stale_dirs",
+        move || -> Result<(), std::io::Error> {
+            let entries = match std::fs::read_dir(&root) {
+                Ok(entries) => entries,
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+                Err(err) => return Err(err),
+            };
+            for entry in entries {
+                let entry = entry?;
+                let path = entry.path();
+                if !entry.file_type()?.is_dir() || path == keep {
+                    continue;
+                }
+                debug!("removing stale blob cache dir {}", path.display());
+                if let Err(err) = std::fs::remove_dir_all(&path) {
+                    warn!(
+                        "failed to remove stale blob cache dir {}: {}",
+                        path.display(),
+                        err
+                    );
+                }
+            }
+            Ok(())
+        },
+    )
+    .await;
+    match res {
+        Ok(Ok(())) => {}
+        Ok(Err(err)) => warn!("failed to remove stale blob cache dirs: {}", err),
+        Err(err) => warn!("failed to remove stale blob cache dirs: {}", err),
+    }
+}
+
+/// Returns the keys and sizes of the entries in `dir`, least recently modified
+/// first, removing any partially written ones.
+fn scan_dir_entries(dir: &Path) -> Result<Vec<(String, usize)>, std::io::Error> {
+    std::fs::create_dir_all(dir)?;
+    let mut entries = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        if !metadata.is_file() {
+            continue;
+        }
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if name.ends_with(TMP_SUFFIX) {
+            std::fs::remove_file(entry.path())?;
+            continue;
+        }
+        let key = file_name_to_key(&name);
+        entries.push((metadata.modified()?, key, usize::cast_from(metadata.len())));
+    }
+    entries.sort();
+    Ok(entries
+        .into_iter()
+        .map(|(_, key, size)| (key, size))
+        .collect())
+}
+
+/// The in-memory index of a [DiskCache].
+#[derive(Debug, Default)]
+struct LruState {
+    /// The size and most recent use of each entry.
+    entries: HashMap<String, (usize, u64)>,
+    /// The entries, ordered from least to most recently used.
+    by_use: BTreeMap<u64, String>,
+    next_use: u64,
+    size_bytes: usize,
+}
+
+impl LruState {
+    /// Marks `key` as used, returning its size if it is present.
+    fn touch(&mut self, key: &str) -> Option<usize> {
+        let use_id = self.next_use;
+        let (size, last_use) = self.entries.get_mut(key)?;
+        let key = self.by_use.remove(&*last_use).expect("entry is indexed");
+        *last_use = use_id;
+        self.by_use.insert(use_id, key);
+        self.next_use += 1;
+        Some(*size)
+    }
+
+    fn insert(&mut self, key: String, size: usize) {
+        self.remove(&key);
+        let use_id = self.next_use;
+        self.next_use += 1;
+        self.entries.insert(key.clone(), (size, use_id));
+        self.by_use.insert(use_id, key);
+        self.size_bytes += size;
+    }
+
+    fn remove(&mut self, key: &str) -> Option<usize> {
+        let (size, last_use) = self.entries.remove(key)?;
+        self.by_use.remove(&last_use);
+        self.size_bytes -= size;
+        Some(size)
+    }
+
+    /// Removes the least recently used entries until the total size is at
+    /// most `max_size_bytes`, returning the removed keys.
+    fn evict(&mut self, max_size_bytes: usize) -> Vec<String> {
+        let mut evicted = Vec::new();
+        while self.size_bytes > max_size_bytes {
+            let Some(&last_use) = self.by_use.keys().next() else {
+                break;
+            };
+            let key = self.by_use.remove(&last_use).expect("entry exists");
+            let (size, _) = self.entries.remove(&key).expect("entry is indexed");
+            self.size_bytes -= size;
+            evicted.push(key);
+        }
+        evicted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_ore::metrics::MetricsRegistry;
+    use mz_persist::mem::{MemBlob, MemBlobConfig};
+
+    use crate::internal::metrics::Metrics;
+    use crate::internal::paths::{PartId, PartialBatchKey};
+    use crate::write::WriterId;
+    use crate::{PersistConfig, ShardId};
+
+    use super::*;
+
+    fn batch_key() -> String {
+        PartialBatchKey::new(&WriterId::new(), &PartId::new())
+            .complete(&ShardId::new())
+            .to_string()
+    }
+
+    #[tokio::test]
+    async fn disk_cache_blob() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let metrics = Metrics::new(&PersistConfig::new_for_tests(), &MetricsRegistry::new());
+        let metrics = metrics.blob_cache;
+        let blob: Arc<dyn Blob + Send + Sync> = Arc::new(MemBlob::open(MemBlobConfig::default()));
+        let cache = DiskCache::open(dir.path().to_owned(), 10, metrics.clone())
+            .await
+            .expect("open cache");
+        let cached = DiskCacheBlob::new(Arc::clone(&blob), Arc::new(cache));
+
+        let (k0, k1, k2) = (batch_key(), batch_key(), batch_key());
+        for k in [&k0, &k1, &k2] {
+            blob.set(k, Bytes::from(vec![0u8; 4]), Atomicity::AllowNonAtomic)
+                .await
+                .expect("set");
+        }
+
+        // The first fetch misses and populates the cache, the second hits.
+        assert_eq!(cached.get(&k0).await.expect("get"), Some(vec![0u8; 4]));
+        assert_eq!((metrics.hits.get(), metrics.misses.get()), (0, 1));
+        assert_eq!(cached.get(&k0).await.expect("get"), Some(vec![0u8; 4]));
+        assert_eq!((metrics.hits.get(), metrics.misses.get()), (1, 1));
+
+        // Only two entries fit, so fetching a third evicts the least recently
+        // used one.
+        cached.get(&k1).await.expect("get");
+        cached.get(&k0).await.expect("get");
+        cached.get(&k2).await.expect("get");
+        assert_eq!(metrics.evictions.get(), 1);
+        assert_eq!(metrics.size_bytes.get(), 8);
+        assert!(!dir.path().join(key_to_file_name(&k1)).exists());
+
+        // Reopening the cache picks up the surviving entries.
+        let cache = DiskCache::open(dir.path().to_owned(), 10, metrics.clone())
+            .await
+            .expect("open cache");
+        let cached = DiskCacheBlob::new(Arc::clone(&blob), Arc::new(cache));
+        let hits = metrics.hits.get();
+        cached.get(&k2).await.expect("get");
+        assert_eq!(metrics.hits.get(), hits + 1);
+
+        // Deletes remove the cached copy too.
+        cached.delete(&k2).await.expect("delete");
+        assert_eq!(cached.get(&k2).await.expect("get"), None);
+        assert!(!dir.path().join(key_to_file_name(&k2)).exists());
+    }
+}
@@ -362,8 +362,15 @@ where
                 .saturating_sub(run_chunk_max_memory_usage))
                 / cfg.blob_target_size;
 
-            let batch_parts = BatchParts::new(
+            // Stats are recomputed from the compacted data, rather than
+            // merged from the inputs, so that they stay tight as updates
+            // consolidate away. Similarly, the output is written in whichever
+            // format is currently enabled, which is how a shard's existing parts
+            // get migrated to (or from) the structured format.
+            let batch_parts = BatchParts::new::<K>(
                 1 + extra_outstanding_parts,
+                cfg.should_collect_stats(),
+                cfg.dynamic.structured_parts_enabled(),
                 Arc::clone(&metrics),
                 req.shard_id,
                 writer_id.clone(),
@@ -571,7 +578,7 @@ where
 
         let mut timings = Timings::default();
 
-        start_prefetches(prefetch_budget_bytes, &mut runs, shard_id, &blob, &metrics);
+        start_prefetches::<K, T>(prefetch_budget_bytes, &mut runs, shard_id, &blob, &metrics);
 
         let all_prefetched = runs
             .iter()
@@ -585,7 +592,7 @@ where
             if let Some(part) = parts.pop_front() {
                 let start = Instant::now();
                 let mut part = part
-                    .join(shard_id, blob.as_ref(), &metrics, part_desc)
+                    .join::<K>(shard_id, blob.as_ref(), &metrics, part_desc)
                     .await?;
                 // Ideally we'd hook into start_prefetches here, too, but runs
                 // is mutable borrowed. Not the end of the world. Instead do it
@@ -605,7 +612,7 @@ where
             }
         }
 
-        start_prefetches(prefetch_budget_bytes, &mut runs, shard_id, &blob, &metrics);
+        start_prefetches::<K, T>(prefetch_budget_bytes, &mut runs, shard_id, &blob, &metrics);
 
         // repeatedly pull off the least element from our heap, refilling from the originating run
         // if needed. the heap will be exhausted only when all parts from all input runs have been
@@ -618,7 +625,7 @@ where
                 if let Some(part) = parts.pop_front() {
                     let start = Instant::now();
                     let mut part = part
-                        .join(shard_id, blob.as_ref(), &metrics, part_desc)
+                        .join::<K>(shard_id, blob.as_ref(), &metrics, part_desc)
                         .await?;
                     // start_prefetches is O(n) so calling it here is O(n^2). N
                     // is the number of things we're about to fetch over the
@@ -626,7 +633,13 @@ where
                     // got bigger problems. It might be possible to do make this
                     // overall linear, but the bookkeeping would be pretty
                     // subtle.
-                    start_prefetches(prefetch_budget_bytes, &mut runs, shard_id, &blob, &metrics);
+                    start_prefetches::<K, T>(
+                        prefetch_budget_bytes,
+                        &mut runs,
+                        shard_id,
+                        &blob,
+                        &metrics,
+                    );
                     timings.part_fetching += start.elapsed();
                     let start = Instant::now();
                     while let Some((k, v, mut t, d)) = part.next() {
@@ -894,7 +907,7 @@ impl<'a, T: Timestamp + Lattice + Codec64> CompactionPart<'a, T> {
         }
     }
 
-    async fn join(
+    async fn join<K: Codec>(
         self,
         shard_id: &ShardId,
         blob: &(dyn Blob + Send + Sync),
@@ -912,13 +925,14 @@ impl<'a, T: Timestamp + Lattice + Codec64> CompactionPart<'a, T> {
             }
             CompactionPart::Queued(part) => {
                 metrics.compaction.parts_waited.inc();
-                fetch_batch_part(
+                fetch_batch_part::<K, T>(
                     shard_id,
                     blob,
                     metrics,
                     &metrics.read.compaction,
                     &part.key,
                     part_desc,
+                    None,
                 )
                 .await
             }
@@ -926,7 +940,7 @@ impl<'a, T: Timestamp + Lattice + Codec64> CompactionPart<'a, T> {
     }
 }
 
-fn start_prefetches<T: Timestamp + Lattice + Codec64>(
+fn start_prefetches<K: Codec, T: Timestamp + Lattice + Codec64>(
     mut prefetch_budget_bytes: usize,
     runs: &mut Vec<(&Description<T>, VecDeque<CompactionPart<'_, T>>)>,
     shard_id: &ShardId,
@@ -978,13 +992,14 @@ fn start_prefetches<T: Timestamp + Lattice + Codec64>(
             let handle = spawn(
                 || "persist::compaction::prefetch",
                 async move {
-                    fetch_batch_part(
+                    fetch_batch_part::<K, T>(
                         &shard_id,
                         blob.as_ref(),
                         &metrics,
                         &metrics.read.compaction,
                         &part_key,
                         &part_desc,
+                        None,
                     )
                     .await
                 }
@@ -1082,6 +1097,7 @@ mod tests {
             .map(|encoded_size_bytes| HollowBatchPart {
                 key: PartialBatchKey("".into()),
                 encoded_size_bytes,
+                stats: None,
             })
             .collect::<Vec<_>>();
         let parse = |x: &str| {
@@ -1134,35 +1150,35 @@ mod tests {
 
         // Enough budget for none, some, and all parts of a single run
         let mut runs = parse(" 1, 1, 1");
-        start_prefetches(0, &mut runs, &shard_id, blob, metrics);
+        start_prefetches::<String, u64>(0, &mut runs, &shard_id, blob, metrics);
         assert_eq!(print(&runs), " 1, 1, 1");
 
         let mut runs = parse(" 1, 1, 1");
-        start_prefetches(1, &mut runs, &shard_id, blob, metrics);
+        start_prefetches::<String, u64>(1, &mut runs, &shard_id, blob, metrics);
         assert_eq!(print(&runs), "f1, 1, 1");
 
         let mut runs = parse(" 1, 1, 1");
-        start_prefetches(3, &mut runs, &shard_id, blob, metrics);
+        start_prefetches::<String, u64>(3, &mut runs, &shard_id, blob, metrics);
         assert_eq!(print(&runs), "f1,f1,f1");
 
         // Budget partially covers some part (which is then not prefetched)
         let mut runs = parse(" 1| 2| 2");
-        start_prefetches(4, &mut runs, &shard_id, blob, metrics);
+        start_prefetches::<String, u64>(4, &mut runs, &shard_id, blob, metrics);
         assert_eq!(print(&runs), "f1|f2| 2");
 
         // Runs of length > 1
         let mut runs = parse(" 1, 1, 1, 1| 1| 1, 1, 1");
-        start_prefetches(5, &mut runs, &shard_id, blob, metrics);
+        start_prefetches::<String, u64>(5, &mut runs, &shard_id, blob, metrics);
         assert_eq!(print(&runs), "f1,f1, 1, 1|f1|f1,f1, 1");
 
         // Some budget is already used from a previous call
         let mut runs = parse(" 1| 1|f1,f1");
-        start_prefetches(3, &mut runs, &shard_id, blob, metrics);
+        start_prefetches::<String, u64>(3, &mut runs, &shard_id, blob, metrics);
         assert_eq!(print(&runs), "f1| 1|f1,f1");
 
         // Sanity check budget has gone down (no panics)
         let mut runs = parse(" 1| 1|f9");
-        start_prefetches(1, &mut runs, &shard_id, blob, metrics);
+        start_prefetches::<String, u64>(1, &mut runs, &shard_id, blob, metrics);
         assert_eq!(print(&runs), " 1| 1|f9");
     }
 }
@@ -12,6 +12,7 @@ use std::marker::PhantomData;
 
 use differential_dataflow::lattice::Lattice;
 use differential_dataflow::trace::Description;
+use mz_persist_types::stats::{ColumnStats, PartStats, StructStats};
 use mz_persist_types::{Codec, Codec64};
 use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
 use prost::Message;
@@ -28,10 +29,11 @@ use crate::error::CodecMismatch;
 use crate::internal::paths::{PartialBatchKey, PartialRollupKey};
 use crate::internal::state::{
     CriticalReaderState, HandleDebugState, HollowBatch, HollowBatchPart, IdempotencyToken,
-    LeasedReaderState, OpaqueState, ProtoCriticalReaderState, ProtoHandleDebugState,
-    ProtoHollowBatch, ProtoHollowBatchPart, ProtoLeasedReaderState, ProtoStateDiff,
-    ProtoStateField, ProtoStateFieldDiffType, ProtoStateFieldDiffs, ProtoStateRollup, ProtoTrace,
-    ProtoU64Antichain, ProtoU64Description, ProtoWriterState, State, StateCollections, WriterState,
+    LeasedReaderState, OpaqueState, ProtoColumnBounds, ProtoColumnStats, ProtoCriticalReaderState,
+    ProtoHandleDebugState, ProtoHollowBatch, ProtoHollowBatchPart, ProtoLeasedReaderState,
+    ProtoPartStats, ProtoStateDiff, ProtoStateField, ProtoStateFieldDiffType, ProtoStateFieldDiffs,
+    ProtoStateRollup, ProtoStructStats, ProtoTrace, ProtoU64Antichain, ProtoU64Description,
+    ProtoWriterState, State, StateCollections, WriterState,
 };
 use crate::internal::state_diff::{
     ProtoStateFieldDiff, StateDiff, StateFieldDiff, StateFieldValDiff,
@@ -810,6 +812,7 @@ impl<T: Timestamp + Codec64> RustType<ProtoHollowBatch> for HollowBatch<T> {
                 .map(|key| HollowBatchPart {
                     key: PartialBatchKey(key),
                     encoded_size_bytes: 0,
+                    stats: None,
                 }),
         );
         Ok(HollowBatch {
@@ -826,6 +829,7 @@ impl RustType<ProtoHollowBatchPart> for HollowBatchPart {
         ProtoHollowBatchPart {
             key: self.key.into_proto(),
             encoded_size_bytes: self.encoded_size_bytes.into_proto(),
+            stats: self.stats.into_proto(),
         }
     }
 
@@ -833,6 +837,59 @@ impl RustType<ProtoHollowBatchPart> for HollowBatchPart {
         Ok(HollowBatchPart {
             key: proto.key.into_rust()?,
             encoded_size_bytes: proto.encoded_size_bytes.into_rust()?,
+            stats: proto.stats.into_rust()?,
+        })
+    }
+}
+
+impl RustType<ProtoPartStats> for PartStats {
+    fn into_proto(&self) -> ProtoPartStats {
+        ProtoPartStats {
+            key: Some(self.key.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: ProtoPartStats) -> Result<Self, TryFromProtoError> {
+        Ok(PartStats {
+            key: proto.key.into_rust_if_some("key")?,
+        })
+    }
+}
+
+impl RustType<ProtoStructStats> for StructStats {
+    fn into_proto(&self) -> ProtoStructStats {
+        ProtoStructStats {
+            len: self.len.into_proto(),
+            cols: self.cols.into_proto(),
+        }
+    }
+
+    fn from_proto(proto: ProtoStructStats) -> Result<Self, TryFromProtoError> {
+        Ok(StructStats {
+            len: proto.len.into_rust()?,
+            cols: proto.cols.into_rust()?,
+        })
+    }
+}
+
+impl RustType<ProtoColumnStats> for ColumnStats {
+    fn into_proto(&self) -> ProtoColumnStats {
+        ProtoColumnStats {
+            bounds: self
+                .bounds
+                .as_ref()
+                .map(|(lower, upper)| ProtoColumnBounds {
+                    lower: lower.clone(),
+                    upper: upper.clone(),
+                }),
+            nulls: self.nulls.into_proto(),
+        }
+    }
+
+    fn from_proto(proto: ProtoColumnStats) -> Result<Self, TryFromProtoError> {
+        Ok(ColumnStats {
+            bounds: proto.bounds.map(|b| (b.lower, b.upper)),
+            nulls: proto.nulls.into_rust()?,
         })
     }
 }
@@ -982,6 +1039,7 @@ mod tests {
             parts: vec![HollowBatchPart {
                 key: PartialBatchKey("a".into()),
                 encoded_size_bytes: 5,
+                stats: None,
             }],
             runs: vec![],
         };
@@ -999,6 +1057,7 @@ mod tests {
         expected.parts.push(HollowBatchPart {
             key: PartialBatchKey("b".into()),
             encoded_size_bytes: 0,
+            stats: None,
         });
         assert_eq!(<HollowBatch<u64>>::from_proto(old).unwrap(), expected);
     }
@@ -233,6 +233,11 @@ where
             if state.seqno < req.new_seqno_since {
                 state.collections.trace.map_batches(|b| {
                     for part in b.parts.iter() {
+                        // Parts shared from another shard are that shard's to
+                        // delete.
+                        if part.key.is_shared() {
+                            continue;
+                        }
                         // It's okay (expected) if the key already exists in
                         // deleteable_batch_blobs, it may have been present in
                         // previous versions of state.
@@ -1354,13 +1354,14 @@ pub mod datadriven {
                     continue;
                 }
             };
-            let mut part = fetch_batch_part(
+            let mut part = fetch_batch_part::<String, u64>(
                 &datadriven.shard_id,
                 datadriven.client.blob.as_ref(),
                 datadriven.client.metrics.as_ref(),
                 &datadriven.client.metrics.read.batch_fetcher,
                 &part.key,
                 &batch.desc,
+                None,
             )
             .await
             .expect("invalid batch part");
@@ -1504,13 +1505,14 @@ pub mod datadriven {
         let mut updates = Vec::new();
         for batch in snapshot {
             for part in batch.parts {
-                let mut part = fetch_batch_part(
+                let mut part = fetch_batch_part::<String, u64>(
                     &datadriven.shard_id,
                     datadriven.client.blob.as_ref(),
                     datadriven.client.metrics.as_ref(),
                     &datadriven.client.metrics.read.batch_fetcher,
                     &part.key,
                     &batch.desc,
+                    None,
                 )
                 .await
                 .expect("invalid batch part");
@@ -44,6 +44,8 @@ pub struct Metrics {
 
     /// Metrics for [Blob] usage.
     pub blob: BlobMetrics,
+    /// Metrics for the local disk cache of [Blob] fetches.
+    pub blob_cache: BlobCacheMetrics,
     /// Metrics for [Consensus] usage.
     pub consensus: ConsensusMetrics,
     /// Metrics of command evaluation.
@@ -61,6 +63,8 @@ pub struct Metrics {
     pub gc: GcMetrics,
     /// Metrics for leasing and automatic lease expiry.
     pub lease: LeaseMetrics,
+    /// Metrics for part statistics and filter pushdown.
+    pub stats: StatsMetrics,
     /// Metrics for various encodings and decodings.
     pub codecs: CodecsMetrics,
     /// Metrics for (incremental) state updates and fetches.
@@ -92,6 +96,7 @@ impl Metrics {
         );
         Metrics {
             blob: vecs.blob_metrics(),
+            blob_cache: BlobCacheMetrics::new(registry),
             consensus: vecs.consensus_metrics(),
             cmds: vecs.cmds_metrics(registry),
             retries: vecs.retries_metrics(),
@@ -101,6 +106,7 @@ impl Metrics {
             compaction: CompactionMetrics::new(registry),
             gc: GcMetrics::new(registry),
             lease: LeaseMetrics::new(registry),
+            stats: StatsMetrics::new(registry),
             state: StateMetrics::new(registry),
             shards: ShardsMetrics::new(registry),
             audit: UsageAuditMetrics::new(registry),
@@ -797,6 +803,83 @@ impl LeaseMetrics {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct BlobCacheMetrics {
+    pub(crate) hits: IntCounter,
+    pub(crate) hit_bytes: IntCounter,
+    pub(crate) misses: IntCounter,
+    pub(crate) evictions: IntCounter,
+    pub(crate) write_failures: IntCounter,
+    pub(crate) size_bytes: UIntGauge,
+}
+
+impl BlobCacheMetrics {
+    fn new(registry: &MetricsRegistry) -> Self {
+        BlobCacheMetrics {
+            hits: registry.register(metric!(
+                name: "mz_persist_blob_cache_hits",
+                help: "count of blob fetches served from the local disk cache",
+            )),
+            hit_bytes: registry.register(metric!(
+                name: "mz_persist_blob_cache_hit_bytes",
+                help: "total size of blob fetches served from the local disk cache",
+            )),
+            misses: registry.register(metric!(
+                name: "mz_persist_blob_cache_misses",
+                help: "count of cacheable blob fetches not found in the local disk cache",
+            )),
+            evictions: registry.register(metric!(
+                name: "mz_persist_blob_cache_evictions",
+                help: "count of blobs evicted from the local disk cache",
+            )),
+            write_failures: registry.register(metric!(
+                name: "mz_persist_blob_cache_write_failures",
+                help: "count of blobs that could not be written to the local disk cache",
+            )),
+            size_bytes: registry.register(metric!(
+                name: "mz_persist_blob_cache_size_bytes",
+                help: "total size of blobs in the local disk cache",
+            )),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct StatsMetrics {
+    pub(crate) compute_failed: IntCounter,
+    pub(crate) parts_fetched_count: IntCounter,
+    pub(crate) parts_fetched_bytes: IntCounter,
+    pub(crate) parts_filtered_count: IntCounter,
+    pub(crate) parts_filtered_bytes: IntCounter,
+}
+
+impl StatsMetrics {
+    fn new(registry: &MetricsRegistry) -> Self {
+        StatsMetrics {
+            compute_failed: registry.register(metric!(
+                name: "mz_persist_stats_compute_failed",
+                help: "count of parts written without stats because computing them failed",
+            )),
+            parts_fetched_count: registry.register(metric!(
+                name: "mz_persist_pushdown_parts_fetched_count",
+                help: "count of parts fetched by filter pushdown-aware readers",
+            )),
+            parts_fetched_bytes: registry.register(metric!(
+                name: "mz_persist_pushdown_parts_fetched_bytes",
+                help: "total size of parts fetched by filter pushdown-aware readers",
+            )),
+            parts_filtered_count: registry.register(metric!(
+                name: "mz_persist_pushdown_parts_filtered_count",
+                help: "count of parts skipped because their stats ruled out the pushed-down filter",
+            )),
+            parts_filtered_bytes: registry.register(metric!(
+                name: "mz_persist_pushdown_parts_filtered_bytes",
+                help: "total size of parts skipped because their stats ruled out the pushed-down filter",
+            )),
+        }
+    }
+}
+
 struct IncOnDrop(IntCounter);
 
 impl Drop for IncOnDrop {
@@ -54,6 +54,11 @@ impl PartId {
 /// Used to reduce the bytes needed to refer to a blob key in memory and in
 /// persistent state, all access to blobs are always within the context of an
 /// individual shard.
+///
+/// The exception is a part shared with a clone of the shard that wrote it (see
+/// [crate::admin::copy_shard]), which the clone refers to by its full
+/// [BlobKey]. Such a part is owned by the shard that wrote it: it's never
+/// deleted by the clone's garbage collection.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct PartialBatchKey(pub(crate) String);
 
@@ -62,7 +67,22 @@ impl PartialBatchKey {
         PartialBatchKey(format!("{}/{}", writer_id, part_id))
     }
 
+    /// Returns a key that refers to the part at `key` in `shard_id` from any
+    /// other shard.
+    pub fn shared(shard_id: &ShardId, key: &PartialBatchKey) -> Self {
+        PartialBatchKey(key.complete(shard_id).0)
+    }
+
+    /// Returns whether this key refers to a part owned by another shard.
+    pub fn is_shared(&self) -> bool {
+        // A partial key starts with its WriterId, a full one with its ShardId.
+        self.0.starts_with('s')
+    }
+
     pub fn complete(&self, shard_id: &ShardId) -> BlobKey {
+        if self.is_shared() {
+            return BlobKey(self.0.clone());
+        }
         BlobKey(format!("{}/{}", shard_id, self))
     }
 }
@@ -238,6 +258,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn shared_blob_key_completion() {
+        let (shard_id, writer_id, part_id) = (ShardId::new(), WriterId::new(), PartId::new());
+        let partial_key = PartialBatchKey::new(&writer_id, &part_id);
+        assert!(!partial_key.is_shared());
+        let shared_key = PartialBatchKey::shared(&shard_id, &partial_key);
+        assert!(shared_key.is_shared());
+        // A shared key completes to the same blob key from any shard.
+        assert_eq!(
+            shared_key.complete(&ShardId::new()),
+            partial_key.complete(&shard_id)
+        );
+        // Sharing a shared key is a no-op.
+        assert_eq!(
+            PartialBatchKey::shared(&ShardId::new(), &shared_key),
+            shared_key
+        );
+    }
+
     #[test]
     fn blob_key_parse() -> Result<(), String> {
         let (shard_id, writer_id, part_id) = (ShardId::new(), WriterId::new(), PartId::new());
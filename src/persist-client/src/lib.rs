@@ -14,6 +14,7 @@
 
 use std::fmt::Debug;
 use std::marker::PhantomData;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -33,7 +34,9 @@ use tracing::instrument;
 use uuid::Uuid;
 
 use crate::async_runtime::CpuHeavyRuntime;
+use crate::cfg::DynamicConfig;
 use crate::critical::{CriticalReaderId, SinceHandle};
+use crate::encryption::KeyEncryptionKeys;
 use crate::error::InvalidUsage;
 use crate::internal::compact::Compactor;
 use crate::internal::encoding::parse_id;
@@ -47,7 +50,9 @@ pub mod admin;
 pub mod async_runtime;
 pub mod batch;
 pub mod cache;
+pub mod cfg;
 pub mod critical;
+pub mod encryption;
 pub mod error;
 pub mod fetch;
 pub mod inspect;
@@ -63,6 +68,7 @@ pub use crate::internal::state::{Since, Upper};
 
 /// An implementation of the public crate interface.
 pub(crate) mod internal {
+    pub mod blob_cache;
     pub mod compact;
     pub mod encoding;
     pub mod gc;
@@ -271,6 +277,27 @@ pub struct PersistConfig {
     pub critical_downgrade_interval: Duration,
     /// Hostname of this persist user. Stored in state and used for debugging.
     pub hostname: String,
+    /// Configuration that can be changed while the process is running. This
+    /// is shared by all clones of this config.
+    pub dynamic: Arc<DynamicConfig>,
+    /// A local directory in which to cache fetched batch parts, if any.
+    ///
+    /// Batch parts are never modified once written, so cached copies can't go
+    /// stale. The cache is only enabled if `blob_cache_size_bytes` is also
+    /// non-zero.
+    ///
+    /// Each blob location is cached in its own subdirectory. Subdirectories
+    /// that aren't in use by this process are removed, so this directory
+    /// shouldn't be shared with anything else.
+    pub blob_cache_dir: Option<PathBuf>,
+    /// The maximum total size of the parts kept in `blob_cache_dir`. The
+    /// least recently used parts are evicted once this is exceeded.
+    pub blob_cache_size_bytes: usize,
+    /// The keys with which to encrypt batch parts, if any.
+    ///
+    /// Part statistics are recorded in shard metadata, which is not encrypted,
+    /// so they aren't collected while this is set.
+    pub blob_encryption_keys: Option<Arc<KeyEncryptionKeys>>,
 }
 
 // Tuning inputs:
@@ -347,6 +374,10 @@ impl PersistConfig {
             // options, where the first is always provided and the second is
             // conditionally enabled by the process orchestrator.
             hostname: std::env::var("HOSTNAME").unwrap_or_else(|_| "unknown".to_owned()),
+            dynamic: Arc::new(DynamicConfig::new()),
+            blob_cache_dir: None,
+            blob_cache_size_bytes: 0,
+            blob_encryption_keys: None,
         }
     }
 
@@ -358,11 +389,20 @@ impl PersistConfig {
 
         let mut cfg = Self::new(&DUMMY_BUILD_INFO, SYSTEM_TIME.clone());
         cfg.hostname = "tests".into();
+        cfg.dynamic.set_stats_collection_enabled(true);
+        cfg.dynamic.set_stats_filter_enabled(true);
         cfg
     }
 }
 
 impl PersistConfig {
+    /// Whether to compute and record statistics about the data in each part.
+    pub(crate) fn should_collect_stats(&self) -> bool {
+        // Stats contain bounds on the data, so they'd leak it into unencrypted
+        // shard metadata.
+        self.dynamic.stats_collection_enabled() && self.blob_encryption_keys.is_none()
+    }
+
     // Move this to a PersistConfig field when we actually have read leases.
     //
     // MIGRATION: Remove this once we remove the ReaderState <->
@@ -556,6 +596,9 @@ impl PersistClient {
             blob: Arc::clone(&self.blob),
             metrics: Arc::clone(&self.metrics),
             shard_id,
+            key_projection: None,
+            cfg: self.cfg.clone(),
+            part_filter: None,
             _phantom: PhantomData,
         };
 
@@ -830,7 +873,8 @@ mod tests {
             .await
             .expect("failed to fetch part")
             .expect("missing part");
-        let part = BlobTraceBatchPart::decode(&value).expect("failed to decode part");
+        let part = BlobTraceBatchPart::decode_structured::<K>(&value, None)
+            .expect("failed to decode part");
         let mut updates = Vec::new();
         for chunk in part.updates.iter() {
             for ((k, v), t, d) in chunk.iter() {
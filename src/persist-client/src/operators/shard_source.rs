@@ -10,6 +10,7 @@
 //! A source that reads from a persist shard.
 
 use std::any::Any;
+use std::collections::BTreeSet;
 use std::convert::Infallible;
 use std::fmt::Debug;
 use std::rc::Rc;
@@ -25,6 +26,7 @@ use mz_ore::cast::CastFrom;
 use mz_ore::collections::CollectionExt;
 use mz_ore::vec::VecExt;
 use mz_persist::location::ExternalError;
+use mz_persist_types::stats::PartStats;
 use mz_persist_types::{Codec, Codec64};
 use mz_timely_util::builder_async::{Event, OperatorBuilder as AsyncOperatorBuilder};
 use timely::dataflow::channels::pact::{Exchange, Pipeline};
@@ -38,7 +40,7 @@ use tokio::sync::{mpsc, Mutex};
 use tracing::trace;
 
 use crate::cache::PersistClientCache;
-use crate::fetch::{FetchedPart, SerdeLeasedBatchPart};
+use crate::fetch::{FetchedPart, PartFilter, SerdeLeasedBatchPart};
 use crate::{PersistLocation, ShardId};
 
 /// Creates a new source that reads from a persist shard, distributing the work
@@ -62,8 +64,19 @@ use crate::{PersistLocation, ShardId};
 /// to the empty antichain can be used. An easy easy of creating such stream is by
 /// using [`timely::dataflow::operators::generic::operator::empty`].
 ///
+/// While [DynamicConfig::stats_filter_enabled] is set, parts that recorded
+/// [PartStats] when they were written are only fetched if `should_fetch_part`
+/// returns true for those stats. Callers that push a filter down into the
+/// source can use this to skip parts that the filter would remove in their
+/// entirety. Parts without stats are always fetched.
+///
+/// If `key_projection` is set, only the named key columns of parts that store
+/// their keys in columns are decoded (see
+/// [BatchFetcher::set_key_projection](crate::fetch::BatchFetcher::set_key_projection)).
+///
 /// [advanced by]: differential_dataflow::lattice::Lattice::advance_by
-pub fn shard_source<K, V, D, G>(
+/// [DynamicConfig::stats_filter_enabled]: crate::cfg::DynamicConfig::stats_filter_enabled
+pub fn shard_source<K, V, D, F, G>(
     scope: &G,
     name: &str,
     clients: Arc<Mutex<PersistClientCache>>,
@@ -75,11 +88,14 @@ pub fn shard_source<K, V, D, G>(
     // Replace Infallible with `!` once the latter is stabilized.
     flow_control_input: &Stream<G, Infallible>,
     flow_control_max_inflight_bytes: usize,
+    should_fetch_part: F,
+    key_projection: Option<BTreeSet<String>>,
 ) -> (Stream<G, FetchedPart<K, V, G::Timestamp, D>>, Rc<dyn Any>)
 where
     K: Debug + Codec,
     V: Debug + Codec,
     D: Semigroup + Codec64 + Send + Sync,
+    F: Fn(&PartStats) -> bool + Send + Sync + 'static,
     G: Scope,
     // TODO: Figure out how to get rid of the TotalOrder bound :(.
     G::Timestamp: Timestamp + Lattice + Codec64 + TotalOrder,
@@ -123,8 +139,15 @@ where
         consumed_part_rx,
         chosen_worker,
     );
-    let (parts, tokens, fetch_shutdown) =
-        shard_source_fetch(&descs, name, clients, location, shard_id);
+    let (parts, tokens, fetch_shutdown) = shard_source_fetch(
+        &descs,
+        name,
+        clients,
+        location,
+        shard_id,
+        PartFilter::new(should_fetch_part),
+        key_projection,
+    );
     shard_source_tokens(&tokens, name, consumed_part_tx, chosen_worker);
 
     let token = Rc::new((
@@ -176,12 +199,12 @@ where
             return;
         }
 
-        let read = {
+        let client = {
             let mut persist_clients = clients.lock().await;
             persist_clients.open(location).await
         };
-        let read = read
-            .expect("location should be valid")
+        let client = client.expect("location should be valid");
+        let read = client
             .open_leased_reader::<K, V, G::Timestamp, D>(
                 shard_id,
                 &format!("shard_source({})", name_owned),
@@ -377,6 +400,8 @@ pub(crate) fn shard_source_fetch<K, V, T, D, G>(
     clients: Arc<Mutex<PersistClientCache>>,
     location: PersistLocation,
     shard_id: ShardId,
+    part_filter: PartFilter,
+    key_projection: Option<BTreeSet<String>>,
 ) -> (
     Stream<G, FetchedPart<K, V, T, D>>,
     Stream<G, SerdeLeasedBatchPart>,
@@ -410,7 +435,10 @@ where
             // Unlock the client cache before we do any async work.
             std::mem::drop(clients);
 
-            client.create_batch_fetcher::<K, V, T, D>(shard_id).await
+            let mut fetcher = client.create_batch_fetcher::<K, V, T, D>(shard_id).await;
+            fetcher.set_key_projection(key_projection);
+            fetcher.set_part_filter(Some(part_filter));
+            fetcher
         };
 
         let mut buffer = Vec::new();
@@ -335,6 +335,8 @@ where
                 Arc::clone(&self.handle.metrics),
                 &self.handle.metrics.read.listen,
                 Some(&self.handle.reader_id),
+                None,
+                None,
             )
             .await;
             self.handle.process_returned_leased_part(part);
@@ -598,6 +600,8 @@ where
                 Arc::clone(&self.metrics),
                 &self.metrics.read.snapshot,
                 Some(&self.reader_id),
+                None,
+                None,
             )
             .await;
             self.process_returned_leased_part(part);
@@ -634,6 +638,7 @@ where
             desc: batch.desc.clone(),
             key: part.key,
             encoded_size_bytes: part.encoded_size_bytes,
+            stats: part.stats,
             leased_seqno: Some(self.lease_seqno()),
         })
     }
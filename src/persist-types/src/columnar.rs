@@ -0,0 +1,177 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Columnar representations of the data stored in persist.
+
+/// A column of nullable, variable-length binary values.
+///
+/// The i'th value is stored in `data[offsets[i]..offsets[i+1]]`, unless it is
+/// null.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BinaryColumn {
+    data: Vec<u8>,
+    offsets: Vec<usize>,
+    validity: Vec<bool>,
+}
+
+impl Default for BinaryColumn {
+    fn default() -> Self {
+        BinaryColumn {
+            data: Vec::new(),
+            offsets: vec![0],
+            validity: Vec::new(),
+        }
+    }
+}
+
+impl BinaryColumn {
+    /// The number of values in the column.
+    pub fn len(&self) -> usize {
+        self.validity.len()
+    }
+
+    /// Whether the column has no values.
+    pub fn is_empty(&self) -> bool {
+        self.validity.is_empty()
+    }
+
+    /// Appends a value, or a null if `value` is None, to the column.
+    pub fn push(&mut self, value: Option<&[u8]>) {
+        if let Some(value) = value {
+            self.data.extend_from_slice(value);
+        }
+        self.offsets.push(self.data.len());
+        self.validity.push(value.is_some());
+    }
+
+    /// Returns the value at `idx`, or None if it is null.
+    ///
+    /// Panics if `idx >= self.len()`.
+    pub fn get(&self, idx: usize) -> Option<&[u8]> {
+        if !self.validity[idx] {
+            return None;
+        }
+        Some(&self.data[self.offsets[idx]..self.offsets[idx + 1]])
+    }
+
+    /// Iterates through the values in the column.
+    pub fn iter(&self) -> impl Iterator<Item = Option<&[u8]>> {
+        (0..self.len()).map(|idx| self.get(idx))
+    }
+}
+
+impl<'a> FromIterator<Option<&'a [u8]>> for BinaryColumn {
+    fn from_iter<I: IntoIterator<Item = Option<&'a [u8]>>>(iter: I) -> Self {
+        let mut ret = BinaryColumn::default();
+        for value in iter {
+            ret.push(value);
+        }
+        ret
+    }
+}
+
+/// A column of nullable values, all of the same type.
+///
+/// Each type is stored natively in the columnar format, so that it compresses
+/// well and can be read without the [Codec](crate::Codec) that wrote it.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DynColumn {
+    /// A column of booleans.
+    Bool(Vec<Option<bool>>),
+    /// A column of i16s.
+    I16(Vec<Option<i16>>),
+    /// A column of i32s.
+    I32(Vec<Option<i32>>),
+    /// A column of i64s.
+    I64(Vec<Option<i64>>),
+    /// A column of u8s.
+    U8(Vec<Option<u8>>),
+    /// A column of u16s.
+    U16(Vec<Option<u16>>),
+    /// A column of u32s.
+    U32(Vec<Option<u32>>),
+    /// A column of u64s.
+    U64(Vec<Option<u64>>),
+    /// A column of f32s.
+    F32(Vec<Option<f32>>),
+    /// A column of f64s.
+    F64(Vec<Option<f64>>),
+    /// A column of UTF-8 strings.
+    String(BinaryColumn),
+    /// A column of opaque bytes, for values without a more specific type.
+    Bytes(BinaryColumn),
+}
+
+impl DynColumn {
+    /// The number of values in the column.
+    pub fn len(&self) -> usize {
+        match self {
+            DynColumn::Bool(x) => x.len(),
+            DynColumn::I16(x) => x.len(),
+            DynColumn::I32(x) => x.len(),
+            DynColumn::I64(x) => x.len(),
+            DynColumn::U8(x) => x.len(),
+            DynColumn::U16(x) => x.len(),
+            DynColumn::U32(x) => x.len(),
+            DynColumn::U64(x) => x.len(),
+            DynColumn::F32(x) => x.len(),
+            DynColumn::F64(x) => x.len(),
+            DynColumn::String(x) | DynColumn::Bytes(x) => x.len(),
+        }
+    }
+
+    /// Whether the column has no values.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Appends a null to the column.
+    pub fn push_null(&mut self) {
+        match self {
+            DynColumn::Bool(x) => x.push(None),
+            DynColumn::I16(x) => x.push(None),
+            DynColumn::I32(x) => x.push(None),
+            DynColumn::I64(x) => x.push(None),
+            DynColumn::U8(x) => x.push(None),
+            DynColumn::U16(x) => x.push(None),
+            DynColumn::U32(x) => x.push(None),
+            DynColumn::U64(x) => x.push(None),
+            DynColumn::F32(x) => x.push(None),
+            DynColumn::F64(x) => x.push(None),
+            DynColumn::String(x) | DynColumn::Bytes(x) => x.push(None),
+        }
+    }
+}
+
+/// The values of a single part, split into named columns by their
+/// [Codec](crate::Codec).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PartColumns {
+    /// The number of values.
+    pub len: usize,
+    /// The columns, in order, each with exactly `len` values.
+    ///
+    /// A column is None if it was projected away when the part was read, in
+    /// which case its values are unknown.
+    pub cols: Vec<(String, Option<DynColumn>)>,
+}
+
+impl PartColumns {
+    /// Returns the column with the given name, or None if it was projected
+    /// away.
+    ///
+    /// Returns an error if there is no such column.
+    pub fn col(&self, name: &str) -> Result<Option<&DynColumn>, String> {
+        self.cols
+            .iter()
+            .find(|(col_name, _)| col_name == name)
+            .map(|(_, col)| col.as_ref())
+            .ok_or_else(|| format!("missing column: {}", name))
+    }
+}
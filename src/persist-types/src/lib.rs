@@ -18,7 +18,12 @@
 
 use bytes::BufMut;
 
+use crate::columnar::PartColumns;
+use crate::stats::StructStats;
+
 mod codec_impls;
+pub mod columnar;
+pub mod stats;
 
 /// Encoding and decoding operations for a type usable as a persisted key or
 /// value.
@@ -49,6 +54,49 @@ pub trait Codec: Sized + 'static {
     // TODO: Mechanically, this could return a ref to the original bytes
     // without any copies, see if we can make the types work out for that.
     fn decode<'a>(buf: &'a [u8]) -> Result<Self, String>;
+
+    /// Computes statistics about the given encoded values, which together make
+    /// up the keys of a single part.
+    ///
+    /// The default implementation returns `Ok(None)`, indicating that this
+    /// codec doesn't support statistics.
+    fn stats<'a>(encoded: impl Iterator<Item = &'a [u8]>) -> Result<Option<StructStats>, String> {
+        let _ = encoded;
+        Ok(None)
+    }
+
+    /// Splits the given encoded values, which together make up the keys of a
+    /// single part, into columns.
+    ///
+    /// Parts whose keys are split into columns can be read without decoding
+    /// the columns a reader doesn't need, and compress better than opaque
+    /// encoded values. The default implementation returns `Ok(None)`,
+    /// indicating that this codec doesn't support a columnar representation,
+    /// so the values are stored as they are.
+    fn encode_columns<'a>(
+        encoded: impl Iterator<Item = &'a [u8]>,
+    ) -> Result<Option<PartColumns>, String> {
+        let _ = encoded;
+        Ok(None)
+    }
+
+    /// Reassembles the `idx`'th value of columns previously computed by this
+    /// codec's [Codec::encode_columns], appending its encoding to `buf`.
+    ///
+    /// This must produce exactly the bytes that [Codec::encode_columns] was
+    /// given, unless some of the columns were projected away. In that case,
+    /// the codec decides how to represent the missing data, or returns an
+    /// error if it can't.
+    fn decode_columns<B>(cols: &PartColumns, idx: usize, buf: &mut B) -> Result<(), String>
+    where
+        B: BufMut,
+    {
+        let _ = (cols, idx, buf);
+        Err(format!(
+            "{} doesn't support columnar encoding",
+            Self::codec_name()
+        ))
+    }
 }
 
 /// Encoding and decoding operations for a type usable as a persisted timestamp
@@ -0,0 +1,45 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Aggregate statistics about data stored in persist.
+
+use serde::{Deserialize, Serialize};
+
+/// Aggregate statistics about the contents of a single part.
+///
+/// Persist itself never interprets these. They are computed by the shard's
+/// key [Codec](crate::Codec) when a part is written and handed back to readers,
+/// which may use them to skip fetching parts that can't contain relevant data.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct PartStats {
+    /// Statistics about the keys in the part.
+    pub key: StructStats,
+}
+
+/// Statistics about a collection of structured values, one per column.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct StructStats {
+    /// The number of values summarized by these statistics.
+    pub len: usize,
+    /// Statistics for each column, in column order.
+    pub cols: Vec<ColumnStats>,
+}
+
+/// Statistics about a single column of a collection of structured values.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct ColumnStats {
+    /// Inclusive lower and upper bounds on the non-null values of the column,
+    /// in an encoding chosen by the [Codec](crate::Codec) that computed them.
+    ///
+    /// This is `None` if the column contains only nulls, or if the bounds were
+    /// too large to be worth recording.
+    pub bounds: Option<(Vec<u8>, Vec<u8>)>,
+    /// The number of nulls in the column.
+    pub nulls: usize,
+}
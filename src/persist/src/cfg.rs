@@ -23,6 +23,7 @@ use crate::mem::{MemBlob, MemBlobConfig, MemConsensus};
 use crate::metrics::PostgresConsensusMetrics;
 use crate::postgres::{PostgresConsensus, PostgresConsensusConfig};
 use crate::s3::{S3Blob, S3BlobConfig};
+use crate::sqlite::{SqliteConsensus, SqliteConsensusConfig};
 
 /// Config for an implementation of [Blob].
 #[derive(Debug, Clone)]
@@ -117,6 +118,8 @@ impl BlobConfig {
 pub enum ConsensusConfig {
     /// Config for [PostgresConsensus].
     Postgres(PostgresConsensusConfig),
+    /// Config for [SqliteConsensus].
+    Sqlite(SqliteConsensusConfig),
     /// Config for [MemConsensus], only available in testing.
     Mem,
 }
@@ -140,6 +143,7 @@ impl ConsensusConfig {
             ConsensusConfig::Postgres(config) => {
                 Ok(Arc::new(PostgresConsensus::open(config).await?))
             }
+            ConsensusConfig::Sqlite(config) => Ok(Arc::new(SqliteConsensus::open(config).await?)),
             ConsensusConfig::Mem => Ok(Arc::new(MemConsensus::default())),
         }
     }
@@ -162,6 +166,26 @@ impl ConsensusConfig {
             "postgres" | "postgresql" => Ok(ConsensusConfig::Postgres(
                 PostgresConsensusConfig::new(value, knobs, metrics)?,
             )),
+            "sqlite" => {
+                // The database is a local file named by the path. A host would
+                // otherwise be silently ignored, e.g. `sqlite://foo/bar` would
+                // put the database at `/bar`.
+                if url.host_str().map_or(false, |x| !x.is_empty()) {
+                    Err(anyhow!(
+                        "sqlite consensus location must not have a host: {}",
+                        url.as_str()
+                    ))
+                } else if url.path().is_empty() || url.path().ends_with('/') {
+                    Err(anyhow!(
+                        "sqlite consensus location must name a file: {}",
+                        url.as_str()
+                    ))
+                } else {
+                    Ok(ConsensusConfig::Sqlite(SqliteConsensusConfig::from(
+                        url.path(),
+                    )))
+                }
+            }
             "mem" => {
                 if !cfg!(debug_assertions) {
                     warn!("persist unexpectedly using in-mem consensus in a release binary");
@@ -177,3 +201,56 @@ impl ConsensusConfig {
         Ok(config)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use mz_ore::metrics::MetricsRegistry;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct TestConsensusKnobs;
+
+    impl ConsensusKnobs for TestConsensusKnobs {
+        fn connection_pool_max_size(&self) -> usize {
+            2
+        }
+        fn connection_pool_ttl(&self) -> Duration {
+            Duration::MAX
+        }
+        fn connection_pool_ttl_stagger(&self) -> Duration {
+            Duration::MAX
+        }
+    }
+
+    fn consensus_config(value: &str) -> Result<ConsensusConfig, ExternalError> {
+        ConsensusConfig::try_from(
+            value,
+            Box::new(TestConsensusKnobs),
+            PostgresConsensusMetrics::new(&MetricsRegistry::new()),
+        )
+    }
+
+    #[test]
+    fn sqlite_consensus_config() {
+        assert!(matches!(
+            consensus_config("sqlite:///tmp/consensus.db"),
+            Ok(ConsensusConfig::Sqlite(_))
+        ));
+
+        // A host would be silently dropped from the path of the database.
+        let err = consensus_config("sqlite://tmp/consensus.db").unwrap_err();
+        assert!(err.to_string().contains("must not have a host"), "{}", err);
+
+        // The location must name the database file.
+        for value in ["sqlite:", "sqlite://", "sqlite:///", "sqlite:///tmp/"] {
+            let err = consensus_config(value).unwrap_err();
+            assert!(
+                err.to_string().contains("must name a file"),
+                "{}: {}",
+                value,
+                err
+            );
+        }
+    }
+}
@@ -14,13 +14,15 @@ use std::convert;
 use std::io::{Read, Seek, Write};
 use std::sync::Arc;
 
-use arrow2::array::{Array, BinaryArray, PrimitiveArray};
+use arrow2::array::{Array, BinaryArray, BooleanArray, PrimitiveArray, Utf8Array};
 use arrow2::chunk::Chunk;
 use arrow2::datatypes::{DataType, Field, Schema};
 use arrow2::io::ipc::read::{read_file_metadata, FileMetadata, FileReader};
 use arrow2::io::ipc::write::{FileWriter, WriteOptions};
+use arrow2::types::NativeType;
 use differential_dataflow::trace::Description;
-use mz_persist_types::Codec64;
+use mz_persist_types::columnar::{DynColumn, PartColumns};
+use mz_persist_types::{Codec, Codec64};
 use once_cell::sync::Lazy;
 use timely::progress::{Antichain, Timestamp};
 
@@ -75,6 +77,109 @@ pub static SCHEMA_ARROW_KVTD: Lazy<Arc<Schema>> = Lazy::new(|| {
     ]))
 });
 
+/// The prefix of the names of the key columns in [schema_arrow_structured].
+pub(crate) const KEY_COL_PREFIX: &str = "k.";
+
+/// The Arrow schema we use to encode ((K, V), T, D) tuples whose keys have
+/// been split into the given columns by their [Codec].
+///
+/// Each key column is a nullable field of the column's type (see
+/// [dyn_column_data_type]), named with [KEY_COL_PREFIX]. They're followed by
+/// the same v, t, and d fields as [SCHEMA_ARROW_KVTD].
+pub fn schema_arrow_structured(key_cols: &[(&str, DataType)]) -> Schema {
+    let mut fields = key_cols
+        .iter()
+        .map(|(name, data_type)| Field {
+            name: format!("{}{}", KEY_COL_PREFIX, name),
+            data_type: data_type.clone(),
+            is_nullable: true,
+            metadata: BTreeMap::new(),
+        })
+        .collect::<Vec<_>>();
+    fields.extend(SCHEMA_ARROW_KVTD.fields[1..].iter().cloned());
+    Schema::from(fields)
+}
+
+/// The Arrow type that a [DynColumn] is encoded as.
+pub fn dyn_column_data_type(col: &DynColumn) -> DataType {
+    match col {
+        DynColumn::Bool(_) => DataType::Boolean,
+        DynColumn::I16(_) => DataType::Int16,
+        DynColumn::I32(_) => DataType::Int32,
+        DynColumn::I64(_) => DataType::Int64,
+        DynColumn::U8(_) => DataType::UInt8,
+        DynColumn::U16(_) => DataType::UInt16,
+        DynColumn::U32(_) => DataType::UInt32,
+        DynColumn::U64(_) => DataType::UInt64,
+        DynColumn::F32(_) => DataType::Float32,
+        DynColumn::F64(_) => DataType::Float64,
+        DynColumn::String(_) => DataType::Utf8,
+        DynColumn::Bytes(_) => DataType::Binary,
+    }
+}
+
+fn encode_dyn_column(col: &DynColumn) -> Result<Box<dyn Array>, String> {
+    fn primitive<T: NativeType>(x: &[Option<T>]) -> Box<dyn Array> {
+        Box::new(PrimitiveArray::<T>::from(x))
+    }
+    let array: Box<dyn Array> = match col {
+        DynColumn::Bool(x) => Box::new(BooleanArray::from(x.as_slice())),
+        DynColumn::I16(x) => primitive(x),
+        DynColumn::I32(x) => primitive(x),
+        DynColumn::I64(x) => primitive(x),
+        DynColumn::U8(x) => primitive(x),
+        DynColumn::U16(x) => primitive(x),
+        DynColumn::U32(x) => primitive(x),
+        DynColumn::U64(x) => primitive(x),
+        DynColumn::F32(x) => primitive(x),
+        DynColumn::F64(x) => primitive(x),
+        DynColumn::String(x) => {
+            let values = x
+                .iter()
+                .map(|x| x.map(std::str::from_utf8).transpose())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|err| err.to_string())?;
+            Box::new(values.into_iter().collect::<Utf8Array<i32>>())
+        }
+        DynColumn::Bytes(x) => Box::new(x.iter().collect::<BinaryArray<i32>>()),
+    };
+    Ok(array)
+}
+
+fn decode_dyn_column(array: &dyn Array) -> Result<DynColumn, String> {
+    fn downcast<'a, A: 'static>(array: &'a dyn Array) -> Result<&'a A, String> {
+        array
+            .as_any()
+            .downcast_ref::<A>()
+            .ok_or_else(|| format!("column of type {:?} doesn't match", array.data_type()))
+    }
+    fn primitive<T: NativeType>(array: &dyn Array) -> Result<Vec<Option<T>>, String> {
+        let array = downcast::<PrimitiveArray<T>>(array)?;
+        Ok(array.iter().map(|x| x.copied()).collect())
+    }
+    let col = match array.data_type() {
+        DataType::Boolean => DynColumn::Bool(downcast::<BooleanArray>(array)?.iter().collect()),
+        DataType::Int16 => DynColumn::I16(primitive(array)?),
+        DataType::Int32 => DynColumn::I32(primitive(array)?),
+        DataType::Int64 => DynColumn::I64(primitive(array)?),
+        DataType::UInt8 => DynColumn::U8(primitive(array)?),
+        DataType::UInt16 => DynColumn::U16(primitive(array)?),
+        DataType::UInt32 => DynColumn::U32(primitive(array)?),
+        DataType::UInt64 => DynColumn::U64(primitive(array)?),
+        DataType::Float32 => DynColumn::F32(primitive(array)?),
+        DataType::Float64 => DynColumn::F64(primitive(array)?),
+        DataType::Utf8 => DynColumn::String(
+            downcast::<Utf8Array<i32>>(array)?
+                .iter()
+                .map(|x| x.map(str::as_bytes))
+                .collect(),
+        ),
+        DataType::Binary => DynColumn::Bytes(downcast::<BinaryArray<i32>>(array)?.iter().collect()),
+        x => return Err(format!("unsupported key column type {:?}", x)),
+    };
+    Ok(col)
+}
+
 const INLINE_METADATA_KEY: &str = "MZ:inline";
 
 /// Encodes an BlobTraceBatchPart into the Arrow file format.
@@ -117,6 +222,9 @@ pub fn decode_trace_arrow<R: Read + Seek, T: Timestamp + Codec64>(
         ProtoBatchFormat::ParquetKvtd => {
             return Err("ParquetKvtd format not supported in arrow".into())
         }
+        ProtoBatchFormat::ParquetStructured => {
+            return Err("ParquetStructured format not supported in arrow".into())
+        }
     };
 
     let ret = BlobTraceBatchPart {
@@ -241,3 +349,118 @@ pub fn decode_arrow_batch_kvtd(x: &Chunk<Box<dyn Array>>) -> Result<ColumnarReco
     ret.borrow().validate()?;
     Ok(ret)
 }
+
+/// Converts a ColumnarRecords, along with its keys split into columns, into an
+/// arrow Chunk with the [schema_arrow_structured] of those columns.
+pub fn encode_arrow_batch_structured(
+    x: &ColumnarRecords,
+    key_cols: &PartColumns,
+) -> Result<Chunk<Box<dyn Array>>, String> {
+    if key_cols.len != x.len {
+        return Err(format!("expected {} keys got {}", x.len, key_cols.len));
+    }
+    let mut arrays = Vec::with_capacity(key_cols.cols.len() + 3);
+    for (name, col) in key_cols.cols.iter() {
+        let col = col
+            .as_ref()
+            .ok_or_else(|| format!("key column {} has no values", name))?;
+        if col.len() != x.len {
+            return Err(format!(
+                "expected {} values in key column {} got {}",
+                x.len,
+                name,
+                col.len()
+            ));
+        }
+        arrays.push(encode_dyn_column(col)?);
+    }
+    // The v, t, and d columns are the same as in the kvtd format.
+    let kvtd = encode_arrow_batch_kvtd(x);
+    arrays.extend(kvtd.into_arrays().into_iter().skip(1));
+    Chunk::try_new(arrays).map_err(|err| err.to_string())
+}
+
+/// Converts an arrow Chunk with a [schema_arrow_structured] into a
+/// ColumnarRecords, reassembling its keys with `K`.
+///
+/// `key_cols` lists the names of all of the key columns in the schema, along
+/// with whether each one was projected into the Chunk. The Chunk contains the
+/// projected key columns, in order, followed by the v, t, and d columns.
+pub fn decode_arrow_batch_structured<K: Codec>(
+    x: &Chunk<Box<dyn Array>>,
+    key_cols: &[(String, bool)],
+) -> Result<ColumnarRecords, String> {
+    let columns = x.columns();
+    let projected = key_cols.iter().filter(|(_, projected)| *projected).count();
+    if columns.len() != projected + 3 {
+        return Err(format!(
+            "expected {} fields got {}",
+            projected + 3,
+            columns.len()
+        ));
+    }
+    let len = x.len();
+
+    let mut columns_iter = columns.iter().enumerate();
+    let mut cols = PartColumns {
+        len,
+        cols: Vec::with_capacity(key_cols.len()),
+    };
+    for (name, projected) in key_cols.iter() {
+        let col = if *projected {
+            let (idx, col) = columns_iter.next().expect("checked above");
+            let col = decode_dyn_column(col.as_ref())
+                .map_err(|err| format!("column {}: {}", idx, err))?;
+            Some(col)
+        } else {
+            None
+        };
+        cols.cols.push((name.clone(), col));
+    }
+
+    let mut key_data = Vec::new();
+    let mut key_offsets = Vec::with_capacity(len + 1);
+    key_offsets.push(0);
+    for idx in 0..len {
+        K::decode_columns(&cols, idx, &mut key_data)?;
+        let offset = i32::try_from(key_data.len())
+            .map_err(|_| format!("key data exceeds {} bytes", i32::MAX))?;
+        key_offsets.push(offset);
+    }
+
+    // The v, t, and d columns are the same as in the kvtd format.
+    let val_col = &columns[projected];
+    let ts_col = &columns[projected + 1];
+    let diff_col = &columns[projected + 2];
+    let val_array = val_col
+        .as_any()
+        .downcast_ref::<BinaryArray<i32>>()
+        .ok_or_else(|| format!("column {} doesn't match schema", projected))?
+        .clone();
+    let val_offsets = val_array.offsets().clone();
+    let val_data = val_array.values().clone();
+    let timestamps = ts_col
+        .as_any()
+        .downcast_ref::<PrimitiveArray<i64>>()
+        .ok_or_else(|| format!("column {} doesn't match schema", projected + 1))?
+        .values()
+        .clone();
+    let diffs = diff_col
+        .as_any()
+        .downcast_ref::<PrimitiveArray<i64>>()
+        .ok_or_else(|| format!("column {} doesn't match schema", projected + 2))?
+        .values()
+        .clone();
+
+    let ret = ColumnarRecords {
+        len,
+        key_data: key_data.into(),
+        key_offsets: key_offsets.into(),
+        val_data,
+        val_offsets,
+        timestamps,
+        diffs,
+    };
+    ret.borrow().validate()?;
+    Ok(ret)
+}
@@ -9,20 +9,27 @@
 
 //! Apache Parquet encodings and utils for persist data
 
+use std::collections::BTreeSet;
 use std::io::{Read, Seek, Write};
 
+use arrow2::array::Array;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::Schema;
 use arrow2::io::parquet::read::{infer_schema, read_metadata, FileReader};
 use arrow2::io::parquet::write::{
     CompressionOptions, Encoding, FileWriter, KeyValue, RowGroupIterator, Version, WriteOptions,
 };
 use differential_dataflow::trace::Description;
-use mz_persist_types::Codec64;
+use mz_persist_types::columnar::PartColumns;
+use mz_persist_types::{Codec, Codec64};
 use timely::progress::{Antichain, Timestamp};
 
 use crate::error::Error;
 use crate::gen::persist::ProtoBatchFormat;
 use crate::indexed::columnar::arrow::{
-    decode_arrow_batch_kvtd, encode_arrow_batch_kvtd, SCHEMA_ARROW_KVTD,
+    decode_arrow_batch_kvtd, decode_arrow_batch_structured, dyn_column_data_type,
+    encode_arrow_batch_kvtd, encode_arrow_batch_structured, schema_arrow_structured,
+    KEY_COL_PREFIX, SCHEMA_ARROW_KVTD,
 };
 use crate::indexed::columnar::ColumnarRecords;
 use crate::indexed::encoding::{
@@ -45,10 +52,109 @@ pub fn encode_trace_parquet<W: Write, T: Timestamp + Codec64>(
     )
 }
 
+/// Encodes a BlobTraceBatchPart into the Parquet format, with its keys split
+/// into columns by `K`.
+///
+/// Falls back to the same format as [encode_trace_parquet] if `K` doesn't
+/// support a columnar representation of these keys.
+pub fn encode_trace_parquet_structured<W: Write, K: Codec, T: Timestamp + Codec64>(
+    w: &mut W,
+    batch: &BlobTraceBatchPart<T>,
+) -> Result<(), Error> {
+    // Better to error now than write out an invalid batch.
+    batch.validate()?;
+    let mut key_cols = Vec::with_capacity(batch.updates.len());
+    for records in batch.updates.iter() {
+        let keys = records.iter().map(|((k, _v), _t, _d)| k);
+        match K::encode_columns(keys)? {
+            Some(cols) => key_cols.push(cols),
+            None => return encode_trace_parquet(w, batch),
+        }
+    }
+    // Every row group in the file has the same schema, so the keys in every
+    // ColumnarRecords have to have been split into the same columns, of the
+    // same types.
+    let col_types = |cols: &PartColumns| {
+        cols.cols
+            .iter()
+            .map(|(name, col)| (name.clone(), col.as_ref().map(dyn_column_data_type)))
+            .collect::<Vec<_>>()
+    };
+    let types = match key_cols.first() {
+        Some(cols) => col_types(cols),
+        None => return encode_trace_parquet(w, batch),
+    };
+    if key_cols.iter().any(|cols| col_types(cols) != types) {
+        return encode_trace_parquet(w, batch);
+    }
+    let mut fields = Vec::with_capacity(types.len());
+    for (name, data_type) in types.iter() {
+        let data_type = data_type
+            .clone()
+            .ok_or_else(|| format!("key column {} has no values", name))?;
+        fields.push((name.as_str(), data_type));
+    }
+    let schema = schema_arrow_structured(&fields);
+
+    let mut chunks = Vec::with_capacity(batch.updates.len());
+    for (records, cols) in batch.updates.iter().zip(key_cols.iter()) {
+        chunks.push(encode_arrow_batch_structured(records, cols)?);
+    }
+    encode_parquet(
+        w,
+        encode_trace_inline_meta(batch, ProtoBatchFormat::ParquetStructured),
+        &schema,
+        chunks.into_iter(),
+    )
+}
+
 /// Decodes a BlobTraceBatchPart from the Parquet format.
+///
+/// This can't decode parts written by [encode_trace_parquet_structured], whose
+/// keys can only be reassembled by their codec. Use
+/// [decode_trace_parquet_structured] for those.
 pub fn decode_trace_parquet<R: Read + Seek, T: Timestamp + Codec64>(
     r: &mut R,
 ) -> Result<BlobTraceBatchPart<T>, Error> {
+    decode_trace_parquet_with(r, |r, format| match format {
+        ProtoBatchFormat::Unknown => Err("unknown format".into()),
+        ProtoBatchFormat::ArrowKvtd => Err("ArrowKVTD format not supported in parquet".into()),
+        ProtoBatchFormat::ParquetKvtd => decode_parquet_file_kvtd(r),
+        ProtoBatchFormat::ParquetStructured => {
+            Err("ParquetStructured format requires the key codec to decode".into())
+        }
+    })
+}
+
+/// Decodes a BlobTraceBatchPart, in any of the Parquet formats, reassembling
+/// its keys with `K` if they were split into columns.
+///
+/// If `projection` is set, only the key columns it names are decoded, and `K`
+/// decides how to represent the rest (see [Codec::decode_columns]). The keys
+/// of a part without key columns are always decoded in full.
+pub fn decode_trace_parquet_structured<R: Read + Seek, K: Codec, T: Timestamp + Codec64>(
+    r: &mut R,
+    projection: Option<&BTreeSet<String>>,
+) -> Result<BlobTraceBatchPart<T>, Error> {
+    decode_trace_parquet_with(r, |r, format| match format {
+        ProtoBatchFormat::Unknown => Err("unknown format".into()),
+        ProtoBatchFormat::ArrowKvtd => Err("ArrowKVTD format not supported in parquet".into()),
+        ProtoBatchFormat::ParquetKvtd => decode_parquet_file_kvtd(r),
+        ProtoBatchFormat::ParquetStructured => {
+            decode_parquet_file_structured::<R, K>(r, projection)
+        }
+    })
+}
+
+fn decode_trace_parquet_with<R, T, F>(
+    r: &mut R,
+    decode_updates: F,
+) -> Result<BlobTraceBatchPart<T>, Error>
+where
+    R: Read + Seek,
+    T: Timestamp + Codec64,
+    F: FnOnce(&mut R, ProtoBatchFormat) -> Result<Vec<ColumnarRecords>, Error>,
+{
     let metadata = read_metadata(r).map_err(|err| err.to_string())?;
     let metadata = metadata
         .key_value_metadata()
@@ -56,13 +162,7 @@ pub fn decode_trace_parquet<R: Read + Seek, T: Timestamp + Codec64>(
         .and_then(|x| x.iter().find(|x| x.key == INLINE_METADATA_KEY));
     let (format, meta) = decode_trace_inline_meta(metadata.and_then(|x| x.value.as_ref()))?;
 
-    let updates = match format {
-        ProtoBatchFormat::Unknown => return Err("unknown format".into()),
-        ProtoBatchFormat::ArrowKvtd => {
-            return Err("ArrowKVTD format not supported in parquet".into())
-        }
-        ProtoBatchFormat::ParquetKvtd => decode_parquet_file_kvtd(r)?,
-    };
+    let updates = decode_updates(r, format)?;
 
     let ret = BlobTraceBatchPart {
         desc: meta.desc.map_or_else(
@@ -87,7 +187,17 @@ fn encode_parquet_kvtd<W: Write>(
     inline_base64: String,
     iter: &[ColumnarRecords],
 ) -> Result<(), Error> {
-    let iter = iter.into_iter().map(|x| Ok(encode_arrow_batch_kvtd(x)));
+    let iter = iter.into_iter().map(encode_arrow_batch_kvtd);
+    encode_parquet(w, inline_base64, &SCHEMA_ARROW_KVTD, iter)
+}
+
+fn encode_parquet<W: Write, I: Iterator<Item = Chunk<Box<dyn Array>>>>(
+    w: &mut W,
+    inline_base64: String,
+    schema: &Schema,
+    iter: I,
+) -> Result<(), Error> {
+    let iter = iter.map(Ok);
 
     let options = WriteOptions {
         write_statistics: false,
@@ -96,21 +206,16 @@ fn encode_parquet_kvtd<W: Write>(
     };
     let row_groups = RowGroupIterator::try_new(
         iter,
-        &SCHEMA_ARROW_KVTD,
+        schema,
         options,
-        vec![
-            vec![Encoding::Plain],
-            vec![Encoding::Plain],
-            vec![Encoding::Plain],
-            vec![Encoding::Plain],
-        ],
+        vec![vec![Encoding::Plain]; schema.fields.len()],
     )?;
 
     let metadata = vec![KeyValue {
         key: INLINE_METADATA_KEY.into(),
         value: Some(inline_base64),
     }];
-    let mut writer = FileWriter::try_new(w, (**SCHEMA_ARROW_KVTD).clone(), options)?;
+    let mut writer = FileWriter::try_new(w, schema.clone(), options)?;
     for group in row_groups {
         writer.write(group?).map_err(|err| err.to_string())?;
     }
@@ -140,3 +245,52 @@ fn decode_parquet_file_kvtd<R: Read + Seek>(r: &mut R) -> Result<Vec<ColumnarRec
     }
     Ok(ret)
 }
+
+fn decode_parquet_file_structured<R: Read + Seek, K: Codec>(
+    r: &mut R,
+    projection: Option<&BTreeSet<String>>,
+) -> Result<Vec<ColumnarRecords>, Error> {
+    let metadata = read_metadata(r)?;
+    let schema = infer_schema(&metadata)?;
+
+    let mut key_cols = Vec::new();
+    let mut key_fields = Vec::new();
+    let mut projected_fields = Vec::new();
+    for field in schema.fields.iter() {
+        match field.name.strip_prefix(KEY_COL_PREFIX) {
+            Some(name) => {
+                let projected = projection.map_or(true, |x| x.contains(name));
+                if projected {
+                    projected_fields.push(field.clone());
+                }
+                key_cols.push((name.to_owned(), projected));
+                key_fields.push((name, field.data_type.clone()));
+            }
+            None => projected_fields.push(field.clone()),
+        }
+    }
+
+    // We're not trying to accept any sort of user created data, so be strict.
+    let expected_schema = schema_arrow_structured(&key_fields);
+    if schema.fields != expected_schema.fields {
+        return Err(format!(
+            "expected arrow schema {:?} got: {:?}",
+            expected_schema.fields, schema.fields
+        )
+        .into());
+    }
+
+    let reader = FileReader::new(
+        r,
+        metadata.row_groups,
+        Schema::from(projected_fields),
+        None,
+        None,
+        None,
+    );
+    let mut ret = Vec::new();
+    for batch in reader {
+        ret.push(decode_arrow_batch_structured::<K>(&batch?, &key_cols)?);
+    }
+    Ok(ret)
+}
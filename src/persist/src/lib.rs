@@ -28,6 +28,7 @@ pub mod metrics;
 pub mod postgres;
 pub mod retry;
 pub mod s3;
+pub mod sqlite;
 pub mod unreliable;
 pub mod workload;
 
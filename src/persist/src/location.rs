@@ -104,9 +104,7 @@ impl std::error::Error for Determinate {}
 
 impl Determinate {
     /// Return a new Determinate wrapping the given error.
-    ///
-    /// Exposed for testing via [crate::unreliable].
-    pub(crate) fn new(inner: anyhow::Error) -> Self {
+    pub fn new(inner: anyhow::Error) -> Self {
         Determinate { inner }
     }
 }
@@ -267,6 +265,14 @@ impl From<deadpool_postgres::PoolError> for ExternalError {
     }
 }
 
+impl From<rusqlite::Error> for ExternalError {
+    fn from(x: rusqlite::Error) -> Self {
+        ExternalError::Indeterminate(Indeterminate {
+            inner: anyhow::Error::new(x),
+        })
+    }
+}
+
 impl From<tokio::task::JoinError> for ExternalError {
     fn from(x: tokio::task::JoinError) -> Self {
         ExternalError::Indeterminate(Indeterminate {
@@ -0,0 +1,304 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Implementation of [Consensus] backed by an embedded SQLite database.
+
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use bytes::Bytes;
+use rusqlite::{params, Connection, OptionalExtension, TransactionBehavior};
+
+use crate::error::Error;
+use crate::location::{Consensus, ExternalError, SeqNo, VersionedData, SCAN_ALL};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS consensus (
+    shard text NOT NULL,
+    sequence_number integer NOT NULL,
+    data blob NOT NULL,
+    PRIMARY KEY(shard, sequence_number)
+);
+";
+
+/// How long to wait for another process to release its lock on the database
+/// before giving up on an operation.
+const BUSY_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Configuration for opening a [SqliteConsensus].
+#[derive(Clone, Debug)]
+pub struct SqliteConsensusConfig {
+    path: PathBuf,
+}
+
+impl<P: Into<PathBuf>> From<P> for SqliteConsensusConfig {
+    fn from(path: P) -> Self {
+        SqliteConsensusConfig { path: path.into() }
+    }
+}
+
+/// Implementation of [Consensus] over a single SQLite database file.
+///
+/// This is intended for single-node deployments, where running a separate
+/// Postgres just to hold consensus is overkill. Any number of processes may
+/// open the same file: every read-modify-write happens in an `IMMEDIATE`
+/// transaction, which SQLite serializes with a lock on the file.
+pub struct SqliteConsensus {
+    // rusqlite connections can't be shared between threads, so operations take
+    // turns on this one from blocking tasks.
+    conn: Arc<Mutex<Connection>>,
+}
+
+impl std::fmt::Debug for SqliteConsensus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SqliteConsensus").finish_non_exhaustive()
+    }
+}
+
+impl SqliteConsensus {
+    /// Opens the SQLite [Consensus] at `config`'s path, creating it if
+    /// necessary.
+    pub async fn open(config: SqliteConsensusConfig) -> Result<Self, ExternalError> {
+        let conn = mz_ore::task::spawn_blocking(
+            || "persist::sqlite::open",
+            move || -> Result<Connection, ExternalError> {
+                if let Some(parent) = config.path.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                let conn = Connection::open(&config.path)?;
+                conn.busy_timeout(BUSY_TIMEOUT)?;
+                // WAL mode lets readers proceed concurrently with a writer,
+                // and with synchronous=FULL each commit is durable before it
+                // returns.
+                conn.query_row("PRAGMA journal_mode = WAL", [], |_| Ok(()))?;
+                conn.execute_batch("PRAGMA synchronous = FULL")?;
+                conn.execute_batch(SCHEMA)?;
+                Ok(conn)
+            },
+        )
+        .await??;
+        Ok(SqliteConsensus {
+            conn: Arc::new(Mutex::new(conn)),
+        })
+    }
+
+    /// Runs `f` against the connection on a thread where blocking is
+    /// acceptable.
+    async fn run<R, F>(&self, name: &'static str, f: F) -> Result<R, ExternalError>
+    where
+        R: Send + 'static,
+        F: FnOnce(&mut Connection) -> Result<R, ExternalError> + Send + 'static,
+    {
+        let conn = Arc::clone(&self.conn);
+        mz_ore::task::spawn_blocking(
+            || name,
+            move || {
+                let mut conn = conn.lock().expect("lock poisoned");
+                f(&mut conn)
+            },
+        )
+        .await?
+    }
+}
+
+fn seqno_to_sql(seqno: SeqNo) -> Result<i64, ExternalError> {
+    // We can only represent sequence numbers in the range [0, i64::MAX].
+    i64::try_from(seqno.0)
+        .map_err(|_| ExternalError::from(anyhow!("seqno must be [0, i64::MAX]: {:?}", seqno)))
+}
+
+fn seqno_from_sql(seqno: i64) -> Result<SeqNo, ExternalError> {
+    // Sanity check that the sequence number we read falls in the
+    // [0, i64::MAX] range.
+    u64::try_from(seqno)
+        .map(SeqNo)
+        .map_err(|_| ExternalError::from(anyhow!("invalid seqno in consensus: {}", seqno)))
+}
+
+fn head(conn: &Connection, key: &str) -> Result<Option<VersionedData>, ExternalError> {
+    let row = conn
+        .query_row(
+            "SELECT sequence_number, data FROM consensus
+             WHERE shard = ?1 ORDER BY sequence_number DESC LIMIT 1",
+            params![key],
+            |row| Ok((row.get::<_, i64>(0)?, row.get::<_, Vec<u8>>(1)?)),
+        )
+        .optional()?;
+    match row {
+        None => Ok(None),
+        Some((seqno, data)) => Ok(Some(VersionedData {
+            seqno: seqno_from_sql(seqno)?,
+            data: Bytes::from(data),
+        })),
+    }
+}
+
+fn scan(
+    conn: &Connection,
+    key: &str,
+    from: SeqNo,
+    limit: usize,
+) -> Result<Vec<VersionedData>, ExternalError> {
+    let Ok(limit) = i64::try_from(limit) else {
+        return Err(ExternalError::from(anyhow!(
+            "limit must be [0, i64::MAX]. was: {:?}",
+            limit
+        )));
+    };
+    let mut statement = conn.prepare_cached(
+        "SELECT sequence_number, data FROM consensus
+         WHERE shard = ?1 AND sequence_number >= ?2
+         ORDER BY sequence_number ASC LIMIT ?3",
+    )?;
+    let rows = statement.query_map(params![key, seqno_to_sql(from)?, limit], |row| {
+        Ok((row.get::<_, i64>(0)?, row.get::<_, Vec<u8>>(1)?))
+    })?;
+    let mut results = Vec::new();
+    for row in rows {
+        let (seqno, data) = row?;
+        results.push(VersionedData {
+            seqno: seqno_from_sql(seqno)?,
+            data: Bytes::from(data),
+        });
+    }
+    Ok(results)
+}
+
+#[async_trait]
+impl Consensus for SqliteConsensus {
+    async fn head(&self, key: &str) -> Result<Option<VersionedData>, ExternalError> {
+        let key = key.to_owned();
+        self.run("persist::sqlite::head", move |conn| head(conn, &key))
+            .await
+    }
+
+    async fn compare_and_set(
+        &self,
+        key: &str,
+        expected: Option<SeqNo>,
+        new: VersionedData,
+    ) -> Result<Result<(), Vec<VersionedData>>, ExternalError> {
+        if let Some(expected) = expected {
+            if new.seqno <= expected {
+                return Err(Error::from(
+                        format!("new seqno must be strictly greater than expected. Got new: {:?} expected: {:?}",
+                                 new.seqno, expected)).into());
+            }
+        }
+
+        let key = key.to_owned();
+        self.run("persist::sqlite::compare_and_set", move |conn| {
+            // Take the write lock up front, so that no other process can
+            // modify the shard between our check of head and our insert.
+            let tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+            let current = head(&tx, &key)?.map(|x| x.seqno);
+            if current != expected {
+                // Unlike Postgres, we can read the current state in the same
+                // transaction, so it's guaranteed to be consistent with the
+                // failed check.
+                let from = expected.map_or_else(SeqNo::minimum, |x| x.next());
+                let current = scan(&tx, &key, from, SCAN_ALL)?;
+                return Ok(Err(current));
+            }
+            tx.execute(
+                "INSERT INTO consensus (shard, sequence_number, data) VALUES (?1, ?2, ?3)",
+                params![key, seqno_to_sql(new.seqno)?, new.data.as_ref()],
+            )?;
+            tx.commit()?;
+            Ok(Ok(()))
+        })
+        .await
+    }
+
+    async fn scan(
+        &self,
+        key: &str,
+        from: SeqNo,
+        limit: usize,
+    ) -> Result<Vec<VersionedData>, ExternalError> {
+        let key = key.to_owned();
+        self.run("persist::sqlite::scan", move |conn| {
+            scan(conn, &key, from, limit)
+        })
+        .await
+    }
+
+    async fn truncate(&self, key: &str, seqno: SeqNo) -> Result<usize, ExternalError> {
+        let key = key.to_owned();
+        self.run("persist::sqlite::truncate", move |conn| {
+            let tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+            // Truncating must never delete head, so the request is only valid
+            // if there is data at or beyond `seqno`.
+            let current = head(&tx, &key)?;
+            if current.map_or(true, |data| data.seqno < seqno) {
+                return Err(ExternalError::from(anyhow!(
+                    "upper bound too high for truncate: {:?}",
+                    seqno
+                )));
+            }
+            let deleted = tx.execute(
+                "DELETE FROM consensus WHERE shard = ?1 AND sequence_number < ?2",
+                params![key, seqno_to_sql(seqno)?],
+            )?;
+            tx.commit()?;
+            Ok(deleted)
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::location::tests::consensus_impl_test;
+    use uuid::Uuid;
+
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn sqlite_consensus() -> Result<(), ExternalError> {
+        let temp_dir = tempfile::tempdir().map_err(Error::from)?;
+        let config = SqliteConsensusConfig::from(temp_dir.path().join("consensus.db"));
+        consensus_impl_test(|| SqliteConsensus::open(config.clone())).await?;
+
+        // Separate instances have separate connections, as separate processes
+        // would. Racing compare_and_sets from the same expected state must
+        // still result in exactly one winner.
+        let key = Uuid::new_v4().to_string();
+        let mut handles = Vec::new();
+        for idx in 0..10u64 {
+            let consensus = SqliteConsensus::open(config.clone()).await?;
+            let key = key.clone();
+            handles.push(mz_ore::task::spawn(
+                || "sqlite_consensus_race",
+                async move {
+                    let data = VersionedData {
+                        seqno: SeqNo(1),
+                        data: Bytes::from(idx.to_string()),
+                    };
+                    consensus.compare_and_set(&key, None, data).await
+                },
+            ));
+        }
+        let mut successes = 0;
+        for handle in handles {
+            if handle.await.expect("task panicked")?.is_ok() {
+                successes += 1;
+            }
+        }
+        assert_eq!(successes, 1);
+
+        let consensus = SqliteConsensus::open(config.clone()).await?;
+        assert_eq!(consensus.scan(&key, SeqNo(0), SCAN_ALL).await?.len(), 1);
+
+        Ok(())
+    }
+}
@@ -14,10 +14,14 @@ use bytes::BytesMut;
 use csv::ByteRecord;
 use csv::ReaderBuilder;
 
+use mz_repr::adt::datetime::Timezone;
 use mz_repr::{Datum, RelationType, Row, RowArena};
 
 static END_OF_COPY_MARKER: &[u8] = b"\\.";
 
+/// The signature that begins the header of the binary COPY format.
+static BINARY_SIGNATURE: &[u8] = b"PGCOPY\n\xFF\r\n\0";
+
 pub fn encode_copy_row_binary(
     row: Row,
     typ: &RelationType,
@@ -62,9 +66,12 @@ pub fn encode_copy_row_binary(
     Ok(())
 }
 
+/// Encodes `row` in the text COPY format, rendering `timestamptz` values in the
+/// time zone `tz`.
 pub fn encode_copy_row_text(
     row: Row,
     typ: &RelationType,
+    tz: &Timezone,
     out: &mut Vec<u8>,
 ) -> Result<(), io::Error> {
     let delim = b'\t';
@@ -78,7 +85,7 @@ pub fn encode_copy_row_text(
             None => out.extend(null),
             Some(field) => {
                 buf.clear();
-                field.encode_text(&mut buf);
+                field.encode_text_in_zone(tz, &mut buf);
                 for b in &buf {
                     match b {
                         b'\\' => out.extend(b"\\\\"),
@@ -359,6 +366,7 @@ impl<'a> RawIterator<'a> {
 pub enum CopyFormatParams<'a> {
     Text(CopyTextFormatParams<'a>),
     Csv(CopyCsvFormatParams<'a>),
+    Binary,
 }
 
 pub fn decode_copy_format<'a>(
@@ -369,6 +377,7 @@ pub fn decode_copy_format<'a>(
     match params {
         CopyFormatParams::Text(params) => decode_copy_format_text(data, column_types, params),
         CopyFormatParams::Csv(params) => decode_copy_format_csv(data, column_types, params),
+        CopyFormatParams::Binary => decode_copy_format_binary(data, column_types),
     }
 }
 
@@ -496,8 +505,144 @@ pub fn decode_copy_format_csv(
     Ok(rows)
 }
 
+pub fn decode_copy_format_binary(
+    data: &[u8],
+    column_types: &[mz_pgrepr::Type],
+) -> Result<Vec<Row>, io::Error> {
+    let mut rows = Vec::new();
+
+    let mut parser = CopyBinaryFormatParser::new(data);
+    parser.expect_header()?;
+    loop {
+        // As in PG, data that ends at a tuple boundary without a trailer is
+        // accepted.
+        if parser.is_eof() {
+            break;
+        }
+        // 16-bit field count, or -1 to mark the end of the data.
+        let count = parser.read_i16()?;
+        if count == -1 {
+            break;
+        }
+        if usize::try_from(count).ok() != Some(column_types.len()) {
+            return Err(invalid_data(format!(
+                "row field count is {}, expected {}",
+                count,
+                column_types.len()
+            )));
+        }
+
+        let mut row = Vec::new();
+        let buf = RowArena::new();
+        for typ in column_types {
+            match parser.read_field()? {
+                Some(raw_value) => match mz_pgrepr::Value::decode_binary(typ, raw_value) {
+                    Ok(value) => row.push(value.into_datum(&buf, typ)),
+                    Err(err) => {
+                        let msg = format!("unable to decode column: {}", err);
+                        return Err(invalid_data(msg));
+                    }
+                },
+                None => row.push(Datum::Null),
+            }
+        }
+        rows.push(Row::pack(row));
+    }
+    // As in PG, anything after the trailer is ignored.
+    Ok(rows)
+}
+
+/// A parser for the binary COPY format.
+struct CopyBinaryFormatParser<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> CopyBinaryFormatParser<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        CopyBinaryFormatParser { data }
+    }
+
+    fn expect_header(&mut self) -> Result<(), io::Error> {
+        match self.data.strip_prefix(BINARY_SIGNATURE) {
+            Some(rest) => self.data = rest,
+            None => return Err(invalid_data("COPY file signature not recognized")),
+        }
+        // 32-bit flags field. Bit 16 indicates that the data contains OIDs,
+        // which we don't support. The other high bits indicate critical
+        // format changes; the low bits are reserved for backwards-compatible
+        // changes and must be ignored.
+        let flags = self.read_i32()?;
+        if flags & (1 << 16) != 0 {
+            return Err(invalid_data("COPY with OIDs is not supported"));
+        }
+        if flags & !0xFFFF & !(1 << 16) != 0 {
+            return Err(invalid_data(
+                "unrecognized critical flags in COPY file header",
+            ));
+        }
+        // 32-bit header extension length, followed by the extension itself,
+        // which we skip.
+        let len = self.read_i32()?;
+        let len = usize::try_from(len)
+            .map_err(|_| invalid_data("invalid COPY file header (missing length)"))?;
+        self.read_bytes(len)
+            .map_err(|_| invalid_data("invalid COPY file header (wrong length)"))?;
+        Ok(())
+    }
+
+    fn is_eof(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Reads a length-prefixed field, returning `None` if the field is NULL.
+    fn read_field(&mut self) -> Result<Option<&'a [u8]>, io::Error> {
+        let len = self.read_i32()?;
+        if len == -1 {
+            return Ok(None);
+        }
+        let len = usize::try_from(len).map_err(|_| invalid_data("invalid field size"))?;
+        self.read_bytes(len).map(Some)
+    }
+
+    fn read_i16(&mut self) -> Result<i16, io::Error> {
+        let bytes = self.read_bytes(2)?;
+        Ok(i16::from_be_bytes(
+            bytes.try_into().expect("read two bytes"),
+        ))
+    }
+
+    fn read_i32(&mut self) -> Result<i32, io::Error> {
+        let bytes = self.read_bytes(4)?;
+        Ok(i32::from_be_bytes(
+            bytes.try_into().expect("read four bytes"),
+        ))
+    }
+
+    fn read_bytes(&mut self, n: usize) -> Result<&'a [u8], io::Error> {
+        if self.data.len() < n {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "unexpected EOF in COPY data",
+            ));
+        }
+        let (bytes, rest) = self.data.split_at(n);
+        self.data = rest;
+        Ok(bytes)
+    }
+}
+
+fn invalid_data<E>(err: E) -> io::Error
+where
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
 #[cfg(test)]
 mod tests {
+    use mz_repr::adt::array::ArrayDimension;
+    use mz_repr::ScalarType;
+
     use super::*;
 
     #[test]
@@ -661,4 +806,66 @@ mod tests {
             assert!(parser.is_eof());
         }
     }
+
+    #[test]
+    fn test_copy_format_binary_roundtrip() {
+        let typ = RelationType::new(vec![
+            ScalarType::Int32.nullable(true),
+            ScalarType::String.nullable(true),
+            ScalarType::Array(Box::new(ScalarType::Int64)).nullable(true),
+        ]);
+        let column_types: Vec<_> = typ
+            .column_types
+            .iter()
+            .map(|ty| mz_pgrepr::Type::from(&ty.scalar_type))
+            .collect();
+
+        let mut array = Row::default();
+        array
+            .packer()
+            .push_array(
+                &[ArrayDimension {
+                    lower_bound: 1,
+                    length: 2,
+                }],
+                [Datum::Int64(1), Datum::Null],
+            )
+            .expect("valid array");
+        let rows = vec![
+            Row::pack_slice(&[Datum::Int32(1), Datum::String("one"), array.unpack_first()]),
+            Row::pack_slice(&[Datum::Null, Datum::String(""), Datum::Null]),
+        ];
+
+        let mut data = BINARY_SIGNATURE.to_vec();
+        data.extend(0i32.to_be_bytes());
+        data.extend(0i32.to_be_bytes());
+        for row in &rows {
+            encode_copy_row_binary(row.clone(), &typ, &mut data).expect("encoding succeeds");
+        }
+        data.extend((-1i16).to_be_bytes());
+
+        let decoded = decode_copy_format_binary(&data, &column_types).expect("decoding succeeds");
+        assert_eq!(decoded, rows);
+
+        // A missing trailer is treated as the end of the data.
+        let decoded = decode_copy_format_binary(&data[..data.len() - 2], &column_types)
+            .expect("decoding succeeds");
+        assert_eq!(decoded, rows);
+
+        // Truncated data is rejected.
+        let err = decode_copy_format_binary(&data[..data.len() - 3], &column_types).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_copy_format_binary_bad_header() {
+        let err = decode_copy_format_binary(b"PGCOPY\n", &[]).unwrap_err();
+        assert_eq!(err.to_string(), "COPY file signature not recognized");
+
+        let mut data = BINARY_SIGNATURE.to_vec();
+        data.extend((1i32 << 16).to_be_bytes());
+        data.extend(0i32.to_be_bytes());
+        let err = decode_copy_format_binary(&data, &[]).unwrap_err();
+        assert_eq!(err.to_string(), "COPY with OIDs is not supported");
+    }
 }
@@ -19,6 +19,7 @@ pub const TYPE_ANYCOMPATIBLEARRAY_OID: u32 = 5078;
 pub const TYPE_ANYCOMPATIBLENONARRAY_OID: u32 = 5079;
 pub const TYPE_ANYELEMENT_OID: u32 = 2283;
 pub const TYPE_ANYNONARRAY_OID: u32 = 2776;
+pub const TYPE_ANYRANGE_OID: u32 = 3831;
 pub const TYPE_BOOL_ARRAY_OID: u32 = 1000;
 pub const TYPE_BOOL_OID: u32 = 16;
 pub const TYPE_BPCHAR_ARRAY_OID: u32 = 1014;
@@ -29,6 +30,8 @@ pub const TYPE_CHAR_ARRAY_OID: u32 = 1002;
 pub const TYPE_CHAR_OID: u32 = 18;
 pub const TYPE_DATE_ARRAY_OID: u32 = 1182;
 pub const TYPE_DATE_OID: u32 = 1082;
+pub const TYPE_DATERANGE_ARRAY_OID: u32 = 3913;
+pub const TYPE_DATERANGE_OID: u32 = 3912;
 pub const TYPE_FLOAT4_ARRAY_OID: u32 = 1021;
 pub const TYPE_FLOAT4_OID: u32 = 700;
 pub const TYPE_FLOAT8_ARRAY_OID: u32 = 1022;
@@ -39,8 +42,12 @@ pub const TYPE_INT2_VECTOR_ARRAY_OID: u32 = 1006;
 pub const TYPE_INT2_VECTOR_OID: u32 = 22;
 pub const TYPE_INT4_ARRAY_OID: u32 = 1007;
 pub const TYPE_INT4_OID: u32 = 23;
+pub const TYPE_INT4RANGE_ARRAY_OID: u32 = 3905;
+pub const TYPE_INT4RANGE_OID: u32 = 3904;
 pub const TYPE_INT8_ARRAY_OID: u32 = 1016;
 pub const TYPE_INT8_OID: u32 = 20;
+pub const TYPE_INT8RANGE_ARRAY_OID: u32 = 3927;
+pub const TYPE_INT8RANGE_OID: u32 = 3926;
 pub const TYPE_INTERVAL_ARRAY_OID: u32 = 1187;
 pub const TYPE_INTERVAL_OID: u32 = 1186;
 pub const TYPE_JSONB_ARRAY_OID: u32 = 3807;
@@ -48,6 +55,8 @@ pub const TYPE_JSONB_OID: u32 = 3802;
 pub const TYPE_LIST_OID_OID: u32 = 16_384;
 pub const TYPE_NUMERIC_ARRAY_OID: u32 = 1231;
 pub const TYPE_NUMERIC_OID: u32 = 1700;
+pub const TYPE_NUMRANGE_ARRAY_OID: u32 = 3907;
+pub const TYPE_NUMRANGE_OID: u32 = 3906;
 pub const TYPE_OID_ARRAY_OID: u32 = 1028;
 pub const TYPE_OID_OID: u32 = 26;
 pub const TYPE_RECORD_ARRAY_OID: u32 = 2287;
@@ -66,6 +75,10 @@ pub const TYPE_TIMESTAMP_ARRAY_OID: u32 = 1115;
 pub const TYPE_TIMESTAMP_OID: u32 = 1114;
 pub const TYPE_TIMESTAMPTZ_ARRAY_OID: u32 = 1185;
 pub const TYPE_TIMESTAMPTZ_OID: u32 = 1184;
+pub const TYPE_TSRANGE_ARRAY_OID: u32 = 3909;
+pub const TYPE_TSRANGE_OID: u32 = 3908;
+pub const TYPE_TSTZRANGE_ARRAY_OID: u32 = 3911;
+pub const TYPE_TSTZRANGE_OID: u32 = 3910;
 pub const TYPE_UUID_ARRAY_OID: u32 = 2951;
 pub const TYPE_UUID_OID: u32 = 2950;
 pub const TYPE_VARCHAR_ARRAY_OID: u32 = 1015;
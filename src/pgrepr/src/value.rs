@@ -20,9 +20,11 @@ use postgres_types::{FromSql, IsNull, ToSql, Type as PgType};
 use uuid::Uuid;
 
 use mz_ore::cast::ReinterpretCast;
-use mz_repr::adt::array::ArrayDimension;
+use mz_repr::adt::array::{ArrayDimension, MAX_ARRAY_DIMENSIONS};
 use mz_repr::adt::char;
+use mz_repr::adt::datetime::Timezone;
 use mz_repr::adt::jsonb::JsonbRef;
+use mz_repr::adt::range::{RangeBoundDesc, RangeBoundDescValue, RangeDesc};
 use mz_repr::strconv::{self, Nestable};
 use mz_repr::{Datum, RelationType, Row, RowArena, ScalarType};
 
@@ -102,6 +104,8 @@ pub enum Value {
     },
     /// A Materialize timestamp.
     MzTimestamp(mz_repr::Timestamp),
+    /// A range of values, or `None` if the range is empty.
+    Range(Option<RangeDesc<Box<Value>>>),
 }
 
 impl Value {
@@ -185,6 +189,17 @@ impl Value {
                     .collect();
                 Some(Value::Map(entries))
             }
+            (Datum::Range(range), ScalarType::Range { element_type }) => {
+                let range = range.desc().map(|desc| {
+                    desc.map(|elem| {
+                        Box::new(
+                            Value::from_datum(elem, element_type)
+                                .expect("range bounds are never null"),
+                        )
+                    })
+                });
+                Some(Value::Range(range))
+            }
             _ => panic!("can't serialize {}::{:?}", datum, typ),
         }
     }
@@ -192,10 +207,22 @@ impl Value {
     /// Converts a Materialize datum from this value.
     pub fn into_datum<'a>(self, buf: &'a RowArena, typ: &Type) -> Datum<'a> {
         match self {
-            Value::Array { .. } => {
-                // This situation is handled gracefully by Value::decode; if we
-                // wind up here it's a programming error.
-                unreachable!("into_datum cannot be called on Value::Array");
+            Value::Array { dims, elements } => {
+                let elem_pg_type = match typ {
+                    Type::Array(t) => &*t,
+                    _ => panic!("Value::Array should have type Type::Array. Found {:?}", typ),
+                };
+                buf.make_datum(|packer| {
+                    packer
+                        .push_array(
+                            &dims,
+                            elements.into_iter().map(|elem| match elem {
+                                Some(elem) => elem.into_datum(buf, elem_pg_type),
+                                None => Datum::Null,
+                            }),
+                        )
+                        .expect("array dimensions validated by Value::decode");
+                })
             }
             Value::Int2Vector { .. } => {
                 // This situation is handled gracefully by Value::decode; if we
@@ -246,10 +273,22 @@ impl Value {
                 })
             }
             Value::Oid(oid) => Datum::UInt32(oid),
-            Value::Record(_) => {
-                // This situation is handled gracefully by Value::decode; if we
-                // wind up here it's a programming error.
-                unreachable!("into_datum cannot be called on Value::Record");
+            Value::Record(fields) => {
+                let field_pg_types = match typ {
+                    Type::Record(t) => t,
+                    _ => panic!(
+                        "Value::Record should have type Type::Record. Found {:?}",
+                        typ
+                    ),
+                };
+                buf.make_datum(|packer| {
+                    packer.push_list(fields.into_iter().zip(field_pg_types).map(|(field, typ)| {
+                        match field {
+                            Some(field) => field.into_datum(buf, typ),
+                            None => Datum::Null,
+                        }
+                    }));
+                })
             }
             Value::Time(t) => Datum::Time(t),
             Value::Timestamp(ts) => Datum::Timestamp(ts),
@@ -261,14 +300,38 @@ impl Value {
             Value::Uuid(u) => Datum::Uuid(u),
             Value::Numeric(n) => Datum::Numeric(n.0),
             Value::MzTimestamp(t) => Datum::MzTimestamp(t),
+            Value::Range(range) => {
+                let elem_pg_type = match typ {
+                    Type::Range { element_type } => &*element_type,
+                    _ => panic!("Value::Range should have type Type::Range. Found {:?}", typ),
+                };
+                buf.make_datum(|packer| match range {
+                    None => packer.push_empty_range(),
+                    Some(range) => {
+                        let range = range.map(|elem| elem.into_datum(buf, elem_pg_type));
+                        packer
+                            .push_range(range.lower, range.upper)
+                            .expect("range bounds validated by Value::decode");
+                    }
+                })
+            }
         }
     }
 
     /// Serializes this value to `buf` in the specified `format`.
-    pub fn encode(&self, ty: &Type, format: Format, buf: &mut BytesMut) -> Result<(), io::Error> {
+    ///
+    /// Text-encoded `timestamptz` values are rendered in the time zone `tz`.
+    /// The binary encoding is always relative to UTC and ignores `tz`.
+    pub fn encode(
+        &self,
+        ty: &Type,
+        format: Format,
+        tz: &Timezone,
+        buf: &mut BytesMut,
+    ) -> Result<(), io::Error> {
         match format {
             Format::Text => {
-                self.encode_text(buf);
+                self.encode_text_in_zone(tz, buf);
                 Ok(())
             }
             Format::Binary => self.encode_binary(ty, buf),
@@ -276,13 +339,19 @@ impl Value {
     }
 
     /// Serializes this value to `buf` using the [text encoding
-    /// format](Format::Text).
+    /// format](Format::Text), rendering `timestamptz` values in UTC.
     pub fn encode_text(&self, buf: &mut BytesMut) -> Nestable {
+        self.encode_text_in_zone(&Timezone::UTC, buf)
+    }
+
+    /// Like [`Value::encode_text`], but renders `timestamptz` values in the
+    /// time zone `tz`.
+    pub fn encode_text_in_zone(&self, tz: &Timezone, buf: &mut BytesMut) -> Nestable {
         match self {
             Value::Array { dims, elements } => {
                 strconv::format_array(buf, dims, elements, |buf, elem| match elem {
                     None => Ok::<_, ()>(buf.write_null()),
-                    Some(elem) => Ok(elem.encode_text(buf.nonnull_buffer())),
+                    Some(elem) => Ok(elem.encode_text_in_zone(tz, buf.nonnull_buffer())),
                 })
                 .expect("provided closure never fails")
             }
@@ -291,7 +360,7 @@ impl Value {
                     Ok::<_, ()>(
                         elem.as_ref()
                             .expect("Int2Vector does not support NULL values")
-                            .encode_text(buf.nonnull_buffer()),
+                            .encode_text_in_zone(tz, buf.nonnull_buffer()),
                     )
                 })
                 .expect("provided closure never fails")
@@ -315,27 +384,31 @@ impl Value {
             Value::Jsonb(js) => strconv::format_jsonb(buf, js.0.as_ref()),
             Value::List(elems) => strconv::format_list(buf, elems, |buf, elem| match elem {
                 None => Ok::<_, ()>(buf.write_null()),
-                Some(elem) => Ok(elem.encode_text(buf.nonnull_buffer())),
+                Some(elem) => Ok(elem.encode_text_in_zone(tz, buf.nonnull_buffer())),
             })
             .expect("provided closure never fails"),
             Value::Map(elems) => strconv::format_map(buf, elems, |buf, value| match value {
                 None => Ok::<_, ()>(buf.write_null()),
-                Some(elem) => Ok(elem.encode_text(buf.nonnull_buffer())),
+                Some(elem) => Ok(elem.encode_text_in_zone(tz, buf.nonnull_buffer())),
             })
             .expect("provided closure never fails"),
             Value::Oid(oid) => strconv::format_uint32(buf, *oid),
             Value::Record(elems) => strconv::format_record(buf, elems, |buf, elem| match elem {
                 None => Ok::<_, ()>(buf.write_null()),
-                Some(elem) => Ok(elem.encode_text(buf.nonnull_buffer())),
+                Some(elem) => Ok(elem.encode_text_in_zone(tz, buf.nonnull_buffer())),
             })
             .expect("provided closure never fails"),
             Value::Text(s) | Value::VarChar(s) | Value::BpChar(s) => strconv::format_string(buf, s),
             Value::Time(t) => strconv::format_time(buf, *t),
             Value::Timestamp(ts) => strconv::format_timestamp(buf, ts),
-            Value::TimestampTz(ts) => strconv::format_timestamptz(buf, ts),
+            Value::TimestampTz(ts) => strconv::format_timestamptz_in_zone(buf, ts, tz),
             Value::Uuid(u) => strconv::format_uuid(buf, *u),
             Value::Numeric(d) => strconv::format_numeric(buf, &d.0),
             Value::MzTimestamp(t) => strconv::format_mz_timestamp(buf, *t),
+            Value::Range(range) => strconv::format_range(buf, range.as_ref(), |buf, elem| {
+                Ok::<_, ()>(elem.encode_text_in_zone(tz, buf))
+            })
+            .expect("provided closure never fails"),
         }
     }
 
@@ -451,6 +524,37 @@ impl Value {
             Value::Uuid(u) => u.to_sql(&PgType::UUID, buf),
             Value::Numeric(a) => a.to_sql(&PgType::NUMERIC, buf),
             Value::MzTimestamp(t) => t.to_string().to_sql(&PgType::TEXT, buf),
+            Value::Range(range) => {
+                let elem_type = match ty {
+                    Type::Range { element_type } => element_type,
+                    _ => unreachable!(),
+                };
+                match range {
+                    None => buf.put_u8(RANGE_EMPTY),
+                    Some(range) => {
+                        let mut flags = 0;
+                        for (bound, inc, inf) in [
+                            (&range.lower, RANGE_LB_INC, RANGE_LB_INF),
+                            (&range.upper, RANGE_UB_INC, RANGE_UB_INF),
+                        ] {
+                            match bound.value {
+                                RangeBoundDescValue::Finite { .. } if bound.inclusive => {
+                                    flags |= inc
+                                }
+                                RangeBoundDescValue::Finite { .. } => (),
+                                RangeBoundDescValue::Infinite => flags |= inf,
+                            }
+                        }
+                        buf.put_u8(flags);
+                        for bound in [&range.lower, &range.upper] {
+                            if let RangeBoundDescValue::Finite { value } = &bound.value {
+                                encode_element(buf, Some(&**value), elem_type)?;
+                            }
+                        }
+                    }
+                }
+                Ok(postgres_types::IsNull::No)
+            }
         }
         .expect("encode_binary should never trigger a to_sql failure");
         if let IsNull::Yes = is_null {
@@ -540,6 +644,12 @@ impl Value {
             Type::TimestampTz { .. } => Value::TimestampTz(strconv::parse_timestamptz(s)?),
             Type::Uuid => Value::Uuid(Uuid::parse_str(s)?),
             Type::MzTimestamp => Value::MzTimestamp(strconv::parse_mz_timestamp(s)?),
+            Type::Range { element_type } => {
+                let range = strconv::parse_range(s, |elem_text| {
+                    Value::decode_text(element_type, elem_text.as_bytes()).map(Box::new)
+                })?;
+                canonicalize_range(range, element_type)?
+            }
         })
     }
 
@@ -547,7 +657,47 @@ impl Value {
     /// format](Format::Binary).
     pub fn decode_binary(ty: &Type, mut raw: &[u8]) -> Result<Value, Box<dyn Error + Sync + Send>> {
         match ty {
-            Type::Array(_) => Err("input of array types is not implemented".into()),
+            Type::Array(elem_type) => {
+                let ndims = read_i32(&mut raw)?;
+                // The "has nulls" flag is redundant with the element lengths.
+                let _has_null = read_i32(&mut raw)?;
+                let elem_oid = read_u32(&mut raw)?;
+                if elem_oid != elem_type.oid() {
+                    return Err(format!(
+                        "wrong element type: {}, expected {}",
+                        elem_oid,
+                        elem_type.oid()
+                    )
+                    .into());
+                }
+                let ndims = match usize::try_from(ndims) {
+                    Ok(ndims) if ndims <= usize::from(MAX_ARRAY_DIMENSIONS) => ndims,
+                    _ => return Err(format!("invalid number of dimensions: {}", ndims).into()),
+                };
+                let mut dims = Vec::with_capacity(ndims);
+                let mut cardinality: usize = if ndims == 0 { 0 } else { 1 };
+                for _ in 0..ndims {
+                    let length = usize::try_from(read_i32(&mut raw)?)
+                        .map_err(|_| "invalid array dimension length")?;
+                    let lower_bound = usize::try_from(read_i32(&mut raw)?)
+                        .map_err(|_| "array lower bounds must be non-negative")?;
+                    cardinality = cardinality
+                        .checked_mul(length)
+                        .ok_or("array size exceeds the maximum allowed")?;
+                    dims.push(ArrayDimension {
+                        lower_bound,
+                        length,
+                    });
+                }
+                let mut elements = Vec::new();
+                for _ in 0..cardinality {
+                    elements.push(decode_element(&mut raw, elem_type)?);
+                }
+                if !raw.is_empty() {
+                    return Err("invalid buffer size".into());
+                }
+                Ok(Value::Array { dims, elements })
+            }
             Type::Int2Vector => Err("input of int2vector types is not implemented".into()),
             Type::Bool => bool::from_sql(ty.inner(), raw).map(Value::Bool),
             Type::Bytea => Vec::<u8>::from_sql(ty.inner(), raw).map(Value::Bytea),
@@ -593,7 +743,34 @@ impl Value {
             Type::Oid | Type::RegClass | Type::RegProc | Type::RegType => {
                 u32::from_sql(ty.inner(), raw).map(Value::Oid)
             }
-            Type::Record(_) => Err("input of anonymous composite types is not implemented".into()),
+            Type::Record(field_types) => {
+                let nfields = read_i32(&mut raw)?;
+                if usize::try_from(nfields).ok() != Some(field_types.len()) {
+                    return Err(format!(
+                        "wrong number of columns: {}, expected {}",
+                        nfields,
+                        field_types.len()
+                    )
+                    .into());
+                }
+                let mut fields = Vec::with_capacity(field_types.len());
+                for field_type in field_types {
+                    let oid = read_u32(&mut raw)?;
+                    if oid != field_type.oid() {
+                        return Err(format!(
+                            "wrong data type: {}, expected {}",
+                            oid,
+                            field_type.oid()
+                        )
+                        .into());
+                    }
+                    fields.push(decode_element(&mut raw, field_type)?);
+                }
+                if !raw.is_empty() {
+                    return Err("invalid buffer size".into());
+                }
+                Ok(Value::Record(fields))
+            }
             Type::Text => String::from_sql(ty.inner(), raw).map(Value::Text),
             Type::BpChar { .. } => String::from_sql(ty.inner(), raw).map(Value::BpChar),
             Type::VarChar { .. } => String::from_sql(ty.inner(), raw).map(Value::VarChar),
@@ -615,10 +792,76 @@ impl Value {
                 let t: mz_repr::Timestamp = s.parse()?;
                 Ok(Value::MzTimestamp(t))
             }
+            Type::Range { element_type } => {
+                if raw.remaining() < 1 {
+                    return Err("insufficient data left in message".into());
+                }
+                let flags = raw.get_u8();
+                let range = if flags & RANGE_EMPTY != 0 {
+                    None
+                } else {
+                    let mut decode_bound = |inc, inf| -> Result<_, Box<dyn Error + Sync + Send>> {
+                        let value = if flags & inf != 0 {
+                            RangeBoundDescValue::Infinite
+                        } else {
+                            let value = decode_element(&mut raw, element_type)?
+                                .ok_or("range bounds cannot be null")?;
+                            RangeBoundDescValue::Finite {
+                                value: Box::new(value),
+                            }
+                        };
+                        Ok(RangeBoundDesc {
+                            inclusive: flags & inc != 0,
+                            value,
+                        })
+                    };
+                    let lower = decode_bound(RANGE_LB_INC, RANGE_LB_INF)?;
+                    let upper = decode_bound(RANGE_UB_INC, RANGE_UB_INF)?;
+                    Some(RangeDesc { lower, upper })
+                };
+                if !raw.is_empty() {
+                    return Err("invalid buffer size".into());
+                }
+                canonicalize_range(range, element_type)
+            }
         }
     }
 }
 
+// Flags that describe a range in its binary encoding. These match
+// PostgreSQL's `rangetypes.h`.
+const RANGE_EMPTY: u8 = 0x01;
+const RANGE_LB_INC: u8 = 0x02;
+const RANGE_UB_INC: u8 = 0x04;
+const RANGE_LB_INF: u8 = 0x08;
+const RANGE_UB_INF: u8 = 0x10;
+
+/// Validates and canonicalizes a range received from a client, as PostgreSQL
+/// does on input, e.g. `[1,3]` becomes `[1,4)`.
+fn canonicalize_range(
+    range: Option<RangeDesc<Box<Value>>>,
+    elem_type: &Type,
+) -> Result<Value, Box<dyn Error + Sync + Send>> {
+    let range = match range {
+        None => return Ok(Value::Range(None)),
+        Some(range) => range,
+    };
+    let elem_scalar_type = ScalarType::try_from(elem_type)?;
+    let arena = RowArena::new();
+    let range = range
+        .map(|elem| elem.into_datum(&arena, elem_type))
+        .canonicalize()?
+        .map(|range| {
+            range.map(|elem| {
+                Box::new(
+                    Value::from_datum(elem, &elem_scalar_type)
+                        .expect("range bounds are never null"),
+                )
+            })
+        });
+    Ok(Value::Range(range))
+}
+
 fn encode_element(buf: &mut BytesMut, elem: Option<&Value>, ty: &Type) -> Result<(), io::Error> {
     match elem {
         None => buf.put_i32(-1),
@@ -633,6 +876,39 @@ fn encode_element(buf: &mut BytesMut, elem: Option<&Value>, ty: &Type) -> Result
     Ok(())
 }
 
+/// Decodes a length-prefixed array element or record field, as encoded by
+/// [`encode_element`].
+fn decode_element(
+    raw: &mut &[u8],
+    ty: &Type,
+) -> Result<Option<Value>, Box<dyn Error + Sync + Send>> {
+    let len = read_i32(raw)?;
+    if len == -1 {
+        return Ok(None);
+    }
+    let len = usize::try_from(len).map_err(|_| format!("invalid element length: {}", len))?;
+    if raw.len() < len {
+        return Err("insufficient data left in message".into());
+    }
+    let (elem, rest) = raw.split_at(len);
+    *raw = rest;
+    Value::decode_binary(ty, elem).map(Some)
+}
+
+fn read_i32(raw: &mut &[u8]) -> Result<i32, Box<dyn Error + Sync + Send>> {
+    if raw.remaining() < 4 {
+        return Err("insufficient data left in message".into());
+    }
+    Ok(raw.get_i32())
+}
+
+fn read_u32(raw: &mut &[u8]) -> Result<u32, Box<dyn Error + Sync + Send>> {
+    if raw.remaining() < 4 {
+        return Err("insufficient data left in message".into());
+    }
+    Ok(raw.get_u32())
+}
+
 fn pg_len(what: &str, len: usize) -> Result<i32, io::Error> {
     len.try_into().map_err(|_| {
         io::Error::new(
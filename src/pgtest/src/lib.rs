@@ -534,8 +534,15 @@ pub fn run_test(tf: &mut datadriven::TestFile, addr: String, user: String, timeo
                             .unwrap();
                         }
                         "CopyData" => {
-                            let v: String = serde_json::from_str(args).unwrap();
-                            frontend::CopyData::new(v.as_bytes()).unwrap().write(buf);
+                            // Data that is not valid UTF-8 is specified as an
+                            // array of bytes, matching how it is printed.
+                            let v: Vec<u8> = match serde_json::from_str(args) {
+                                Ok(v) => v,
+                                Err(_) => {
+                                    serde_json::from_str::<String>(args).unwrap().into_bytes()
+                                }
+                            };
+                            frontend::CopyData::new(&v[..]).unwrap().write(buf);
                         }
                         "CopyDone" => {
                             frontend::copy_done(buf);
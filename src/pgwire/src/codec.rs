@@ -31,6 +31,7 @@ use tracing::trace;
 use mz_ore::cast::CastFrom;
 use mz_ore::future::OreSinkExt;
 use mz_ore::netio::{self, AsyncReady};
+use mz_repr::adt::datetime::Timezone;
 
 use crate::message::{
     BackendMessage, ErrorResponse, FrontendMessage, FrontendStartupMessage, VERSION_CANCEL,
@@ -156,8 +157,17 @@ where
     /// type information in the codec before sending any data row messages. This
     /// violates the abstraction boundary a bit but results in much better
     /// performance.
-    pub fn set_encode_state(&mut self, encode_state: Vec<(mz_pgrepr::Type, mz_pgrepr::Format)>) {
-        self.inner.get_mut().codec_mut().encode_state = encode_state;
+    ///
+    /// Text-encoded `timestamptz` values in data rows are rendered in the time
+    /// zone `timezone`, which should be the session's `TimeZone` setting.
+    pub fn set_encode_state(
+        &mut self,
+        encode_state: Vec<(mz_pgrepr::Type, mz_pgrepr::Format)>,
+        timezone: Timezone,
+    ) {
+        let codec = self.inner.get_mut().codec_mut();
+        codec.encode_state = encode_state;
+        codec.timezone = timezone;
     }
 
     /// Waits for the connection to be closed.
@@ -213,6 +223,7 @@ where
 struct Codec {
     decode_state: DecodeState,
     encode_state: Vec<(mz_pgrepr::Type, mz_pgrepr::Format)>,
+    timezone: Timezone,
 }
 
 impl Codec {
@@ -221,6 +232,7 @@ impl Codec {
         Codec {
             decode_state: DecodeState::Head,
             encode_state: vec![],
+            timezone: Timezone::UTC,
         }
     }
 }
@@ -315,7 +327,7 @@ impl Encoder<BackendMessage> for Codec {
                     if let Some(f) = f {
                         let base = dst.len();
                         dst.put_u32(0);
-                        f.encode(ty, *format, dst)?;
+                        f.encode(ty, *format, &self.timezone, dst)?;
                         let len = dst.len() - base - 4;
                         let len = i32::try_from(len).map_err(|_| {
                             io::Error::new(
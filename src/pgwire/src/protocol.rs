@@ -1274,6 +1274,7 @@ where
             ExecuteResponse::AlteredIndexLogicalCompaction
             | ExecuteResponse::AlteredObject(..)
             | ExecuteResponse::AlteredSystemConfiguration
+            | ExecuteResponse::Analyzed
             | ExecuteResponse::CreatedComputeInstance { .. }
             | ExecuteResponse::CreatedComputeReplica { .. }
             | ExecuteResponse::CreatedConnection { .. }
@@ -1308,9 +1309,11 @@ where
             | ExecuteResponse::DroppedTable
             | ExecuteResponse::DroppedType
             | ExecuteResponse::DroppedView
+            | ExecuteResponse::GrantedPrivilege
             | ExecuteResponse::Inserted(..)
             | ExecuteResponse::Prepare
             | ExecuteResponse::Raised
+            | ExecuteResponse::RevokedPrivilege
             | ExecuteResponse::StartedTransaction { .. }
             | ExecuteResponse::TransactionCommitted
             | ExecuteResponse::TransactionRolledBack
@@ -1359,6 +1362,7 @@ where
             ExecuteTimeout::WaitOnce => (true, None),
         };
 
+        let timezone = *self.adapter_client.session().vars().timezone();
         self.conn.set_encode_state(
             row_desc
                 .typ()
@@ -1367,6 +1371,7 @@ where
                 .map(|ty| mz_pgrepr::Type::from(&ty.scalar_type))
                 .zip(result_formats)
                 .collect(),
+            timezone,
         );
 
         let mut total_sent_rows = 0;
@@ -1516,12 +1521,23 @@ where
         row_desc: RelationDesc,
         mut stream: RowBatchStream,
     ) -> Result<State, io::Error> {
+        // Text-encoded `timestamptz` values are rendered in the session's time
+        // zone, as they are for rows sent via the extended query protocol.
+        let timezone = *self.adapter_client.session().vars().timezone();
         let (encode_fn, encode_format): (
-            fn(Row, &RelationType, &mut Vec<u8>) -> Result<(), std::io::Error>,
+            Box<dyn Fn(Row, &RelationType, &mut Vec<u8>) -> Result<(), std::io::Error> + Send>,
             mz_pgrepr::Format,
         ) = match format {
-            CopyFormat::Text => (mz_pgcopy::encode_copy_row_text, mz_pgrepr::Format::Text),
-            CopyFormat::Binary => (mz_pgcopy::encode_copy_row_binary, mz_pgrepr::Format::Binary),
+            CopyFormat::Text => (
+                Box::new(move |row, typ, out| {
+                    mz_pgcopy::encode_copy_row_text(row, typ, &timezone, out)
+                }),
+                mz_pgrepr::Format::Text,
+            ),
+            CopyFormat::Binary => (
+                Box::new(mz_pgcopy::encode_copy_row_binary),
+                mz_pgrepr::Format::Binary,
+            ),
             _ => {
                 return self
                     .error(ErrorResponse::error(
@@ -1625,9 +1641,13 @@ where
         row_desc: RelationDesc,
     ) -> Result<State, io::Error> {
         let typ = row_desc.typ();
-        let column_formats = vec![mz_pgrepr::Format::Text; typ.column_types.len()];
+        let format = match params {
+            CopyFormatParams::Binary => mz_pgrepr::Format::Binary,
+            _ => mz_pgrepr::Format::Text,
+        };
+        let column_formats = vec![format; typ.column_types.len()];
         self.send(BackendMessage::CopyInResponse {
-            overall_format: mz_pgrepr::Format::Text,
+            overall_format: format,
             column_formats,
         })
         .await?;
@@ -16,7 +16,7 @@ use std::collections::VecDeque;
 use std::fmt;
 use std::str::FromStr;
 
-use chrono::{FixedOffset, NaiveDate, NaiveTime};
+use chrono::{FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone};
 use chrono_tz::Tz;
 use mz_proto::{RustType, TryFromProtoError};
 use proptest::prelude::*;
@@ -410,6 +410,39 @@ pub enum Timezone {
     Tz(#[proptest(strategy = "any_timezone()")] Tz),
 }
 
+impl Timezone {
+    /// The UTC time zone, which is the default session time zone.
+    pub const UTC: Timezone = Timezone::Tz(Tz::UTC);
+
+    /// Reports whether this time zone is always equivalent to UTC, i.e. it
+    /// has a zero offset and never observes daylight saving time.
+    pub fn is_utc(&self) -> bool {
+        match self {
+            Timezone::FixedOffset(offset) => offset.local_minus_utc() == 0,
+            Timezone::Tz(tz) => matches!(
+                tz,
+                Tz::UTC
+                    | Tz::UCT
+                    | Tz::Universal
+                    | Tz::Zulu
+                    | Tz::Etc__UTC
+                    | Tz::Etc__UCT
+                    | Tz::Etc__Universal
+                    | Tz::Etc__Zulu
+            ),
+        }
+    }
+
+    /// Returns the offset from UTC that is in effect in this time zone at the
+    /// UTC instant `utc`, taking daylight saving time into account.
+    pub fn offset_at(&self, utc: &NaiveDateTime) -> FixedOffset {
+        match self {
+            Timezone::FixedOffset(offset) => *offset,
+            Timezone::Tz(tz) => tz.offset_from_utc_datetime(utc).fix(),
+        }
+    }
+}
+
 impl RustType<ProtoTimezone> for Timezone {
     fn into_proto(&self) -> ProtoTimezone {
         use proto_timezone::Kind;
@@ -9,14 +9,22 @@
 
 use std::any::type_name;
 use std::cmp::Ordering;
+use std::convert::Infallible;
 use std::error::Error;
 use std::fmt::{self, Debug, Display};
 
 use bitflags::bitflags;
+use proptest_derive::Arbitrary;
+use serde::{Deserialize, Serialize};
+
+use mz_lowertest::MzReflect;
+use mz_proto::{RustType, TryFromProtoError};
 
 use crate::row::DatumNested;
 use crate::Datum;
 
+include!(concat!(env!("OUT_DIR"), "/mz_repr.adt.range.rs"));
+
 bitflags! {
     pub(crate) struct Flags: u8 {
         const EMPTY = 1;
@@ -48,13 +56,162 @@ pub trait RangeOps<'a>: Debug + Ord + PartialOrd + Eq + PartialEq + TryFrom<Datu
 impl<'a, T: Debug + Ord + PartialOrd + Eq + PartialEq + TryFrom<Datum<'a>>> RangeOps<'a> for T {}
 
 impl<'a> Range<'a> {
-    #[allow(dead_code)]
-    fn contains<T: RangeOps<'a>>(&self, elem: &T) -> bool {
+    /// The empty range.
+    pub const EMPTY: Range<'a> = Range { inner: None };
+
+    /// Returns a range whose bounds are `lower` and `upper`, or the empty range
+    /// if the bounds do not enclose any values.
+    fn from_bounds(lower: RangeLowerBound<'a>, upper: RangeUpperBound<'a>) -> Range<'a> {
+        if lower.cmp_bound(&upper) == Ordering::Greater {
+            Range::EMPTY
+        } else {
+            Range {
+                inner: Some(RangeInner { lower, upper }),
+            }
+        }
+    }
+
+    /// Returns the lower bound of the range, or `None` if the range is empty.
+    pub fn lower(&self) -> Option<RangeLowerBound<'a>> {
+        self.inner.map(|inner| inner.lower)
+    }
+
+    /// Returns the upper bound of the range, or `None` if the range is empty.
+    pub fn upper(&self) -> Option<RangeUpperBound<'a>> {
+        self.inner.map(|inner| inner.upper)
+    }
+
+    /// Returns a description of the range's bounds, or `None` if the range is
+    /// empty.
+    pub fn desc(&self) -> Option<RangeDesc<Datum<'a>>> {
+        self.inner.map(|inner| RangeDesc {
+            lower: inner.lower.desc(),
+            upper: inner.upper.desc(),
+        })
+    }
+
+    /// Does the range contain `elem`?
+    pub fn contains<T: RangeOps<'a>>(&self, elem: &T) -> bool {
         match self.inner {
             None => false,
             Some(inner) => inner.lower.satisfied_by(elem) && inner.upper.satisfied_by(elem),
         }
     }
+
+    /// Does the range contain every value of `other`?
+    pub fn contains_range(&self, other: &Range<'a>) -> bool {
+        match (self.inner, other.inner) {
+            (_, None) => true,
+            (None, Some(_)) => false,
+            (Some(i), Some(o)) => i.lower <= o.lower && i.upper >= o.upper,
+        }
+    }
+
+    /// Do the range and `other` share any values?
+    pub fn overlaps(&self, other: &Range<'a>) -> bool {
+        match (self.inner, other.inner) {
+            (Some(i), Some(o)) => {
+                i.lower.cmp_bound(&o.upper) != Ordering::Greater
+                    && o.lower.cmp_bound(&i.upper) != Ordering::Greater
+            }
+            _ => false,
+        }
+    }
+
+    /// Does the range abut `other` without sharing any values with it?
+    pub fn adjacent(&self, other: &Range<'a>) -> bool {
+        match (self.inner, other.inner) {
+            (Some(i), Some(o)) => i.upper.adjacent(&o.lower) || o.upper.adjacent(&i.lower),
+            _ => false,
+        }
+    }
+
+    /// Returns the smallest range containing every value of the range and of
+    /// `other`.
+    ///
+    /// # Errors
+    /// - If the ranges neither overlap nor are adjacent, i.e. their union is
+    ///   not itself a range.
+    pub fn union(&self, other: &Range<'a>) -> Result<Range<'a>, RangeError> {
+        let (i, o) = match (self.inner, other.inner) {
+            (None, _) => return Ok(*other),
+            (_, None) => return Ok(*self),
+            (Some(i), Some(o)) => (i, o),
+        };
+
+        if !self.overlaps(other) && !self.adjacent(other) {
+            return Err(RangeError::DiscontiguousUnion);
+        }
+
+        Ok(Range {
+            inner: Some(RangeInner {
+                lower: std::cmp::min(i.lower, o.lower),
+                upper: std::cmp::max(i.upper, o.upper),
+            }),
+        })
+    }
+
+    /// Returns the range of values contained in both the range and `other`.
+    pub fn intersection(&self, other: &Range<'a>) -> Range<'a> {
+        match (self.inner, other.inner) {
+            (Some(i), Some(o)) if self.overlaps(other) => Range {
+                inner: Some(RangeInner {
+                    lower: std::cmp::max(i.lower, o.lower),
+                    upper: std::cmp::min(i.upper, o.upper),
+                }),
+            },
+            _ => Range::EMPTY,
+        }
+    }
+
+    /// Returns the range of values contained in the range but not in `other`.
+    ///
+    /// # Errors
+    /// - If `other` lies strictly within the range, i.e. the difference is not
+    ///   itself a range.
+    pub fn difference(&self, other: &Range<'a>) -> Result<Range<'a>, RangeError> {
+        let (i, o) = match (self.inner, other.inner) {
+            (Some(i), Some(o)) => (i, o),
+            _ => return Ok(*self),
+        };
+
+        let cmp_lowers = i.lower.cmp(&o.lower);
+        let cmp_uppers = i.upper.cmp(&o.upper);
+
+        if cmp_lowers == Ordering::Less && cmp_uppers == Ordering::Greater {
+            return Err(RangeError::DiscontiguousDifference);
+        }
+
+        // `other` lies entirely before or after the range.
+        if !self.overlaps(other) {
+            return Ok(*self);
+        }
+
+        // `other` covers the range.
+        if cmp_lowers != Ordering::Less && cmp_uppers != Ordering::Greater {
+            return Ok(Range::EMPTY);
+        }
+
+        // `other` covers the range's upper portion, so the range now ends
+        // where `other` begins, or vice versa.
+        Ok(if cmp_lowers == Ordering::Less {
+            Range::from_bounds(
+                i.lower,
+                RangeBound {
+                    inclusive: !o.lower.inclusive,
+                    bound: o.lower.bound,
+                },
+            )
+        } else {
+            Range::from_bounds(
+                RangeBound {
+                    inclusive: !o.upper.inclusive,
+                    bound: o.upper.bound,
+                },
+                i.upper,
+            )
+        })
+    }
 }
 
 /// Holds the upper and lower `DatumRangeBound`s for non-empty ranges.
@@ -107,23 +264,7 @@ impl<'a, const UPPER: bool> Display for RangeBound<'a, UPPER> {
 
 impl<'a, const UPPER: bool> Ord for RangeBound<'a, UPPER> {
     fn cmp(&self, other: &Self) -> Ordering {
-        let ordering = match self.bound.cmp(&other.bound) {
-            Ordering::Equal => {
-                if self.inclusive == other.inclusive {
-                    Ordering::Equal
-                } else if self.inclusive {
-                    Ordering::Less
-                } else {
-                    Ordering::Greater
-                }
-            }
-            o => o,
-        };
-        if UPPER {
-            ordering.reverse()
-        } else {
-            ordering
-        }
+        self.cmp_bound(other)
     }
 }
 
@@ -140,6 +281,56 @@ pub type RangeLowerBound<'a> = RangeBound<'a, false>;
 pub type RangeUpperBound<'a> = RangeBound<'a, true>;
 
 impl<'a, const UPPER: bool> RangeBound<'a, UPPER> {
+    /// Returns the bound's value, or `None` if the bound is infinite.
+    pub fn datum(&self) -> Option<Datum<'a>> {
+        self.bound.map(|bound| bound.datum())
+    }
+
+    /// Returns a description of the bound.
+    pub fn desc(&self) -> RangeBoundDesc<Datum<'a>> {
+        RangeBoundDesc {
+            inclusive: self.inclusive,
+            value: match self.datum() {
+                None => RangeBoundDescValue::Infinite,
+                Some(value) => RangeBoundDescValue::Finite { value },
+            },
+        }
+    }
+
+    /// Compares the positions of `self` and `other`, either of which may be a
+    /// lower or an upper bound.
+    ///
+    /// Infinite lower bounds precede, and infinite upper bounds follow, all
+    /// finite bounds. Between bounds of equal value, an exclusive upper bound
+    /// precedes an inclusive bound, which precedes an exclusive lower bound.
+    fn cmp_bound<const OTHER_UPPER: bool>(&self, other: &RangeBound<'a, OTHER_UPPER>) -> Ordering {
+        let ordering = match (self.bound, other.bound) {
+            (None, None) if UPPER == OTHER_UPPER => Ordering::Equal,
+            (None, _) if UPPER => Ordering::Greater,
+            (None, _) => Ordering::Less,
+            (_, None) if OTHER_UPPER => Ordering::Less,
+            (_, None) => Ordering::Greater,
+            (Some(s), Some(o)) => s.cmp(&o),
+        };
+        let position = |inclusive: bool, upper: bool| match (inclusive, upper) {
+            (true, _) => 0,
+            (false, true) => -1,
+            (false, false) => 1,
+        };
+        ordering.then_with(|| {
+            position(self.inclusive, UPPER).cmp(&position(other.inclusive, OTHER_UPPER))
+        })
+    }
+
+    /// Does `other` begin exactly where `self` ends (or vice versa) without
+    /// both bounds containing their shared value?
+    fn adjacent<const OTHER_UPPER: bool>(&self, other: &RangeBound<'a, OTHER_UPPER>) -> bool {
+        match (self.bound, other.bound) {
+            (Some(s), Some(o)) => s == o && self.inclusive != other.inclusive,
+            _ => false,
+        }
+    }
+
     /// Determines where `elem` lies in relation to the range bound.
     ///
     /// # Panics
@@ -205,9 +396,159 @@ impl<'a> RangeBoundDesc<Datum<'a>> {
     }
 }
 
-#[derive(Debug, Eq, PartialEq)]
+impl<D> RangeBoundDesc<D> {
+    /// Returns the bound's value, or `None` if the bound is infinite.
+    pub fn finite_value(&self) -> Option<&D> {
+        match &self.value {
+            RangeBoundDescValue::Finite { value } => Some(value),
+            RangeBoundDescValue::Infinite => None,
+        }
+    }
+
+    /// Maps the bound's value, if any, using `f`.
+    pub fn try_map<E, F, R>(self, f: F) -> Result<RangeBoundDesc<R>, E>
+    where
+        F: FnOnce(D) -> Result<R, E>,
+    {
+        Ok(RangeBoundDesc {
+            inclusive: self.inclusive,
+            value: match self.value {
+                RangeBoundDescValue::Finite { value } => {
+                    RangeBoundDescValue::Finite { value: f(value)? }
+                }
+                RangeBoundDescValue::Infinite => RangeBoundDescValue::Infinite,
+            },
+        })
+    }
+}
+
+/// Describes the bounds of a non-empty range, independent of how the range is
+/// encoded.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd)]
+pub struct RangeDesc<D> {
+    pub lower: RangeBoundDesc<D>,
+    pub upper: RangeBoundDesc<D>,
+}
+
+impl<D> RangeDesc<D> {
+    /// Maps the values of both bounds, if any, using `f`.
+    pub fn try_map<E, F, R>(self, mut f: F) -> Result<RangeDesc<R>, E>
+    where
+        F: FnMut(D) -> Result<R, E>,
+    {
+        Ok(RangeDesc {
+            lower: self.lower.try_map(&mut f)?,
+            upper: self.upper.try_map(&mut f)?,
+        })
+    }
+
+    /// Like [`RangeDesc::try_map`], but for infallible `f`.
+    pub fn map<F, R>(self, mut f: F) -> RangeDesc<R>
+    where
+        F: FnMut(D) -> R,
+    {
+        match self.try_map(|d| Ok::<_, Infallible>(f(d))) {
+            Ok(desc) => desc,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl<'a> RangeDesc<Datum<'a>> {
+    /// Canonicalizes the bounds the way PostgreSQL does when constructing a
+    /// range, returning `None` if the range is empty.
+    ///
+    /// Infinite bounds are made exclusive, and ranges over discrete types
+    /// (`int4`, `int8` and `date`) are normalized to the `[)` form, e.g.
+    /// `[1,3]` becomes `[1,4)`.
+    ///
+    /// # Errors
+    /// - If the lower bound is greater than the upper bound.
+    /// - If normalizing a discrete bound overflows its type.
+    pub fn canonicalize(mut self) -> Result<Option<RangeDesc<Datum<'a>>>, RangeError> {
+        fn is_empty(desc: &RangeDesc<Datum>) -> Result<bool, RangeError> {
+            Ok(
+                match (desc.lower.finite_value(), desc.upper.finite_value()) {
+                    (Some(lower), Some(upper)) => match lower.cmp(upper) {
+                        Ordering::Greater => return Err(RangeError::MisorderedRangeBounds),
+                        Ordering::Equal => !(desc.lower.inclusive && desc.upper.inclusive),
+                        Ordering::Less => false,
+                    },
+                    _ => false,
+                },
+            )
+        }
+
+        if self.lower.finite_value().is_none() {
+            self.lower.inclusive = false;
+        }
+        if self.upper.finite_value().is_none() {
+            self.upper.inclusive = false;
+        }
+
+        if is_empty(&self)? {
+            return Ok(None);
+        }
+
+        if let RangeBoundDescValue::Finite { value } = &mut self.lower.value {
+            if !self.lower.inclusive {
+                if let Some(successor) = discrete_successor(*value) {
+                    *value = successor?;
+                    self.lower.inclusive = true;
+                }
+            }
+        }
+        if let RangeBoundDescValue::Finite { value } = &mut self.upper.value {
+            if self.upper.inclusive {
+                if let Some(successor) = discrete_successor(*value) {
+                    *value = successor?;
+                    self.upper.inclusive = false;
+                }
+            }
+        }
+
+        // Normalizing discrete bounds can empty the range, e.g. `(1,2)`.
+        if is_empty(&self)? {
+            return Ok(None);
+        }
+
+        Ok(Some(self))
+    }
+}
+
+/// Returns the value following `d` if `d` belongs to a discrete range type, or
+/// `None` otherwise.
+fn discrete_successor(d: Datum) -> Option<Result<Datum, RangeError>> {
+    let overflow = |type_name: &str| RangeError::CanonicalOverflow(type_name.to_string());
+    match d {
+        Datum::Int32(i) => Some(
+            i.checked_add(1)
+                .map(Datum::Int32)
+                .ok_or_else(|| overflow("integer")),
+        ),
+        Datum::Int64(i) => Some(
+            i.checked_add(1)
+                .map(Datum::Int64)
+                .ok_or_else(|| overflow("bigint")),
+        ),
+        Datum::Date(d) => Some(
+            d.checked_add(1)
+                .map(Datum::Date)
+                .map_err(|_| overflow("date")),
+        ),
+        _ => None,
+    }
+}
+
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
 pub enum RangeError {
     MisorderedRangeBounds,
+    CanonicalOverflow(String),
+    InvalidRangeBoundFlags,
+    DiscontiguousUnion,
+    DiscontiguousDifference,
 }
 
 impl Display for RangeError {
@@ -216,6 +557,14 @@ impl Display for RangeError {
             RangeError::MisorderedRangeBounds => {
                 f.write_str("range lower bound must be less than or equal to range upper bound")
             }
+            RangeError::CanonicalOverflow(type_name) => write!(f, "{type_name} out of range"),
+            RangeError::InvalidRangeBoundFlags => f.write_str("invalid range bound flags"),
+            RangeError::DiscontiguousUnion => {
+                f.write_str("result of range union would not be contiguous")
+            }
+            RangeError::DiscontiguousDifference => {
+                f.write_str("result of range difference would not be contiguous")
+            }
         }
     }
 }
@@ -233,6 +582,34 @@ impl From<RangeError> for String {
     }
 }
 
+impl RustType<ProtoRangeError> for RangeError {
+    fn into_proto(&self) -> ProtoRangeError {
+        use proto_range_error::Kind::*;
+        let kind = match self {
+            RangeError::MisorderedRangeBounds => MisorderedRangeBounds(()),
+            RangeError::CanonicalOverflow(type_name) => CanonicalOverflow(type_name.clone()),
+            RangeError::InvalidRangeBoundFlags => InvalidRangeBoundFlags(()),
+            RangeError::DiscontiguousUnion => DiscontiguousUnion(()),
+            RangeError::DiscontiguousDifference => DiscontiguousDifference(()),
+        };
+        ProtoRangeError { kind: Some(kind) }
+    }
+
+    fn from_proto(proto: ProtoRangeError) -> Result<Self, TryFromProtoError> {
+        use proto_range_error::Kind::*;
+        match proto.kind {
+            Some(kind) => Ok(match kind {
+                MisorderedRangeBounds(()) => RangeError::MisorderedRangeBounds,
+                CanonicalOverflow(type_name) => RangeError::CanonicalOverflow(type_name),
+                InvalidRangeBoundFlags(()) => RangeError::InvalidRangeBoundFlags,
+                DiscontiguousUnion(()) => RangeError::DiscontiguousUnion,
+                DiscontiguousDifference(()) => RangeError::DiscontiguousDifference,
+            }),
+            None => Err(TryFromProtoError::missing_field("`ProtoRangeError::kind`")),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
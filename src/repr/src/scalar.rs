@@ -687,6 +687,19 @@ impl<'a> Datum<'a> {
         }
     }
 
+    /// Unwraps the range value within this datum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the datum is not [`Datum::Range`].
+    #[track_caller]
+    pub fn unwrap_range(&self) -> Range<'a> {
+        match self {
+            Datum::Range(range) => *range,
+            _ => panic!("Datum::unwrap_range called on {:?}", self),
+        }
+    }
+
     /// Reports whether this datum is an instance of the specified column type.
     pub fn is_instance_of(self, column_type: &ColumnType) -> bool {
         fn is_instance_of_scalar(datum: Datum, scalar_type: &ScalarType) -> bool {
@@ -787,9 +800,16 @@ impl<'a> Datum<'a> {
                     (Datum::Numeric(_), _) => false,
                     (Datum::MzTimestamp(_), ScalarType::MzTimestamp) => true,
                     (Datum::MzTimestamp(_), _) => false,
-                    (Datum::Range(_), _) => {
-                        unreachable!("ranges not available in dataflows or types")
+                    (Datum::Range(range), ScalarType::Range { element_type }) => {
+                        match range.inner {
+                            None => true,
+                            Some(inner) => [inner.lower.datum(), inner.upper.datum()]
+                                .into_iter()
+                                .flatten()
+                                .all(|d| is_instance_of_scalar(d, element_type)),
+                        }
                     }
+                    (Datum::Range(_), _) => false,
                 }
             }
         }
@@ -1188,6 +1208,9 @@ pub enum ScalarType {
     Int2Vector,
     /// A Materialize timestamp.
     MzTimestamp,
+    /// The type of [`Datum::Range`]. Represents a continuous set of values of
+    /// `element_type`, whose bounds may not be [`Datum::Null`].
+    Range { element_type: Box<ScalarType> },
 }
 
 impl RustType<ProtoRecordField> for (ColumnName, ColumnType) {
@@ -1270,6 +1293,9 @@ impl RustType<ProtoScalarType> for ScalarType {
                     custom_id: custom_id.map(|id| id.into_proto()),
                 })),
                 ScalarType::MzTimestamp => MzTimestamp(()),
+                ScalarType::Range { element_type } => Range(Box::new(ProtoRange {
+                    element_type: Some(element_type.into_proto()),
+                })),
             }),
         }
     }
@@ -1342,6 +1368,13 @@ impl RustType<ProtoScalarType> for ScalarType {
                 custom_id: x.custom_id.map(|id| id.into_rust().unwrap()),
             }),
             MzTimestamp(()) => Ok(ScalarType::MzTimestamp),
+            Range(x) => Ok(ScalarType::Range {
+                element_type: Box::new(
+                    x.element_type
+                        .map(|x| *x)
+                        .into_rust_if_some("ProtoRange::element_type")?,
+                ),
+            }),
         }
     }
 }
@@ -1990,6 +2023,9 @@ impl<'a> ScalarType {
             // to support Char values of different lengths in e.g. lists.
             Char { .. } => Char { length: None },
             VarChar { .. } => VarChar { max_length: None },
+            Range { element_type } => Range {
+                element_type: Box::new(element_type.without_modifiers()),
+            },
             v => v.clone(),
         }
     }
@@ -2041,6 +2077,18 @@ impl<'a> ScalarType {
         }
     }
 
+    /// Returns the [`ScalarType`] of elements in a [`ScalarType::Range`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if called on anything other than a [`ScalarType::Range`].
+    pub fn unwrap_range_element_type(&self) -> &ScalarType {
+        match self {
+            ScalarType::Range { element_type } => &**element_type,
+            _ => panic!("ScalarType::unwrap_range_element_type called on {:?}", self),
+        }
+    }
+
     /// Returns the length of a [`ScalarType::Char`].
     ///
     /// # Panics
@@ -2186,6 +2234,7 @@ impl<'a> ScalarType {
                 },
             ) => l.eq_inner(r, structure_only) && (oid_l == oid_r || structure_only),
             (Array(a), Array(b)) => a.eq_inner(b, structure_only),
+            (Range { element_type: l }, Range { element_type: r }) => l.eq_inner(r, structure_only),
             (
                 Record {
                     fields: fields_a,
@@ -2433,6 +2482,7 @@ impl<'a> ScalarType {
                 Datum::MzTimestamp(crate::Timestamp::MAX),
             ])
         });
+        static RANGE: Lazy<Row> = Lazy::new(|| Row::pack_slice(&[Datum::Range(Range::EMPTY)]));
 
         match self {
             ScalarType::Bool => (*BOOL).iter(),
@@ -2467,6 +2517,7 @@ impl<'a> ScalarType {
             ScalarType::RegClass => (*REGCLASS).iter(),
             ScalarType::Int2Vector => (*INT2VECTOR).iter(),
             ScalarType::MzTimestamp => (*MZTIMESTAMP).iter(),
+            ScalarType::Range { .. } => (*RANGE).iter(),
         }
     }
 
@@ -2575,6 +2626,10 @@ impl Arbitrary for ScalarType {
                 prop_oneof![
                     // Array
                     inner.clone().prop_map(|x| ScalarType::Array(Box::new(x))),
+                    // Range
+                    inner.clone().prop_map(|x| ScalarType::Range {
+                        element_type: Box::new(x)
+                    }),
                     // List
                     (inner.clone(), any::<Option<GlobalId>>()).prop_map(|(x, id)| {
                         ScalarType::List {
@@ -52,10 +52,11 @@ use mz_proto::{RustType, TryFromProtoError};
 
 use crate::adt::array::ArrayDimension;
 use crate::adt::date::Date;
-use crate::adt::datetime::{self, DateTimeField, ParsedDateTime};
+use crate::adt::datetime::{self, DateTimeField, ParsedDateTime, Timezone};
 use crate::adt::interval::Interval;
 use crate::adt::jsonb::{Jsonb, JsonbRef};
 use crate::adt::numeric::{self, Numeric, NUMERIC_DATUM_MAX_PRECISION};
+use crate::adt::range::{RangeBoundDesc, RangeBoundDescValue, RangeDesc};
 use crate::adt::timestamp::CheckedTimestamp;
 
 include!(concat!(env!("OUT_DIR"), "/mz_repr.strconv.rs"));
@@ -366,7 +367,9 @@ where
 /// <time zone interval> ::=
 ///     <sign> <hours value> <colon> <minutes value>
 /// ```
-fn parse_timestamp_string(s: &str) -> Result<(NaiveDate, NaiveTime, datetime::Timezone), String> {
+fn parse_timestamp_string(
+    s: &str,
+) -> Result<(NaiveDate, NaiveTime, Option<datetime::Timezone>), String> {
     if s.is_empty() {
         return Err("timestamp string is empty".into());
     }
@@ -379,7 +382,7 @@ fn parse_timestamp_string(s: &str) -> Result<(NaiveDate, NaiveTime, datetime::Ti
         return Ok((
             NaiveDate::from_ymd_opt(1970, 1, 1).unwrap(),
             NaiveTime::from_hms_opt(0, 0, 0).unwrap(),
-            Default::default(),
+            Some(Default::default()),
         ));
     }
 
@@ -390,9 +393,9 @@ fn parse_timestamp_string(s: &str) -> Result<(NaiveDate, NaiveTime, datetime::Ti
     let t: NaiveTime = pdt.compute_time()?;
 
     let offset = if tz_string.is_empty() {
-        Default::default()
+        None
     } else {
-        tz_string.parse()?
+        Some(tz_string.parse()?)
     };
 
     Ok((d, t, offset))
@@ -469,11 +472,20 @@ where
 
 /// Parses a `DateTime<Utc>` from `s`. See `mz_expr::scalar::func::timezone_timestamp` for timezone anomaly considerations.
 pub fn parse_timestamptz(s: &str) -> Result<CheckedTimestamp<DateTime<Utc>>, ParseError> {
+    parse_timestamptz_in_zone(s, &Timezone::UTC)
+}
+
+/// Like [`parse_timestamptz`], but interprets timestamps that do not specify a
+/// time zone as local times in the time zone `default_tz`.
+pub fn parse_timestamptz_in_zone(
+    s: &str,
+    default_tz: &Timezone,
+) -> Result<CheckedTimestamp<DateTime<Utc>>, ParseError> {
     parse_timestamp_string(s)
         .and_then(|(date, time, timezone)| {
             use datetime::Timezone::*;
             let mut dt = date.and_time(time);
-            let offset = match timezone {
+            let offset = match timezone.unwrap_or(*default_tz) {
                 FixedOffset(offset) => offset,
                 Tz(tz) => match tz.offset_from_local_datetime(&dt).latest() {
                     Some(offset) => offset.fix(),
@@ -502,10 +514,22 @@ pub fn format_timestamptz<F>(buf: &mut F, ts: &DateTime<Utc>) -> Nestable
 where
     F: FormatBuffer,
 {
-    let (year_ad, year) = ts.year_ce();
-    write!(buf, "{:04}-{}", year, ts.format("%m-%d %H:%M:%S"));
-    format_nanos_to_micros(buf, ts.timestamp_subsec_nanos());
-    write!(buf, "+00");
+    format_timestamptz_in_zone(buf, ts, &Timezone::UTC)
+}
+
+/// Writes `ts` to `buf` as the wall-clock time in the time zone `tz`, followed
+/// by the UTC offset in effect in `tz` at that instant, e.g.
+/// `2020-07-01 08:00:00-04` for `America/New_York`.
+pub fn format_timestamptz_in_zone<F>(buf: &mut F, ts: &DateTime<Utc>, tz: &Timezone) -> Nestable
+where
+    F: FormatBuffer,
+{
+    let offset = tz.offset_at(&ts.naive_utc());
+    let local = ts.naive_utc() + offset;
+    let (year_ad, year) = local.year_ce();
+    write!(buf, "{:04}-{}", year, local.format("%m-%d %H:%M:%S"));
+    format_nanos_to_micros(buf, local.timestamp_subsec_nanos());
+    format_utc_offset(buf, offset.local_minus_utc());
     if !year_ad {
         write!(buf, " BC");
     }
@@ -513,6 +537,24 @@ where
     Nestable::MayNeedEscaping
 }
 
+/// Writes a UTC offset of `secs` seconds the way PostgreSQL does, omitting the
+/// minutes and seconds components when they are zero, e.g. `+00`, `-03:30`.
+fn format_utc_offset<F>(buf: &mut F, secs: i32)
+where
+    F: FormatBuffer,
+{
+    let sign = if secs < 0 { '-' } else { '+' };
+    let secs = secs.unsigned_abs();
+    let (hours, minutes, seconds) = (secs / 3600, (secs / 60) % 60, secs % 60);
+    write!(buf, "{}{:02}", sign, hours);
+    if minutes != 0 || seconds != 0 {
+        write!(buf, ":{:02}", minutes);
+    }
+    if seconds != 0 {
+        write!(buf, ":{:02}", seconds);
+    }
+}
+
 /// parse
 ///
 /// ```text
@@ -1051,6 +1093,119 @@ fn lex_unquoted_element<'a>(
     })
 }
 
+/// Parses a range from `s`, using `gen_elem` to parse the value of each finite
+/// bound. Returns `None` if `s` describes the empty range.
+///
+/// The returned bounds are not canonicalized; see [`RangeDesc::canonicalize`].
+pub fn parse_range<'a, V, E>(
+    s: &'a str,
+    gen_elem: impl FnMut(Cow<'a, str>) -> Result<V, E>,
+) -> Result<Option<RangeDesc<V>>, ParseError>
+where
+    E: fmt::Display,
+{
+    parse_range_inner(s, gen_elem)
+        .map_err(|details| ParseError::invalid_input_syntax("range", s).with_details(details))
+}
+
+fn parse_range_inner<'a, V, E>(
+    s: &'a str,
+    mut gen_elem: impl FnMut(Cow<'a, str>) -> Result<V, E>,
+) -> Result<Option<RangeDesc<V>>, String>
+where
+    E: fmt::Display,
+{
+    if s.trim().eq_ignore_ascii_case("empty") {
+        return Ok(None);
+    }
+
+    let buf = &mut LexBuf::new(s);
+    let mut gen = |elem| gen_elem(elem).map_err_to_string();
+
+    buf.take_while(|ch| ch.is_ascii_whitespace());
+    let lower_inclusive = match buf.next() {
+        Some('[') => true,
+        Some('(') => false,
+        _ => bail!("missing left parenthesis or bracket"),
+    };
+
+    let lower = lex_range_bound(buf)?;
+    if !buf.consume(',') {
+        bail!("missing comma after lower bound")
+    }
+    let upper = lex_range_bound(buf)?;
+
+    let upper_inclusive = match buf.next() {
+        Some(']') => true,
+        Some(')') => false,
+        _ => bail!("missing right parenthesis or bracket"),
+    };
+    buf.take_while(|ch| ch.is_ascii_whitespace());
+    if buf.next().is_some() {
+        bail!("junk after right parenthesis or bracket")
+    }
+
+    let mut desc = |inclusive, bound| -> Result<_, String> {
+        Ok(RangeBoundDesc {
+            inclusive,
+            value: match bound {
+                None => RangeBoundDescValue::Infinite,
+                Some(bound) => RangeBoundDescValue::Finite { value: gen(bound)? },
+            },
+        })
+    };
+
+    Ok(Some(RangeDesc {
+        lower: desc(lower_inclusive, lower)?,
+        upper: desc(upper_inclusive, upper)?,
+    }))
+}
+
+// Result of `None` indicates an infinite bound.
+fn lex_range_bound<'a>(buf: &mut LexBuf<'a>) -> Result<Option<Cow<'a, str>>, String> {
+    let is_end_of_bound = |c| matches!(c, ',' | ')' | ']');
+    let s = buf.take_while(|ch| !is_end_of_bound(ch) && !matches!(ch, '"' | '\\'));
+
+    // `Cow::Borrowed` optimization for bounds without quotes or escapes.
+    match buf.peek() {
+        Some(c) if is_end_of_bound(c) => {
+            return Ok(if s.is_empty() { None } else { Some(s.into()) });
+        }
+        _ => {}
+    }
+
+    let mut s = s.to_string();
+    loop {
+        match buf.next() {
+            Some(c) if is_end_of_bound(c) => {
+                buf.prev();
+                break;
+            }
+            Some('"') => loop {
+                match buf.next() {
+                    // A doubled quote within quotes is a literal quote.
+                    Some('"') if buf.consume('"') => s.push('"'),
+                    Some('"') => break,
+                    Some('\\') => match buf.next() {
+                        Some(c) => s.push(c),
+                        None => bail!("unexpected end of input"),
+                    },
+                    Some(c) => s.push(c),
+                    None => bail!("unexpected end of input"),
+                }
+            },
+            Some('\\') => match buf.next() {
+                Some(c) => s.push(c),
+                None => bail!("unexpected end of input"),
+            },
+            Some(c) => s.push(c),
+            None => bail!("unexpected end of input"),
+        }
+    }
+    // Quoted or escaped bounds are always finite, even if empty.
+    Ok(Some(Cow::Owned(s)))
+}
+
 pub fn parse_map<'a, V, E>(
     s: &'a str,
     is_value_type_map: bool,
@@ -1277,6 +1432,42 @@ where
     Ok(())
 }
 
+/// Writes a range to `buf`, using `format_elem` to format the value of each
+/// finite bound. `None` represents the empty range.
+pub fn format_range<F, V, E>(
+    buf: &mut F,
+    range: Option<&RangeDesc<V>>,
+    mut format_elem: impl FnMut(&mut F, &V) -> Result<Nestable, E>,
+) -> Result<Nestable, E>
+where
+    F: FormatBuffer,
+{
+    let range = match range {
+        None => {
+            buf.write_str("empty");
+            return Ok(Nestable::MayNeedEscaping);
+        }
+        Some(range) => range,
+    };
+
+    buf.write_char(if range.lower.inclusive { '[' } else { '(' });
+    if let RangeBoundDescValue::Finite { value } = &range.lower.value {
+        let start = buf.len();
+        if let Nestable::MayNeedEscaping = format_elem(buf, value)? {
+            escape_elem::<_, RangeElementEscaper>(buf, start);
+        }
+    }
+    buf.write_char(',');
+    if let RangeBoundDescValue::Finite { value } = &range.upper.value {
+        let start = buf.len();
+        if let Nestable::MayNeedEscaping = format_elem(buf, value)? {
+            escape_elem::<_, RangeElementEscaper>(buf, start);
+        }
+    }
+    buf.write_char(if range.upper.inclusive { ']' } else { ')' });
+    Ok(Nestable::MayNeedEscaping)
+}
+
 pub trait ElementEscaper {
     fn needs_escaping(elem: &[u8]) -> bool;
     fn escape_char(c: u8) -> u8;
@@ -1334,7 +1525,25 @@ impl ElementEscaper for RecordElementEscaper {
     }
 }
 
-/// Escapes a list, record, or map element in place.
+struct RangeElementEscaper;
+
+impl ElementEscaper for RangeElementEscaper {
+    fn needs_escaping(elem: &[u8]) -> bool {
+        elem.is_empty()
+            || elem.iter().any(|c| {
+                matches!(c, b'(' | b')' | b'[' | b']' | b',' | b'"' | b'\\')
+                    || c.is_ascii_whitespace()
+            })
+    }
+
+    fn escape_char(c: u8) -> u8 {
+        // Like PostgreSQL, double quotes and backslashes rather than escaping
+        // them with a backslash.
+        c
+    }
+}
+
+/// Escapes a list, record, map, or range element in place.
 ///
 /// The element must start at `start` and extend to the end of the buffer. The
 /// buffer will be resized if escaping is necessary to account for the
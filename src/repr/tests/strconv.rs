@@ -10,7 +10,7 @@
 use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
 
 use mz_repr::adt::date::Date;
-use mz_repr::adt::datetime::DateTimeField;
+use mz_repr::adt::datetime::{DateTimeField, Timezone};
 use mz_repr::adt::interval::Interval;
 use mz_repr::strconv;
 
@@ -776,3 +776,57 @@ fn test_format_timestamptz() {
         assert_eq!(buf, e);
     }
 }
+
+#[test]
+fn test_format_timestamptz_in_zone() {
+    let ts = |month, day, hour| {
+        DateTime::from_utc(
+            NaiveDate::from_ymd_opt(2020, month, day)
+                .unwrap()
+                .and_hms_opt(hour, 30, 0)
+                .unwrap(),
+            Utc,
+        )
+    };
+    run_test("UTC", ts(7, 1, 12), "2020-07-01 12:30:00+00");
+    run_test("+05:30", ts(7, 1, 12), "2020-07-01 18:00:00+05:30");
+    run_test("-00:30:15", ts(7, 1, 12), "2020-07-01 11:59:45-00:30:15");
+    // Daylight saving time is in effect in July but not in January.
+    run_test("America/New_York", ts(7, 1, 12), "2020-07-01 08:30:00-04");
+    run_test("America/New_York", ts(1, 1, 12), "2020-01-01 07:30:00-05");
+    // The local date can differ from the UTC date.
+    run_test("Asia/Kolkata", ts(12, 31, 20), "2021-01-01 02:00:00+05:30");
+
+    fn run_test(tz: &str, n: DateTime<Utc>, e: &str) {
+        let tz: Timezone = tz.parse().unwrap();
+        let mut buf = String::new();
+        strconv::format_timestamptz_in_zone(&mut buf, &n, &tz);
+        assert_eq!(buf, e);
+    }
+}
+
+#[test]
+fn test_parse_timestamptz_in_zone() {
+    let ts = |month, day, hour| {
+        DateTime::from_utc(
+            NaiveDate::from_ymd_opt(2020, month, day)
+                .unwrap()
+                .and_hms_opt(hour, 30, 0)
+                .unwrap(),
+            Utc,
+        )
+    };
+    // Timestamps without a zone are interpreted in the given zone.
+    run_test("2020-07-01 08:30:00", "America/New_York", ts(7, 1, 12));
+    run_test("2020-01-01 07:30:00", "America/New_York", ts(1, 1, 12));
+    run_test("2020-07-01 18:00:00", "+05:30", ts(7, 1, 12));
+    // An explicit zone takes precedence.
+    run_test("2020-07-01 12:30:00+00", "America/New_York", ts(7, 1, 12));
+    run_test("2020-11-01 01:30:00-05", "America/New_York", ts(11, 1, 6));
+
+    fn run_test(s: &str, tz: &str, e: DateTime<Utc>) {
+        let tz: Timezone = tz.parse().unwrap();
+        let ts = strconv::parse_timestamptz_in_zone(s, &tz).unwrap();
+        assert_eq!(*ts, e);
+    }
+}
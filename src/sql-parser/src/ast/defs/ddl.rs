@@ -339,6 +339,8 @@ pub enum Format<T: AstInfo> {
         delimiter: char,
     },
     Json,
+    /// `PARQUET USING SCHEMA '<message type>'`
+    Parquet(String),
     Text,
 }
 
@@ -483,6 +485,11 @@ impl<T: AstInfo> AstDisplay for Format<T> {
                 }
             }
             Self::Json => f.write_str("JSON"),
+            Self::Parquet(schema) => {
+                f.write_str("PARQUET USING SCHEMA '");
+                f.write_node(&display::escape_single_quote_string(schema));
+                f.write_str("'");
+            }
             Self::Text => f.write_str("TEXT"),
         }
     }
@@ -695,6 +702,50 @@ impl<T: AstInfo> AstDisplay for PostgresConnectionOption<T> {
 }
 impl_display_t!(PostgresConnectionOption);
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MySqlConnectionOptionName {
+    AwsPrivatelink,
+    Host,
+    Password,
+    Port,
+    SshTunnel,
+    SslMode,
+    User,
+}
+
+impl AstDisplay for MySqlConnectionOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            MySqlConnectionOptionName::AwsPrivatelink => "AWS PRIVATELINK",
+            MySqlConnectionOptionName::Host => "HOST",
+            MySqlConnectionOptionName::Password => "PASSWORD",
+            MySqlConnectionOptionName::Port => "PORT",
+            MySqlConnectionOptionName::SshTunnel => "SSH TUNNEL",
+            MySqlConnectionOptionName::SslMode => "SSL MODE",
+            MySqlConnectionOptionName::User => "USER",
+        })
+    }
+}
+impl_display!(MySqlConnectionOptionName);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// An option in a `CREATE CONNECTION ... MYSQL`.
+pub struct MySqlConnectionOption<T: AstInfo> {
+    pub name: MySqlConnectionOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for MySqlConnectionOption<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        if let Some(v) = &self.value {
+            f.write_str(" = ");
+            f.write_node(v);
+        }
+    }
+}
+impl_display_t!(MySqlConnectionOption);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum AwsConnectionOptionName {
     AccessKeyId,
@@ -824,6 +875,9 @@ pub enum CreateConnection<T: AstInfo> {
     Postgres {
         with_options: Vec<PostgresConnectionOption<T>>,
     },
+    MySql {
+        with_options: Vec<MySqlConnectionOption<T>>,
+    },
     Ssh {
         with_options: Vec<SshConnectionOption<T>>,
     },
@@ -847,6 +901,11 @@ impl<T: AstInfo> AstDisplay for CreateConnection<T> {
                 f.write_node(&display::comma_separated(with_options));
                 f.write_str(")");
             }
+            Self::MySql { with_options } => {
+                f.write_str("MYSQL (");
+                f.write_node(&display::comma_separated(with_options));
+                f.write_str(")");
+            }
             Self::Aws { with_options } => {
                 f.write_str("AWS (");
                 f.write_node(&display::comma_separated(with_options));
@@ -993,6 +1052,75 @@ impl<T: AstInfo> AstDisplay for PgConfigOption<T> {
 }
 impl_display_t!(PgConfigOption);
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MySqlConfigOptionName {
+    /// Hex encoded string of binary serialization of `dataflow_types::MySqlSourceDetails`
+    Details,
+}
+
+impl AstDisplay for MySqlConfigOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            MySqlConfigOptionName::Details => "DETAILS",
+        })
+    }
+}
+impl_display!(MySqlConfigOptionName);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// An option in a `FROM MYSQL CONNECTION ...` statement.
+pub struct MySqlConfigOption<T: AstInfo> {
+    pub name: MySqlConfigOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for MySqlConfigOption<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        if let Some(v) = &self.value {
+            f.write_str(" = ");
+            f.write_node(v);
+        }
+    }
+}
+impl_display_t!(MySqlConfigOption);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum WebhookOptionName {
+    /// The secret used to validate request signatures.
+    Secret,
+    /// The request header that carries the signature.
+    SignatureHeader,
+}
+
+impl AstDisplay for WebhookOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            WebhookOptionName::Secret => "SECRET",
+            WebhookOptionName::SignatureHeader => "SIGNATURE HEADER",
+        })
+    }
+}
+impl_display!(WebhookOptionName);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// An option in a `FROM WEBHOOK ...` statement.
+pub struct WebhookOption<T: AstInfo> {
+    pub name: WebhookOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for WebhookOption<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        if let Some(v) = &self.value {
+            f.write_str(" = ");
+            f.write_node(v);
+        }
+    }
+}
+impl_display_t!(WebhookOption);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum CreateSourceConnection<T: AstInfo> {
     Kafka(KafkaSourceConnection<T>),
@@ -1015,6 +1143,11 @@ pub enum CreateSourceConnection<T: AstInfo> {
         connection: T::ObjectName,
         options: Vec<PgConfigOption<T>>,
     },
+    MySql {
+        /// The MySQL connection.
+        connection: T::ObjectName,
+        options: Vec<MySqlConfigOption<T>>,
+    },
     LoadGenerator {
         generator: LoadGenerator,
         options: Vec<LoadGeneratorOption<T>>,
@@ -1022,6 +1155,9 @@ pub enum CreateSourceConnection<T: AstInfo> {
     TestScript {
         desc_json: String,
     },
+    Webhook {
+        options: Vec<WebhookOption<T>>,
+    },
 }
 
 impl<T: AstInfo> AstDisplay for CreateSourceConnection<T> {
@@ -1074,6 +1210,18 @@ impl<T: AstInfo> AstDisplay for CreateSourceConnection<T> {
                     f.write_str(")");
                 }
             }
+            CreateSourceConnection::MySql {
+                connection,
+                options,
+            } => {
+                f.write_str("MYSQL CONNECTION ");
+                f.write_node(connection);
+                if !options.is_empty() {
+                    f.write_str(" (");
+                    f.write_node(&display::comma_separated(options));
+                    f.write_str(")");
+                }
+            }
             CreateSourceConnection::LoadGenerator { generator, options } => {
                 f.write_str("LOAD GENERATOR ");
                 f.write_node(generator);
@@ -1089,6 +1237,14 @@ impl<T: AstInfo> AstDisplay for CreateSourceConnection<T> {
                 f.write_str(&display::escape_single_quote_string(desc_json));
                 f.write_str("'");
             }
+            CreateSourceConnection::Webhook { options } => {
+                f.write_str("WEBHOOK");
+                if !options.is_empty() {
+                    f.write_str(" (");
+                    f.write_node(&display::comma_separated(options));
+                    f.write_str(")");
+                }
+            }
         }
     }
 }
@@ -22,7 +22,7 @@ use std::fmt;
 use std::mem;
 
 use crate::ast::display::{self, AstDisplay, AstFormatter};
-use crate::ast::{AstInfo, Ident, OrderByExpr, Query, UnresolvedObjectName, Value};
+use crate::ast::{AstInfo, Ident, IntervalValue, OrderByExpr, Query, UnresolvedObjectName, Value};
 
 /// An SQL expression of any type.
 ///
@@ -765,6 +765,10 @@ pub enum WindowFrameBound {
     Preceding(Option<u64>),
     /// `<N> FOLLOWING` or `UNBOUNDED FOLLOWING`.
     Following(Option<u64>),
+    /// `INTERVAL '<value>' PRECEDING`
+    IntervalPreceding(IntervalValue),
+    /// `INTERVAL '<value>' FOLLOWING`
+    IntervalFollowing(IntervalValue),
 }
 
 impl AstDisplay for WindowFrameBound {
@@ -781,6 +785,14 @@ impl AstDisplay for WindowFrameBound {
                 f.write_str(n);
                 f.write_str(" FOLLOWING");
             }
+            WindowFrameBound::IntervalPreceding(interval) => {
+                f.write_node(&Value::Interval(interval.clone()));
+                f.write_str(" PRECEDING");
+            }
+            WindowFrameBound::IntervalFollowing(interval) => {
+                f.write_node(&Value::Interval(interval.clone()));
+                f.write_str(" FOLLOWING");
+            }
         }
     }
 }
@@ -314,7 +314,7 @@ impl<T: AstInfo> AstDisplay for Distinct<T> {
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum CteBlock<T: AstInfo> {
     Simple(Vec<Cte<T>>),
-    MutuallyRecursive(Vec<CteMutRec<T>>),
+    MutuallyRecursive(MutRecBlock<T>),
 }
 
 impl<T: AstInfo> CteBlock<T> {
@@ -326,7 +326,7 @@ impl<T: AstInfo> CteBlock<T> {
     pub fn is_empty(&self) -> bool {
         match self {
             CteBlock::Simple(list) => list.is_empty(),
-            CteBlock::MutuallyRecursive(list) => list.is_empty(),
+            CteBlock::MutuallyRecursive(MutRecBlock { options: _, ctes }) => ctes.is_empty(),
         }
     }
     /// Iterates through the identifiers used in bindings.
@@ -338,8 +338,8 @@ impl<T: AstInfo> CteBlock<T> {
                     names.push(&cte.alias.name);
                 }
             }
-            CteBlock::MutuallyRecursive(list) => {
-                for cte in list.iter() {
+            CteBlock::MutuallyRecursive(MutRecBlock { options: _, ctes }) => {
+                for cte in ctes.iter() {
                     names.push(&cte.name);
                 }
             }
@@ -356,9 +356,9 @@ impl<T: AstInfo> AstDisplay for CteBlock<T> {
                     f.write_str("WITH ");
                     f.write_node(&display::comma_separated(list));
                 }
-                CteBlock::MutuallyRecursive(list) => {
+                CteBlock::MutuallyRecursive(block) => {
                     f.write_str("WITH MUTUALLY RECURSIVE ");
-                    f.write_node(&display::comma_separated(list));
+                    f.write_node(block);
                 }
             }
             f.write_str(" ");
@@ -387,6 +387,57 @@ impl<T: AstInfo> AstDisplay for Cte<T> {
 }
 impl_display_t!(Cte);
 
+/// The bindings of a `WITH MUTUALLY RECURSIVE` block, and the options that
+/// govern their evaluation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MutRecBlock<T: AstInfo> {
+    pub options: Vec<MutRecBlockOption<T>>,
+    pub ctes: Vec<CteMutRec<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for MutRecBlock<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        if !self.options.is_empty() {
+            f.write_str("(");
+            f.write_node(&display::comma_separated(&self.options));
+            f.write_str(") ");
+        }
+        f.write_node(&display::comma_separated(&self.ctes));
+    }
+}
+impl_display_t!(MutRecBlock);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MutRecBlockOptionName {
+    RecursionLimit,
+}
+
+impl AstDisplay for MutRecBlockOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            MutRecBlockOptionName::RecursionLimit => "RECURSION LIMIT",
+        })
+    }
+}
+impl_display!(MutRecBlockOptionName);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MutRecBlockOption<T: AstInfo> {
+    pub name: MutRecBlockOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for MutRecBlockOption<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        if let Some(v) = &self.value {
+            f.write_str(" = ");
+            f.write_node(v);
+        }
+    }
+}
+impl_display_t!(MutRecBlockOption);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CteMutRec<T: AstInfo> {
     pub name: Ident,
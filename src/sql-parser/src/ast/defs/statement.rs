@@ -69,6 +69,8 @@ pub enum Statement<T: AstInfo> {
     AlterSystemReset(AlterSystemResetStatement),
     AlterSystemResetAll(AlterSystemResetAllStatement),
     AlterConnection(AlterConnectionStatement),
+    AlterOwner(AlterOwnerStatement),
+    AlterTableAddColumn(AlterTableAddColumnStatement<T>),
     Discard(DiscardStatement),
     DropDatabase(DropDatabaseStatement),
     DropSchema(DropSchemaStatement),
@@ -85,6 +87,7 @@ pub enum Statement<T: AstInfo> {
     Rollback(RollbackStatement),
     Subscribe(SubscribeStatement<T>),
     Explain(ExplainStatement<T>),
+    ExplainAnalyze(ExplainAnalyzeStatement<T>),
     Declare(DeclareStatement<T>),
     Fetch(FetchStatement<T>),
     Close(CloseStatement),
@@ -92,6 +95,9 @@ pub enum Statement<T: AstInfo> {
     Execute(ExecuteStatement<T>),
     Deallocate(DeallocateStatement),
     Raise(RaiseStatement),
+    GrantPrivileges(GrantPrivilegesStatement),
+    RevokePrivileges(RevokePrivilegesStatement),
+    Analyze(AnalyzeStatement<T>),
 }
 
 impl<T: AstInfo> AstDisplay for Statement<T> {
@@ -126,6 +132,8 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::AlterSystemReset(stmt) => f.write_node(stmt),
             Statement::AlterSystemResetAll(stmt) => f.write_node(stmt),
             Statement::AlterConnection(stmt) => f.write_node(stmt),
+            Statement::AlterOwner(stmt) => f.write_node(stmt),
+            Statement::AlterTableAddColumn(stmt) => f.write_node(stmt),
             Statement::Discard(stmt) => f.write_node(stmt),
             Statement::DropDatabase(stmt) => f.write_node(stmt),
             Statement::DropSchema(stmt) => f.write_node(stmt),
@@ -142,6 +150,7 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::Rollback(stmt) => f.write_node(stmt),
             Statement::Subscribe(stmt) => f.write_node(stmt),
             Statement::Explain(stmt) => f.write_node(stmt),
+            Statement::ExplainAnalyze(stmt) => f.write_node(stmt),
             Statement::Declare(stmt) => f.write_node(stmt),
             Statement::Close(stmt) => f.write_node(stmt),
             Statement::Fetch(stmt) => f.write_node(stmt),
@@ -149,6 +158,9 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::Execute(stmt) => f.write_node(stmt),
             Statement::Deallocate(stmt) => f.write_node(stmt),
             Statement::Raise(stmt) => f.write_node(stmt),
+            Statement::GrantPrivileges(stmt) => f.write_node(stmt),
+            Statement::RevokePrivileges(stmt) => f.write_node(stmt),
+            Statement::Analyze(stmt) => f.write_node(stmt),
         }
     }
 }
@@ -1495,6 +1507,55 @@ impl AstDisplay for AlterConnectionStatement {
 
 impl_display!(AlterConnectionStatement);
 
+/// `ALTER <OBJECT> ... OWNER TO`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AlterOwnerStatement {
+    pub object_type: ObjectType,
+    pub if_exists: bool,
+    pub name: UnresolvedObjectName,
+    pub new_owner: Ident,
+}
+
+impl AstDisplay for AlterOwnerStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("ALTER ");
+        f.write_node(&self.object_type);
+        f.write_str(" ");
+        if self.if_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_node(&self.name);
+        f.write_str(" OWNER TO ");
+        f.write_node(&self.new_owner);
+    }
+}
+impl_display!(AlterOwnerStatement);
+
+/// `ALTER TABLE ... ADD COLUMN`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AlterTableAddColumnStatement<T: AstInfo> {
+    pub if_exists: bool,
+    pub name: UnresolvedObjectName,
+    pub if_col_not_exists: bool,
+    pub column: ColumnDef<T>,
+}
+
+impl<T: AstInfo> AstDisplay for AlterTableAddColumnStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("ALTER TABLE ");
+        if self.if_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_node(&self.name);
+        f.write_str(" ADD COLUMN ");
+        if self.if_col_not_exists {
+            f.write_str("IF NOT EXISTS ");
+        }
+        f.write_node(&self.column);
+    }
+}
+impl_display_t!(AlterTableAddColumnStatement);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DiscardStatement {
     pub target: DiscardTarget,
@@ -2078,18 +2139,53 @@ impl<T: AstInfo> AstDisplay for SubscribeOption<T> {
 }
 impl_display_t!(SubscribeOption);
 
+/// The `ENVELOPE` of a `SUBSCRIBE`, which presents its updates per key rather
+/// than as raw diffs.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SubscribeEnvelope {
+    /// `ENVELOPE UPSERT (KEY (...))`
+    Upsert { key_columns: Vec<Ident> },
+    /// `ENVELOPE DEBEZIUM (KEY (...))`
+    Debezium { key_columns: Vec<Ident> },
+}
+
+impl AstDisplay for SubscribeEnvelope {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        let key_columns = match self {
+            SubscribeEnvelope::Upsert { key_columns } => {
+                f.write_str("UPSERT");
+                key_columns
+            }
+            SubscribeEnvelope::Debezium { key_columns } => {
+                f.write_str("DEBEZIUM");
+                key_columns
+            }
+        };
+        f.write_str(" (KEY (");
+        f.write_node(&display::comma_separated(key_columns));
+        f.write_str("))");
+    }
+}
+impl_display!(SubscribeEnvelope);
+
 /// `SUBSCRIBE`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SubscribeStatement<T: AstInfo> {
     pub relation: SubscribeRelation<T>,
+    pub envelope: Option<SubscribeEnvelope>,
     pub options: Vec<SubscribeOption<T>>,
     pub as_of: Option<AsOf<T>>,
+    pub up_to: Option<Expr<T>>,
 }
 
 impl<T: AstInfo> AstDisplay for SubscribeStatement<T> {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         f.write_str("SUBSCRIBE ");
         f.write_node(&self.relation);
+        if let Some(envelope) = &self.envelope {
+            f.write_str(" ENVELOPE ");
+            f.write_node(envelope);
+        }
         if !self.options.is_empty() {
             f.write_str(" WITH (");
             f.write_node(&display::comma_separated(&self.options));
@@ -2099,6 +2195,10 @@ impl<T: AstInfo> AstDisplay for SubscribeStatement<T> {
             f.write_str(" ");
             f.write_node(as_of);
         }
+        if let Some(up_to) = &self.up_to {
+            f.write_str(" UP TO ");
+            f.write_node(up_to);
+        }
     }
 }
 impl_display_t!(SubscribeStatement);
@@ -2148,6 +2248,69 @@ impl<T: AstInfo> AstDisplay for ExplainStatement<T> {
 }
 impl_display_t!(ExplainStatement);
 
+/// `EXPLAIN ANALYZE ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExplainAnalyzeStatement<T: AstInfo> {
+    /// The property to report, or all of them if `None`.
+    pub property: Option<ExplainAnalyzeProperty>,
+    pub explainee: ExplainAnalyzeObject<T>,
+}
+
+impl<T: AstInfo> AstDisplay for ExplainAnalyzeStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("EXPLAIN ANALYZE ");
+        if let Some(property) = &self.property {
+            f.write_node(property);
+            f.write_str(" ");
+        }
+        f.write_str("FOR ");
+        f.write_node(&self.explainee);
+    }
+}
+impl_display_t!(ExplainAnalyzeStatement);
+
+/// The runtime property reported by [Statement::ExplainAnalyze].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExplainAnalyzeProperty {
+    /// Records and batches held by arrangements, and arrangement sharing.
+    Memory,
+    /// Time spent scheduling operators.
+    Cpu,
+}
+
+impl AstDisplay for ExplainAnalyzeProperty {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            Self::Memory => f.write_str("MEMORY"),
+            Self::Cpu => f.write_str("CPU"),
+        }
+    }
+}
+impl_display!(ExplainAnalyzeProperty);
+
+/// The running dataflow analyzed by [Statement::ExplainAnalyze].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ExplainAnalyzeObject<T: AstInfo> {
+    Index(T::ObjectName),
+    MaterializedView(T::ObjectName),
+}
+
+impl<T: AstInfo> AstDisplay for ExplainAnalyzeObject<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            Self::Index(name) => {
+                f.write_str("INDEX ");
+                f.write_node(name);
+            }
+            Self::MaterializedView(name) => {
+                f.write_str("MATERIALIZED VIEW ");
+                f.write_node(name);
+            }
+        }
+    }
+}
+impl_display_t!(ExplainAnalyzeObject);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum InsertSource<T: AstInfo> {
     Query(Query<T>),
@@ -2179,6 +2342,8 @@ pub enum ObjectType {
     Object,
     Secret,
     Connection,
+    Database,
+    Schema,
 }
 
 impl AstDisplay for ObjectType {
@@ -2197,6 +2362,8 @@ impl AstDisplay for ObjectType {
             ObjectType::Object => "OBJECT",
             ObjectType::Secret => "SECRET",
             ObjectType::Connection => "CONNECTION",
+            ObjectType::Database => "DATABASE",
+            ObjectType::Schema => "SCHEMA",
         })
     }
 }
@@ -2633,6 +2800,20 @@ impl AstDisplay for DeallocateStatement {
 }
 impl_display!(DeallocateStatement);
 
+/// `ANALYZE ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AnalyzeStatement<T: AstInfo> {
+    pub name: T::ObjectName,
+}
+
+impl<T: AstInfo> AstDisplay for AnalyzeStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("ANALYZE ");
+        f.write_node(&self.name);
+    }
+}
+impl_display_t!(AnalyzeStatement);
+
 /// `RAISE ...`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct RaiseStatement {
@@ -2766,3 +2947,103 @@ impl<T: AstInfo> AstDisplay for ShowStatement<T> {
     }
 }
 impl_display_t!(ShowStatement);
+
+/// A privilege that can be granted to or revoked from a role.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Privilege {
+    Select,
+    Insert,
+    Update,
+    Delete,
+    Usage,
+    Create,
+}
+
+impl AstDisplay for Privilege {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            Privilege::Select => "SELECT",
+            Privilege::Insert => "INSERT",
+            Privilege::Update => "UPDATE",
+            Privilege::Delete => "DELETE",
+            Privilege::Usage => "USAGE",
+            Privilege::Create => "CREATE",
+        });
+    }
+}
+impl_display!(Privilege);
+
+/// The privileges named in a `GRANT` or `REVOKE` statement.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PrivilegeSpecification {
+    /// `ALL [PRIVILEGES]`
+    All,
+    /// A comma separated list of privileges.
+    Privileges(Vec<Privilege>),
+}
+
+impl AstDisplay for PrivilegeSpecification {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            PrivilegeSpecification::All => f.write_str("ALL"),
+            PrivilegeSpecification::Privileges(privileges) => {
+                f.write_node(&display::comma_separated(privileges))
+            }
+        }
+    }
+}
+impl_display!(PrivilegeSpecification);
+
+/// `GRANT ... ON ... TO ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GrantPrivilegesStatement {
+    /// The privileges being granted.
+    pub privileges: PrivilegeSpecification,
+    /// The type of the objects on which privileges are being granted.
+    pub object_type: ObjectType,
+    /// The objects on which privileges are being granted.
+    pub names: Vec<UnresolvedObjectName>,
+    /// The roles that will be granted the privileges.
+    pub roles: Vec<Ident>,
+}
+
+impl AstDisplay for GrantPrivilegesStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("GRANT ");
+        f.write_node(&self.privileges);
+        f.write_str(" ON ");
+        f.write_node(&self.object_type);
+        f.write_str(" ");
+        f.write_node(&display::comma_separated(&self.names));
+        f.write_str(" TO ");
+        f.write_node(&display::comma_separated(&self.roles));
+    }
+}
+impl_display!(GrantPrivilegesStatement);
+
+/// `REVOKE ... ON ... FROM ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RevokePrivilegesStatement {
+    /// The privileges being revoked.
+    pub privileges: PrivilegeSpecification,
+    /// The type of the objects on which privileges are being revoked.
+    pub object_type: ObjectType,
+    /// The objects on which privileges are being revoked.
+    pub names: Vec<UnresolvedObjectName>,
+    /// The roles that will have privileges revoked.
+    pub roles: Vec<Ident>,
+}
+
+impl AstDisplay for RevokePrivilegesStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("REVOKE ");
+        f.write_node(&self.privileges);
+        f.write_str(" ON ");
+        f.write_node(&self.object_type);
+        f.write_str(" ");
+        f.write_node(&display::comma_separated(&self.names));
+        f.write_str(" FROM ");
+        f.write_node(&display::comma_separated(&self.roles));
+    }
+}
+impl_display!(RevokePrivilegesStatement);
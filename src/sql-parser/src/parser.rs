@@ -280,6 +280,9 @@ impl<'a> Parser<'a> {
                 Token::Keyword(EXECUTE) => Ok(self.parse_execute()?),
                 Token::Keyword(DEALLOCATE) => Ok(self.parse_deallocate()?),
                 Token::Keyword(RAISE) => Ok(self.parse_raise()?),
+                Token::Keyword(GRANT) => Ok(self.parse_grant()?),
+                Token::Keyword(REVOKE) => Ok(self.parse_revoke()?),
+                Token::Keyword(ANALYZE) => Ok(self.parse_analyze()?),
                 Token::Keyword(kw) => parser_err!(
                     self,
                     self.peek_prev_pos(),
@@ -658,10 +661,22 @@ impl<'a> Parser<'a> {
         })
     }
 
-    /// Parse `CURRENT ROW` or `{ <positive number> | UNBOUNDED } { PRECEDING | FOLLOWING }`
+    /// Parse `CURRENT ROW` or `{ <positive number> | <interval> | UNBOUNDED } { PRECEDING | FOLLOWING }`
     fn parse_window_frame_bound(&mut self) -> Result<WindowFrameBound, ParserError> {
         if self.parse_keywords(&[CURRENT, ROW]) {
             Ok(WindowFrameBound::CurrentRow)
+        } else if self.parse_keyword(INTERVAL) {
+            let interval = match self.parse_interval_value()? {
+                Value::Interval(interval) => interval,
+                _ => unreachable!(),
+            };
+            if self.parse_keyword(PRECEDING) {
+                Ok(WindowFrameBound::IntervalPreceding(interval))
+            } else if self.parse_keyword(FOLLOWING) {
+                Ok(WindowFrameBound::IntervalFollowing(interval))
+            } else {
+                self.expected(self.peek_pos(), "PRECEDING or FOLLOWING", self.peek_token())
+            }
         } else {
             let rows = if self.parse_keyword(UNBOUNDED) {
                 None
@@ -1714,6 +1729,9 @@ impl<'a> Parser<'a> {
             Format::Csv { columns, delimiter }
         } else if self.parse_keyword(JSON) {
             Format::Json
+        } else if self.parse_keyword(PARQUET) {
+            self.expect_keywords(&[USING, SCHEMA])?;
+            Format::Parquet(self.parse_literal_string()?)
         } else if self.parse_keyword(TEXT) {
             Format::Text
         } else if self.parse_keyword(BYTES) {
@@ -1721,7 +1739,7 @@ impl<'a> Parser<'a> {
         } else {
             return self.expected(
                 self.peek_pos(),
-                "AVRO, PROTOBUF, REGEX, CSV, JSON, TEXT, or BYTES",
+                "AVRO, PROTOBUF, REGEX, CSV, JSON, PARQUET, TEXT, or BYTES",
                 self.peek_token(),
             );
         };
@@ -1960,7 +1978,7 @@ impl<'a> Parser<'a> {
             _ => unreachable!(),
         };
         let connection = match self
-            .expect_one_of_keywords(&[AWS, KAFKA, CONFLUENT, POSTGRES, SSH])?
+            .expect_one_of_keywords(&[AWS, KAFKA, CONFLUENT, POSTGRES, MYSQL, SSH])?
         {
             AWS => {
                 if self.parse_keyword(PRIVATELINK) {
@@ -2004,6 +2022,14 @@ impl<'a> Parser<'a> {
                     self.parse_comma_separated(Parser::parse_postgres_connection_option)?;
                 CreateConnection::Postgres { with_options }
             }
+            MYSQL => {
+                if expect_paren {
+                    self.expect_token(&Token::LParen)?;
+                }
+                let with_options =
+                    self.parse_comma_separated(Parser::parse_mysql_connection_option)?;
+                CreateConnection::MySql { with_options }
+            }
             SSH => {
                 self.expect_keyword(TUNNEL)?;
                 if expect_paren {
@@ -2308,6 +2334,40 @@ impl<'a> Parser<'a> {
         })
     }
 
+    fn parse_mysql_connection_option(&mut self) -> Result<MySqlConnectionOption<Raw>, ParserError> {
+        let name = match self
+            .expect_one_of_keywords(&[AWS, HOST, PASSWORD, PORT, SSH, SSL, USER, USERNAME])?
+        {
+            AWS => {
+                self.expect_keyword(PRIVATELINK)?;
+                return Ok(MySqlConnectionOption {
+                    name: MySqlConnectionOptionName::AwsPrivatelink,
+                    value: Some(self.parse_object_option_value()?),
+                });
+            }
+            HOST => MySqlConnectionOptionName::Host,
+            PASSWORD => MySqlConnectionOptionName::Password,
+            PORT => MySqlConnectionOptionName::Port,
+            SSH => {
+                self.expect_keyword(TUNNEL)?;
+                return Ok(MySqlConnectionOption {
+                    name: MySqlConnectionOptionName::SshTunnel,
+                    value: Some(self.parse_object_option_value()?),
+                });
+            }
+            SSL => {
+                self.expect_keyword(MODE)?;
+                MySqlConnectionOptionName::SslMode
+            }
+            USER | USERNAME => MySqlConnectionOptionName::User,
+            _ => unreachable!(),
+        };
+        Ok(MySqlConnectionOption {
+            name,
+            value: self.parse_optional_option_value()?,
+        })
+    }
+
     fn parse_aws_connection_option(&mut self) -> Result<AwsConnectionOption<Raw>, ParserError> {
         let name =
             match self.expect_one_of_keywords(&[ACCESS, ENDPOINT, REGION, ROLE, SECRET, TOKEN])? {
@@ -2589,7 +2649,9 @@ impl<'a> Parser<'a> {
     fn parse_create_source_connection(
         &mut self,
     ) -> Result<CreateSourceConnection<Raw>, ParserError> {
-        match self.expect_one_of_keywords(&[KAFKA, KINESIS, S3, POSTGRES, LOAD, TEST])? {
+        match self
+            .expect_one_of_keywords(&[KAFKA, KINESIS, S3, POSTGRES, MYSQL, LOAD, TEST, WEBHOOK])?
+        {
             POSTGRES => {
                 self.expect_keyword(CONNECTION)?;
                 let connection = self.parse_raw_name()?;
@@ -2607,6 +2669,23 @@ impl<'a> Parser<'a> {
                     options,
                 })
             }
+            MYSQL => {
+                self.expect_keyword(CONNECTION)?;
+                let connection = self.parse_raw_name()?;
+
+                let options = if self.consume_token(&Token::LParen) {
+                    let options = self.parse_comma_separated(Parser::parse_mysql_config_option)?;
+                    self.expect_token(&Token::RParen)?;
+                    options
+                } else {
+                    vec![]
+                };
+
+                Ok(CreateSourceConnection::MySql {
+                    connection,
+                    options,
+                })
+            }
             KAFKA => {
                 self.expect_keyword(CONNECTION)?;
                 let connection = self.parse_kafka_connection_reference()?;
@@ -2709,10 +2788,46 @@ impl<'a> Parser<'a> {
                     desc_json: self.parse_literal_string()?,
                 })
             }
+            WEBHOOK => {
+                let options = if self.consume_token(&Token::LParen) {
+                    let options = self.parse_comma_separated(Parser::parse_webhook_option)?;
+                    self.expect_token(&Token::RParen)?;
+                    options
+                } else {
+                    vec![]
+                };
+                Ok(CreateSourceConnection::Webhook { options })
+            }
             _ => unreachable!(),
         }
     }
 
+    fn parse_webhook_option(&mut self) -> Result<WebhookOption<Raw>, ParserError> {
+        let name = match self.expect_one_of_keywords(&[SECRET, SIGNATURE])? {
+            SECRET => WebhookOptionName::Secret,
+            SIGNATURE => {
+                self.expect_keyword(HEADER)?;
+                WebhookOptionName::SignatureHeader
+            }
+            _ => unreachable!(),
+        };
+        Ok(WebhookOption {
+            name,
+            value: self.parse_optional_option_value()?,
+        })
+    }
+
+    fn parse_mysql_config_option(&mut self) -> Result<MySqlConfigOption<Raw>, ParserError> {
+        let name = match self.expect_one_of_keywords(&[DETAILS])? {
+            DETAILS => MySqlConfigOptionName::Details,
+            _ => unreachable!(),
+        };
+        Ok(MySqlConfigOption {
+            name,
+            value: self.parse_optional_option_value()?,
+        })
+    }
+
     fn parse_pg_connection_option(&mut self) -> Result<PgConfigOption<Raw>, ParserError> {
         let name = match self.expect_one_of_keywords(&[DETAILS, PUBLICATION, TEXT])? {
             DETAILS => PgConfigOptionName::Details,
@@ -3347,26 +3462,7 @@ impl<'a> Parser<'a> {
             if let Some(constraint) = self.parse_optional_table_constraint()? {
                 constraints.push(constraint);
             } else if let Some(column_name) = self.consume_identifier() {
-                let data_type = self.parse_data_type()?;
-                let collation = if self.parse_keyword(COLLATE) {
-                    Some(self.parse_object_name()?)
-                } else {
-                    None
-                };
-                let mut options = vec![];
-                loop {
-                    match self.peek_token() {
-                        None | Some(Token::Comma) | Some(Token::RParen) => break,
-                        _ => options.push(self.parse_column_option_def()?),
-                    }
-                }
-
-                columns.push(ColumnDef {
-                    name: column_name,
-                    data_type,
-                    collation,
-                    options,
-                });
+                columns.push(self.parse_column_def(column_name)?);
             } else {
                 return self.expected(
                     self.peek_pos(),
@@ -3390,6 +3486,30 @@ impl<'a> Parser<'a> {
         Ok((columns, constraints))
     }
 
+    /// Parses the remainder of a column definition, after its name.
+    fn parse_column_def(&mut self, name: Ident) -> Result<ColumnDef<Raw>, ParserError> {
+        let data_type = self.parse_data_type()?;
+        let collation = if self.parse_keyword(COLLATE) {
+            Some(self.parse_object_name()?)
+        } else {
+            None
+        };
+        let mut options = vec![];
+        loop {
+            match self.peek_token() {
+                None | Some(Token::Comma) | Some(Token::RParen) | Some(Token::Semicolon) => break,
+                _ => options.push(self.parse_column_option_def()?),
+            }
+        }
+
+        Ok(ColumnDef {
+            name,
+            data_type,
+            collation,
+            options,
+        })
+    }
+
     fn parse_column_option_def(&mut self) -> Result<ColumnOptionDef<Raw>, ParserError> {
         let name = if self.parse_keyword(CONSTRAINT) {
             Some(self.parse_identifier()?)
@@ -3564,6 +3684,10 @@ impl<'a> Parser<'a> {
             SECRET,
             SYSTEM,
             CONNECTION,
+            TYPE,
+            DATABASE,
+            SCHEMA,
+            CLUSTER,
         ])? {
             SINK => return self.parse_alter_sink(),
             SOURCE => return self.parse_alter_source(),
@@ -3572,33 +3696,107 @@ impl<'a> Parser<'a> {
                 self.expect_keyword(VIEW)?;
                 ObjectType::MaterializedView
             }
-            TABLE => ObjectType::Table,
+            TABLE => return self.parse_alter_table(),
             INDEX => return self.parse_alter_index(),
             SECRET => return self.parse_alter_secret(),
             SYSTEM => return self.parse_alter_system(),
             CONNECTION => return self.parse_alter_connection(),
+            TYPE => return self.parse_alter_owner_only(ObjectType::Type),
+            DATABASE => return self.parse_alter_owner_only(ObjectType::Database),
+            SCHEMA => return self.parse_alter_owner_only(ObjectType::Schema),
+            CLUSTER => return self.parse_alter_owner_only(ObjectType::Cluster),
             _ => unreachable!(),
         };
 
         let if_exists = self.parse_if_exists()?;
         let name = self.parse_object_name()?;
 
-        self.expect_keywords(&[RENAME, TO])?;
-        let to_item_name = self.parse_identifier()?;
+        Ok(match self.expect_one_of_keywords(&[RENAME, OWNER])? {
+            RENAME => {
+                self.expect_keyword(TO)?;
+                let to_item_name = self.parse_identifier()?;
 
-        Ok(Statement::AlterObjectRename(AlterObjectRenameStatement {
+                Statement::AlterObjectRename(AlterObjectRenameStatement {
+                    object_type,
+                    if_exists,
+                    name,
+                    to_item_name,
+                })
+            }
+            OWNER => self.parse_alter_owner(object_type, if_exists, name)?,
+            _ => unreachable!(),
+        })
+    }
+
+    /// Parses the `TO <role>` portion of an `ALTER <object> ... OWNER TO`
+    /// statement. Assumes that the `OWNER` keyword has already been consumed.
+    fn parse_alter_owner(
+        &mut self,
+        object_type: ObjectType,
+        if_exists: bool,
+        name: UnresolvedObjectName,
+    ) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(TO)?;
+        let new_owner = self.parse_identifier()?;
+        Ok(Statement::AlterOwner(AlterOwnerStatement {
             object_type,
             if_exists,
             name,
-            to_item_name,
+            new_owner,
         }))
     }
 
+    /// Parses an `ALTER <object> ... OWNER TO` statement for object types that
+    /// support no other `ALTER` actions.
+    fn parse_alter_owner_only(
+        &mut self,
+        object_type: ObjectType,
+    ) -> Result<Statement<Raw>, ParserError> {
+        let if_exists = self.parse_if_exists()?;
+        let name = self.parse_object_name()?;
+        self.expect_keyword(OWNER)?;
+        self.parse_alter_owner(object_type, if_exists, name)
+    }
+
+    fn parse_alter_table(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let if_exists = self.parse_if_exists()?;
+        let name = self.parse_object_name()?;
+
+        Ok(match self.expect_one_of_keywords(&[ADD, RENAME, OWNER])? {
+            ADD => {
+                let _ = self.parse_keyword(COLUMN);
+                let if_col_not_exists = self.parse_if_not_exists()?;
+                let column_name = self.parse_identifier()?;
+                let column = self.parse_column_def(column_name)?;
+                Statement::AlterTableAddColumn(AlterTableAddColumnStatement {
+                    if_exists,
+                    name,
+                    if_col_not_exists,
+                    column,
+                })
+            }
+            RENAME => {
+                self.expect_keyword(TO)?;
+                let to_item_name = self.parse_identifier()?;
+
+                Statement::AlterObjectRename(AlterObjectRenameStatement {
+                    object_type: ObjectType::Table,
+                    if_exists,
+                    name,
+                    to_item_name,
+                })
+            }
+            OWNER => self.parse_alter_owner(ObjectType::Table, if_exists, name)?,
+            _ => unreachable!(),
+        })
+    }
+
     fn parse_alter_source(&mut self) -> Result<Statement<Raw>, ParserError> {
         let if_exists = self.parse_if_exists()?;
         let name = self.parse_object_name()?;
 
-        Ok(match self.expect_one_of_keywords(&[RESET, SET, RENAME])? {
+        let action = self.expect_one_of_keywords(&[RESET, SET, RENAME, OWNER])?;
+        Ok(match action {
             RESET => {
                 self.expect_token(&Token::LParen)?;
                 let reset_options = self.parse_comma_separated(Parser::parse_source_option_name)?;
@@ -3631,6 +3829,7 @@ impl<'a> Parser<'a> {
                     to_item_name,
                 })
             }
+            OWNER => self.parse_alter_owner(ObjectType::Source, if_exists, name)?,
             _ => unreachable!(),
         })
     }
@@ -3639,7 +3838,8 @@ impl<'a> Parser<'a> {
         let if_exists = self.parse_if_exists()?;
         let name = self.parse_object_name()?;
 
-        Ok(match self.expect_one_of_keywords(&[RESET, SET, RENAME])? {
+        let action = self.expect_one_of_keywords(&[RESET, SET, RENAME, OWNER])?;
+        Ok(match action {
             RESET => {
                 self.expect_token(&Token::LParen)?;
                 let reset_options = self.parse_comma_separated(Parser::parse_index_option_name)?;
@@ -3672,6 +3872,7 @@ impl<'a> Parser<'a> {
                     to_item_name,
                 })
             }
+            OWNER => self.parse_alter_owner(ObjectType::Index, if_exists, name)?,
             _ => unreachable!(),
         })
     }
@@ -3680,7 +3881,7 @@ impl<'a> Parser<'a> {
         let if_exists = self.parse_if_exists()?;
         let name = self.parse_object_name()?;
 
-        Ok(match self.expect_one_of_keywords(&[AS, RENAME])? {
+        Ok(match self.expect_one_of_keywords(&[AS, RENAME, OWNER])? {
             AS => {
                 let value = self.parse_expr()?;
                 Statement::AlterSecret(AlterSecretStatement {
@@ -3700,6 +3901,7 @@ impl<'a> Parser<'a> {
                     to_item_name,
                 })
             }
+            OWNER => self.parse_alter_owner(ObjectType::Secret, if_exists, name)?,
             _ => unreachable!(),
         })
     }
@@ -3709,7 +3911,8 @@ impl<'a> Parser<'a> {
         let if_exists = self.parse_if_exists()?;
         let name = self.parse_object_name()?;
 
-        Ok(match self.expect_one_of_keywords(&[RESET, SET, RENAME])? {
+        let action = self.expect_one_of_keywords(&[RESET, SET, RENAME, OWNER])?;
+        Ok(match action {
             RESET => {
                 self.expect_token(&Token::LParen)?;
                 let reset_options =
@@ -3743,6 +3946,7 @@ impl<'a> Parser<'a> {
                     to_item_name,
                 })
             }
+            OWNER => self.parse_alter_owner(ObjectType::Sink, if_exists, name)?,
             _ => unreachable!(),
         })
     }
@@ -3779,7 +3983,8 @@ impl<'a> Parser<'a> {
         let if_exists = self.parse_if_exists()?;
         let name = self.parse_object_name()?;
 
-        Ok(match self.expect_one_of_keywords(&[RENAME, ROTATE])? {
+        let action = self.expect_one_of_keywords(&[RENAME, ROTATE, OWNER])?;
+        Ok(match action {
             RENAME => {
                 self.expect_keyword(TO)?;
                 let to_item_name = self.parse_identifier()?;
@@ -3795,10 +4000,88 @@ impl<'a> Parser<'a> {
                 self.expect_keyword(KEYS)?;
                 Statement::AlterConnection(AlterConnectionStatement { name, if_exists })
             }
+            OWNER => self.parse_alter_owner(ObjectType::Connection, if_exists, name)?,
+            _ => unreachable!(),
+        })
+    }
+
+    /// Parse a `GRANT` statement, assuming that the `GRANT` token has already
+    /// been consumed.
+    fn parse_grant(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let privileges = self.parse_privilege_specification()?;
+        self.expect_keyword(ON)?;
+        let (object_type, names) = self.parse_privilege_target()?;
+        self.expect_keyword(TO)?;
+        let roles = self.parse_comma_separated(Parser::parse_identifier)?;
+        Ok(Statement::GrantPrivileges(GrantPrivilegesStatement {
+            privileges,
+            object_type,
+            names,
+            roles,
+        }))
+    }
+
+    /// Parse a `REVOKE` statement, assuming that the `REVOKE` token has
+    /// already been consumed.
+    fn parse_revoke(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let privileges = self.parse_privilege_specification()?;
+        self.expect_keyword(ON)?;
+        let (object_type, names) = self.parse_privilege_target()?;
+        self.expect_keyword(FROM)?;
+        let roles = self.parse_comma_separated(Parser::parse_identifier)?;
+        Ok(Statement::RevokePrivileges(RevokePrivilegesStatement {
+            privileges,
+            object_type,
+            names,
+            roles,
+        }))
+    }
+
+    fn parse_privilege_specification(&mut self) -> Result<PrivilegeSpecification, ParserError> {
+        if self.parse_keyword(ALL) {
+            let _ = self.parse_keyword(PRIVILEGES);
+            Ok(PrivilegeSpecification::All)
+        } else {
+            let privileges = self.parse_comma_separated(Parser::parse_privilege)?;
+            Ok(PrivilegeSpecification::Privileges(privileges))
+        }
+    }
+
+    fn parse_privilege(&mut self) -> Result<Privilege, ParserError> {
+        let privilege =
+            self.expect_one_of_keywords(&[SELECT, INSERT, UPDATE, DELETE, USAGE, CREATE])?;
+        Ok(match privilege {
+            SELECT => Privilege::Select,
+            INSERT => Privilege::Insert,
+            UPDATE => Privilege::Update,
+            DELETE => Privilege::Delete,
+            USAGE => Privilege::Usage,
+            CREATE => Privilege::Create,
             _ => unreachable!(),
         })
     }
 
+    /// Parses the objects named in a `GRANT` or `REVOKE` statement. As in
+    /// PostgreSQL, the object type defaults to `TABLE` when omitted.
+    fn parse_privilege_target(
+        &mut self,
+    ) -> Result<(ObjectType, Vec<UnresolvedObjectName>), ParserError> {
+        let object_type = self
+            .parse_one_of_keywords(&[TABLE, TYPE, SECRET, CONNECTION, DATABASE, SCHEMA, CLUSTER]);
+        let object_type = match object_type {
+            Some(TABLE) | None => ObjectType::Table,
+            Some(TYPE) => ObjectType::Type,
+            Some(SECRET) => ObjectType::Secret,
+            Some(CONNECTION) => ObjectType::Connection,
+            Some(DATABASE) => ObjectType::Database,
+            Some(SCHEMA) => ObjectType::Schema,
+            Some(CLUSTER) => ObjectType::Cluster,
+            Some(_) => unreachable!(),
+        };
+        let names = self.parse_comma_separated(Parser::parse_object_name)?;
+        Ok((object_type, names))
+    }
+
     /// Parse a copy statement
     fn parse_copy(&mut self) -> Result<Statement<Raw>, ParserError> {
         let relation = if self.consume_token(&Token::LParen) {
@@ -4390,9 +4673,18 @@ impl<'a> Parser<'a> {
             let cte_block = if parser.parse_keyword(WITH) {
                 if parser.parse_keyword(MUTUALLY) {
                     parser.expect_keyword(RECURSIVE)?;
-                    CteBlock::MutuallyRecursive(
-                        parser.parse_comma_separated(Parser::parse_cte_mut_rec)?,
-                    )
+                    let options = if parser.consume_token(&Token::LParen) {
+                        let options =
+                            parser.parse_comma_separated(Parser::parse_mut_rec_block_option)?;
+                        parser.expect_token(&Token::RParen)?;
+                        options
+                    } else {
+                        vec![]
+                    };
+                    CteBlock::MutuallyRecursive(MutRecBlock {
+                        options,
+                        ctes: parser.parse_comma_separated(Parser::parse_cte_mut_rec)?,
+                    })
                 } else {
                     // TODO: optional RECURSIVE
                     CteBlock::Simple(parser.parse_comma_separated(Parser::parse_cte)?)
@@ -4535,6 +4827,16 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parse an option of a `WITH MUTUALLY RECURSIVE` block (`RECURSION LIMIT [=] value`).
+    fn parse_mut_rec_block_option(&mut self) -> Result<MutRecBlockOption<Raw>, ParserError> {
+        self.expect_keywords(&[RECURSION, LIMIT])?;
+        let name = MutRecBlockOptionName::RecursionLimit;
+        Ok(MutRecBlockOption {
+            name,
+            value: self.parse_optional_option_value()?,
+        })
+    }
+
     /// Parse a mutually recursive CTE (`alias ( col1: typ1, col2: typ2, ... ) AS (subquery)`).
     ///
     /// The main distinction from `parse_cte` is that the column names and types are mandatory.
@@ -4938,6 +5240,7 @@ impl<'a> Parser<'a> {
                         on_object,
                     }
                 }
+                ObjectType::Database | ObjectType::Schema => unreachable!(),
             };
 
             // Only Materialized Views and Indexes (handled separately below) are associated with clusters.
@@ -5479,6 +5782,11 @@ impl<'a> Parser<'a> {
         } else {
             SubscribeRelation::Name(self.parse_raw_name()?)
         };
+        let envelope = if self.parse_keyword(ENVELOPE) {
+            Some(self.parse_subscribe_envelope()?)
+        } else {
+            None
+        };
         let options = if self.parse_keyword(WITH) {
             self.expect_token(&Token::LParen)?;
             let options = self.parse_comma_separated(Self::parse_subscribe_option)?;
@@ -5488,13 +5796,33 @@ impl<'a> Parser<'a> {
             vec![]
         };
         let as_of = self.parse_optional_as_of()?;
+        let up_to = if self.parse_keywords(&[UP, TO]) {
+            Some(self.parse_expr()?)
+        } else {
+            None
+        };
         Ok(Statement::Subscribe(SubscribeStatement {
             relation,
+            envelope,
             options,
             as_of,
+            up_to,
         }))
     }
 
+    fn parse_subscribe_envelope(&mut self) -> Result<SubscribeEnvelope, ParserError> {
+        let kind = self.expect_one_of_keywords(&[UPSERT, DEBEZIUM])?;
+        self.expect_token(&Token::LParen)?;
+        self.expect_keyword(KEY)?;
+        let key_columns = self.parse_parenthesized_column_list(Mandatory)?;
+        self.expect_token(&Token::RParen)?;
+        Ok(match kind {
+            UPSERT => SubscribeEnvelope::Upsert { key_columns },
+            DEBEZIUM => SubscribeEnvelope::Debezium { key_columns },
+            _ => unreachable!(),
+        })
+    }
+
     fn parse_subscribe_option(&mut self) -> Result<SubscribeOption<Raw>, ParserError> {
         let name = match self.expect_one_of_keywords(&[PROGRESS, SNAPSHOT])? {
             PROGRESS => SubscribeOptionName::Progress,
@@ -5510,6 +5838,10 @@ impl<'a> Parser<'a> {
     /// Parse an `EXPLAIN` statement, assuming that the `EXPLAIN` token
     /// has already been consumed.
     fn parse_explain(&mut self) -> Result<Statement<Raw>, ParserError> {
+        if self.parse_keyword(ANALYZE) {
+            return self.parse_explain_analyze();
+        }
+
         let stage = match self.parse_one_of_keywords(&[
             RAW,
             DECORRELATED,
@@ -5601,6 +5933,34 @@ impl<'a> Parser<'a> {
         }))
     }
 
+    /// Parse an `EXPLAIN ANALYZE` statement, assuming that the `EXPLAIN ANALYZE`
+    /// tokens have already been consumed.
+    fn parse_explain_analyze(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let property = match self.parse_one_of_keywords(&[MEMORY, CPU]) {
+            Some(MEMORY) => Some(ExplainAnalyzeProperty::Memory),
+            Some(CPU) => Some(ExplainAnalyzeProperty::Cpu),
+            None => None,
+            _ => unreachable!(),
+        };
+
+        self.expect_keyword(FOR)?;
+
+        // INDEX name | MATERIALIZED VIEW name
+        let explainee = match self.expect_one_of_keywords(&[INDEX, MATERIALIZED])? {
+            INDEX => ExplainAnalyzeObject::Index(self.parse_raw_name()?),
+            MATERIALIZED => {
+                self.expect_keyword(VIEW)?;
+                ExplainAnalyzeObject::MaterializedView(self.parse_raw_name()?)
+            }
+            _ => unreachable!(),
+        };
+
+        Ok(Statement::ExplainAnalyze(ExplainAnalyzeStatement {
+            property,
+            explainee,
+        }))
+    }
+
     /// Parse a `DECLARE` statement, assuming that the `DECLARE` token
     /// has already been consumed.
     fn parse_declare(&mut self) -> Result<Statement<Raw>, ParserError> {
@@ -5720,6 +6080,13 @@ impl<'a> Parser<'a> {
 
         Ok(Statement::Raise(RaiseStatement { severity }))
     }
+
+    /// Parse an `ANALYZE` statement, assuming that the `ANALYZE` token
+    /// has already been consumed.
+    fn parse_analyze(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let name = self.parse_raw_name()?;
+        Ok(Statement::Analyze(AnalyzeStatement { name }))
+    }
 }
 
 impl CheckedRecursion for Parser<'_> {
@@ -30,6 +30,7 @@ use mz_build_info::{BuildInfo, DUMMY_BUILD_INFO};
 use mz_compute_client::controller::ComputeInstanceId;
 use mz_expr::MirScalarExpr;
 use mz_ore::now::{EpochMillis, NowFn, NOW_ZERO};
+use mz_repr::adt::datetime::Timezone;
 use mz_repr::explain_new::{DummyHumanizer, ExprHumanizer};
 use mz_repr::{ColumnName, GlobalId, RelationDesc, ScalarType};
 use mz_sql_parser::ast::Expr;
@@ -197,6 +198,10 @@ pub trait SessionCatalog: fmt::Debug + ExprHumanizer + Send + Sync {
     /// Check if window functions are supported by the current system configuration.
     fn window_functions(&self) -> bool;
 
+    /// Returns the session's `TimeZone`, which determines how zone-dependent
+    /// operations on `timestamptz` values behave in one-shot queries.
+    fn timezone(&self) -> Timezone;
+
     /// Returns the number of milliseconds since the system epoch. For normal use
     /// this means the Unix epoch. This can safely be mocked in tests and start
     /// at 0.
@@ -349,6 +354,9 @@ pub trait CatalogItem {
     /// If this catalog item is a source, it return the IDs of its subsources
     fn subsources(&self) -> Vec<GlobalId>;
 
+    /// Reports whether this catalog item is a webhook source.
+    fn is_webhook_source(&self) -> bool;
+
     /// Returns the index details associated with the catalog item, if the
     /// catalog item is an index.
     fn index_details(&self) -> Option<(&[MirScalarExpr], GlobalId)>;
@@ -472,6 +480,9 @@ pub enum CatalogType<T: TypeReference> {
     Oid,
     PgLegacyChar,
     Pseudo,
+    Range {
+        element_reference: T::Reference,
+    },
     Record {
         fields: Vec<(ColumnName, T::Reference)>,
     },
@@ -911,6 +922,10 @@ impl SessionCatalog for DummyCatalog {
         true
     }
 
+    fn timezone(&self) -> Timezone {
+        Timezone::UTC
+    }
+
     fn now(&self) -> EpochMillis {
         (self.config().now)()
     }
@@ -105,6 +105,7 @@ impl TypeCategory {
             }
             ScalarType::Map { .. } => Self::Pseudo,
             ScalarType::MzTimestamp => Self::Numeric,
+            ScalarType::Range { .. } => Self::Range,
         }
     }
 
@@ -120,6 +121,8 @@ impl TypeCategory {
             | ParamType::NonVecAny
             | ParamType::MapAny
             | ParamType::MapAnyCompatible
+            | ParamType::RangeAny
+            | ParamType::RangeElementAny
             | ParamType::RecordAny => Self::Pseudo,
             ParamType::Plain(t) => Self::from_type(t),
         }
@@ -160,6 +163,7 @@ impl TypeCategory {
             | CatalogType::String
             | CatalogType::Char { .. }
             | CatalogType::VarChar { .. } => Self::String,
+            CatalogType::Range { .. } => Self::Range,
             CatalogType::Record { .. } => TypeCategory::Composite,
             CatalogType::Map { .. } | CatalogType::Pseudo => Self::Pseudo,
             CatalogType::MzTimestamp => Self::String,
@@ -704,6 +708,14 @@ pub enum ParamType {
     /// A standard parameter that accepts arguments that match its embedded
     /// `ScalarType`.
     Plain(ScalarType),
+    /// A pseudotype permitting any range type, requiring other "Any"-type
+    /// parameters to be of the same type.
+    RangeAny,
+    /// A pseudotype permitting any type, requiring other "Any"-type
+    /// parameters to be of the same type. Additionally, enforces a constraint
+    /// that when used with `RangeAny`, resolves to that argument's element
+    /// type.
+    RangeElementAny,
     /// A polymorphic pseudotype permitting a `ScalarType::Record` of any type,
     /// but all records must be structurally equal.
     RecordAny,
@@ -716,12 +728,13 @@ impl ParamType {
         use ScalarType::*;
 
         match self {
-            Any | AnyCompatible | ListElementAnyCompatible => true,
+            Any | AnyCompatible | ListElementAnyCompatible | RangeElementAny => true,
             ArrayAny | ArrayAnyCompatible => matches!(t, Array(..) | Int2Vector),
             ListAny | ListAnyCompatible => matches!(t, List { .. }),
             MapAny | MapAnyCompatible => matches!(t, Map { .. }),
             NonVecAny => !t.is_vec(),
             Plain(to) => typeconv::can_cast(ecx, CastContext::Implicit, t, to),
+            RangeAny => matches!(t, Range { .. }),
             RecordAny => matches!(t, Record { .. }),
         }
     }
@@ -767,6 +780,8 @@ impl ParamType {
             | MapAny
             | MapAnyCompatible
             | NonVecAny
+            | RangeAny
+            | RangeElementAny
             // In PG, RecordAny isn't polymorphic even though it offers
             // polymorphic behavior. For more detail, see
             // `PolymorphicCompatClass::StructuralEq`.
@@ -795,6 +810,8 @@ impl ParamType {
             ParamType::MapAny => "map",
             ParamType::MapAnyCompatible => "anycompatiblemap",
             ParamType::NonVecAny => "anynonarray",
+            ParamType::RangeAny => "anyrange",
+            ParamType::RangeElementAny => "anyelement",
             ParamType::RecordAny => "record",
         }
     }
@@ -826,7 +843,7 @@ impl From<ScalarBaseType> for ParamType {
     fn from(s: ScalarBaseType) -> ParamType {
         use ScalarBaseType::*;
         let s = match s {
-            Array | List | Map | Record => {
+            Array | List | Map | Range | Record => {
                 panic!("use polymorphic parameters rather than {:?}", s);
             }
             Bool => ScalarType::Bool,
@@ -1380,7 +1397,9 @@ impl TryFrom<&ParamType> for PolymorphicCompatClass {
         use ParamType::*;
 
         Ok(match param {
-            ArrayAny | ListAny | MapAny | NonVecAny => PolymorphicCompatClass::BaseEq,
+            ArrayAny | ListAny | MapAny | NonVecAny | RangeAny | RangeElementAny => {
+                PolymorphicCompatClass::BaseEq
+            }
             ArrayAnyCompatible | AnyCompatible => PolymorphicCompatClass::BestCommonAny,
             ListAnyCompatible | ListElementAnyCompatible => PolymorphicCompatClass::BestCommonList,
             MapAnyCompatible => PolymorphicCompatClass::BestCommonMap,
@@ -1449,12 +1468,15 @@ impl PolymorphicSolution {
 
         self.seen.push(match param {
             AnyCompatible | ArrayAny | ListAny | ListAnyCompatible | MapAny | MapAnyCompatible
-            | NonVecAny | RecordAny => seen,
+            | NonVecAny | RangeAny | RecordAny => seen,
             ArrayAnyCompatible => seen.map(|array| array.unwrap_array_element_type().clone()),
             ListElementAnyCompatible => seen.map(|el| ScalarType::List {
                 custom_id: None,
                 element_type: Box::new(el),
             }),
+            RangeElementAny => seen.map(|el| ScalarType::Range {
+                element_type: Box::new(el),
+            }),
             o => {
                 assert!(
                     !o.is_polymorphic(),
@@ -1550,7 +1572,7 @@ impl PolymorphicSolution {
 
         match param {
             AnyCompatible | ArrayAny | ListAny | ListAnyCompatible | MapAny | MapAnyCompatible
-            | NonVecAny => self.key.clone(),
+            | NonVecAny | RangeAny => self.key.clone(),
             ArrayAnyCompatible => self
                 .key
                 .as_ref()
@@ -1559,6 +1581,10 @@ impl PolymorphicSolution {
                 .key
                 .as_ref()
                 .map(|key| key.unwrap_list_element_type().clone()),
+            RangeElementAny => self
+                .key
+                .as_ref()
+                .map(|key| key.unwrap_range_element_type().clone()),
             _ => unreachable!(
                 "cannot use polymorphic solution to resolve target type for param {:?}",
                 param,
@@ -1591,7 +1617,7 @@ fn coerce_args_to_types(
                 }
                 _ => cexpr.type_as_any(ecx)?,
             },
-            p @ (ArrayAny | ListAny | MapAny) => {
+            p @ (ArrayAny | ListAny | MapAny | RangeAny | RangeElementAny) => {
                 let target = polymorphic_solution
                     .target_for_param_type(p)
                     .ok_or_else(|| {
@@ -1932,9 +1958,38 @@ pub static PG_CATALOG_BUILTINS: Lazy<HashMap<&'static str, Func>> = Lazy::new(||
         },
         "date_trunc" => Scalar {
             params!(String, Timestamp) => BinaryFunc::DateTruncTimestamp, 2020;
-            params!(String, TimestampTz) => BinaryFunc::DateTruncTimestampTz, 1217;
+            // Truncation happens in the session's time zone, so that e.g.
+            // truncating to days yields local midnight.
+            params!(String, TimestampTz) => Operation::binary(|ecx, lhs, rhs| {
+                let tz = ecx.session_timezone();
+                if tz.is_utc() {
+                    Ok(lhs.call_binary(rhs, BinaryFunc::DateTruncTimestampTz))
+                } else {
+                    Ok(lhs
+                        .call_binary(
+                            rhs.call_unary(UnaryFunc::TimezoneTimestampTz(func::TimezoneTimestampTz(tz))),
+                            BinaryFunc::DateTruncTimestamp,
+                        )
+                        .call_unary(UnaryFunc::TimezoneTimestamp(func::TimezoneTimestamp(tz))))
+                }
+            }), 1217;
+            params!(String, TimestampTz, String) => Operation::variadic(|_ecx, exprs| {
+                let mut exprs = exprs.into_iter();
+                let (units, ts, zone) = (exprs.next().unwrap(), exprs.next().unwrap(), exprs.next().unwrap());
+                Ok(zone.clone().call_binary(
+                    units.call_binary(
+                        zone.call_binary(ts, BinaryFunc::TimezoneTimestampTz),
+                        BinaryFunc::DateTruncTimestamp,
+                    ),
+                    BinaryFunc::TimezoneTimestamp,
+                ))
+            }), 1284;
             params!(String, Interval) => BinaryFunc::DateTruncInterval, 1218;
         },
+        "daterange" => Scalar {
+            params!(Date, Date) => VariadicFunc::RangeCreate { elem_type: ScalarType::Date }, 3941;
+            params!(Date, Date, String) => VariadicFunc::RangeCreate { elem_type: ScalarType::Date }, 3942;
+        },
         "degrees" => Scalar {
             params!(Float64) => UnaryFunc::Degrees(func::Degrees), 1608;
         },
@@ -1966,6 +2021,17 @@ pub static PG_CATALOG_BUILTINS: Lazy<HashMap<&'static str, Func>> = Lazy::new(||
             params!(String, String, String) => VariadicFunc::HmacString, 44156;
             params!(Bytes, Bytes, String) => VariadicFunc::HmacBytes, 44157;
         },
+        "int4range" => Scalar {
+            params!(Int32, Int32) => VariadicFunc::RangeCreate { elem_type: ScalarType::Int32 }, 3840;
+            params!(Int32, Int32, String) => VariadicFunc::RangeCreate { elem_type: ScalarType::Int32 }, 3841;
+        },
+        "int8range" => Scalar {
+            params!(Int64, Int64) => VariadicFunc::RangeCreate { elem_type: ScalarType::Int64 }, 3945;
+            params!(Int64, Int64, String) => VariadicFunc::RangeCreate { elem_type: ScalarType::Int64 }, 3946;
+        },
+        "isempty" => Scalar {
+            params!(RangeAny) => UnaryFunc::RangeEmpty(func::RangeEmpty) => Bool, 3850;
+        },
         "jsonb_array_length" => Scalar {
             params!(Jsonb) => UnaryFunc::JsonbArrayLength(func::JsonbArrayLength) => Int32, 3207;
         },
@@ -2037,6 +2103,13 @@ pub static PG_CATALOG_BUILTINS: Lazy<HashMap<&'static str, Func>> = Lazy::new(||
         },
         "lower" => Scalar {
             params!(String) => UnaryFunc::Lower(func::Lower), 870;
+            params!(RangeAny) => UnaryFunc::RangeLower(func::RangeLower) => RangeElementAny, 3848;
+        },
+        "lower_inc" => Scalar {
+            params!(RangeAny) => UnaryFunc::RangeLowerInc(func::RangeLowerInc) => Bool, 3851;
+        },
+        "lower_inf" => Scalar {
+            params!(RangeAny) => UnaryFunc::RangeLowerInf(func::RangeLowerInf) => Bool, 3853;
         },
         "lpad" => Scalar {
             params!(String, Int64) => VariadicFunc::PadLeading, 879;
@@ -2073,6 +2146,10 @@ pub static PG_CATALOG_BUILTINS: Lazy<HashMap<&'static str, Func>> = Lazy::new(||
         "now" => Scalar {
             params!() => UnmaterializableFunc::CurrentTimestamp, 1299;
         },
+        "numrange" => Scalar {
+            params!(Numeric, Numeric) => VariadicFunc::RangeCreate { elem_type: ScalarType::Numeric { max_scale: None } }, 3844;
+            params!(Numeric, Numeric, String) => VariadicFunc::RangeCreate { elem_type: ScalarType::Numeric { max_scale: None } }, 3845;
+        },
         "octet_length" => Scalar {
             params!(Bytes) => UnaryFunc::ByteLengthBytes(func::ByteLengthBytes), 720;
             params!(String) => UnaryFunc::ByteLengthString(func::ByteLengthString), 1374;
@@ -2394,6 +2471,14 @@ pub static PG_CATALOG_BUILTINS: Lazy<HashMap<&'static str, Func>> = Lazy::new(||
         "to_timestamp" => Scalar {
             params!(Float64) => UnaryFunc::ToTimestamp(func::ToTimestamp), 1158;
         },
+        "tsrange" => Scalar {
+            params!(Timestamp, Timestamp) => VariadicFunc::RangeCreate { elem_type: ScalarType::Timestamp }, 3933;
+            params!(Timestamp, Timestamp, String) => VariadicFunc::RangeCreate { elem_type: ScalarType::Timestamp }, 3934;
+        },
+        "tstzrange" => Scalar {
+            params!(TimestampTz, TimestampTz) => VariadicFunc::RangeCreate { elem_type: ScalarType::TimestampTz }, 3937;
+            params!(TimestampTz, TimestampTz, String) => VariadicFunc::RangeCreate { elem_type: ScalarType::TimestampTz }, 3938;
+        },
         "trunc" => Scalar {
             params!(Float32) => UnaryFunc::TruncFloat32(func::TruncFloat32), oid::FUNC_TRUNC_F32_OID;
             params!(Float64) => UnaryFunc::TruncFloat64(func::TruncFloat64), 1343;
@@ -2401,6 +2486,13 @@ pub static PG_CATALOG_BUILTINS: Lazy<HashMap<&'static str, Func>> = Lazy::new(||
         },
         "upper" => Scalar {
             params!(String) => UnaryFunc::Upper(func::Upper), 871;
+            params!(RangeAny) => UnaryFunc::RangeUpper(func::RangeUpper) => RangeElementAny, 3849;
+        },
+        "upper_inc" => Scalar {
+            params!(RangeAny) => UnaryFunc::RangeUpperInc(func::RangeUpperInc) => Bool, 3852;
+        },
+        "upper_inf" => Scalar {
+            params!(RangeAny) => UnaryFunc::RangeUpperInf(func::RangeUpperInf) => Bool, 3854;
         },
         "variance" => Scalar {
             params!(Float32) => Operation::nullary(|_ecx| catalog_name_only!("variance")) => Float64, 2151;
@@ -3093,6 +3185,32 @@ fn array_to_string(
     })
 }
 
+/// Plans the addition of the interval `iv` to the `timestamptz` `ts`.
+///
+/// Like PostgreSQL, the months and days of `iv` are added to the local time in
+/// the session's time zone, so that e.g. adding a day across a daylight saving
+/// time transition preserves the wall-clock time. The remaining time part of
+/// `iv` is added as an absolute duration.
+fn plan_add_timestamptz_interval(
+    ecx: &ExprContext,
+    ts: HirScalarExpr,
+    iv: HirScalarExpr,
+) -> HirScalarExpr {
+    let tz = ecx.session_timezone();
+    if tz.is_utc() {
+        return ts.call_binary(iv, BinaryFunc::AddTimestampTzInterval);
+    }
+    let days = HirScalarExpr::literal(Datum::String("day"), ScalarType::String)
+        .call_binary(iv.clone(), BinaryFunc::DateTruncInterval);
+    let time = iv.call_binary(days.clone(), BinaryFunc::SubInterval);
+    let to_local = UnaryFunc::TimezoneTimestampTz(func::TimezoneTimestampTz(tz));
+    let from_local = UnaryFunc::TimezoneTimestamp(func::TimezoneTimestamp(tz));
+    ts.call_unary(to_local)
+        .call_binary(days, BinaryFunc::AddTimestampInterval)
+        .call_unary(from_local)
+        .call_binary(time, BinaryFunc::AddTimestampTzInterval)
+}
+
 /// Correlates an operator with all of its implementations.
 static OP_IMPLS: Lazy<HashMap<&'static str, Func>> = Lazy::new(|| {
     use BinaryFunc::*;
@@ -3150,9 +3268,11 @@ static OP_IMPLS: Lazy<HashMap<&'static str, Func>> = Lazy::new(|| {
             params!(Interval, Timestamp) => {
                 Operation::binary(|_ecx, lhs, rhs| Ok(rhs.call_binary(lhs, AddTimestampInterval)))
             }, 2066;
-            params!(TimestampTz, Interval) => AddTimestampTzInterval, 1327;
+            params!(TimestampTz, Interval) => {
+                Operation::binary(|ecx, lhs, rhs| Ok(plan_add_timestamptz_interval(ecx, lhs, rhs)))
+            }, 1327;
             params!(Interval, TimestampTz) => {
-                Operation::binary(|_ecx, lhs, rhs| Ok(rhs.call_binary(lhs, AddTimestampTzInterval)))
+                Operation::binary(|ecx, lhs, rhs| Ok(plan_add_timestamptz_interval(ecx, rhs, lhs)))
             }, 2554;
             params!(Date, Interval) => AddDateInterval, 1076;
             params!(Interval, Date) => {
@@ -3167,6 +3287,7 @@ static OP_IMPLS: Lazy<HashMap<&'static str, Func>> = Lazy::new(|| {
                 Operation::binary(|_ecx, lhs, rhs| Ok(rhs.call_binary(lhs, AddTimeInterval)))
             }, 1849;
             params!(Numeric, Numeric) => AddNumeric, 1758;
+            params!(RangeAny, RangeAny) => RangeUnion => RangeAny, 3898;
         },
         "-" => Scalar {
             params!(Int16) => UnaryFunc::NegInt16(func::NegInt16), 559;
@@ -3188,13 +3309,22 @@ static OP_IMPLS: Lazy<HashMap<&'static str, Func>> = Lazy::new(|| {
             params!(Timestamp, Timestamp) => SubTimestamp, 2067;
             params!(TimestampTz, TimestampTz) => SubTimestampTz, 1328;
             params!(Timestamp, Interval) => SubTimestampInterval, 2068;
-            params!(TimestampTz, Interval) => SubTimestampTzInterval, 1329;
+            params!(TimestampTz, Interval) => Operation::binary(|ecx, lhs, rhs| {
+                let tz = ecx.session_timezone();
+                if tz.is_utc() {
+                    Ok(lhs.call_binary(rhs, SubTimestampTzInterval))
+                } else {
+                    let rhs = rhs.call_unary(UnaryFunc::NegInterval(func::NegInterval));
+                    Ok(plan_add_timestamptz_interval(ecx, lhs, rhs))
+                }
+            }), 1329;
             params!(Date, Date) => SubDate, 1099;
             params!(Date, Interval) => SubDateInterval, 1077;
             params!(Time, Time) => SubTime, 1399;
             params!(Time, Interval) => SubTimeInterval, 1801;
             params!(Jsonb, Int64) => JsonbDeleteInt64, 3286;
             params!(Jsonb, String) => JsonbDeleteString, 3285;
+            params!(RangeAny, RangeAny) => RangeDifference => RangeAny, 3899;
             // TODO(jamii) there should be corresponding overloads for
             // Array(Int64) and Array(String)
         },
@@ -3212,6 +3342,7 @@ static OP_IMPLS: Lazy<HashMap<&'static str, Func>> = Lazy::new(|| {
                 Operation::binary(|_ecx, lhs, rhs| Ok(rhs.call_binary(lhs, MulInterval)))
             }, 1584;
             params!(Numeric, Numeric) => MulNumeric, 1760;
+            params!(RangeAny, RangeAny) => RangeIntersection => RangeAny, 3900;
         },
         "/" => Scalar {
             params!(Int16, Int16) => DivInt16, 527;
@@ -3442,6 +3573,8 @@ static OP_IMPLS: Lazy<HashMap<&'static str, Func>> = Lazy::new(|| {
                       .call_binary(rhs, JsonbContainsJsonb))
             }), oid::OP_CONTAINS_STRING_JSONB_OID;
             params!(MapAnyCompatible, MapAnyCompatible) => MapContainsMap => Bool, oid::OP_CONTAINS_MAP_MAP_OID;
+            params!(RangeAny, RangeElementAny) => RangeContainsElem { rev: false } => Bool, 3889;
+            params!(RangeAny, RangeAny) => RangeContainsRange { rev: false } => Bool, 3890;
         },
         "<@" => Scalar {
             params!(Jsonb, Jsonb) => Operation::binary(|_ecx, lhs, rhs| {
@@ -3463,6 +3596,14 @@ static OP_IMPLS: Lazy<HashMap<&'static str, Func>> = Lazy::new(|| {
             params!(MapAnyCompatible, MapAnyCompatible) => Operation::binary(|_ecx, lhs, rhs| {
                 Ok(rhs.call_binary(lhs, MapContainsMap))
             }) => Bool, oid::OP_CONTAINED_MAP_MAP_OID;
+            params!(RangeElementAny, RangeAny) => RangeContainsElem { rev: true } => Bool, 3891;
+            params!(RangeAny, RangeAny) => RangeContainsRange { rev: true } => Bool, 3892;
+        },
+        "&&" => Scalar {
+            params!(RangeAny, RangeAny) => RangeOverlaps => Bool, 3888;
+        },
+        "-|-" => Scalar {
+            params!(RangeAny, RangeAny) => RangeAdjacent => Bool, 3897;
         },
         "?" => Scalar {
             params!(Jsonb, String) => JsonbContainsString, 3247;
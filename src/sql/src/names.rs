@@ -27,8 +27,8 @@ use crate::ast::fold::{Fold, FoldNode};
 use crate::ast::visit::{Visit, VisitNode};
 use crate::ast::visit_mut::VisitMut;
 use crate::ast::{
-    self, AstInfo, Cte, CteBlock, CteMutRec, Ident, Query, Raw, RawClusterName, RawDataType,
-    RawObjectName, Statement, UnresolvedObjectName,
+    self, AstInfo, Cte, CteBlock, CteMutRec, Ident, MutRecBlock, Query, Raw, RawClusterName,
+    RawDataType, RawObjectName, Statement, UnresolvedObjectName,
 };
 use crate::catalog::{CatalogItemType, CatalogTypeDetails, SessionCatalog};
 use crate::normalize;
@@ -235,7 +235,7 @@ impl From<Option<String>> for RawDatabaseSpecifier {
 }
 
 /// An id of a database.
-#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum ResolvedDatabaseSpecifier {
     /// The "ambient" database, which is always present and is not named
     /// explicitly, but by omission.
@@ -272,7 +272,7 @@ impl From<u64> for ResolvedDatabaseSpecifier {
  * their Id.
  */
 /// An id of a schema.
-#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum SchemaSpecifier {
     /// A temporary schema
     Temporary,
@@ -660,6 +660,8 @@ impl FromStr for DatabaseId {
 pub enum RoleId {
     System(u64),
     User(u64),
+    /// The pseudo-role `PUBLIC`, of which every role is implicitly a member.
+    Public,
 }
 
 impl RoleId {
@@ -676,6 +678,9 @@ impl FromStr for RoleId {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "p" {
+            return Ok(Self::Public);
+        }
         if s.len() < 2 {
             return Err(anyhow!("couldn't parse role id {}", s));
         }
@@ -693,6 +698,30 @@ impl fmt::Display for RoleId {
         match self {
             Self::System(id) => write!(f, "s{}", id),
             Self::User(id) => write!(f, "u{}", id),
+            Self::Public => write!(f, "p"),
+        }
+    }
+}
+
+/// The identifier for any object that can be owned and have privileges
+/// granted on it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum ObjectId {
+    Cluster(ComputeInstanceId),
+    Database(DatabaseId),
+    Schema((ResolvedDatabaseSpecifier, SchemaSpecifier)),
+    Item(GlobalId),
+}
+
+impl fmt::Display for ObjectId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ObjectId::Cluster(id) => write!(f, "{id}"),
+            ObjectId::Database(id) => write!(f, "{id}"),
+            ObjectId::Schema((database_spec, schema_spec)) => {
+                write!(f, "{database_spec}.{schema_spec}")
+            }
+            ObjectId::Item(id) => write!(f, "{id}"),
         }
     }
 }
@@ -839,7 +868,7 @@ impl<'a> Fold<Raw, Aug> for NameResolver<'a> {
                 }
                 CteBlock::Simple(result_ctes)
             }
-            CteBlock::MutuallyRecursive(ctes) => {
+            CteBlock::MutuallyRecursive(MutRecBlock { options, ctes }) => {
                 let mut result_ctes = Vec::<CteMutRec<Aug>>::new();
 
                 let initial_id = self.ctes.len();
@@ -868,7 +897,13 @@ impl<'a> Fold<Raw, Aug> for NameResolver<'a> {
                         query,
                     });
                 }
-                CteBlock::MutuallyRecursive(result_ctes)
+                CteBlock::MutuallyRecursive(MutRecBlock {
+                    options: options
+                        .into_iter()
+                        .map(|option| self.fold_mut_rec_block_option(option))
+                        .collect(),
+                    ctes: result_ctes,
+                })
             }
         };
 
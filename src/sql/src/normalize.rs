@@ -25,8 +25,8 @@ use mz_sql_parser::ast::{
     CreateConnectionStatement, CreateIndexStatement, CreateMaterializedViewStatement,
     CreateSecretStatement, CreateSinkStatement, CreateSourceStatement, CreateSubsourceStatement,
     CreateTableStatement, CreateTypeStatement, CreateViewStatement, CteBlock, Function,
-    FunctionArgs, Ident, IfExistsBehavior, Op, Query, Statement, TableFactor, TableFunction,
-    UnresolvedObjectName, UnresolvedSchemaName, Value, ViewDefinition,
+    FunctionArgs, Ident, IfExistsBehavior, MutRecBlock, Op, Query, Statement, TableFactor,
+    TableFunction, UnresolvedObjectName, UnresolvedSchemaName, Value, ViewDefinition,
 };
 
 use crate::names::{
@@ -218,7 +218,7 @@ pub fn create_statement(
                         self.ctes.push(cte.alias.name.clone());
                     }
                 }
-                CteBlock::MutuallyRecursive(ctes) => {
+                CteBlock::MutuallyRecursive(MutRecBlock { options: _, ctes }) => {
                     for cte in ctes.iter() {
                         self.ctes.push(cte.name.clone());
                     }
@@ -45,6 +45,7 @@ use mz_pgcopy::CopyFormatParams;
 use mz_repr::explain_new::{ExplainConfig, ExplainFormat};
 use mz_repr::{ColumnName, Diff, GlobalId, RelationDesc, Row, ScalarType};
 use mz_storage_client::types::sinks::{SinkEnvelope, StorageSinkConnectionBuilder};
+use mz_storage_client::types::sources::encoding::DataEncodingInner;
 use mz_storage_client::types::sources::{SourceDesc, Timeline};
 
 use crate::ast::{
@@ -53,8 +54,10 @@ use crate::ast::{
 };
 use crate::catalog::{CatalogType, IdReference};
 use crate::names::{
-    Aug, DatabaseId, FullObjectName, QualifiedObjectName, ResolvedDatabaseSpecifier, SchemaId,
+    Aug, DatabaseId, FullObjectName, ObjectId, QualifiedObjectName, ResolvedDatabaseSpecifier,
+    RoleId, SchemaId,
 };
+use crate::rbac::AclMode;
 
 pub(crate) mod error;
 pub(crate) mod explain;
@@ -78,7 +81,9 @@ pub use explain::Explanation;
 use mz_sql_parser::ast::TransactionIsolationLevel;
 pub use optimize::OptimizerConfig;
 pub use query::{QueryContext, QueryLifetime};
-pub use statement::{describe, plan, plan_copy_from, StatementContext, StatementDesc};
+pub use statement::{
+    analyze_estimates, describe, plan, plan_copy_from, StatementContext, StatementDesc,
+};
 
 /// Instructions for executing a SQL query.
 #[derive(Debug, EnumKind)]
@@ -127,7 +132,9 @@ pub enum Plan {
     AlterSink(AlterSinkPlan),
     AlterSource(AlterSourcePlan),
     AlterItemRename(AlterItemRenamePlan),
+    AlterOwner(AlterOwnerPlan),
     AlterSecret(AlterSecretPlan),
+    AlterTableAddColumn(AlterTableAddColumnPlan),
     AlterSystemSet(AlterSystemSetPlan),
     AlterSystemReset(AlterSystemResetPlan),
     AlterSystemResetAll(AlterSystemResetAllPlan),
@@ -140,6 +147,9 @@ pub enum Plan {
     Deallocate(DeallocatePlan),
     Raise(RaisePlan),
     RotateKeys(RotateKeysPlan),
+    GrantPrivileges(GrantPrivilegesPlan),
+    RevokePrivileges(RevokePrivilegesPlan),
+    Analyze(AnalyzePlan),
 }
 
 impl Plan {
@@ -148,6 +158,7 @@ impl Plan {
     pub fn generated_from(stmt: StatementKind) -> Vec<PlanKind> {
         match stmt {
             StatementKind::AlterConnection => vec![PlanKind::AlterNoop, PlanKind::RotateKeys],
+            StatementKind::Analyze => vec![PlanKind::Analyze],
             StatementKind::AlterIndex => vec![
                 PlanKind::AlterIndexResetOptions,
                 PlanKind::AlterIndexSetOptions,
@@ -156,9 +167,13 @@ impl Plan {
             StatementKind::AlterObjectRename => {
                 vec![PlanKind::AlterItemRename, PlanKind::AlterNoop]
             }
+            StatementKind::AlterOwner => vec![PlanKind::AlterNoop, PlanKind::AlterOwner],
             StatementKind::AlterSecret => vec![PlanKind::AlterNoop, PlanKind::AlterSecret],
             StatementKind::AlterSink => vec![PlanKind::AlterNoop, PlanKind::AlterSink],
             StatementKind::AlterSource => vec![PlanKind::AlterNoop, PlanKind::AlterSource],
+            StatementKind::AlterTableAddColumn => {
+                vec![PlanKind::AlterNoop, PlanKind::AlterTableAddColumn]
+            }
             StatementKind::AlterSystemReset => {
                 vec![PlanKind::AlterNoop, PlanKind::AlterSystemReset]
             }
@@ -202,11 +217,14 @@ impl Plan {
             StatementKind::DropSchema => vec![PlanKind::DropSchema],
             StatementKind::Execute => vec![PlanKind::Execute],
             StatementKind::Explain => vec![PlanKind::Explain],
+            StatementKind::ExplainAnalyze => vec![PlanKind::Peek],
             StatementKind::Fetch => vec![PlanKind::Fetch],
+            StatementKind::GrantPrivileges => vec![PlanKind::GrantPrivileges],
             StatementKind::Insert => vec![PlanKind::Insert],
             StatementKind::Prepare => vec![PlanKind::Prepare],
             StatementKind::Raise => vec![PlanKind::Raise],
             StatementKind::ResetVariable => vec![PlanKind::ResetVariable],
+            StatementKind::RevokePrivileges => vec![PlanKind::RevokePrivileges],
             StatementKind::Rollback => vec![PlanKind::AbortTransaction],
             StatementKind::Select => vec![PlanKind::Peek],
             StatementKind::SetTransaction => vec![],
@@ -246,6 +264,7 @@ pub struct CreateSchemaPlan {
 #[derive(Debug)]
 pub struct CreateRolePlan {
     pub name: String,
+    pub superuser: bool,
 }
 
 #[derive(Debug)]
@@ -458,6 +477,22 @@ pub struct SubscribePlan {
     pub when: QueryWhen,
     pub copy_to: Option<CopyFormat>,
     pub emit_progress: bool,
+    pub output: SubscribeOutput,
+    /// The timestamp at which to complete the subscribe, if any. Only updates
+    /// at times before it are emitted.
+    pub up_to: Option<MirScalarExpr>,
+}
+
+/// How the updates of a `SUBSCRIBE` are presented to the client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubscribeOutput {
+    /// Each update is the changed row, preceded by its diff.
+    Diffs,
+    /// `ENVELOPE UPSERT`: each update is the new value of a key, or a
+    /// deletion of the key.
+    EnvelopeUpsert { key_indices: Vec<usize> },
+    /// `ENVELOPE DEBEZIUM`: each update is the old and new value of a key.
+    EnvelopeDebezium { key_indices: Vec<usize> },
 }
 
 #[derive(Debug)]
@@ -516,6 +551,18 @@ pub struct ReadThenWritePlan {
     pub returning: Vec<mz_expr::MirScalarExpr>,
 }
 
+/// Collects statistics about the relation identified by `id`, which has
+/// `arity` columns, by reading the rows produced by `selection`: the number of
+/// rows in the relation, and sketches of the distinct values in each of its
+/// columns. See [`analyze_estimates`] for how to interpret them.
+#[derive(Debug)]
+pub struct AnalyzePlan {
+    pub id: GlobalId,
+    pub selection: mz_expr::MirRelationExpr,
+    pub finishing: RowSetFinishing,
+    pub arity: usize,
+}
+
 /// Generated by `ALTER ... IF EXISTS` if the named object did not exist.
 #[derive(Debug)]
 pub struct AlterNoopPlan {
@@ -564,6 +611,21 @@ pub struct AlterItemRenamePlan {
     pub object_type: ObjectType,
 }
 
+#[derive(Debug)]
+pub struct AlterOwnerPlan {
+    pub id: ObjectId,
+    pub object_type: ObjectType,
+    pub new_owner: RoleId,
+}
+
+#[derive(Debug)]
+pub struct AlterTableAddColumnPlan {
+    pub id: GlobalId,
+    pub column_name: ColumnName,
+    /// The table, including the new column.
+    pub table: Table,
+}
+
 #[derive(Debug)]
 pub struct AlterSecretPlan {
     pub id: GlobalId,
@@ -589,6 +651,30 @@ pub struct RotateKeysPlan {
     pub id: GlobalId,
 }
 
+#[derive(Debug)]
+pub struct GrantPrivilegesPlan {
+    /// The privileges to grant, and the objects to grant them on.
+    pub update_privileges: Vec<UpdatePrivilege>,
+    /// The roles that will be granted the privileges.
+    pub grantees: Vec<RoleId>,
+}
+
+#[derive(Debug)]
+pub struct RevokePrivilegesPlan {
+    /// The privileges to revoke, and the objects to revoke them from.
+    pub update_privileges: Vec<UpdatePrivilege>,
+    /// The roles that will have the privileges revoked.
+    pub revokees: Vec<RoleId>,
+}
+
+#[derive(Debug)]
+pub struct UpdatePrivilege {
+    /// The privileges being granted or revoked.
+    pub acl_mode: AclMode,
+    /// The object on which the privileges are being granted or revoked.
+    pub target_id: ObjectId,
+}
+
 #[derive(Debug)]
 pub struct DeclarePlan {
     pub name: String,
@@ -635,16 +721,30 @@ pub struct Table {
     pub create_sql: String,
     pub desc: RelationDesc,
     pub defaults: Vec<Expr<Aug>>,
+    /// The value of each column in rows that were written before the column
+    /// was added to the table: the column's default, if it is a constant, and
+    /// otherwise `NULL`.
+    pub missing_values: Row,
     pub temporary: bool,
 }
 
 #[derive(Clone, Debug)]
 pub struct Source {
     pub create_sql: String,
-    pub ingestion: Option<Ingestion>,
+    pub data_source: DataSourceDesc,
     pub desc: RelationDesc,
 }
 
+#[derive(Clone, Debug)]
+pub enum DataSourceDesc {
+    /// Receives data from an external system.
+    Ingestion(Ingestion),
+    /// Receives data from some other source.
+    Source,
+    /// Receives data appended through `environmentd`'s HTTP webhook endpoint.
+    Webhook(Webhook),
+}
+
 #[derive(Clone, Debug)]
 pub struct Ingestion {
     pub desc: SourceDesc,
@@ -652,6 +752,26 @@ pub struct Ingestion {
     pub subsource_exports: HashMap<GlobalId, usize>,
 }
 
+/// Describes how requests to a webhook source are validated and decoded.
+#[derive(Clone, Debug, Serialize)]
+pub struct Webhook {
+    /// The encoding of request bodies. One of `Bytes`, `Text`, or `Json`.
+    pub body_encoding: DataEncodingInner,
+    /// Whether the request headers are recorded in a column after the body.
+    pub include_headers: bool,
+    /// How request signatures are validated, if at all.
+    pub validation: Option<WebhookValidation>,
+}
+
+/// Describes how to validate the HMAC-SHA256 signature of a webhook request.
+#[derive(Clone, Debug, Serialize)]
+pub struct WebhookValidation {
+    /// The secret the signature is keyed with.
+    pub secret_id: GlobalId,
+    /// The (lowercase) name of the header carrying the hex-encoded signature.
+    pub header: String,
+}
+
 #[derive(Clone, Debug)]
 pub struct Connection {
     pub create_sql: String,
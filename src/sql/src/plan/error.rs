@@ -101,6 +101,15 @@ pub enum PlanError {
     FetchingPostgresPublicationInfoFailed {
         cause: Arc<mz_postgres_util::PostgresError>,
     },
+    UnsupportedTypeInMySqlSource {
+        cols: Vec<(String, String)>,
+    },
+    FetchingMySqlSchemaInfoFailed {
+        cause: Arc<mz_mysql_util::MySqlError>,
+    },
+    InvalidMySqlBinlogConfig {
+        cause: Arc<mz_mysql_util::MySqlError>,
+    },
     InvalidProtobufSchema {
         cause: protobuf_native::OperationFailedError,
     },
@@ -131,6 +140,8 @@ impl PlanError {
         match self {
             Self::FetchingCsrSchemaFailed { cause, .. } => Some(cause.to_string()),
             Self::FetchingPostgresPublicationInfoFailed { cause } => Some(cause.to_string()),
+            Self::FetchingMySqlSchemaInfoFailed { cause } => Some(cause.to_string()),
+            Self::InvalidMySqlBinlogConfig { cause } => Some(cause.to_string()),
             Self::InvalidProtobufSchema { cause } => Some(cause.to_string()),
             Self::InvalidOptionValue { err, .. } => err.detail(),
             _ => None,
@@ -178,6 +189,25 @@ impl PlanError {
                 }
                 None
             }
+            Self::FetchingMySqlSchemaInfoFailed { cause } => {
+                if let Some(cause) = cause.source() {
+                    if let Some(cause) = cause.downcast_ref::<io::Error>() {
+                        if cause.kind() == io::ErrorKind::TimedOut {
+                            return Some(
+                                "Do you have a firewall or security group that is \
+                                preventing Materialize from conecting to your MySQL server?"
+                                    .into(),
+                            );
+                        }
+                    }
+                }
+                None
+            }
+            Self::InvalidMySqlBinlogConfig { .. } => Some(
+                "MySQL sources require log_bin = ON, binlog_format = ROW, and \
+                binlog_row_image = FULL on the upstream server."
+                    .into(),
+            ),
             Self::InvalidOptionValue {  err, .. } => err.hint(),
             _ => None,
         }
@@ -297,6 +327,25 @@ impl fmt::Display for PlanError {
             Self::FetchingPostgresPublicationInfoFailed { .. } => {
                 write!(f, "failed to fetch publication information from PostgreSQL database")
             }
+            Self::UnsupportedTypeInMySqlSource { cols } => {
+                let mut cols = cols.to_owned();
+                cols.sort();
+
+                write!(
+                    f,
+                    "the following columns contain unsupported types:\n{}",
+                    itertools::join(
+                        cols.into_iter().map(|(col, ty)| format!("{} ({})", col, ty)),
+                        "\n"
+                    )
+                )
+            },
+            Self::FetchingMySqlSchemaInfoFailed { .. } => {
+                write!(f, "failed to fetch schema information from MySQL server")
+            }
+            Self::InvalidMySqlBinlogConfig { .. } => {
+                write!(f, "MySQL server is not configured for replication")
+            }
             Self::InvalidProtobufSchema { .. } => {
                 write!(f, "invalid protobuf schema")
             }
@@ -175,6 +175,7 @@ impl<'a> Explanation<'a> {
                 }
                 LetRec {
                     bindings: _,
+                    max_iters: _,
                     body: _,
                 } => {
                     // TODO: determine if we have to insert ids before walking.
@@ -550,6 +551,11 @@ impl<'a> Explanation<'a> {
                         self.fmt_scalar_expr(f, &scalar.expr)?;
                         write!(f, ")")?
                     }
+                    WindowExprType::Aggregate(aggregate) => {
+                        write!(f, "{}(", aggregate.clone().into_expr())?;
+                        self.fmt_scalar_expr(f, &aggregate.aggregate_expr.expr)?;
+                        write!(f, ")")?
+                    }
                 }
                 write!(f, " over (")?;
                 for (i, e) in expr.partition.iter().enumerate() {
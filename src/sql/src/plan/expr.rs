@@ -108,6 +108,8 @@ pub enum HirRelationExpr {
     LetRec {
         /// List of bindings all of which are in scope of each other.
         bindings: Vec<(String, mz_expr::LocalId, HirRelationExpr, RelationType)>,
+        /// The maximum number of iterations to evaluate, if any.
+        max_iters: Option<u64>,
         /// Result of the AST node.
         body: Box<HirRelationExpr>,
     },
@@ -343,6 +345,7 @@ impl VisitChildren<HirScalarExpr> for WindowExpr {
 pub enum WindowExprType {
     Scalar(ScalarWindowExpr),
     Value(ValueWindowExpr),
+    Aggregate(AggregateWindowExpr),
 }
 
 impl WindowExprType {
@@ -355,6 +358,7 @@ impl WindowExprType {
         match self {
             Self::Scalar(expr) => expr.visit_expressions(f),
             Self::Value(expr) => expr.visit_expressions(f),
+            Self::Aggregate(expr) => expr.visit_expressions(f),
         }
     }
 
@@ -367,6 +371,7 @@ impl WindowExprType {
         match self {
             Self::Scalar(expr) => expr.visit_expressions_mut(f),
             Self::Value(expr) => expr.visit_expressions_mut(f),
+            Self::Aggregate(expr) => expr.visit_expressions_mut(f),
         }
     }
 
@@ -379,6 +384,7 @@ impl WindowExprType {
         match self {
             Self::Scalar(expr) => expr.typ(outers, inner, params),
             Self::Value(expr) => expr.typ(outers, inner, params),
+            Self::Aggregate(expr) => expr.typ(outers, inner, params),
         }
     }
 }
@@ -391,6 +397,7 @@ impl VisitChildren<HirScalarExpr> for WindowExprType {
         match self {
             Self::Scalar(_) => (),
             Self::Value(expr) => expr.visit_children(f),
+            Self::Aggregate(expr) => expr.visit_children(f),
         }
     }
 
@@ -401,6 +408,7 @@ impl VisitChildren<HirScalarExpr> for WindowExprType {
         match self {
             Self::Scalar(_) => (),
             Self::Value(expr) => expr.visit_mut_children(f),
+            Self::Aggregate(expr) => expr.visit_mut_children(f),
         }
     }
 
@@ -412,6 +420,7 @@ impl VisitChildren<HirScalarExpr> for WindowExprType {
         match self {
             Self::Scalar(_) => Ok(()),
             Self::Value(expr) => expr.try_visit_children(f),
+            Self::Aggregate(expr) => expr.try_visit_children(f),
         }
     }
 
@@ -423,6 +432,7 @@ impl VisitChildren<HirScalarExpr> for WindowExprType {
         match self {
             Self::Scalar(_) => Ok(()),
             Self::Value(expr) => expr.try_visit_mut_children(f),
+            Self::Aggregate(expr) => expr.try_visit_mut_children(f),
         }
     }
 }
@@ -609,6 +619,80 @@ impl ValueWindowFunc {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AggregateWindowExpr {
+    pub aggregate_expr: AggregateExpr,
+    pub order_by: Vec<ColumnOrder>,
+    pub window_frame: WindowFrame,
+}
+
+impl AggregateWindowExpr {
+    #[deprecated = "Use `VisitChildren<HirScalarExpr>::visit_children` instead."]
+    pub fn visit_expressions<'a, F, E>(&'a self, f: &mut F) -> Result<(), E>
+    where
+        F: FnMut(&'a HirScalarExpr) -> Result<(), E>,
+    {
+        f(&self.aggregate_expr.expr)
+    }
+
+    #[deprecated = "Use `VisitChildren<HirScalarExpr>::visit_mut_children` instead."]
+    pub fn visit_expressions_mut<'a, F, E>(&'a mut self, f: &mut F) -> Result<(), E>
+    where
+        F: FnMut(&'a mut HirScalarExpr) -> Result<(), E>,
+    {
+        f(&mut self.aggregate_expr.expr)
+    }
+
+    fn typ(
+        &self,
+        outers: &[RelationType],
+        inner: &RelationType,
+        params: &BTreeMap<usize, ScalarType>,
+    ) -> ColumnType {
+        self.aggregate_expr.typ(outers, inner, params)
+    }
+
+    pub fn into_expr(self) -> mz_expr::AggregateFunc {
+        mz_expr::AggregateFunc::WindowAggregate {
+            wrapped_aggregate: Box::new(self.aggregate_expr.func.into_expr()),
+            order_by: self.order_by,
+            window_frame: self.window_frame,
+        }
+    }
+}
+
+impl VisitChildren<HirScalarExpr> for AggregateWindowExpr {
+    fn visit_children<F>(&self, mut f: F)
+    where
+        F: FnMut(&HirScalarExpr),
+    {
+        f(&self.aggregate_expr.expr)
+    }
+
+    fn visit_mut_children<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut HirScalarExpr),
+    {
+        f(&mut self.aggregate_expr.expr)
+    }
+
+    fn try_visit_children<F, E>(&self, mut f: F) -> Result<(), E>
+    where
+        F: FnMut(&HirScalarExpr) -> Result<(), E>,
+        E: From<RecursionLimitError>,
+    {
+        f(&self.aggregate_expr.expr)
+    }
+
+    fn try_visit_mut_children<F, E>(&mut self, mut f: F) -> Result<(), E>
+    where
+        F: FnMut(&mut HirScalarExpr) -> Result<(), E>,
+        E: From<RecursionLimitError>,
+    {
+        f(&mut self.aggregate_expr.expr)
+    }
+}
+
 /// A `CoercibleScalarExpr` is a [`HirScalarExpr`] whose type is not fully
 /// determined. Several SQL expressions can be freely coerced based upon where
 /// in the expression tree they appear. For example, the string literal '42'
@@ -1341,7 +1425,11 @@ impl HirRelationExpr {
                 f(value, depth)?;
                 f(body, depth)?;
             }
-            HirRelationExpr::LetRec { bindings, body } => {
+            HirRelationExpr::LetRec {
+                bindings,
+                max_iters: _,
+                body,
+            } => {
                 for (_, _, value, _) in bindings.iter() {
                     f(value, depth)?;
                 }
@@ -1424,7 +1512,11 @@ impl HirRelationExpr {
                 f(value, depth)?;
                 f(body, depth)?;
             }
-            HirRelationExpr::LetRec { bindings, body } => {
+            HirRelationExpr::LetRec {
+                bindings,
+                max_iters: _,
+                body,
+            } => {
                 for (_, _, value, _) in bindings.iter_mut() {
                     f(value, depth)?;
                 }
@@ -1683,7 +1775,11 @@ impl VisitChildren<Self> for HirRelationExpr {
                 f(value);
                 f(body);
             }
-            LetRec { bindings, body } => {
+            LetRec {
+                bindings,
+                max_iters: _,
+                body,
+            } => {
                 for (_, _, value, _) in bindings.iter() {
                     f(value);
                 }
@@ -1765,7 +1861,11 @@ impl VisitChildren<Self> for HirRelationExpr {
                 f(value);
                 f(body);
             }
-            LetRec { bindings, body } => {
+            LetRec {
+                bindings,
+                max_iters: _,
+                body,
+            } => {
                 for (_, _, value, _) in bindings.iter_mut() {
                     f(value);
                 }
@@ -1847,7 +1947,11 @@ impl VisitChildren<Self> for HirRelationExpr {
                 f(value)?;
                 f(body)?;
             }
-            LetRec { bindings, body } => {
+            LetRec {
+                bindings,
+                max_iters: _,
+                body,
+            } => {
                 for (_, _, value, _) in bindings.iter() {
                     f(value)?;
                 }
@@ -1930,7 +2034,11 @@ impl VisitChildren<Self> for HirRelationExpr {
                 f(value)?;
                 f(body)?;
             }
-            LetRec { bindings, body } => {
+            LetRec {
+                bindings,
+                max_iters: _,
+                body,
+            } => {
                 for (_, _, value, _) in bindings.iter_mut() {
                     f(value)?;
                 }
@@ -2004,6 +2112,7 @@ impl VisitChildren<HirScalarExpr> for HirRelationExpr {
             }
             | LetRec {
                 bindings: _,
+                max_iters: _,
                 body: _,
             }
             | Project {
@@ -2074,6 +2183,7 @@ impl VisitChildren<HirScalarExpr> for HirRelationExpr {
             }
             | LetRec {
                 bindings: _,
+                max_iters: _,
                 body: _,
             }
             | Project {
@@ -2145,6 +2255,7 @@ impl VisitChildren<HirScalarExpr> for HirRelationExpr {
             }
             | LetRec {
                 bindings: _,
+                max_iters: _,
                 body: _,
             }
             | Project {
@@ -2217,6 +2328,7 @@ impl VisitChildren<HirScalarExpr> for HirRelationExpr {
             }
             | LetRec {
                 bindings: _,
+                max_iters: _,
                 body: _,
             }
             | Project {
@@ -2690,7 +2802,11 @@ impl HirScalarExpr {
         f(depth, self)
     }
 
-    fn simplify_to_literal(self) -> Option<Row> {
+    /// Attempts to simplify this expression to a literal.
+    ///
+    /// Returns `None` if this expression cannot be simplified, e.g. because it
+    /// contains non-literal values or evaluates to an error.
+    pub fn simplify_to_literal(self) -> Option<Row> {
         let mut expr = self.lower_uncorrelated().ok()?;
         expr.reduce(&[]);
         match expr {
@@ -298,7 +298,11 @@ impl HirRelationExpr {
                         body
                     })
                 }
-                LetRec { bindings, body } => {
+                LetRec {
+                    bindings,
+                    max_iters,
+                    body,
+                } => {
                     // We use the outer type with the HIR types to form MIR CTE types.
                     let outer_column_types = get_outer.typ().column_types;
 
@@ -349,6 +353,7 @@ impl HirRelationExpr {
                     mz_expr::MirRelationExpr::LetRec {
                         ids: mir_ids,
                         values: mir_values,
+                        max_iters,
                         body: Box::new(mir_body),
                     }
                 }
@@ -1104,8 +1109,17 @@ impl HirScalarExpr {
                                     });
                             SS::Column(inner.arity() - 1)
                         }
-                        WindowExprType::Value(func) => {
-                            let hir_scalar_input = func.expr.clone();
+                        WindowExprType::Value(_) | WindowExprType::Aggregate(_) => {
+                            // Value and aggregate window functions share the encoding of their input
+                            let (hir_scalar_input, func) = match expr.func {
+                                WindowExprType::Value(func) => {
+                                    (func.expr.clone(), func.into_expr())
+                                }
+                                WindowExprType::Aggregate(func) => {
+                                    (func.aggregate_expr.expr.clone(), func.into_expr())
+                                }
+                                WindowExprType::Scalar(_) => unreachable!(),
+                            };
                             *inner =
                                 inner
                                     .take_dangerous()
@@ -1248,7 +1262,6 @@ impl HirScalarExpr {
                                             }
                                             .nullable(false);
 
-                                            let func = func.into_expr();
                                             let aggregate = mz_expr::AggregateExpr {
                                                 func,
                                                 expr: agg_input,
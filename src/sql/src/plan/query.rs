@@ -49,6 +49,8 @@ use mz_ore::collections::CollectionExt;
 use mz_ore::stack::{CheckedRecursion, RecursionGuard};
 use mz_ore::str::StrExt;
 use mz_repr::adt::char::CharLength;
+use mz_repr::adt::datetime::Timezone;
+use mz_repr::adt::interval::Interval;
 use mz_repr::adt::numeric::{NumericMaxScale, NUMERIC_DATUM_MAX_PRECISION};
 use mz_repr::adt::varchar::VarCharMaxLength;
 use mz_repr::{
@@ -56,14 +58,16 @@ use mz_repr::{
     ScalarType,
 };
 use mz_sql_parser::ast::display::AstDisplay;
+use mz_sql_parser::ast::visit::{self, Visit};
 use mz_sql_parser::ast::visit_mut::{self, VisitMut};
 use mz_sql_parser::ast::{
     AsOf, Assignment, AstInfo, CteBlock, DeleteStatement, Distinct, Expr, Function, FunctionArgs,
-    HomogenizingFunction, Ident, InsertSource, IsExprConstruct, Join, JoinConstraint, JoinOperator,
-    Limit, OrderByExpr, Query, Select, SelectItem, SelectOption, SelectOptionName, SetExpr,
-    SetOperator, ShowStatement, SubscriptPosition, TableAlias, TableFactor, TableFunction,
-    TableWithJoins, UnresolvedObjectName, UpdateStatement, Value, Values, WindowFrame,
-    WindowFrameBound, WindowFrameUnits, WindowSpec,
+    HomogenizingFunction, Ident, InsertSource, IntervalValue, IsExprConstruct, Join,
+    JoinConstraint, JoinOperator, Limit, MutRecBlock, MutRecBlockOption, MutRecBlockOptionName,
+    OrderByExpr, Query, Select, SelectItem, SelectOption, SelectOptionName, SetExpr, SetOperator,
+    ShowStatement, SubscriptPosition, TableAlias, TableFactor, TableFunction, TableWithJoins,
+    UnresolvedObjectName, UpdateStatement, Value, Values, WindowFrame, WindowFrameBound,
+    WindowFrameUnits, WindowSpec,
 };
 
 use crate::catalog::{CatalogItemType, CatalogType, SessionCatalog};
@@ -72,10 +76,10 @@ use crate::names::{Aug, PartialObjectName, ResolvedDataType, ResolvedObjectName}
 use crate::normalize;
 use crate::plan::error::PlanError;
 use crate::plan::expr::{
-    AbstractColumnType, AbstractExpr, AggregateExpr, AggregateFunc, BinaryFunc,
-    CoercibleScalarExpr, ColumnOrder, ColumnRef, Hir, HirRelationExpr, HirScalarExpr, JoinKind,
-    ScalarWindowExpr, ScalarWindowFunc, UnaryFunc, ValueWindowExpr, VariadicFunc, WindowExpr,
-    WindowExprType,
+    AbstractColumnType, AbstractExpr, AggregateExpr, AggregateFunc, AggregateWindowExpr,
+    BinaryFunc, CoercibleScalarExpr, ColumnOrder, ColumnRef, Hir, HirRelationExpr, HirScalarExpr,
+    JoinKind, ScalarWindowExpr, ScalarWindowFunc, UnaryFunc, ValueWindowExpr, VariadicFunc,
+    WindowExpr, WindowExprType,
 };
 use crate::plan::plan_utils::{self, JoinSide};
 use crate::plan::scope::{Scope, ScopeItem};
@@ -815,33 +819,46 @@ pub fn plan_as_of(
 ) -> Result<QueryWhen, PlanError> {
     match as_of {
         None => Ok(QueryWhen::Immediately),
-        Some(mut as_of) => match as_of {
-            AsOf::At(ref mut expr) | AsOf::AtLeast(ref mut expr) => {
-                let scope = Scope::empty();
-                let desc = RelationDesc::empty();
-                let qcx = QueryContext::root(scx, QueryLifetime::OneShot(scx.pcx()?));
-                transform_ast::transform_expr(scx, expr)?;
-                let ecx = &ExprContext {
-                    qcx: &qcx,
-                    name: "AS OF",
-                    scope: &scope,
-                    relation_type: desc.typ(),
-                    allow_aggregates: false,
-                    allow_subqueries: false,
-                    allow_windows: false,
-                };
-                let expr = plan_expr(ecx, expr)?
-                    .type_as_any(ecx)?
-                    .lower_uncorrelated()?;
-                match as_of {
-                    AsOf::At(_) => Ok(QueryWhen::AtTimestamp(expr)),
-                    AsOf::AtLeast(_) => Ok(QueryWhen::AtLeastTimestamp(expr)),
-                }
-            }
-        },
+        Some(AsOf::At(expr)) => Ok(QueryWhen::AtTimestamp(plan_timestamp_expr(
+            scx, expr, "AS OF",
+        )?)),
+        Some(AsOf::AtLeast(expr)) => Ok(QueryWhen::AtLeastTimestamp(plan_timestamp_expr(
+            scx, expr, "AS OF",
+        )?)),
     }
 }
 
+/// Plans the `UP TO` clause of a `SUBSCRIBE`.
+pub fn plan_up_to(scx: &StatementContext, up_to: Expr<Aug>) -> Result<MirScalarExpr, PlanError> {
+    plan_timestamp_expr(scx, up_to, "UP TO")
+}
+
+/// Plans an expression that evaluates to a timestamp, like that of an
+/// `AS OF`.
+fn plan_timestamp_expr(
+    scx: &StatementContext,
+    mut expr: Expr<Aug>,
+    name: &'static str,
+) -> Result<MirScalarExpr, PlanError> {
+    let scope = Scope::empty();
+    let desc = RelationDesc::empty();
+    let qcx = QueryContext::root(scx, QueryLifetime::OneShot(scx.pcx()?));
+    transform_ast::transform_expr(scx, &mut expr)?;
+    let ecx = &ExprContext {
+        qcx: &qcx,
+        name,
+        scope: &scope,
+        relation_type: desc.typ(),
+        allow_aggregates: false,
+        allow_subqueries: false,
+        allow_windows: false,
+    };
+    let expr = plan_expr(ecx, &expr)?
+        .type_as_any(ecx)?
+        .lower_uncorrelated()?;
+    Ok(expr)
+}
+
 /// Plans an expression in the AS position of a `CREATE SECRET`.
 pub fn plan_secret_as(
     scx: &StatementContext,
@@ -1079,7 +1096,24 @@ fn plan_query_inner(
                 }
             }
         }
-        CteBlock::MutuallyRecursive(_) => {
+        CteBlock::MutuallyRecursive(MutRecBlock { options, ctes: _ }) => {
+            let MutRecBlockOptionExtracted {
+                recursion_limit,
+                seen: _,
+            } = MutRecBlockOptionExtracted::try_from(options.clone())?;
+            if recursion_limit == Some(0) {
+                sql_bail!("RECURSION LIMIT must be greater than zero");
+            }
+
+            // A nested block would be rendered in the same iterative scope as
+            // this block, and would not iterate to completion for each of its
+            // iterations.
+            let mut counter = MutRecBlockCounter::default();
+            counter.visit_query(q);
+            if counter.blocks > 1 {
+                sql_bail!("WITH MUTUALLY RECURSIVE cannot be nested");
+            }
+
             let mut bindings = Vec::new();
             for (id, value, shadowed_val) in cte_bindings.into_iter() {
                 if let Some(cte) = qcx.ctes.remove(&id) {
@@ -1092,6 +1126,7 @@ fn plan_query_inner(
             if !bindings.is_empty() {
                 result = HirRelationExpr::LetRec {
                     bindings,
+                    max_iters: recursion_limit,
                     body: Box::new(result),
                 }
             }
@@ -1101,6 +1136,24 @@ fn plan_query_inner(
     Ok((result, scope, finishing))
 }
 
+generate_extracted_config!(MutRecBlockOption, (RecursionLimit, u64));
+
+/// Counts the `WITH MUTUALLY RECURSIVE` blocks in a query, including those in
+/// nested queries.
+#[derive(Default)]
+struct MutRecBlockCounter {
+    blocks: usize,
+}
+
+impl<'ast> Visit<'ast, Aug> for MutRecBlockCounter {
+    fn visit_cte_block(&mut self, block: &'ast CteBlock<Aug>) {
+        if let CteBlock::MutuallyRecursive(_) = block {
+            self.blocks += 1;
+        }
+        visit::visit_cte_block(self, block);
+    }
+}
+
 /// Creates plans for CTEs and introduces them to `qcx.ctes`.
 ///
 /// Returns for each identifier a planned `HirRelationExpr` value, and an optional
@@ -1148,7 +1201,7 @@ pub fn plan_ctes(
                 result.push((cte.id, val, shadowed));
             }
         }
-        CteBlock::MutuallyRecursive(ctes) => {
+        CteBlock::MutuallyRecursive(MutRecBlock { options: _, ctes }) => {
             qcx.scx.require_unsafe_mode("WITH MUTUALLY_RECURSIVE")?;
 
             // Insert column types into `qcx.ctes` first for recursive bindings.
@@ -4010,13 +4063,15 @@ fn plan_function_order_by(
     Ok((order_by_exprs, col_orders))
 }
 
+/// Plans an aggregate function call. Any `OVER` clause is the responsibility
+/// of the caller.
 fn plan_aggregate(
     ecx: &ExprContext,
     Function::<Aug> {
         name,
         args,
         filter,
-        over,
+        over: _,
         distinct,
     }: &Function<Aug>,
 ) -> Result<AggregateExpr, PlanError> {
@@ -4038,10 +4093,6 @@ fn plan_aggregate(
         _ => unreachable!("plan_aggregate called on non-aggregate function,"),
     };
 
-    if over.is_some() {
-        bail_unsupported!("aggregate window functions");
-    }
-
     let name = normalize::unresolved_object_name(name.clone())?;
 
     // We follow PostgreSQL's rule here for mapping `count(*)` into the
@@ -4236,6 +4287,7 @@ fn plan_function<'a>(
     let unresolved_name = normalize::unresolved_object_name(name.clone())?;
 
     let impls = match resolve_func(ecx, name, args)? {
+        Func::Aggregate(_) if over.is_some() => return plan_window_aggregate(ecx, f),
         Func::Aggregate(_) if ecx.allow_aggregates => {
             // should already have been caught by `scope.resolve_expr` in `plan_expr`
             sql_bail!(
@@ -4340,6 +4392,47 @@ fn plan_function<'a>(
     )
 }
 
+/// Plans an aggregate function call with an `OVER` clause, which evaluates the
+/// aggregate over the window frame of each row.
+fn plan_window_aggregate(ecx: &ExprContext, f: &Function<Aug>) -> Result<HirScalarExpr, PlanError> {
+    if !ecx.allow_windows {
+        sql_bail!("window functions are not allowed in {}", ecx.name);
+    }
+
+    if f.distinct {
+        bail_unsupported!("DISTINCT in window aggregates");
+    }
+
+    let window_spec = f
+        .over
+        .as_ref()
+        .expect("plan_window_aggregate called on function without OVER clause");
+    let mut partition = Vec::new();
+    for expr in &window_spec.partition_by {
+        partition.push(plan_expr(ecx, expr)?.type_as_any(ecx)?);
+    }
+
+    let (order_by, col_orders) = plan_function_order_by(ecx, &window_spec.order_by)?;
+
+    let window_frame = match window_spec.window_frame.as_ref() {
+        Some(frame) => plan_window_frame(frame)?,
+        None => mz_expr::WindowFrame::default(),
+    };
+    validate_range_frame_offsets(ecx, &window_frame, &order_by)?;
+
+    let aggregate_expr = plan_aggregate(ecx, f)?;
+
+    Ok(HirScalarExpr::Windowing(WindowExpr {
+        func: WindowExprType::Aggregate(AggregateWindowExpr {
+            aggregate_expr,
+            order_by: col_orders,
+            window_frame,
+        }),
+        partition,
+        order_by,
+    }))
+}
+
 /// Resolves the name to a set of function implementations.
 ///
 /// If the name does not specify a known built-in function, returns an error.
@@ -4477,24 +4570,7 @@ fn plan_literal<'a>(l: &'a Value) -> Result<CoercibleScalarExpr, PlanError> {
             false => (Datum::False, ScalarType::Bool),
             true => (Datum::True, ScalarType::Bool),
         },
-        Value::Interval(iv) => {
-            let leading_precision = parser_datetimefield_to_adt(iv.precision_high);
-            let mut i = strconv::parse_interval_w_disambiguator(
-                &iv.value,
-                match leading_precision {
-                    mz_repr::adt::datetime::DateTimeField::Hour
-                    | mz_repr::adt::datetime::DateTimeField::Minute => Some(leading_precision),
-                    _ => None,
-                },
-                parser_datetimefield_to_adt(iv.precision_low),
-            )?;
-            i.truncate_high_fields(parser_datetimefield_to_adt(iv.precision_high));
-            i.truncate_low_fields(
-                parser_datetimefield_to_adt(iv.precision_low),
-                iv.fsec_max_precision,
-            )?;
-            (Datum::Interval(i), ScalarType::Interval)
-        }
+        Value::Interval(iv) => (Datum::Interval(plan_interval(iv)?), ScalarType::Interval),
         Value::String(s) => return Ok(CoercibleScalarExpr::LiteralString(s.clone())),
         Value::Null => return Ok(CoercibleScalarExpr::LiteralNull),
     };
@@ -4502,6 +4578,25 @@ fn plan_literal<'a>(l: &'a Value) -> Result<CoercibleScalarExpr, PlanError> {
     Ok(expr.into())
 }
 
+fn plan_interval(iv: &IntervalValue) -> Result<Interval, PlanError> {
+    let leading_precision = parser_datetimefield_to_adt(iv.precision_high);
+    let mut i = strconv::parse_interval_w_disambiguator(
+        &iv.value,
+        match leading_precision {
+            mz_repr::adt::datetime::DateTimeField::Hour
+            | mz_repr::adt::datetime::DateTimeField::Minute => Some(leading_precision),
+            _ => None,
+        },
+        parser_datetimefield_to_adt(iv.precision_low),
+    )?;
+    i.truncate_high_fields(parser_datetimefield_to_adt(iv.precision_high));
+    i.truncate_low_fields(
+        parser_datetimefield_to_adt(iv.precision_low),
+        iv.fsec_max_precision,
+    )?;
+    Ok(i)
+}
+
 fn validate_window_function_plan<'a>(
     ecx: &ExprContext,
     Function {
@@ -4545,6 +4640,21 @@ fn validate_window_function_plan<'a>(
         Some(frame) => plan_window_frame(frame)?,
         None => mz_expr::WindowFrame::default(),
     };
+
+    // Only aggregate window functions support GROUPS, and RANGE outside of
+    // the default frame
+    match window_frame.units {
+        mz_expr::WindowFrameUnits::Rows => (),
+        mz_expr::WindowFrameUnits::Range => {
+            if window_frame.start_bound != mz_expr::WindowFrameBound::UnboundedPreceding
+                || window_frame.end_bound != mz_expr::WindowFrameBound::CurrentRow
+            {
+                bail_unsupported!("RANGE in non-default window frames")
+            }
+        }
+        mz_expr::WindowFrameUnits::Groups => bail_unsupported!("GROUPS in window frames"),
+    }
+
     let mut partition = Vec::new();
     for expr in &window_spec.partition_by {
         partition.push(plan_expr(ecx, expr)?.type_as_any(ecx)?);
@@ -4576,12 +4686,12 @@ fn plan_window_frame(
     }: &WindowFrame,
 ) -> Result<mz_expr::WindowFrame, PlanError> {
     use mz_expr::WindowFrameBound::*;
-    let units = window_frame_unit_ast_to_expr(units)?;
-    let start_bound = window_frame_bound_ast_to_expr(start_bound);
-    let end_bound = end_bound
-        .as_ref()
-        .map(window_frame_bound_ast_to_expr)
-        .unwrap_or(CurrentRow);
+    let units = window_frame_unit_ast_to_expr(units);
+    let start_bound = window_frame_bound_ast_to_expr(start_bound)?;
+    let end_bound = match end_bound {
+        Some(end_bound) => window_frame_bound_ast_to_expr(end_bound)?,
+        None => CurrentRow,
+    };
 
     // Validate bounds according to Postgres rules
     match (&start_bound, &end_bound) {
@@ -4594,21 +4704,34 @@ fn plan_window_frame(
             sql_bail!("frame end cannot be UNBOUNDED PRECEDING")
         }
         // Start bound should come before end bound in the list of bound definitions
-        (CurrentRow, OffsetPreceding(_)) => {
+        (CurrentRow, OffsetPreceding(_) | IntervalPreceding(_)) => {
             sql_bail!("frame starting from current row cannot have preceding rows")
         }
-        (OffsetFollowing(_), OffsetPreceding(_) | CurrentRow) => {
+        (
+            OffsetFollowing(_) | IntervalFollowing(_),
+            OffsetPreceding(_) | IntervalPreceding(_) | CurrentRow,
+        ) => {
             sql_bail!("frame starting from following row cannot have preceding rows")
         }
         // Other bounds are valid
         (_, _) => (),
     }
 
-    // RANGE is only supported in the default frame
-    if units == mz_expr::WindowFrameUnits::Range
-        && (start_bound != UnboundedPreceding || end_bound != CurrentRow)
+    // Interval offsets only make sense for RANGE
+    if units != mz_expr::WindowFrameUnits::Range
+        && [&start_bound, &end_bound]
+            .iter()
+            .any(|bound| matches!(bound, IntervalPreceding(_) | IntervalFollowing(_)))
     {
-        bail_unsupported!("RANGE in non-default window frames")
+        let units = match units {
+            mz_expr::WindowFrameUnits::Rows => "ROWS",
+            mz_expr::WindowFrameUnits::Groups => "GROUPS",
+            mz_expr::WindowFrameUnits::Range => unreachable!(),
+        };
+        sql_bail!(
+            "argument of {} must be type bigint, not type interval",
+            units
+        )
     }
 
     let frame = mz_expr::WindowFrame {
@@ -4619,18 +4742,70 @@ fn plan_window_frame(
     Ok(frame)
 }
 
-fn window_frame_unit_ast_to_expr(
-    unit: &WindowFrameUnits,
-) -> Result<mz_expr::WindowFrameUnits, PlanError> {
+/// Validates the offsets of a `RANGE` frame against the window's `ORDER BY`
+/// expressions, which need to be a single expression that the offsets can be
+/// added to and subtracted from.
+fn validate_range_frame_offsets(
+    ecx: &ExprContext,
+    window_frame: &mz_expr::WindowFrame,
+    order_by: &[HirScalarExpr],
+) -> Result<(), PlanError> {
+    use mz_expr::WindowFrameBound::*;
+    if window_frame.units != mz_expr::WindowFrameUnits::Range {
+        return Ok(());
+    }
+    let has_offset = |bound: &mz_expr::WindowFrameBound| {
+        !matches!(bound, UnboundedPreceding | CurrentRow | UnboundedFollowing)
+    };
+    let bounds = [&window_frame.start_bound, &window_frame.end_bound];
+    if !bounds.iter().any(|bound| has_offset(bound)) {
+        return Ok(());
+    }
+
+    if order_by.len() != 1 {
+        sql_bail!("RANGE with offset PRECEDING/FOLLOWING requires exactly one ORDER BY column");
+    }
+    let order_by_type = ecx.scalar_type(&order_by[0]);
+    for bound in bounds {
+        let supported = match bound {
+            OffsetPreceding(_) | OffsetFollowing(_) => matches!(
+                order_by_type,
+                ScalarType::Int16
+                    | ScalarType::Int32
+                    | ScalarType::Int64
+                    | ScalarType::UInt16
+                    | ScalarType::UInt32
+                    | ScalarType::UInt64
+                    | ScalarType::Numeric { .. }
+            ),
+            IntervalPreceding(_) | IntervalFollowing(_) => matches!(
+                order_by_type,
+                ScalarType::Timestamp | ScalarType::TimestampTz
+            ),
+            UnboundedPreceding | CurrentRow | UnboundedFollowing => true,
+        };
+        if !supported {
+            sql_bail!(
+                "RANGE with offset PRECEDING/FOLLOWING is not supported for column type {}",
+                ecx.humanize_scalar_type(&order_by_type)
+            );
+        }
+    }
+    Ok(())
+}
+
+fn window_frame_unit_ast_to_expr(unit: &WindowFrameUnits) -> mz_expr::WindowFrameUnits {
     match unit {
-        WindowFrameUnits::Rows => Ok(mz_expr::WindowFrameUnits::Rows),
-        WindowFrameUnits::Range => Ok(mz_expr::WindowFrameUnits::Range),
-        WindowFrameUnits::Groups => bail_unsupported!("GROUPS in window frames"),
+        WindowFrameUnits::Rows => mz_expr::WindowFrameUnits::Rows,
+        WindowFrameUnits::Range => mz_expr::WindowFrameUnits::Range,
+        WindowFrameUnits::Groups => mz_expr::WindowFrameUnits::Groups,
     }
 }
 
-fn window_frame_bound_ast_to_expr(bound: &WindowFrameBound) -> mz_expr::WindowFrameBound {
-    match bound {
+fn window_frame_bound_ast_to_expr(
+    bound: &WindowFrameBound,
+) -> Result<mz_expr::WindowFrameBound, PlanError> {
+    Ok(match bound {
         WindowFrameBound::CurrentRow => mz_expr::WindowFrameBound::CurrentRow,
         WindowFrameBound::Preceding(None) => mz_expr::WindowFrameBound::UnboundedPreceding,
         WindowFrameBound::Preceding(Some(offset)) => {
@@ -4640,7 +4815,21 @@ fn window_frame_bound_ast_to_expr(bound: &WindowFrameBound) -> mz_expr::WindowFr
         WindowFrameBound::Following(Some(offset)) => {
             mz_expr::WindowFrameBound::OffsetFollowing(*offset)
         }
+        WindowFrameBound::IntervalPreceding(interval) => {
+            mz_expr::WindowFrameBound::IntervalPreceding(plan_window_frame_interval(interval)?)
+        }
+        WindowFrameBound::IntervalFollowing(interval) => {
+            mz_expr::WindowFrameBound::IntervalFollowing(plan_window_frame_interval(interval)?)
+        }
+    })
+}
+
+fn plan_window_frame_interval(interval: &IntervalValue) -> Result<Interval, PlanError> {
+    let interval = plan_interval(interval)?;
+    if interval.is_negative() {
+        sql_bail!("invalid preceding or following size in window function");
     }
+    Ok(interval)
 }
 
 // Implement these as two identical enums without From/Into impls so that they
@@ -4801,6 +4990,11 @@ fn scalar_type_from_catalog(
                     value_type: Box::new(scalar_type_from_catalog(scx, *value_id, &[])?),
                     custom_id: Some(id),
                 }),
+                CatalogType::Range {
+                    element_reference: element_id,
+                } => Ok(ScalarType::Range {
+                    element_type: Box::new(scalar_type_from_catalog(scx, *element_id, &[])?),
+                }),
                 CatalogType::Record { fields } => {
                     let scalars: Vec<(ColumnName, ColumnType)> = fields
                         .iter()
@@ -4913,7 +5107,9 @@ impl<'a> VisitMut<'_, Aug> for AggregateTableFuncVisitor<'a> {
         };
 
         match item.func() {
-            Ok(Func::Aggregate { .. }) => {
+            // Aggregates with an OVER clause are window functions, which are
+            // planned after grouping.
+            Ok(Func::Aggregate { .. }) if func.over.is_none() => {
                 if self.within_aggregate {
                     self.err = Some(sql_err!("nested aggregate functions are not allowed",));
                     return;
@@ -5175,6 +5371,20 @@ impl<'a> ExprContext<'a> {
         self.qcx.scx.catalog
     }
 
+    /// Returns the time zone in which zone-dependent operations on
+    /// `timestamptz` values, like casts to and from `timestamp`, are
+    /// performed.
+    ///
+    /// One-shot queries use the session's `TimeZone`. Maintained queries
+    /// always use UTC, so that their results do not depend on the settings of
+    /// the session that happened to create them.
+    pub fn session_timezone(&self) -> Timezone {
+        match self.qcx.lifetime {
+            QueryLifetime::OneShot(_) => self.catalog().timezone(),
+            QueryLifetime::Static => Timezone::UTC,
+        }
+    }
+
     pub fn with_name(&self, name: &'a str) -> ExprContext<'a> {
         let mut ecx = self.clone();
         ecx.name = name;
@@ -112,9 +112,11 @@ pub fn describe(
         Statement::AlterConnection(stmt) => ddl::describe_alter_connection(&scx, stmt)?,
         Statement::AlterIndex(stmt) => ddl::describe_alter_index_options(&scx, stmt)?,
         Statement::AlterObjectRename(stmt) => ddl::describe_alter_object_rename(&scx, stmt)?,
+        Statement::AlterOwner(stmt) => ddl::describe_alter_owner(&scx, stmt)?,
         Statement::AlterSecret(stmt) => ddl::describe_alter_secret_options(&scx, stmt)?,
         Statement::AlterSink(stmt) => ddl::describe_alter_sink(&scx, stmt)?,
         Statement::AlterSource(stmt) => ddl::describe_alter_source(&scx, stmt)?,
+        Statement::AlterTableAddColumn(stmt) => ddl::describe_alter_table_add_column(&scx, stmt)?,
         Statement::AlterSystemSet(stmt) => ddl::describe_alter_system_set(&scx, stmt)?,
         Statement::AlterSystemReset(stmt) => ddl::describe_alter_system_reset(&scx, stmt)?,
         Statement::AlterSystemResetAll(stmt) => ddl::describe_alter_system_reset_all(&scx, stmt)?,
@@ -141,6 +143,8 @@ pub fn describe(
         Statement::DropObjects(stmt) => ddl::describe_drop_objects(&scx, stmt)?,
         Statement::DropRoles(stmt) => ddl::describe_drop_role(&scx, stmt)?,
         Statement::DropSchema(stmt) => ddl::describe_drop_schema(&scx, stmt)?,
+        Statement::GrantPrivileges(stmt) => ddl::describe_grant_privileges(&scx, stmt)?,
+        Statement::RevokePrivileges(stmt) => ddl::describe_revoke_privileges(&scx, stmt)?,
 
         // `SHOW` statements.
         Statement::Show(ShowStatement::ShowColumns(stmt)) => {
@@ -195,6 +199,8 @@ pub fn describe(
         Statement::Copy(stmt) => dml::describe_copy(&scx, stmt)?,
         Statement::Delete(stmt) => dml::describe_delete(&scx, stmt)?,
         Statement::Explain(stmt) => dml::describe_explain(&scx, stmt)?,
+        Statement::Analyze(stmt) => dml::describe_analyze(&scx, stmt)?,
+        Statement::ExplainAnalyze(stmt) => show::explain_analyze(&scx, stmt)?.describe()?,
         Statement::Insert(stmt) => dml::describe_insert(&scx, stmt)?,
         Statement::Select(stmt) => dml::describe_select(&scx, stmt)?,
         Statement::Subscribe(stmt) => dml::describe_subscribe(&scx, stmt)?,
@@ -250,9 +256,11 @@ pub fn plan(
         Statement::AlterConnection(stmt) => ddl::plan_alter_connection(scx, stmt),
         Statement::AlterIndex(stmt) => ddl::plan_alter_index_options(scx, stmt),
         Statement::AlterObjectRename(stmt) => ddl::plan_alter_object_rename(scx, stmt),
+        Statement::AlterOwner(stmt) => ddl::plan_alter_owner(scx, stmt),
         Statement::AlterSecret(stmt) => ddl::plan_alter_secret(scx, stmt),
         Statement::AlterSink(stmt) => ddl::plan_alter_sink(scx, stmt),
         Statement::AlterSource(stmt) => ddl::plan_alter_source(scx, stmt),
+        Statement::AlterTableAddColumn(stmt) => ddl::plan_alter_table_add_column(scx, stmt),
         Statement::AlterSystemSet(stmt) => ddl::plan_alter_system_set(scx, stmt),
         Statement::AlterSystemReset(stmt) => ddl::plan_alter_system_reset(scx, stmt),
         Statement::AlterSystemResetAll(stmt) => ddl::plan_alter_system_reset_all(scx, stmt),
@@ -279,11 +287,15 @@ pub fn plan(
         Statement::DropObjects(stmt) => ddl::plan_drop_objects(scx, stmt),
         Statement::DropRoles(stmt) => ddl::plan_drop_role(scx, stmt),
         Statement::DropSchema(stmt) => ddl::plan_drop_schema(scx, stmt),
+        Statement::GrantPrivileges(stmt) => ddl::plan_grant_privileges(scx, stmt),
+        Statement::RevokePrivileges(stmt) => ddl::plan_revoke_privileges(scx, stmt),
 
         // DML statements.
         Statement::Copy(stmt) => dml::plan_copy(scx, stmt),
         Statement::Delete(stmt) => dml::plan_delete(scx, stmt, params),
         Statement::Explain(stmt) => dml::plan_explain(scx, stmt, params),
+        Statement::Analyze(stmt) => dml::plan_analyze(scx, stmt),
+        Statement::ExplainAnalyze(stmt) => show::explain_analyze(scx, stmt)?.plan(),
         Statement::Insert(stmt) => dml::plan_insert(scx, stmt, params),
         Statement::Select(stmt) => dml::plan_select(scx, stmt, params, None),
         Statement::Subscribe(stmt) => dml::plan_subscribe(scx, stmt, None),
@@ -362,6 +374,10 @@ pub fn plan_copy_from(
     query::plan_copy_from_rows(pcx, catalog, id, columns, rows)
 }
 
+pub fn analyze_estimates(arity: usize, rows: &[mz_repr::Row]) -> (u64, Vec<u64>) {
+    dml::analyze_estimates(arity, rows)
+}
+
 /// Whether a SQL object type can be interpreted as matching the type of the given catalog item.
 /// For example, if `v` is a view, `DROP SOURCE v` should not work, since Source and View
 /// are non-matching types.
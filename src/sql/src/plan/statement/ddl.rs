@@ -27,13 +27,16 @@ use tracing::warn;
 
 use mz_expr::CollectionPlan;
 use mz_interchange::avro::AvroSchemaGenerator;
+use mz_interchange::protobuf;
 use mz_ore::collections::CollectionExt;
 use mz_ore::str::StrExt;
 use mz_proto::RustType;
 use mz_repr::adt::interval::Interval;
 use mz_repr::adt::system::Oid;
 use mz_repr::strconv;
-use mz_repr::{ColumnName, ColumnType, GlobalId, RelationDesc, RelationType, ScalarType};
+use mz_repr::{
+    ColumnName, ColumnType, Datum, GlobalId, RelationDesc, RelationType, Row, ScalarType,
+};
 use mz_sql_parser::ast::display::comma_separated;
 use mz_sql_parser::ast::{
     AlterSinkAction, AlterSinkStatement, AlterSourceAction, AlterSourceStatement,
@@ -46,30 +49,33 @@ use mz_storage_client::types::connections::aws::{
 };
 use mz_storage_client::types::connections::{
     AwsPrivatelink, AwsPrivatelinkConnection, Connection, CsrConnectionHttpAuth, KafkaConnection,
-    KafkaSecurity, KafkaTlsConfig, SaslConfig, SshTunnel, StringOrSecret, TlsIdentity, Tunnel,
+    KafkaSecurity, KafkaTlsConfig, MySqlSslMode, SaslConfig, SshTunnel, StringOrSecret,
+    TlsIdentity, Tunnel,
 };
 use mz_storage_client::types::sinks::{
     KafkaConsistencyConfig, KafkaSinkConnectionBuilder, KafkaSinkConnectionRetention,
-    KafkaSinkFormat, SinkEnvelope, StorageSinkConnectionBuilder,
+    KafkaSinkFormat, ProtobufSinkMessage, ProtobufSinkMessages, SinkEnvelope,
+    StorageSinkConnectionBuilder,
 };
 use mz_storage_client::types::sources::encoding::{
     included_column_desc, AvroEncoding, ColumnSpec, CsvEncoding, DataEncoding, DataEncodingInner,
-    ProtobufEncoding, RegexEncoding, SourceDataEncoding, SourceDataEncodingInner,
+    ParquetEncoding, ProtobufEncoding, RegexEncoding, SourceDataEncoding, SourceDataEncodingInner,
 };
 use mz_storage_client::types::sources::{
     GenericSourceConnection, IncludedColumnPos, KafkaSourceConnection, KeyEnvelope,
-    KinesisSourceConnection, LoadGenerator, LoadGeneratorSourceConnection,
-    PostgresSourceConnection, PostgresSourcePublicationDetails,
-    ProtoPostgresSourcePublicationDetails, S3SourceConnection, SourceDesc, SourceEnvelope,
-    TestScriptSourceConnection, Timeline, UnplannedSourceEnvelope, UpsertStyle,
+    KinesisSourceConnection, LoadGenerator, LoadGeneratorSourceConnection, MySqlSourceConnection,
+    MySqlSourceDetails, PostgresSourceConnection, PostgresSourcePublicationDetails,
+    ProtoMySqlSourceDetails, ProtoPostgresSourcePublicationDetails, S3SourceConnection, SourceDesc,
+    SourceEnvelope, TestScriptSourceConnection, Timeline, UnplannedSourceEnvelope, UpsertStyle,
 };
 
 use crate::ast::display::AstDisplay;
 use crate::ast::{
     AlterConnectionStatement, AlterIndexAction, AlterIndexStatement, AlterObjectRenameStatement,
-    AlterSecretStatement, AvroSchema, AvroSchemaOption, AvroSchemaOptionName, AwsConnectionOption,
-    AwsConnectionOptionName, AwsPrivatelinkConnectionOption, AwsPrivatelinkConnectionOptionName,
-    ClusterOption, ClusterOptionName, ColumnOption, Compression, CreateClusterReplicaStatement,
+    AlterOwnerStatement, AlterSecretStatement, AlterTableAddColumnStatement, AvroSchema,
+    AvroSchemaOption, AvroSchemaOptionName, AwsConnectionOption, AwsConnectionOptionName,
+    AwsPrivatelinkConnectionOption, AwsPrivatelinkConnectionOptionName, ClusterOption,
+    ClusterOptionName, ColumnOption, Compression, CreateClusterReplicaStatement,
     CreateClusterStatement, CreateConnection, CreateConnectionStatement, CreateDatabaseStatement,
     CreateIndexStatement, CreateMaterializedViewStatement, CreateReferencedSubsources,
     CreateRoleOption, CreateRoleStatement, CreateSchemaStatement, CreateSecretStatement,
@@ -80,20 +86,22 @@ use crate::ast::{
     CsrConnectionAvro, CsrConnectionOption, CsrConnectionOptionName, CsrConnectionProtobuf,
     CsrSeedProtobuf, CsvColumns, DbzMode, DropClusterReplicasStatement, DropClustersStatement,
     DropDatabaseStatement, DropObjectsStatement, DropRolesStatement, DropSchemaStatement, Envelope,
-    Expr, Format, Ident, IfExistsBehavior, IndexOption, IndexOptionName, KafkaBroker,
-    KafkaBrokerAwsPrivatelinkOption, KafkaBrokerAwsPrivatelinkOptionName, KafkaBrokerTunnel,
-    KafkaConfigOptionName, KafkaConnectionOption, KafkaConnectionOptionName, KeyConstraint,
-    LoadGeneratorOption, LoadGeneratorOptionName, ObjectType, PgConfigOption, PgConfigOptionName,
-    PostgresConnectionOption, PostgresConnectionOptionName, ProtobufSchema, QualifiedReplica,
-    ReplicaDefinition, ReplicaOption, ReplicaOptionName, SourceIncludeMetadata,
-    SourceIncludeMetadataType, SshConnectionOptionName, Statement, TableConstraint,
-    UnresolvedDatabaseName, Value, ViewDefinition,
+    Expr, Format, GrantPrivilegesStatement, Ident, IfExistsBehavior, IndexOption, IndexOptionName,
+    KafkaBroker, KafkaBrokerAwsPrivatelinkOption, KafkaBrokerAwsPrivatelinkOptionName,
+    KafkaBrokerTunnel, KafkaConfigOptionName, KafkaConnectionOption, KafkaConnectionOptionName,
+    KeyConstraint, LoadGeneratorOption, LoadGeneratorOptionName, MySqlConfigOption,
+    MySqlConfigOptionName, MySqlConnectionOption, MySqlConnectionOptionName, ObjectType,
+    PgConfigOption, PgConfigOptionName, PostgresConnectionOption, PostgresConnectionOptionName,
+    PrivilegeSpecification, ProtobufSchema, QualifiedReplica, ReplicaDefinition, ReplicaOption,
+    ReplicaOptionName, RevokePrivilegesStatement, SourceIncludeMetadata, SourceIncludeMetadataType,
+    SshConnectionOptionName, Statement, TableConstraint, UnresolvedDatabaseName,
+    UnresolvedSchemaName, Value, ViewDefinition, WebhookOption, WebhookOptionName,
 };
 use crate::catalog::{CatalogItem, CatalogItemType, CatalogType, CatalogTypeDetails};
 use crate::kafka_util::{self, KafkaConfigOptionExtracted, KafkaStartOffsetType};
 use crate::names::{
-    Aug, FullSchemaName, QualifiedObjectName, RawDatabaseSpecifier, ResolvedClusterName,
-    ResolvedDataType, ResolvedDatabaseSpecifier, ResolvedObjectName, SchemaSpecifier,
+    Aug, FullSchemaName, ObjectId, QualifiedObjectName, RawDatabaseSpecifier, ResolvedClusterName,
+    ResolvedDataType, ResolvedDatabaseSpecifier, ResolvedObjectName, RoleId, SchemaSpecifier,
 };
 use crate::normalize::{self, ident};
 use crate::plan::error::PlanError;
@@ -105,16 +113,19 @@ use crate::plan::typeconv::{plan_cast, CastContext};
 use crate::plan::with_options::{self, OptionalInterval, TryFromValue};
 use crate::plan::{
     plan_utils, query, AlterIndexResetOptionsPlan, AlterIndexSetOptionsPlan, AlterItemRenamePlan,
-    AlterNoopPlan, AlterOptionParameter, AlterSecretPlan, AlterSinkPlan, AlterSourcePlan,
-    AlterSystemResetAllPlan, AlterSystemResetPlan, AlterSystemSetPlan, ComputeReplicaConfig,
-    ComputeReplicaIntrospectionConfig, CreateComputeInstancePlan, CreateComputeReplicaPlan,
-    CreateConnectionPlan, CreateDatabasePlan, CreateIndexPlan, CreateMaterializedViewPlan,
-    CreateRolePlan, CreateSchemaPlan, CreateSecretPlan, CreateSinkPlan, CreateSourcePlan,
-    CreateTablePlan, CreateTypePlan, CreateViewPlan, DropComputeInstancesPlan,
-    DropComputeReplicasPlan, DropDatabasePlan, DropItemsPlan, DropRolesPlan, DropSchemaPlan,
-    FullObjectName, HirScalarExpr, Index, Ingestion, MaterializedView, Params, Plan, QueryContext,
-    RotateKeysPlan, Secret, Sink, Source, StorageHostConfig, Table, Type, View,
+    AlterNoopPlan, AlterOptionParameter, AlterOwnerPlan, AlterSecretPlan, AlterSinkPlan,
+    AlterSourcePlan, AlterSystemResetAllPlan, AlterSystemResetPlan, AlterSystemSetPlan,
+    AlterTableAddColumnPlan, ComputeReplicaConfig, ComputeReplicaIntrospectionConfig,
+    CreateComputeInstancePlan, CreateComputeReplicaPlan, CreateConnectionPlan, CreateDatabasePlan,
+    CreateIndexPlan, CreateMaterializedViewPlan, CreateRolePlan, CreateSchemaPlan,
+    CreateSecretPlan, CreateSinkPlan, CreateSourcePlan, CreateTablePlan, CreateTypePlan,
+    CreateViewPlan, DataSourceDesc, DropComputeInstancesPlan, DropComputeReplicasPlan,
+    DropDatabasePlan, DropItemsPlan, DropRolesPlan, DropSchemaPlan, FullObjectName,
+    GrantPrivilegesPlan, HirScalarExpr, Index, Ingestion, MaterializedView, Params, Plan,
+    QueryContext, RevokePrivilegesPlan, RotateKeysPlan, Secret, Sink, Source, StorageHostConfig,
+    Table, Type, UpdatePrivilege, View, Webhook, WebhookValidation,
 };
+use crate::rbac::{self, AclMode, PUBLIC_ROLE_NAME};
 
 pub fn describe_create_database(
     _: &StatementContext,
@@ -207,6 +218,7 @@ pub fn plan_create_table(
     // and NOT NULL constraints.
     let mut column_types = Vec::with_capacity(columns.len());
     let mut defaults = Vec::with_capacity(columns.len());
+    let mut missing_values = Vec::with_capacity(columns.len());
     let mut keys = Vec::new();
 
     for (i, c) in columns.into_iter().enumerate() {
@@ -214,13 +226,15 @@ pub fn plan_create_table(
         let ty = query::scalar_type_from_sql(scx, aug_data_type)?;
         let mut nullable = true;
         let mut default = Expr::null();
+        let mut missing_value = None;
         for option in &c.options {
             match &option.option {
                 ColumnOption::NotNull => nullable = false,
                 ColumnOption::Default(expr) => {
                     // Ensure expression can be planned and yields the correct
                     // type.
-                    let _ = query::plan_default_expr(scx, expr, &ty)?;
+                    let expr_hir = query::plan_default_expr(scx, expr, &ty)?;
+                    missing_value = expr_hir.simplify_to_literal();
                     default = expr.clone();
                 }
                 ColumnOption::Unique { is_primary } => {
@@ -236,6 +250,7 @@ pub fn plan_create_table(
         }
         column_types.push(ty.nullable(nullable));
         defaults.push(default);
+        missing_values.push(missing_value);
     }
 
     for constraint in constraints {
@@ -288,12 +303,17 @@ pub fn plan_create_table(
         scx.allocate_qualified_name(normalize::unresolved_object_name(name.to_owned())?)?
     };
     let desc = RelationDesc::new(typ, names);
+    let missing_values = Row::pack(missing_values.iter().map(|value| match value {
+        Some(value) => value.unpack_first(),
+        None => Datum::Null,
+    }));
 
     let create_sql = normalize::create_statement(scx, Statement::CreateTable(stmt.clone()))?;
     let table = Table {
         create_sql,
         desc,
         defaults,
+        missing_values,
         temporary,
     };
     Ok(Plan::CreateTable(CreateTablePlan {
@@ -333,10 +353,26 @@ generate_extracted_config!(
     (TextColumns, Vec::<UnresolvedObjectName>, Default(vec![]))
 );
 
+generate_extracted_config!(MySqlConfigOption, (Details, String));
+
+generate_extracted_config!(
+    WebhookOption,
+    (Secret, with_options::Secret),
+    (SignatureHeader, String)
+);
+
+/// The header that carries request signatures when a webhook source is
+/// created with a `SECRET` but without a `SIGNATURE HEADER`.
+const DEFAULT_WEBHOOK_SIGNATURE_HEADER: &str = "x-signature";
+
 pub fn plan_create_source(
     scx: &StatementContext,
     stmt: CreateSourceStatement<Aug>,
 ) -> Result<Plan, PlanError> {
+    if let CreateSourceConnection::Webhook { .. } = &stmt.connection {
+        return plan_create_webhook_source(scx, stmt);
+    }
+
     let CreateSourceStatement {
         name,
         col_names,
@@ -568,6 +604,11 @@ pub fn plan_create_source(
             if matches!(encoding, SourceDataEncoding::KeyValue { .. }) {
                 sql_bail!("S3 sources do not support key decoding");
             }
+            if matches!(encoding.value_ref().inner, DataEncodingInner::Parquet(_))
+                && !matches!(compression, Compression::None)
+            {
+                sql_bail!("FORMAT PARQUET does not support COMPRESSION; Parquet objects are compressed internally");
+            }
             let connection = GenericSourceConnection::S3(S3SourceConnection {
                 connection_id: connection_item.id(),
                 key_sources: converted_sources,
@@ -795,6 +836,51 @@ pub fn plan_create_source(
             ));
             (connection, encoding, Some(available_subsources))
         }
+        CreateSourceConnection::MySql {
+            connection,
+            options,
+        } => {
+            let connection_item = scx.get_item_by_resolved_name(connection)?;
+            let connection = match connection_item.connection()? {
+                Connection::MySql(connection) => connection.clone(),
+                _ => sql_bail!("{} is not a mysql connection", connection_item.name()),
+            };
+            let MySqlConfigOptionExtracted { details, seen: _ } = options.clone().try_into()?;
+
+            let details = details
+                .as_ref()
+                .ok_or_else(|| sql_err!("internal error: MySQL source missing details"))?;
+            let details = hex::decode(details).map_err(|e| sql_err!("{}", e))?;
+            let details =
+                ProtoMySqlSourceDetails::decode(&*details).map_err(|e| sql_err!("{}", e))?;
+            let details = MySqlSourceDetails::from_proto(details).map_err(|e| sql_err!("{}", e))?;
+
+            // Register the available subsources. Purification only records
+            // the tables that the user selected, so every table maps to an
+            // output.
+            let mut available_subsources = BTreeMap::new();
+            for (i, table) in details.tables.iter().enumerate() {
+                let name = FullObjectName {
+                    database: RawDatabaseSpecifier::Ambient,
+                    schema: table.schema_name.clone(),
+                    item: table.name.clone(),
+                };
+                // The zero-th output is the main output
+                available_subsources.insert(name, i + 1);
+            }
+
+            let connection = GenericSourceConnection::MySql(MySqlSourceConnection {
+                connection,
+                connection_id: connection_item.id(),
+                details,
+            });
+            // Like the postgres source, the mysql source only outputs data to
+            // its subsources.
+            let encoding = SourceDataEncoding::Single(DataEncoding::new(
+                DataEncodingInner::RowCodec(RelationDesc::empty()),
+            ));
+            (connection, encoding, Some(available_subsources))
+        }
         CreateSourceConnection::LoadGenerator { generator, options } => {
             let (load_generator, available_subsources) =
                 load_generator_ast_to_generator(generator, options)?;
@@ -829,6 +915,9 @@ pub fn plan_create_source(
             let encoding = get_encoding(scx, format, &envelope, None)?;
             (connection, encoding, None)
         }
+        CreateSourceConnection::Webhook { .. } => {
+            unreachable!("webhook sources are planned by plan_create_webhook_source")
+        }
     };
 
     let (available_subsources, requested_subsources) = match (available_subsources, subsources) {
@@ -1062,7 +1151,7 @@ pub fn plan_create_source(
 
     let source = Source {
         create_sql,
-        ingestion: Some(Ingestion {
+        data_source: DataSourceDesc::Ingestion(Ingestion {
             desc: source_desc,
             // Currently no source reads from another source
             source_imports: HashSet::new(),
@@ -1080,6 +1169,134 @@ pub fn plan_create_source(
     }))
 }
 
+/// Plans a `CREATE SOURCE ... FROM WEBHOOK` statement.
+///
+/// Webhook sources are not backed by an ingestion. Their data is appended by
+/// the coordinator, as with tables, whenever `environmentd` receives a request
+/// on the source's HTTP endpoint.
+fn plan_create_webhook_source(
+    scx: &StatementContext,
+    stmt: CreateSourceStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    let CreateSourceStatement {
+        name,
+        col_names,
+        connection,
+        envelope,
+        if_not_exists,
+        format,
+        key_constraint,
+        include_metadata,
+        with_options,
+        subsources,
+    } = &stmt;
+
+    let options = match connection {
+        CreateSourceConnection::Webhook { options } => options,
+        _ => unreachable!("caller ensures connection is a webhook"),
+    };
+
+    if !matches!(envelope, None | Some(Envelope::None)) {
+        bail_unsupported!("ENVELOPE other than NONE with webhook sources");
+    }
+    if key_constraint.is_some() {
+        bail_unsupported!("PRIMARY KEY NOT ENFORCED with webhook sources");
+    }
+    if subsources.is_some() {
+        sql_bail!("webhook sources do not support FOR TABLES");
+    }
+    if !with_options.is_empty() {
+        bail_unsupported!("WITH options with webhook sources");
+    }
+
+    let body_encoding = match format {
+        CreateSourceFormat::Bare(format @ (Format::Bytes | Format::Text | Format::Json)) => {
+            match get_encoding_inner(scx, format)? {
+                SourceDataEncodingInner::Single(encoding) => encoding,
+                SourceDataEncodingInner::KeyValue { .. } => {
+                    unreachable!("BYTES, TEXT, and JSON are single-valued formats")
+                }
+            }
+        }
+        CreateSourceFormat::None => {
+            sql_bail!("webhook sources require FORMAT BYTES, FORMAT TEXT, or FORMAT JSON")
+        }
+        _ => bail_unsupported!("webhook sources with a FORMAT other than BYTES, TEXT, or JSON"),
+    };
+
+    let (_, mut desc) =
+        SourceDataEncoding::Single(DataEncoding::new(body_encoding.clone())).desc()?;
+
+    let mut include_headers = false;
+    for SourceIncludeMetadata { ty, alias } in include_metadata {
+        match ty {
+            SourceIncludeMetadataType::Headers if !include_headers => {
+                include_headers = true;
+                let name = alias
+                    .clone()
+                    .map(normalize::column_name)
+                    .unwrap_or_else(|| "headers".into());
+                let typ = ScalarType::Map {
+                    value_type: Box::new(ScalarType::String),
+                    custom_id: None,
+                };
+                desc = desc.with_column(name, typ.nullable(false));
+            }
+            SourceIncludeMetadataType::Headers => {
+                sql_bail!("INCLUDE HEADERS specified more than once")
+            }
+            ty => bail_unsupported!(format!("INCLUDE {} with webhook sources", ty)),
+        }
+    }
+
+    let WebhookOptionExtracted {
+        secret,
+        signature_header,
+        seen: _,
+    } = options.clone().try_into()?;
+    let validation = match (secret, signature_header) {
+        (Some(secret), header) => Some(WebhookValidation {
+            secret_id: secret.into(),
+            header: header
+                .unwrap_or_else(|| DEFAULT_WEBHOOK_SIGNATURE_HEADER.into())
+                .to_lowercase(),
+        }),
+        (None, Some(_)) => sql_bail!("SIGNATURE HEADER requires that SECRET be specified"),
+        (None, None) => None,
+    };
+
+    plan_utils::maybe_rename_columns(format!("source {}", name), &mut desc, col_names)?;
+
+    let names: Vec<_> = desc.iter_names().cloned().collect();
+    if let Some(dup) = names.iter().duplicates().next() {
+        sql_bail!("column {} specified more than once", dup.as_str().quoted());
+    }
+
+    let if_not_exists = *if_not_exists;
+    let name = scx.allocate_qualified_name(normalize::unresolved_object_name(name.clone())?)?;
+    let create_sql = normalize::create_statement(scx, Statement::CreateSource(stmt))?;
+
+    let source = Source {
+        create_sql,
+        data_source: DataSourceDesc::Webhook(Webhook {
+            body_encoding,
+            include_headers,
+            validation,
+        }),
+        desc,
+    };
+
+    Ok(Plan::CreateSource(CreateSourcePlan {
+        name,
+        source,
+        if_not_exists,
+        // The coordinator appends to webhook sources, so they move in
+        // lockstep with tables.
+        timeline: Timeline::EpochMilliseconds,
+        host_config: StorageHostConfig::Undefined,
+    }))
+}
+
 pub fn plan_create_subsource(
     scx: &StatementContext,
     stmt: CreateSubsourceStatement<Aug>,
@@ -1172,7 +1389,7 @@ pub fn plan_create_subsource(
 
     let source = Source {
         create_sql,
-        ingestion: None,
+        data_source: DataSourceDesc::Source,
         desc,
     };
 
@@ -1312,6 +1529,20 @@ fn get_encoding(
         && matches!(envelope, Envelope::None);
     let encoding = encoding.into_source_data_encoding(force_nullable_keys);
 
+    // Parquet objects are read whole, one row group at a time, and JSON is
+    // split on newlines, both of which only make sense for object stores.
+    if !matches!(connection, Some(CreateSourceConnection::S3 { .. })) {
+        for e in encoding.key_ref().into_iter().chain([encoding.value_ref()]) {
+            match e.inner {
+                DataEncodingInner::Parquet(_) => {
+                    sql_bail!("FORMAT PARQUET is only supported for S3 sources")
+                }
+                DataEncodingInner::Json => bail_unsupported!("FORMAT JSON for non-S3 sources"),
+                _ => (),
+            }
+        }
+    }
+
     let requires_keyvalue = matches!(
         envelope,
         Envelope::Debezium(DbzMode::Plain) | Envelope::Upsert
@@ -1511,7 +1742,10 @@ fn get_encoding_inner(
                     .map_err(|_| sql_err!("CSV delimiter must be an ASCII character"))?,
             })
         }
-        Format::Json => bail_unsupported!("JSON sources"),
+        Format::Json => DataEncodingInner::Json,
+        Format::Parquet(schema) => DataEncodingInner::Parquet(ParquetEncoding {
+            schema: schema.clone(),
+        }),
         Format::Text => DataEncodingInner::Text,
     }))
 }
@@ -1561,9 +1795,10 @@ fn get_unnamed_key_envelope(key: &DataEncoding) -> Result<KeyEnvelope, PlanError
         DataEncodingInner::RowCodec(_) => {
             sql_bail!("{} sources cannot use INCLUDE KEY", key.op_name())
         }
-        DataEncodingInner::Bytes | DataEncodingInner::Text => false,
+        DataEncodingInner::Bytes | DataEncodingInner::Text | DataEncodingInner::Json => false,
         DataEncodingInner::Avro(_)
         | DataEncodingInner::Csv(_)
+        | DataEncodingInner::Parquet(_)
         | DataEncodingInner::Protobuf(_)
         | DataEncodingInner::Regex { .. } => true,
     };
@@ -2083,6 +2318,88 @@ fn kafka_sink_builder(
             }
         }
         Some(Format::Json) => KafkaSinkFormat::Json,
+        Some(Format::Protobuf(schema)) => {
+            if matches!(envelope, SinkEnvelope::Debezium) {
+                sql_bail!("FORMAT PROTOBUF is not supported with ENVELOPE DEBEZIUM");
+            }
+
+            let (messages, csr_connection) = match schema {
+                ProtobufSchema::Csr {
+                    csr_connection:
+                        CsrConnectionProtobuf {
+                            connection:
+                                CsrConnection {
+                                    connection,
+                                    options,
+                                },
+                            seed,
+                        },
+                } => {
+                    let item = scx.get_item_by_resolved_name(&connection)?;
+                    let csr_connection = match item.connection()? {
+                        Connection::Csr(connection) => connection.clone(),
+                        _ => {
+                            sql_bail!("{} is not a schema registry connection", item.name())
+                        }
+                    };
+
+                    if !options.is_empty() {
+                        sql_bail!("Protobuf CSR connections do not support any options");
+                    }
+
+                    let CsrSeedProtobuf { key, value } =
+                        seed.expect("CSR seed resolution should already have been called: Proto");
+                    let key = key
+                        .map(|key| -> Result<_, PlanError> {
+                            Ok(ProtobufSinkMessage {
+                                descriptors: strconv::parse_bytes(&key.schema)?,
+                                message_name: key.message_name,
+                            })
+                        })
+                        .transpose()?;
+                    let value = ProtobufSinkMessage {
+                        descriptors: strconv::parse_bytes(&value.schema)?,
+                        message_name: value.message_name,
+                    };
+                    (ProtobufSinkMessages { key, value }, Some(csr_connection))
+                }
+                ProtobufSchema::InlineSchema {
+                    message_name,
+                    schema: mz_sql_parser::ast::Schema { schema },
+                } => {
+                    let value = ProtobufSinkMessage {
+                        descriptors: strconv::parse_bytes(&schema)?,
+                        message_name,
+                    };
+                    (ProtobufSinkMessages { key: None, value }, None)
+                }
+            };
+
+            // Reject relations that cannot be represented by the messages now,
+            // rather than when the sink encodes its first row.
+            if let Some((key_desc, _indices)) = &key_desc_and_indices {
+                let key_message = messages.key.as_ref().unwrap_or(&messages.value);
+                protobuf::MessageEncoder::new(
+                    &key_message.descriptors,
+                    &key_message.message_name,
+                    key_desc.clone(),
+                    None,
+                )
+                .map_err(|e| sql_err!("invalid protobuf key: {:#}", e))?;
+            }
+            protobuf::MessageEncoder::new(
+                &messages.value.descriptors,
+                &messages.value.message_name,
+                value_desc.clone(),
+                None,
+            )
+            .map_err(|e| sql_err!("invalid protobuf value: {:#}", e))?;
+
+            KafkaSinkFormat::Protobuf {
+                messages,
+                csr_connection,
+            }
+        }
         Some(format) => bail_unsupported!(format!("sink format {:?}", format)),
         None => bail_unsupported!("sink without format"),
     };
@@ -2417,11 +2734,13 @@ pub fn plan_create_role(
     if login != Some(true) {
         bail_unsupported!("non-login users");
     }
-    if super_user != Some(true) {
-        bail_unsupported!("non-superusers");
+    let name = normalize::ident(name);
+    if name == PUBLIC_ROLE_NAME {
+        sql_bail!("role name {} is reserved", name.quoted());
     }
     Ok(Plan::CreateRole(CreateRolePlan {
-        name: normalize::ident(name),
+        name,
+        superuser: super_user.unwrap_or(false),
     }))
 }
 
@@ -2929,6 +3248,52 @@ impl PostgresConnectionOptionExtracted {
     }
 }
 
+generate_extracted_config!(
+    MySqlConnectionOption,
+    (AwsPrivatelink, with_options::Object),
+    (Host, String),
+    (Password, with_options::Secret),
+    (Port, u16, Default(3306_u16)),
+    (SshTunnel, with_options::Object),
+    (SslMode, String),
+    (User, StringOrSecret)
+);
+
+impl MySqlConnectionOptionExtracted {
+    fn to_connection(
+        self,
+        scx: &StatementContext,
+    ) -> Result<mz_storage_client::types::connections::MySqlConnection, PlanError> {
+        let tls_mode = match self.ssl_mode.as_ref().map(|m| m.as_str()) {
+            None | Some("disabled") => MySqlSslMode::Disabled,
+            Some("required") => MySqlSslMode::Required,
+            Some("verify_ca") | Some("verify-ca") => MySqlSslMode::VerifyCa,
+            Some("verify_identity") | Some("verify-identity") => MySqlSslMode::VerifyIdentity,
+            Some(m) => sql_bail!("invalid CONNECTION: unknown SSL MODE {}", m.quoted()),
+        };
+
+        let tunnel = scx.build_tunnel_definition(self.ssh_tunnel, self.aws_privatelink)?;
+        if tls_mode == MySqlSslMode::VerifyIdentity && tunnel != Tunnel::Direct {
+            sql_bail!(
+                "invalid CONNECTION: SSL MODE verify_identity cannot be used with SSH TUNNEL or AWS PRIVATELINK"
+            );
+        }
+
+        Ok(mz_storage_client::types::connections::MySqlConnection {
+            host: self
+                .host
+                .ok_or_else(|| sql_err!("HOST option is required"))?,
+            port: self.port,
+            user: self
+                .user
+                .ok_or_else(|| sql_err!("USER option is required"))?,
+            password: self.password.map(|password| password.into()),
+            tunnel,
+            tls_mode,
+        })
+    }
+}
+
 generate_extracted_config!(
     SshConnectionOption,
     (Host, String),
@@ -3042,6 +3407,10 @@ pub fn plan_create_connection(
             let c = PostgresConnectionOptionExtracted::try_from(with_options)?;
             Connection::Postgres(c.to_connection(scx)?)
         }
+        CreateConnection::MySql { with_options } => {
+            let c = MySqlConnectionOptionExtracted::try_from(with_options)?;
+            Connection::MySql(c.to_connection(scx)?)
+        }
         CreateConnection::Aws { with_options } => {
             let c = AwsConnectionOptionExtracted::try_from(with_options)?;
             let connection = AwsConfig::try_from(c)?;
@@ -3140,7 +3509,11 @@ pub fn plan_drop_objects(
         | ObjectType::Type
         | ObjectType::Secret
         | ObjectType::Connection => plan_drop_items(scx, object_type, &items, cascade),
-        ObjectType::Role | ObjectType::Cluster | ObjectType::ClusterReplica => {
+        ObjectType::Role
+        | ObjectType::Cluster
+        | ObjectType::ClusterReplica
+        | ObjectType::Database
+        | ObjectType::Schema => {
             unreachable!("handled through their respective plan_drop functions")
         }
         ObjectType::Object => unreachable!("cannot drop generic OBJECT, must provide object type"),
@@ -3871,3 +4244,363 @@ pub fn plan_alter_connection(
     let id = entry.id();
     Ok(Plan::RotateKeys(RotateKeysPlan { id }))
 }
+
+pub fn describe_alter_owner(
+    _: &StatementContext,
+    _: AlterOwnerStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_alter_owner(
+    scx: &StatementContext,
+    AlterOwnerStatement {
+        object_type,
+        if_exists,
+        name,
+        new_owner,
+    }: AlterOwnerStatement,
+) -> Result<Plan, PlanError> {
+    let new_owner = scx.catalog.resolve_role(&normalize::ident(new_owner))?.id();
+    match resolve_object_for_privileges(scx, object_type, name, false, if_exists)? {
+        Some((id, object_type)) => Ok(Plan::AlterOwner(AlterOwnerPlan {
+            id,
+            object_type,
+            new_owner,
+        })),
+        // TODO(benesch/jkosh44): generate a notice indicating this object does
+        // not exist.
+        None => Ok(Plan::AlterNoop(AlterNoopPlan { object_type })),
+    }
+}
+
+pub fn describe_alter_table_add_column(
+    _: &StatementContext,
+    _: AlterTableAddColumnStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_alter_table_add_column(
+    scx: &StatementContext,
+    AlterTableAddColumnStatement {
+        if_exists,
+        name,
+        if_col_not_exists,
+        column,
+    }: AlterTableAddColumnStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    let name = normalize::unresolved_object_name(name)?;
+    let entry = match scx.catalog.resolve_item(&name) {
+        Ok(entry) => entry,
+        Err(_) if if_exists => {
+            // TODO(benesch/jkosh44): generate a notice indicating this table
+            // does not exist.
+            return Ok(Plan::AlterNoop(AlterNoopPlan {
+                object_type: ObjectType::Table,
+            }));
+        }
+        Err(e) => return Err(e.into()),
+    };
+    let full_name = scx.catalog.resolve_full_name(entry.name());
+    if entry.item_type() != CatalogItemType::Table {
+        sql_bail!("\"{}\" is a {} not a table", full_name, entry.item_type())
+    }
+    if entry.id().is_system() {
+        sql_bail!(
+            "cannot alter table {} because it is required by the database system",
+            full_name.to_string().quoted()
+        )
+    }
+
+    let column_name = normalize::column_name(column.name.clone());
+    if entry.desc(&full_name)?.get_by_name(&column_name).is_some() {
+        if if_col_not_exists {
+            // TODO(benesch/jkosh44): generate a notice indicating this column
+            // already exists.
+            return Ok(Plan::AlterNoop(AlterNoopPlan {
+                object_type: ObjectType::Table,
+            }));
+        }
+        sql_bail!(
+            "column {} of relation {} already exists",
+            column_name.as_str().quoted(),
+            full_name.to_string().quoted()
+        )
+    }
+
+    // Rows already in the table are not rewritten, so the new column must have
+    // a single value that is known ahead of time for all of them.
+    let ty = query::scalar_type_from_sql(scx, &column.data_type)?;
+    let mut nullable = true;
+    let mut missing_value = None;
+    for option in &column.options {
+        match &option.option {
+            ColumnOption::NotNull => nullable = false,
+            ColumnOption::Default(expr) => {
+                match query::plan_default_expr(scx, expr, &ty)?.simplify_to_literal() {
+                    Some(row) => missing_value = Some(row),
+                    None => sql_bail!(
+                        "cannot add column {} with a DEFAULT expression that is not a constant",
+                        column_name.as_str().quoted()
+                    ),
+                }
+            }
+            ColumnOption::Unique { .. } => {
+                bail_unsupported!(
+                    "ALTER TABLE ... ADD COLUMN with a primary key or unique constraint"
+                )
+            }
+            _ => {}
+        }
+    }
+    let missing_value_is_null = match &missing_value {
+        Some(row) => row.unpack_first().is_null(),
+        None => true,
+    };
+    if !nullable && missing_value_is_null {
+        sql_bail!(
+            "cannot add NOT NULL column {} without a non-null DEFAULT",
+            column_name.as_str().quoted()
+        )
+    }
+
+    // Tables are described by their creation statement, so the new definition
+    // of the table is its creation statement with the column appended.
+    let create_stmt = crate::parse::parse(entry.create_sql())?.into_element();
+    let mut create_stmt = match crate::names::resolve(scx.catalog, create_stmt)?.0 {
+        Statement::CreateTable(create_stmt) => create_stmt,
+        _ => sql_bail!(
+            "table {} was not created with a CREATE TABLE statement",
+            full_name.to_string().quoted()
+        ),
+    };
+    create_stmt.columns.push(column);
+    let table = match plan_create_table(scx, create_stmt)? {
+        Plan::CreateTable(CreateTablePlan { table, .. }) => table,
+        _ => unreachable!("plan_create_table returns a CreateTablePlan"),
+    };
+
+    Ok(Plan::AlterTableAddColumn(AlterTableAddColumnPlan {
+        id: entry.id(),
+        column_name,
+        table,
+    }))
+}
+
+pub fn describe_grant_privileges(
+    _: &StatementContext,
+    _: GrantPrivilegesStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_grant_privileges(
+    scx: &StatementContext,
+    GrantPrivilegesStatement {
+        privileges,
+        object_type,
+        names,
+        roles,
+    }: GrantPrivilegesStatement,
+) -> Result<Plan, PlanError> {
+    Ok(Plan::GrantPrivileges(GrantPrivilegesPlan {
+        update_privileges: plan_update_privileges(scx, privileges, object_type, names)?,
+        grantees: plan_privilege_roles(scx, roles)?,
+    }))
+}
+
+pub fn describe_revoke_privileges(
+    _: &StatementContext,
+    _: RevokePrivilegesStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_revoke_privileges(
+    scx: &StatementContext,
+    RevokePrivilegesStatement {
+        privileges,
+        object_type,
+        names,
+        roles,
+    }: RevokePrivilegesStatement,
+) -> Result<Plan, PlanError> {
+    Ok(Plan::RevokePrivileges(RevokePrivilegesPlan {
+        update_privileges: plan_update_privileges(scx, privileges, object_type, names)?,
+        revokees: plan_privilege_roles(scx, roles)?,
+    }))
+}
+
+fn plan_update_privileges(
+    scx: &StatementContext,
+    privileges: PrivilegeSpecification,
+    object_type: ObjectType,
+    names: Vec<UnresolvedObjectName>,
+) -> Result<Vec<UpdatePrivilege>, PlanError> {
+    let mut update_privileges = Vec::with_capacity(names.len());
+    for name in names {
+        let (target_id, object_type) =
+            resolve_object_for_privileges(scx, object_type, name, true, false)?
+                .expect("if_exists is false");
+        let mut all_privileges = AclMode::all_for(object_type);
+        // Webhook sources are written to by requests, which require INSERT.
+        if let ObjectId::Item(id) = target_id {
+            if scx.catalog.get_item(&id).is_webhook_source() {
+                all_privileges |= AclMode::INSERT;
+            }
+        }
+        let acl_mode = match &privileges {
+            PrivilegeSpecification::All => all_privileges,
+            PrivilegeSpecification::Privileges(privileges) => {
+                let mut acl_mode = AclMode::empty();
+                for privilege in privileges {
+                    let mode = AclMode::from(*privilege);
+                    if !all_privileges.contains(mode) {
+                        sql_bail!(
+                            "invalid privilege type {} for {}",
+                            privilege,
+                            object_type.to_string().to_lowercase()
+                        );
+                    }
+                    acl_mode |= mode;
+                }
+                acl_mode
+            }
+        };
+        update_privileges.push(UpdatePrivilege {
+            acl_mode,
+            target_id,
+        });
+    }
+    Ok(update_privileges)
+}
+
+/// Resolves the roles named in a `GRANT` or `REVOKE` statement. The name
+/// `PUBLIC` refers to the pseudo-role of which every role is a member.
+fn plan_privilege_roles(
+    scx: &StatementContext,
+    roles: Vec<Ident>,
+) -> Result<Vec<RoleId>, PlanError> {
+    roles
+        .into_iter()
+        .map(|role| {
+            let name = normalize::ident(role);
+            if name == PUBLIC_ROLE_NAME {
+                Ok(RoleId::Public)
+            } else {
+                Ok(scx.catalog.resolve_role(&name)?.id())
+            }
+        })
+        .collect()
+}
+
+/// Resolves the object of type `object_type` named `name`, returning its ID and
+/// its actual type. Returns `None` if the object does not exist and
+/// `if_exists` is set.
+///
+/// If `relations_as_tables` is set, then `object_type` of `TABLE` matches any
+/// relation, as it does for `GRANT` and `REVOKE`.
+fn resolve_object_for_privileges(
+    scx: &StatementContext,
+    object_type: ObjectType,
+    name: UnresolvedObjectName,
+    relations_as_tables: bool,
+    if_exists: bool,
+) -> Result<Option<(ObjectId, ObjectType)>, PlanError> {
+    let single_name = |name: UnresolvedObjectName| {
+        if name.0.len() == 1 {
+            Ok(name.0.into_element())
+        } else {
+            Err(sql_err!(
+                "invalid {} name {}",
+                object_type.to_string().to_lowercase(),
+                name.to_string().quoted()
+            ))
+        }
+    };
+    let resolved = match object_type {
+        ObjectType::Database => scx
+            .resolve_database(&UnresolvedDatabaseName(single_name(name)?))
+            .map(|database| ObjectId::Database(database.id())),
+        ObjectType::Schema => match scx.resolve_schema(UnresolvedSchemaName(name.0)) {
+            Ok(schema) if *schema.database() == ResolvedDatabaseSpecifier::Ambient => sql_bail!(
+                "cannot alter schema {} because it is required by the database system",
+                schema.name().schema.quoted()
+            ),
+            Ok(schema) => Ok(ObjectId::Schema((*schema.database(), *schema.id()))),
+            Err(e) => Err(e),
+        },
+        ObjectType::Cluster => {
+            let name = normalize::ident(single_name(name)?);
+            match scx.catalog.resolve_compute_instance(Some(name.as_str())) {
+                Ok(instance) if instance.id().is_system() => sql_bail!(
+                    "cannot alter cluster {} because it is required by the database system",
+                    name.quoted()
+                ),
+                Ok(instance) => Ok(ObjectId::Cluster(instance.id())),
+                Err(e) => Err(e.into()),
+            }
+        }
+        ObjectType::Table
+        | ObjectType::View
+        | ObjectType::MaterializedView
+        | ObjectType::Source
+        | ObjectType::Sink
+        | ObjectType::Index
+        | ObjectType::Type
+        | ObjectType::Secret
+        | ObjectType::Connection => {
+            let name = normalize::unresolved_object_name(name)?;
+            match scx.catalog.resolve_item(&name) {
+                Ok(item) => {
+                    let full_name = scx.catalog.resolve_full_name(item.name());
+                    let item_type = item.item_type();
+                    let is_relation = matches!(
+                        item_type,
+                        CatalogItemType::Table
+                            | CatalogItemType::Source
+                            | CatalogItemType::View
+                            | CatalogItemType::MaterializedView
+                    );
+                    if object_type == ObjectType::View
+                        && item_type == CatalogItemType::MaterializedView
+                    {
+                        return Err(PlanError::AlterViewOnMaterializedView(
+                            full_name.to_string(),
+                        ));
+                    } else if object_type != item_type
+                        && !(relations_as_tables && object_type == ObjectType::Table && is_relation)
+                    {
+                        sql_bail!(
+                            "\"{}\" is a {} not a {}",
+                            full_name,
+                            item_type,
+                            object_type.to_string().to_lowercase()
+                        )
+                    }
+                    if item.id().is_system() {
+                        sql_bail!(
+                            "cannot alter {} {} because it is required by the database system",
+                            item_type,
+                            full_name.to_string().quoted()
+                        )
+                    }
+                    return Ok(Some((
+                        ObjectId::Item(item.id()),
+                        rbac::item_object_type(item_type),
+                    )));
+                }
+                Err(e) => Err(e.into()),
+            }
+        }
+        ObjectType::Role | ObjectType::ClusterReplica | ObjectType::Object => {
+            unreachable!("{object_type} does not have an owner or privileges")
+        }
+    };
+    match resolved {
+        Ok(id) => Ok(Some((id, object_type))),
+        Err(_) if if_exists => Ok(None),
+        Err(e) => Err(e),
+    }
+}
@@ -10,7 +10,8 @@
 //! Data manipulation language (DML).
 //!
 //! This module houses the handlers for statements that manipulate data, like
-//! `INSERT`, `SELECT`, `SUBSCRIBE`, and `COPY`.
+//! `INSERT`, `SELECT`, `SUBSCRIBE`, and `COPY`. `ANALYZE`, which reads a
+//! relation to collect statistics about it, is planned here too.
 
 use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
@@ -20,24 +21,27 @@ use mz_ore::collections::CollectionExt;
 use mz_pgcopy::{CopyCsvFormatParams, CopyFormatParams, CopyTextFormatParams};
 use mz_repr::adt::numeric::NumericMaxScale;
 use mz_repr::explain_new::{ExplainConfig, ExplainFormat};
-use mz_repr::{RelationDesc, ScalarType};
+use mz_repr::{Datum, RelationDesc, Row, ScalarType};
 
 use crate::ast::display::AstDisplay;
 use crate::ast::{
-    AstInfo, CopyDirection, CopyOption, CopyOptionName, CopyRelation, CopyStatement, CopyTarget,
-    CreateMaterializedViewStatement, CreateViewStatement, DeleteStatement, ExplainStage,
-    ExplainStatement, Explainee, Ident, InsertStatement, Query, SelectStatement, Statement,
-    SubscribeOption, SubscribeOptionName, SubscribeRelation, SubscribeStatement, UpdateStatement,
-    ViewDefinition,
+    AnalyzeStatement, AstInfo, CopyDirection, CopyOption, CopyOptionName, CopyRelation,
+    CopyStatement, CopyTarget, CreateMaterializedViewStatement, CreateViewStatement,
+    DeleteStatement, ExplainStage, ExplainStatement, Explainee, Ident, InsertStatement, Query,
+    SelectStatement, Statement, SubscribeEnvelope, SubscribeOption, SubscribeOptionName,
+    SubscribeRelation, SubscribeStatement, UpdateStatement, ViewDefinition,
 };
 use crate::catalog::CatalogItemType;
 use crate::names::{self, Aug, ResolvedObjectName};
+use crate::normalize;
+use crate::parse;
 use crate::plan::query::QueryLifetime;
 use crate::plan::statement::{StatementContext, StatementDesc};
 use crate::plan::with_options::TryFromValue;
 use crate::plan::{
-    query, CopyFormat, CopyFromPlan, ExplainPlan, InsertPlan, MutationKind, Params, PeekPlan, Plan,
-    PlanError, QueryContext, ReadThenWritePlan, SubscribeFrom, SubscribePlan,
+    query, AnalyzePlan, CopyFormat, CopyFromPlan, ExplainPlan, InsertPlan, MutationKind, Params,
+    PeekPlan, Plan, PlanError, QueryContext, ReadThenWritePlan, SubscribeFrom, SubscribeOutput,
+    SubscribePlan,
 };
 
 // TODO(benesch): currently, describing a `SELECT` or `INSERT` query
@@ -171,8 +175,14 @@ pub fn plan_select(
     copy_to: Option<CopyFormat>,
 ) -> Result<Plan, PlanError> {
     let query::PlannedQuery {
-        expr, finishing, ..
+        expr,
+        desc,
+        finishing,
+        ..
     } = plan_query(scx, query, params, QueryLifetime::OneShot(scx.pcx()?))?;
+    if let Some(CopyFormat::Binary) = copy_to {
+        check_binary_copy_types(scx, desc.iter_types().map(|ty| &ty.scalar_type))?;
+    }
     let when = query::plan_as_of(scx, as_of)?;
     Ok(Plan::Peek(PeekPlan {
         source: expr,
@@ -376,6 +386,147 @@ pub fn plan_query(
     })
 }
 
+pub fn describe_analyze(
+    _: &StatementContext,
+    _: AnalyzeStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_analyze(
+    scx: &StatementContext,
+    AnalyzeStatement { name }: AnalyzeStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    let item = scx.get_item_by_resolved_name(&name)?;
+    match item.item_type() {
+        CatalogItemType::Table | CatalogItemType::Source | CatalogItemType::MaterializedView => {}
+        ty => sql_bail!("cannot analyze {} '{}'", ty, name.full_name_str()),
+    }
+    let full_name = scx.catalog.resolve_full_name(item.name());
+    let desc = item.desc(&full_name)?;
+
+    // Statistics are collected by a single query that counts the rows of the
+    // relation and sketches the distinct values of each of its columns with a
+    // HyperLogLog: the first bits of the hash of each non-null value pick one
+    // of the sketch's registers, which keeps the smallest of the next bits of
+    // the hashes it sees. Unlike an exact count of the distinct values, the
+    // query only needs to keep a bounded amount of state for each column.
+    let relation = normalize::unresolve(full_name).to_ast_string_stable();
+    let mut selections = vec![format!(
+        "SELECT NULL::pg_catalog.int4, NULL::pg_catalog.int4, pg_catalog.count(*) FROM {relation}"
+    )];
+    for (idx, (column_name, _)) in desc.iter().enumerate() {
+        let column = Ident::new(column_name.as_str()).to_ast_string_stable();
+        selections.push(format!(
+            "SELECT {idx}, register, pg_catalog.min(bits)::pg_catalog.int8
+            FROM (
+                SELECT
+                    pg_catalog.get_byte(h, 0) * 4 + pg_catalog.get_byte(h, 1) / 64 AS register,
+                    (pg_catalog.get_byte(h, 1) % 64) * 65536
+                        + pg_catalog.get_byte(h, 2) * 256
+                        + pg_catalog.get_byte(h, 3) AS bits
+                FROM (
+                    SELECT pg_catalog.digest({column}::pg_catalog.text, 'md5') AS h
+                    FROM {relation}
+                    WHERE {column} IS NOT NULL
+                ) AS hashes
+            ) AS registers
+            GROUP BY register"
+        ));
+    }
+    let query = selections.join(" UNION ALL ");
+    let stmt = match parse::parse(&query)
+        .expect("plan_analyze generated invalid SQL")
+        .into_element()
+    {
+        Statement::Select(select) => select,
+        _ => unreachable!("plan_analyze generated a non-SELECT statement"),
+    };
+    let (stmt, _) = names::resolve(scx.catalog, stmt)?;
+    let query::PlannedQuery {
+        expr: selection,
+        finishing,
+        ..
+    } = plan_query(
+        scx,
+        stmt.query,
+        &Params::empty(),
+        QueryLifetime::OneShot(scx.pcx()?),
+    )?;
+
+    Ok(Plan::Analyze(AnalyzePlan {
+        id: item.id(),
+        selection,
+        finishing,
+        arity: desc.arity(),
+    }))
+}
+
+/// The number of bits of a hash that pick its register in the sketches
+/// collected by `ANALYZE`.
+const ANALYZE_REGISTER_BITS: u32 = 10;
+
+/// The number of bits of a hash, following its register bits, that are kept
+/// by the sketches collected by `ANALYZE`.
+const ANALYZE_RANK_BITS: u32 = 22;
+
+/// Returns the number of rows of a relation with `arity` columns, and the
+/// estimated number of distinct values in each of its columns, from the rows
+/// produced by the selection of an [`AnalyzePlan`].
+pub fn analyze_estimates(arity: usize, rows: &[Row]) -> (u64, Vec<u64>) {
+    let mut row_count = 0;
+    let mut ranks = vec![vec![0; 1 << ANALYZE_REGISTER_BITS]; arity];
+    for row in rows {
+        match &row.unpack()[..] {
+            [Datum::Null, Datum::Null, Datum::Int64(count)] => {
+                row_count = u64::try_from(*count).expect("counts are non-negative");
+            }
+            [Datum::Int32(column), Datum::Int32(register), Datum::Int64(bits)] => {
+                let column = usize::try_from(*column).expect("columns are non-negative");
+                let register = usize::try_from(*register).expect("registers are non-negative");
+                let bits = u32::try_from(*bits).expect("bits fit in a u32");
+                // The rank of a register is the position of the first set bit
+                // among the bits it keeps.
+                let rank = bits.leading_zeros() - (u32::BITS - ANALYZE_RANK_BITS) + 1;
+                ranks[column][register] = rank;
+            }
+            datums => unreachable!("unexpected ANALYZE row: {:?}", datums),
+        }
+    }
+    let distinct_counts = ranks
+        .iter()
+        .map(|ranks| hyperloglog_estimate(ranks))
+        .collect();
+    (row_count, distinct_counts)
+}
+
+/// Estimates the number of distinct values seen by a HyperLogLog sketch with
+/// the given register ranks, as described in "HyperLogLog: the analysis of a
+/// near-optimal cardinality estimation algorithm" by Flajolet et al.
+fn hyperloglog_estimate(ranks: &[u32]) -> u64 {
+    let m = f64::from(u32::try_from(ranks.len()).expect("register count fits in a u32"));
+    let alpha = 0.7213 / (1.0 + 1.079 / m);
+    let sum: f64 = ranks.iter().map(|rank| (-f64::from(*rank)).exp2()).sum();
+    let mut estimate = alpha * m * m / sum;
+    let empty = ranks.iter().filter(|rank| **rank == 0).count();
+    let empty = f64::from(u32::try_from(empty).expect("register count fits in a u32"));
+    let hash_space = f64::from(ANALYZE_REGISTER_BITS + ANALYZE_RANK_BITS).exp2();
+    if estimate <= 2.5 * m && empty > 0.0 {
+        // Small cardinalities are better estimated by the number of registers
+        // that saw no values.
+        estimate = m * (m / empty).ln();
+    } else if estimate > hash_space / 30.0 {
+        // Large cardinalities must account for collisions between hashes.
+        estimate = -hash_space * (1.0 - estimate / hash_space).ln();
+    }
+    // The estimate is non-negative and far below `u64::MAX`, so the cast
+    // cannot truncate.
+    #[allow(clippy::as_conversions)]
+    {
+        estimate.round() as u64
+    }
+}
+
 generate_extracted_config!(SubscribeOption, (Snapshot, bool), (Progress, bool));
 
 pub fn describe_subscribe(
@@ -396,6 +547,7 @@ pub fn describe_subscribe(
     };
     let SubscribeOptionExtracted { progress, .. } = stmt.options.try_into()?;
     let progress = progress.unwrap_or(false);
+    let output = plan_subscribe_output(&relation_desc, stmt.envelope)?;
     let mut desc = RelationDesc::empty().with_column(
         "mz_timestamp",
         ScalarType::Numeric {
@@ -406,33 +558,110 @@ pub fn describe_subscribe(
     if progress {
         desc = desc.with_column("mz_progressed", ScalarType::Bool.nullable(false));
     }
-    desc = desc.with_column("mz_diff", ScalarType::Int64.nullable(true));
-    for (name, mut ty) in relation_desc.into_iter() {
+    let key_indices = match &output {
+        SubscribeOutput::Diffs => {
+            desc = desc.with_column("mz_diff", ScalarType::Int64.nullable(true));
+            for (name, mut ty) in relation_desc.into_iter() {
+                if progress {
+                    ty.nullable = true;
+                }
+                desc = desc.with_column(name, ty);
+            }
+            return Ok(StatementDesc::new(Some(desc)));
+        }
+        SubscribeOutput::EnvelopeUpsert { key_indices }
+        | SubscribeOutput::EnvelopeDebezium { key_indices } => key_indices,
+    };
+    // Updates under an envelope start with the state of their key, followed
+    // by the key itself. The values are null for deleted keys.
+    desc = desc.with_column("mz_state", ScalarType::String.nullable(true));
+    for idx in key_indices.iter() {
+        let (name, ty) = relation_desc.iter().nth(*idx).expect("valid key index");
+        let mut ty = ty.clone();
         if progress {
             ty.nullable = true;
         }
-        desc = desc.with_column(name, ty);
+        desc = desc.with_column(name.clone(), ty);
+    }
+    let values = relation_desc
+        .iter()
+        .enumerate()
+        .filter(|(idx, _)| !key_indices.contains(idx))
+        .map(|(_, (name, ty))| (name, ty.scalar_type.clone().nullable(true)))
+        .collect::<Vec<_>>();
+    match output {
+        SubscribeOutput::EnvelopeDebezium { .. } => {
+            for prefix in ["before", "after"] {
+                for (name, ty) in values.iter() {
+                    desc = desc.with_column(format!("{}_{}", prefix, name), ty.clone());
+                }
+            }
+        }
+        _ => {
+            for (name, ty) in values {
+                desc = desc.with_column(name.clone(), ty);
+            }
+        }
     }
     Ok(StatementDesc::new(Some(desc)))
 }
 
+/// Resolves the key columns of a `SUBSCRIBE` envelope against the columns of
+/// the relation being subscribed to.
+fn plan_subscribe_output(
+    desc: &RelationDesc,
+    envelope: Option<SubscribeEnvelope>,
+) -> Result<SubscribeOutput, PlanError> {
+    let (key_columns, make_output): (_, fn(Vec<usize>) -> SubscribeOutput) = match envelope {
+        None => return Ok(SubscribeOutput::Diffs),
+        Some(SubscribeEnvelope::Upsert { key_columns }) => (key_columns, |key_indices| {
+            SubscribeOutput::EnvelopeUpsert { key_indices }
+        }),
+        Some(SubscribeEnvelope::Debezium { key_columns }) => (key_columns, |key_indices| {
+            SubscribeOutput::EnvelopeDebezium { key_indices }
+        }),
+    };
+    let mut key_indices = Vec::with_capacity(key_columns.len());
+    for col in key_columns.into_iter().map(normalize::column_name) {
+        let idx = desc
+            .get_by_name(&col)
+            .map(|(idx, _type)| idx)
+            .ok_or_else(|| sql_err!("No such column in subscribe key: {}", col))?;
+        if desc.get_unambiguous_name(idx).is_none() {
+            sql_bail!("Ambiguous column in subscribe key: {}", col);
+        }
+        if key_indices.contains(&idx) {
+            sql_bail!("Repeated column name in subscribe key: {}", col);
+        }
+        key_indices.push(idx);
+    }
+    Ok(make_output(key_indices))
+}
+
 pub fn plan_subscribe(
     scx: &StatementContext,
     SubscribeStatement {
         relation,
+        envelope,
         options,
         as_of,
+        up_to,
     }: SubscribeStatement<Aug>,
     copy_to: Option<CopyFormat>,
 ) -> Result<Plan, PlanError> {
-    let from = match relation {
+    let (from, desc) = match relation {
         SubscribeRelation::Name(name) => {
             let entry = scx.get_item_by_resolved_name(&name)?;
             match entry.item_type() {
                 CatalogItemType::Table
                 | CatalogItemType::Source
                 | CatalogItemType::View
-                | CatalogItemType::MaterializedView => SubscribeFrom::Id(entry.id()),
+                | CatalogItemType::MaterializedView => {
+                    let desc = entry
+                        .desc(&scx.catalog.resolve_full_name(entry.name()))?
+                        .into_owned();
+                    (SubscribeFrom::Id(entry.id()), desc)
+                }
                 CatalogItemType::Func
                 | CatalogItemType::Index
                 | CatalogItemType::Sink
@@ -458,14 +687,23 @@ pub fn plan_subscribe(
                 QueryLifetime::OneShot(scx.pcx()?),
             )?;
             assert!(query.finishing.is_trivial(query.desc.arity()));
-            SubscribeFrom::Query {
+            let desc = query.desc.clone();
+            let from = SubscribeFrom::Query {
                 expr: query.expr,
                 desc: query.desc,
-            }
+            };
+            (from, desc)
         }
     };
+    if let Some(CopyFormat::Binary) = copy_to {
+        check_binary_copy_types(scx, desc.iter_types().map(|ty| &ty.scalar_type))?;
+    }
+    let output = plan_subscribe_output(&desc, envelope)?;
 
     let when = query::plan_as_of(scx, as_of)?;
+    let up_to = up_to
+        .map(|up_to| query::plan_up_to(scx, up_to))
+        .transpose()?;
     let SubscribeOptionExtracted {
         progress, snapshot, ..
     } = options.try_into()?;
@@ -475,6 +713,8 @@ pub fn plan_subscribe(
         with_snapshot: snapshot.unwrap_or(true),
         copy_to,
         emit_progress: progress.unwrap_or(false),
+        output,
+        up_to,
     }))
 }
 
@@ -563,10 +803,26 @@ fn plan_copy_from(
                 header,
             })
         }
-        CopyFormat::Binary => bail_unsupported!("FORMAT BINARY"),
+        CopyFormat::Binary => {
+            only_available_with_csv(options.quote, "quote")?;
+            only_available_with_csv(options.escape, "escape")?;
+            if options.delimiter.is_some() {
+                sql_bail!("cannot specify DELIMITER in BINARY mode");
+            }
+            if options.null.is_some() {
+                sql_bail!("cannot specify NULL in BINARY mode");
+            }
+            if options.header.is_some() {
+                sql_bail!("cannot specify HEADER in BINARY mode");
+            }
+            CopyFormatParams::Binary
+        }
     };
 
-    let (id, _, columns) = query::plan_copy_from(scx, table_name, columns)?;
+    let (id, desc, columns) = query::plan_copy_from(scx, table_name, columns)?;
+    if let CopyFormatParams::Binary = params {
+        check_binary_copy_types(scx, desc.iter_types().map(|ty| &ty.scalar_type))?;
+    }
     Ok(Plan::CopyFrom(CopyFromPlan {
         id,
         columns,
@@ -574,6 +830,39 @@ fn plan_copy_from(
     }))
 }
 
+/// Rejects column types that have no binary wire encoding, so that `COPY
+/// ... (FORMAT BINARY)` fails before any data is transferred rather than at
+/// the first row.
+///
+/// Lists and maps have no stable OIDs for their element types, and the
+/// binary format of `int2vector` is not implemented; see `mz_pgrepr::Value`.
+fn check_binary_copy_types<'a>(
+    scx: &StatementContext,
+    types: impl Iterator<Item = &'a ScalarType>,
+) -> Result<(), PlanError> {
+    /// Returns the first type nested in `ty` that has no binary encoding.
+    fn find_unsupported(ty: &ScalarType) -> Option<&ScalarType> {
+        match ty {
+            ScalarType::List { .. } | ScalarType::Map { .. } | ScalarType::Int2Vector => Some(ty),
+            ScalarType::Array(elem_type) => find_unsupported(elem_type),
+            ScalarType::Record { fields, .. } => fields
+                .iter()
+                .find_map(|(_, ty)| find_unsupported(&ty.scalar_type)),
+            _ => None,
+        }
+    }
+
+    for ty in types {
+        if let Some(ty) = find_unsupported(ty) {
+            bail_unsupported!(format!(
+                "COPY (FORMAT BINARY) of type {}",
+                scx.humanize_scalar_type(ty)
+            ));
+        }
+    }
+    Ok(())
+}
+
 generate_extracted_config!(
     CopyOption,
     (Format, String, Default("text")),
@@ -11,14 +11,18 @@
 //!
 //! This module houses the handlers for the `SHOW` suite of statements, like
 //! `SHOW CREATE TABLE` and `SHOW VIEWS`. Note that `SHOW <var>` is considered
-//! an SCL statement.
+//! an SCL statement. `EXPLAIN ANALYZE`, which reports on the live state of a
+//! dataflow, is planned here too, as it is similarly rewritten into a query
+//! against the system catalog.
 
 use std::fmt::Write;
 
 use mz_ore::collections::CollectionExt;
+use mz_ore::str::StrExt;
 use mz_repr::{Datum, RelationDesc, Row, ScalarType};
 use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::{
+    ExplainAnalyzeObject, ExplainAnalyzeProperty, ExplainAnalyzeStatement,
     ShowCreateConnectionStatement, ShowCreateMaterializedViewStatement, ShowObjectType,
 };
 use query::QueryContext;
@@ -578,6 +582,108 @@ pub fn show_secrets<'a>(
     ShowSelect::new(scx, query, filter, None, None)
 }
 
+pub fn explain_analyze<'a>(
+    scx: &'a StatementContext<'a>,
+    ExplainAnalyzeStatement {
+        property,
+        explainee,
+    }: ExplainAnalyzeStatement<Aug>,
+) -> Result<ShowSelect<'a>, PlanError> {
+    let (name, item) = match &explainee {
+        ExplainAnalyzeObject::Index(name) => {
+            let item = scx.get_item_by_resolved_name(name)?;
+            if item.item_type() != CatalogItemType::Index {
+                sql_bail!("{} is not an index", name.full_name_str());
+            }
+            (name, item)
+        }
+        ExplainAnalyzeObject::MaterializedView(name) => {
+            let item = scx.get_item_by_resolved_name(name)?;
+            if item.item_type() != CatalogItemType::MaterializedView {
+                sql_bail!("{} is not a materialized view", name.full_name_str());
+            }
+            (name, item)
+        }
+    };
+
+    // The introspection sources only describe the dataflows of the cluster
+    // the query runs on.
+    let cluster = scx.resolve_compute_instance(None)?;
+    if !cluster.exports().contains(&item.id()) {
+        sql_bail!(
+            "{} is not installed on the active cluster {}",
+            name.full_name_str(),
+            cluster.name().quoted()
+        );
+    }
+
+    // Operators are attributed to the innermost plan node whose range of
+    // operator IDs contains them, as the ranges of plan nodes include those
+    // of their inputs.
+    let query = format!(
+        "WITH
+            lir AS (
+                SELECT lir_id, worker_id, operator, nesting, operator_id_start, operator_id_end
+                FROM mz_internal.mz_lir_mapping
+                WHERE export_id = '{}'
+            ),
+            operators AS (
+                SELECT DISTINCT ON (o.id, o.worker_id) lir.lir_id, o.id, o.worker_id
+                FROM mz_internal.mz_dataflow_operators AS o
+                JOIN lir ON
+                    o.worker_id = lir.worker_id AND
+                    o.id >= lir.operator_id_start AND
+                    o.id < lir.operator_id_end
+                ORDER BY o.id, o.worker_id, lir.operator_id_start DESC
+            ),
+            memory AS (
+                SELECT
+                    operators.lir_id,
+                    pg_catalog.sum(sizes.records)::int8 AS records,
+                    pg_catalog.sum(sizes.batches)::int8 AS batches
+                FROM operators
+                JOIN mz_internal.mz_arrangement_sizes AS sizes ON
+                    operators.id = sizes.operator_id AND
+                    operators.worker_id = sizes.worker_id
+                GROUP BY operators.lir_id
+            ),
+            sharing AS (
+                SELECT operators.lir_id, pg_catalog.max(sharing.count) AS sharing
+                FROM operators
+                JOIN mz_internal.mz_arrangement_sharing AS sharing ON
+                    operators.id = sharing.operator_id AND
+                    operators.worker_id = sharing.worker_id
+                GROUP BY operators.lir_id
+            ),
+            cpu AS (
+                SELECT operators.lir_id, pg_catalog.sum(elapsed.elapsed_ns)::int8 AS elapsed_ns
+                FROM operators
+                JOIN mz_internal.mz_scheduling_elapsed AS elapsed ON
+                    operators.id = elapsed.id AND
+                    operators.worker_id = elapsed.worker_id
+                GROUP BY operators.lir_id
+            )
+        SELECT
+            nodes.lir_id,
+            pg_catalog.repeat('  ', nodes.nesting::int4) || nodes.operator AS operator,
+            memory.records,
+            memory.batches,
+            sharing.sharing,
+            cpu.elapsed_ns / 1000 * '1 microsecond'::interval AS elapsed
+        FROM (SELECT DISTINCT lir_id, nesting, operator FROM lir) AS nodes
+        LEFT JOIN memory ON nodes.lir_id = memory.lir_id
+        LEFT JOIN sharing ON nodes.lir_id = sharing.lir_id
+        LEFT JOIN cpu ON nodes.lir_id = cpu.lir_id",
+        item.id(),
+    );
+    let projection: &[&str] = match property {
+        Some(ExplainAnalyzeProperty::Memory) => &["operator", "records", "batches", "sharing"],
+        Some(ExplainAnalyzeProperty::Cpu) => &["operator", "elapsed"],
+        None => &["operator", "records", "batches", "sharing", "elapsed"],
+    };
+    ShowSelect::new(scx, query, None, Some("lir_id"), Some(projection))
+}
+
 /// An intermediate result when planning a `SHOW` query.
 ///
 /// Can be interrogated for its columns, or converted into a proper [`Plan`].
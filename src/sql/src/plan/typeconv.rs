@@ -354,7 +354,15 @@ static VALID_CASTS: Lazy<HashMap<(ScalarBaseType, ScalarBaseType), CastImpl>> =
 
         // DATE
         (Date, Timestamp) => Implicit: CastDateToTimestamp(func::CastDateToTimestamp),
-        (Date, TimestampTz) => Implicit: CastDateToTimestampTz(func::CastDateToTimestampTz),
+        (Date, TimestampTz) => Implicit: CastTemplate::new(|ecx, _ccx, _from_type, _to_type| {
+            let tz = ecx.session_timezone();
+            Some(move |e: HirScalarExpr| if tz.is_utc() {
+                e.call_unary(CastDateToTimestampTz(func::CastDateToTimestampTz))
+            } else {
+                e.call_unary(CastDateToTimestamp(func::CastDateToTimestamp))
+                    .call_unary(TimezoneTimestamp(func::TimezoneTimestamp(tz)))
+            })
+        }),
         (Date, String) => Assignment: CastDateToString(func::CastDateToString),
 
         // TIME
@@ -363,15 +371,52 @@ static VALID_CASTS: Lazy<HashMap<(ScalarBaseType, ScalarBaseType), CastImpl>> =
 
         // TIMESTAMP
         (Timestamp, Date) => Assignment: CastTimestampToDate(func::CastTimestampToDate),
-        (Timestamp, TimestampTz) => Implicit: CastTimestampToTimestampTz(func::CastTimestampToTimestampTz),
+        (Timestamp, TimestampTz) => Implicit: CastTemplate::new(|ecx, _ccx, _from_type, _to_type| {
+            let tz = ecx.session_timezone();
+            Some(move |e: HirScalarExpr| if tz.is_utc() {
+                e.call_unary(CastTimestampToTimestampTz(func::CastTimestampToTimestampTz))
+            } else {
+                e.call_unary(TimezoneTimestamp(func::TimezoneTimestamp(tz)))
+            })
+        }),
         (Timestamp, Time) => Assignment: CastTimestampToTime(func::CastTimestampToTime),
         (Timestamp, String) => Assignment: CastTimestampToString(func::CastTimestampToString),
 
         // TIMESTAMPTZ
-        (TimestampTz, Date) => Assignment: CastTimestampTzToDate(func::CastTimestampTzToDate),
-        (TimestampTz, Timestamp) => Assignment: CastTimestampTzToTimestamp(func::CastTimestampTzToTimestamp),
-        (TimestampTz, Time) => Assignment: CastTimestampTzToTime(func::CastTimestampTzToTime),
-        (TimestampTz, String) => Assignment: CastTimestampTzToString(func::CastTimestampTzToString),
+        (TimestampTz, Date) => Assignment: CastTemplate::new(|ecx, _ccx, _from_type, _to_type| {
+            let tz = ecx.session_timezone();
+            Some(move |e: HirScalarExpr| if tz.is_utc() {
+                e.call_unary(CastTimestampTzToDate(func::CastTimestampTzToDate))
+            } else {
+                e.call_unary(TimezoneTimestampTz(func::TimezoneTimestampTz(tz)))
+                    .call_unary(CastTimestampToDate(func::CastTimestampToDate))
+            })
+        }),
+        (TimestampTz, Timestamp) => Assignment: CastTemplate::new(|ecx, _ccx, _from_type, _to_type| {
+            let tz = ecx.session_timezone();
+            Some(move |e: HirScalarExpr| if tz.is_utc() {
+                e.call_unary(CastTimestampTzToTimestamp(func::CastTimestampTzToTimestamp))
+            } else {
+                e.call_unary(TimezoneTimestampTz(func::TimezoneTimestampTz(tz)))
+            })
+        }),
+        (TimestampTz, Time) => Assignment: CastTemplate::new(|ecx, _ccx, _from_type, _to_type| {
+            let tz = ecx.session_timezone();
+            Some(move |e: HirScalarExpr| if tz.is_utc() {
+                e.call_unary(CastTimestampTzToTime(func::CastTimestampTzToTime))
+            } else {
+                e.call_unary(TimezoneTimestampTz(func::TimezoneTimestampTz(tz)))
+                    .call_unary(CastTimestampToTime(func::CastTimestampToTime))
+            })
+        }),
+        (TimestampTz, String) => Assignment: CastTemplate::new(|ecx, _ccx, _from_type, _to_type| {
+            let tz = ecx.session_timezone();
+            Some(move |e: HirScalarExpr| if tz.is_utc() {
+                e.call_unary(CastTimestampTzToString(func::CastTimestampTzToString))
+            } else {
+                e.call_unary(CastTimestampTzToStringInZone(func::CastTimestampTzToStringInZone(tz)))
+            })
+        }),
 
         // INTERVAL
         (Interval, Time) => Assignment: CastIntervalToTime(func::CastIntervalToTime),
@@ -451,7 +496,14 @@ static VALID_CASTS: Lazy<HashMap<(ScalarBaseType, ScalarBaseType), CastImpl>> =
         (String, Date) => Explicit: CastStringToDate(func::CastStringToDate),
         (String, Time) => Explicit: CastStringToTime(func::CastStringToTime),
         (String, Timestamp) => Explicit: CastStringToTimestamp(func::CastStringToTimestamp),
-        (String, TimestampTz) => Explicit: CastStringToTimestampTz(func::CastStringToTimestampTz),
+        (String, TimestampTz) => Explicit: CastTemplate::new(|ecx, _ccx, _from_type, _to_type| {
+            let tz = ecx.session_timezone();
+            Some(move |e: HirScalarExpr| if tz.is_utc() {
+                e.call_unary(CastStringToTimestampTz(func::CastStringToTimestampTz))
+            } else {
+                e.call_unary(CastStringToTimestampTzInZone(func::CastStringToTimestampTzInZone(tz)))
+            })
+        }),
         (String, Interval) => Explicit: CastStringToInterval(func::CastStringToInterval),
         (String, Bytes) => Explicit: CastStringToBytes(func::CastStringToBytes),
         (String, Jsonb) => Explicit: CastStringToJsonb(func::CastStringToJsonb),
@@ -484,6 +536,15 @@ static VALID_CASTS: Lazy<HashMap<(ScalarBaseType, ScalarBaseType), CastImpl>> =
             })))
         }),
         (String, Int2Vector) => Explicit: CastStringToInt2Vector(func::CastStringToInt2Vector),
+        (String, Range) => Explicit: CastTemplate::new(|ecx, ccx, from_type, to_type| {
+            let return_ty = to_type.clone();
+            let to_el_type = to_type.unwrap_range_element_type();
+            let cast_expr = plan_hypothetical_cast(ecx, ccx, from_type, to_el_type)?;
+            Some(|e: HirScalarExpr| e.call_unary(UnaryFunc::CastStringToRange(func::CastStringToRange {
+                return_ty,
+                cast_expr: Box::new(cast_expr),
+            })))
+        }),
         (String, Char) => Implicit: CastTemplate::new(|_ecx, ccx, _from_type, to_type| {
             let length = to_type.unwrap_char_length();
             Some(move |e: HirScalarExpr| e.call_unary(CastStringToChar(func::CastStringToChar {length, fail_on_len: ccx != CastContext::Explicit})))
@@ -598,6 +659,12 @@ static VALID_CASTS: Lazy<HashMap<(ScalarBaseType, ScalarBaseType), CastImpl>> =
             Some(|e: HirScalarExpr| e.call_unary(CastMapToString(func::CastMapToString { ty })))
         }),
 
+        // RANGE
+        (Range, String) => Assignment: CastTemplate::new(|_ecx, _ccx, from_type, _to_type| {
+            let ty = from_type.clone();
+            Some(|e: HirScalarExpr| e.call_unary(CastRangeToString(func::CastRangeToString { ty })))
+        }),
+
         // JSONB
         (Jsonb, Bool) => Explicit: CastJsonbToBool(func::CastJsonbToBool),
         (Jsonb, Int16) => Explicit: CastJsonbToInt16(func::CastJsonbToInt16),
@@ -17,6 +17,7 @@ use std::path::Path;
 use std::sync::Arc;
 
 use anyhow::{anyhow, Context};
+use itertools::Itertools;
 use mz_repr::adt::system::Oid;
 use prost::Message;
 use protobuf_native::compiler::{SourceTreeDescriptorDatabase, VirtualSourceTree};
@@ -25,8 +26,9 @@ use tracing::info;
 use uuid::Uuid;
 
 use mz_ccsr::Schema as CcsrSchema;
-use mz_ccsr::{Client, GetByIdError, GetBySubjectError};
+use mz_ccsr::{Client, GetByIdError, GetBySubjectError, Subject};
 use mz_cloud_resources::AwsExternalIdPrefix;
+use mz_mysql_util::desc::MySqlTableDesc;
 use mz_ore::cast::CastFrom;
 use mz_ore::str::StrExt;
 use mz_proto::RustType;
@@ -36,17 +38,19 @@ use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::{
     ColumnDef, ColumnOption, ColumnOptionDef, CsrConnection, CsrSeedAvro, CsrSeedProtobuf,
     CsrSeedProtobufSchema, DbzMode, DeferredObjectName, Envelope, Ident, KafkaConfigOption,
-    KafkaConfigOptionName, KafkaConnection, KafkaSourceConnection, PgConfigOption,
-    PgConfigOptionName, ReaderSchemaSelectionStrategy, TableConstraint, UnresolvedObjectName,
+    KafkaConfigOptionName, KafkaConnection, KafkaSourceConnection, MySqlConfigOption,
+    MySqlConfigOptionName, PgConfigOption, PgConfigOptionName, ReaderSchemaSelectionStrategy,
+    TableConstraint, UnresolvedObjectName,
 };
 use mz_storage_client::types::connections::aws::AwsConfig;
 use mz_storage_client::types::connections::{Connection, ConnectionContext};
-use mz_storage_client::types::sources::PostgresSourcePublicationDetails;
+use mz_storage_client::types::sources::{MySqlSourceDetails, PostgresSourcePublicationDetails};
 
 use crate::ast::{
-    AvroSchema, CreateReferencedSubsources, CreateSourceConnection, CreateSourceFormat,
-    CreateSourceStatement, CreateSourceSubsource, CreateSubsourceStatement, CsrConnectionAvro,
-    CsrConnectionProtobuf, CsvColumns, Format, ProtobufSchema, Value, WithOptionValue,
+    AvroSchema, CreateReferencedSubsources, CreateSinkConnection, CreateSinkStatement,
+    CreateSourceConnection, CreateSourceFormat, CreateSourceStatement, CreateSourceSubsource,
+    CreateSubsourceStatement, CsrConnectionAvro, CsrConnectionProtobuf, CsvColumns, Format,
+    ProtobufSchema, Value, WithOptionValue,
 };
 use crate::catalog::{ErsatzCatalog, SessionCatalog};
 use crate::kafka_util;
@@ -57,10 +61,13 @@ use crate::plan::error::PlanError;
 use crate::plan::statement::ddl::load_generator_ast_to_generator;
 use crate::plan::StatementContext;
 
-fn subsource_gen<'a, T>(
+fn subsource_gen<'a, T, F>(
     selected_subsources: &mut Vec<CreateSourceSubsource<Aug>>,
-    catalog: &ErsatzCatalog<'a, T>,
-) -> Result<Vec<(UnresolvedObjectName, UnresolvedObjectName, &'a T)>, PlanError> {
+    resolve: F,
+) -> Result<Vec<(UnresolvedObjectName, UnresolvedObjectName, &'a T)>, PlanError>
+where
+    F: Fn(UnresolvedObjectName) -> Result<(UnresolvedObjectName, &'a T), PlanError>,
+{
     let mut validated_requested_subsources = vec![];
 
     for subsource in selected_subsources {
@@ -81,7 +88,7 @@ fn subsource_gen<'a, T>(
             }
         };
 
-        let (qualified_upstream_name, desc) = catalog.resolve(subsource.reference.clone())?;
+        let (qualified_upstream_name, desc) = resolve(subsource.reference.clone())?;
 
         validated_requested_subsources.push((qualified_upstream_name, subsource_name, desc));
     }
@@ -312,8 +319,9 @@ pub async fn purify_create_source(
                             .or_insert(table);
                     }
 
-                    validated_requested_subsources
-                        .extend(subsource_gen(subsources, &publication_catalog)?);
+                    validated_requested_subsources.extend(subsource_gen(subsources, |name| {
+                        publication_catalog.resolve(name)
+                    })?);
                 }
                 None => {}
             };
@@ -483,6 +491,196 @@ pub async fn purify_create_source(
                 )))),
             })
         }
+        CreateSourceConnection::MySql {
+            connection,
+            options,
+        } => {
+            let scx = StatementContext::new(None, &*catalog);
+            let connection = {
+                let item = scx.get_item_by_resolved_name(connection)?;
+                match item.connection()? {
+                    Connection::MySql(connection) => connection.clone(),
+                    _ => sql_bail!("{} is not a mysql connection", item.name()),
+                }
+            };
+
+            // verify that we can connect upstream, that the server's binary
+            // log is usable for replication, and snapshot the table schemas
+            let config = connection
+                .config(&*connection_context.secrets_reader)
+                .await?;
+            let fetch_err = |cause| PlanError::FetchingMySqlSchemaInfoFailed {
+                cause: Arc::new(cause),
+            };
+            let mut conn = config
+                .connect("mysql_purification")
+                .await
+                .map_err(fetch_err)?;
+            mz_mysql_util::validate_binlog_config(&mut conn)
+                .await
+                .map_err(|cause| PlanError::InvalidMySqlBinlogConfig {
+                    cause: Arc::new(cause),
+                })?;
+            let upstream_tables = mz_mysql_util::schema_info(&mut conn)
+                .await
+                .map_err(fetch_err)?;
+            conn.disconnect().await.map_err(fetch_err)?;
+
+            // An index from table name -> schema name -> MySqlTableDesc. MySQL
+            // has no level of naming above schemas (which it also calls
+            // databases), so references have the form `[schema.]table`.
+            let mut tables_by_name: HashMap<&str, HashMap<&str, &MySqlTableDesc>> = HashMap::new();
+            for table in &upstream_tables {
+                tables_by_name
+                    .entry(table.name.as_str())
+                    .or_default()
+                    .insert(table.schema_name.as_str(), table);
+            }
+            let resolve = |name: UnresolvedObjectName| -> Result<_, PlanError> {
+                let name = normalize::unresolved_object_name(name)?;
+                if name.database.is_some() {
+                    sql_bail!("invalid MySQL table reference {name}: expected [schema.]table");
+                }
+                let schemas = match tables_by_name.get(name.item.as_str()) {
+                    Some(schemas) => schemas,
+                    None => sql_bail!("table {name} not found in source"),
+                };
+                let (schema, table) = match &name.schema {
+                    Some(schema) => match schemas.get(schema.as_str()) {
+                        Some(table) => (schema.as_str(), *table),
+                        None => sql_bail!("table {name} not found in source"),
+                    },
+                    None => match schemas.iter().exactly_one() {
+                        Ok((schema, table)) => (*schema, *table),
+                        Err(_) => {
+                            sql_bail!("table {name} is ambiguous, consider specifying the schema")
+                        }
+                    },
+                };
+                Ok((
+                    UnresolvedObjectName::qualified(&[schema, &name.item]),
+                    table,
+                ))
+            };
+
+            let mut validated_requested_subsources = vec![];
+            match requested_subsources {
+                Some(CreateReferencedSubsources::All) => {
+                    for table in &upstream_tables {
+                        let upstream_name =
+                            UnresolvedObjectName::qualified(&[&table.schema_name, &table.name]);
+                        let subsource_name = UnresolvedObjectName::unqualified(&table.name);
+                        validated_requested_subsources.push((upstream_name, subsource_name, table));
+                    }
+                }
+                Some(CreateReferencedSubsources::Subset(subsources)) => {
+                    validated_requested_subsources.extend(subsource_gen(subsources, resolve)?);
+                }
+                None => {
+                    sql_bail!("multi-output sources require a FOR TABLES (..) or FOR ALL TABLES statement");
+                }
+            };
+
+            let mut targeted_subsources = vec![];
+            let mut selected_tables: Vec<MySqlTableDesc> = vec![];
+
+            // Aggregate all unsupported types.
+            let mut unsupported_cols = vec![];
+
+            // Now that we have an explicit list of validated requested subsources we can create them
+            for (i, (upstream_name, subsource_name, table)) in
+                validated_requested_subsources.into_iter().enumerate()
+            {
+                if selected_tables.contains(table) {
+                    return Err(PlanError::UnexpectedDuplicateReference {
+                        name: upstream_name,
+                    });
+                }
+
+                // Figure out the schema of the subsource
+                let mut columns = vec![];
+                for c in table.columns.iter() {
+                    let name = Ident::new(c.name.clone());
+                    let scalar_type = match c.scalar_type() {
+                        Some(scalar_type) => scalar_type,
+                        None => {
+                            let mut full_name = upstream_name.0.clone();
+                            full_name.push(name);
+                            unsupported_cols.push((
+                                UnresolvedObjectName(full_name).to_ast_string(),
+                                c.column_type.clone(),
+                            ));
+                            continue;
+                        }
+                    };
+                    let data_type = scx.resolve_type(mz_pgrepr::Type::from(&scalar_type))?;
+
+                    columns.push(ColumnDef {
+                        name,
+                        data_type,
+                        collation: None,
+                        options: vec![],
+                    });
+                }
+
+                // Create the targeted AST node for the original CREATE SOURCE statement
+                let transient_id = GlobalId::Transient(u64::cast_from(i));
+                let partial_subsource_name =
+                    normalize::unresolved_object_name(subsource_name.clone())?;
+                let qualified_subsource_name =
+                    scx.allocate_qualified_name(partial_subsource_name.clone())?;
+                let full_subsource_name = scx.allocate_full_name(partial_subsource_name)?;
+                targeted_subsources.push(CreateSourceSubsource {
+                    reference: upstream_name,
+                    subsource: Some(DeferredObjectName::Named(ResolvedObjectName::Object {
+                        id: transient_id,
+                        qualifiers: qualified_subsource_name.qualifiers,
+                        full_name: full_subsource_name,
+                        print_id: true,
+                    })),
+                });
+
+                // Create the subsource statement
+                let subsource = CreateSubsourceStatement {
+                    name: subsource_name,
+                    columns,
+                    // As with PostgreSQL sources, we deliberately do not mirror
+                    // upstream constraints, as we would not learn about them
+                    // being dropped through the binary log.
+                    constraints: vec![],
+                    if_not_exists: false,
+                };
+                subsources.push((transient_id, subsource));
+                selected_tables.push(table.clone());
+            }
+
+            if !unsupported_cols.is_empty() {
+                return Err(PlanError::UnsupportedTypeInMySqlSource {
+                    cols: unsupported_cols,
+                });
+            }
+
+            *requested_subsources = Some(CreateReferencedSubsources::Subset(targeted_subsources));
+
+            // The server ID identifies this source among the replicas of the
+            // upstream server, so it must be unique; pick one at random.
+            let uuid = Uuid::new_v4().into_bytes();
+            let server_id = u32::from_le_bytes([uuid[0], uuid[1], uuid[2], uuid[3]]).max(1);
+
+            // Remove any old detail references
+            options
+                .retain(|MySqlConfigOption { name, .. }| name != &MySqlConfigOptionName::Details);
+            let details = MySqlSourceDetails {
+                tables: selected_tables,
+                server_id,
+            };
+            options.push(MySqlConfigOption {
+                name: MySqlConfigOptionName::Details,
+                value: Some(WithOptionValue::Value(Value::String(hex::encode(
+                    details.into_proto().encode_to_vec(),
+                )))),
+            })
+        }
         CreateSourceConnection::LoadGenerator { generator, options } => {
             let scx = StatementContext::new(None, &*catalog);
 
@@ -532,10 +730,11 @@ pub async fn purify_create_source(
                             .or_insert(desc);
                     }
 
-                    validated_requested_subsources.extend(subsource_gen(
-                        selected_subsources,
-                        &ErsatzCatalog(tables_by_name),
-                    )?);
+                    let catalog = ErsatzCatalog(tables_by_name);
+                    validated_requested_subsources
+                        .extend(subsource_gen(selected_subsources, |name| {
+                            catalog.resolve(name)
+                        })?);
                 }
                 None => {
                     if available_subsources.is_some() {
@@ -621,6 +820,10 @@ pub async fn purify_create_source(
                     Some(CreateReferencedSubsources::Subset(targeted_subsources));
             }
         }
+        CreateSourceConnection::Webhook { .. } => {
+            // Webhook sources receive their data over HTTP, so there is no
+            // upstream system to validate against.
+        }
     }
 
     purify_source_format(&*catalog, format, connection, envelope, &connection_context).await?;
@@ -628,6 +831,59 @@ pub async fn purify_create_source(
     Ok((subsources, stmt))
 }
 
+/// Purifies a `CREATE SINK` statement.
+///
+/// Sinks that encode their data as Protobuf messages fetched from a Confluent
+/// Schema Registry have those messages resolved into the `SEED` of their
+/// format, so that the statement can be planned without network access.
+pub async fn purify_create_sink(
+    catalog: Box<dyn SessionCatalog>,
+    mut stmt: CreateSinkStatement<Aug>,
+    connection_context: ConnectionContext,
+) -> Result<CreateSinkStatement<Aug>, PlanError> {
+    let CreateSinkStatement {
+        connection, format, ..
+    } = &mut stmt;
+
+    let Some(Format::Protobuf(ProtobufSchema::Csr {
+        csr_connection:
+            CsrConnectionProtobuf {
+                seed: seed @ None,
+                connection:
+                    CsrConnection {
+                        connection: csr, ..
+                    },
+            },
+    })) = format
+    else {
+        return Ok(stmt);
+    };
+
+    let CreateSinkConnection::Kafka {
+        connection: KafkaConnection { options, .. },
+        ..
+    } = connection;
+    let KafkaConfigOptionExtracted { topic, .. } = options.clone().try_into()?;
+    let topic = topic.ok_or_else(|| sql_err!("KAFKA CONNECTION must specify TOPIC"))?;
+
+    let scx = StatementContext::new(None, &*catalog);
+    let ccsr_connection = match scx.get_item_by_resolved_name(csr)?.connection()? {
+        Connection::Csr(connection) => connection.clone(),
+        _ => sql_bail!("{} is not a schema registry connection", csr),
+    };
+    let ccsr_client = ccsr_connection
+        .connect(&*connection_context.secrets_reader)
+        .await?;
+
+    // The key subject is optional. Keys of sinks without one are encoded as
+    // the value message.
+    let value = compile_proto(&format!("{}-value", topic), &ccsr_client).await?;
+    let key = compile_proto_if_exists(&format!("{}-key", topic), &ccsr_client).await?;
+    *seed = Some(CsrSeedProtobuf { value, key });
+
+    Ok(stmt)
+}
+
 async fn purify_source_format(
     catalog: &dyn SessionCatalog,
     format: &mut CreateSourceFormat<Aug>,
@@ -711,7 +967,7 @@ async fn purify_source_format_single(
                 }
             }
         }
-        Format::Bytes | Format::Regex(_) | Format::Json | Format::Text => (),
+        Format::Bytes | Format::Regex(_) | Format::Json | Format::Parquet(_) | Format::Text => (),
     }
     Ok(())
 }
@@ -758,9 +1014,7 @@ async fn purify_csr_connection_proto(
                 .await?;
 
             let value = compile_proto(&format!("{}-value", topic), &ccsr_client).await?;
-            let key = compile_proto(&format!("{}-key", topic), &ccsr_client)
-                .await
-                .ok();
+            let key = compile_proto_if_exists(&format!("{}-key", topic), &ccsr_client).await?;
 
             if matches!(envelope, Some(Envelope::Debezium(DbzMode::Plain))) && key.is_none() {
                 sql_bail!("Key schema is required for ENVELOPE DEBEZIUM");
@@ -898,10 +1152,39 @@ async fn compile_proto(
             schema_lookup: format!("subject {}", subject_name.quoted()),
             cause: Arc::new(e),
         })?;
+    compile_proto_subjects(&primary_subject, &dependency_subjects)
+}
 
+/// Like [`compile_proto`], but returns `None` if the subject does not exist.
+///
+/// Any other failure to fetch or compile the schema is returned, so that an
+/// unreachable schema registry is not mistaken for a missing subject.
+async fn compile_proto_if_exists(
+    subject_name: &String,
+    ccsr_client: &Client,
+) -> Result<Option<CsrSeedProtobufSchema>, PlanError> {
+    match ccsr_client.get_subject_and_references(subject_name).await {
+        Ok((primary_subject, dependency_subjects)) => {
+            compile_proto_subjects(&primary_subject, &dependency_subjects).map(Some)
+        }
+        Err(GetBySubjectError::SubjectNotFound) => Ok(None),
+        // Other "not found" errors, e.g. for a subject without versions.
+        Err(GetBySubjectError::Server { code, .. }) if code / 100 == 404 => Ok(None),
+        Err(e) => Err(PlanError::FetchingCsrSchemaFailed {
+            schema_lookup: format!("subject {}", subject_name.quoted()),
+            cause: Arc::new(e),
+        }),
+    }
+}
+
+/// Compiles the descriptor of a protobuf subject and its dependencies.
+fn compile_proto_subjects(
+    primary_subject: &Subject,
+    dependency_subjects: &[Subject],
+) -> Result<CsrSeedProtobufSchema, PlanError> {
     // Compile .proto files into a file descriptor set.
     let mut source_tree = VirtualSourceTree::new();
-    for subject in iter::once(&primary_subject).chain(dependency_subjects.iter()) {
+    for subject in iter::once(primary_subject).chain(dependency_subjects) {
         source_tree.as_mut().add_file(
             Path::new(&subject.name),
             subject.schema.raw.as_bytes().to_vec(),
@@ -22,6 +22,7 @@ use mz_expr::MirScalarExpr;
 use mz_lowertest::*;
 use mz_ore::cast::CastFrom;
 use mz_ore::now::{EpochMillis, NOW_ZERO};
+use mz_repr::adt::datetime::Timezone;
 use mz_repr::explain_new::{DummyHumanizer, ExprHumanizer};
 use mz_repr::{GlobalId, RelationDesc, ScalarType};
 use mz_storage_client::types::connections::Connection;
@@ -135,6 +136,10 @@ impl CatalogItem for TestCatalogItem {
         unimplemented!()
     }
 
+    fn is_webhook_source(&self) -> bool {
+        unimplemented!()
+    }
+
     fn index_details(&self) -> Option<(&[MirScalarExpr], GlobalId)> {
         unimplemented!()
     }
@@ -303,6 +308,10 @@ impl SessionCatalog for TestCatalog {
         true
     }
 
+    fn timezone(&self) -> Timezone {
+        Timezone::UTC
+    }
+
     fn now(&self) -> EpochMillis {
         (self.config().now)()
     }
@@ -0,0 +1,290 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Role-based access control.
+//!
+//! The privilege model mirrors PostgreSQL's: every database, schema, cluster
+//! and catalog item has an owning role and an access control list (ACL) that
+//! describes which privileges have been granted to which roles. The owner of
+//! an object is the only role, other than a superuser, that can drop or alter
+//! it or grant and revoke privileges on it.
+
+use std::fmt;
+
+use bitflags::bitflags;
+use serde::{Deserialize, Serialize};
+
+use mz_sql_parser::ast::{ObjectType, Privilege};
+
+use crate::catalog::CatalogItemType;
+use crate::names::RoleId;
+
+/// The name of the pseudo-role of which every role is implicitly a member.
+pub const PUBLIC_ROLE_NAME: &str = "public";
+
+bitflags! {
+    /// A set of privileges.
+    ///
+    /// The bit positions match PostgreSQL's `AclMode` so that the two are easy
+    /// to compare, even though Materialize supports only a subset of them.
+    #[derive(Serialize, Deserialize)]
+    pub struct AclMode: u64 {
+        const INSERT = 1 << 0;
+        const SELECT = 1 << 1;
+        const UPDATE = 1 << 2;
+        const DELETE = 1 << 3;
+        const USAGE = 1 << 8;
+        const CREATE = 1 << 9;
+    }
+}
+
+impl AclMode {
+    /// The privileges and the characters that represent them in an `aclitem`,
+    /// in the order that PostgreSQL prints them.
+    const CHARS: [(AclMode, char); 6] = [
+        (AclMode::INSERT, 'a'),
+        (AclMode::SELECT, 'r'),
+        (AclMode::UPDATE, 'w'),
+        (AclMode::DELETE, 'd'),
+        (AclMode::USAGE, 'U'),
+        (AclMode::CREATE, 'C'),
+    ];
+
+    /// Returns every privilege that applies to an object of type
+    /// `object_type`.
+    pub fn all_for(object_type: ObjectType) -> AclMode {
+        match object_type {
+            ObjectType::Table => {
+                AclMode::INSERT | AclMode::SELECT | AclMode::UPDATE | AclMode::DELETE
+            }
+            ObjectType::View | ObjectType::MaterializedView | ObjectType::Source => AclMode::SELECT,
+            ObjectType::Type | ObjectType::Secret | ObjectType::Connection => AclMode::USAGE,
+            ObjectType::Database => AclMode::CREATE,
+            ObjectType::Schema | ObjectType::Cluster => AclMode::USAGE | AclMode::CREATE,
+            ObjectType::Sink
+            | ObjectType::Index
+            | ObjectType::Role
+            | ObjectType::ClusterReplica
+            | ObjectType::Object => AclMode::empty(),
+        }
+    }
+
+    /// Returns the SQL names of the privileges in this set, e.g. `SELECT`.
+    pub fn names(&self) -> Vec<&'static str> {
+        let mut names = Vec::new();
+        for (mode, name) in [
+            (AclMode::INSERT, "INSERT"),
+            (AclMode::SELECT, "SELECT"),
+            (AclMode::UPDATE, "UPDATE"),
+            (AclMode::DELETE, "DELETE"),
+            (AclMode::USAGE, "USAGE"),
+            (AclMode::CREATE, "CREATE"),
+        ] {
+            if self.contains(mode) {
+                names.push(name);
+            }
+        }
+        names
+    }
+}
+
+impl From<Privilege> for AclMode {
+    fn from(privilege: Privilege) -> AclMode {
+        match privilege {
+            Privilege::Select => AclMode::SELECT,
+            Privilege::Insert => AclMode::INSERT,
+            Privilege::Update => AclMode::UPDATE,
+            Privilege::Delete => AclMode::DELETE,
+            Privilege::Usage => AclMode::USAGE,
+            Privilege::Create => AclMode::CREATE,
+        }
+    }
+}
+
+impl fmt::Display for AclMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (mode, c) in AclMode::CHARS {
+            if self.contains(mode) {
+                write!(f, "{c}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the [`ObjectType`] that corresponds to a catalog item of type
+/// `item_type`.
+pub fn item_object_type(item_type: CatalogItemType) -> ObjectType {
+    match item_type {
+        CatalogItemType::Table => ObjectType::Table,
+        CatalogItemType::Source => ObjectType::Source,
+        CatalogItemType::Sink => ObjectType::Sink,
+        CatalogItemType::View => ObjectType::View,
+        CatalogItemType::MaterializedView => ObjectType::MaterializedView,
+        CatalogItemType::Index => ObjectType::Index,
+        CatalogItemType::Type => ObjectType::Type,
+        CatalogItemType::Func => ObjectType::Object,
+        CatalogItemType::Secret => ObjectType::Secret,
+        CatalogItemType::Connection => ObjectType::Connection,
+    }
+}
+
+/// A single entry in an access control list: the privileges that `grantor`
+/// granted to `grantee`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub struct MzAclItem {
+    pub grantee: RoleId,
+    pub grantor: RoleId,
+    pub acl_mode: AclMode,
+}
+
+impl MzAclItem {
+    /// Renders the item in PostgreSQL's `aclitem` text format, e.g.
+    /// `alice=arw/bob`. Grants to `PUBLIC` have an empty grantee.
+    pub fn to_aclitem_string<'a, F>(&self, role_name: F) -> String
+    where
+        F: Fn(&RoleId) -> &'a str,
+    {
+        let grantee = match self.grantee {
+            RoleId::Public => "",
+            ref grantee => role_name(grantee),
+        };
+        format!("{grantee}={}/{}", self.acl_mode, role_name(&self.grantor))
+    }
+}
+
+/// The owner and access control list of an object.
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+pub struct Privileges {
+    pub owner_id: RoleId,
+    pub acl: Vec<MzAclItem>,
+}
+
+impl Privileges {
+    /// Returns the privileges of a newly created object of type `object_type`
+    /// owned by `owner_id`. The owner is granted every applicable privilege.
+    pub fn new(owner_id: RoleId, object_type: ObjectType) -> Privileges {
+        let mut privileges = Privileges {
+            owner_id,
+            acl: Vec::new(),
+        };
+        privileges.grant(owner_id, AclMode::all_for(object_type));
+        privileges
+    }
+
+    /// Grants `acl_mode` to `grantee`. Only owners (or superusers acting on
+    /// their behalf) may grant privileges, so the owner is the grantor.
+    pub fn grant(&mut self, grantee: RoleId, acl_mode: AclMode) {
+        if acl_mode.is_empty() {
+            return;
+        }
+        let grantor = self.owner_id;
+        match self
+            .acl
+            .iter_mut()
+            .find(|item| item.grantee == grantee && item.grantor == grantor)
+        {
+            Some(item) => item.acl_mode |= acl_mode,
+            None => self.acl.push(MzAclItem {
+                grantee,
+                grantor,
+                acl_mode,
+            }),
+        }
+    }
+
+    /// Revokes `acl_mode` from `grantee`.
+    pub fn revoke(&mut self, grantee: RoleId, acl_mode: AclMode) {
+        for item in self.acl.iter_mut().filter(|item| item.grantee == grantee) {
+            item.acl_mode -= acl_mode;
+        }
+        self.acl.retain(|item| !item.acl_mode.is_empty());
+    }
+
+    /// Transfers ownership to `new_owner`. As in PostgreSQL, privileges held by
+    /// or granted by the previous owner are transferred to the new owner.
+    pub fn set_owner(&mut self, new_owner: RoleId) {
+        let old_owner = self.owner_id;
+        let acl = std::mem::take(&mut self.acl);
+        self.owner_id = new_owner;
+        for mut item in acl {
+            if item.grantee == old_owner {
+                item.grantee = new_owner;
+            }
+            if item.grantor == old_owner {
+                item.grantor = new_owner;
+            }
+            match self
+                .acl
+                .iter_mut()
+                .find(|i| i.grantee == item.grantee && i.grantor == item.grantor)
+            {
+                Some(existing) => existing.acl_mode |= item.acl_mode,
+                None => self.acl.push(item),
+            }
+        }
+    }
+
+    /// Returns the privileges that `role_id` holds, either directly or through
+    /// `PUBLIC`.
+    pub fn effective(&self, role_id: RoleId) -> AclMode {
+        self.acl
+            .iter()
+            .filter(|item| item.grantee == role_id || item.grantee == RoleId::Public)
+            .fold(AclMode::empty(), |acc, item| acc | item.acl_mode)
+    }
+
+    /// Reports whether `role_id` owns the object or appears anywhere in its
+    /// access control list.
+    pub fn references(&self, role_id: RoleId) -> bool {
+        self.owner_id == role_id
+            || self
+                .acl
+                .iter()
+                .any(|item| item.grantee == role_id || item.grantor == role_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grant_revoke_owner() {
+        let alice = RoleId::User(1);
+        let bob = RoleId::User(2);
+        let mut privileges = Privileges::new(alice, ObjectType::Table);
+        privileges.grant(bob, AclMode::SELECT);
+        privileges.grant(RoleId::Public, AclMode::SELECT);
+        privileges.grant(bob, AclMode::INSERT);
+        let names = |id: &RoleId| match id {
+            RoleId::User(1) => "alice",
+            _ => "bob",
+        };
+        let acl: Vec<_> = privileges
+            .acl
+            .iter()
+            .map(|item| item.to_aclitem_string(names))
+            .collect();
+        assert_eq!(acl, ["alice=arwd/alice", "bob=ar/alice", "=r/alice"]);
+        assert_eq!(privileges.effective(bob), AclMode::INSERT | AclMode::SELECT);
+
+        privileges.revoke(bob, AclMode::all_for(ObjectType::Table));
+        assert_eq!(privileges.effective(bob), AclMode::SELECT);
+
+        privileges.set_owner(bob);
+        let acl: Vec<_> = privileges
+            .acl
+            .iter()
+            .map(|item| item.to_aclitem_string(names))
+            .collect();
+        assert_eq!(acl, ["bob=arwd/bob", "=r/bob"]);
+        assert!(!privileges.references(alice));
+    }
+}
@@ -410,6 +410,10 @@ impl<'a> FromSql<'a> for Slt {
                         elements,
                     })
                 }
+                PgKind::Range(_) => {
+                    let ty = mz_pgrepr::Type::from_oid(ty.oid())?;
+                    Self(Value::decode_binary(&ty, raw)?)
+                }
                 _ => match ty.oid() {
                     oid::TYPE_UINT2_OID => {
                         let v = raw.get_u16();
@@ -444,7 +448,7 @@ impl<'a> FromSql<'a> for Slt {
     }
     fn accepts(ty: &PgType) -> bool {
         match ty.kind() {
-            PgKind::Array(_) | PgKind::Composite(_) => return true,
+            PgKind::Array(_) | PgKind::Composite(_) | PgKind::Range(_) => return true,
             _ => {}
         }
         match ty.oid() {
@@ -813,6 +817,7 @@ impl RunnerInner {
                     consensus_uri,
                 },
                 persist_clients,
+                persist_blob_encryption: Default::default(),
                 storage_stash_url,
                 now: SYSTEM_TIME.clone(),
                 postgres_factory: postgres_factory.clone(),
@@ -23,6 +23,7 @@ use differential_dataflow::lattice::Lattice;
 use proptest::prelude::{any, Arbitrary};
 use proptest::prop_oneof;
 use proptest::strategy::{BoxedStrategy, Strategy};
+use proptest_derive::Arbitrary;
 use serde::{Deserialize, Serialize};
 use timely::progress::frontier::{Antichain, MutableAntichain};
 use timely::PartialOrder;
@@ -30,6 +31,7 @@ use tokio_stream::wrappers::UnboundedReceiverStream;
 use tonic::{Request, Response, Status, Streaming};
 
 use mz_ore::cast::CastFrom;
+use mz_persist_client::cfg::PersistParameters;
 use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
 use mz_repr::{Diff, GlobalId, Row};
 use mz_service::client::{GenericClient, Partitionable, PartitionedState};
@@ -112,6 +114,48 @@ pub enum StorageCommand<T = mz_repr::Timestamp> {
     /// accumulations must be correct.
     AllowCompaction(Vec<(GlobalId, Antichain<T>)>),
     CreateSinks(Vec<CreateSinkCommand<T>>),
+    /// Update storage instance configuration.
+    UpdateConfiguration(StorageParameters),
+}
+
+/// Storage instance configuration parameters.
+///
+/// Parameters can be set (`Some`) or unset (`None`). Unset parameters should be interpreted to
+/// mean "use the previous value".
+#[derive(Arbitrary, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct StorageParameters {
+    /// Persist client configuration.
+    pub persist: PersistParameters,
+}
+
+impl StorageParameters {
+    /// Update the parameter values with the set ones from `other`.
+    pub fn update(&mut self, other: StorageParameters) {
+        let StorageParameters { persist } = other;
+
+        self.persist.update(persist);
+    }
+
+    /// Return whether all parameters are unset.
+    pub fn all_unset(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl RustType<ProtoStorageParameters> for StorageParameters {
+    fn into_proto(&self) -> ProtoStorageParameters {
+        ProtoStorageParameters {
+            persist: Some(self.persist.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: ProtoStorageParameters) -> Result<Self, TryFromProtoError> {
+        Ok(Self {
+            persist: proto
+                .persist
+                .into_rust_if_some("ProtoStorageParameters::persist")?,
+        })
+    }
 }
 
 /// A command that starts ingesting the given ingestion description
@@ -223,6 +267,9 @@ impl RustType<ProtoStorageCommand> for StorageCommand<mz_repr::Timestamp> {
                 StorageCommand::CreateSinks(sinks) => CreateSinks(ProtoCreateSinks {
                     sinks: sinks.into_proto(),
                 }),
+                StorageCommand::UpdateConfiguration(params) => {
+                    UpdateConfiguration(params.into_proto())
+                }
             }),
         }
     }
@@ -240,6 +287,9 @@ impl RustType<ProtoStorageCommand> for StorageCommand<mz_repr::Timestamp> {
             Some(CreateSinks(ProtoCreateSinks { sinks })) => {
                 Ok(StorageCommand::CreateSinks(sinks.into_rust()?))
             }
+            Some(UpdateConfiguration(params)) => {
+                Ok(StorageCommand::UpdateConfiguration(params.into_rust()?))
+            }
             None => Err(TryFromProtoError::missing_field(
                 "ProtoStorageCommand::kind",
             )),
@@ -270,6 +320,7 @@ impl Arbitrary for StorageCommand<mz_repr::Timestamp> {
                     .map(|(id, frontier_vec)| { (id, Antichain::from(frontier_vec)) })
                     .collect()
             )),
+            any::<StorageParameters>().prop_map(StorageCommand::UpdateConfiguration),
         ]
         .boxed()
     }
@@ -389,7 +440,9 @@ where
                     assert!(previous.is_none(), "Protocol error: starting frontier tracking for already present identifier {:?} due to command {:?}", export.id, command);
                 }
             }
-            StorageCommand::AllowCompaction(_) | StorageCommand::InitializationComplete => {
+            StorageCommand::AllowCompaction(_)
+            | StorageCommand::InitializationComplete
+            | StorageCommand::UpdateConfiguration(_) => {
                 // Other commands have no known impact on frontier tracking.
             }
         }
@@ -47,6 +47,7 @@ use mz_orchestrator::NamespacedOrchestrator;
 use mz_ore::now::{EpochMillis, NowFn};
 use mz_persist_client::cache::PersistClientCache;
 use mz_persist_client::critical::SinceHandle;
+use mz_persist_client::encryption::BlobEncryptionCliArgs;
 use mz_persist_client::{PersistClient, PersistLocation, ShardId};
 use mz_persist_types::{Codec, Codec64, Opaque};
 use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
@@ -55,7 +56,7 @@ use mz_stash::{self, PostgresFactory, StashError, TypedCollection};
 
 use crate::client::{
     CreateSinkCommand, CreateSourceCommand, ProtoStorageCommand, ProtoStorageResponse,
-    StorageCommand, StorageResponse, Update,
+    StorageCommand, StorageParameters, StorageResponse, Update,
 };
 use crate::controller::hosts::{StorageHosts, StorageHostsConfig};
 use crate::types::errors::DataflowError;
@@ -134,6 +135,9 @@ pub struct CollectionDescription<T> {
     /// A GlobalId to use for this collection to use for the status collection.
     /// Used to keep track of source status/error information.
     pub status_collection_id: Option<GlobalId>,
+    /// The values of columns that were added to this collection after some of
+    /// its data was written. See [`CollectionMetadata::missing_values`].
+    pub missing_values: Option<Row>,
 }
 
 impl<T> From<RelationDesc> for CollectionDescription<T> {
@@ -143,6 +147,7 @@ impl<T> From<RelationDesc> for CollectionDescription<T> {
             data_source: DataSource::Other,
             since: None,
             status_collection_id: None,
+            missing_values: None,
         }
     }
 }
@@ -175,6 +180,12 @@ pub trait StorageController: Debug + Send {
     /// This method can be invoked immediately, at the potential expense of performance.
     fn initialization_complete(&mut self);
 
+    /// Update storage configuration.
+    ///
+    /// This applies to all storage hosts, including the ones provisioned in the
+    /// future, and to the persist clients of the controller itself.
+    async fn update_configuration(&mut self, config_params: StorageParameters);
+
     /// Acquire an immutable reference to the collection state, should it exist.
     fn collection(&self, id: GlobalId) -> Result<&CollectionState<Self::Timestamp>, StorageError>;
 
@@ -203,6 +214,20 @@ pub trait StorageController: Debug + Send {
         collections: Vec<(GlobalId, StorageHostConfig)>,
     ) -> Result<(), StorageError>;
 
+    /// Evolves the schema of the table `id` to `desc`, which must extend the
+    /// table's current schema with additional trailing columns.
+    ///
+    /// Data already written to the table is not rewritten. Instead, readers
+    /// created after this call fill in the values of the added columns from
+    /// `missing_values`. Readers created before this call continue to observe
+    /// the table's previous schema.
+    fn alter_table_desc(
+        &mut self,
+        id: GlobalId,
+        desc: RelationDesc,
+        missing_values: Row,
+    ) -> Result<(), StorageError>;
+
     /// Acquire an immutable reference to the export state, should it exist.
     fn export(&self, id: GlobalId) -> Result<&ExportState<Self::Timestamp>, StorageError>;
 
@@ -416,6 +441,14 @@ pub struct CollectionMetadata {
     pub data_shard: ShardId,
     /// The persist shard containing the status updates for this storage collection
     pub status_shard: Option<ShardId>,
+    /// The values of columns that were added to this collection after some of
+    /// its data was written.
+    ///
+    /// If present, rows read from `data_shard` are adjusted to have exactly
+    /// the arity of this row: rows that are missing trailing columns take
+    /// their values from this row, and rows with additional trailing columns
+    /// have them removed.
+    pub missing_values: Option<Row>,
 }
 
 impl RustType<ProtoCollectionMetadata> for CollectionMetadata {
@@ -426,6 +459,7 @@ impl RustType<ProtoCollectionMetadata> for CollectionMetadata {
             data_shard: self.data_shard.to_string(),
             remap_shard: self.remap_shard.to_string(),
             status_shard: self.status_shard.map(|s| s.to_string()),
+            missing_values: self.missing_values.into_proto(),
         }
     }
 
@@ -447,10 +481,25 @@ impl RustType<ProtoCollectionMetadata> for CollectionMetadata {
                 .status_shard
                 .map(|s| s.parse().map_err(TryFromProtoError::InvalidShardId))
                 .transpose()?,
+            missing_values: value.missing_values.into_rust()?,
         })
     }
 }
 
+/// Adjusts `row`, which was read from a collection's data shard, to the arity
+/// of `missing_values`. See [`CollectionMetadata::missing_values`].
+pub fn adjust_to_missing_values(row: Row, missing_values: &Row, row_buf: &mut Row) -> Row {
+    let arity = missing_values.iter().count();
+    let row_arity = row.iter().count();
+    if row_arity == arity {
+        return row;
+    }
+    let mut packer = row_buf.packer();
+    packer.extend(row.iter().take(arity));
+    packer.extend(missing_values.iter().skip(row_arity));
+    row_buf.clone()
+}
+
 impl Codec for CollectionMetadata {
     fn codec_name() -> String {
         "protobuf[CollectionMetadata]".into()
@@ -805,6 +854,11 @@ where
         self.hosts.initialization_complete();
     }
 
+    async fn update_configuration(&mut self, config_params: StorageParameters) {
+        config_params.persist.apply(self.persist.lock().await.cfg());
+        self.hosts.update_configuration(config_params);
+    }
+
     fn collection(&self, id: GlobalId) -> Result<&CollectionState<Self::Timestamp>, StorageError> {
         self.state
             .collections
@@ -886,6 +940,7 @@ where
                 remap_shard: collection_shards.remap_shard,
                 data_shard: collection_shards.data_shard,
                 status_shard,
+                missing_values: description.missing_values.clone(),
             };
 
             // should be replaced with real introspection (https://github.com/MaterializeInc/materialize/issues/14266)
@@ -1079,6 +1134,19 @@ where
         Ok(())
     }
 
+    fn alter_table_desc(
+        &mut self,
+        id: GlobalId,
+        desc: RelationDesc,
+        missing_values: Row,
+    ) -> Result<(), StorageError> {
+        let collection = self.collection_mut(id)?;
+        collection.description.desc = desc;
+        collection.description.missing_values = Some(missing_values.clone());
+        collection.collection_metadata.missing_values = Some(missing_values);
+        Ok(())
+    }
+
     fn export(&self, id: GlobalId) -> Result<&ExportState<Self::Timestamp>, StorageError> {
         self.state
             .exports
@@ -1305,10 +1373,14 @@ where
         match read_handle.snapshot_and_fetch(as_of).await {
             Ok(contents) => {
                 let mut snapshot = Vec::with_capacity(contents.len());
+                let mut row_buf = Row::default();
                 for ((data, _), _, diff) in contents {
                     // TODO(petrosagg): We should accumulate the errors too and let the user
                     // interprret the result
-                    let row = data.expect("invalid protobuf data").0?;
+                    let mut row = data.expect("invalid protobuf data").0?;
+                    if let Some(missing_values) = &metadata.missing_values {
+                        row = adjust_to_missing_values(row, missing_values, &mut row_buf);
+                    }
                     snapshot.push((row, diff));
                 }
                 Ok(snapshot)
@@ -1535,6 +1607,7 @@ where
         orchestrator: Arc<dyn NamespacedOrchestrator>,
         storaged_image: String,
         init_container_image: Option<String>,
+        persist_blob_encryption: BlobEncryptionCliArgs,
         now: NowFn,
         postgres_factory: &PostgresFactory,
         envd_epoch: NonZeroI64,
@@ -1547,6 +1620,7 @@ where
                 orchestrator,
                 storaged_image,
                 init_container_image,
+                persist_blob_encryption,
             },
             Arc::clone(&persist_clients),
         );
@@ -34,11 +34,14 @@ use mz_orchestrator::{NamespacedOrchestrator, ServiceConfig, ServicePort, Servic
 use mz_ore::collections::CollectionExt;
 use mz_ore::halt;
 use mz_persist_client::cache::PersistClientCache;
+use mz_persist_client::encryption::BlobEncryptionCliArgs;
 use mz_persist_types::Codec64;
 use mz_proto::RustType;
 use mz_repr::GlobalId;
 
-use crate::client::{ProtoStorageCommand, ProtoStorageResponse, StorageCommand, StorageResponse};
+use crate::client::{
+    ProtoStorageCommand, ProtoStorageResponse, StorageCommand, StorageParameters, StorageResponse,
+};
 use crate::controller::rehydration::RehydratingStorageClient;
 use crate::types::hosts::{StorageHostConfig, StorageHostResourceAllocation};
 
@@ -55,6 +58,8 @@ pub struct StorageHostsConfig {
     pub storaged_image: String,
     /// The init container image to use for storaged.
     pub init_container_image: Option<String>,
+    /// The persist blob encryption keys to configure storaged with.
+    pub persist_blob_encryption: BlobEncryptionCliArgs,
 }
 
 /// Manages provisioning of storage hosts and assignment of storage objects
@@ -71,12 +76,16 @@ pub struct StorageHosts<T> {
     storaged_image: String,
     /// The init container image to use for storaged.
     init_container_image: Option<String>,
+    /// The persist blob encryption keys to configure storaged with.
+    persist_blob_encryption: BlobEncryptionCliArgs,
     /// The known storage hosts, identified by network address.
     hosts: HashMap<StorageHostAddr, StorageHost<T>>,
     /// The assignment of storage objects to storage hosts.
     objects: Arc<std::sync::Mutex<HashMap<GlobalId, StorageHostAddr>>>,
     /// Set to `true` once `initialization_complete` has been called.
     initialized: bool,
+    /// Storage configuration to apply to newly provisioned hosts.
+    config: StorageParameters,
     /// A handle to Persist
     persist: Arc<Mutex<PersistClientCache>>,
 }
@@ -107,9 +116,11 @@ where
             orchestrator: config.orchestrator,
             storaged_image: config.storaged_image,
             init_container_image: config.init_container_image,
+            persist_blob_encryption: config.persist_blob_encryption,
             objects: Arc::new(std::sync::Mutex::new(HashMap::new())),
             hosts: HashMap::new(),
             initialized: false,
+            config: Default::default(),
             persist,
         }
     }
@@ -127,6 +138,14 @@ where
         }
     }
 
+    /// Update the configuration of all storage hosts, current and future.
+    pub fn update_configuration(&mut self, config_params: StorageParameters) {
+        for client in self.clients() {
+            client.send(StorageCommand::UpdateConfiguration(config_params.clone()));
+        }
+        self.config.update(config_params);
+    }
+
     /// Creates a [`MetricsFetcher`] that can be used to repeatedly fetch
     /// metrics for all known storage objects.
     pub fn metrics_fetcher(&self) -> MetricsFetcher {
@@ -180,6 +199,7 @@ where
                 self.build_info,
                 Arc::clone(&self.persist),
             );
+            client.send(StorageCommand::UpdateConfiguration(self.config.clone()));
             if self.initialized {
                 client.send(StorageCommand::InitializationComplete);
             }
@@ -255,12 +275,14 @@ where
                     image: self.storaged_image.clone(),
                     init_container_image: self.init_container_image.clone(),
                     args: &|assigned| {
-                        vec![
+                        let mut args = vec![
                             format!("--workers={}", allocation.workers),
                             format!("--controller-listen-addr={}", assigned["controller"]),
                             format!("--internal-http-listen-addr={}", assigned["internal-http"]),
                             format!("--opentelemetry-resource=storage_id={}", id),
-                        ]
+                        ];
+                        args.extend(self.persist_blob_encryption.to_args());
+                        args
                     },
                     ports: vec![
                         ServicePort {
@@ -282,6 +304,8 @@ where
                     availability_zone: None,
                     // TODO: Decide on an A-A policy for storage hosts
                     anti_affinity: None,
+                    // Upsert sources spill their state to the scratch directory.
+                    scratch_directory: true,
                 },
             )
             .await?;
@@ -40,7 +40,7 @@ use mz_service::client::GenericClient;
 
 use crate::client::{
     CreateSinkCommand, CreateSourceCommand, StorageClient, StorageCommand, StorageGrpcClient,
-    StorageResponse,
+    StorageParameters, StorageResponse,
 };
 use crate::controller::ResumptionFrontierCalculator;
 use crate::types::sources::SourceData;
@@ -78,6 +78,7 @@ where
             sinks: BTreeMap::new(),
             uppers: HashMap::new(),
             initialized: false,
+            config: Default::default(),
             persist,
         };
         let task = mz_ore::task::spawn(|| "rehydration", async move { task.run().await });
@@ -120,6 +121,8 @@ struct RehydrationTask<T> {
     /// Set to `true` once [`StorageCommand::InitializationComplete`] has been
     /// observed.
     initialized: bool,
+    /// The current configuration of the storage host.
+    config: StorageParameters,
     /// A handle to Persist
     persist: Arc<Mutex<PersistClientCache>>,
 }
@@ -222,8 +225,10 @@ where
             export.description.as_of = cached_as_of.maybe_fast_forward(from_since);
         }
 
-        // Rehydrate all commands.
+        // Rehydrate all commands. The configuration goes first, so that the
+        // sources and sinks are created with it.
         let mut commands = vec![
+            StorageCommand::UpdateConfiguration(self.config.clone()),
             StorageCommand::CreateSources(self.sources.values().cloned().collect()),
             StorageCommand::CreateSinks(self.sinks.values().cloned().collect()),
         ];
@@ -320,6 +325,7 @@ where
                 }
             }
             StorageCommand::AllowCompaction(_frontiers) => {}
+            StorageCommand::UpdateConfiguration(params) => self.config.update(params.clone()),
         }
     }
 
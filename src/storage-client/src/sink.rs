@@ -211,6 +211,7 @@ async fn build_kafka(
     .await
     .context("error registering kafka topic for sink")?;
 
+    let mut protobuf_messages = None;
     let published_schema_info = match builder.format {
         KafkaSinkFormat::Avro {
             key_schema,
@@ -237,6 +238,41 @@ async fn build_kafka(
             })
         }
         KafkaSinkFormat::Json => None,
+        KafkaSinkFormat::Protobuf {
+            messages,
+            csr_connection,
+        } => {
+            let published_schema_info = match csr_connection {
+                Some(csr_connection) => {
+                    let ccsr = csr_connection
+                        .connect(&*connection_context.secrets_reader)
+                        .await?;
+                    let value_schema_id = ccsr
+                        .get_schema_by_subject(&format!("{}-value", builder.topic_name))
+                        .await
+                        .context("unable to fetch value schema from registry in kafka sink")?
+                        .id;
+                    // Keys without a message of their own are encoded as the
+                    // value message, and so are framed with its schema ID.
+                    let key_schema_id = match messages.key {
+                        Some(_) => {
+                            ccsr.get_schema_by_subject(&format!("{}-key", builder.topic_name))
+                                .await
+                                .context("unable to fetch key schema from registry in kafka sink")?
+                                .id
+                        }
+                        None => value_schema_id,
+                    };
+                    Some(PublishedSchemaInfo {
+                        key_schema_id: Some(key_schema_id),
+                        value_schema_id,
+                    })
+                }
+                None => None,
+            };
+            protobuf_messages = Some(messages);
+            published_schema_info
+        }
     };
 
     let progress = match builder.consistency_config {
@@ -263,6 +299,7 @@ async fn build_kafka(
         key_desc_and_indices: builder.key_desc_and_indices,
         value_desc: builder.value_desc,
         published_schema_info,
+        protobuf_messages,
         progress,
         fuel: builder.fuel,
     }))
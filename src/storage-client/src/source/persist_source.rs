@@ -25,12 +25,15 @@ use tokio::sync::Mutex;
 use mz_expr::MfpPlan;
 use mz_persist_client::cache::PersistClientCache;
 use mz_persist_client::fetch::FetchedPart;
+use mz_persist_types::stats::PartStats;
 use mz_repr::{Diff, GlobalId, Row, Timestamp};
 use mz_timely_util::builder_async::{Event, OperatorBuilder as AsyncOperatorBuilder};
 
-use crate::controller::CollectionMetadata;
+use crate::controller::{adjust_to_missing_values, CollectionMetadata};
+use crate::types::columnar::source_data_key_projection;
 use crate::types::errors::DataflowError;
 use crate::types::sources::SourceData;
+use crate::types::stats::RelationPartStats;
 
 /// Creates a new source that reads from a persist shard, distributing the work
 /// of reading data to all timely workers.
@@ -127,6 +130,19 @@ where
     YFn: Fn(Instant, usize) -> bool + 'static,
 {
     let name = source_id.to_string();
+    // Skip fetching parts whose stats show that no row in them could pass the
+    // filter. The MFP itself is still applied to the parts that are fetched.
+    let filter = map_filter_project.as_ref().map(|mfp| (**mfp).clone());
+    let should_fetch_part = move |stats: &PartStats| match &filter {
+        Some(mfp) => RelationPartStats::new(stats).may_match_mfp(mfp),
+        None => true,
+    };
+    // Only decode the columns the MFP actually looks at, when the parts are
+    // stored in a format that allows it. The rest are decoded as nulls, which
+    // the MFP then projects away.
+    let key_projection = map_filter_project
+        .as_ref()
+        .map(|mfp| source_data_key_projection(mfp.demand()));
     let (fetched, token) = shard_source(
         scope,
         &name,
@@ -137,8 +153,17 @@ where
         until.clone(),
         flow_control_input,
         flow_control_max_inflight_bytes,
+        should_fetch_part,
+        key_projection,
+    );
+    let rows = decode_and_mfp(
+        &fetched,
+        &name,
+        until,
+        metadata.missing_values,
+        map_filter_project,
+        yield_fn,
     );
-    let rows = decode_and_mfp(&fetched, &name, until, map_filter_project, yield_fn);
     (rows, token)
 }
 
@@ -146,6 +171,7 @@ pub fn decode_and_mfp<G, YFn>(
     fetched: &Stream<G, FetchedPart<SourceData, (), Timestamp, Diff>>,
     name: &str,
     until: Antichain<Timestamp>,
+    missing_values: Option<Row>,
     mut map_filter_project: Option<&mut MfpPlan>,
     yield_fn: YFn,
 ) -> Stream<G, (Result<Row, DataflowError>, Timestamp, Diff)>
@@ -196,7 +222,14 @@ where
                 for ((key, val), time, diff) in fetched_part {
                     if !until.less_equal(&time) {
                         match (key, val) {
-                            (Ok(SourceData(Ok(row))), Ok(())) => {
+                            (Ok(SourceData(Ok(mut row))), Ok(())) => {
+                                if let Some(missing_values) = &missing_values {
+                                    row = adjust_to_missing_values(
+                                        row,
+                                        missing_values,
+                                        &mut row_builder,
+                                    );
+                                }
                                 if let Some(mfp) = &mut map_filter_project {
                                     let arena = mz_repr::RowArena::new();
                                     let mut datums_local = datum_vec.borrow_with(&row);
@@ -0,0 +1,411 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A columnar representation of the [SourceData] stored in persist parts.
+//!
+//! The rows of a part are split into one column per column of the relation,
+//! plus a column for errors. Readers that only need some of the relation's
+//! columns can decode just those (see [source_data_key_projection]).
+
+use std::collections::BTreeSet;
+
+use bytes::BufMut;
+use mz_persist_types::columnar::{BinaryColumn, DynColumn, PartColumns};
+use mz_persist_types::Codec;
+use mz_proto::{ProtoType, RustType};
+use mz_repr::{Datum, Row, RowPacker};
+use prost::Message;
+
+use crate::types::errors::ProtoDataflowError;
+use crate::types::sources::SourceData;
+
+/// The name of the column holding the errors in a part.
+const ERR_COL: &str = "err";
+
+/// The name of the column holding the `idx`'th column of the rows in a part.
+fn col_name(idx: usize) -> String {
+    format!("c{}", idx)
+}
+
+/// Returns an empty column of the type that stores `datum`.
+///
+/// Datums of the SQL types with a native columnar equivalent (booleans,
+/// integers, floats, and strings) are stored as that type. The rest are stored
+/// as opaque bytes, each the encoding of a single-datum [Row].
+fn datum_column(datum: Datum) -> DynColumn {
+    match datum {
+        Datum::False | Datum::True => DynColumn::Bool(Vec::new()),
+        Datum::Int16(_) => DynColumn::I16(Vec::new()),
+        Datum::Int32(_) => DynColumn::I32(Vec::new()),
+        Datum::Int64(_) => DynColumn::I64(Vec::new()),
+        Datum::UInt8(_) => DynColumn::U8(Vec::new()),
+        Datum::UInt16(_) => DynColumn::U16(Vec::new()),
+        Datum::UInt32(_) => DynColumn::U32(Vec::new()),
+        Datum::UInt64(_) => DynColumn::U64(Vec::new()),
+        Datum::Float32(_) => DynColumn::F32(Vec::new()),
+        Datum::Float64(_) => DynColumn::F64(Vec::new()),
+        Datum::String(_) => DynColumn::String(BinaryColumn::default()),
+        _ => DynColumn::Bytes(BinaryColumn::default()),
+    }
+}
+
+/// Appends `datum` to `col`, which must be the [datum_column] of it (or a
+/// bytes column).
+fn push_datum(col: &mut DynColumn, datum: Datum, buf: &mut Vec<u8>) {
+    match (col, datum) {
+        (col, Datum::Null) => col.push_null(),
+        (DynColumn::Bool(col), Datum::False) => col.push(Some(false)),
+        (DynColumn::Bool(col), Datum::True) => col.push(Some(true)),
+        (DynColumn::I16(col), Datum::Int16(x)) => col.push(Some(x)),
+        (DynColumn::I32(col), Datum::Int32(x)) => col.push(Some(x)),
+        (DynColumn::I64(col), Datum::Int64(x)) => col.push(Some(x)),
+        (DynColumn::U8(col), Datum::UInt8(x)) => col.push(Some(x)),
+        (DynColumn::U16(col), Datum::UInt16(x)) => col.push(Some(x)),
+        (DynColumn::U32(col), Datum::UInt32(x)) => col.push(Some(x)),
+        (DynColumn::U64(col), Datum::UInt64(x)) => col.push(Some(x)),
+        (DynColumn::F32(col), Datum::Float32(x)) => col.push(Some(x.into_inner())),
+        (DynColumn::F64(col), Datum::Float64(x)) => col.push(Some(x.into_inner())),
+        (DynColumn::String(col), Datum::String(x)) => col.push(Some(x.as_bytes())),
+        (DynColumn::Bytes(col), datum) => {
+            buf.clear();
+            Row::pack_slice(&[datum]).encode(buf);
+            col.push(Some(buf));
+        }
+        (col, datum) => unreachable!("{:?} can't be stored in {:?}", datum, col),
+    }
+}
+
+/// Pushes the `idx`'th datum of `col` onto `packer`.
+fn pack_datum(col: &DynColumn, idx: usize, packer: &mut RowPacker) -> Result<(), String> {
+    let datum = match col {
+        DynColumn::Bool(col) => col[idx].map(Datum::from),
+        DynColumn::I16(col) => col[idx].map(Datum::Int16),
+        DynColumn::I32(col) => col[idx].map(Datum::Int32),
+        DynColumn::I64(col) => col[idx].map(Datum::Int64),
+        DynColumn::U8(col) => col[idx].map(Datum::UInt8),
+        DynColumn::U16(col) => col[idx].map(Datum::UInt16),
+        DynColumn::U32(col) => col[idx].map(Datum::UInt32),
+        DynColumn::U64(col) => col[idx].map(Datum::UInt64),
+        DynColumn::F32(col) => col[idx].map(|x| Datum::Float32(x.into())),
+        DynColumn::F64(col) => col[idx].map(|x| Datum::Float64(x.into())),
+        DynColumn::String(col) => match col.get(idx) {
+            Some(x) => Some(Datum::String(
+                std::str::from_utf8(x).map_err(|err| err.to_string())?,
+            )),
+            None => None,
+        },
+        DynColumn::Bytes(col) => {
+            if let Some(x) = col.get(idx) {
+                packer.extend_by_row(&Row::decode(x)?);
+                return Ok(());
+            }
+            None
+        }
+    };
+    packer.push(datum.unwrap_or(Datum::Null));
+    Ok(())
+}
+
+/// Splits the given encoded [SourceData] into columns.
+///
+/// The first column holds the errors, and is null for rows. Each of the rest
+/// holds the corresponding column of the rows, and is null for nulls and
+/// errors. A column's type follows the type of the relation's column (see
+/// [datum_column]), falling back to bytes if its datums are of more than one
+/// type or are all null.
+///
+/// Returns `Ok(None)` if the rows don't all have the same number of columns.
+pub(crate) fn source_data_encode_columns<'a>(
+    encoded: impl Iterator<Item = &'a [u8]>,
+) -> Result<Option<PartColumns>, String> {
+    let data = encoded
+        .map(SourceData::decode)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Pick the type of each column.
+    let mut cols: Option<Vec<Option<DynColumn>>> = None;
+    for row in data.iter().filter_map(|x| x.0.as_ref().ok()) {
+        let cols = cols.get_or_insert_with(|| vec![None; row.iter().count()]);
+        if row.iter().count() != cols.len() {
+            return Ok(None);
+        }
+        for (col, datum) in cols.iter_mut().zip(row.iter()) {
+            if datum.is_null() {
+                continue;
+            }
+            let datum_col = datum_column(datum);
+            match col {
+                None => *col = Some(datum_col),
+                Some(col) if std::mem::discriminant(col) != std::mem::discriminant(&datum_col) => {
+                    *col = DynColumn::Bytes(BinaryColumn::default())
+                }
+                Some(_) => {}
+            }
+        }
+    }
+    let mut cols = cols
+        .unwrap_or_default()
+        .into_iter()
+        .map(|col| col.unwrap_or_else(|| DynColumn::Bytes(BinaryColumn::default())))
+        .collect::<Vec<_>>();
+
+    let mut err_col = BinaryColumn::default();
+    let mut buf = Vec::new();
+    for data in data.iter() {
+        match &data.0 {
+            Ok(row) => {
+                for (col, datum) in cols.iter_mut().zip(row.iter()) {
+                    push_datum(col, datum, &mut buf);
+                }
+                err_col.push(None);
+            }
+            Err(err) => {
+                for col in cols.iter_mut() {
+                    col.push_null();
+                }
+                err_col.push(Some(&err.into_proto().encode_to_vec()));
+            }
+        }
+    }
+    let cols = std::iter::once((ERR_COL.to_owned(), Some(DynColumn::Bytes(err_col))))
+        .chain(
+            cols.into_iter()
+                .enumerate()
+                .map(|(idx, col)| (col_name(idx), Some(col))),
+        )
+        .collect();
+    Ok(Some(PartColumns {
+        len: data.len(),
+        cols,
+    }))
+}
+
+/// Reassembles the `idx`'th [SourceData] of columns computed by
+/// [source_data_encode_columns], appending its encoding to `buf`.
+///
+/// Columns of the rows that were projected away are decoded as nulls. The
+/// errors are always needed, so it's an error for them to be projected away.
+pub(crate) fn source_data_decode_columns<B: BufMut>(
+    cols: &PartColumns,
+    idx: usize,
+    buf: &mut B,
+) -> Result<(), String> {
+    let mut cols = cols.cols.iter();
+    let err_col = match cols.next() {
+        Some((name, Some(DynColumn::Bytes(col)))) if name == ERR_COL => col,
+        Some((name, None)) if name == ERR_COL => {
+            return Err(format!("{} column can't be projected away", ERR_COL))
+        }
+        _ => return Err(format!("missing {} column", ERR_COL)),
+    };
+    let data = match err_col.get(idx) {
+        Some(err) => {
+            let err = ProtoDataflowError::decode(err).map_err(|err| err.to_string())?;
+            SourceData(Err(err.into_rust().map_err(|err| err.to_string())?))
+        }
+        None => {
+            let mut row = Row::default();
+            let mut packer = row.packer();
+            for (_name, col) in cols {
+                match col {
+                    Some(col) => pack_datum(col, idx, &mut packer)?,
+                    None => packer.push(Datum::Null),
+                }
+            }
+            SourceData(Ok(row))
+        }
+    };
+    data.encode(buf);
+    Ok(())
+}
+
+/// Returns the persist key columns needed to read the given columns of a
+/// relation stored as [SourceData].
+pub fn source_data_key_projection(demand: impl IntoIterator<Item = usize>) -> BTreeSet<String> {
+    std::iter::once(ERR_COL.to_owned())
+        .chain(demand.into_iter().map(col_name))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_build_info::DUMMY_BUILD_INFO;
+    use mz_expr::EvalError;
+    use mz_ore::metrics::MetricsRegistry;
+    use mz_ore::now::SYSTEM_TIME;
+    use mz_persist_client::cache::PersistClientCache;
+    use mz_persist_client::{PersistConfig, PersistLocation, ShardId};
+    use timely::progress::Antichain;
+
+    use crate::types::errors::DataflowError;
+
+    use super::*;
+
+    fn encode(data: &[SourceData]) -> Vec<Vec<u8>> {
+        data.iter()
+            .map(|x| {
+                let mut buf = Vec::new();
+                x.encode(&mut buf);
+                buf
+            })
+            .collect()
+    }
+
+    fn decode(cols: &PartColumns) -> Vec<Vec<u8>> {
+        (0..cols.len)
+            .map(|idx| {
+                let mut buf = Vec::new();
+                source_data_decode_columns(cols, idx, &mut buf).expect("decodable");
+                buf
+            })
+            .collect()
+    }
+
+    #[test]
+    fn source_data_columns_roundtrip() {
+        let err = SourceData(Err(DataflowError::EvalError(EvalError::DivisionByZero)));
+        let data = vec![
+            err.clone(),
+            SourceData(Ok(Row::pack_slice(&[Datum::Int64(1), Datum::String("a")]))),
+            SourceData(Ok(Row::pack_slice(&[Datum::Null, Datum::String("b")]))),
+            err,
+        ];
+        let encoded = encode(&data);
+        let cols = source_data_encode_columns(encoded.iter().map(|x| x.as_slice()))
+            .expect("encodable")
+            .expect("supported");
+        let names = cols
+            .cols
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["err", "c0", "c1"]);
+        // Columns are typed by their datums, and nulls and errors are nulls.
+        match cols.col("c0") {
+            Ok(Some(DynColumn::I64(col))) => assert_eq!(col, &vec![None, Some(1), None, None]),
+            col => panic!("expected an i64 column: {:?}", col),
+        }
+        assert!(matches!(cols.col("c1"), Ok(Some(DynColumn::String(_)))));
+        // Reassembly must produce exactly the original bytes.
+        assert_eq!(decode(&cols), encoded);
+
+        // Columns that are projected away are decoded as nulls.
+        let projection = source_data_key_projection([1]);
+        let mut projected = cols.clone();
+        for (name, col) in projected.cols.iter_mut() {
+            if !projection.contains(name) {
+                *col = None;
+            }
+        }
+        let expected = encode(&[
+            data[0].clone(),
+            SourceData(Ok(Row::pack_slice(&[Datum::Null, Datum::String("a")]))),
+            SourceData(Ok(Row::pack_slice(&[Datum::Null, Datum::String("b")]))),
+            data[3].clone(),
+        ]);
+        assert_eq!(decode(&projected), expected);
+
+        // Columns of datums without a columnar type, or of mixed types, are
+        // stored as bytes.
+        let mixed = encode(&[
+            SourceData(Ok(Row::pack_slice(&[Datum::Int64(1), Datum::Bytes(b"y")]))),
+            SourceData(Ok(Row::pack_slice(&[Datum::String("x"), Datum::Null]))),
+        ]);
+        let cols = source_data_encode_columns(mixed.iter().map(|x| x.as_slice()))
+            .expect("encodable")
+            .expect("supported");
+        assert!(matches!(cols.col("c0"), Ok(Some(DynColumn::Bytes(_)))));
+        assert!(matches!(cols.col("c1"), Ok(Some(DynColumn::Bytes(_)))));
+        assert_eq!(decode(&cols), mixed);
+
+        // Rows of different arities can't be split into columns.
+        let encoded = encode(&[
+            SourceData(Ok(Row::pack_slice(&[Datum::Int64(1)]))),
+            SourceData(Ok(Row::pack_slice(&[Datum::Int64(1), Datum::Int64(2)]))),
+        ]);
+        assert_eq!(
+            source_data_encode_columns(encoded.iter().map(|x| x.as_slice())),
+            Ok(None)
+        );
+    }
+
+    #[tokio::test]
+    async fn source_data_structured_parts_roundtrip() {
+        let cfg = PersistConfig::new(&DUMMY_BUILD_INFO, SYSTEM_TIME.clone());
+        let dynamic = std::sync::Arc::clone(&cfg.dynamic);
+        let mut cache = PersistClientCache::new(cfg, &MetricsRegistry::new());
+        let client = cache
+            .open(PersistLocation {
+                blob_uri: "mem://".to_owned(),
+                consensus_uri: "mem://".to_owned(),
+            })
+            .await
+            .expect("client construction failed");
+        let (mut write, mut read) = client
+            .open::<SourceData, (), u64, i64>(ShardId::new(), "test")
+            .await
+            .expect("codecs should match");
+
+        let err = SourceData(Err(DataflowError::EvalError(EvalError::DivisionByZero)));
+        let data = vec![
+            ((err, ()), 0, 1),
+            (
+                (
+                    SourceData(Ok(Row::pack_slice(&[
+                        Datum::Int64(1),
+                        Datum::String("a"),
+                        Datum::Float64(1.5.into()),
+                    ]))),
+                    (),
+                ),
+                0,
+                1,
+            ),
+            (
+                (
+                    SourceData(Ok(Row::pack_slice(&[
+                        Datum::Null,
+                        Datum::String("b"),
+                        Datum::Bytes(b"c"),
+                    ]))),
+                    (),
+                ),
+                1,
+                -1,
+            ),
+        ];
+
+        // Write a structured batch, then one in the row format, and read them
+        // both back.
+        dynamic.set_structured_parts_enabled(true);
+        write
+            .compare_and_append(&data[..2], Antichain::from_elem(0), Antichain::from_elem(1))
+            .await
+            .expect("invalid usage")
+            .expect("unexpected upper");
+        dynamic.set_structured_parts_enabled(false);
+        write
+            .compare_and_append(&data[2..], Antichain::from_elem(1), Antichain::from_elem(2))
+            .await
+            .expect("invalid usage")
+            .expect("unexpected upper");
+
+        let mut actual = read
+            .snapshot_and_fetch(Antichain::from_elem(1))
+            .await
+            .expect("as_of should be readable");
+        actual.sort();
+        let mut expected = data
+            .into_iter()
+            .map(|((k, v), _t, d)| ((Ok(k), Ok(v)), 1, d))
+            .collect::<Vec<_>>();
+        expected.sort();
+        assert_eq!(actual, expected);
+    }
+}
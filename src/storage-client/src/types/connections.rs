@@ -157,6 +157,7 @@ pub enum Connection {
     Kafka(KafkaConnection),
     Csr(CsrConnection),
     Postgres(PostgresConnection),
+    MySql(MySqlConnection),
     Ssh(SshConnection),
     Aws(AwsConfig),
     AwsPrivatelink(AwsPrivatelinkConnection),
@@ -843,6 +844,169 @@ impl Arbitrary for PostgresConnection {
     }
 }
 
+/// A connection to a MySQL server.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct MySqlConnection {
+    /// The hostname of the server.
+    pub host: String,
+    /// The port of the server.
+    pub port: u16,
+    /// The username to authenticate as.
+    pub user: StringOrSecret,
+    /// An optional password for authentication.
+    pub password: Option<GlobalId>,
+    /// A tunnel through which to route traffic.
+    pub tunnel: Tunnel,
+    /// Whether to use TLS for encryption, verification, or both.
+    pub tls_mode: MySqlSslMode,
+}
+
+impl MySqlConnection {
+    pub async fn config(
+        &self,
+        secrets_reader: &dyn mz_secrets::SecretsReader,
+    ) -> Result<mz_mysql_util::Config, anyhow::Error> {
+        let user = self.user.get_string(secrets_reader).await?;
+        let password = match self.password {
+            Some(password) => Some(secrets_reader.read_string(password).await?),
+            None => None,
+        };
+
+        let tunnel = match &self.tunnel {
+            Tunnel::Direct => mz_mysql_util::TunnelConfig::Direct,
+            Tunnel::Ssh(SshTunnel {
+                connection_id,
+                connection,
+            }) => {
+                let secret = secrets_reader.read(*connection_id).await?;
+                let key_set = SshKeyPairSet::from_bytes(&secret)?;
+                let key_pair = key_set.primary().clone();
+                mz_mysql_util::TunnelConfig::Ssh(SshTunnelConfig {
+                    host: connection.host.clone(),
+                    port: connection.port,
+                    user: connection.user.clone(),
+                    key_pair,
+                })
+            }
+            Tunnel::AwsPrivatelink(connection) => {
+                assert!(connection.port.is_none());
+                mz_mysql_util::TunnelConfig::AwsPrivatelink {
+                    connection_id: connection.connection_id,
+                }
+            }
+        };
+
+        Ok(mz_mysql_util::Config::new(
+            self.host.clone(),
+            self.port,
+            user,
+            password,
+            self.tls_mode.into(),
+            tunnel,
+        )?)
+    }
+}
+
+impl RustType<ProtoMySqlConnection> for MySqlConnection {
+    fn into_proto(&self) -> ProtoMySqlConnection {
+        ProtoMySqlConnection {
+            host: self.host.into_proto(),
+            port: self.port.into_proto(),
+            user: Some(self.user.into_proto()),
+            password: self.password.into_proto(),
+            tls_mode: Some(self.tls_mode.into_proto()),
+            tunnel: Some(self.tunnel.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: ProtoMySqlConnection) -> Result<Self, TryFromProtoError> {
+        Ok(MySqlConnection {
+            host: proto.host,
+            port: proto.port.into_rust()?,
+            user: proto.user.into_rust_if_some("ProtoMySqlConnection::user")?,
+            password: proto.password.into_rust()?,
+            tunnel: proto
+                .tunnel
+                .into_rust_if_some("ProtoMySqlConnection::tunnel")?,
+            tls_mode: proto
+                .tls_mode
+                .into_rust_if_some("ProtoMySqlConnection::tls_mode")?,
+        })
+    }
+}
+
+impl Arbitrary for MySqlConnection {
+    type Strategy = BoxedStrategy<Self>;
+    type Parameters = ();
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<String>(),
+            any::<u16>(),
+            any::<StringOrSecret>(),
+            any::<Option<GlobalId>>(),
+            any::<Tunnel>(),
+            any::<MySqlSslMode>(),
+        )
+            .prop_map(
+                |(host, port, user, password, tunnel, tls_mode)| MySqlConnection {
+                    host,
+                    port,
+                    user,
+                    password,
+                    tunnel,
+                    tls_mode,
+                },
+            )
+            .boxed()
+    }
+}
+
+/// The TLS requirements of a [`MySqlConnection`].
+#[derive(Arbitrary, Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum MySqlSslMode {
+    Disabled,
+    Required,
+    VerifyCa,
+    VerifyIdentity,
+}
+
+impl From<MySqlSslMode> for mz_mysql_util::SslMode {
+    fn from(mode: MySqlSslMode) -> Self {
+        match mode {
+            MySqlSslMode::Disabled => mz_mysql_util::SslMode::Disabled,
+            MySqlSslMode::Required => mz_mysql_util::SslMode::Required,
+            MySqlSslMode::VerifyCa => mz_mysql_util::SslMode::VerifyCa,
+            MySqlSslMode::VerifyIdentity => mz_mysql_util::SslMode::VerifyIdentity,
+        }
+    }
+}
+
+impl RustType<ProtoMySqlSslMode> for MySqlSslMode {
+    fn into_proto(&self) -> ProtoMySqlSslMode {
+        use proto_my_sql_ssl_mode::Kind;
+        ProtoMySqlSslMode {
+            kind: Some(match self {
+                MySqlSslMode::Disabled => Kind::Disabled(()),
+                MySqlSslMode::Required => Kind::Required(()),
+                MySqlSslMode::VerifyCa => Kind::VerifyCa(()),
+                MySqlSslMode::VerifyIdentity => Kind::VerifyIdentity(()),
+            }),
+        }
+    }
+
+    fn from_proto(proto: ProtoMySqlSslMode) -> Result<Self, TryFromProtoError> {
+        use proto_my_sql_ssl_mode::Kind;
+        Ok(match proto.kind {
+            Some(Kind::Disabled(())) => MySqlSslMode::Disabled,
+            Some(Kind::Required(())) => MySqlSslMode::Required,
+            Some(Kind::VerifyCa(())) => MySqlSslMode::VerifyCa,
+            Some(Kind::VerifyIdentity(())) => MySqlSslMode::VerifyIdentity,
+            None => return Err(TryFromProtoError::missing_field("ProtoMySqlSslMode::kind")),
+        })
+    }
+}
+
 /// Specifies how to tunnel a connection.
 #[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum Tunnel {
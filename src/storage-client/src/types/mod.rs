@@ -9,8 +9,10 @@
 
 #![allow(missing_docs)]
 
+pub mod columnar;
 pub mod connections;
 pub mod errors;
 pub mod hosts;
 pub mod sinks;
 pub mod sources;
+pub mod stats;
@@ -286,6 +286,9 @@ pub struct KafkaSinkConnection {
     pub relation_key_indices: Option<Vec<usize>>,
     pub value_desc: RelationDesc,
     pub published_schema_info: Option<PublishedSchemaInfo>,
+    /// The messages as which keys and values are encoded, if the sink uses the
+    /// Protobuf format.
+    pub protobuf_messages: Option<ProtobufSinkMessages>,
     pub progress: KafkaSinkProgressConnection,
     // Maximum number of records the sink will attempt to send each time it is
     // invoked
@@ -301,6 +304,7 @@ proptest::prop_compose! {
         relation_key_indices in any::<Option<Vec<usize>>>(),
         value_desc in any::<RelationDesc>(),
         published_schema_info in any::<Option<PublishedSchemaInfo>>(),
+        protobuf_messages in any::<Option<ProtobufSinkMessages>>(),
         progress in any::<KafkaSinkProgressConnection>(),
         fuel in any::<usize>(),
     ) -> KafkaSinkConnection {
@@ -312,6 +316,7 @@ proptest::prop_compose! {
             relation_key_indices,
             value_desc,
             published_schema_info,
+            protobuf_messages,
             progress,
             fuel,
         }
@@ -371,6 +376,7 @@ impl RustType<ProtoKafkaSinkConnection> for KafkaSinkConnection {
             relation_key_indices: self.relation_key_indices.into_proto(),
             value_desc: Some(self.value_desc.into_proto()),
             published_schema_info: self.published_schema_info.into_proto(),
+            protobuf_messages: self.protobuf_messages.into_proto(),
             progress: Some(self.progress.into_proto()),
             fuel: self.fuel.into_proto(),
         }
@@ -391,6 +397,7 @@ impl RustType<ProtoKafkaSinkConnection> for KafkaSinkConnection {
                 .value_desc
                 .into_rust_if_some("ProtoKafkaSinkConnection::addrs")?,
             published_schema_info: proto.published_schema_info.into_rust()?,
+            protobuf_messages: proto.protobuf_messages.into_rust()?,
             progress: proto
                 .progress
                 .into_rust_if_some("ProtoKafkaSinkConnection::progress")?,
@@ -399,6 +406,56 @@ impl RustType<ProtoKafkaSinkConnection> for KafkaSinkConnection {
     }
 }
 
+/// A Protobuf message, identified by its fully qualified name within an
+/// encoded `FileDescriptorSet`.
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ProtobufSinkMessage {
+    pub descriptors: Vec<u8>,
+    pub message_name: String,
+}
+
+impl RustType<ProtoProtobufSinkMessage> for ProtobufSinkMessage {
+    fn into_proto(&self) -> ProtoProtobufSinkMessage {
+        ProtoProtobufSinkMessage {
+            descriptors: self.descriptors.clone(),
+            message_name: self.message_name.clone(),
+        }
+    }
+
+    fn from_proto(proto: ProtoProtobufSinkMessage) -> Result<Self, TryFromProtoError> {
+        Ok(ProtobufSinkMessage {
+            descriptors: proto.descriptors,
+            message_name: proto.message_name,
+        })
+    }
+}
+
+/// The Protobuf messages as which a sink encodes its keys and values.
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ProtobufSinkMessages {
+    /// The message for keys. If absent, keys are encoded as the value message.
+    pub key: Option<ProtobufSinkMessage>,
+    pub value: ProtobufSinkMessage,
+}
+
+impl RustType<ProtoProtobufSinkMessages> for ProtobufSinkMessages {
+    fn into_proto(&self) -> ProtoProtobufSinkMessages {
+        ProtoProtobufSinkMessages {
+            key: self.key.into_proto(),
+            value: Some(self.value.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: ProtoProtobufSinkMessages) -> Result<Self, TryFromProtoError> {
+        Ok(ProtobufSinkMessages {
+            key: proto.key.into_rust()?,
+            value: proto
+                .value
+                .into_rust_if_some("ProtoProtobufSinkMessages::value")?,
+        })
+    }
+}
+
 /// TODO(JLDLaughlin): Documentation.
 #[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct PublishedSchemaInfo {
@@ -482,4 +539,11 @@ pub enum KafkaSinkFormat {
         csr_connection: CsrConnection,
     },
     Json,
+    Protobuf {
+        messages: ProtobufSinkMessages,
+        /// The schema registry from which the schema IDs of the messages are
+        /// fetched. If absent, messages are not framed in the Confluent wire
+        /// format.
+        csr_connection: Option<CsrConnection>,
+    },
 }
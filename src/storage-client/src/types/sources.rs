@@ -34,6 +34,8 @@ use mz_expr::MirScalarExpr;
 use mz_ore::now::NowFn;
 use mz_persist_client::cache::PersistClientCache;
 use mz_persist_client::write::WriteHandle;
+use mz_persist_types::columnar::PartColumns;
+use mz_persist_types::stats::StructStats;
 use mz_persist_types::{Codec, Codec64};
 use mz_proto::{IntoRustIfSome, ProtoMapEntry, ProtoType, RustType, TryFromProtoError};
 use mz_repr::adt::numeric::NumericMaxScale;
@@ -41,7 +43,7 @@ use mz_repr::{ColumnType, Diff, GlobalId, RelationDesc, RelationType, Row, Scala
 
 use crate::controller::{CollectionMetadata, ResumptionFrontierCalculator};
 use crate::types::connections::aws::AwsConfig;
-use crate::types::connections::{KafkaConnection, PostgresConnection};
+use crate::types::connections::{KafkaConnection, MySqlConnection, PostgresConnection};
 use crate::types::errors::DataflowError;
 use crate::types::hosts::StorageHostConfig;
 
@@ -97,6 +99,8 @@ impl<T: Timestamp + Lattice + Codec64> ResumptionFrontierCalculator<T>
                 data_shard,
                 // The status shard only contains non-definite status updates
                 status_shard: _,
+                // Sources write every column of their output.
+                missing_values: _,
             } = &export.storage_metadata;
             let handle = client_cache
                 .open(persist_location.clone())
@@ -117,6 +121,7 @@ impl<T: Timestamp + Lattice + Codec64> ResumptionFrontierCalculator<T>
             data_shard: _,
             // The status shard only contains non-definite status updates
             status_shard: _,
+            missing_values: _,
         } = &self.ingestion_metadata;
         let remap_handle = client_cache
             .open(persist_location.clone())
@@ -1405,6 +1410,11 @@ impl SourceDesc {
                 connection: GenericSourceConnection::Postgres(_),
                 ..
             } => false,
+            // MySQL can produce retractions (deletes)
+            SourceDesc {
+                connection: GenericSourceConnection::MySql(_),
+                ..
+            } => false,
             // Loadgen can produce retractions (deletes)
             SourceDesc {
                 connection: GenericSourceConnection::LoadGenerator(_),
@@ -1442,6 +1452,7 @@ impl SourceDesc {
             GenericSourceConnection::Postgres(connection) => {
                 connection.publication_details.tables.len()
             }
+            GenericSourceConnection::MySql(connection) => connection.details.tables.len(),
         };
         // Every ingestion produces a main stream plus subsource streams
         subsources + 1
@@ -1462,6 +1473,7 @@ pub enum GenericSourceConnection {
     Kinesis(KinesisSourceConnection),
     S3(S3SourceConnection),
     Postgres(PostgresSourceConnection),
+    MySql(MySqlSourceConnection),
     LoadGenerator(LoadGeneratorSourceConnection),
     TestScript(TestScriptSourceConnection),
 }
@@ -1473,7 +1485,8 @@ impl GenericSourceConnection {
             Kafka(KafkaSourceConnection { connection_id, .. })
             | Kinesis(KinesisSourceConnection { connection_id, .. })
             | S3(S3SourceConnection { connection_id, .. })
-            | Postgres(PostgresSourceConnection { connection_id, .. }) => Some(*connection_id),
+            | Postgres(PostgresSourceConnection { connection_id, .. })
+            | MySql(MySqlSourceConnection { connection_id, .. }) => Some(*connection_id),
             LoadGenerator(_) | TestScript(_) => None,
         }
     }
@@ -1490,6 +1503,7 @@ impl RustType<ProtoSourceConnection> for GenericSourceConnection {
                 GenericSourceConnection::Postgres(postgres) => {
                     Kind::Postgres(postgres.into_proto())
                 }
+                GenericSourceConnection::MySql(mysql) => Kind::Mysql(mysql.into_proto()),
                 GenericSourceConnection::LoadGenerator(loadgen) => {
                     Kind::Loadgen(loadgen.into_proto())
                 }
@@ -1510,6 +1524,7 @@ impl RustType<ProtoSourceConnection> for GenericSourceConnection {
             Kind::Kinesis(kinesis) => GenericSourceConnection::Kinesis(kinesis.into_rust()?),
             Kind::S3(s3) => GenericSourceConnection::S3(s3.into_rust()?),
             Kind::Postgres(postgres) => GenericSourceConnection::Postgres(postgres.into_rust()?),
+            Kind::Mysql(mysql) => GenericSourceConnection::MySql(mysql.into_rust()?),
             Kind::Loadgen(loadgen) => GenericSourceConnection::LoadGenerator(loadgen.into_rust()?),
             Kind::Testscript(testscript) => {
                 GenericSourceConnection::TestScript(testscript.into_rust()?)
@@ -1580,6 +1595,7 @@ impl GenericSourceConnection {
             Self::Kinesis(_) => vec![],
             Self::S3(_) => vec![],
             Self::Postgres(_) => vec![],
+            Self::MySql(_) => vec![],
             Self::LoadGenerator(_) => vec![],
             Self::TestScript(_) => vec![],
         }
@@ -1617,6 +1633,7 @@ impl GenericSourceConnection {
             GenericSourceConnection::Kinesis(_)
             | GenericSourceConnection::S3(_)
             | GenericSourceConnection::Postgres(_)
+            | GenericSourceConnection::MySql(_)
             | GenericSourceConnection::LoadGenerator(_)
             | GenericSourceConnection::TestScript(_) => Vec::new(),
         }
@@ -1629,6 +1646,7 @@ impl GenericSourceConnection {
             GenericSourceConnection::Kinesis(c) => c.name(),
             GenericSourceConnection::S3(c) => c.name(),
             GenericSourceConnection::Postgres(c) => c.name(),
+            GenericSourceConnection::MySql(c) => c.name(),
             GenericSourceConnection::LoadGenerator(c) => c.name(),
             GenericSourceConnection::TestScript(c) => c.name(),
         }
@@ -1647,6 +1665,7 @@ impl GenericSourceConnection {
             }
             GenericSourceConnection::S3(_) => None,
             GenericSourceConnection::Postgres(_) => None,
+            GenericSourceConnection::MySql(_) => None,
             GenericSourceConnection::LoadGenerator(_) => None,
             GenericSourceConnection::TestScript(_) => None,
         }
@@ -1828,6 +1847,74 @@ impl RustType<ProtoPostgresSourcePublicationDetails> for PostgresSourcePublicati
     }
 }
 
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MySqlSourceConnection {
+    pub connection_id: GlobalId,
+    pub connection: MySqlConnection,
+    pub details: MySqlSourceDetails,
+}
+
+impl SourceConnection for MySqlSourceConnection {
+    fn name(&self) -> &'static str {
+        "mysql"
+    }
+}
+
+impl RustType<ProtoMySqlSourceConnection> for MySqlSourceConnection {
+    fn into_proto(&self) -> ProtoMySqlSourceConnection {
+        ProtoMySqlSourceConnection {
+            connection: Some(self.connection.into_proto()),
+            connection_id: Some(self.connection_id.into_proto()),
+            details: Some(self.details.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: ProtoMySqlSourceConnection) -> Result<Self, TryFromProtoError> {
+        Ok(MySqlSourceConnection {
+            connection: proto
+                .connection
+                .into_rust_if_some("ProtoMySqlSourceConnection::connection")?,
+            connection_id: proto
+                .connection_id
+                .into_rust_if_some("ProtoMySqlSourceConnection::connection_id")?,
+            details: proto
+                .details
+                .into_rust_if_some("ProtoMySqlSourceConnection::details")?,
+        })
+    }
+}
+
+/// The upstream state of a MySQL source, captured during purification.
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MySqlSourceDetails {
+    /// The tables to replicate. The table at index `i` is written to the
+    /// source's output `i + 1`.
+    pub tables: Vec<mz_mysql_util::desc::MySqlTableDesc>,
+    /// The server ID that the source identifies itself with when reading the
+    /// binary log. Must be unique among the upstream server's replicas.
+    pub server_id: u32,
+}
+
+impl RustType<ProtoMySqlSourceDetails> for MySqlSourceDetails {
+    fn into_proto(&self) -> ProtoMySqlSourceDetails {
+        ProtoMySqlSourceDetails {
+            tables: self.tables.iter().map(|t| t.into_proto()).collect(),
+            server_id: self.server_id,
+        }
+    }
+
+    fn from_proto(proto: ProtoMySqlSourceDetails) -> Result<Self, TryFromProtoError> {
+        Ok(MySqlSourceDetails {
+            tables: proto
+                .tables
+                .into_iter()
+                .map(mz_mysql_util::desc::MySqlTableDesc::from_proto)
+                .collect::<Result<_, _>>()?,
+            server_id: proto.server_id,
+        })
+    }
+}
+
 #[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct LoadGeneratorSourceConnection {
     pub load_generator: LoadGenerator,
@@ -2324,6 +2411,24 @@ impl Codec for SourceData {
         let proto = ProtoSourceData::decode(buf).map_err(|err| err.to_string())?;
         proto.into_rust().map_err(|err| err.to_string())
     }
+
+    fn stats<'a>(encoded: impl Iterator<Item = &'a [u8]>) -> Result<Option<StructStats>, String> {
+        crate::types::stats::source_data_stats(encoded)
+    }
+
+    fn encode_columns<'a>(
+        encoded: impl Iterator<Item = &'a [u8]>,
+    ) -> Result<Option<PartColumns>, String> {
+        crate::types::columnar::source_data_encode_columns(encoded)
+    }
+
+    fn decode_columns<B: BufMut>(
+        cols: &PartColumns,
+        idx: usize,
+        buf: &mut B,
+    ) -> Result<(), String> {
+        crate::types::columnar::source_data_decode_columns(cols, idx, buf)
+    }
 }
 
 /// A `SourceToken` manages interest in a source.
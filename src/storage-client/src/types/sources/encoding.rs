@@ -14,7 +14,7 @@ use proptest::prelude::{Arbitrary, BoxedStrategy, Strategy};
 use proptest_derive::Arbitrary;
 use serde::{Deserialize, Serialize};
 
-use mz_interchange::{avro, protobuf};
+use mz_interchange::{avro, parquet, protobuf};
 use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
 use mz_repr::adt::regex::any_regex;
 use mz_repr::{ColumnType, RelationDesc, ScalarType};
@@ -139,6 +139,9 @@ pub enum DataEncodingInner {
     Bytes,
     Text,
     RowCodec(RelationDesc),
+    /// Newline-delimited JSON, where each line becomes a single `jsonb` datum.
+    Json,
+    Parquet(ParquetEncoding),
 }
 
 impl RustType<ProtoDataEncodingInner> for DataEncodingInner {
@@ -153,6 +156,8 @@ impl RustType<ProtoDataEncodingInner> for DataEncodingInner {
                 DataEncodingInner::Bytes => Kind::Bytes(()),
                 DataEncodingInner::Text => Kind::Text(()),
                 DataEncodingInner::RowCodec(e) => Kind::RowCodec(e.into_proto()),
+                DataEncodingInner::Json => Kind::Json(()),
+                DataEncodingInner::Parquet(e) => Kind::Parquet(e.into_proto()),
             }),
         }
     }
@@ -170,6 +175,8 @@ impl RustType<ProtoDataEncodingInner> for DataEncodingInner {
             Kind::Bytes(()) => DataEncodingInner::Bytes,
             Kind::Text(()) => DataEncodingInner::Text,
             Kind::RowCodec(e) => DataEncodingInner::RowCodec(e.into_rust()?),
+            Kind::Json(()) => DataEncodingInner::Json,
+            Kind::Parquet(e) => DataEncodingInner::Parquet(e.into_rust()?),
         })
     }
 }
@@ -271,6 +278,13 @@ impl DataEncoding {
                 RelationDesc::empty().with_column("text", ScalarType::String.nullable(false))
             }
             DataEncodingInner::RowCodec(desc) => desc.clone(),
+            DataEncodingInner::Json => {
+                RelationDesc::empty().with_column("data", ScalarType::Jsonb.nullable(false))
+            }
+            DataEncodingInner::Parquet(ParquetEncoding { schema }) => {
+                let fields = parquet::parse_schema(schema).context("validating parquet schema")?;
+                parquet::schema_to_relationdesc(&fields).context("validating parquet schema")?
+            }
         };
 
         if self.force_nullable_columns {
@@ -292,6 +306,8 @@ impl DataEncoding {
             DataEncodingInner::Csv(_) => "Csv",
             DataEncodingInner::Text => "Text",
             DataEncodingInner::RowCodec(_) => "RowCodec",
+            DataEncodingInner::Json => "Json",
+            DataEncodingInner::Parquet(_) => "Parquet",
         }
     }
 }
@@ -348,6 +364,30 @@ impl RustType<ProtoProtobufEncoding> for ProtobufEncoding {
     }
 }
 
+/// Encoding in Parquet format.
+///
+/// The schema is a Parquet message type, as printed by `parquet-tools schema`,
+/// and describes the columns to read from each object. Objects may contain
+/// additional columns, which are ignored.
+#[derive(Arbitrary, Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ParquetEncoding {
+    pub schema: String,
+}
+
+impl RustType<ProtoParquetEncoding> for ParquetEncoding {
+    fn into_proto(&self) -> ProtoParquetEncoding {
+        ProtoParquetEncoding {
+            schema: self.schema.clone(),
+        }
+    }
+
+    fn from_proto(proto: ProtoParquetEncoding) -> Result<Self, TryFromProtoError> {
+        Ok(ParquetEncoding {
+            schema: proto.schema,
+        })
+    }
+}
+
 /// Arguments necessary to define how to decode from CSV format
 #[derive(Arbitrary, Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
 pub struct CsvEncoding {
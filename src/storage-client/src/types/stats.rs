@@ -0,0 +1,372 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Statistics about the [SourceData] stored in persist parts, and their use to
+//! skip fetching parts during filter pushdown.
+
+use mz_expr::{BinaryFunc, MfpPlan, MirScalarExpr, UnaryFunc, VariadicFunc};
+use mz_persist_types::stats::{ColumnStats, PartStats, StructStats};
+use mz_persist_types::Codec;
+use mz_repr::{Datum, Row};
+
+use crate::types::sources::SourceData;
+
+/// The maximum encoded size of a column's lower or upper bound.
+///
+/// Columns whose bounds are larger than this (e.g. long strings or large jsonb
+/// values) record only their null count, to keep the stats that persist stores
+/// in its state small.
+const MAX_BOUND_LEN: usize = 100;
+
+/// Computes [StructStats] over the given encoded [SourceData].
+///
+/// Returns `Ok(None)` if any of the values is an error, because parts that
+/// contain errors must never be skipped, or if the rows don't all have the same
+/// number of columns.
+pub(crate) fn source_data_stats<'a>(
+    encoded: impl Iterator<Item = &'a [u8]>,
+) -> Result<Option<StructStats>, String> {
+    let mut len = 0;
+    let mut cols: Option<Vec<ColumnStatsBuilder>> = None;
+    for buf in encoded {
+        let row = match SourceData::decode(buf)?.0 {
+            Ok(row) => row,
+            Err(_) => return Ok(None),
+        };
+        let arity = row.iter().count();
+        let cols = cols.get_or_insert_with(|| {
+            std::iter::repeat_with(ColumnStatsBuilder::default)
+                .take(arity)
+                .collect()
+        });
+        if arity != cols.len() {
+            return Ok(None);
+        }
+        for (col, datum) in cols.iter_mut().zip(row.iter()) {
+            col.push(datum);
+        }
+        len += 1;
+    }
+    let cols = cols
+        .unwrap_or_default()
+        .into_iter()
+        .map(ColumnStatsBuilder::finish)
+        .collect();
+    Ok(Some(StructStats { len, cols }))
+}
+
+#[derive(Default)]
+struct ColumnStatsBuilder {
+    lower: Option<Row>,
+    upper: Option<Row>,
+    nulls: usize,
+}
+
+impl ColumnStatsBuilder {
+    fn push(&mut self, datum: Datum) {
+        if datum.is_null() {
+            self.nulls += 1;
+            return;
+        }
+        if self
+            .lower
+            .as_ref()
+            .map_or(true, |x| datum < x.unpack_first())
+        {
+            self.lower = Some(Row::pack_slice(&[datum]));
+        }
+        if self
+            .upper
+            .as_ref()
+            .map_or(true, |x| datum > x.unpack_first())
+        {
+            self.upper = Some(Row::pack_slice(&[datum]));
+        }
+    }
+
+    fn finish(self) -> ColumnStats {
+        let encode = |row: Row| {
+            let mut buf = Vec::new();
+            row.encode(&mut buf);
+            buf
+        };
+        let bounds = match (self.lower, self.upper) {
+            (Some(lower), Some(upper)) => Some((encode(lower), encode(upper))),
+            _ => None,
+        };
+        let bounds = bounds
+            .filter(|(lower, upper)| lower.len() <= MAX_BOUND_LEN && upper.len() <= MAX_BOUND_LEN);
+        ColumnStats {
+            bounds,
+            nulls: self.nulls,
+        }
+    }
+}
+
+/// The stats of a single persist part containing [SourceData], interpreted as
+/// the rows of a relation.
+#[derive(Debug)]
+pub struct RelationPartStats<'a> {
+    stats: &'a StructStats,
+}
+
+impl<'a> RelationPartStats<'a> {
+    pub fn new(stats: &'a PartStats) -> Self {
+        RelationPartStats { stats: &stats.key }
+    }
+
+    /// Returns whether any row in the part could make it through the
+    /// non-temporal part of `mfp`, or cause it to produce an error.
+    ///
+    /// If this returns false, the part can be skipped without changing the
+    /// output of `mfp`. The analysis is conservative: predicates it doesn't
+    /// understand are assumed to match.
+    pub fn may_match_mfp(&self, mfp: &MfpPlan) -> bool {
+        let mfp = mfp.non_temporal();
+        for (support, predicate) in mfp.predicates.iter() {
+            // Evaluation computes map expressions lazily, as predicates need
+            // them. Once one of those could error, skipping the part might
+            // swallow that error.
+            let needed = support.saturating_sub(mfp.input_arity);
+            if mfp.expressions[..needed].iter().any(|e| e.could_error()) {
+                return true;
+            }
+            if !self.may_be_true(predicate, mfp.input_arity) {
+                return false;
+            }
+            // Later predicates are only evaluated for rows that pass this one,
+            // so they can't be used to skip the part if this one could error.
+            if predicate.could_error() {
+                return true;
+            }
+        }
+        true
+    }
+
+    /// Returns whether `expr` could evaluate to true for some row in the part.
+    fn may_be_true(&self, expr: &MirScalarExpr, input_arity: usize) -> bool {
+        match expr {
+            MirScalarExpr::Literal(Ok(row), _) => row.unpack_first() == Datum::True,
+            MirScalarExpr::CallVariadic {
+                func: VariadicFunc::And,
+                exprs,
+            } => exprs.iter().all(|e| self.may_be_true(e, input_arity)),
+            MirScalarExpr::CallVariadic {
+                func: VariadicFunc::Or,
+                exprs,
+            } => exprs.iter().any(|e| self.may_be_true(e, input_arity)),
+            MirScalarExpr::CallUnary {
+                func: UnaryFunc::IsNull(_),
+                expr,
+            } => match self.col(expr, input_arity) {
+                Some(col) => col.nulls > 0,
+                None => true,
+            },
+            MirScalarExpr::CallUnary {
+                func: UnaryFunc::Not(_),
+                expr,
+            } => match &**expr {
+                MirScalarExpr::CallUnary {
+                    func: UnaryFunc::IsNull(_),
+                    expr,
+                } => match self.col(expr, input_arity) {
+                    Some(col) => col.nulls < self.stats.len,
+                    None => true,
+                },
+                _ => true,
+            },
+            MirScalarExpr::CallBinary { func, expr1, expr2 } => {
+                // Normalize to `col <op> literal`.
+                let (func, col, lit) = match (expr1.as_literal(), expr2.as_literal()) {
+                    (None, Some(Ok(lit))) => (func.clone(), expr1, lit),
+                    (Some(Ok(lit)), None) => match func {
+                        BinaryFunc::Lt => (BinaryFunc::Gt, expr2, lit),
+                        BinaryFunc::Lte => (BinaryFunc::Gte, expr2, lit),
+                        BinaryFunc::Gt => (BinaryFunc::Lt, expr2, lit),
+                        BinaryFunc::Gte => (BinaryFunc::Lte, expr2, lit),
+                        func => (func.clone(), expr2, lit),
+                    },
+                    _ => return true,
+                };
+                let Some(col) = self.col(col, input_arity) else {
+                    return true;
+                };
+                self.may_compare_true(&func, col, lit)
+            }
+            _ => true,
+        }
+    }
+
+    /// Returns whether `<col> <func> <lit>` could be true for some row.
+    fn may_compare_true(&self, func: &BinaryFunc, col: &ColumnStats, lit: Datum) -> bool {
+        if !matches!(
+            func,
+            BinaryFunc::Eq | BinaryFunc::Lt | BinaryFunc::Lte | BinaryFunc::Gt | BinaryFunc::Gte
+        ) {
+            return true;
+        }
+        // Comparisons with null are never true.
+        if lit.is_null() {
+            return false;
+        }
+        let Some((lower, upper)) = &col.bounds else {
+            // Without bounds, we only know something if the column is entirely
+            // null, in which case the comparison is never true.
+            return col.nulls < self.stats.len;
+        };
+        let (Ok(lower), Ok(upper)) = (Row::decode(lower), Row::decode(upper)) else {
+            return true;
+        };
+        let (lower, upper) = (lower.unpack_first(), upper.unpack_first());
+        match func {
+            BinaryFunc::Eq => lower <= lit && lit <= upper,
+            BinaryFunc::Lt => lower < lit,
+            BinaryFunc::Lte => lower <= lit,
+            BinaryFunc::Gt => upper > lit,
+            BinaryFunc::Gte => upper >= lit,
+            _ => unreachable!("checked above"),
+        }
+    }
+
+    /// Returns the stats for `expr`, if it is a column of the input.
+    fn col(&self, expr: &MirScalarExpr, input_arity: usize) -> Option<&ColumnStats> {
+        // Columns beyond the input arity are computed by the plan, and columns
+        // beyond the part's arity were added after it was written and are
+        // filled in with defaults on read.
+        expr.as_column()
+            .filter(|col| *col < input_arity)
+            .and_then(|col| self.stats.cols.get(col))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_expr::{func, EvalError, MapFilterProject};
+    use mz_repr::ScalarType;
+
+    use crate::types::errors::DataflowError;
+
+    use super::*;
+
+    fn part(rows: &[Row]) -> PartStats {
+        let encoded: Vec<_> = rows
+            .iter()
+            .map(|row| {
+                let mut buf = Vec::new();
+                SourceData(Ok(row.clone())).encode(&mut buf);
+                buf
+            })
+            .collect();
+        let key = source_data_stats(encoded.iter().map(|x| x.as_slice()))
+            .unwrap()
+            .unwrap();
+        PartStats { key }
+    }
+
+    fn may_match(stats: &PartStats, predicate: MirScalarExpr) -> bool {
+        let mfp = MapFilterProject::new(2)
+            .filter(vec![predicate])
+            .into_plan()
+            .unwrap();
+        RelationPartStats::new(stats).may_match_mfp(&mfp)
+    }
+
+    #[test]
+    fn stats_bounds_and_nulls() {
+        let stats = part(&[
+            Row::pack_slice(&[Datum::Int64(3), Datum::Null]),
+            Row::pack_slice(&[Datum::Int64(7), Datum::Null]),
+            Row::pack_slice(&[Datum::Int64(5), Datum::Null]),
+        ]);
+        assert_eq!(stats.key.len, 3);
+        let (lower, upper) = stats.key.cols[0].bounds.clone().unwrap();
+        assert_eq!(Row::decode(&lower).unwrap().unpack_first(), Datum::Int64(3));
+        assert_eq!(Row::decode(&upper).unwrap().unpack_first(), Datum::Int64(7));
+        assert_eq!(stats.key.cols[0].nulls, 0);
+        assert_eq!(stats.key.cols[1].bounds, None);
+        assert_eq!(stats.key.cols[1].nulls, 3);
+
+        // Parts containing errors have no stats, so they're never skipped.
+        let mut buf = Vec::new();
+        SourceData(Err(DataflowError::EvalError(EvalError::DivisionByZero))).encode(&mut buf);
+        assert_eq!(source_data_stats([buf.as_slice()].into_iter()), Ok(None));
+    }
+
+    #[test]
+    fn mfp_pushdown() {
+        let stats = part(&[
+            Row::pack_slice(&[Datum::Int64(3), Datum::Null]),
+            Row::pack_slice(&[Datum::Int64(7), Datum::Null]),
+        ]);
+        let lit = |x| MirScalarExpr::literal_ok(Datum::Int64(x), ScalarType::Int64);
+        let col = MirScalarExpr::column;
+
+        assert!(may_match(
+            &stats,
+            col(0).call_binary(lit(5), BinaryFunc::Gt)
+        ));
+        assert!(!may_match(
+            &stats,
+            col(0).call_binary(lit(7), BinaryFunc::Gt)
+        ));
+        assert!(may_match(
+            &stats,
+            col(0).call_binary(lit(7), BinaryFunc::Gte)
+        ));
+        assert!(!may_match(
+            &stats,
+            col(0).call_binary(lit(3), BinaryFunc::Lt)
+        ));
+        assert!(may_match(
+            &stats,
+            col(0).call_binary(lit(4), BinaryFunc::Eq)
+        ));
+        assert!(!may_match(
+            &stats,
+            col(0).call_binary(lit(8), BinaryFunc::Eq)
+        ));
+        assert!(!may_match(
+            &stats,
+            lit(8).call_binary(col(0), BinaryFunc::Lt)
+        ));
+        assert!(may_match(
+            &stats,
+            lit(6).call_binary(col(0), BinaryFunc::Lt)
+        ));
+
+        // Column 1 is entirely null.
+        assert!(!may_match(
+            &stats,
+            col(1).call_binary(lit(0), BinaryFunc::Gt)
+        ));
+        assert!(!may_match(
+            &stats,
+            col(1)
+                .call_unary(UnaryFunc::IsNull(func::IsNull))
+                .call_unary(UnaryFunc::Not(func::Not))
+        ));
+        assert!(!may_match(
+            &stats,
+            col(0).call_unary(UnaryFunc::IsNull(func::IsNull))
+        ));
+
+        // A predicate that could error must not be skipped past.
+        let could_error = col(0)
+            .call_binary(col(0), BinaryFunc::DivInt64)
+            .call_binary(lit(0), BinaryFunc::Gt);
+        let mfp = MapFilterProject::new(2)
+            .filter(vec![
+                could_error,
+                col(0).call_binary(lit(100), BinaryFunc::Gt),
+            ])
+            .into_plan()
+            .unwrap();
+        assert!(RelationPartStats::new(&stats).may_match_mfp(&mfp));
+    }
+}
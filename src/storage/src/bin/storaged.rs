@@ -8,7 +8,9 @@
 // by the Apache License, Version 2.0.
 
 use std::env;
-use std::path::PathBuf;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
 use std::process;
 
 use anyhow::{bail, Context};
@@ -24,9 +26,11 @@ use mz_ore::metrics::MetricsRegistry;
 use mz_ore::netio::{Listener, SocketAddr};
 use mz_ore::now::SYSTEM_TIME;
 use mz_ore::tracing::TracingHandle;
+use mz_persist_client::encryption::BlobEncryptionCliArgs;
 use mz_pid_file::PidFile;
 use mz_service::grpc::GrpcServer;
 use mz_service::secrets::SecretsReaderCliArgs;
+use mz_storage::storage_state::UpsertConfig;
 use mz_storage_client::client::proto_storage_server::ProtoStorageServer;
 use mz_storage_client::types::connections::ConnectionContext;
 
@@ -76,6 +80,23 @@ struct Args {
     #[clap(long, env = "WORKERS", value_name = "N", default_value = "1")]
     workers: usize,
 
+    // === Upsert options. ===
+    /// A scratch directory on local disk, to which upsert sources may spill
+    /// their state. If unset, upsert state is kept entirely in memory.
+    ///
+    /// Orchestrators provision this directory for storage hosts.
+    #[clap(long, env = "SCRATCH_DIRECTORY", value_name = "PATH")]
+    scratch_directory: Option<PathBuf>,
+    /// The number of bytes of state each upsert source keeps in memory per
+    /// worker before spilling it to the scratch directory.
+    #[clap(
+        long,
+        env = "UPSERT_MEMORY_LIMIT",
+        value_name = "BYTES",
+        default_value = "268435456"
+    )]
+    upsert_memory_limit: usize,
+
     // === Cloud options. ===
     /// An external ID to be supplied to all AWS AssumeRole operations.
     ///
@@ -83,6 +104,10 @@ struct Args {
     #[clap(long, env = "AWS_EXTERNAL_ID", value_name = "ID", parse(from_str = AwsExternalIdPrefix::new_from_cli_argument_or_environment_variable))]
     aws_external_id: Option<AwsExternalIdPrefix>,
 
+    // === Persist options. ===
+    #[clap(flatten)]
+    persist_blob_encryption: BlobEncryptionCliArgs,
+
     // === Process orchestrator options. ===
     /// Where to write a PID lock file.
     ///
@@ -209,6 +234,12 @@ async fn run(args: Args) -> Result<(), anyhow::Error> {
         .load()
         .await
         .context("loading secrets reader")?;
+    let persist_blob_encryption_keys = args.persist_blob_encryption.load(&*secrets_reader).await?;
+    if let Some(scratch_directory) = &args.scratch_directory {
+        clear_scratch_directory(scratch_directory).with_context(|| {
+            format!("clearing scratch directory {}", scratch_directory.display())
+        })?;
+    }
     let config = mz_storage::Config {
         build_info: &BUILD_INFO,
         workers: args.workers,
@@ -220,6 +251,11 @@ async fn run(args: Args) -> Result<(), anyhow::Error> {
             args.aws_external_id,
             secrets_reader,
         ),
+        persist_blob_encryption_keys,
+        upsert_config: UpsertConfig {
+            scratch_directory: args.scratch_directory,
+            memory_limit: args.upsert_memory_limit,
+        },
     };
 
     // Initialize fail crate for failpoint support
@@ -239,3 +275,18 @@ async fn run(args: Args) -> Result<(), anyhow::Error> {
     )
     .await
 }
+
+/// Removes anything a previous incarnation of this process left behind in
+/// the scratch directory, which may outlive the process.
+fn clear_scratch_directory(path: &Path) -> Result<(), io::Error> {
+    fs::create_dir_all(path)?;
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            fs::remove_dir_all(entry.path())?;
+        } else {
+            fs::remove_file(entry.path())?;
+        }
+    }
+    Ok(())
+}
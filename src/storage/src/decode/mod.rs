@@ -34,6 +34,7 @@ use tracing::error;
 use mz_avro::{AvroDeserializer, GeneralDeserializer};
 use mz_expr::PartitionId;
 use mz_interchange::avro::ConfluentAvroResolver;
+use mz_repr::adt::jsonb::JsonbPacker;
 use mz_repr::{adt::timestamp::CheckedTimestamp, Datum};
 use mz_repr::{Diff, Row, Timestamp};
 use mz_storage_client::types::connections::{ConnectionContext, CsrClient};
@@ -46,12 +47,14 @@ use mz_storage_client::types::sources::{IncludedColumnSource, MzOffset};
 use self::avro::AvroDecoderState;
 use self::csv::CsvDecoderState;
 use self::metrics::DecodeMetrics;
+use self::parquet::ParquetDecoderState;
 use self::protobuf::ProtobufDecoderState;
 use crate::source::types::{DecodeResult, SourceOutput};
 
 mod avro;
 mod csv;
 pub mod metrics;
+pub(crate) mod parquet;
 mod protobuf;
 
 /// Decode delimited CDCv2 messages.
@@ -138,6 +141,7 @@ pub(crate) enum PreDelimitedFormat {
     Text,
     Regex(Regex, Row),
     Protobuf(ProtobufDecoderState),
+    Json(Row),
 }
 
 impl PreDelimitedFormat {
@@ -165,6 +169,16 @@ impl PreDelimitedFormat {
                 Ok(Some(row_buf.clone()))
             }
             PreDelimitedFormat::Protobuf(pb) => pb.get_value(bytes).transpose(),
+            PreDelimitedFormat::Json(row_buf) => {
+                // Tolerate blank lines, e.g. a trailing `\r\n`.
+                if bytes.iter().all(|b| b.is_ascii_whitespace()) {
+                    return Ok(None);
+                }
+                JsonbPacker::new(&mut row_buf.packer())
+                    .pack_slice(bytes)
+                    .map_err(|e| DecodeErrorKind::Text(format!("Failed to decode JSON: {}", e)))?;
+                Ok(Some(row_buf.clone()))
+            }
         }
     }
 }
@@ -177,6 +191,7 @@ pub(crate) enum DataDecoderInner {
         format: PreDelimitedFormat,
     },
     Csv(CsvDecoderState),
+    Parquet(ParquetDecoderState),
 
     PreDelimited(PreDelimitedFormat),
 }
@@ -202,6 +217,7 @@ impl DataDecoder {
             }
             DataDecoderInner::Avro(avro) => avro.decode(bytes),
             DataDecoderInner::Csv(csv) => csv.decode(bytes),
+            DataDecoderInner::Parquet(parquet) => parquet.decode(bytes),
             DataDecoderInner::PreDelimited(format) => {
                 let result = format.decode(*bytes);
                 *bytes = &[];
@@ -282,6 +298,7 @@ fn get_decoder(
         }
         DataEncodingInner::Text
         | DataEncodingInner::Bytes
+        | DataEncodingInner::Json
         | DataEncodingInner::Protobuf(_)
         | DataEncodingInner::Regex(_) => {
             let after_delimiting = match encoding.inner {
@@ -296,6 +313,7 @@ fn get_decoder(
                 }
                 DataEncodingInner::Bytes => PreDelimitedFormat::Bytes,
                 DataEncodingInner::Text => PreDelimitedFormat::Text,
+                DataEncodingInner::Json => PreDelimitedFormat::Json(Default::default()),
                 _ => unreachable!(),
             };
             let inner = if is_connection_delimited {
@@ -315,6 +333,10 @@ fn get_decoder(
                 metrics,
             }
         }
+        DataEncodingInner::Parquet(_) => DataDecoder {
+            inner: DataDecoderInner::Parquet(ParquetDecoderState::default()),
+            metrics,
+        },
         DataEncodingInner::RowCodec(_) => {
             unreachable!("RowCodec sources should not go through the general decoding path.")
         }
@@ -0,0 +1,109 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Decoding of Parquet objects.
+//!
+//! Unlike the other formats, Parquet cannot be decoded from a stream of
+//! bytes: the footer at the end of the object must be read before any data.
+//! The S3 source therefore decodes each object itself, one row group at a
+//! time, and sends the decoded rows to the decode operator as a sequence of
+//! frames. Each frame is a tag byte, a little-endian `u32` payload length, and
+//! the payload: a [`Codec`]-encoded row for `FRAME_ROW`, or a UTF-8 error
+//! message for `FRAME_ERROR`.
+
+use mz_persist_types::Codec;
+use mz_repr::Row;
+use mz_storage_client::types::errors::DecodeErrorKind;
+
+/// Tag of a frame that holds a decoded row.
+const FRAME_ROW: u8 = 0;
+/// Tag of a frame that holds an error message.
+const FRAME_ERROR: u8 = 1;
+/// Length of the tag byte plus the payload length.
+const FRAME_HEADER_LEN: usize = 5;
+
+/// Appends a frame holding `row` to `buf`.
+pub(crate) fn encode_row_frame(buf: &mut Vec<u8>, row: &Row) {
+    let mut payload = Vec::new();
+    row.encode(&mut payload);
+    encode_frame(buf, FRAME_ROW, &payload);
+}
+
+/// Appends a frame holding the error message `err` to `buf`.
+pub(crate) fn encode_error_frame(buf: &mut Vec<u8>, err: &str) {
+    encode_frame(buf, FRAME_ERROR, err.as_bytes());
+}
+
+fn encode_frame(buf: &mut Vec<u8>, tag: u8, payload: &[u8]) {
+    let len = u32::try_from(payload.len()).expect("parquet frame must be smaller than 4GiB");
+    buf.push(tag);
+    buf.extend_from_slice(&len.to_le_bytes());
+    buf.extend_from_slice(payload);
+}
+
+/// Decodes the frames produced by the S3 source for Parquet objects.
+#[derive(Debug, Default)]
+pub struct ParquetDecoderState;
+
+impl ParquetDecoderState {
+    pub fn decode(&mut self, bytes: &mut &[u8]) -> Result<Option<Row>, DecodeErrorKind> {
+        if bytes.len() < FRAME_HEADER_LEN {
+            return Ok(None);
+        }
+        let tag = bytes[0];
+        let len = usize::try_from(u32::from_le_bytes(bytes[1..5].try_into().unwrap()))
+            .expect("u32 fits in usize");
+        if bytes.len() < FRAME_HEADER_LEN + len {
+            return Ok(None);
+        }
+        let payload = &bytes[FRAME_HEADER_LEN..FRAME_HEADER_LEN + len];
+        *bytes = &bytes[FRAME_HEADER_LEN + len..];
+        match tag {
+            FRAME_ROW => Row::decode(payload).map(Some).map_err(|e| {
+                DecodeErrorKind::Text(format!("Failed to decode parquet row frame: {}", e))
+            }),
+            FRAME_ERROR => Err(DecodeErrorKind::Text(
+                String::from_utf8_lossy(payload).into_owned(),
+            )),
+            tag => Err(DecodeErrorKind::Text(format!(
+                "Unknown parquet frame tag: {}",
+                tag
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_repr::Datum;
+
+    use super::*;
+
+    #[test]
+    fn test_frames_roundtrip() {
+        let row = Row::pack_slice(&[Datum::Int64(7), Datum::String("seven")]);
+        let mut buf = Vec::new();
+        encode_row_frame(&mut buf, &row);
+        encode_error_frame(&mut buf, "bad value");
+        encode_row_frame(&mut buf, &row);
+
+        let mut decoder = ParquetDecoderState::default();
+        let bytes = &mut buf.as_slice();
+        assert_eq!(decoder.decode(bytes), Ok(Some(row.clone())));
+        assert_eq!(
+            decoder.decode(bytes),
+            Err(DecodeErrorKind::Text("bad value".into()))
+        );
+        // A partial frame waits for more bytes.
+        let mut partial = &bytes[..bytes.len() - 1];
+        assert_eq!(decoder.decode(&mut partial), Ok(None));
+        assert_eq!(decoder.decode(bytes), Ok(Some(row)));
+        assert!(bytes.is_empty());
+    }
+}
@@ -153,6 +153,17 @@ where
             let oks = oks.into_iter().map(SourceType::Row).collect();
             ((oks, err), cap)
         }
+        GenericSourceConnection::MySql(connection) => {
+            let ((oks, err), cap) = source::create_raw_source(
+                scope,
+                base_source_config,
+                connection,
+                storage_state.connection_context.clone(),
+                resumption_calculator,
+            );
+            let oks = oks.into_iter().map(SourceType::Row).collect();
+            ((oks, err), cap)
+        }
         GenericSourceConnection::LoadGenerator(connection) => {
             let ((oks, err), cap) = source::create_raw_source(
                 scope,
@@ -414,10 +425,12 @@ where
                         };
                     let (upsert_ok, upsert_err) = super::upsert::upsert(
                         &transformed_results,
+                        id,
                         resume_upper,
                         upsert_envelope.clone(),
                         previous_stream,
                         previous_token,
+                        &storage_state.upsert_config,
                     );
 
                     (upsert_ok.as_collection(), Some(upsert_err.as_collection()))
@@ -8,7 +8,6 @@
 // by the Apache License, Version 2.0.
 
 use std::any::Any;
-use std::collections::hash_map::Entry;
 use std::collections::{BTreeMap, HashMap};
 use std::rc::Rc;
 
@@ -21,19 +20,24 @@ use timely::dataflow::operators::{Capability, CapabilityRef, Concat, OkErr, Oper
 use timely::dataflow::{Scope, Stream};
 use timely::order::PartialOrder;
 use timely::progress::frontier::AntichainRef;
-use timely::progress::{Antichain, ChangeBatch};
+use timely::progress::Antichain;
 use tracing::{error, info};
 
 use mz_expr::{EvalError, MirScalarExpr};
 use mz_ore::permutations::inverse_argsort;
-use mz_repr::{Datum, DatumVec, DatumVecBorrow, Diff, Row, RowArena, Timestamp};
+use mz_repr::{Datum, DatumVec, DatumVecBorrow, Diff, GlobalId, Row, RowArena, Timestamp};
 use mz_storage_client::types::errors::{
     DataflowError, DecodeError, EnvelopeError, UpsertError, UpsertValueError,
 };
 use mz_storage_client::types::sources::{MzOffset, UpsertEnvelope, UpsertStyle};
 use mz_timely_util::operator::StreamExt;
 
+use crate::render::upsert::types::{Rehydration, UpsertState};
 use crate::source::types::DecodeResult;
+use crate::storage_state::UpsertConfig;
+
+mod disk;
+mod types;
 
 #[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
 struct UpsertSourceData {
@@ -56,10 +60,12 @@ struct UpsertSourceData {
 /// can be null or empty.
 pub(crate) fn upsert<G>(
     stream: &Stream<G, DecodeResult>,
+    id: GlobalId,
     as_of_frontier: Antichain<Timestamp>,
     upsert_envelope: UpsertEnvelope,
     previous: Stream<G, (Result<Row, DataflowError>, Timestamp, Diff)>,
     previous_token: Option<Rc<dyn Any>>,
+    upsert_config: &UpsertConfig,
 ) -> (
     Stream<G, (Row, Timestamp, Diff)>,
     Stream<G, (DataflowError, Timestamp, Diff)>,
@@ -133,12 +139,14 @@ where
 
     let upsert_output = upsert_core(
         stream,
+        id,
         predicates,
         position_or,
         as_of_frontier,
         upsert_envelope,
         previous.as_collection(),
         previous_token,
+        upsert_config.clone(),
     );
     let (mut oks, errs2) = upsert_output.ok_err(|(data, time, diff)| match data {
         Ok(data) => Ok((data, time, diff)),
@@ -274,12 +282,14 @@ fn extract_kv<G: Scope>(
 /// Internal core upsert logic.
 fn upsert_core<G>(
     stream: &Stream<G, DecodeResult>,
+    id: GlobalId,
     predicates: Vec<MirScalarExpr>,
     position_or: Vec<Option<usize>>,
     as_of_frontier: Antichain<Timestamp>,
     upsert_envelope: UpsertEnvelope,
     previous: Collection<G, Result<Row, UpsertError>, Diff>,
     mut previous_token: Option<Rc<dyn Any>>,
+    upsert_config: UpsertConfig,
 ) -> Stream<G, (Result<Row, DataflowError>, Timestamp, Diff)>
 where
     G: Scope<Timestamp = Timestamp>,
@@ -290,6 +300,8 @@ where
     let mut key_indices_sorted = upsert_envelope.key_indices.clone();
     key_indices_sorted.sort_unstable();
 
+    let worker_id = stream.scope().index();
+
     let previous_ok = extract_kv(
         previous,
         key_indices_sorted.clone(),
@@ -347,43 +359,43 @@ where
             let mut kdv = DatumVec::new();
             // this is a map of (decoded key) -> (decoded_value). We store the
             // latest value for a given key that way we know what to retract if
-            // a new value with the same key comes along.
+            // a new value with the same key comes along. Depending on
+            // `upsert_config`, it is kept in memory or spilled to disk.
             //
             // If `previous_token` is true, we need to rehydrate this from the last good input,
-            // so set it to `None` for now.
+            // so set it to `None` for now and build it up in `rehydrating` instead.
+            let mut rehydrating = None;
             let mut current_values = if previous_token.is_some() {
+                let state = types::new_state(&upsert_config, id, worker_id);
+                rehydrating = Some((state, Rehydration::default()));
                 None
             } else {
-                Some(HashMap::default())
+                Some(types::new_state(&upsert_config, id, worker_id))
             };
 
-            let mut initial_values_multiset = ChangeBatch::default();
+            let mut repop_updates = Vec::new();
             move |data_input, previous_input, output| {
                 if previous_token.is_some() {
                     assert!(current_values.is_none());
-                    // Hydrate the `current_values` map from the previous state of the collection.
-                    // We can't just insert things into the `current_values` map directly, since
-                    // we might in general have non-one multiplicities due to Persist being behind on compaction.
-                    // Thus, we use `initial_values_multiset` to keep track of how many of each record we've seen.
-                    //
-                    // At the end of reading the entire previous input, `initial_values_multiset` must have exactly one of each record,
-                    // and furthermore, each key must be unique. We validate this property for sanity's sake, and build `current_values`.
+                    // Hydrate the state from the previous state of the collection. We can't just
+                    // insert things into the state directly, since we might in general have
+                    // non-one multiplicities due to Persist being behind on compaction.
+                    // `Rehydration` streams each batch into the state as it arrives, keeping only
+                    // the keys whose updates don't yet consolidate to a single value in memory.
                     //
-                    // TODO[btv] This algorithm has the potential to use unbounded space if we can't make any assumptions
-                    // about Persist's level of compaction or the order in which it returns updates.
-                    // See the detailed discussion [here](https://materializeinc.slack.com/archives/C01CFKM1QRF/p1659063332027139).
-                    // This is thought to be fine for our current purposes, but we will need to rethink it once the Persist team has
-                    // thought harder about what guarantees/APIs they want to offer relating to bounded-memory consolidation.
-                    // Tracked here: https://github.com/MaterializeInc/materialize/issues/14086
+                    // At the end of reading the entire previous input, every key must have exactly
+                    // one value, which `Rehydration::finish` validates for sanity's sake.
+                    let (state, rehydration) = rehydrating.as_mut().expect("rehydrating");
                     previous_input.for_each(|_cap, data| {
                         data.swap(&mut repop_scratch_vector);
-                        initial_values_multiset.extend(
+                        repop_updates.extend(
                             repop_scratch_vector
                                 .drain(..)
                                 // filter out records at or past when we are resuming this operator from
                                 .filter(|(_d, t, _r)| !as_of_frontier.less_equal(t))
                                 .map(|(d, _t, r)| (d, r)),
                         );
+                        rehydration.apply(&mut **state, &mut repop_updates);
                     });
                     if PartialOrder::less_equal(
                         &AntichainRef::new(&as_of_frontier),
@@ -393,23 +405,9 @@ where
                         // Without this, we will re-download everything we upload, wasting tons of bandwidth.
                         previous_token = None;
 
-                        let mut new_current_values =
-                            HashMap::with_capacity(initial_values_multiset.len());
-                        for ((k, v), r) in initial_values_multiset.drain() {
-                            assert!(
-                                r == 1,
-                                "The upsert state should have exactly one value per key"
-                            );
-                            match new_current_values.entry(k) {
-                                Entry::Occupied(_oe) => {
-                                    panic!("The upsert state should have exactly one value per key")
-                                }
-                                Entry::Vacant(ve) => {
-                                    ve.insert(v);
-                                }
-                            }
-                        }
-                        current_values = Some(new_current_values);
+                        let (state, rehydration) = rehydrating.take().expect("rehydrating");
+                        rehydration.finish();
+                        current_values = Some(state);
                     }
                 }
 
@@ -429,7 +427,7 @@ where
                 // memory in `pending_values` until we are ready to merge it into `current_values`.
                 let current_values = match &mut current_values {
                     None => return,
-                    Some(x) => x,
+                    Some(x) => &mut **x,
                 };
 
                 let mut removed_times = Vec::new();
@@ -549,7 +547,7 @@ fn process_pending_values_batch(
     cap: &mut Capability<Timestamp>,
     map: &mut HashMap<Option<Result<Row, DecodeError>>, UpsertSourceData>,
     // The current map of values we use to perform the upsert comparision
-    current_values: &mut HashMap<Result<Row, DecodeError>, Result<Row, DataflowError>>,
+    current_values: &mut dyn UpsertState,
     // A shared row used to pack new rows for evaluation and output
     row_packer: &mut Row,
     // A shared row used to build a Vec<Datum<'_>> for evaluation
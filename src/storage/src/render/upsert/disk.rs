@@ -0,0 +1,514 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! An upsert state backend that spills to local disk.
+//!
+//! The state is a small log-structured merge tree. Recent writes are kept in
+//! an in-memory table, which is written out as an immutable, sorted run
+//! whenever it grows beyond the memory limit. Lookups consult the in-memory
+//! table and then each run from newest to oldest. Every run has a Bloom filter
+//! and a sparse index, so that consulting a run costs at most one block read,
+//! and usually none when the key is absent. Whenever there are too many runs,
+//! they are merged into one.
+//!
+//! The memory used is therefore the memory limit, plus roughly
+//! [`BLOOM_BITS_PER_KEY`] bits per key and one index entry per [`BLOCK_SIZE`]
+//! bytes of state.
+//!
+//! The state is not durable. The upsert operator rebuilds it from its output
+//! collection whenever it restarts, and its directory is removed on drop.
+
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::hash::{Hash, Hasher};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::os::unix::fs::FileExt;
+use std::path::PathBuf;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tracing::warn;
+
+use mz_ore::cast::CastFrom;
+
+use super::types::{UpsertKey, UpsertState, UpsertValue};
+
+/// The approximate number of bytes between the entries of a run's sparse
+/// index.
+const BLOCK_SIZE: u64 = 64 << 10;
+
+/// The number of runs above which all runs are merged into one.
+const MAX_RUNS: usize = 8;
+
+/// The number of bits of a run's Bloom filter per key in the run.
+const BLOOM_BITS_PER_KEY: usize = 10;
+
+/// The number of hash functions of a run's Bloom filter.
+const BLOOM_HASHES: u64 = 7;
+
+/// The estimated in-memory overhead of each entry in the in-memory table.
+const ENTRY_OVERHEAD: usize = 64;
+
+/// Upsert state that keeps at most a bounded number of bytes in memory and
+/// spills the rest to files in a directory.
+#[derive(Debug)]
+pub(crate) struct DiskState {
+    directory: PathBuf,
+    memory_limit: usize,
+    /// The most recent writes, which shadow those in `runs`. A `None` value
+    /// is a deletion.
+    memtable: BTreeMap<Vec<u8>, Option<Vec<u8>>>,
+    /// The estimated size of `memtable` in bytes.
+    memtable_bytes: usize,
+    /// The runs, from oldest to newest.
+    runs: Vec<Run>,
+    next_run_id: u64,
+}
+
+impl DiskState {
+    /// Constructs an empty state that spills to files in `directory`, once the
+    /// writes it holds in memory exceed `memory_limit` bytes.
+    pub(crate) fn new(directory: PathBuf, memory_limit: usize) -> Result<Self, io::Error> {
+        fs::create_dir_all(&directory)?;
+        Ok(DiskState {
+            directory,
+            memory_limit,
+            memtable: BTreeMap::new(),
+            memtable_bytes: 0,
+            runs: Vec::new(),
+            next_run_id: 0,
+        })
+    }
+
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>, io::Error> {
+        if let Some(value) = self.memtable.get(key) {
+            return Ok(value.clone());
+        }
+        for run in self.runs.iter().rev() {
+            if let Some(value) = run.get(key)? {
+                return Ok(value);
+            }
+        }
+        Ok(None)
+    }
+
+    fn put(&mut self, key: Vec<u8>, value: Option<Vec<u8>>) -> Result<(), io::Error> {
+        let entry_bytes = |key: &[u8], value: &Option<Vec<u8>>| {
+            key.len() + value.as_ref().map_or(0, |value| value.len()) + ENTRY_OVERHEAD
+        };
+        self.memtable_bytes += entry_bytes(&key, &value);
+        let key_len = key.len();
+        if let Some(previous) = self.memtable.insert(key, value) {
+            self.memtable_bytes -= key_len + entry_bytes(&[], &previous);
+        }
+        if self.memtable_bytes > self.memory_limit {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    fn delete(&mut self, key: Vec<u8>) -> Result<(), io::Error> {
+        if self.runs.is_empty() {
+            // There is no older version of the key to shadow.
+            if let Some(previous) = self.memtable.remove(&key) {
+                self.memtable_bytes -=
+                    key.len() + previous.map_or(0, |previous| previous.len()) + ENTRY_OVERHEAD;
+            }
+            Ok(())
+        } else {
+            self.put(key, None)
+        }
+    }
+
+    /// Writes the in-memory table out as a new run.
+    fn flush(&mut self) -> Result<(), io::Error> {
+        let mut writer = RunWriter::new(self.next_run_path(), self.memtable.len())?;
+        for (key, value) in std::mem::take(&mut self.memtable) {
+            writer.push(&key, value.as_deref())?;
+        }
+        self.memtable_bytes = 0;
+        self.runs.push(writer.finish()?);
+        if self.runs.len() > MAX_RUNS {
+            self.compact()?;
+        }
+        Ok(())
+    }
+
+    /// Merges all runs into one.
+    ///
+    /// Only the newest version of each key is kept, and as there are no older
+    /// runs left for them to shadow, deletions are dropped.
+    fn compact(&mut self) -> Result<(), io::Error> {
+        let keys = self.runs.iter().map(|run| run.keys).sum();
+        let mut writer = RunWriter::new(self.next_run_path(), keys)?;
+        let mut readers = self
+            .runs
+            .iter()
+            .map(Run::reader)
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut heads = readers
+            .iter_mut()
+            .map(RunReader::next)
+            .collect::<Result<Vec<_>, _>>()?;
+        loop {
+            // Find the smallest key. Of equal keys, the one in the newest run
+            // wins.
+            let mut min: Option<usize> = None;
+            for (i, head) in heads.iter().enumerate() {
+                if let Some((key, _)) = head {
+                    match min {
+                        Some(current) if heads[current].as_ref().expect("present").0 < *key => {}
+                        _ => min = Some(i),
+                    }
+                }
+            }
+            let Some(min) = min else { break };
+            let (key, value) = heads[min].take().expect("present");
+            for (head, reader) in heads.iter_mut().zip(readers.iter_mut()) {
+                if matches!(head, Some((head_key, _)) if *head_key == key) {
+                    *head = reader.next()?;
+                }
+            }
+            heads[min] = readers[min].next()?;
+            if let Some(value) = value {
+                writer.push(&key, Some(&value))?;
+            }
+        }
+        let run = writer.finish()?;
+        for run in self.runs.drain(..) {
+            fs::remove_file(&run.path)?;
+        }
+        self.runs.push(run);
+        Ok(())
+    }
+
+    fn next_run_path(&mut self) -> PathBuf {
+        let path = self.directory.join(format!("run-{}", self.next_run_id));
+        self.next_run_id += 1;
+        path
+    }
+}
+
+impl UpsertState for DiskState {
+    fn insert(&mut self, key: UpsertKey, value: UpsertValue) -> Option<UpsertValue> {
+        let key = encode(&key);
+        let previous = self.get(&key).unwrap_or_else(|e| io_panic(e));
+        self.put(key, Some(encode(&value)))
+            .unwrap_or_else(|e| io_panic(e));
+        previous.map(|previous| decode(&previous))
+    }
+
+    fn remove(&mut self, key: &UpsertKey) -> Option<UpsertValue> {
+        let key = encode(key);
+        let previous = self.get(&key).unwrap_or_else(|e| io_panic(e));
+        if previous.is_some() {
+            self.delete(key).unwrap_or_else(|e| io_panic(e));
+        }
+        previous.map(|previous| decode(&previous))
+    }
+}
+
+impl Drop for DiskState {
+    fn drop(&mut self) {
+        if let Err(e) = fs::remove_dir_all(&self.directory) {
+            warn!(
+                "failed to remove upsert state in {}: {e}",
+                self.directory.display()
+            );
+        }
+    }
+}
+
+fn encode<T: Serialize>(data: &T) -> Vec<u8> {
+    bincode::serialize(data).expect("upsert state is serializable")
+}
+
+fn decode<T: DeserializeOwned>(bytes: &[u8]) -> T {
+    bincode::deserialize(bytes).expect("upsert state was written by `encode`")
+}
+
+fn io_panic(e: io::Error) -> ! {
+    panic!("failed to access upsert state on disk: {e}")
+}
+
+/// An immutable, sorted file of keys and their values or deletions.
+#[derive(Debug)]
+struct Run {
+    path: PathBuf,
+    file: File,
+    len: u64,
+    keys: usize,
+    /// The first key of each block of the file, and the block's offset.
+    index: Vec<(Vec<u8>, u64)>,
+    bloom: Bloom,
+}
+
+impl Run {
+    /// Looks up `key` in the run, returning `Some(None)` if the run records
+    /// its deletion.
+    fn get(&self, key: &[u8]) -> Result<Option<Option<Vec<u8>>>, io::Error> {
+        if !self.bloom.contains(key) {
+            return Ok(None);
+        }
+        // The block that would contain `key` is the last one whose first key
+        // is not greater than it.
+        let block = match self
+            .index
+            .partition_point(|(first_key, _)| first_key.as_slice() <= key)
+        {
+            0 => return Ok(None),
+            i => i - 1,
+        };
+        let start = self.index[block].1;
+        let end = self
+            .index
+            .get(block + 1)
+            .map_or(self.len, |(_, offset)| *offset);
+        let mut buf = vec![0; usize::cast_from(end - start)];
+        self.file.read_exact_at(&mut buf, start)?;
+        let mut records = &buf[..];
+        while !records.is_empty() {
+            let (record_key, value) = read_record(&mut records)?;
+            match record_key.as_slice().cmp(key) {
+                Ordering::Less => (),
+                Ordering::Equal => return Ok(Some(value)),
+                Ordering::Greater => break,
+            }
+        }
+        Ok(None)
+    }
+
+    fn reader(&self) -> Result<RunReader, io::Error> {
+        Ok(RunReader {
+            reader: BufReader::new(File::open(&self.path)?),
+            remaining: self.len,
+        })
+    }
+}
+
+/// Writes a run, one record at a time, in ascending order of keys.
+struct RunWriter {
+    path: PathBuf,
+    writer: BufWriter<File>,
+    offset: u64,
+    block_start: Option<u64>,
+    keys: usize,
+    index: Vec<(Vec<u8>, u64)>,
+    bloom: Bloom,
+}
+
+impl RunWriter {
+    /// Creates a run at `path` that will hold at most `keys` keys.
+    fn new(path: PathBuf, keys: usize) -> Result<Self, io::Error> {
+        Ok(RunWriter {
+            writer: BufWriter::new(File::create(&path)?),
+            path,
+            offset: 0,
+            block_start: None,
+            keys: 0,
+            index: Vec::new(),
+            bloom: Bloom::new(keys),
+        })
+    }
+
+    fn push(&mut self, key: &[u8], value: Option<&[u8]>) -> Result<(), io::Error> {
+        if self
+            .block_start
+            .map_or(true, |start| self.offset - start >= BLOCK_SIZE)
+        {
+            self.index.push((key.to_vec(), self.offset));
+            self.block_start = Some(self.offset);
+        }
+        self.bloom.insert(key);
+        self.keys += 1;
+        self.offset += write_record(&mut self.writer, key, value)?;
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Run, io::Error> {
+        self.writer.into_inner().map_err(|e| e.into_error())?;
+        Ok(Run {
+            file: File::open(&self.path)?,
+            path: self.path,
+            len: self.offset,
+            keys: self.keys,
+            index: self.index,
+            bloom: self.bloom,
+        })
+    }
+}
+
+/// Reads the records of a run in order.
+struct RunReader {
+    reader: BufReader<File>,
+    remaining: u64,
+}
+
+impl RunReader {
+    fn next(&mut self) -> Result<Option<(Vec<u8>, Option<Vec<u8>>)>, io::Error> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        let (key, value) = read_record(&mut self.reader)?;
+        self.remaining -= record_len(&key, value.as_deref());
+        Ok(Some((key, value)))
+    }
+}
+
+/// Writes a record, returning its length in bytes.
+///
+/// A record is the length of the key as a little-endian `u32`, the key, and
+/// then either a zero byte for a deletion or a one byte, the length of the
+/// value as a little-endian `u32`, and the value.
+fn write_record<W: Write>(
+    writer: &mut W,
+    key: &[u8],
+    value: Option<&[u8]>,
+) -> Result<u64, io::Error> {
+    write_bytes(writer, key)?;
+    match value {
+        None => writer.write_all(&[0])?,
+        Some(value) => {
+            writer.write_all(&[1])?;
+            write_bytes(writer, value)?;
+        }
+    }
+    Ok(record_len(key, value))
+}
+
+fn read_record<R: Read>(reader: &mut R) -> Result<(Vec<u8>, Option<Vec<u8>>), io::Error> {
+    let key = read_bytes(reader)?;
+    let mut tag = [0];
+    reader.read_exact(&mut tag)?;
+    let value = match tag {
+        [0] => None,
+        [1] => Some(read_bytes(reader)?),
+        _ => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid upsert state record",
+            ))
+        }
+    };
+    Ok((key, value))
+}
+
+fn record_len(key: &[u8], value: Option<&[u8]>) -> u64 {
+    let len = 4 + key.len() + 1 + value.map_or(0, |value| 4 + value.len());
+    u64::cast_from(len)
+}
+
+fn write_bytes<W: Write>(writer: &mut W, bytes: &[u8]) -> Result<(), io::Error> {
+    let len = u32::try_from(bytes.len()).expect("upsert state entries are smaller than 4GiB");
+    writer.write_all(&len.to_le_bytes())?;
+    writer.write_all(bytes)
+}
+
+fn read_bytes<R: Read>(reader: &mut R) -> Result<Vec<u8>, io::Error> {
+    let mut len = [0; 4];
+    reader.read_exact(&mut len)?;
+    let mut bytes = vec![0; usize::cast_from(u32::from_le_bytes(len))];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// A Bloom filter over the keys of a run.
+#[derive(Debug)]
+struct Bloom {
+    bits: Vec<u64>,
+    num_bits: u64,
+}
+
+impl Bloom {
+    fn new(keys: usize) -> Self {
+        let num_bits = u64::cast_from(std::cmp::max(keys * BLOOM_BITS_PER_KEY, 64));
+        Bloom {
+            bits: vec![0; usize::cast_from((num_bits + 63) / 64)],
+            num_bits,
+        }
+    }
+
+    fn insert(&mut self, key: &[u8]) {
+        for bit in self.bits_for(key) {
+            self.bits[usize::cast_from(bit / 64)] |= 1 << (bit % 64);
+        }
+    }
+
+    fn contains(&self, key: &[u8]) -> bool {
+        self.bits_for(key)
+            .all(|bit| self.bits[usize::cast_from(bit / 64)] & (1 << (bit % 64)) != 0)
+    }
+
+    /// Derives the bits for `key` from two hashes, as in Kirsch and
+    /// Mitzenmacher's "Less Hashing, Same Performance".
+    fn bits_for(&self, key: &[u8]) -> impl Iterator<Item = u64> {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        let h1 = hasher.finish();
+        h1.hash(&mut hasher);
+        let h2 = hasher.finish() | 1;
+        let num_bits = self.num_bits;
+        (0..BLOOM_HASHES).map(move |i| h1.wrapping_add(i.wrapping_mul(h2)) % num_bits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use mz_repr::{Datum, Row};
+
+    use super::*;
+
+    #[test]
+    fn test_disk_state_matches_hash_map() {
+        let directory = tempfile::tempdir().unwrap();
+        // A tiny memory limit forces frequent flushes and compactions.
+        let mut disk = DiskState::new(directory.path().join("state"), 1 << 10).unwrap();
+        let mut memory = HashMap::new();
+
+        for i in 0..10_000i64 {
+            let key: UpsertKey = Ok(Row::pack([Datum::Int64(i % 1_000)]));
+            if i % 7 == 0 {
+                assert_eq!(
+                    UpsertState::remove(&mut disk, &key),
+                    UpsertState::remove(&mut memory, &key)
+                );
+            } else {
+                let value: UpsertValue = Ok(Row::pack([Datum::Int64(i)]));
+                assert_eq!(
+                    UpsertState::insert(&mut disk, key.clone(), value.clone()),
+                    UpsertState::insert(&mut memory, key, value)
+                );
+            }
+        }
+        assert!(disk.runs.len() <= MAX_RUNS);
+
+        for i in 0..1_000i64 {
+            let key: UpsertKey = Ok(Row::pack([Datum::Int64(i)]));
+            assert_eq!(
+                UpsertState::remove(&mut disk, &key),
+                UpsertState::remove(&mut memory, &key)
+            );
+        }
+    }
+
+    #[test]
+    fn test_disk_state_removes_directory() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("state");
+        let mut disk = DiskState::new(path.clone(), 0).unwrap();
+        UpsertState::insert(&mut disk, Ok(Row::default()), Ok(Row::default()));
+        assert!(!disk.runs.is_empty());
+        drop(disk);
+        assert!(!path.exists());
+    }
+}
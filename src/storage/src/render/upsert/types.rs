@@ -0,0 +1,187 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Backends for the state kept by the upsert operator.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use differential_dataflow::consolidation::consolidate;
+use tracing::info;
+
+use mz_repr::{Diff, GlobalId, Row};
+use mz_storage_client::types::errors::{DataflowError, DecodeError};
+
+use crate::storage_state::UpsertConfig;
+
+use super::disk::DiskState;
+
+/// A key of an upsert source.
+pub(crate) type UpsertKey = Result<Row, DecodeError>;
+
+/// The current value of a key of an upsert source.
+pub(crate) type UpsertValue = Result<Row, DataflowError>;
+
+/// A map from each key of an upsert source to its current value.
+pub(crate) trait UpsertState {
+    /// Sets the value of `key` to `value`, returning its previous value, if
+    /// any.
+    fn insert(&mut self, key: UpsertKey, value: UpsertValue) -> Option<UpsertValue>;
+
+    /// Removes the value of `key`, returning it, if any.
+    fn remove(&mut self, key: &UpsertKey) -> Option<UpsertValue>;
+}
+
+impl UpsertState for HashMap<UpsertKey, UpsertValue> {
+    fn insert(&mut self, key: UpsertKey, value: UpsertValue) -> Option<UpsertValue> {
+        HashMap::insert(self, key, value)
+    }
+
+    fn remove(&mut self, key: &UpsertKey) -> Option<UpsertValue> {
+        HashMap::remove(self, key)
+    }
+}
+
+/// Distinguishes the on-disk state of upsert operators that are rendered more
+/// than once on the same worker, e.g. when a source is dropped and recreated.
+static NEXT_INSTANCE: AtomicU64 = AtomicU64::new(0);
+
+/// Constructs the state for the upsert operator of the source `id` on the
+/// worker `worker_id`, using the backend selected by `config`.
+pub(crate) fn new_state(
+    config: &UpsertConfig,
+    id: GlobalId,
+    worker_id: usize,
+) -> Box<dyn UpsertState> {
+    match &config.scratch_directory {
+        None => Box::new(HashMap::new()),
+        Some(scratch_directory) => {
+            let instance = NEXT_INSTANCE.fetch_add(1, Ordering::Relaxed);
+            let directory = scratch_directory.join(format!("upsert-{id}-{worker_id}-{instance}"));
+            info!(
+                "upsert state for {id} on worker {worker_id} spilling to {}",
+                directory.display()
+            );
+            let state = DiskState::new(directory, config.memory_limit)
+                .unwrap_or_else(|e| panic!("failed to create upsert state on disk: {e}"));
+            Box::new(state)
+        }
+    }
+}
+
+/// Rehydrates an [`UpsertState`] from the previous output of the upsert
+/// operator, one batch of updates at a time.
+///
+/// The previous output is not necessarily consolidated, so while it is being
+/// read a key can have no value, several values, or a value with a
+/// multiplicity other than one. Only keys in that situation, of which there
+/// are usually few, are kept in memory. All other keys are written through to
+/// the state.
+#[derive(Debug, Default)]
+pub(crate) struct Rehydration {
+    /// The keys whose updates so far don't consolidate to a single value,
+    /// with those updates.
+    unsettled: HashMap<UpsertKey, Vec<(UpsertValue, Diff)>>,
+}
+
+impl Rehydration {
+    /// Applies a batch of updates to `state`, draining `updates`.
+    pub(crate) fn apply(
+        &mut self,
+        state: &mut dyn UpsertState,
+        updates: &mut Vec<((UpsertKey, UpsertValue), Diff)>,
+    ) {
+        consolidate(updates);
+        for ((key, value), diff) in updates.drain(..) {
+            let mut values = match self.unsettled.remove(&key) {
+                Some(values) => values,
+                None => state.remove(&key).map(|v| (v, 1)).into_iter().collect(),
+            };
+            values.push((value, diff));
+            consolidate(&mut values);
+            match values.as_slice() {
+                [] => (),
+                [(_, 1)] => {
+                    let (value, _) = values.pop().expect("known to exist");
+                    state.insert(key, value);
+                }
+                _ => {
+                    self.unsettled.insert(key, values);
+                }
+            }
+        }
+    }
+
+    /// Completes the rehydration once all updates have been applied.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any key does not have exactly one value.
+    pub(crate) fn finish(self) {
+        if let Some((key, values)) = self.unsettled.into_iter().next() {
+            panic!(
+                "The upsert state should have exactly one value per key, \
+                 but {key:?} has {values:?}"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_repr::Datum;
+
+    use super::*;
+
+    #[test]
+    fn test_rehydration_out_of_order() {
+        let row = |i| Ok(Row::pack_slice(&[Datum::Int64(i)]));
+        let mut state: HashMap<UpsertKey, UpsertValue> = HashMap::new();
+        let mut rehydration = Rehydration::default();
+
+        // Key 1 is retracted before it is inserted, and key 2 transiently has
+        // two values, across batches.
+        rehydration.apply(
+            &mut state,
+            &mut vec![((row(1), row(10)), -1), ((row(2), row(20)), 1)],
+        );
+        rehydration.apply(
+            &mut state,
+            &mut vec![((row(2), row(21)), 1), ((row(3), row(30)), 2)],
+        );
+        rehydration.apply(
+            &mut state,
+            &mut vec![
+                ((row(1), row(10)), 1),
+                ((row(1), row(11)), 1),
+                ((row(2), row(20)), -1),
+                ((row(3), row(30)), -1),
+            ],
+        );
+        rehydration.finish();
+
+        assert_eq!(
+            state,
+            HashMap::from([(row(1), row(11)), (row(2), row(21)), (row(3), row(30)),])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "exactly one value per key")]
+    fn test_rehydration_unsettled() {
+        let row = |i| Ok(Row::pack_slice(&[Datum::Int64(i)]));
+        let mut state: HashMap<UpsertKey, UpsertValue> = HashMap::new();
+        let mut rehydration = Rehydration::default();
+        rehydration.apply(
+            &mut state,
+            &mut vec![((row(1), row(10)), 1), ((row(1), row(11)), 1)],
+        );
+        rehydration.finish();
+    }
+}
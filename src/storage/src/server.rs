@@ -20,6 +20,7 @@ use mz_build_info::BuildInfo;
 use mz_ore::metrics::MetricsRegistry;
 use mz_ore::now::NowFn;
 use mz_persist_client::cache::PersistClientCache;
+use mz_persist_client::encryption::KeyEncryptionKeys;
 use mz_persist_client::PersistConfig;
 use mz_service::local::LocalClient;
 use mz_storage_client::client::StorageClient;
@@ -27,7 +28,7 @@ use mz_storage_client::types::connections::ConnectionContext;
 
 use crate::sink::SinkBaseMetrics;
 use crate::source::metrics::SourceBaseMetrics;
-use crate::storage_state::{StorageState, Worker};
+use crate::storage_state::{StorageState, UpsertConfig, Worker};
 use crate::DecodeMetrics;
 
 /// Configures a dataflow server.
@@ -44,6 +45,10 @@ pub struct Config {
     pub metrics_registry: MetricsRegistry,
     /// Configuration for source and sink connection.
     pub connection_context: ConnectionContext,
+    /// The keys with which to encrypt persist batch parts, if any.
+    pub persist_blob_encryption_keys: Option<Arc<KeyEncryptionKeys>>,
+    /// Configuration for the state of upsert sources.
+    pub upsert_config: UpsertConfig,
 }
 
 /// A handle to a running dataflow server.
@@ -59,6 +64,21 @@ pub fn serve(
 ) -> Result<(Server, impl Fn() -> Box<dyn StorageClient>), anyhow::Error> {
     assert!(config.workers > 0);
 
+    // Upsert state is rebuilt from persist whenever a source is rendered, so
+    // anything a previous process spilled to disk is garbage.
+    if let Some(scratch_directory) = &config.upsert_config.scratch_directory {
+        match std::fs::remove_dir_all(scratch_directory) {
+            Ok(()) => (),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => (),
+            Err(e) => {
+                return Err(anyhow!(e).context(format!(
+                    "clearing upsert scratch directory {}",
+                    scratch_directory.display()
+                )))
+            }
+        }
+    }
+
     // Various metrics related things.
     let source_metrics = SourceBaseMetrics::register_with(&config.metrics_registry);
     let sink_metrics = SinkBaseMetrics::register_with(&config.metrics_registry);
@@ -73,10 +93,9 @@ pub fn serve(
 
     let tokio_executor = tokio::runtime::Handle::current();
     let now = config.now;
-    let persist_clients = PersistClientCache::new(
-        PersistConfig::new(config.build_info, now.clone()),
-        &config.metrics_registry,
-    );
+    let mut persist_cfg = PersistConfig::new(config.build_info, now.clone());
+    persist_cfg.blob_encryption_keys = config.persist_blob_encryption_keys;
+    let persist_clients = PersistClientCache::new(persist_cfg, &config.metrics_registry);
     let persist_clients = Arc::new(tokio::sync::Mutex::new(persist_clients));
 
     let worker_guards = timely::execute::execute(config.timely_config, move |timely_worker| {
@@ -107,6 +126,7 @@ pub fn serve(
                 timely_worker_index,
                 timely_worker_peers,
                 connection_context: config.connection_context.clone(),
+                upsert_config: config.upsert_config.clone(),
                 persist_clients,
                 sink_tokens: HashMap::new(),
                 sink_write_frontiers: HashMap::new(),
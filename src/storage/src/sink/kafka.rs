@@ -46,6 +46,7 @@ use tracing::{debug, error, info, warn};
 use mz_interchange::avro::{AvroEncoder, AvroSchemaGenerator};
 use mz_interchange::encode::Encode;
 use mz_interchange::json::JsonEncoder;
+use mz_interchange::protobuf;
 use mz_kafka_util::client::{BrokerRewritingClientContext, MzClientContext};
 use mz_ore::cast::CastFrom;
 use mz_ore::collections::CollectionExt;
@@ -940,11 +941,52 @@ where
         .map(|(desc, _indices)| desc.clone());
     let value_desc = connection.value_desc.clone();
 
-    let encoded_stream = match connection.published_schema_info {
-        Some(PublishedSchemaInfo {
-            key_schema_id,
-            value_schema_id,
-        }) => {
+    let encoded_stream = match (
+        &connection.protobuf_messages,
+        &connection.published_schema_info,
+    ) {
+        (Some(messages), published_schema_info) => {
+            let (key_schema_id, value_schema_id) = match published_schema_info {
+                Some(PublishedSchemaInfo {
+                    key_schema_id,
+                    value_schema_id,
+                }) => (*key_schema_id, Some(*value_schema_id)),
+                None => (None, None),
+            };
+            let key_encoder = key_desc.map(|key_desc| {
+                let key_message = messages.key.as_ref().unwrap_or(&messages.value);
+                protobuf::MessageEncoder::new(
+                    &key_message.descriptors,
+                    &key_message.message_name,
+                    key_desc,
+                    key_schema_id,
+                )
+                .expect("protobuf schema validated")
+            });
+            let value_encoder = protobuf::MessageEncoder::new(
+                &messages.value.descriptors,
+                &messages.value.message_name,
+                value_desc,
+                value_schema_id,
+            )
+            .expect("protobuf schema validated");
+            let encoder = protobuf::Encoder::new(key_encoder, value_encoder);
+            encode_stream(
+                stream,
+                as_of.clone(),
+                Rc::clone(&shared_gate_ts),
+                encoder,
+                connection.fuel,
+                name.clone(),
+            )
+        }
+        (
+            None,
+            Some(PublishedSchemaInfo {
+                key_schema_id,
+                value_schema_id,
+            }),
+        ) => {
             let schema_generator = AvroSchemaGenerator::new(
                 None,
                 None,
@@ -953,7 +995,7 @@ where
                 matches!(envelope, Some(SinkEnvelope::Debezium)),
             )
             .expect("avro schema validated");
-            let encoder = AvroEncoder::new(schema_generator, key_schema_id, value_schema_id);
+            let encoder = AvroEncoder::new(schema_generator, *key_schema_id, *value_schema_id);
             encode_stream(
                 stream,
                 as_of.clone(),
@@ -963,7 +1005,7 @@ where
                 name.clone(),
             )
         }
-        None => {
+        (None, None) => {
             let encoder = JsonEncoder::new(
                 key_desc,
                 value_desc,
@@ -677,6 +677,7 @@ mod tests {
             remap_shard: ShardId::new(),
             data_shard: ShardId::new(),
             status_shard: Some(status_shard_id),
+            missing_values: None,
         };
 
         Healthchecker::new(
@@ -256,6 +256,65 @@ impl PostgresSourceSpecificMetrics {
     }
 }
 
+#[derive(Clone, Debug)]
+pub(super) struct MySqlSourceSpecificMetrics {
+    pub(super) total_events: IntCounterVec,
+    pub(super) transactions: IntCounterVec,
+    pub(super) ignored_events: IntCounterVec,
+    pub(super) insert_rows: IntCounterVec,
+    pub(super) update_rows: IntCounterVec,
+    pub(super) delete_rows: IntCounterVec,
+    pub(super) tables: UIntGaugeVec,
+    pub(super) binlog_offset: UIntGaugeVec,
+}
+
+impl MySqlSourceSpecificMetrics {
+    fn register_with(registry: &MetricsRegistry) -> Self {
+        Self {
+            total_events: registry.register(metric!(
+                name: "mz_mysql_per_source_events_total",
+                help: "The total number of binary log events for this source, not expected to be the sum of the other values.",
+                var_labels: ["source_id"],
+            )),
+            transactions: registry.register(metric!(
+                name: "mz_mysql_per_source_transactions_total",
+                help: "The number of committed transactions for all tables in this source",
+                var_labels: ["source_id"],
+            )),
+            ignored_events: registry.register(metric!(
+                name: "mz_mysql_per_source_ignored_events",
+                help: "The number of binary log events ignored because of an irrelevant type or table",
+                var_labels: ["source_id"],
+            )),
+            insert_rows: registry.register(metric!(
+                name: "mz_mysql_per_source_inserts",
+                help: "The number of inserted rows for all tables in this source",
+                var_labels: ["source_id"],
+            )),
+            update_rows: registry.register(metric!(
+                name: "mz_mysql_per_source_updates",
+                help: "The number of updated rows for all tables in this source",
+                var_labels: ["source_id"],
+            )),
+            delete_rows: registry.register(metric!(
+                name: "mz_mysql_per_source_deletes",
+                help: "The number of deleted rows for all tables in this source",
+                var_labels: ["source_id"],
+            )),
+            tables: registry.register(metric!(
+                name: "mz_mysql_per_source_tables_count",
+                help: "The number of upstream tables for this source",
+                var_labels: ["source_id"],
+            )),
+            binlog_offset: registry.register(metric!(
+                name: "mz_mysql_per_source_binlog_offset",
+                help: "Binary log position of the latest transaction committed for this source, encoded as (file sequence number << 32) | position",
+                var_labels: ["source_id"],
+            ))
+        }
+    }
+}
+
 /// A set of base metrics that hang off a central metrics registry, labeled by the source they
 /// belong to.
 #[derive(Debug, Clone)]
@@ -263,6 +322,7 @@ pub struct SourceBaseMetrics {
     pub(super) source_specific: SourceSpecificMetrics,
     pub(super) partition_specific: PartitionSpecificMetrics,
     pub(super) postgres_source_specific: PostgresSourceSpecificMetrics,
+    pub(super) mysql_source_specific: MySqlSourceSpecificMetrics,
 
     pub(crate) s3: S3Metrics,
     pub(crate) kinesis: KinesisMetrics,
@@ -277,6 +337,7 @@ impl SourceBaseMetrics {
             source_specific: SourceSpecificMetrics::register_with(registry),
             partition_specific: PartitionSpecificMetrics::register_with(registry),
             postgres_source_specific: PostgresSourceSpecificMetrics::register_with(registry),
+            mysql_source_specific: MySqlSourceSpecificMetrics::register_with(registry),
 
             s3: S3Metrics::register_with(registry),
             kinesis: KinesisMetrics::register_with(registry),
@@ -43,6 +43,7 @@ pub mod healthcheck;
 mod kafka;
 mod kinesis;
 pub mod metrics;
+mod mysql;
 mod postgres;
 mod reclock;
 mod resumption;
@@ -57,6 +58,7 @@ pub use delimited_value_reader::DelimitedValueSourceConnection;
 pub use generator::LoadGeneratorSourceReader;
 pub use kafka::KafkaSourceReader;
 pub use kinesis::KinesisSourceReader;
+pub use mysql::MySqlSourceReader;
 pub use postgres::PostgresSourceReader;
 pub use s3::S3SourceReader;
 pub use source_reader_pipeline::create_raw_source;
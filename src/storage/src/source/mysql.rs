@@ -0,0 +1,916 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::HashMap;
+use std::future;
+use std::time::Duration;
+
+use anyhow::{anyhow, bail};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use futures::{FutureExt, StreamExt};
+use itertools::Itertools;
+use mysql_async::binlog::events::EventData;
+use mysql_async::binlog::value::BinlogValue;
+use mysql_async::prelude::Queryable;
+use mysql_async::Value;
+use timely::scheduling::SyncActivator;
+use tokio::runtime::Handle as TokioHandle;
+use tokio::sync::mpsc::{Receiver, Sender};
+use tracing::{error, info, warn};
+
+use mz_expr::PartitionId;
+use mz_mysql_util::desc::{MySqlColumnDesc, MySqlTableDesc};
+use mz_mysql_util::{quote_identifier, BinlogPosition, MySqlError};
+use mz_ore::task;
+use mz_repr::adt::numeric;
+use mz_repr::adt::timestamp::CheckedTimestamp;
+use mz_repr::{strconv, Datum, Diff, GlobalId, Row, RowPacker, ScalarType};
+use mz_storage_client::types::connections::ConnectionContext;
+use mz_storage_client::types::errors::SourceErrorDetails;
+use mz_storage_client::types::sources::{
+    encoding::SourceDataEncoding, MySqlSourceConnection, MzOffset,
+};
+
+use self::metrics::MySqlSourceMetrics;
+use super::metrics::SourceBaseMetrics;
+use crate::source::commit::LogCommitter;
+use crate::source::source_reader_pipeline::HealthStatus;
+use crate::source::types::{HealthStatusUpdate, SourceConnectionBuilder};
+use crate::source::{
+    NextMessage, SourceMessage, SourceMessageType, SourceReader, SourceReaderError,
+};
+
+mod metrics;
+
+trait ErrorExt {
+    fn is_definite(&self) -> bool;
+}
+
+impl ErrorExt for mysql_async::Error {
+    fn is_definite(&self) -> bool {
+        match self {
+            mysql_async::Error::Server(err) => match err.code {
+                // See https://dev.mysql.com/doc/mysql-errors/8.0/en/server-error-reference.html
+                // for the error code definitions.
+
+                // access denied to the server, a database, a table, or an
+                // operation requiring a privilege like REPLICATION SLAVE
+                1044 | 1045 | 1142 | 1227 => true,
+                // unknown database or table
+                1049 | 1146 => true,
+                // the requested binary log position can no longer be read,
+                // e.g. because the file has been purged
+                1236 => true,
+                _ => false,
+            },
+            // We have no information about what happened, so we adopt the same
+            // "indefinite unless proven otherwise" policy as Postgres sources
+            // and keep retrying.
+            _ => false,
+        }
+    }
+}
+
+impl ErrorExt for MySqlError {
+    fn is_definite(&self) -> bool {
+        match self {
+            MySqlError::MySql(err) => err.is_definite(),
+            MySqlError::Generic(_) | MySqlError::Ssh(_) | MySqlError::SshIo(_) => false,
+        }
+    }
+}
+
+enum ReplicationError {
+    /// This error is definite: this source is permanently wedged.
+    /// Returning a definite error will cause the collection to become un-queryable.
+    Definite(anyhow::Error),
+    /// This error may or may not resolve itself in the future, and
+    /// should be retried instead of being added to the output.
+    Indefinite(anyhow::Error),
+}
+
+impl<E: ErrorExt + Into<anyhow::Error>> From<E> for ReplicationError {
+    fn from(err: E) -> Self {
+        if err.is_definite() {
+            Self::Definite(err.into())
+        } else {
+            Self::Indefinite(err.into())
+        }
+    }
+}
+
+macro_rules! try_definite {
+    ($expr:expr $(,)?) => {
+        match $expr {
+            Ok(val) => val,
+            Err(err) => return Err(ReplicationError::Definite(err.into())),
+        }
+    };
+}
+macro_rules! try_indefinite {
+    ($expr:expr $(,)?) => {
+        match $expr {
+            Ok(val) => val,
+            Err(err) => return Err(ReplicationError::Indefinite(err.into())),
+        }
+    };
+}
+
+// Message used to communicate between `get_next_message` and the tokio task
+enum InternalMessage {
+    /// A definite error, emitted at the binlog offset the replication task had
+    /// reached when it occurred. All data sent before the error is at a
+    /// strictly smaller offset.
+    Err {
+        err: SourceReaderError,
+        offset: MzOffset,
+    },
+    Status(HealthStatusUpdate),
+    Value {
+        output: usize,
+        value: Row,
+        offset: MzOffset,
+        diff: Diff,
+        end: bool,
+    },
+}
+
+/// Information required to sync data from MySQL
+pub struct MySqlSourceReader {
+    receiver_stream: Receiver<InternalMessage>,
+
+    // MySQL sources support single-threaded ingestion only, so only one of
+    // the `MySqlSourceReader`s will actually produce data.
+    active_read_worker: bool,
+
+    // The non-active reader (see above `active_read_worker`) has to report back
+    // that is is not consuming from the one [`PartitionId:None`] partition.
+    // Before it can return a [`NextMessage::Finished`]. This is keeping track
+    // of that.
+    reported_unconsumed_partitions: bool,
+}
+
+/// Information about an ingested upstream table
+struct SourceTable {
+    /// The source output index of this table
+    output_index: usize,
+    /// The description of this table at the time the source was created
+    desc: MySqlTableDesc,
+    /// The types that the columns of this table are ingested as
+    scalar_types: Vec<ScalarType>,
+}
+
+/// An internal struct held by the spawned tokio task
+struct MySqlTaskInfo {
+    source_id: GlobalId,
+    connection_config: mz_mysql_util::Config,
+    /// The server ID to identify ourselves with when reading the binary log
+    server_id: u32,
+    /// Our cursor into the binary log, encoded as per
+    /// [`BinlogPosition::to_offset`]. Data is emitted at the offset right
+    /// before the position of the event that follows it, so that resuming at
+    /// an upper always resumes at the position of the next unread event.
+    offset: u64,
+    metrics: MySqlSourceMetrics,
+    /// A map of the schema and name of each table to its information
+    source_tables: HashMap<(String, String), SourceTable>,
+    row_sender: RowSender,
+    sender: Sender<InternalMessage>,
+}
+
+impl SourceConnectionBuilder for MySqlSourceConnection {
+    type Reader = MySqlSourceReader;
+    type OffsetCommitter = LogCommitter;
+
+    fn into_reader(
+        self,
+        _source_name: String,
+        source_id: GlobalId,
+        worker_id: usize,
+        worker_count: usize,
+        consumer_activator: SyncActivator,
+        start_offsets: Vec<(PartitionId, Option<MzOffset>)>,
+        _encoding: SourceDataEncoding,
+        metrics: SourceBaseMetrics,
+        connection_context: ConnectionContext,
+    ) -> Result<(Self::Reader, Self::OffsetCommitter), anyhow::Error> {
+        let active_read_worker =
+            crate::source::responsible_for(&source_id, worker_id, worker_count, &PartitionId::None);
+
+        let (dataflow_tx, dataflow_rx) = tokio::sync::mpsc::channel(50_000);
+
+        // Pick out the partition we care about
+        let start_offset = start_offsets
+            .into_iter()
+            .find_map(|(pid, offset)| {
+                if pid == PartitionId::None {
+                    offset
+                } else {
+                    None
+                }
+            })
+            .unwrap_or_default();
+
+        let connection_config = TokioHandle::current()
+            .block_on(self.connection.config(&*connection_context.secrets_reader))
+            .expect("MySQL connection unexpectedly missing secrets");
+
+        if active_read_worker {
+            let mut source_tables = HashMap::new();
+            for (i, desc) in self.details.tables.into_iter().enumerate() {
+                // Purification only records tables whose types are all
+                // ingestable.
+                let scalar_types = desc
+                    .columns
+                    .iter()
+                    .map(|c| c.scalar_type().expect("validated during purification"))
+                    .collect();
+                source_tables.insert(
+                    (desc.schema_name.clone(), desc.name.clone()),
+                    SourceTable {
+                        output_index: i + 1,
+                        desc,
+                        scalar_types,
+                    },
+                );
+            }
+
+            let task_info = MySqlTaskInfo {
+                source_id,
+                connection_config,
+                server_id: self.details.server_id,
+                offset: start_offset.offset,
+                metrics: MySqlSourceMetrics::new(&metrics, source_id),
+                source_tables,
+                row_sender: RowSender::new(dataflow_tx.clone(), consumer_activator),
+                sender: dataflow_tx,
+            };
+
+            task::spawn(
+                || format!("mysql_source:{}", source_id),
+                mysql_replication_loop(task_info),
+            );
+        }
+
+        Ok((
+            MySqlSourceReader {
+                receiver_stream: dataflow_rx,
+                active_read_worker,
+                reported_unconsumed_partitions: false,
+            },
+            LogCommitter {
+                source_id,
+                worker_id,
+                worker_count,
+            },
+        ))
+    }
+}
+
+impl SourceReader for MySqlSourceReader {
+    type Key = ();
+    type Value = Row;
+    // MySQL can produce deletes that cause retractions
+    type Diff = Diff;
+
+    fn get_next_message(&mut self) -> NextMessage<Self::Key, Self::Value, Self::Diff> {
+        if !self.active_read_worker {
+            if !self.reported_unconsumed_partitions {
+                self.reported_unconsumed_partitions = true;
+                return NextMessage::Ready(SourceMessageType::DropPartitionCapabilities(vec![
+                    PartitionId::None,
+                ]));
+            }
+            return NextMessage::Finished;
+        }
+
+        match self.receiver_stream.recv().now_or_never() {
+            Some(Some(InternalMessage::Value {
+                output,
+                value,
+                offset,
+                diff,
+                end,
+            })) => {
+                let msg = SourceMessage {
+                    output,
+                    upstream_time_millis: None,
+                    key: (),
+                    value,
+                    headers: None,
+                };
+                let ts = (PartitionId::None, offset);
+                if end {
+                    NextMessage::Ready(SourceMessageType::Finalized(Ok(msg), ts, diff))
+                } else {
+                    NextMessage::Ready(SourceMessageType::InProgress(Ok(msg), ts, diff))
+                }
+            }
+            Some(Some(InternalMessage::Status(update))) => {
+                NextMessage::Ready(SourceMessageType::SourceStatus(update))
+            }
+            Some(Some(InternalMessage::Err { err, offset })) => {
+                let ts = (PartitionId::None, offset);
+                NextMessage::Ready(SourceMessageType::Finalized(Err(err), ts, 1))
+            }
+            None => NextMessage::Pending,
+            Some(None) => NextMessage::Finished,
+        }
+    }
+}
+
+/// Defers to `mysql_replication_loop_inner` and sends errors through the channel if they occur
+async fn mysql_replication_loop(mut task_info: MySqlTaskInfo) {
+    match mysql_replication_loop_inner(&mut task_info).await {
+        Ok(()) => {}
+        Err(err) => {
+            // Data is only ever emitted at offsets strictly below our cursor, so
+            // the cursor is the first offset at which the error can be emitted
+            // without being ordered before data we already produced.
+            let offset = MzOffset::from(task_info.offset);
+            // Drop the send error, as we have no way of communicating back to the
+            // source operator if the channel is gone.
+            let _ = task_info
+                .row_sender
+                .sender
+                .send(InternalMessage::Err { err, offset })
+                .await;
+            task_info
+                .row_sender
+                .activator
+                .activate()
+                .expect("mysql reader activation failed");
+        }
+    }
+}
+
+/// Core logic
+async fn mysql_replication_loop_inner(
+    task_info: &mut MySqlTaskInfo,
+) -> Result<(), SourceReaderError> {
+    if task_info.offset == 0 {
+        match task_info.produce_snapshot().await {
+            Ok(_) => {
+                info!(
+                    "replication snapshot for source {} succeeded",
+                    &task_info.source_id
+                );
+            }
+            Err(ReplicationError::Indefinite(e)) => {
+                warn!(
+                    "replication snapshot for source {} failed: {}",
+                    &task_info.source_id, e
+                );
+                // If the channel is shutting down, so is the source.
+                let _ = task_info
+                    .sender
+                    .send(InternalMessage::Status(HealthStatusUpdate {
+                        update: HealthStatus::StalledWithError(e.to_string()),
+                        // As with Postgres sources, halting is the easiest way
+                        // to dump the partially emitted snapshot. The restarted
+                        // storaged instance will take a fresh snapshot.
+                        should_halt: true,
+                    }))
+                    .await;
+
+                future::pending().await
+            }
+            Err(ReplicationError::Definite(e)) => {
+                return Err(SourceReaderError {
+                    inner: SourceErrorDetails::Initialization(e.to_string()),
+                })
+            }
+        }
+    }
+
+    loop {
+        match task_info.produce_replication().await {
+            Err(ReplicationError::Indefinite(e)) => {
+                // If the channel is shutting down, so is the source.
+                let _ = task_info
+                    .sender
+                    .send(InternalMessage::Status(HealthStatusUpdate {
+                        update: HealthStatus::StalledWithError(e.to_string()),
+                        should_halt: false,
+                    }))
+                    .await;
+                warn!(
+                    "replication for source {} interrupted, retrying: {}",
+                    task_info.source_id, e
+                );
+            }
+            Err(ReplicationError::Definite(e)) => {
+                return Err(SourceReaderError {
+                    inner: SourceErrorDetails::Other(e.to_string()),
+                })
+            }
+            Ok(_) => {
+                // shutdown initiated elsewhere
+                return Ok(());
+            }
+        }
+
+        tokio::time::sleep(Duration::from_secs(3)).await;
+        info!("resuming replication for source {}", task_info.source_id);
+    }
+}
+
+struct RowMessage {
+    output_index: usize,
+    row: Row,
+    offset: MzOffset,
+    diff: i64,
+}
+
+/// A type that makes it easy to correctly send inserts and deletes.
+///
+/// Note: `RowSender::delete/insert` should be called with the same
+/// offset until `close_offset` is called, which should be called and awaited
+/// before dropping the `RowSender` or moving onto a new offset.
+/// Internally, this type uses asserts to uphold the first requirement.
+struct RowSender {
+    sender: Sender<InternalMessage>,
+    activator: SyncActivator,
+    buffered_message: Option<RowMessage>,
+}
+
+impl RowSender {
+    /// Create a new `RowSender`.
+    pub fn new(sender: Sender<InternalMessage>, activator: SyncActivator) -> Self {
+        Self {
+            sender,
+            activator,
+            buffered_message: None,
+        }
+    }
+
+    /// Insert a row at an offset.
+    pub async fn insert(&mut self, output_index: usize, row: Row, offset: MzOffset) {
+        self.buffer(output_index, row, offset, 1).await;
+    }
+
+    /// Delete a row at an offset.
+    pub async fn delete(&mut self, output_index: usize, row: Row, offset: MzOffset) {
+        self.buffer(output_index, row, offset, -1).await;
+    }
+
+    /// Finalize an offset, making sure all messages that my be buffered are
+    /// flushed, and that the last message sent is marked as closing the
+    /// `offset`.
+    pub async fn close_offset(&mut self, offset: MzOffset) {
+        if let Some(buffered) = self.buffered_message.take() {
+            assert_eq!(buffered.offset, offset);
+            self.send_row(buffered, true).await;
+        }
+    }
+
+    async fn buffer(&mut self, output_index: usize, row: Row, offset: MzOffset, diff: i64) {
+        if let Some(buffered) = self.buffered_message.take() {
+            assert_eq!(buffered.offset, offset);
+            self.send_row(buffered, false).await;
+        }
+
+        self.buffered_message = Some(RowMessage {
+            output_index,
+            row,
+            offset,
+            diff,
+        });
+    }
+
+    async fn send_row(&self, message: RowMessage, end: bool) {
+        // a closed receiver means the source has been shutdown
+        // (dropped or the process is dying), so just continue on
+        // without activation
+        if let Ok(_) = self
+            .sender
+            .send(InternalMessage::Value {
+                output: message.output_index,
+                value: message.row,
+                offset: message.offset,
+                diff: message.diff,
+                end,
+            })
+            .await
+        {
+            self.activator
+                .activate()
+                .expect("mysql reader activation failed");
+        }
+    }
+}
+
+// implement the core mysql logic in this impl block
+impl MySqlTaskInfo {
+    fn task_name(&self) -> String {
+        format!("mysql_source:{}", self.source_id)
+    }
+
+    /// Validates that all expected tables exist upstream and have the same schema
+    fn validate_tables(&self, tables: Vec<MySqlTableDesc>) -> Result<(), anyhow::Error> {
+        let upstream_tables: HashMap<_, _> = tables
+            .into_iter()
+            .map(|t| ((t.schema_name.clone(), t.name.clone()), t))
+            .collect();
+        for (name, info) in self.source_tables.iter() {
+            match upstream_tables.get(name) {
+                Some(upstream) => {
+                    if upstream != &info.desc {
+                        error!(
+                            "Error validating upstream table. Expected: {:?} Actual: {:?}",
+                            &info.desc, upstream
+                        );
+                        bail!(
+                            "Schema for table {}.{} differs, recreate Materialize source to use new schema",
+                            info.desc.schema_name,
+                            info.desc.name
+                        )
+                    }
+                }
+                None => {
+                    error!(
+                        "upstream missing table: {}.{}",
+                        info.desc.schema_name, info.desc.name
+                    );
+                    bail!(
+                        "Upstream missing expected table {}.{}",
+                        info.desc.schema_name,
+                        info.desc.name
+                    )
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Produces the initial snapshot of the data and positions the cursor at
+    /// the binary log position that the snapshot is consistent with.
+    async fn produce_snapshot(&mut self) -> Result<(), ReplicationError> {
+        let task_name = self.task_name();
+        let mut lock_conn = self.connection_config.connect(&task_name).await?;
+        let mut conn = self.connection_config.connect(&task_name).await?;
+
+        try_definite!(mz_mysql_util::validate_binlog_config(&mut conn).await);
+        let upstream_tables = mz_mysql_util::schema_info(&mut conn).await?;
+        try_definite!(self.validate_tables(upstream_tables));
+
+        // Briefly block writes to the ingested tables so that we can start a
+        // transaction whose snapshot is consistent with a known binary log
+        // position.
+        let table_list = self
+            .source_tables
+            .values()
+            .map(|info| {
+                format!(
+                    "{}.{}",
+                    quote_identifier(&info.desc.schema_name),
+                    quote_identifier(&info.desc.name)
+                )
+            })
+            .join(", ");
+        lock_conn
+            .query_drop(format!("FLUSH TABLES {} WITH READ LOCK", table_list))
+            .await?;
+        conn.query_drop("SET time_zone = '+00:00'").await?;
+        conn.query_drop("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ")
+            .await?;
+        conn.query_drop("START TRANSACTION WITH CONSISTENT SNAPSHOT, READ ONLY")
+            .await?;
+        let position = BinlogPosition::current(&mut lock_conn).await?;
+        lock_conn.query_drop("UNLOCK TABLES").await?;
+        try_indefinite!(lock_conn.disconnect().await);
+
+        self.offset = try_definite!(position.to_offset());
+        let snapshot_offset = MzOffset::from(self.offset - 1);
+
+        for info in self.source_tables.values() {
+            let query = format!(
+                "SELECT {} FROM {}.{}",
+                info.desc
+                    .columns
+                    .iter()
+                    .map(|c| quote_identifier(&c.name))
+                    .join(", "),
+                quote_identifier(&info.desc.schema_name),
+                quote_identifier(&info.desc.name)
+            );
+            let mut result = conn.query_iter(query).await?;
+            while let Some(row) = result.next().await? {
+                let row = try_definite!(pack_row(info, row.unwrap()));
+                self.row_sender
+                    .insert(info.output_index, row, snapshot_offset)
+                    .await;
+                // Failure scenario after we have produced at least one row, but before a
+                // successful `COMMIT`
+                fail::fail_point!("mysql_snapshot_failure", |_| {
+                    Err(ReplicationError::Indefinite(anyhow::anyhow!(
+                        "recoverable errors should crash the process"
+                    )))
+                });
+            }
+
+            self.metrics.tables.inc();
+        }
+        self.metrics.offset.set(self.offset);
+        conn.query_drop("COMMIT").await?;
+        try_indefinite!(conn.disconnect().await);
+
+        // close the current `row_sender` context after we are sure we have not errored
+        // out (in the commit).
+        self.row_sender.close_offset(snapshot_offset).await;
+        Ok(())
+    }
+
+    /// Emits the buffered changes of a transaction whose final event ends at
+    /// `end`.
+    async fn commit(
+        &mut self,
+        end: BinlogPosition,
+        deletes: &mut Vec<(usize, Row)>,
+        inserts: &mut Vec<(usize, Row)>,
+    ) -> Result<(), ReplicationError> {
+        self.metrics.transactions.inc();
+        self.offset = try_definite!(end.to_offset());
+        let offset = MzOffset::from(self.offset - 1);
+
+        for (output, row) in deletes.drain(..) {
+            self.row_sender.delete(output, row, offset).await;
+        }
+        for (output, row) in inserts.drain(..) {
+            self.row_sender.insert(output, row, offset).await;
+        }
+
+        self.row_sender.close_offset(offset).await;
+        self.metrics.offset.set(self.offset);
+        Ok(())
+    }
+
+    async fn produce_replication(&mut self) -> Result<(), ReplicationError> {
+        use ReplicationError::*;
+
+        let task_name = self.task_name();
+        let mut conn = self.connection_config.connect(&task_name).await?;
+        try_definite!(mz_mysql_util::validate_binlog_config(&mut conn).await);
+        let upstream_tables = mz_mysql_util::schema_info(&mut conn).await?;
+        try_definite!(self.validate_tables(upstream_tables));
+
+        // Offsets only record the sequence number of the binary log file, so
+        // we recover the rest of its name from the server.
+        let current = BinlogPosition::current(&mut conn).await?;
+        let start = BinlogPosition::from_offset(current.basename(), self.offset);
+        let mut file = start.file.clone();
+
+        let (mut stream, _tunnel) = conn.into_binlog_stream(self.server_id, &start).await?;
+
+        let mut inserts = vec![];
+        let mut deletes = vec![];
+
+        while let Some(event) = stream.next().await {
+            let event = event?;
+            self.metrics.total.inc();
+            // The position of the event that follows this one.
+            let end = BinlogPosition {
+                file: file.clone(),
+                position: u64::from(event.header().log_pos()),
+            };
+
+            match try_definite!(event.read_data()) {
+                Some(EventData::RotateEvent(rotate)) => {
+                    file = rotate.name().into_owned();
+                }
+                Some(EventData::RowsEvent(rows_event)) => {
+                    let Some(tme) = stream.get_tme(rows_event.table_id()) else {
+                        return Err(Definite(anyhow!(
+                            "rows event for unknown table id {}",
+                            rows_event.table_id()
+                        )));
+                    };
+                    let name = (
+                        tme.database_name().into_owned(),
+                        tme.table_name().into_owned(),
+                    );
+                    let Some(info) = self.source_tables.get(&name) else {
+                        self.metrics.ignored.inc();
+                        continue;
+                    };
+                    for rows in rows_event.rows(tme) {
+                        let (before, after) = try_definite!(rows);
+                        match (&before, &after) {
+                            (None, Some(_)) => self.metrics.inserts.inc(),
+                            (Some(_), Some(_)) => self.metrics.updates.inc(),
+                            (Some(_), None) => self.metrics.deletes.inc(),
+                            (None, None) => {}
+                        }
+                        if let Some(before) = before {
+                            let values = try_definite!(binlog_values(info, before.unwrap()));
+                            deletes
+                                .push((info.output_index, try_definite!(pack_row(info, values))));
+                        }
+                        if let Some(after) = after {
+                            let values = try_definite!(binlog_values(info, after.unwrap()));
+                            inserts
+                                .push((info.output_index, try_definite!(pack_row(info, values))));
+                        }
+                    }
+                }
+                Some(EventData::XidEvent(_)) => {
+                    self.commit(end, &mut deletes, &mut inserts).await?;
+                }
+                Some(EventData::QueryEvent(query)) => {
+                    let statement = query.query();
+                    if statement.eq_ignore_ascii_case("BEGIN") {
+                        continue;
+                    }
+                    if statement.eq_ignore_ascii_case("COMMIT") {
+                        // Transactions against non-transactional storage
+                        // engines end with a `COMMIT` rather than an XID.
+                        self.commit(end, &mut deletes, &mut inserts).await?;
+                        continue;
+                    }
+
+                    // With row-based logging, any other statement is DDL.
+                    if let Some(name) = truncated_table(&query.schema(), &statement) {
+                        if self.source_tables.contains_key(&name) {
+                            return Err(Definite(anyhow!(
+                                "source table {}.{} got truncated",
+                                name.0,
+                                name.1
+                            )));
+                        }
+                    }
+                    let mut conn = self.connection_config.connect(&task_name).await?;
+                    let upstream_tables = mz_mysql_util::schema_info(&mut conn).await?;
+                    try_definite!(self.validate_tables(upstream_tables));
+                    try_indefinite!(conn.disconnect().await);
+                }
+                _ => self.metrics.ignored.inc(),
+            }
+        }
+        Err(Indefinite(anyhow!("replication stream ended")))
+    }
+}
+
+/// Unwraps the values of a row read from the binary log.
+fn binlog_values(
+    info: &SourceTable,
+    values: Vec<BinlogValue<'static>>,
+) -> Result<Vec<Value>, anyhow::Error> {
+    values
+        .into_iter()
+        .map(|value| match value {
+            BinlogValue::Value(value) => Ok(value),
+            _ => bail!(
+                "unexpected JSON value in source table {}.{}",
+                info.desc.schema_name,
+                info.desc.name
+            ),
+        })
+        .collect()
+}
+
+/// Converts the values of a row of an upstream table into a [`Row`].
+fn pack_row(info: &SourceTable, values: Vec<Value>) -> Result<Row, anyhow::Error> {
+    if values.len() != info.desc.columns.len() {
+        bail!(
+            "source table {}.{} has been altered",
+            info.desc.schema_name,
+            info.desc.name
+        );
+    }
+    let mut row = Row::default();
+    let mut packer = row.packer();
+    for ((column, scalar_type), value) in
+        info.desc.columns.iter().zip(&info.scalar_types).zip(values)
+    {
+        pack_value(&mut packer, column, scalar_type, value)?;
+    }
+    Ok(row)
+}
+
+/// Packs a value of `column` into `packer` as a datum of type `scalar_type`.
+///
+/// Values are received in the text protocol during snapshots and in binary
+/// form from the binary log.
+fn pack_value(
+    packer: &mut RowPacker,
+    column: &MySqlColumnDesc,
+    scalar_type: &ScalarType,
+    value: Value,
+) -> Result<(), anyhow::Error> {
+    match value {
+        Value::NULL => packer.push(Datum::Null),
+        Value::Bytes(b) if matches!(scalar_type, ScalarType::Bytes) => {
+            packer.push(Datum::Bytes(&b))
+        }
+        Value::Bytes(b) => pack_text(packer, scalar_type, std::str::from_utf8(&b)?)?,
+        Value::Int(i) => {
+            // The binary log does not record signedness, so values of unsigned
+            // columns that exceed the signed range arrive as negative numbers.
+            let n = match column.integer_bits() {
+                Some(bits) if column.is_unsigned() && i < 0 => {
+                    let mask = u64::MAX >> (64 - bits);
+                    i128::from(u64::from_ne_bytes(i.to_ne_bytes()) & mask)
+                }
+                _ => i128::from(i),
+            };
+            pack_text(packer, scalar_type, &n.to_string())?
+        }
+        Value::UInt(u) => pack_text(packer, scalar_type, &u.to_string())?,
+        Value::Float(f) if matches!(scalar_type, ScalarType::Float32) => {
+            packer.push(Datum::from(f))
+        }
+        Value::Double(f) if matches!(scalar_type, ScalarType::Float64) => {
+            packer.push(Datum::from(f))
+        }
+        Value::Date(year, month, day, hour, minute, second, micros) => {
+            let s = format!(
+                "{:04}-{:02}-{:02} {:02}:{:02}:{:02}.{:06}",
+                year, month, day, hour, minute, second, micros
+            );
+            pack_text(packer, scalar_type, &s)?
+        }
+        value => bail!(
+            "unexpected value {:?} for column {} of type {}",
+            value,
+            column.name,
+            column.column_type
+        ),
+    }
+    Ok(())
+}
+
+/// Parses the text representation of a value into a datum of type
+/// `scalar_type` and packs it into `packer`.
+fn pack_text(
+    packer: &mut RowPacker,
+    scalar_type: &ScalarType,
+    s: &str,
+) -> Result<(), anyhow::Error> {
+    let datum = match scalar_type {
+        ScalarType::Int16 => Datum::from(strconv::parse_int16(s)?),
+        ScalarType::Int32 => Datum::from(strconv::parse_int32(s)?),
+        ScalarType::Int64 => Datum::from(strconv::parse_int64(s)?),
+        ScalarType::UInt16 => Datum::from(strconv::parse_uint16(s)?),
+        ScalarType::UInt32 => Datum::from(strconv::parse_uint32(s)?),
+        ScalarType::UInt64 => Datum::from(strconv::parse_uint64(s)?),
+        ScalarType::Float32 => Datum::from(strconv::parse_float32(s)?),
+        ScalarType::Float64 => Datum::from(strconv::parse_float64(s)?),
+        ScalarType::Numeric { max_scale } => {
+            let mut n = strconv::parse_numeric(s)?;
+            if let Some(max_scale) = max_scale {
+                numeric::rescale(&mut n.0, max_scale.into_u8())?;
+            }
+            Datum::Numeric(n)
+        }
+        ScalarType::String => Datum::String(s),
+        ScalarType::Bytes => Datum::Bytes(s.as_bytes()),
+        ScalarType::Date => Datum::Date(strconv::parse_date(s)?),
+        ScalarType::Timestamp => Datum::Timestamp(strconv::parse_timestamp(s)?),
+        ScalarType::TimestampTz => match parse_epoch(s) {
+            Some(ts) => Datum::TimestampTz(ts),
+            None => Datum::TimestampTz(strconv::parse_timestamptz(s)?),
+        },
+        _ => bail!("unsupported type {:?}", scalar_type),
+    };
+    packer.push(datum);
+    Ok(())
+}
+
+/// Parses a number of seconds since the Unix epoch with an optional fractional
+/// part, which is how the binary log encodes `TIMESTAMP` values.
+fn parse_epoch(s: &str) -> Option<CheckedTimestamp<DateTime<Utc>>> {
+    let (secs, frac) = s.split_once('.').unwrap_or((s, ""));
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if secs.is_empty() || !is_digits(secs) || !is_digits(frac) || frac.len() > 6 {
+        return None;
+    }
+    let secs: i64 = secs.parse().ok()?;
+    let micros: u32 = format!("{:0<6}", frac).parse().ok()?;
+    let ts = NaiveDateTime::from_timestamp_opt(secs, micros * 1_000)?;
+    CheckedTimestamp::from_timestamplike(DateTime::from_utc(ts, Utc)).ok()
+}
+
+/// Returns the schema and name of the table that `statement` truncates, if it
+/// is a `TRUNCATE` statement executed with `schema` as the default schema.
+fn truncated_table(schema: &str, statement: &str) -> Option<(String, String)> {
+    let mut words = statement.split_whitespace();
+    if !words.next()?.eq_ignore_ascii_case("truncate") {
+        return None;
+    }
+    let mut name = words.next()?;
+    if name.eq_ignore_ascii_case("table") {
+        name = words.next()?;
+    }
+    let unquote = |ident: &str| ident.trim_matches('`').replace("``", "`");
+    let name = name.trim_end_matches(';');
+    Some(match name.split_once('.') {
+        Some((schema, table)) => (unquote(schema), unquote(table)),
+        None => (schema.to_string(), unquote(name)),
+    })
+}
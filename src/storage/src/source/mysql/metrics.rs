@@ -0,0 +1,59 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use prometheus::core::AtomicU64;
+
+use mz_ore::metrics::{CounterVecExt, DeleteOnDropCounter, DeleteOnDropGauge, GaugeVecExt};
+use mz_repr::GlobalId;
+
+use crate::source::metrics::SourceBaseMetrics;
+
+pub(super) struct MySqlSourceMetrics {
+    pub inserts: DeleteOnDropCounter<'static, AtomicU64, Vec<String>>,
+    pub updates: DeleteOnDropCounter<'static, AtomicU64, Vec<String>>,
+    pub deletes: DeleteOnDropCounter<'static, AtomicU64, Vec<String>>,
+    pub ignored: DeleteOnDropCounter<'static, AtomicU64, Vec<String>>,
+    pub total: DeleteOnDropCounter<'static, AtomicU64, Vec<String>>,
+    pub transactions: DeleteOnDropCounter<'static, AtomicU64, Vec<String>>,
+    pub tables: DeleteOnDropGauge<'static, AtomicU64, Vec<String>>,
+    pub offset: DeleteOnDropGauge<'static, AtomicU64, Vec<String>>,
+}
+
+impl MySqlSourceMetrics {
+    pub(super) fn new(base_metrics: &SourceBaseMetrics, source_id: GlobalId) -> Self {
+        let labels = &[source_id.to_string()];
+        let mysql_metrics = &base_metrics.mysql_source_specific;
+        Self {
+            inserts: mysql_metrics
+                .insert_rows
+                .get_delete_on_drop_counter(labels.to_vec()),
+            updates: mysql_metrics
+                .update_rows
+                .get_delete_on_drop_counter(labels.to_vec()),
+            deletes: mysql_metrics
+                .delete_rows
+                .get_delete_on_drop_counter(labels.to_vec()),
+            ignored: mysql_metrics
+                .ignored_events
+                .get_delete_on_drop_counter(labels.to_vec()),
+            total: mysql_metrics
+                .total_events
+                .get_delete_on_drop_counter(labels.to_vec()),
+            transactions: mysql_metrics
+                .transactions
+                .get_delete_on_drop_counter(labels.to_vec()),
+            tables: mysql_metrics
+                .tables
+                .get_delete_on_drop_gauge(labels.to_vec()),
+            offset: mysql_metrics
+                .binlog_offset
+                .get_delete_on_drop_gauge(labels.to_vec()),
+        }
+    }
+}
@@ -684,6 +684,7 @@ mod tests {
             remap_shard: shard,
             data_shard: ShardId::new(),
             status_shard: None,
+            missing_values: None,
         };
 
         let clock_stream = futures::stream::iter((0..).map(|seconds| {
@@ -50,13 +50,16 @@ use tracing::{debug, error, trace, warn};
 
 use mz_cloud_resources::AwsExternalIdPrefix;
 use mz_expr::PartitionId;
+use mz_interchange::parquet::ParquetDecoder;
 use mz_ore::retry::{Retry, RetryReader};
 use mz_ore::task;
 use mz_repr::GlobalId;
 use mz_secrets::SecretsReader;
 use mz_storage_client::types::connections::aws::AwsConfig;
 use mz_storage_client::types::connections::ConnectionContext;
-use mz_storage_client::types::sources::encoding::SourceDataEncoding;
+use mz_storage_client::types::sources::encoding::{
+    DataEncodingInner, ParquetEncoding, SourceDataEncoding,
+};
 use mz_storage_client::types::sources::{Compression, MzOffset, S3KeySource, S3SourceConnection};
 
 use self::metrics::{BucketMetrics, ScanBucketMetrics};
@@ -71,6 +74,7 @@ use super::metrics::SourceBaseMetrics;
 
 mod metrics;
 mod notifications;
+mod parquet;
 
 struct InternalMessage {
     record: Option<Vec<u8>>,
@@ -145,6 +149,7 @@ async fn download_objects_task(
     aws_external_id_prefix: Option<AwsExternalIdPrefix>,
     activator: SyncActivator,
     compression: Compression,
+    parquet_decoder: Option<ParquetDecoder>,
     metrics: SourceBaseMetrics,
     secrets_reader: Arc<dyn SecretsReader>,
 ) {
@@ -207,16 +212,32 @@ async fn download_objects_task(
                 let (tx, activator, client, msg_ref, sid) =
                     (&tx, &activator, &client, &msg, &source_id);
 
-                let download_result = download_object(
-                    tx,
-                    activator,
-                    client,
-                    &msg_ref.bucket,
-                    &msg_ref.key,
-                    compression,
-                    sid,
-                )
-                .await;
+                let download_result = match &parquet_decoder {
+                    Some(decoder) => {
+                        parquet::download_object(
+                            tx,
+                            activator,
+                            client,
+                            &msg_ref.bucket,
+                            &msg_ref.key,
+                            decoder,
+                            sid,
+                        )
+                        .await
+                    }
+                    None => {
+                        download_object(
+                            tx,
+                            activator,
+                            client,
+                            &msg_ref.bucket,
+                            &msg_ref.key,
+                            compression,
+                            sid,
+                        )
+                        .await
+                    }
+                };
 
                 // Extract and handle status updates
                 match download_result {
@@ -822,10 +843,19 @@ impl SourceConnectionBuilder for S3SourceConnection {
         worker_count: usize,
         consumer_activator: SyncActivator,
         _restored_offsets: Vec<(PartitionId, Option<MzOffset>)>,
-        _encoding: SourceDataEncoding,
+        encoding: SourceDataEncoding,
         metrics: crate::source::metrics::SourceBaseMetrics,
         connection_context: ConnectionContext,
     ) -> Result<(Self::Reader, Self::OffsetCommitter), anyhow::Error> {
+        // Parquet objects are decoded by the downloader rather than by the
+        // decode operator, since they cannot be decoded as a byte stream.
+        let parquet_decoder = match &encoding.value_ref().inner {
+            DataEncodingInner::Parquet(ParquetEncoding { schema }) => {
+                Some(ParquetDecoder::new(schema)?)
+            }
+            _ => None,
+        };
+
         let active_read_worker =
             crate::source::responsible_for(&source_id, worker_id, worker_count, &PartitionId::None);
 
@@ -847,6 +877,7 @@ impl SourceConnectionBuilder for S3SourceConnection {
                     connection_context.aws_external_id_prefix.clone(),
                     consumer_activator,
                     self.compression,
+                    parquet_decoder,
                     metrics.clone(),
                     secrets_reader,
                 )
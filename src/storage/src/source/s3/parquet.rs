@@ -0,0 +1,278 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Downloading of Parquet objects.
+//!
+//! Parquet objects are not streamed to dataflow as raw bytes. Instead, the
+//! object's footer is fetched with a ranged GET, and then each row group is
+//! fetched and decoded in turn on a blocking thread. The decoded rows are sent
+//! to dataflow as frames (see [`crate::decode::parquet`]), one chunk per row
+//! group, so that only a single row group needs to be held in memory at once.
+
+use std::cmp;
+use std::io::{self, Read, Seek, SeekFrom};
+
+use aws_sdk_s3::Client as S3Client;
+use timely::scheduling::SyncActivator;
+use tokio::runtime::Handle as TokioHandle;
+use tokio::sync::mpsc::Sender;
+use tokio::time::Duration;
+use tracing::trace;
+
+use mz_interchange::parquet::ParquetDecoder;
+use mz_ore::cast::CastFrom;
+use mz_ore::retry::Retry;
+use mz_ore::task;
+
+use crate::decode::parquet::{encode_error_frame, encode_row_frame};
+
+use super::{DownloadError, DownloadMetricUpdate, InternalMessage, S3Result};
+
+/// Minimum size of each ranged GET.
+///
+/// Parquet readers issue many small reads (e.g. for the footer and page
+/// headers), so reads are coalesced into windows of at least this size.
+const READ_WINDOW: u64 = 8 << 20;
+
+/// Downloads and decodes the Parquet object `bucket/key`, sending one chunk
+/// of frames per row group to `tx`.
+///
+/// Errors in the object itself, e.g. a corrupt footer or a column that does
+/// not match the source's schema, are sent to dataflow as decode errors rather
+/// than failing the source. If the decoder panics, the object is reported as
+/// failed to download.
+pub(super) async fn download_object(
+    tx: &Sender<S3Result<InternalMessage>>,
+    activator: &SyncActivator,
+    client: &S3Client,
+    bucket: &str,
+    key: &str,
+    decoder: &ParquetDecoder,
+    source_id: &str,
+) -> Result<DownloadMetricUpdate, DownloadError> {
+    let len = match client.head_object().bucket(bucket).key(key).send().await {
+        Ok(obj) => u64::try_from(obj.content_length()).unwrap_or(0),
+        Err(err) => {
+            return Err(DownloadError::Failed {
+                err: io::Error::new(io::ErrorKind::Other, err),
+            })
+        }
+    };
+    if len == 0 {
+        trace!("source_id={} empty object {}/{}", source_id, bucket, key);
+        return Ok(Default::default());
+    }
+
+    let reader = RangeReader {
+        handle: TokioHandle::current(),
+        client: client.clone(),
+        bucket: bucket.to_string(),
+        key: key.to_string(),
+        len,
+        pos: 0,
+        buf_start: 0,
+        buf: Vec::new(),
+    };
+
+    // Decoding happens on a blocking thread, which hands each row group's
+    // chunk back to this task to forward to dataflow. The small channel
+    // capacity bounds how many decoded row groups are buffered.
+    let (chunk_tx, mut chunk_rx) = tokio::sync::mpsc::channel(1);
+    let decode_task = task::spawn_blocking(
+        || format!("s3_parquet_decode:{}:{}/{}", source_id, bucket, key),
+        {
+            let decoder = decoder.clone();
+            let object = format!("{}/{}", bucket, key);
+            move || decode_object(reader, &decoder, &object, &chunk_tx)
+        },
+    );
+
+    let mut update = DownloadMetricUpdate::default();
+    while let Some(chunk) = chunk_rx.recv().await {
+        update.bytes += u64::cast_from(chunk.len());
+        update.messages += 1;
+        if tx
+            .send(Ok(InternalMessage {
+                record: Some(chunk),
+            }))
+            .await
+            .is_err()
+        {
+            return Err(DownloadError::SendFailed);
+        }
+        activator.activate().expect("s3 reader activation failed");
+    }
+    match decode_task.await {
+        Ok(result) => result?,
+        // A panic while decoding one object must not take down the worker, so
+        // it is reported as a failure to read the object, which surfaces as an
+        // error of the source.
+        Err(err) => {
+            return Err(DownloadError::Failed {
+                err: io::Error::new(
+                    io::ErrorKind::Other,
+                    format!("decoding parquet object {}/{} failed: {}", bucket, key, err),
+                ),
+            })
+        }
+    }
+
+    if tx.send(Ok(InternalMessage { record: None })).await.is_err() {
+        return Err(DownloadError::SendFailed);
+    }
+    activator.activate().expect("s3 reader activation failed");
+    Ok(update)
+}
+
+/// Decodes `reader` one row group at a time, sending a chunk of frames for
+/// each.
+fn decode_object<R: Read + Seek>(
+    reader: R,
+    decoder: &ParquetDecoder,
+    object: &str,
+    chunk_tx: &Sender<Vec<u8>>,
+) -> Result<(), DownloadError> {
+    let send = |chunk: Vec<u8>| {
+        chunk_tx
+            .blocking_send(chunk)
+            .map_err(|_| DownloadError::SendFailed)
+    };
+    let error_chunk = |err: &str| {
+        let mut chunk = Vec::new();
+        encode_error_frame(&mut chunk, &format!("{}: {}", object, err));
+        chunk
+    };
+
+    let row_groups = match decoder.read(reader) {
+        Ok(row_groups) => row_groups,
+        Err(err) => return send(error_chunk(&format!("{:#}", err))),
+    };
+    for row_group in row_groups {
+        let rows = match row_group {
+            Ok(rows) => rows,
+            // The reader's position within the object is unknown after a
+            // failed read, so give up on the rest of it.
+            Err(err) => return send(error_chunk(&format!("{:#}", err))),
+        };
+        let mut chunk = Vec::new();
+        for row in rows {
+            match row {
+                Ok(row) => encode_row_frame(&mut chunk, &row),
+                Err(err) => {
+                    // The decode operator stops reading a chunk at its first
+                    // error, so each error must end its chunk.
+                    encode_error_frame(&mut chunk, &format!("{}: {}", object, err));
+                    send(std::mem::take(&mut chunk))?;
+                }
+            }
+        }
+        if !chunk.is_empty() {
+            send(chunk)?;
+        }
+    }
+    Ok(())
+}
+
+/// A blocking [`Read`] + [`Seek`] view of an S3 object that fetches the
+/// bytes it needs with ranged GETs.
+///
+/// Must only be used from a thread on which blocking is acceptable.
+struct RangeReader {
+    handle: TokioHandle,
+    client: S3Client,
+    bucket: String,
+    key: String,
+    /// The length of the object.
+    len: u64,
+    /// The current position within the object.
+    pos: u64,
+    /// The offset within the object of the first byte of `buf`.
+    buf_start: u64,
+    /// The most recently fetched range of the object.
+    buf: Vec<u8>,
+}
+
+impl RangeReader {
+    /// Replaces `buf` with the bytes of the object in `[start, end)`.
+    fn fetch(&mut self, start: u64, end: u64) -> io::Result<()> {
+        let range = format!("bytes={}-{}", start, end - 1);
+        let (client, bucket, key, range) = (&self.client, &self.bucket, &self.key, &range);
+        let bytes = self.handle.block_on(
+            Retry::default()
+                .max_duration(Duration::from_secs(30))
+                .retry_async(|_| async move {
+                    let obj = client
+                        .get_object()
+                        .bucket(bucket)
+                        .key(key)
+                        .range(range)
+                        .send()
+                        .await
+                        .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+                    obj.body
+                        .collect()
+                        .await
+                        .map(|data| data.into_bytes())
+                        .map_err(|e| io::Error::new(io::ErrorKind::Other, e))
+                }),
+        )?;
+        if u64::cast_from(bytes.len()) != end - start {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                format!(
+                    "expected {} bytes from {}/{}, got {}",
+                    end - start,
+                    self.bucket,
+                    self.key,
+                    bytes.len()
+                ),
+            ));
+        }
+        self.buf_start = start;
+        self.buf = bytes.to_vec();
+        Ok(())
+    }
+}
+
+impl Read for RangeReader {
+    fn read(&mut self, out: &mut [u8]) -> io::Result<usize> {
+        if out.is_empty() || self.pos >= self.len {
+            return Ok(0);
+        }
+        let buf_end = self.buf_start + u64::cast_from(self.buf.len());
+        if self.pos < self.buf_start || self.pos >= buf_end {
+            let want = cmp::max(u64::cast_from(out.len()), READ_WINDOW);
+            let end = cmp::min(self.pos.saturating_add(want), self.len);
+            self.fetch(self.pos, end)?;
+        }
+        let offset = usize::cast_from(self.pos - self.buf_start);
+        let n = cmp::min(out.len(), self.buf.len() - offset);
+        out[..n].copy_from_slice(&self.buf[offset..offset + n]);
+        self.pos += u64::cast_from(n);
+        Ok(n)
+    }
+}
+
+impl Seek for RangeReader {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let (base, delta) = match pos {
+            SeekFrom::Start(pos) => (pos, 0),
+            SeekFrom::End(delta) => (self.len, delta),
+            SeekFrom::Current(delta) => (self.pos, delta),
+        };
+        let pos = i128::from(base) + i128::from(delta);
+        self.pos = u64::try_from(pos).map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative position",
+            )
+        })?;
+        Ok(self.pos)
+    }
+}
@@ -8,6 +8,7 @@
 use std::any::Any;
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
 use std::rc::Rc;
 use std::sync::Arc;
 
@@ -86,6 +87,8 @@ pub struct StorageState {
     pub timely_worker_peers: usize,
     /// Configuration for source and sink connections.
     pub connection_context: ConnectionContext,
+    /// Configuration for the state of upsert sources.
+    pub upsert_config: UpsertConfig,
     /// A process-global cache of (blob_uri, consensus_uri) -> PersistClient.
     /// This is intentionally shared between workers
     pub persist_clients: Arc<Mutex<PersistClientCache>>,
@@ -101,6 +104,18 @@ pub struct StorageState {
     pub dropped_ids: Vec<GlobalId>,
 }
 
+/// Configuration for the state that upsert sources keep about the current
+/// value of each key.
+#[derive(Clone, Debug)]
+pub struct UpsertConfig {
+    /// A directory to which upsert state may be spilled. If absent, upsert
+    /// state is kept entirely in memory.
+    pub scratch_directory: Option<PathBuf>,
+    /// The number of bytes of recently written state that each upsert
+    /// operator keeps in memory before spilling it to the scratch directory.
+    pub memory_limit: usize,
+}
+
 /// This maintains an additional read hold on the source data for a sink, alongside
 /// the controller's hold and the handle used to read the shard internally.
 /// This is useful because environmentd's hold might expire, and the handle we use
@@ -253,6 +268,13 @@ impl<'w, A: Allocate> Worker<'w, A> {
     pub fn handle_storage_command(&mut self, cmd: StorageCommand) {
         match cmd {
             StorageCommand::InitializationComplete => (),
+            StorageCommand::UpdateConfiguration(params) => {
+                // The persist config is shared by all clients in the cache, so
+                // this also affects the dataflows that are already running.
+                params
+                    .persist
+                    .apply(self.storage_state.persist_clients.blocking_lock().cfg());
+            }
             StorageCommand::CreateSources(ingestions) => {
                 for ingestion in ingestions {
                     // Remember the ingestion description to facilitate possible
@@ -480,7 +502,9 @@ impl<'w, A: Allocate> Worker<'w, A> {
                         }
                     })
                 }
-                StorageCommand::AllowCompaction(_) | StorageCommand::InitializationComplete => (),
+                StorageCommand::AllowCompaction(_)
+                | StorageCommand::InitializationComplete
+                | StorageCommand::UpdateConfiguration(_) => (),
             }
         }
 
@@ -177,6 +177,7 @@ where
                 remap_shard: mz_persist_client::ShardId::new(),
                 data_shard: mz_persist_client::ShardId::new(),
                 status_shard: None,
+                missing_values: None,
             };
             let data_shard = collection_metadata.data_shard.clone();
             let id = GlobalId::User(1);
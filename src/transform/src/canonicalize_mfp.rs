@@ -50,6 +50,10 @@ use std::collections::{BTreeMap, BTreeSet};
 pub struct CanonicalizeMfp;
 
 impl crate::Transform for CanonicalizeMfp {
+    fn recursion_safe(&self) -> bool {
+        true
+    }
+
     #[tracing::instrument(
         target = "optimizer"
         level = "trace",
@@ -43,6 +43,10 @@ impl CheckedRecursion for ColumnKnowledge {
 }
 
 impl crate::Transform for ColumnKnowledge {
+    fn recursion_safe(&self) -> bool {
+        true
+    }
+
     /// Transforms an expression through accumulated knowledge.
     #[tracing::instrument(
         target = "optimizer"
@@ -110,7 +114,14 @@ impl ColumnKnowledge {
                     }
                     Ok(body_knowledge)
                 }
-                MirRelationExpr::LetRec { .. } => Err(crate::TransformError::LetRecUnsupported)?,
+                MirRelationExpr::LetRec { values, body, .. } => {
+                    // Uses of recursive bindings fall back to the knowledge derived
+                    // from their declared types, which holds for every iterate.
+                    for value in values.iter_mut() {
+                        self.harvest(value, knowledge, knowledge_stack)?;
+                    }
+                    self.harvest(body, knowledge, knowledge_stack)
+                }
                 MirRelationExpr::Project { input, outputs } => {
                     let input_knowledge = self.harvest(input, knowledge, knowledge_stack)?;
                     Ok(outputs
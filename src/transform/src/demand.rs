@@ -52,6 +52,10 @@ impl CheckedRecursion for Demand {
 }
 
 impl crate::Transform for Demand {
+    fn recursion_safe(&self) -> bool {
+        true
+    }
+
     #[tracing::instrument(
         target = "optimizer"
         level = "trace",
@@ -112,7 +116,28 @@ impl Demand {
 
                     self.action(value, needs, gets)
                 }
-                MirRelationExpr::LetRec { .. } => Err(crate::TransformError::LetRecUnsupported)?,
+                MirRelationExpr::LetRec {
+                    ids, values, body, ..
+                } => {
+                    // Recursive bindings are used by each other, so we conservatively
+                    // demand all of their columns rather than harvest requirements.
+                    let priors = ids
+                        .iter()
+                        .map(|id| (Id::Local(*id), gets.insert(Id::Local(*id), HashSet::new())))
+                        .collect::<Vec<_>>();
+                    self.action(body, columns, gets)?;
+                    for value in values.iter_mut() {
+                        let arity = value.arity();
+                        self.action(value, (0..arity).collect(), gets)?;
+                    }
+                    for (id, prior) in priors {
+                        gets.remove(&id);
+                        if let Some(prior) = prior {
+                            gets.insert(id, prior);
+                        }
+                    }
+                    Ok(())
+                }
                 MirRelationExpr::Project { input, outputs } => self.action(
                     input,
                     columns.into_iter().map(|c| outputs[c]).collect(),
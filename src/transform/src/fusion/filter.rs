@@ -36,6 +36,7 @@
 //! use mz_transform::{Transform, TransformArgs};
 //! Filter.transform(&mut expr, TransformArgs {
 //!   indexes: &mz_transform::EmptyIndexOracle,
+//!   stats: &mz_transform::EmptyStatisticsOracle,
 //! });
 //!
 //! let correct = input.filter(vec![predicate0]);
@@ -52,6 +53,10 @@ use mz_expr::MirRelationExpr;
 pub struct Filter;
 
 impl crate::Transform for Filter {
+    fn recursion_safe(&self) -> bool {
+        true
+    }
+
     #[tracing::instrument(
         target = "optimizer"
         level = "trace",
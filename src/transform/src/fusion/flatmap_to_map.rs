@@ -19,6 +19,10 @@ use mz_expr::{MirRelationExpr, TableFunc};
 pub struct FlatMapToMap;
 
 impl crate::Transform for FlatMapToMap {
+    fn recursion_safe(&self) -> bool {
+        true
+    }
+
     #[tracing::instrument(
         target = "optimizer"
         level = "trace",
@@ -18,6 +18,10 @@ use mz_expr::MirRelationExpr;
 pub struct Negate;
 
 impl crate::Transform for Negate {
+    fn recursion_safe(&self) -> bool {
+        true
+    }
+
     #[tracing::instrument(
         target = "optimizer"
         level = "trace",
@@ -17,6 +17,10 @@ use mz_expr::{MirRelationExpr, MirScalarExpr};
 pub struct Reduce;
 
 impl crate::Transform for Reduce {
+    fn recursion_safe(&self) -> bool {
+        true
+    }
+
     #[tracing::instrument(
         target = "optimizer"
         level = "trace",
@@ -24,6 +24,10 @@ use mz_repr::RelationType;
 pub struct Union;
 
 impl crate::Transform for Union {
+    fn recursion_safe(&self) -> bool {
+        true
+    }
+
     #[tracing::instrument(
         target = "optimizer"
         level = "trace",
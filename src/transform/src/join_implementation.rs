@@ -28,7 +28,7 @@ use mz_ore::stack::{CheckedRecursion, RecursionGuard};
 
 use self::index_map::IndexMap;
 use crate::predicate_pushdown::PredicatePushdown;
-use crate::{TransformArgs, TransformError};
+use crate::{StatisticsOracle, TransformArgs, TransformError};
 
 /// Determines the join implementation for join operators.
 #[derive(Debug)]
@@ -68,7 +68,7 @@ impl crate::Transform for JoinImplementation {
         relation: &mut MirRelationExpr,
         args: TransformArgs,
     ) -> Result<(), TransformError> {
-        let result = self.action_recursive(relation, &mut IndexMap::new(args.indexes));
+        let result = self.action_recursive(relation, &mut IndexMap::new(args.indexes), args.stats);
         mz_repr::explain_new::trace_plan(&*relation);
         result
     }
@@ -83,9 +83,10 @@ impl JoinImplementation {
         &self,
         relation: &mut MirRelationExpr,
         indexes: &mut IndexMap,
+        stats: &dyn StatisticsOracle,
     ) -> Result<(), TransformError> {
         if let MirRelationExpr::Let { id, value, body } = relation {
-            self.action_recursive(value, indexes)?;
+            self.action_recursive(value, indexes, stats)?;
             match &**value {
                 MirRelationExpr::ArrangeBy { keys, .. } => {
                     for key in keys {
@@ -100,13 +101,13 @@ impl JoinImplementation {
                 }
                 _ => {}
             }
-            self.action_recursive(body, indexes)?;
+            self.action_recursive(body, indexes, stats)?;
             indexes.remove_local(*id);
             Ok(())
         } else {
             let (mfp, mfp_input) = MapFilterProject::extract_non_errors_from_expr_ref_mut(relation);
-            mfp_input.try_visit_mut_children(|e| self.action_recursive(e, indexes))?;
-            self.action(mfp_input, mfp, indexes)?;
+            mfp_input.try_visit_mut_children(|e| self.action_recursive(e, indexes, stats))?;
+            self.action(mfp_input, mfp, indexes, stats)?;
             Ok(())
         }
     }
@@ -117,6 +118,7 @@ impl JoinImplementation {
         relation: &mut MirRelationExpr,
         mfp_above: MapFilterProject,
         indexes: &IndexMap,
+        stats: &dyn StatisticsOracle,
     ) -> Result<(), TransformError> {
         if let MirRelationExpr::Join {
             inputs,
@@ -163,6 +165,7 @@ impl JoinImplementation {
                     .collect::<Vec<_>>();
                 let mut available_arrangements = vec![Vec::new(); inputs.len()];
                 let mut filters = Vec::new();
+                let mut statistics = Vec::new();
 
                 // We figure out what predicates from mfp_above could be pushed to which input.
                 // We won't actually push these down now; this just informs FilterCharacteristics.
@@ -229,6 +232,13 @@ impl JoinImplementation {
                         FilterCharacteristics::filter_characteristics(&push_downs[index])?;
                     filters.push(characteristics);
 
+                    // Gather statistics about the collection this input reads, if any.
+                    let columns = project
+                        .iter()
+                        .map(|c| Some(*c).filter(|c| *c < mfp.input_arity))
+                        .collect();
+                    statistics.push(InputStatistics::collect(input, columns, stats));
+
                     // Collect available arrangements on this input.
                     match input {
                         MirRelationExpr::Get { id, typ: _ } => {
@@ -293,6 +303,12 @@ impl JoinImplementation {
                     });
                 }
 
+                // Statistics are only comparable if they are known for all inputs, as we
+                // have no way of telling how an input without statistics measures up.
+                if statistics.iter().any(Option::is_none) {
+                    statistics = vec![None; inputs_len];
+                }
+
                 // Determine if we can perform delta queries with the existing arrangements.
                 // We could defer the execution if we are sure we know we want one input,
                 // but we could imagine wanting the best from each and then comparing the two.
@@ -302,6 +318,7 @@ impl JoinImplementation {
                     &available_arrangements,
                     &unique_keys,
                     &filters,
+                    &statistics,
                 );
                 let differential_plan = differential::plan(
                     relation,
@@ -309,6 +326,7 @@ impl JoinImplementation {
                     &available_arrangements,
                     &unique_keys,
                     &filters,
+                    &statistics,
                 );
 
                 // Employ delta join plans only for multi-way joins of at least three inputs.
@@ -324,6 +342,77 @@ impl JoinImplementation {
     }
 }
 
+/// Statistics about a join input, derived from the statistics of the collection it reads.
+#[derive(Debug, Clone)]
+struct InputStatistics {
+    /// The estimated number of records in the input.
+    cardinality: usize,
+    /// The estimated number of distinct values in each column of the input, if known.
+    distinct: Vec<Option<usize>>,
+}
+
+impl InputStatistics {
+    /// Collects statistics for a join input that reads a global collection, possibly through
+    /// arrangements and map-filter-project operators.
+    ///
+    /// `columns` maps each column of the join input to the column of `input` it projects, or to
+    /// `None` for computed columns. Returns `None` if `input` does not read a collection with
+    /// known statistics.
+    fn collect(
+        mut input: &MirRelationExpr,
+        mut columns: Vec<Option<usize>>,
+        stats: &dyn StatisticsOracle,
+    ) -> Option<Self> {
+        loop {
+            match input {
+                MirRelationExpr::ArrangeBy { input: inner, .. } => {
+                    // Arrangements installed by previous runs may wrap an unlifted mfp.
+                    let (mfp, inner) = MapFilterProject::extract_non_errors_from_expr(inner);
+                    let (_, _, project) = mfp.as_map_filter_project();
+                    columns = columns
+                        .into_iter()
+                        .map(|c| {
+                            c.and_then(|c| project.get(c).copied())
+                                .filter(|c| *c < mfp.input_arity)
+                        })
+                        .collect();
+                    input = inner;
+                }
+                MirRelationExpr::Get {
+                    id: Id::Global(id), ..
+                } => {
+                    let cardinality = stats.cardinality_estimate(*id)?;
+                    let distinct = columns
+                        .iter()
+                        .map(|c| c.and_then(|c| stats.distinct_estimate(*id, c)))
+                        .collect();
+                    return Some(InputStatistics {
+                        cardinality,
+                        distinct,
+                    });
+                }
+                _ => return None,
+            }
+        }
+    }
+
+    /// Estimates the number of records of the input that match each value of `key`.
+    ///
+    /// The number of distinct values of a key is at least that of any of its columns, so the
+    /// estimate is based on the column with the most distinct values.
+    fn records_per_key(&self, key: &[MirScalarExpr]) -> usize {
+        let distinct = key
+            .iter()
+            .filter_map(|k| match k {
+                MirScalarExpr::Column(c) => self.distinct.get(*c).copied().flatten(),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(1);
+        self.cardinality / std::cmp::max(distinct, 1)
+    }
+}
+
 mod index_map {
     use std::collections::HashMap;
 
@@ -381,6 +470,7 @@ mod delta_queries {
         FilterCharacteristics, JoinImplementation, JoinInputMapper, MirRelationExpr, MirScalarExpr,
     };
 
+    use crate::join_implementation::InputStatistics;
     use crate::TransformError;
 
     /// Creates a delta query plan, and any predicates that need to be lifted.
@@ -393,6 +483,7 @@ mod delta_queries {
         available: &[Vec<Vec<MirScalarExpr>>],
         unique_keys: &[Vec<Vec<usize>>],
         filters: &[FilterCharacteristics],
+        statistics: &[Option<InputStatistics>],
     ) -> Result<MirRelationExpr, TransformError> {
         let mut new_join = join.clone();
 
@@ -414,8 +505,14 @@ mod delta_queries {
             }
 
             // Determine a viable order for each relation, or return `Err` if none found.
-            let orders =
-                super::optimize_orders(equivalences, available, unique_keys, filters, input_mapper);
+            let orders = super::optimize_orders(
+                equivalences,
+                available,
+                unique_keys,
+                filters,
+                statistics,
+                input_mapper,
+            );
 
             // A viable delta query requires that, for every order,
             // there is an arrangement for every input except for
@@ -459,7 +556,9 @@ mod delta_queries {
 }
 
 mod differential {
-    use crate::join_implementation::{FilterCharacteristics, JoinInputCharacteristics};
+    use crate::join_implementation::{
+        FilterCharacteristics, InputStatistics, JoinInputCharacteristics,
+    };
     use itertools::Itertools;
     use mz_expr::{JoinImplementation, JoinInputMapper, MirRelationExpr, MirScalarExpr};
     use mz_ore::soft_assert;
@@ -473,6 +572,7 @@ mod differential {
         available: &[Vec<Vec<MirScalarExpr>>],
         unique_keys: &[Vec<Vec<usize>>],
         filters: &[FilterCharacteristics],
+        statistics: &[Option<InputStatistics>],
     ) -> Result<MirRelationExpr, TransformError> {
         let mut new_join = join.clone();
 
@@ -487,8 +587,14 @@ mod differential {
             // Important, we should choose something stable under re-ordering, to converge under fixed
             // point iteration; we choose to start with the first input optimizing our criteria, which
             // should remain stable even when promoted to the first position.
-            let mut orders =
-                super::optimize_orders(equivalences, available, unique_keys, filters, input_mapper);
+            let mut orders = super::optimize_orders(
+                equivalences,
+                available,
+                unique_keys,
+                filters,
+                statistics,
+                input_mapper,
+            );
 
             // Inside each order, we take the `FilterCharacteristics` from each element, and OR it
             // to every other element to the right. This is because we are gonna be looking for the
@@ -736,9 +842,17 @@ fn optimize_orders(
     available: &[Vec<Vec<MirScalarExpr>>],
     unique_keys: &[Vec<Vec<usize>>],
     filters: &[FilterCharacteristics],
+    statistics: &[Option<InputStatistics>],
     input_mapper: &JoinInputMapper,
 ) -> Vec<Vec<(JoinInputCharacteristics, Vec<MirScalarExpr>, usize)>> {
-    let mut orderer = Orderer::new(equivalences, available, unique_keys, filters, input_mapper);
+    let mut orderer = Orderer::new(
+        equivalences,
+        available,
+        unique_keys,
+        filters,
+        statistics,
+        input_mapper,
+    );
     (0..available.len())
         .map(move |i| orderer.optimize_order_for(i))
         .collect::<Vec<_>>()
@@ -750,6 +864,7 @@ struct Orderer<'a> {
     arrangements: &'a [Vec<Vec<MirScalarExpr>>],
     unique_keys: &'a [Vec<Vec<usize>>],
     filters: &'a [FilterCharacteristics],
+    statistics: &'a [Option<InputStatistics>],
     input_mapper: &'a JoinInputMapper,
     reverse_equivalences: Vec<Vec<(usize, usize)>>,
     unique_arrangement: Vec<Vec<bool>>,
@@ -769,6 +884,7 @@ impl<'a> Orderer<'a> {
         arrangements: &'a [Vec<Vec<MirScalarExpr>>],
         unique_keys: &'a [Vec<Vec<usize>>],
         filters: &'a [FilterCharacteristics],
+        statistics: &'a [Option<InputStatistics>],
         input_mapper: &'a JoinInputMapper,
     ) -> Self {
         let inputs = arrangements.len();
@@ -804,6 +920,7 @@ impl<'a> Orderer<'a> {
             arrangements,
             unique_keys,
             filters,
+            statistics,
             input_mapper,
             reverse_equivalences,
             unique_arrangement,
@@ -844,6 +961,7 @@ impl<'a> Orderer<'a> {
                         is_unique,
                         0,
                         true,
+                        self.cardinality(input, &[]),
                         self.filters[input].clone(),
                         input,
                     ),
@@ -856,6 +974,7 @@ impl<'a> Orderer<'a> {
                         is_unique,
                         0,
                         false,
+                        self.cardinality(input, &[]),
                         self.filters[input].clone(),
                         input,
                     ),
@@ -884,7 +1003,14 @@ impl<'a> Orderer<'a> {
         // calculate characteristics of an arrangement, if any on the starting input
         // by default, there is no arrangement on the starting input
         let mut start_tuple = (
-            JoinInputCharacteristics::new(false, 0, false, self.filters[start].clone(), start),
+            JoinInputCharacteristics::new(
+                false,
+                0,
+                false,
+                self.cardinality(start, &[]),
+                self.filters[start].clone(),
+                start,
+            ),
             vec![],
             start,
         );
@@ -913,6 +1039,7 @@ impl<'a> Orderer<'a> {
                             is_unique,
                             candidate_start_key.len(),
                             true,
+                            self.cardinality(start, &candidate_start_key),
                             self.filters[start].clone(),
                             start,
                         ),
@@ -927,6 +1054,14 @@ impl<'a> Orderer<'a> {
         std::mem::replace(&mut self.order, Vec::new())
     }
 
+    /// Estimates the number of records of `input` that match each value of `key`, if
+    /// statistics are available.
+    fn cardinality(&self, input: usize, key: &[MirScalarExpr]) -> Option<usize> {
+        self.statistics[input]
+            .as_ref()
+            .map(|statistics| statistics.records_per_key(key))
+    }
+
     /// Introduces a specific input and keys to the order, along with its characteristics.
     ///
     /// This method places a next element in the order, and updates the associated state
@@ -987,6 +1122,7 @@ impl<'a> Orderer<'a> {
                                                     is_unique,
                                                     keys.len(),
                                                     true,
+                                                    self.cardinality(rel, keys),
                                                     self.filters[rel].clone(),
                                                     rel,
                                                 ),
@@ -1006,6 +1142,7 @@ impl<'a> Orderer<'a> {
                                         is_unique,
                                         self.bound[rel].len(),
                                         false,
+                                        self.cardinality(rel, &self.bound[rel]),
                                         self.filters[rel].clone(),
                                         rel,
                                     ),
@@ -68,6 +68,8 @@ extern crate num_derive;
 pub struct TransformArgs<'a> {
     /// The indexes accessible.
     pub indexes: &'a dyn IndexOracle,
+    /// The statistics collected about accessible collections.
+    pub stats: &'a dyn StatisticsOracle,
 }
 
 /// Types capable of transforming relation expressions.
@@ -98,15 +100,12 @@ pub trait Transform: std::fmt::Debug {
 pub enum TransformError {
     /// An unstructured error.
     Internal(String),
-    /// An ideally temporary error indicating transforms that do not support the `MirRelationExpr::LetRec` variant.
-    LetRecUnsupported,
 }
 
 impl fmt::Display for TransformError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             TransformError::Internal(msg) => write!(f, "internal transform error: {}", msg),
-            TransformError::LetRecUnsupported => write!(f, "LetRec AST node not supported"),
         }
     }
 }
@@ -145,6 +144,37 @@ impl IndexOracle for EmptyIndexOracle {
     }
 }
 
+/// A trait for a type that can answer questions about the statistics of
+/// collections.
+///
+/// Statistics are estimates collected at some point in the past, and may be
+/// arbitrarily out of date. They must only ever inform the choice between
+/// equivalent plans, never the correctness of a plan.
+pub trait StatisticsOracle: fmt::Debug {
+    /// Returns the estimated number of records in the identified collection,
+    /// or `None` if no statistics are known for it.
+    fn cardinality_estimate(&self, id: GlobalId) -> Option<usize>;
+
+    /// Returns the estimated number of distinct values in the specified column
+    /// of the identified collection, or `None` if no statistics are known for
+    /// it.
+    fn distinct_estimate(&self, id: GlobalId, column: usize) -> Option<usize>;
+}
+
+/// A [`StatisticsOracle`] that knows about no statistics.
+#[derive(Debug)]
+pub struct EmptyStatisticsOracle;
+
+impl StatisticsOracle for EmptyStatisticsOracle {
+    fn cardinality_estimate(&self, _: GlobalId) -> Option<usize> {
+        None
+    }
+
+    fn distinct_estimate(&self, _: GlobalId, _: usize) -> Option<usize> {
+        None
+    }
+}
+
 /// A sequence of transformations iterated some number of times.
 #[derive(Debug)]
 pub struct Fixpoint {
@@ -196,6 +226,7 @@ impl Transform for Fixpoint {
                                 relation,
                                 TransformArgs {
                                     indexes: args.indexes,
+                                    stats: args.stats,
                                 },
                             )?;
                         }
@@ -221,6 +252,7 @@ impl Transform for Fixpoint {
                     relation,
                     TransformArgs {
                         indexes: args.indexes,
+                        stats: args.stats,
                     },
                 )?;
             }
@@ -285,6 +317,10 @@ impl Default for FuseAndCollapse {
 }
 
 impl Transform for FuseAndCollapse {
+    fn recursion_safe(&self) -> bool {
+        true
+    }
+
     #[tracing::instrument(
         target = "optimizer"
         level = "trace",
@@ -296,13 +332,17 @@ impl Transform for FuseAndCollapse {
         relation: &mut MirRelationExpr,
         args: TransformArgs,
     ) -> Result<(), TransformError> {
+        let recursive = relation.is_recursive();
         for transform in self.transforms.iter() {
-            transform.transform(
-                relation,
-                TransformArgs {
-                    indexes: args.indexes,
-                },
-            )?;
+            if transform.recursion_safe() || !recursive {
+                transform.transform(
+                    relation,
+                    TransformArgs {
+                        indexes: args.indexes,
+                        stats: args.stats,
+                    },
+                )?;
+            }
         }
         mz_repr::explain_new::trace_plan(&*relation);
         Ok(())
@@ -476,7 +516,8 @@ impl Optimizer {
         &self,
         mut relation: MirRelationExpr,
     ) -> Result<mz_expr::OptimizedMirRelationExpr, TransformError> {
-        let transform_result = self.transform(&mut relation, &EmptyIndexOracle);
+        let transform_result =
+            self.transform(&mut relation, &EmptyIndexOracle, &EmptyStatisticsOracle);
         match transform_result {
             Ok(_) => {
                 mz_repr::explain_new::trace_plan(&relation);
@@ -493,7 +534,8 @@ impl Optimizer {
         }
     }
 
-    /// Optimizes the supplied relation expression in place, using available arrangements.
+    /// Optimizes the supplied relation expression in place, using available arrangements
+    /// and collection statistics.
     ///
     /// This method should only be called with non-empty `indexes` when optimizing a dataflow,
     /// as the optimizations may lock in the use of arrangements that may cease to exist.
@@ -501,11 +543,12 @@ impl Optimizer {
         &self,
         relation: &mut MirRelationExpr,
         indexes: &dyn IndexOracle,
+        stats: &dyn StatisticsOracle,
     ) -> Result<(), TransformError> {
         let recursive = relation.is_recursive();
         for transform in self.transforms.iter() {
             if transform.recursion_safe() || !recursive {
-                transform.transform(relation, TransformArgs { indexes })?;
+                transform.transform(relation, TransformArgs { indexes, stats })?;
             }
         }
 
@@ -53,6 +53,10 @@ impl CheckedRecursion for LiteralLifting {
 }
 
 impl crate::Transform for LiteralLifting {
+    fn recursion_safe(&self) -> bool {
+        true
+    }
+
     #[tracing::instrument(
         target = "optimizer"
         level = "trace",
@@ -263,7 +267,17 @@ impl LiteralLifting {
                     gets.remove(&id);
                     result
                 }
-                MirRelationExpr::LetRec { .. } => Err(crate::TransformError::LetRecUnsupported)?,
+                MirRelationExpr::LetRec { values, body, .. } => {
+                    // Literals lifted out of a recursive binding are re-installed
+                    // atop it, as the uses of the binding cannot be corrected.
+                    for value in values.iter_mut() {
+                        let literals = self.action(value, gets)?;
+                        if !literals.is_empty() {
+                            *value = value.take_dangerous().map(literals);
+                        }
+                    }
+                    self.action(body, gets)
+                }
                 MirRelationExpr::Project { input, outputs } => {
                     // We do not want to lift literals around projections.
                     // Projections are the highest lifted operator and lifting
@@ -116,7 +116,12 @@ impl MonotonicFlag {
                     }
                     result
                 }
-                MirRelationExpr::LetRec { ids, values, body } => {
+                MirRelationExpr::LetRec {
+                    ids,
+                    values,
+                    max_iters: _,
+                    body,
+                } => {
                     for id in ids.iter() {
                         if locals.contains(id) {
                             panic!("Shadowing of identifier: {:?}", id);
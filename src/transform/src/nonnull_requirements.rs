@@ -50,6 +50,10 @@ impl CheckedRecursion for NonNullRequirements {
 }
 
 impl crate::Transform for NonNullRequirements {
+    fn recursion_safe(&self) -> bool {
+        true
+    }
+
     #[tracing::instrument(
         target = "optimizer"
         level = "trace",
@@ -110,7 +114,28 @@ impl NonNullRequirements {
                     }
                     Ok(())
                 }
-                MirRelationExpr::LetRec { .. } => Err(crate::TransformError::LetRecUnsupported)?,
+                MirRelationExpr::LetRec {
+                    ids, values, body, ..
+                } => {
+                    // Requirements at uses of recursive bindings are not pushed
+                    // at their values, which must produce the same collection
+                    // for every use.
+                    let priors = ids
+                        .iter()
+                        .map(|id| (Id::Local(*id), gets.insert(Id::Local(*id), Vec::new())))
+                        .collect::<Vec<_>>();
+                    self.action(body, columns, gets)?;
+                    for value in values.iter_mut() {
+                        self.action(value, HashSet::new(), gets)?;
+                    }
+                    for (id, prior) in priors {
+                        gets.remove(&id);
+                        if let Some(prior) = prior {
+                            gets.insert(id, prior);
+                        }
+                    }
+                    Ok(())
+                }
                 MirRelationExpr::Project { input, outputs } => self.action(
                     input,
                     columns.into_iter().map(|c| outputs[c]).collect(),
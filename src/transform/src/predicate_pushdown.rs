@@ -60,6 +60,7 @@
 //! use mz_transform::{Transform, TransformArgs};
 //! PredicatePushdown::default().transform(&mut expr, TransformArgs {
 //!   indexes: &mz_transform::EmptyIndexOracle,
+//!   stats: &mz_transform::EmptyStatisticsOracle,
 //! });
 //!
 //! let predicate00 = MirScalarExpr::column(0).call_binary(MirScalarExpr::column(0), BinaryFunc::AddInt64);
@@ -107,6 +108,10 @@ impl CheckedRecursion for PredicatePushdown {
 }
 
 impl crate::Transform for PredicatePushdown {
+    fn recursion_safe(&self) -> bool {
+        true
+    }
+
     #[tracing::instrument(
         target = "optimizer"
         level = "trace",
@@ -467,6 +472,20 @@ impl PredicatePushdown {
                     // Continue recursively on the value.
                     self.action(value, get_predicates)
                 }
+                MirRelationExpr::LetRec {
+                    ids, values, body, ..
+                } => {
+                    // Predicates are not moved into recursive bindings, as each
+                    // binding is also used by the other bindings and itself.
+                    self.action(body, get_predicates)?;
+                    for value in values.iter_mut() {
+                        self.action(value, get_predicates)?;
+                    }
+                    for id in ids.iter() {
+                        get_predicates.remove(&Id::Local(*id));
+                    }
+                    Ok(())
+                }
                 MirRelationExpr::Join {
                     inputs,
                     equivalences,
@@ -39,6 +39,10 @@ impl CheckedRecursion for ProjectionLifting {
 }
 
 impl crate::Transform for ProjectionLifting {
+    fn recursion_safe(&self) -> bool {
+        true
+    }
+
     #[tracing::instrument(
         target = "optimizer"
         level = "trace",
@@ -91,7 +95,14 @@ impl ProjectionLifting {
                     gets.remove(&id);
                     Ok(())
                 }
-                MirRelationExpr::LetRec { .. } => Err(crate::TransformError::LetRecUnsupported)?,
+                MirRelationExpr::LetRec { values, body, .. } => {
+                    // Projections are not lifted out of recursive bindings, as
+                    // the types of their uses would need to change as well.
+                    for value in values.iter_mut() {
+                        self.action(value, gets)?;
+                    }
+                    self.action(body, gets)
+                }
                 MirRelationExpr::Project { input, outputs } => {
                     self.action(input, gets)?;
                     if let MirRelationExpr::Project {
@@ -42,6 +42,10 @@ use crate::{TransformArgs, TransformError};
 pub struct ProjectionPushdown;
 
 impl crate::Transform for ProjectionPushdown {
+    fn recursion_safe(&self) -> bool {
+        true
+    }
+
     // This method is only used during unit testing.
     #[tracing::instrument(
         target = "optimizer"
@@ -122,9 +126,28 @@ impl ProjectionPushdown {
                 )?;
                 desired_projection.clone()
             }
-            MirRelationExpr::LetRec { .. } => {
+            MirRelationExpr::LetRec {
+                ids, values, body, ..
+            } => {
+                // Recursive bindings retain all of their columns, as the requirements
+                // of their uses would need to be harvested to a fixed point.
                 // TODO: Implement a more thoughtful projection pushdown.
-                (0..relation.arity()).collect()
+                let priors = ids
+                    .iter()
+                    .map(|id| (Id::Local(*id), gets.insert(Id::Local(*id), BTreeSet::new())))
+                    .collect::<Vec<_>>();
+                self.action(body, desired_projection, gets)?;
+                for value in values.iter_mut() {
+                    let arity = value.arity();
+                    self.action(value, &(0..arity).collect(), gets)?;
+                }
+                for (id, prior) in priors {
+                    gets.remove(&id);
+                    if let Some(prior) = prior {
+                        gets.insert(id, prior);
+                    }
+                }
+                desired_projection.clone()
             }
             MirRelationExpr::Join {
                 inputs,
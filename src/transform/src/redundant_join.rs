@@ -52,6 +52,10 @@ impl CheckedRecursion for RedundantJoin {
 }
 
 impl crate::Transform for RedundantJoin {
+    fn recursion_safe(&self) -> bool {
+        true
+    }
+
     #[tracing::instrument(
         target = "optimizer"
         level = "trace",
@@ -102,7 +106,15 @@ impl RedundantJoin {
                     }
                     Ok(result)
                 }
-                MirRelationExpr::LetRec { .. } => Err(crate::TransformError::LetRecUnsupported)?,
+                MirRelationExpr::LetRec { values, body, .. } => {
+                    // The provenance of recursive bindings is not recorded, as it
+                    // would need to hold for each iterate. Uses of the bindings
+                    // still report themselves as leaves.
+                    for value in values.iter_mut() {
+                        self.action(value, lets)?;
+                    }
+                    self.action(body, lets)
+                }
                 MirRelationExpr::Get { id, typ } => {
                     // Extract the value provenance, or an empty list if unavailable.
                     let mut val_info = lets.get(id).cloned().unwrap_or_default();
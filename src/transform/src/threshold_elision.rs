@@ -27,6 +27,10 @@ use mz_expr::MirRelationExpr;
 pub struct ThresholdElision;
 
 impl crate::Transform for ThresholdElision {
+    fn recursion_safe(&self) -> bool {
+        true
+    }
+
     #[tracing::instrument(
         target = "optimizer"
         level = "trace",
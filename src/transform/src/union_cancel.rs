@@ -19,6 +19,10 @@ use mz_expr::MirRelationExpr;
 pub struct UnionBranchCancellation;
 
 impl crate::Transform for UnionBranchCancellation {
+    fn recursion_safe(&self) -> bool {
+        true
+    }
+
     #[tracing::instrument(
         target = "optimizer"
         level = "trace",
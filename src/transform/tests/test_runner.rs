@@ -29,12 +29,13 @@ mod tests {
     use mz_ore::str::separated;
     use mz_repr::GlobalId;
     use mz_transform::dataflow::{optimize_dataflow_demand_inner, optimize_dataflow_filters_inner};
-    use mz_transform::{EmptyIndexOracle, Optimizer, Transform, TransformArgs};
+    use mz_transform::{EmptyIndexOracle, Optimizer, StatisticsOracle, Transform, TransformArgs};
     use proc_macro2::TokenTree;
 
     // Global options
     const IN: &str = "in";
     const FORMAT: &str = "format";
+    const CARDINALITY: &str = "cardinality";
     // Values that can be supplied for global options
     const JSON: &str = "json";
     const TEST: &str = "test";
@@ -99,6 +100,41 @@ mod tests {
         build_rel(s, cat).map_err(|e| anyhow!(e))
     }
 
+    /// A [`StatisticsOracle`] that knows about the cardinalities specified in
+    /// `args[cardinality]` as `name:count` pairs.
+    #[derive(Debug)]
+    struct TestStatisticsOracle<'a> {
+        cat: &'a TestCatalog,
+        cardinalities: HashMap<String, usize>,
+    }
+
+    impl<'a> TestStatisticsOracle<'a> {
+        fn new(
+            cat: &'a TestCatalog,
+            args: &HashMap<String, Vec<String>>,
+        ) -> Result<TestStatisticsOracle<'a>, Error> {
+            let mut cardinalities = HashMap::new();
+            for spec in args.get(CARDINALITY).into_iter().flatten() {
+                let (name, count) = spec
+                    .split_once(':')
+                    .ok_or_else(|| anyhow!("invalid cardinality {}", spec))?;
+                cardinalities.insert(name.to_string(), count.parse()?);
+            }
+            Ok(TestStatisticsOracle { cat, cardinalities })
+        }
+    }
+
+    impl StatisticsOracle for TestStatisticsOracle<'_> {
+        fn cardinality_estimate(&self, id: GlobalId) -> Option<usize> {
+            let name = self.cat.get_source_name(&id)?;
+            self.cardinalities.get(name).copied()
+        }
+
+        fn distinct_estimate(&self, _: GlobalId, _: usize) -> Option<usize> {
+            None
+        }
+    }
+
     /// Converts MirRelationExpr to `format_type`.
     fn convert_rel_to_string(
         rel: &MirRelationExpr,
@@ -122,11 +158,13 @@ mod tests {
         test_type: TestType,
     ) -> Result<String, Error> {
         let mut rel = parse_relation(s, cat, args)?;
+        let stats = TestStatisticsOracle::new(cat, args)?;
         for t in args.get("apply").cloned().unwrap_or_else(Vec::new).iter() {
             get_transform(t)?.transform(
                 &mut rel,
                 TransformArgs {
                     indexes: &EmptyIndexOracle,
+                    stats: &stats,
                 },
             )?;
         }
@@ -140,6 +178,7 @@ mod tests {
                         &mut rel,
                         TransformArgs {
                             indexes: &EmptyIndexOracle,
+                            stats: &stats,
                         },
                     )?;
                 }
@@ -163,6 +202,7 @@ mod tests {
                             &mut rel,
                             TransformArgs {
                                 indexes: &EmptyIndexOracle,
+                                stats: &stats,
                             },
                         )?;
 
@@ -0,0 +1,38 @@
+# Copyright Materialize, Inc. and contributors. All rights reserved.
+#
+# Use of this software is governed by the Business Source License
+# included in the LICENSE file at the root of this repository.
+#
+# As of the Change Date specified in that file, in accordance with
+# the Business Source License, use of this software will be governed
+# by the Apache License, Version 2.0.
+
+from materialize.mzcompose import Composition, WorkflowArgumentParser
+from materialize.mzcompose.services import Materialized, MySql, Testdrive
+
+MYSQL_ROOT_PASSWORD = "rootpw"
+
+SERVICES = [
+    Materialized(),
+    Testdrive(),
+    MySql(mysql_root_password=MYSQL_ROOT_PASSWORD),
+]
+
+
+def workflow_default(c: Composition, parser: WorkflowArgumentParser) -> None:
+    parser.add_argument(
+        "filter",
+        nargs="*",
+        default=["*.td"],
+        help="limit to only the files matching filter",
+    )
+    args = parser.parse_args()
+
+    c.up("materialized", "testdrive")
+    c.start_and_wait_for_tcp(services=["mysql"])
+    c.wait_for_materialized()
+    c.run(
+        "testdrive",
+        f"--var=mysql-root-password={MYSQL_ROOT_PASSWORD}",
+        *args.filter,
+    )